@@ -1,6 +1,68 @@
 use capnp::Error;
 use capnpc::CompilerCommand;
+use std::{collections::HashSet, fs};
+
+const SCHEMA: &str = "proto/conmon.capnp";
+const SCHEMA_LOCK: &str = "proto/conmon.capnp.lock";
 
 fn main() -> Result<(), Error> {
-    CompilerCommand::new().file("proto/conmon.capnp").run()
+    println!("cargo:rerun-if-changed={}", SCHEMA);
+    println!("cargo:rerun-if-changed={}", SCHEMA_LOCK);
+    verify_schema_compatibility()?;
+    CompilerCommand::new().file(SCHEMA).run()
+}
+
+/// Verify that every entry of the schema lock file is still part of the schema, which ensures
+/// that no released field or method got removed, renumbered or retyped.
+fn verify_schema_compatibility() -> Result<(), Error> {
+    let schema = fs::read_to_string(SCHEMA).map_err(|e| Error::failed(e.to_string()))?;
+    let lock = fs::read_to_string(SCHEMA_LOCK).map_err(|e| Error::failed(e.to_string()))?;
+    let entries = schema_entries(&schema);
+
+    let missing = lock
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter(|l| !entries.contains(*l))
+        .collect::<Vec<_>>();
+
+    if !missing.is_empty() {
+        return Err(Error::failed(format!(
+            "backwards incompatible schema change, missing entries:\n{}",
+            missing.join("\n")
+        )));
+    }
+    Ok(())
+}
+
+/// Collect all fields, enumerants and methods of the schema as fully qualified entries, for
+/// example `Conmon.VersionResponse.version @0 :Text`.
+fn schema_entries(schema: &str) -> HashSet<String> {
+    let mut entries = HashSet::new();
+    let mut scopes: Vec<String> = vec![];
+
+    for line in schema.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        let words = line.split_whitespace().collect::<Vec<_>>();
+
+        match words.as_slice() {
+            ["struct" | "interface" | "enum", name, "{", ..] => scopes.push(name.to_string()),
+            ["}", ..] => {
+                scopes.pop();
+            }
+            [name, ordinal, ..] if ordinal.starts_with('@') && !scopes.is_empty() => {
+                let ordinal = ordinal.trim_end_matches(';');
+                let rest = words[2..].join(" ");
+                let rest = rest.trim_end_matches(';').trim();
+                let mut entry = format!("{}.{} {}", scopes.join("."), name, ordinal);
+                if !rest.is_empty() {
+                    entry.push(' ');
+                    entry.push_str(rest);
+                }
+                entries.insert(entry);
+            }
+            _ => {}
+        }
+    }
+    entries
 }
@@ -1,28 +1,45 @@
-use crate::{container_io::Pipe, listener};
-use anyhow::{bail, Context, Result};
+use crate::{
+    attach_protocol::{
+        self, FrameType, Header, CHANNEL_RESIZE, CHANNEL_STDERR, CHANNEL_STDIN, CHANNEL_STDOUT,
+        FLAG_HEARTBEAT,
+    },
+    container_io::Pipe,
+    encoding::{Transcoder, Translation},
+    listener,
+};
+use anyhow::{bail, format_err, Context, Result};
 use nix::{
     errno::Errno,
     sys::socket::{bind, listen, socket, AddressFamily, SockFlag, SockType, UnixAddr},
 };
 use std::{
     convert::From,
+    fs,
     os::unix::{
         fs::PermissionsExt,
         io::{FromRawFd, RawFd},
         net,
     },
     path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Duration,
 };
+use subtle::ConstantTimeEq;
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt, ErrorKind},
     net::{
         unix::{OwnedReadHalf, OwnedWriteHalf},
-        UnixListener,
+        UnixListener, UnixStream,
+    },
+    sync::{
+        broadcast::{self, error::RecvError, Receiver, Sender},
+        mpsc,
     },
-    sync::broadcast::{self, Receiver, Sender},
     task,
+    time::{self, Instant},
 };
-use tracing::{debug, debug_span, error, Instrument};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, debug_span, error, trace, Instrument};
 
 #[derive(Debug)]
 /// A shared container attach abstraction.
@@ -30,16 +47,23 @@ pub struct SharedContainerAttach {
     read_half_rx: Receiver<Vec<u8>>,
     read_half_tx: Sender<Vec<u8>>,
     write_half_tx: Sender<(Pipe, Vec<u8>)>,
+    resize_tx: Sender<(u16, u16)>,
+    socket_paths: Arc<Mutex<Vec<PathBuf>>>,
+    token: CancellationToken,
 }
 
 impl Default for SharedContainerAttach {
     fn default() -> Self {
         let (read_half_tx, read_half_rx) = broadcast::channel(1000);
         let (write_half_tx, _) = broadcast::channel(1000);
+        let (resize_tx, _) = broadcast::channel(10);
         Self {
             read_half_rx,
             read_half_tx,
             write_half_tx,
+            resize_tx,
+            socket_paths: Default::default(),
+            token: CancellationToken::new(),
         }
     }
 }
@@ -50,23 +74,117 @@ impl Clone for SharedContainerAttach {
             read_half_rx: self.read_half_tx.subscribe(),
             read_half_tx: self.read_half_tx.clone(),
             write_half_tx: self.write_half_tx.clone(),
+            resize_tx: self.resize_tx.clone(),
+            socket_paths: self.socket_paths.clone(),
+            token: self.token.clone(),
         }
     }
 }
 
 impl SharedContainerAttach {
-    /// Add a new attach endpoint to this shared container attach instance.
-    pub async fn add<T>(&mut self, socket_path: T) -> Result<()>
+    /// Add a new attach endpoint to this shared container attach instance, which serves the
+    /// provided protocol version. The optional translation applies to all of its clients.
+    pub async fn add<T>(
+        &mut self,
+        socket_path: T,
+        version: u8,
+        translation: Option<Translation>,
+    ) -> Result<()>
     where
         T: AsRef<Path>,
         PathBuf: From<T>,
     {
+        let path = socket_path.as_ref().to_path_buf();
         Attach::create(
             socket_path,
+            version,
+            translation,
+            self.read_half_tx.clone(),
+            self.write_half_tx.clone(),
+            self.resize_tx.clone(),
+            self.token.clone(),
+        )
+        .context("create attach endpoint")?;
+        self.track(path)
+    }
+
+    /// Subscribe to the terminal resize requests of all attach endpoints. Versioned endpoints
+    /// only offer resizing if at least one subscriber exists.
+    pub fn subscribe_resize(&self) -> Receiver<(u16, u16)> {
+        self.resize_tx.subscribe()
+    }
+
+    /// Add a new one-shot raw passthrough endpoint to this shared container attach instance.
+    /// The connecting client has to send the provided token before any data gets forwarded.
+    pub async fn add_passthrough<T>(&mut self, socket_path: T, token: &str) -> Result<()>
+    where
+        T: AsRef<Path>,
+    {
+        Passthrough::create(
+            socket_path.as_ref(),
+            token,
             self.read_half_tx.clone(),
             self.write_half_tx.clone(),
+            self.token.clone(),
         )
-        .context("create attach endpoint")
+        .context("create passthrough endpoint")?;
+        self.track(socket_path.as_ref().to_path_buf())
+    }
+
+    /// Add a new mirror endpoint, which duplicates the output of `pipe` (or both pipes if not
+    /// set) to all connected clients until `duration` elapsed. Clients cannot write to stdin.
+    pub fn add_mirror(
+        &mut self,
+        socket_path: &Path,
+        pipe: Option<Pipe>,
+        duration: Duration,
+    ) -> Result<()> {
+        Mirror::create(
+            socket_path,
+            pipe,
+            duration,
+            self.write_half_tx.clone(),
+            self.token.clone(),
+        )
+        .context("create mirror endpoint")?;
+        self.track(socket_path.to_path_buf())
+    }
+
+    /// Stop serving all attach endpoints and remove their sockets from disk.
+    pub fn close(&self) -> Result<()> {
+        self.token.cancel();
+        let paths = self
+            .socket_paths
+            .lock()
+            .map_err(|e| format_err!("{:#}", e))?
+            .drain(..)
+            .collect::<Vec<_>>();
+        for path in paths {
+            match fs::remove_file(&path) {
+                Err(e) if e.kind() != ErrorKind::NotFound => {
+                    debug!("Unable to remove attach socket {}: {}", path.display(), e)
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// The sockets of all attach endpoints which are still served.
+    pub fn socket_paths(&self) -> Result<Vec<PathBuf>> {
+        Ok(self
+            .socket_paths
+            .lock()
+            .map_err(|e| format_err!("{:#}", e))?
+            .clone())
+    }
+
+    fn track(&self, path: PathBuf) -> Result<()> {
+        self.socket_paths
+            .lock()
+            .map_err(|e| format_err!("{:#}", e))?
+            .push(path);
+        Ok(())
     }
 
     /// Read from all attach endpoints standard input and return the first result.
@@ -77,17 +195,18 @@ impl SharedContainerAttach {
             .context("receive attach message")
     }
 
-    /// Write a buffer to all attach endpoints.
-    pub async fn write<T>(&mut self, pipe: Pipe, buf: T) -> Result<()>
+    /// Write a buffer to all attach endpoints. Returns false if no endpoint is connected.
+    pub async fn write<T>(&mut self, pipe: Pipe, buf: T) -> Result<bool>
     where
         T: AsRef<[u8]>,
     {
-        if self.write_half_tx.receiver_count() > 0 {
-            self.write_half_tx
-                .send((pipe, buf.as_ref().into()))
-                .context("send data message to attach clients")?;
+        if self.write_half_tx.receiver_count() == 0 {
+            return Ok(false);
         }
-        Ok(())
+        self.write_half_tx
+            .send((pipe, buf.as_ref().into()))
+            .context("send data message to attach clients")?;
+        Ok(true)
     }
 }
 
@@ -102,11 +221,25 @@ impl Attach {
     /// The packet indicating that we're done writing.
     const DONE_PACKET: &'static [u8; Self::PACKET_BUF_SIZE] = &[0; Self::PACKET_BUF_SIZE];
 
+    /// The maximum time to wait for the client header of versioned connections.
+    const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// The interval of heartbeat frames sent to versioned clients.
+    const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+    /// The maximum time a client with heartbeats may stay silent or stop reading frames before
+    /// its connection gets closed.
+    const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(15);
+
     /// Create a new attach instance.
     fn create<T>(
         socket_path: T,
+        version: u8,
+        translation: Option<Translation>,
         read_half_tx: Sender<Vec<u8>>,
         write_half_tx: Sender<(Pipe, Vec<u8>)>,
+        resize_tx: Sender<(u16, u16)>,
+        token: CancellationToken,
     ) -> Result<()>
     where
         T: AsRef<Path>,
@@ -140,7 +273,17 @@ impl Attach {
 
         task::spawn(
             async move {
-                if let Err(e) = Self::start(fd, read_half_tx, write_half_tx).await {
+                if let Err(e) = Self::start(
+                    fd,
+                    version,
+                    translation,
+                    read_half_tx,
+                    write_half_tx,
+                    resize_tx,
+                    token,
+                )
+                .await
+                {
                     error!("Attach failure: {:#}", e);
                 }
             }
@@ -152,13 +295,47 @@ impl Attach {
 
     async fn start(
         fd: RawFd,
+        version: u8,
+        translation: Option<Translation>,
         read_half_tx: Sender<Vec<u8>>,
         write_half_tx: Sender<(Pipe, Vec<u8>)>,
+        resize_tx: Sender<(u16, u16)>,
+        token: CancellationToken,
     ) -> Result<()> {
         debug!("Start listening on attach socket");
         let listener = UnixListener::from_std(unsafe { net::UnixListener::from_raw_fd(fd) })?;
         loop {
-            match listener.accept().await {
+            let accepted = tokio::select! {
+                res = listener.accept() => res,
+                _ = token.cancelled() => {
+                    debug!("Stop listening on attach socket");
+                    return Ok(());
+                }
+            };
+            match accepted {
+                Ok((stream, _)) if version > 0 => {
+                    debug!("Got new versioned attach stream connection");
+                    let read_half_tx_clone = read_half_tx.clone();
+                    let write_half_rx = write_half_tx.subscribe();
+                    let resize_tx_clone = resize_tx.clone();
+                    task::spawn(
+                        async move {
+                            if let Err(e) = Self::serve_versioned(
+                                stream,
+                                version,
+                                translation,
+                                read_half_tx_clone,
+                                write_half_rx,
+                                resize_tx_clone,
+                            )
+                            .await
+                            {
+                                error!("Attach failure: {:#}", e);
+                            }
+                        }
+                        .instrument(debug_span!("serve_versioned", version)),
+                    );
+                }
                 Ok((stream, _)) => {
                     debug!("Got new attach stream connection");
                     let (read, write) = stream.into_split();
@@ -166,7 +343,9 @@ impl Attach {
                     let read_half_tx_clone = read_half_tx.clone();
                     task::spawn(
                         async move {
-                            if let Err(e) = Self::read_loop(read, read_half_tx_clone).await {
+                            if let Err(e) =
+                                Self::read_loop(read, read_half_tx_clone, translation).await
+                            {
                                 error!("Attach read loop failure: {:#}", e);
                             }
                         }
@@ -176,7 +355,9 @@ impl Attach {
                     let write_half_rx = write_half_tx.subscribe();
                     task::spawn(
                         async move {
-                            if let Err(e) = Self::write_loop(write, write_half_rx).await {
+                            if let Err(e) =
+                                Self::write_loop(write, write_half_rx, translation).await
+                            {
                                 error!("Attach write loop failure: {:#}", e);
                             }
                         }
@@ -188,7 +369,12 @@ impl Attach {
         }
     }
 
-    async fn read_loop(mut read_half: OwnedReadHalf, tx: Sender<Vec<u8>>) -> Result<()> {
+    async fn read_loop(
+        mut read_half: OwnedReadHalf,
+        tx: Sender<Vec<u8>>,
+        translation: Option<Translation>,
+    ) -> Result<()> {
+        let mut transcoder = translation.map(|t| t.input());
         loop {
             let mut buf = vec![0; Self::PACKET_BUF_SIZE];
             match read_half.read(&mut buf).await {
@@ -197,6 +383,9 @@ impl Attach {
                         buf.resize(first_zero_idx, 0);
                     }
                     debug!("Read {} stdin bytes from client", buf.len());
+                    if let Some(transcoder) = &mut transcoder {
+                        buf = transcoder.transcode(&buf);
+                    }
                     tx.send(buf).context("send data message")?;
                 }
                 Ok(n) if n == 0 => {
@@ -228,9 +417,15 @@ impl Attach {
     async fn write_loop(
         mut write_half: OwnedWriteHalf,
         mut rx: Receiver<(Pipe, Vec<u8>)>,
+        translation: Option<Translation>,
     ) -> Result<()> {
+        let mut transcoders = translation.map(|t| (t.output(), t.output()));
         loop {
             let (pipe, buf) = rx.recv().await?;
+            let buf = Self::transcode_output(&mut transcoders, pipe, buf);
+            if buf.is_empty() {
+                continue;
+            }
 
             let mut packets = buf
                 .chunks(Self::PACKET_BUF_SIZE - 1)
@@ -260,4 +455,471 @@ impl Attach {
             }
         }
     }
+
+    /// Serve a connection using the versioned protocol, starting with the header exchange.
+    async fn serve_versioned(
+        mut stream: UnixStream,
+        version: u8,
+        translation: Option<Translation>,
+        read_half_tx: Sender<Vec<u8>>,
+        write_half_rx: Receiver<(Pipe, Vec<u8>)>,
+        resize_tx: Sender<(u16, u16)>,
+    ) -> Result<()> {
+        let server_header = Header::server(version, resize_tx.receiver_count() > 0);
+        stream
+            .write_all(&server_header.encode())
+            .await
+            .context("write server header")?;
+
+        let mut buf = vec![0; Self::PACKET_BUF_SIZE];
+        let n = time::timeout(Self::HANDSHAKE_TIMEOUT, stream.read(&mut buf))
+            .await
+            .context("wait for client header")?
+            .context("read client header")?;
+        let header = server_header
+            .negotiate(&Header::decode(&buf[..n])?)
+            .context("negotiate attach protocol")?;
+        debug!("Negotiated attach protocol: {:?}", header);
+
+        let (read, write) = stream.into_split();
+        let (alive_tx, alive_rx) = mpsc::channel(1);
+        let read_loop =
+            Self::read_loop_versioned(read, header, translation, read_half_tx, resize_tx, alive_tx)
+                .instrument(debug_span!("read_loop"));
+        let write_loop =
+            Self::write_loop_versioned(write, write_half_rx, header, translation, alive_rx)
+                .instrument(debug_span!("write_loop"));
+        tokio::pin!(read_loop, write_loop);
+
+        // Both halves get closed as soon as the write loop ends, whereas a client which closed
+        // its sending side may still receive output.
+        tokio::select! {
+            res = &mut read_loop => {
+                res.context("attach read loop")?;
+                write_loop.await
+            }
+            res = &mut write_loop => res,
+        }
+    }
+
+    async fn read_loop_versioned(
+        mut read_half: OwnedReadHalf,
+        header: Header,
+        translation: Option<Translation>,
+        tx: Sender<Vec<u8>>,
+        resize_tx: Sender<(u16, u16)>,
+        alive_tx: mpsc::Sender<()>,
+    ) -> Result<()> {
+        let mut transcoder = translation.map(|t| t.input());
+        let mut buf = vec![0; Self::PACKET_BUF_SIZE];
+        loop {
+            let n = match read_half.read(&mut buf).await {
+                Ok(0) => {
+                    debug!("Stopping read loop because no more data to read");
+                    return Ok(());
+                }
+                Ok(n) => n,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+                Err(e) => return Err(e).context("read frame"),
+            };
+
+            // A full channel already signals liveness to the write loop.
+            let _ = alive_tx.try_send(());
+
+            match attach_protocol::decode_frame(&buf[..n])? {
+                (FrameType::Stdin, payload) if header.has_channel(CHANNEL_STDIN) => {
+                    debug!("Read {} stdin bytes from client", payload.len());
+                    let payload = match &mut transcoder {
+                        Some(transcoder) => transcoder.transcode(payload),
+                        None => payload.to_vec(),
+                    };
+                    tx.send(payload).context("send data message")?;
+                }
+                (FrameType::Resize, payload) if header.has_channel(CHANNEL_RESIZE) => {
+                    let (width, height) = attach_protocol::decode_resize(payload)?;
+                    debug!("Got resize request to {}x{} from client", width, height);
+                    if resize_tx.send((width, height)).is_err() {
+                        debug!("No terminal available for resize request");
+                    }
+                }
+                (FrameType::Heartbeat, _) => trace!("Got heartbeat from client"),
+                (typ, _) => debug!("Ignoring unexpected {:?} frame from client", typ),
+            }
+        }
+    }
+
+    async fn write_loop_versioned(
+        mut write_half: OwnedWriteHalf,
+        mut rx: Receiver<(Pipe, Vec<u8>)>,
+        header: Header,
+        translation: Option<Translation>,
+        mut alive_rx: mpsc::Receiver<()>,
+    ) -> Result<()> {
+        let mut transcoders = translation.map(|t| (t.output(), t.output()));
+        let heartbeat = header.has_flag(FLAG_HEARTBEAT);
+        let mut interval = time::interval_at(
+            Instant::now() + Self::HEARTBEAT_INTERVAL,
+            Self::HEARTBEAT_INTERVAL,
+        );
+        let mut last_seen = Instant::now();
+
+        loop {
+            let frames = tokio::select! {
+                res = rx.recv() => {
+                    let (pipe, buf) = res?;
+                    let (typ, channel) = match pipe {
+                        Pipe::StdOut => (FrameType::Stdout, CHANNEL_STDOUT),
+                        Pipe::StdErr => (FrameType::Stderr, CHANNEL_STDERR),
+                    };
+                    if !header.has_channel(channel) {
+                        continue;
+                    }
+                    Self::transcode_output(&mut transcoders, pipe, buf)
+                        .chunks(Self::PACKET_BUF_SIZE - 1)
+                        .map(|chunk| attach_protocol::encode_frame(typ, chunk))
+                        .collect::<Vec<_>>()
+                }
+                Some(()) = alive_rx.recv() => {
+                    last_seen = Instant::now();
+                    continue;
+                }
+                _ = interval.tick(), if heartbeat => {
+                    if last_seen.elapsed() > Self::HEARTBEAT_TIMEOUT {
+                        bail!("no frame received from client for {:?}", last_seen.elapsed())
+                    }
+                    vec![attach_protocol::encode_frame(FrameType::Heartbeat, &[])]
+                }
+            };
+
+            for frame in frames {
+                let write = write_half.write_all(&frame);
+                let res = if heartbeat {
+                    time::timeout(Self::HEARTBEAT_TIMEOUT, write)
+                        .await
+                        .context("client stopped reading frames")?
+                } else {
+                    write.await
+                };
+                match res {
+                    Ok(_) => trace!("Wrote frame of {} bytes to client", frame.len()),
+                    Err(ref e) if e.kind() == ErrorKind::BrokenPipe => return Ok(()),
+                    Err(e) => bail!("unable to write frame: {:#}", e),
+                }
+            }
+        }
+    }
+
+    /// Translate the output of `pipe` using the stdout and stderr transcoders, if available.
+    fn transcode_output(
+        transcoders: &mut Option<(Transcoder, Transcoder)>,
+        pipe: Pipe,
+        buf: Vec<u8>,
+    ) -> Vec<u8> {
+        match (transcoders, pipe) {
+            (Some((stdout, _)), Pipe::StdOut) => stdout.transcode(&buf),
+            (Some((_, stderr)), Pipe::StdErr) => stderr.transcode(&buf),
+            (None, _) => buf,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Passthrough handles a one-shot raw stream socket of a container, without any packet framing.
+struct Passthrough;
+
+impl Passthrough {
+    /// The maximum time to wait for the client to connect.
+    const ACCEPT_TIMEOUT: Duration = Duration::from_secs(60);
+
+    /// The maximum time to wait for the client to send its token.
+    const TOKEN_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Create a new passthrough socket and serve the first connection in the background. The
+    /// socket gets removed if no client connected until the `cancel` token got cancelled or the
+    /// accept timeout elapsed.
+    fn create(
+        path: &Path,
+        token: &str,
+        read_half_tx: Sender<Vec<u8>>,
+        write_half_tx: Sender<(Pipe, Vec<u8>)>,
+        cancel: CancellationToken,
+    ) -> Result<()> {
+        debug!("Creating passthrough socket: {}", path.display());
+
+        if path.exists() {
+            bail!("Passthrough socket path already exists: {}", path.display())
+        }
+
+        let listener = listener::bind_long_path(path)?;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o700))
+            .context("set passthrough socket permissions")?;
+
+        let path = path.to_path_buf();
+        let token = token.to_string();
+        task::spawn(
+            async move {
+                if let Err(e) =
+                    Self::start(listener, &path, &token, read_half_tx, write_half_tx, cancel).await
+                {
+                    error!("Passthrough failure: {:#}", e);
+                }
+            }
+            .instrument(debug_span!("passthrough")),
+        );
+
+        Ok(())
+    }
+
+    async fn start(
+        listener: UnixListener,
+        path: &Path,
+        token: &str,
+        read_half_tx: Sender<Vec<u8>>,
+        write_half_tx: Sender<(Pipe, Vec<u8>)>,
+        cancel: CancellationToken,
+    ) -> Result<()> {
+        let accepted = tokio::select! {
+            _ = cancel.cancelled() => None,
+            accepted = time::timeout(Self::ACCEPT_TIMEOUT, listener.accept()) => Some(accepted),
+        };
+
+        // The socket is one-shot, so it should not be reachable any more
+        drop(listener);
+        if let Err(e) = fs::remove_file(path) {
+            debug!("Unable to remove passthrough socket: {}", e);
+        }
+
+        let (mut stream, _) = match accepted {
+            Some(accepted) => accepted
+                .context("wait for passthrough connection")?
+                .context("accept passthrough connection")?,
+            None => {
+                debug!("Stopping passthrough before a client connected");
+                return Ok(());
+            }
+        };
+        debug!("Got new passthrough stream connection");
+
+        let mut client_token = vec![0; token.len()];
+        time::timeout(Self::TOKEN_TIMEOUT, stream.read_exact(&mut client_token))
+            .await
+            .context("wait for passthrough token")?
+            .context("read passthrough token")?;
+        // Compared in constant time, so that the token cannot be guessed by timing
+        if !bool::from(client_token.ct_eq(token.as_bytes())) {
+            bail!("invalid passthrough token")
+        }
+
+        let (mut read_half, mut write_half) = stream.into_split();
+        let mut write_half_rx = write_half_tx.subscribe();
+
+        task::spawn(
+            async move {
+                let mut buf = vec![0; Attach::PACKET_BUF_SIZE];
+                loop {
+                    match read_half.read(&mut buf).await {
+                        Ok(0) => {
+                            debug!("Stopping passthrough read loop");
+                            break;
+                        }
+                        Ok(n) => {
+                            if read_half_tx.send(buf[..n].to_vec()).is_err() {
+                                break;
+                            }
+                        }
+                        Err(e) => {
+                            error!("Unable to read from passthrough client: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+            .instrument(debug_span!("read_loop")),
+        );
+
+        loop {
+            let (_, buf) = write_half_rx.recv().await?;
+            if let Err(e) = write_half.write_all(&buf).await {
+                debug!("Stopping passthrough write loop: {}", e);
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Mirror handles a short-lived raw stream socket of a container, which receives a copy of its
+/// output without any packet framing.
+struct Mirror;
+
+impl Mirror {
+    /// The maximum time a mirror socket gets served.
+    const MAX_DURATION: Duration = Duration::from_secs(60 * 60);
+
+    /// Create a new mirror socket and serve it in the background until `duration` elapsed,
+    /// which is capped by `MAX_DURATION`.
+    fn create(
+        path: &Path,
+        pipe: Option<Pipe>,
+        duration: Duration,
+        write_half_tx: Sender<(Pipe, Vec<u8>)>,
+        token: CancellationToken,
+    ) -> Result<()> {
+        debug!("Creating mirror socket: {}", path.display());
+
+        if path.exists() {
+            bail!("Mirror socket path already exists: {}", path.display())
+        }
+
+        let listener = listener::bind_long_path(path)?;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o700))
+            .context("set mirror socket permissions")?;
+
+        let path = path.to_path_buf();
+        let duration = duration.min(Self::MAX_DURATION);
+        task::spawn(
+            Self::start(listener, path, pipe, duration, write_half_tx, token)
+                .instrument(debug_span!("mirror")),
+        );
+
+        Ok(())
+    }
+
+    async fn start(
+        listener: UnixListener,
+        path: PathBuf,
+        pipe: Option<Pipe>,
+        duration: Duration,
+        write_half_tx: Sender<(Pipe, Vec<u8>)>,
+        token: CancellationToken,
+    ) {
+        // Stops the clients on teardown, without affecting other endpoints.
+        let stop = token.child_token();
+        let deadline = time::sleep(duration);
+        tokio::pin!(deadline);
+
+        loop {
+            tokio::select! {
+                res = listener.accept() => match res {
+                    Ok((stream, _)) => {
+                        debug!("Got new mirror stream connection");
+                        task::spawn(
+                            Self::serve(stream, pipe, write_half_tx.subscribe(), stop.clone())
+                                .instrument(debug_span!("serve")),
+                        );
+                    }
+                    Err(e) => error!("Unable to accept mirror stream: {}", e),
+                },
+                _ = &mut deadline => {
+                    debug!("Mirror duration of {:?} elapsed", duration);
+                    break;
+                }
+                _ = token.cancelled() => break,
+            }
+        }
+
+        stop.cancel();
+        drop(listener);
+        match fs::remove_file(&path) {
+            Err(e) if e.kind() != ErrorKind::NotFound => {
+                debug!("Unable to remove mirror socket {}: {}", path.display(), e)
+            }
+            _ => {}
+        }
+    }
+
+    async fn serve(
+        mut stream: UnixStream,
+        pipe: Option<Pipe>,
+        mut rx: Receiver<(Pipe, Vec<u8>)>,
+        token: CancellationToken,
+    ) {
+        loop {
+            let buf = tokio::select! {
+                res = rx.recv() => match res {
+                    Ok((p, buf)) if pipe.map_or(true, |pipe| pipe == p) => buf,
+                    Ok(_) => continue,
+                    Err(RecvError::Lagged(n)) => {
+                        debug!("Mirror client skipped {} output messages", n);
+                        continue;
+                    }
+                    Err(RecvError::Closed) => return,
+                },
+                _ = token.cancelled() => return,
+            };
+            if let Err(e) = stream.write_all(&buf).await {
+                debug!("Stopping mirror write loop: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn mirror() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("mirror");
+        let mut sut = SharedContainerAttach::default();
+        sut.add_mirror(&path, Some(Pipe::StdOut), Duration::from_millis(500))?;
+
+        let mut client = UnixStream::connect(&path).await?;
+        // The subscription happens after accepting the connection.
+        while sut.write_half_tx.receiver_count() == 0 {
+            task::yield_now().await;
+        }
+        sut.write(Pipe::StdErr, "skipped").await?;
+        sut.write(Pipe::StdOut, "hello").await?;
+
+        let mut buf = [0; 5];
+        client.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"hello");
+
+        // The connection gets closed and the socket removed after the duration
+        let mut rest = vec![];
+        client.read_to_end(&mut rest).await?;
+        assert!(rest.is_empty());
+        time::sleep(Duration::from_millis(50)).await;
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn passthrough() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("passthrough");
+        let mut sut = SharedContainerAttach::default();
+        sut.add_passthrough(&path, "token").await?;
+
+        let mut client = UnixStream::connect(&path).await?;
+        client.write_all(b"token").await?;
+        while sut.write_half_tx.receiver_count() == 0 {
+            task::yield_now().await;
+        }
+        assert!(!path.exists());
+        sut.write(Pipe::StdOut, "hello").await?;
+
+        let mut buf = [0; 5];
+        client.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"hello");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn passthrough_cancelled() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("passthrough");
+        let mut sut = SharedContainerAttach::default();
+        sut.add_passthrough(&path, "token").await?;
+        sut.token.cancel();
+
+        // The listener gets dropped without accepting a connection
+        time::sleep(Duration::from_millis(50)).await;
+        assert!(!path.exists());
+        Ok(())
+    }
 }
@@ -0,0 +1,274 @@
+//! Versioned framing of the attach socket protocol.
+//!
+//! Version 0 is the legacy format, where every packet has a fixed size and output packets are
+//! prefixed by the pipe, whereas input packets contain raw data which must not contain zero
+//! bytes.
+//!
+//! Starting with version 1, the server sends a header right after accepting the connection:
+//!
+//! ```text
+//! | magic (4 bytes) | version (u8) | channels (u8) | flags (u16, big endian) |
+//! ```
+//!
+//! The client answers with a header of the same layout, selecting a version not greater than
+//! the one of the server as well as a subset of the offered channels and flags. Afterwards
+//! every packet is a frame consisting of a single type byte followed by the payload. Resize
+//! frames carry the terminal width and height as big endian u16 values.
+//!
+//! If the heartbeat flag got negotiated, the server sends empty heartbeat frames periodically
+//! and expects the client to send any frame, for example a heartbeat, within a timeout.
+//! Otherwise the connection is considered dead and gets closed.
+
+use anyhow::{bail, Context, Result};
+use std::convert::TryFrom;
+
+/// The latest attach protocol version supported by the server.
+pub const VERSION: u8 = 1;
+
+/// The magic bytes every header starts with.
+const MAGIC: &[u8; 4] = b"CRAT";
+
+/// Channel bit for the standard input of the container.
+pub const CHANNEL_STDIN: u8 = 1;
+
+/// Channel bit for the standard output of the container.
+pub const CHANNEL_STDOUT: u8 = 1 << 1;
+
+/// Channel bit for the standard error of the container.
+pub const CHANNEL_STDERR: u8 = 1 << 2;
+
+/// Channel bit for terminal resize requests, only available for containers with a terminal.
+pub const CHANNEL_RESIZE: u8 = 1 << 3;
+
+/// Flag bit for periodic heartbeat frames in both directions.
+pub const FLAG_HEARTBEAT: u16 = 1;
+
+/// Select the protocol version to be served for a client supporting up to `client_version`.
+/// Clients which do not request a version get the legacy format.
+pub fn version(client_version: u32) -> u8 {
+    u8::try_from(client_version.min(VERSION.into())).unwrap_or(VERSION)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// The header exchanged on connection setup of versioned attach sockets.
+pub struct Header {
+    /// Version of the protocol.
+    pub version: u8,
+
+    /// Bitmask of the data channels.
+    pub channels: u8,
+
+    /// Bitmask of optional protocol flags.
+    pub flags: u16,
+}
+
+impl Header {
+    /// The length of an encoded header.
+    pub const LEN: usize = 8;
+
+    /// The header offered by the server for the provided version.
+    pub fn server(version: u8, terminal: bool) -> Self {
+        let mut channels = CHANNEL_STDIN | CHANNEL_STDOUT | CHANNEL_STDERR;
+        if terminal {
+            channels |= CHANNEL_RESIZE;
+        }
+        Self {
+            version,
+            channels,
+            flags: FLAG_HEARTBEAT,
+        }
+    }
+
+    /// Encode the header into its binary representation.
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        let mut buf = [0; Self::LEN];
+        buf[..4].copy_from_slice(MAGIC);
+        buf[4] = self.version;
+        buf[5] = self.channels;
+        buf[6..].copy_from_slice(&self.flags.to_be_bytes());
+        buf
+    }
+
+    /// Decode a header from its binary representation.
+    pub fn decode(buf: &[u8]) -> Result<Self> {
+        if buf.len() < Self::LEN {
+            bail!("attach header too short: {} bytes", buf.len())
+        }
+        if buf[..4] != MAGIC[..] {
+            bail!("invalid attach header magic")
+        }
+        Ok(Self {
+            version: buf[4],
+            channels: buf[5],
+            flags: u16::from_be_bytes([buf[6], buf[7]]),
+        })
+    }
+
+    /// Combine the offered server header with the header selected by the client.
+    pub fn negotiate(&self, client: &Self) -> Result<Self> {
+        if client.version == 0 || client.version > self.version {
+            bail!(
+                "unsupported attach protocol version {}, server supports up to {}",
+                client.version,
+                self.version
+            )
+        }
+        Ok(Self {
+            version: client.version,
+            channels: self.channels & client.channels,
+            flags: self.flags & client.flags,
+        })
+    }
+
+    /// Returns true if the provided channel got selected.
+    pub fn has_channel(&self, channel: u8) -> bool {
+        self.channels & channel != 0
+    }
+
+    /// Returns true if the provided flag got selected.
+    pub fn has_flag(&self, flag: u16) -> bool {
+        self.flags & flag != 0
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Available frame types of versioned attach sockets.
+pub enum FrameType {
+    /// Data for the standard input of the container.
+    Stdin = 1,
+
+    /// Data from the standard output of the container.
+    Stdout = 2,
+
+    /// Data from the standard error of the container.
+    Stderr = 3,
+
+    /// Request to resize the terminal of the container.
+    Resize = 4,
+
+    /// Liveness signal without payload.
+    Heartbeat = 5,
+}
+
+impl TryFrom<u8> for FrameType {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        Ok(match value {
+            1 => Self::Stdin,
+            2 => Self::Stdout,
+            3 => Self::Stderr,
+            4 => Self::Resize,
+            5 => Self::Heartbeat,
+            _ => bail!("unknown attach frame type {}", value),
+        })
+    }
+}
+
+/// Encode a frame of the provided type and payload.
+pub fn encode_frame(typ: FrameType, payload: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(payload.len() + 1);
+    frame.push(typ as u8);
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// Decode a frame into its type and payload.
+pub fn decode_frame(frame: &[u8]) -> Result<(FrameType, &[u8])> {
+    let (typ, payload) = frame.split_first().context("empty attach frame")?;
+    Ok((FrameType::try_from(*typ)?, payload))
+}
+
+/// Decode the payload of a resize frame into the terminal width and height.
+pub fn decode_resize(payload: &[u8]) -> Result<(u16, u16)> {
+    if payload.len() != 4 {
+        bail!("invalid resize frame length {}", payload.len())
+    }
+    Ok((
+        u16::from_be_bytes([payload[0], payload[1]]),
+        u16::from_be_bytes([payload[2], payload[3]]),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_selection() {
+        assert_eq!(version(0), 0);
+        assert_eq!(version(1), 1);
+        assert_eq!(version(u32::MAX), VERSION);
+    }
+
+    #[test]
+    fn header_roundtrip() -> Result<()> {
+        let header = Header {
+            version: 1,
+            channels: CHANNEL_STDOUT,
+            flags: 0x0102,
+        };
+        assert_eq!(Header::decode(&header.encode())?, header);
+        Ok(())
+    }
+
+    #[test]
+    fn header_decode_failure() {
+        assert!(Header::decode(b"CRAT").is_err());
+        assert!(Header::decode(b"XXXX\x01\x00\x00\x00").is_err());
+    }
+
+    #[test]
+    fn header_negotiate() -> Result<()> {
+        let server = Header::server(VERSION, false);
+        assert!(!server.has_channel(CHANNEL_RESIZE));
+        assert!(Header::server(VERSION, true).has_channel(CHANNEL_RESIZE));
+
+        let client = Header {
+            version: 1,
+            channels: CHANNEL_STDOUT | 1 << 7,
+            flags: FLAG_HEARTBEAT | 1 << 15,
+        };
+
+        let res = server.negotiate(&client)?;
+        assert_eq!(res.version, 1);
+        assert!(res.has_channel(CHANNEL_STDOUT));
+        assert!(!res.has_channel(CHANNEL_STDIN));
+        assert_eq!(res.channels, CHANNEL_STDOUT);
+        assert!(res.has_flag(FLAG_HEARTBEAT));
+        assert_eq!(res.flags, FLAG_HEARTBEAT);
+
+        assert!(server
+            .negotiate(&Header {
+                version: 0,
+                ..client
+            })
+            .is_err());
+        assert!(server
+            .negotiate(&Header {
+                version: VERSION + 1,
+                ..client
+            })
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn frame_roundtrip() -> Result<()> {
+        let frame = encode_frame(FrameType::Stderr, b"a\0b");
+        assert_eq!(decode_frame(&frame)?, (FrameType::Stderr, &b"a\0b"[..]));
+        assert!(decode_frame(&[]).is_err());
+        assert!(decode_frame(&[42]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn resize_frame() -> Result<()> {
+        let frame = [FrameType::Resize as u8, 0, 80, 0, 24];
+        let (typ, payload) = decode_frame(&frame)?;
+        assert_eq!(typ, FrameType::Resize);
+        assert_eq!(decode_resize(payload)?, (80, 24));
+        assert!(decode_resize(&[0, 80]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+//! Introspection of the OCI runtime configuration (`config.json`) of a container bundle.
+
+use anyhow::{Context, Result};
+use getset::Getters;
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Clone, Debug, Default, Deserialize, Getters, PartialEq)]
+#[serde(default)]
+/// The parts of the OCI runtime configuration conmon-rs is interested in. All other fields are
+/// ignored.
+pub struct BundleConfig {
+    /// The container process (`process`).
+    process: Process,
+
+    #[getset(get = "pub")]
+    /// Arbitrary metadata of the container (`annotations`).
+    annotations: HashMap<String, String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+/// The interpreted fields of the container process.
+struct Process {
+    /// Whether the container process gets a terminal attached.
+    terminal: bool,
+
+    /// Working directory of the container process.
+    cwd: PathBuf,
+}
+
+impl BundleConfig {
+    /// Read and parse the `config.json` of the bundle at `bundle_path`.
+    pub fn from_bundle(bundle_path: &Path) -> Result<Self> {
+        let path = bundle_path.join("config.json");
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("read bundle config '{}'", path.display()))?;
+        Self::parse(&content).with_context(|| format!("parse bundle config '{}'", path.display()))
+    }
+
+    /// Parse the OCI runtime configuration from its JSON representation.
+    pub fn parse(content: &str) -> Result<Self> {
+        serde_json::from_str(content).context("deserialize JSON")
+    }
+
+    /// Whether the container process gets a terminal attached (`process.terminal`).
+    pub fn terminal(&self) -> bool {
+        self.process.terminal
+    }
+
+    /// Working directory of the container process (`process.cwd`).
+    pub fn cwd(&self) -> &Path {
+        &self.process.cwd
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const CONFIG: &str = r#"{
+        "ociVersion": "1.0.2",
+        "process": {
+            "terminal": true,
+            "user": {"uid": 0, "gid": 0},
+            "args": ["sh", "-c", "echo \"hi\" ä😀"],
+            "env": [],
+            "cwd": "/work",
+            "rlimits": [{"type": "RLIMIT_NOFILE", "hard": 1024, "soft": 1.5e3}],
+            "noNewPrivileges": false
+        },
+        "root": {"path": "rootfs", "readonly": null},
+        "annotations": {
+            "io.conmon-rs/log-max-size": "1024",
+            "other": "value"
+        }
+    }"#;
+
+    #[test]
+    fn parse_config() -> Result<()> {
+        let sut = BundleConfig::parse(CONFIG)?;
+        assert!(sut.terminal());
+        assert_eq!(sut.cwd(), Path::new("/work"));
+        assert_eq!(sut.annotations().len(), 2);
+        assert_eq!(
+            sut.annotations().get("io.conmon-rs/log-max-size"),
+            Some(&"1024".to_string())
+        );
+
+        let sut = BundleConfig::parse("{}")?;
+        assert_eq!(sut, BundleConfig::default());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid_config() {
+        let nested = "[".repeat(200);
+        for config in [
+            "",
+            "1",
+            "{",
+            "{} {}",
+            r#"{"process": "sh"}"#,
+            r#"{"process": {"terminal": "yes"}}"#,
+            r#"{"annotations": {"key": 1}}"#,
+            r#"{"key": "\x"}"#,
+            r#"{"key": 01}"#,
+            r#"{"key": [1 2]}"#,
+            &nested,
+        ] {
+            assert!(BundleConfig::parse(config).is_err(), "{}", config);
+        }
+    }
+
+    #[test]
+    fn from_bundle() -> Result<()> {
+        let dir = tempdir()?;
+        assert!(BundleConfig::from_bundle(dir.path()).is_err());
+
+        fs::write(dir.path().join("config.json"), CONFIG)?;
+        assert!(BundleConfig::from_bundle(dir.path())?.terminal());
+        Ok(())
+    }
+}
@@ -0,0 +1,245 @@
+//! Resource usage statistics of container cgroups for cgroup v1 and v2.
+
+use crate::oom_watcher::OOMWatcher;
+use anyhow::{Context, Result};
+use getset::CopyGetters;
+use nix::unistd::{sysconf, SysconfVar};
+use std::{io::ErrorKind, path::Path};
+use tokio::fs;
+
+#[derive(Clone, Copy, CopyGetters, Debug, Default, Eq, PartialEq)]
+#[getset(get_copy = "pub")]
+/// Resource usage of a container cgroup.
+pub struct CgroupStats {
+    /// Total CPU time in nanoseconds.
+    cpu_usage: u64,
+
+    /// CPU time spent in user mode in nanoseconds.
+    cpu_user: u64,
+
+    /// CPU time spent in kernel mode in nanoseconds.
+    cpu_system: u64,
+
+    /// Current memory usage in bytes.
+    memory_current: u64,
+
+    /// Highest recorded memory usage in bytes, zero if not supported by the kernel.
+    memory_peak: u64,
+
+    /// Current number of processes.
+    pids_current: u64,
+
+    /// Bytes read from block devices.
+    io_read_bytes: u64,
+
+    /// Bytes written to block devices.
+    io_write_bytes: u64,
+}
+
+impl CgroupStats {
+    /// Read the statistics of the cgroup of the process `pid`.
+    pub async fn for_pid(pid: u32) -> Result<Self> {
+        if OOMWatcher::is_cgroup_v2() {
+            let cgroup = OOMWatcher::process_cgroup_subsystem_path_cgroup_v2(pid)
+                .await
+                .context("get cgroup path")?;
+            Self::read_v2(&cgroup).await
+        } else {
+            Self::read_v1(pid).await
+        }
+    }
+
+    async fn read_v2(cgroup: &Path) -> Result<Self> {
+        let cpu_stat = read(&cgroup.join("cpu.stat")).await?;
+        let cpu = |key| flat_keyed(&cpu_stat, key).unwrap_or_default() * 1000;
+        let (io_read_bytes, io_write_bytes) = match read_optional(&cgroup.join("io.stat")).await? {
+            Some(io_stat) => io_stat_v2(&io_stat),
+            None => (0, 0),
+        };
+        Ok(Self {
+            cpu_usage: cpu("usage_usec"),
+            cpu_user: cpu("user_usec"),
+            cpu_system: cpu("system_usec"),
+            memory_current: read_u64(&cgroup.join("memory.current")).await?,
+            memory_peak: read_optional_u64(&cgroup.join("memory.peak")).await?,
+            pids_current: read_optional_u64(&cgroup.join("pids.current")).await?,
+            io_read_bytes,
+            io_write_bytes,
+        })
+    }
+
+    async fn read_v1(pid: u32) -> Result<Self> {
+        let path = |subsystem: &'static str| async move {
+            OOMWatcher::process_cgroup_subsystem_path(pid, false, subsystem)
+                .await
+                .with_context(|| format!("get {} cgroup path", subsystem))
+        };
+        let cpuacct = path("cpuacct").await?;
+        let memory = path("memory").await?;
+
+        // The user and system times are reported in clock ticks.
+        let clock_ticks = sysconf(SysconfVar::CLK_TCK)
+            .context("get clock ticks")?
+            .context("clock ticks not available")? as u64;
+        let cpuacct_stat = read(&cpuacct.join("cpuacct.stat")).await?;
+        let cpu = |key| {
+            flat_keyed(&cpuacct_stat, key).unwrap_or_default() * 1_000_000_000 / clock_ticks.max(1)
+        };
+
+        let pids_current = match path("pids").await {
+            Ok(pids) => read_optional_u64(&pids.join("pids.current")).await?,
+            Err(_) => 0,
+        };
+        let (io_read_bytes, io_write_bytes) = match path("blkio").await {
+            Ok(blkio) => {
+                match read_optional(&blkio.join("blkio.throttle.io_service_bytes")).await? {
+                    Some(content) => blkio_v1(&content),
+                    None => (0, 0),
+                }
+            }
+            Err(_) => (0, 0),
+        };
+
+        Ok(Self {
+            cpu_usage: read_u64(&cpuacct.join("cpuacct.usage")).await?,
+            cpu_user: cpu("user"),
+            cpu_system: cpu("system"),
+            memory_current: read_u64(&memory.join("memory.usage_in_bytes")).await?,
+            memory_peak: read_optional_u64(&memory.join("memory.max_usage_in_bytes")).await?,
+            pids_current,
+            io_read_bytes,
+            io_write_bytes,
+        })
+    }
+}
+
+async fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .await
+        .with_context(|| format!("read {}", path.display()))
+}
+
+/// Read the file, or `None` if the controller or kernel does not provide it.
+async fn read_optional(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path).await {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("read {}", path.display())),
+    }
+}
+
+async fn read_u64(path: &Path) -> Result<u64> {
+    parse_u64(&read(path).await?, path)
+}
+
+async fn read_optional_u64(path: &Path) -> Result<u64> {
+    match read_optional(path).await? {
+        Some(content) => parse_u64(&content, path),
+        None => Ok(0),
+    }
+}
+
+fn parse_u64(content: &str, path: &Path) -> Result<u64> {
+    content
+        .trim()
+        .parse()
+        .with_context(|| format!("parse {}", path.display()))
+}
+
+/// The value of `key` in a flat keyed file of `<key> <value>` lines.
+fn flat_keyed(content: &str, key: &str) -> Option<u64> {
+    content.lines().find_map(|line| {
+        let (k, v) = line.split_once(' ')?;
+        if k == key {
+            v.trim().parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+/// Sum of the read and written bytes of all devices in a cgroup v2 `io.stat` file.
+fn io_stat_v2(content: &str) -> (u64, u64) {
+    let (mut read, mut write) = (0, 0);
+    for field in content
+        .lines()
+        .flat_map(|line| line.split_whitespace().skip(1))
+    {
+        match field.split_once('=') {
+            Some(("rbytes", value)) => read += value.parse::<u64>().unwrap_or(0),
+            Some(("wbytes", value)) => write += value.parse::<u64>().unwrap_or(0),
+            _ => {}
+        }
+    }
+    (read, write)
+}
+
+/// Sum of the read and written bytes of all devices in a cgroup v1
+/// `blkio.throttle.io_service_bytes` file.
+fn blkio_v1(content: &str) -> (u64, u64) {
+    let (mut read, mut write) = (0, 0);
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let (op, value) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(_), Some(op), Some(value)) => (op, value.parse::<u64>().unwrap_or(0)),
+            // The last line contains the total of all devices.
+            _ => continue,
+        };
+        match op {
+            "Read" => read += value,
+            "Write" => write += value,
+            _ => {}
+        }
+    }
+    (read, write)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn parse_files() {
+        let cpu_stat = "usage_usec 1500\nuser_usec 1000\nsystem_usec 500\nnr_periods 0\n";
+        assert_eq!(flat_keyed(cpu_stat, "user_usec"), Some(1000));
+        assert_eq!(flat_keyed(cpu_stat, "usage"), None);
+
+        assert_eq!(
+            io_stat_v2(
+                "8:0 rbytes=100 wbytes=20 rios=1 wios=2 dbytes=0 dios=0\n\
+                 8:16 rbytes=5 wbytes=1 rios=1 wios=1 dbytes=0 dios=0\n"
+            ),
+            (105, 21)
+        );
+        assert_eq!(
+            blkio_v1(
+                "8:0 Read 100\n8:0 Write 20\n8:0 Sync 120\n8:0 Total 120\n\
+                 8:16 Read 5\n8:16 Write 1\nTotal 126\n"
+            ),
+            (105, 21)
+        );
+    }
+
+    #[tokio::test]
+    async fn read_v2() -> Result<()> {
+        let dir = tempdir()?;
+        let write = |name: &str, content: &str| std::fs::write(dir.path().join(name), content);
+        write(
+            "cpu.stat",
+            "usage_usec 1500\nuser_usec 1000\nsystem_usec 500\n",
+        )?;
+        write("memory.current", "4096\n")?;
+        write("pids.current", "3\n")?;
+
+        let sut = CgroupStats::read_v2(dir.path()).await?;
+        assert_eq!(sut.cpu_usage(), 1_500_000);
+        assert_eq!(sut.cpu_user(), 1_000_000);
+        assert_eq!(sut.cpu_system(), 500_000);
+        assert_eq!(sut.memory_current(), 4096);
+        assert_eq!(sut.memory_peak(), 0);
+        assert_eq!(sut.pids_current(), 3);
+        assert_eq!(sut.io_read_bytes(), 0);
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::container_io::SharedContainerIO;
+use crate::{container_io::SharedContainerIO, overrides::Overrides};
 use getset::{CopyGetters, Getters};
 use std::path::PathBuf;
 use tokio::time::Instant;
@@ -25,9 +25,22 @@ pub struct Child {
 
     #[getset(get = "pub")]
     cleanup_cmd: Vec<String>,
+
+    #[getset(get = "pub")]
+    name: Option<String>,
+
+    #[getset(get = "pub")]
+    success_exit_codes: Vec<i32>,
+
+    #[getset(get = "pub")]
+    cleanup_paths: Vec<PathBuf>,
+
+    #[getset(get_copy = "pub")]
+    overrides: Overrides,
 }
 
 impl Child {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: String,
         pid: u32,
@@ -36,6 +49,10 @@ impl Child {
         timeout: Option<Instant>,
         io: SharedContainerIO,
         cleanup_cmd: Vec<String>,
+        name: Option<String>,
+        success_exit_codes: Vec<i32>,
+        cleanup_paths: Vec<PathBuf>,
+        overrides: Overrides,
     ) -> Self {
         Self {
             id,
@@ -45,6 +62,10 @@ impl Child {
             timeout,
             io,
             cleanup_cmd,
+            name,
+            success_exit_codes,
+            cleanup_paths,
+            overrides,
         }
     }
 }
@@ -1,34 +1,55 @@
 //! Child process reaping and management.
 use crate::{
     child::Child,
+    clock::BootInstant,
+    config::{ReaperStrategy, Timeouts},
     container_io::{ContainerIO, ContainerIOType, SharedContainerIO},
+    crash,
+    events::{EventBus, EventKind},
+    exec_sessions::ExecSessions,
+    file_watcher,
+    helper::Helper,
+    idle_audit::IdleAudit,
+    oom_kills,
     oom_watcher::OOMWatcher,
+    overrides::Overrides,
+    pressure::PressureMonitor,
+    rpc_error::Phase,
+    runtime_log,
+    sharded_map::ShardedMultiMap,
+    sigchld::{Reaped, SigchldWaiter, FAILED_EXIT_CODE},
 };
 use anyhow::{bail, format_err, Context, Result};
 use getset::{CopyGetters, Getters, Setters};
-use libc::pid_t;
-use multimap::MultiMap;
+use libc::{pid_t, siginfo_t, P_PID, WEXITED, WNOWAIT};
 use nix::errno::Errno;
 use nix::{
     sys::{
         signal::{kill, Signal},
         wait::{waitpid, WaitStatus},
     },
-    unistd::{getpgid, Pid},
+    unistd::{getpgid, getpgrp, Pid},
 };
 use std::{
+    collections::HashMap,
+    convert::TryFrom,
     ffi::OsStr,
     fmt::Write,
+    mem,
     path::{Path, PathBuf},
-    process::Stdio,
+    process::{Output, Stdio},
     str,
     sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use tokio::{
     fs::{self, File},
-    io::AsyncWriteExt,
+    io::{AsyncWriteExt, ErrorKind},
     process::Command,
-    sync::broadcast::{self, Receiver, Sender},
+    sync::{
+        broadcast::{self, Receiver, Sender},
+        Notify,
+    },
     task::{self, JoinHandle},
     time::{self, Instant},
 };
@@ -38,31 +59,123 @@ use tracing::{debug, debug_span, error, warn, Instrument};
 #[derive(Debug, Default, Getters)]
 pub struct ChildReaper {
     #[getset(get)]
-    grandchildren: Arc<Mutex<MultiMap<String, ReapableChild>>>,
-}
+    grandchildren: Arc<ShardedMultiMap<ReapableChild>>,
 
-macro_rules! lock {
-    ($x:expr) => {
-        $x.lock().map_err(|e| format_err!("{:#}", e))?
-    };
+    #[getset(get)]
+    aliases: Arc<Mutex<HashMap<String, String>>>,
+
+    #[getset(get = "pub")]
+    events: Arc<EventBus>,
+
+    #[getset(get = "pub")]
+    exec_sessions: Arc<ExecSessions>,
+
+    strategy: ReaperStrategy,
+
+    timeouts: Timeouts,
+
+    #[getset(get = "pub")]
+    sigchld_waiter: Arc<SigchldWaiter>,
+
+    #[getset(get = "pub")]
+    idle_audit: Arc<IdleAudit>,
+
+    /// Notified whenever a container exited, which may require an eviction.
+    exited: Arc<Notify>,
 }
 
 impl ChildReaper {
+    /// The maximum time to wait for the pidfile after the runtime exited successfully.
+    const PIDFILE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Create a new child reaper which keeps up to `event_history_size` lifecycle events and
+    /// detects exits by using the provided `strategy`. Wakeups while no children are active
+    /// get logged if `audit_idle_wakeups` is set. The `timeouts` apply to all children.
+    pub fn new(
+        event_history_size: usize,
+        strategy: ReaperStrategy,
+        audit_idle_wakeups: bool,
+        timeouts: Timeouts,
+    ) -> Self {
+        let idle_audit = Arc::new(IdleAudit::new(audit_idle_wakeups));
+        Self {
+            events: Arc::new(EventBus::new(event_history_size)),
+            strategy,
+            timeouts,
+            sigchld_waiter: Arc::new(SigchldWaiter::new(idle_audit.clone())),
+            idle_audit,
+            ..Default::default()
+        }
+    }
+
     pub fn get(&self, id: &str) -> Result<ReapableChild> {
-        let locked_grandchildren = &self.grandchildren().clone();
-        let lock = lock!(locked_grandchildren);
-        let r = lock.get(id).context("child not available")?.clone();
-        drop(lock);
-        Ok(r)
+        let id = self.resolve_id(id)?;
+        self.grandchildren()
+            .get(&id)?
+            .context("child not available")
     }
 
+    /// Resolve the provided container ID or name alias into the container ID.
+    /// IDs take precedence over aliases and unknown values are returned unchanged.
+    pub fn resolve_id(&self, id_or_name: &str) -> Result<String> {
+        if self.grandchildren().contains_key(id_or_name)? {
+            return Ok(id_or_name.into());
+        }
+        Ok(lock!(self.aliases)
+            .get(id_or_name)
+            .cloned()
+            .unwrap_or_else(|| id_or_name.into()))
+    }
+
+    /// Returns all tracked containers, optionally filtered by the provided IDs or name aliases.
+    pub fn list(&self, filter: &[&str]) -> Result<Vec<(String, ReapableChild)>> {
+        let ids = filter
+            .iter()
+            .map(|x| self.resolve_id(x))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self
+            .grandchildren()
+            .entries()?
+            .into_iter()
+            .filter(|(id, _)| ids.is_empty() || ids.contains(id))
+            .collect())
+    }
+
+    /// Verify that the provided name alias is neither used as alias nor as ID.
+    pub fn check_alias(&self, name: &str) -> Result<()> {
+        let in_use = self.grandchildren().contains_key(name)?;
+        if in_use || lock!(self.aliases).contains_key(name) {
+            bail!("container name '{}' is already in use", name)
+        }
+        Ok(())
+    }
+
+    /// Reserve the provided name alias for the container `id` while it gets created, which
+    /// fails like `check_alias` if the name is in use. The reservation gets released once the
+    /// returned guard is dropped, unless the guard got kept after registering the container.
+    pub fn reserve_alias(&self, name: &str, id: &str) -> Result<AliasReservation> {
+        let mut aliases = lock!(self.aliases);
+        if aliases.contains_key(name) || self.grandchildren().contains_key(name)? {
+            bail!("container name '{}' is already in use", name)
+        }
+        aliases.insert(name.into(), id.into());
+        Ok(AliasReservation {
+            aliases: self.aliases.clone(),
+            name: name.into(),
+            id: id.into(),
+            kept: false,
+        })
+    }
+
+    /// Spawn the runtime with the provided arguments and return the PID of the created
+    /// grandchild, together with the time spent in the phases of its creation.
     pub async fn create_child<P, I, S>(
         &self,
         cmd: P,
         args: I,
         container_io: &mut ContainerIO,
         pidfile: &Path,
-    ) -> Result<u32>
+    ) -> Result<(u32, CreateTimings)>
     where
         P: AsRef<OsStr>,
         I: IntoIterator<Item = S>,
@@ -70,19 +183,54 @@ impl ChildReaper {
     {
         let mut cmd = Command::new(cmd);
         cmd.args(args);
-        let mut child = cmd
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .context("spawn child process: {}")?;
+        let slave = match container_io.typ_mut() {
+            ContainerIOType::Terminal(terminal) => terminal.take_slave(),
+            ContainerIOType::Streams(_) => None,
+        };
+        match slave {
+            // The container inherits the pre-allocated terminal from the runtime. Our copies of
+            // the slave get closed together with the command, to notice the exit on the master.
+            Some(slave) => cmd
+                .stdin(slave.try_clone().context("clone terminal slave")?)
+                .stdout(slave.try_clone().context("clone terminal slave")?)
+                .stderr(slave),
+            None => cmd
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped()),
+        };
+        // A runtime hanging past the request timeout must not outlive the dropped request.
+        cmd.kill_on_drop(true);
+        let mut timings = CreateTimings::default();
+        let start = Instant::now();
+        let mut child = debug_span!("runtime_spawn")
+            .in_scope(|| cmd.spawn())
+            .context(Phase::RuntimeSpawn)?;
+        timings.runtime_spawn = start.elapsed();
 
         match container_io.typ_mut() {
             ContainerIOType::Terminal(ref mut terminal) => {
-                terminal
-                    .wait_connected()
-                    .await
-                    .context("wait for terminal socket connection")?;
+                // The runtime may fail before connecting to the console socket, so we stop
+                // waiting as soon as it exits.
+                let start = Instant::now();
+                let deadline = start + self.timeouts.create();
+                async {
+                    tokio::select! {
+                        res = terminal.wait_connected() => {
+                            res.context(Phase::TerminalWait)
+                        }
+                        _ = child.wait() => {
+                            debug!("Runtime exited before connecting to the console socket");
+                            Ok(())
+                        }
+                        _ = time::sleep_until(deadline) => {
+                            Err(format_err!("timed out")).context(Phase::TerminalWait)
+                        }
+                    }
+                }
+                .instrument(debug_span!("console_handshake"))
+                .await?;
+                timings.console_handshake = start.elapsed();
             }
             ContainerIOType::Streams(streams) => {
                 let stdout = child.stdout.take();
@@ -92,26 +240,20 @@ impl ChildReaper {
             }
         };
 
-        let output = child.wait_with_output().await?;
-
-        if !output.status.success() {
-            const BASE_ERR: &str = "child command exited with";
+        let start = Instant::now();
+        let output = child
+            .wait_with_output()
+            .instrument(debug_span!("runtime"))
+            .await?;
+        timings.runtime = start.elapsed();
+        check_output(&output)?;
 
-            let mut err_str = match output.status.code() {
-                Some(code) => format!("{}: {}", BASE_ERR, code),
-                None => format!("{} signal", BASE_ERR),
-            };
-
-            if !output.stderr.is_empty() {
-                write!(
-                    err_str,
-                    ": {}",
-                    str::from_utf8(&output.stderr).context("convert stderr to utf8")?,
-                )?;
-            }
-
-            bail!(err_str)
-        }
+        let start = Instant::now();
+        file_watcher::wait_for_path(pidfile, start + Self::PIDFILE_TIMEOUT)
+            .instrument(debug_span!("pidfile_wait"))
+            .await
+            .context(Phase::PidfileWait)?;
+        timings.pidfile_wait = start.elapsed();
 
         let grandchild_pid = fs::read_to_string(pidfile)
             .await
@@ -119,51 +261,276 @@ impl ChildReaper {
             .parse::<u32>()
             .context(format!("grandchild pid parse error {}", pidfile.display()))?;
 
-        Ok(grandchild_pid)
+        Ok((grandchild_pid, timings))
+    }
+
+    /// Run the runtime with the provided arguments to completion, for operations on already
+    /// created containers. Returns the stdout of the runtime.
+    pub async fn run_runtime<P, I, S>(&self, cmd: P, args: I) -> Result<Vec<u8>>
+    where
+        P: AsRef<OsStr>,
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let output = Command::new(cmd)
+            .args(args)
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output()
+            .instrument(debug_span!("runtime"))
+            .await
+            .context(Phase::RuntimeSpawn)?;
+        check_output(&output)?;
+        Ok(output.stdout)
+    }
+
+    /// Verify that the runtime deleted the removed container `id`. A container which is still
+    /// stopped according to the runtime leaks its runtime state, which blocks reusing its name,
+    /// and gets reported as event.
+    pub async fn verify_deleted(&self, runtime: &Path, global_args: &[String], id: &str, pid: u32) {
+        let mut args = global_args.to_vec();
+        args.push("state".into());
+        args.push(id.into());
+        let stdout = match self.run_runtime(runtime, &args).await {
+            Ok(stdout) => stdout,
+            Err(e) => {
+                debug!("Runtime state of {} not available any more: {:#}", id, e);
+                return;
+            }
+        };
+        match runtime_log::state_status(&String::from_utf8_lossy(&stdout)).as_deref() {
+            Some("stopped") => {
+                warn!("Runtime state of removed container {} got leaked", id);
+                self.events()
+                    .publish(EventKind::RuntimeStateLeaked, id, pid, 0, 0);
+            }
+            status => warn!(
+                "Removed container {} is still known to the runtime with status {:?}",
+                id, status
+            ),
+        }
     }
 
-    pub fn watch_grandchild(&self, child: Child) -> Result<Receiver<ExitChannelData>> {
-        let locked_grandchildren = &self.grandchildren().clone();
-        let mut map = lock!(locked_grandchildren);
+    /// Start watching the provided child. Exec processes should set `forget_on_exit`, whereas
+    /// containers are kept after their exit until they get removed.
+    pub fn watch_grandchild(
+        &self,
+        child: Child,
+        forget_on_exit: bool,
+    ) -> Result<Receiver<ExitChannelData>> {
         let mut reapable_grandchild = ReapableChild::from_child(&child);
 
-        let (exit_tx, exit_rx) = reapable_grandchild.watch()?;
+        let (exit_tx, exit_rx) = reapable_grandchild.watch(
+            child.id(),
+            self.strategy,
+            self.timeouts,
+            self.sigchld_waiter.clone(),
+            self.events().clone(),
+        )?;
 
-        map.insert(child.id().clone(), reapable_grandchild);
-        let cleanup_grandchildren = locked_grandchildren.clone();
+        self.grandchildren()
+            .insert(child.id().clone(), reapable_grandchild)?;
+        self.idle_audit().container_started();
+        if let Some(name) = child.name() {
+            lock!(self.aliases).insert(name.clone(), child.id().clone());
+        }
+        let cleanup_grandchildren = self.grandchildren().clone();
+        let idle_audit = self.idle_audit().clone();
+        let exited = self.exited.clone();
         let pid = child.pid();
 
         task::spawn(
             async move {
-                exit_tx.subscribe().recv().await?;
-                Self::forget_grandchild(&cleanup_grandchildren, pid)
+                let res = exit_tx.subscribe().recv().await;
+                idle_audit.container_stopped();
+                res?;
+                if forget_on_exit {
+                    Self::forget_grandchild(&cleanup_grandchildren, pid)?;
+                } else {
+                    exited.notify_one();
+                }
+                Ok::<_, anyhow::Error>(())
             }
             .instrument(debug_span!("watch_grandchild", pid)),
         );
         Ok(exit_rx)
     }
 
+    /// Publish the lifecycle events of a created container, including its exit.
+    pub fn publish_container_events(
+        &self,
+        id: String,
+        pid: u32,
+        mut exit_rx: Receiver<ExitChannelData>,
+    ) {
+        self.events().publish(EventKind::Created, &id, pid, 0, 0);
+        let events = self.events().clone();
+        let token = CancellationToken::new();
+        oom_kills::watch(token.clone(), id.clone(), pid, events.clone());
+        task::spawn(
+            async move {
+                let _guard = token.drop_guard();
+                match exit_rx.recv().await {
+                    Ok(exit_data) => {
+                        let (code, raw_code) = (*exit_data.exit_code(), *exit_data.raw_exit_code());
+                        if *exit_data.oomed() {
+                            events.publish(EventKind::Oom, &id, pid, code, raw_code);
+                        }
+                        events.publish(EventKind::Exited, &id, pid, code, raw_code);
+                    }
+                    Err(e) => error!("Unable to receive exit data: {:#}", e),
+                }
+            }
+            .instrument(debug_span!("publish_container_events", pid)),
+        );
+    }
+
+    /// Unregister an exited container including its exec processes and sessions. Returns the
+    /// removed container, or an error if any of its processes is still running.
+    pub fn remove(&self, id: &str) -> Result<ReapableChild> {
+        let id = self.resolve_id(id)?;
+        let (child, _) = self.take(&id)?;
+        self.exec_sessions().remove_container(&id)?;
+        debug!("Removed container {}", id);
+        Ok(child)
+    }
+
+    /// Unregister an exited container including its exec processes and name aliases, but keep
+    /// its exec sessions, so that it can be registered again by `reinsert`. Returns the removed
+    /// container together with its aliases, or an error if any of its processes is still running.
+    pub fn take(&self, id: &str) -> Result<(ReapableChild, Vec<String>)> {
+        let id = self.resolve_id(id)?;
+        let mut running = false;
+        let children = self.grandchildren().remove_if(&id, |children| {
+            for child in children {
+                if child.exit_data()?.is_none() {
+                    running = true;
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        })?;
+        if running {
+            bail!("container {} is still running", id)
+        }
+        let child = children
+            .and_then(|x| x.into_iter().next())
+            .context("child not available")?;
+
+        let mut aliases = lock!(self.aliases());
+        let names = aliases
+            .iter()
+            .filter(|(_, v)| **v == id)
+            .map(|(k, _)| k.clone())
+            .collect::<Vec<_>>();
+        for name in &names {
+            aliases.remove(name);
+        }
+        Ok((child, names))
+    }
+
+    /// Register an exited container unregistered by `take` again. Aliases which got reused
+    /// in the meantime stay with their new container.
+    pub fn reinsert(&self, id: &str, child: ReapableChild, names: Vec<String>) -> Result<()> {
+        self.grandchildren().insert(id.into(), child)?;
+        let mut aliases = lock!(self.aliases());
+        for name in names {
+            aliases.entry(name).or_insert_with(|| id.into());
+        }
+        self.exited.notify_one();
+        debug!("Registered container {} again", id);
+        Ok(())
+    }
+
+    /// Remove all containers which exited at least `ttl` ago and publish an eviction event for
+    /// each of them. Returns the evicted containers.
+    pub fn evict_exited(&self, ttl: Duration) -> Result<Vec<(String, ReapableChild)>> {
+        let mut evicted = vec![];
+        for id in self.grandchildren().keys()? {
+            let children = self.grandchildren().remove_if(&id, |children| {
+                for child in children {
+                    if !child.exited_for()?.map_or(false, |d| d >= ttl) {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            })?;
+            if let Some(child) = children.and_then(|x| x.into_iter().next()) {
+                self.forget_container(&id)?;
+                debug!("Evicted exited container {}", id);
+                self.events()
+                    .publish(EventKind::Evicted, &id, child.pid(), 0, 0);
+                evicted.push((id, child));
+            }
+        }
+        Ok(evicted)
+    }
+
+    /// Wait until a container exited. A container exiting while nobody waits completes the next
+    /// call immediately.
+    pub async fn wait_exited(&self) {
+        self.exited.notified().await
+    }
+
+    /// Returns the time until the next container can be evicted after exiting at least `ttl`
+    /// ago, or `None` if all containers are still running.
+    pub fn next_eviction(&self, ttl: Duration) -> Result<Option<Duration>> {
+        // A container can be evicted once all of its processes exited.
+        let mut remaining: HashMap<String, Option<Duration>> = HashMap::new();
+        for (id, child) in self.grandchildren().entries()? {
+            let child_remaining = child.exited_for()?.map(|d| ttl.saturating_sub(d));
+            let entry = remaining.entry(id).or_insert(Some(Duration::ZERO));
+            *entry = match (*entry, child_remaining) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                _ => None,
+            };
+        }
+        Ok(remaining.into_values().flatten().min())
+    }
+
+    /// Drop the name alias and exec sessions of an unregistered container.
+    fn forget_container(&self, id: &str) -> Result<()> {
+        lock!(self.aliases()).retain(|_, v| v != id);
+        self.exec_sessions().remove_container(id)?;
+        Ok(())
+    }
+
     fn forget_grandchild(
-        locked_grandchildren: &Arc<Mutex<MultiMap<String, ReapableChild>>>,
+        grandchildren: &ShardedMultiMap<ReapableChild>,
         grandchild_pid: u32,
     ) -> Result<()> {
-        let mut map = lock!(locked_grandchildren);
-        map.retain(|_, v| v.pid == grandchild_pid);
-        Ok(())
+        grandchildren.retain(|_, v| v.pid != grandchild_pid)
     }
 
     pub fn kill_grandchildren(&self, s: Signal) -> Result<()> {
         debug!("Killing grandchildren");
-        let grandchildren = lock!(self.grandchildren);
-        let grandchildren_iter = grandchildren.iter();
-        for (_, grandchild) in grandchildren_iter {
+        let deadline = self.timeouts.shutdown().map(|t| Instant::now() + t);
+        for (_, grandchild) in self.grandchildren().entries()? {
             let span = debug_span!("kill_grandchild", pid = grandchild.pid);
             let _enter = span.enter();
             debug!("Killing single grandchild");
-            kill_grandchild(grandchild.pid, s);
+            if !grandchild.kill_group(s)? {
+                // The PID may be reused already
+                continue;
+            }
             futures::executor::block_on(
                 async {
-                    if let Err(e) = grandchild.close().await {
+                    let res = match deadline {
+                        Some(deadline) => {
+                            match time::timeout_at(deadline, grandchild.close()).await {
+                                Ok(res) => res,
+                                Err(_) => {
+                                    warn!("Grandchild did not exit in time, killing it");
+                                    match grandchild.kill_group(Signal::SIGKILL) {
+                                        Ok(_) => grandchild.close().await,
+                                        Err(e) => Err(e),
+                                    }
+                                }
+                            }
+                        }
+                        None => grandchild.close().await,
+                    };
+                    if let Err(e) = res {
                         error!("Unable to close grandchild: {:#}", e)
                     }
                 }
@@ -174,6 +541,46 @@ impl ChildReaper {
         debug!("Done killing all grandchildren");
         Ok(())
     }
+
+    /// Flush the loggers of all grandchildren and synchronize their files to disk. Failures
+    /// are logged, so that the remaining loggers get flushed anyway.
+    pub async fn sync_logs(&self) {
+        let grandchildren = match self.grandchildren().entries() {
+            Ok(grandchildren) => grandchildren,
+            Err(e) => {
+                error!("Unable to list grandchildren: {:#}", e);
+                return;
+            }
+        };
+        for (id, grandchild) in grandchildren {
+            if let Err(e) = grandchild.io().logger().await.write().await.sync().await {
+                error!("Unable to sync logs of {}: {:#}", id, e);
+            }
+        }
+    }
+}
+
+/// Fail with the exit status and stderr output of the runtime if it did not succeed.
+fn check_output(output: &Output) -> Result<()> {
+    if output.status.success() {
+        return Ok(());
+    }
+    const BASE_ERR: &str = "child command exited with";
+
+    let mut err_str = match output.status.code() {
+        Some(code) => format!("{}: {}", BASE_ERR, code),
+        None => format!("{} signal", BASE_ERR),
+    };
+
+    if !output.stderr.is_empty() {
+        write!(
+            err_str,
+            ": {}",
+            str::from_utf8(&output.stderr).context("convert stderr to utf8")?,
+        )?;
+    }
+
+    bail!(err_str)
 }
 
 pub fn kill_grandchild(raw_pid: u32, s: Signal) {
@@ -181,8 +588,10 @@ pub fn kill_grandchild(raw_pid: u32, s: Signal) {
     if let Ok(pgid) = getpgid(Some(pid)) {
         // If process_group is 1, we will end up calling
         // kill(-1), which kills everything conmon is allowed to.
+        // Children sharing the group of the server must not take it down with them.
+        let own = pgid == getpgrp();
         let pgid = i32::from(pgid);
-        if pgid > 1 {
+        if pgid > 1 && !own {
             if let Err(e) = kill(Pid::from_raw(-pgid), s) {
                 error!(
                     raw_pid,
@@ -198,15 +607,65 @@ pub fn kill_grandchild(raw_pid: u32, s: Signal) {
 
 type TaskHandle = Arc<Mutex<Option<Vec<JoinHandle<()>>>>>;
 
+#[derive(Debug)]
+/// A name alias reserved by `reserve_alias`, which gets released when dropped unless kept.
+pub struct AliasReservation {
+    aliases: Arc<Mutex<HashMap<String, String>>>,
+    name: String,
+    id: String,
+    kept: bool,
+}
+
+impl AliasReservation {
+    /// Keep the alias after the container got registered.
+    pub fn keep(mut self) {
+        self.kept = true;
+    }
+}
+
+impl Drop for AliasReservation {
+    fn drop(&mut self) {
+        if self.kept {
+            return;
+        }
+        match self.aliases.lock() {
+            Ok(mut aliases) => {
+                if aliases.get(&self.name) == Some(&self.id) {
+                    debug!("Releasing reserved container name '{}'", self.name);
+                    aliases.remove(&self.name);
+                }
+            }
+            Err(e) => error!("Unable to release container name '{}': {:#}", self.name, e),
+        }
+    }
+}
+
+#[derive(Clone, Copy, CopyGetters, Debug, Default, Eq, PartialEq)]
+#[getset(get_copy = "pub")]
+/// Time spent in the phases of creating a child by the runtime.
+pub struct CreateTimings {
+    /// Forking and executing the runtime.
+    runtime_spawn: Duration,
+
+    /// Waiting for the runtime to connect to the console socket, zero without a terminal.
+    console_handshake: Duration,
+
+    /// Waiting for the runtime to exit.
+    runtime: Duration,
+
+    /// Waiting for the pidfile after the runtime exited.
+    pidfile_wait: Duration,
+}
+
 #[derive(Clone, CopyGetters, Debug, Getters, Setters)]
 pub struct ReapableChild {
-    #[getset(get)]
+    #[getset(get = "pub")]
     exit_paths: Vec<PathBuf>,
 
-    #[getset(get)]
+    #[getset(get = "pub")]
     oom_exit_paths: Vec<PathBuf>,
 
-    #[getset(get_copy)]
+    #[getset(get_copy = "pub")]
     pid: u32,
 
     #[getset(get = "pub")]
@@ -220,20 +679,64 @@ pub struct ReapableChild {
 
     task: Option<TaskHandle>,
 
+    exit_data: Arc<Mutex<Option<(ExitChannelData, BootInstant)>>>,
+
+    /// Set right after the child got reaped, which happens before the exit data is stored.
+    reaped: Reaped,
+
+    /// Broadcasts the exit data once, available after the child got watched.
+    exit_tx: Option<Sender<ExitChannelData>>,
+
     #[getset(get = "pub")]
     cleanup_cmd: Vec<String>,
+
+    #[getset(get)]
+    success_exit_codes: Vec<i32>,
+
+    #[getset(get)]
+    cleanup_paths: Vec<PathBuf>,
+
+    #[getset(get_copy = "pub")]
+    overrides: Overrides,
+
+    cleanup_failure: Arc<Mutex<Option<String>>>,
+
+    #[getset(get = "pub")]
+    pressure: PressureMonitor,
+
+    /// Time the child got registered in nanoseconds since the UNIX epoch.
+    #[getset(get_copy = "pub")]
+    created: u64,
 }
 
 #[derive(Clone, CopyGetters, Debug, Getters, Setters)]
 pub struct ExitChannelData {
     #[getset(get = "pub")]
-    pub exit_code: i32,
+    exit_code: i32,
+
+    #[getset(get = "pub")]
+    raw_exit_code: i32,
 
     #[getset(get = "pub")]
-    pub oomed: bool,
+    oomed: bool,
 
     #[getset(get = "pub")]
-    pub timed_out: bool,
+    timed_out: bool,
+
+    /// Time of the exit in nanoseconds since the UNIX epoch.
+    #[getset(get = "pub")]
+    exited_at: u64,
+}
+
+impl ExitChannelData {
+    /// The signal which terminated the process, derived from exit codes above 128 by the
+    /// convention of `128 + signal`. `None` for regular exits and timeouts.
+    pub fn signal(&self) -> Option<Signal> {
+        if self.timed_out || self.raw_exit_code <= 128 {
+            return None;
+        }
+        Signal::try_from(self.raw_exit_code - 128).ok()
+    }
 }
 
 impl ReapableChild {
@@ -246,8 +749,114 @@ impl ReapableChild {
             timeout: *child.timeout(),
             token: CancellationToken::new(),
             task: None,
+            exit_data: Default::default(),
+            reaped: Default::default(),
+            exit_tx: None,
             cleanup_cmd: child.cleanup_cmd().to_vec(),
+            success_exit_codes: child.success_exit_codes().to_vec(),
+            cleanup_paths: child.cleanup_paths().to_vec(),
+            overrides: child.overrides(),
+            cleanup_failure: Default::default(),
+            pressure: Default::default(),
+            created: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Sample the pressure stall information of the child every `interval` until it exits.
+    pub fn sample_pressure(&self, interval: Duration) {
+        self.pressure.start(self.token.clone(), self.pid, interval);
+    }
+
+    /// Send the signal to the child, which fails if it exited already.
+    pub fn signal(&self, signal: Signal) -> Result<()> {
+        // Holding the lock prevents the child from being reaped concurrently.
+        let reaped = lock!(self.reaped);
+        if *reaped {
+            bail!("child {} exited already", self.pid)
         }
+        kill(Pid::from_raw(self.pid as pid_t), signal)
+            .with_context(|| format!("send {} to pid {}", signal, self.pid))
+    }
+
+    /// Send the signal to the process group of the child and the child itself. Returns false
+    /// without sending it if the child got reaped already.
+    pub fn kill_group(&self, signal: Signal) -> Result<bool> {
+        let reaped = lock!(self.reaped);
+        if *reaped {
+            return Ok(false);
+        }
+        kill_grandchild(self.pid, signal);
+        Ok(true)
+    }
+
+    /// Returns true once the child got reaped, which may be before its exit data is available.
+    /// Its PID may be reused afterwards.
+    pub fn reaped(&self) -> Result<bool> {
+        Ok(*lock!(self.reaped))
+    }
+
+    /// Returns the exit data of the child, or `None` if it is still running.
+    pub fn exit_data(&self) -> Result<Option<ExitChannelData>> {
+        Ok(lock!(self.exit_data).as_ref().map(|(data, _)| data.clone()))
+    }
+
+    /// Wait until the child exited and return its exit data, which returns immediately if it
+    /// exited already.
+    pub async fn wait(&self) -> Result<ExitChannelData> {
+        // Subscribe before checking the stored exit data, which gets stored before it is sent.
+        let mut exit_rx = self
+            .exit_tx
+            .as_ref()
+            .with_context(|| format!("child {} is not watched", self.pid))?
+            .subscribe();
+        if let Some(exit_data) = self.exit_data()? {
+            return Ok(exit_data);
+        }
+        exit_rx.recv().await.context("receive exit data")
+    }
+
+    /// Returns the reason why the cleanup command failed, or `None` if it succeeded or did not
+    /// finish yet.
+    pub fn cleanup_failure(&self) -> Result<Option<String>> {
+        Ok(lock!(self.cleanup_failure).clone())
+    }
+
+    /// Returns the time elapsed since the child exited including suspended time, or `None` if it
+    /// is still running.
+    pub fn exited_for(&self) -> Result<Option<Duration>> {
+        Ok(lock!(self.exit_data).as_ref().map(|(_, at)| at.elapsed()))
+    }
+
+    /// Remove the on-disk artifacts of the exited child, like attach sockets and pidfiles.
+    /// The log files get removed as well if `remove_logs` is set.
+    pub async fn remove_artifacts(&self, remove_logs: bool) -> Result<()> {
+        self.io()
+            .attach()
+            .await
+            .close()
+            .context("close attach endpoints")?;
+        for path in self.cleanup_paths() {
+            match fs::remove_file(path).await {
+                Err(e) if e.kind() != ErrorKind::NotFound => {
+                    return Err(e).context(format!("remove {}", path.display()))
+                }
+                _ => {}
+            }
+        }
+        if remove_logs {
+            self.io()
+                .logger()
+                .await
+                .write()
+                .await
+                .remove()
+                .await
+                .context("remove logs")?;
+        }
+        Ok(())
     }
 
     pub async fn close(&self) -> Result<()> {
@@ -266,77 +875,181 @@ impl ReapableChild {
         Ok(())
     }
 
-    fn watch(&mut self) -> Result<(Sender<ExitChannelData>, Receiver<ExitChannelData>)> {
+    fn watch(
+        &mut self,
+        id: &str,
+        strategy: ReaperStrategy,
+        timeouts: Timeouts,
+        sigchld_waiter: Arc<SigchldWaiter>,
+        events: Arc<EventBus>,
+    ) -> Result<(Sender<ExitChannelData>, Receiver<ExitChannelData>)> {
         let exit_paths = self.exit_paths().clone();
         let oom_exit_paths = self.oom_exit_paths().clone();
         let pid = self.pid();
         // Only one exit code will be written.
         let (exit_tx, exit_rx) = broadcast::channel(1);
         let exit_tx_clone = exit_tx.clone();
+        self.exit_tx = Some(exit_tx.clone());
         let timeout = *self.timeout();
         let stop_token = self.token().clone();
-        let mut cleanup_cmd_raw = self.cleanup_cmd().clone();
+        let stored_exit_data = self.exit_data.clone();
+        let reaped = self.reaped.clone();
+        let timeout_reaped = reaped.clone();
+        let panic_exit_data = stored_exit_data.clone();
+        let panic_exit_tx = exit_tx.clone();
+        let cleanup_cmd = self.cleanup_cmd().clone();
+        let cleanup_failure = self.cleanup_failure.clone();
+        let id = id.to_string();
+        let success_exit_codes = self.success_exit_codes().clone();
+        let supervisor = self.io().supervisor().clone();
+        let output_supervisor = supervisor.clone();
+        let max_drain_time = self.overrides().drain_time(timeouts.drain());
+        crash::register(pid, &exit_paths);
 
         let task = task::spawn(
             async move {
-                debug!("Running task");
-                let mut exit_code: i32 = -1;
-                let mut oomed = false;
-                let mut timed_out = false;
-                let (oom_tx, mut oom_rx) = tokio::sync::mpsc::channel(1);
-                let oom_watcher = OOMWatcher::new(&stop_token, pid, &oom_exit_paths, oom_tx).await;
-
-                let span = debug_span!("wait_for_exit_code");
-                let wait_for_exit_code = task::spawn_blocking(move || {
-                    let _enter = span.enter();
-                    Self::wait_for_exit_code(&stop_token, pid)
-                });
-
-                let closure = async {
-                    let (code, oom) = tokio::join!(wait_for_exit_code, oom_rx.recv());
-                    if let Ok(code) = code {
-                        exit_code = code;
-                    }
-                    if let Some(event) = oom {
-                        oomed = event.oom;
-                    }
-                };
-                if let Some(timeout) = timeout {
-                    if time::timeout_at(timeout, closure).await.is_err() {
-                        timed_out = true;
-                        exit_code = -3;
-                        kill_grandchild(pid, Signal::SIGKILL);
-                    }
-                } else {
-                    closure.await;
-                }
-                oom_watcher.stop().await;
-                let exit_channel_data = ExitChannelData {
-                    exit_code,
-                    oomed,
-                    timed_out,
-                };
-                debug!(
-                    "Write to exit paths: {}",
-                    exit_paths
-                        .iter()
-                        .map(|x| x.display().to_string())
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                );
-                if let Err(e) = Self::write_to_exit_paths(exit_code, &exit_paths).await {
-                    error!(pid, "Could not write exit paths: {:#}", e);
-                }
+                // The exit status can be only collected once, which means that the task cannot
+                // be restarted on panic.
+                let watched = supervisor
+                    .run_once("watch", async move {
+                        debug!("Running task");
+                        let mut exit_code: i32 = -1;
+                        let mut oomed = false;
+                        let mut timed_out = false;
+                        let (oom_tx, mut oom_rx) = tokio::sync::mpsc::channel(1);
+                        let oom_watcher =
+                            OOMWatcher::new(&stop_token, pid, &oom_exit_paths, oom_tx).await;
 
-                if !cleanup_cmd_raw.is_empty() {
-                    Self::spawn_cleanup_process(&mut cleanup_cmd_raw).await;
-                }
+                        let wait_for_exit_code = match strategy {
+                            ReaperStrategy::Signal => task::spawn(
+                                async move {
+                                    let exit_code = sigchld_waiter
+                                        .wait(pid, reaped)
+                                        .await
+                                        .unwrap_or_else(|e| {
+                                            error!("Unable to wait for exit code: {:#}", e);
+                                            FAILED_EXIT_CODE
+                                        });
+                                    stop_token.cancel();
+                                    exit_code
+                                }
+                                .instrument(debug_span!("wait_for_exit_code")),
+                            ),
+                            ReaperStrategy::Thread => {
+                                let span = debug_span!("wait_for_exit_code");
+                                task::spawn_blocking(move || {
+                                    let _enter = span.enter();
+                                    Self::wait_for_exit_code(&stop_token, pid, &reaped)
+                                })
+                            }
+                        };
+
+                        let closure = async {
+                            let (code, oom) = tokio::join!(wait_for_exit_code, oom_rx.recv());
+                            if let Ok(code) = code {
+                                exit_code = code;
+                            }
+                            if let Some(event) = oom {
+                                oomed = event.oom;
+                            }
+                        };
+                        if let Some(timeout) = timeout {
+                            if time::timeout_at(timeout, closure).await.is_err() {
+                                timed_out = true;
+                                exit_code = -3;
+                                match timeout_reaped.lock() {
+                                    Ok(reaped) if !*reaped => kill_grandchild(pid, Signal::SIGKILL),
+                                    Ok(_) => {}
+                                    Err(e) => error!(pid, "Unable to lock reaped marker: {:#}", e),
+                                }
+                            }
+                        } else {
+                            closure.await;
+                        }
+                        oom_watcher.stop().await;
+
+                        // Keep forwarding the output until EOF, otherwise the last lines of
+                        // fast exiting containers may be missing once the exit is reported.
+                        output_supervisor.drain(max_drain_time).await;
+
+                        let raw_exit_code = exit_code;
+                        if !timed_out && success_exit_codes.contains(&exit_code) {
+                            debug!("Treating exit code {} as success", exit_code);
+                            exit_code = 0;
+                        }
+                        let exit_channel_data = ExitChannelData {
+                            exit_code,
+                            raw_exit_code,
+                            oomed,
+                            timed_out,
+                            exited_at: SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .map(|d| d.as_nanos() as u64)
+                                .unwrap_or_default(),
+                        };
+                        debug!(
+                            "Write to exit paths: {}",
+                            exit_paths
+                                .iter()
+                                .map(|x| x.display().to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        );
+                        if let Err(e) = Self::write_to_exit_paths(exit_code, &exit_paths).await {
+                            error!(pid, "Could not write exit paths: {:#}", e);
+                        }
+                        crash::unregister(pid);
 
-                debug!("Sending exit struct to channel: {:?}", exit_channel_data);
-                if exit_tx_clone.send(exit_channel_data).is_err() {
-                    debug!("Unable to send exit status");
+                        if !cleanup_cmd.is_empty() {
+                            Self::spawn_cleanup_process(
+                                &cleanup_cmd,
+                                timeouts.cleanup_cmd(),
+                                id,
+                                pid,
+                                events,
+                                cleanup_failure,
+                            );
+                        }
+
+                        match stored_exit_data.lock() {
+                            Ok(mut data) => {
+                                *data = Some((exit_channel_data.clone(), BootInstant::now()))
+                            }
+                            Err(e) => error!(pid, "Unable to store exit data: {:#}", e),
+                        }
+
+                        debug!("Sending exit struct to channel: {:?}", exit_channel_data);
+                        if exit_tx_clone.send(exit_channel_data).is_err() {
+                            debug!("Unable to send exit status");
+                        }
+                        debug!("Task done");
+                    })
+                    .await;
+
+                // Waiters would hang forever without an exit, so a synthetic one gets stored
+                // unless the task panicked after storing the real one.
+                if watched.is_none() {
+                    match panic_exit_data.lock() {
+                        Ok(mut data) if data.is_none() => {
+                            let exit_channel_data = ExitChannelData {
+                                exit_code: -1,
+                                raw_exit_code: -1,
+                                oomed: false,
+                                timed_out: false,
+                                exited_at: SystemTime::now()
+                                    .duration_since(UNIX_EPOCH)
+                                    .map(|d| d.as_nanos() as u64)
+                                    .unwrap_or_default(),
+                            };
+                            *data = Some((exit_channel_data.clone(), BootInstant::now()));
+                            if panic_exit_tx.send(exit_channel_data).is_err() {
+                                debug!("Unable to send exit status");
+                            }
+                        }
+                        Ok(_) => {}
+                        Err(e) => error!(pid, "Unable to store exit data: {:#}", e),
+                    }
                 }
-                debug!("Task done");
             }
             .instrument(debug_span!("watch", pid)),
         );
@@ -351,40 +1064,76 @@ impl ReapableChild {
         Ok((exit_tx, exit_rx))
     }
 
-    async fn spawn_cleanup_process(raw_cmd: &mut Vec<String>) {
-        let mut cleanup_cmd = Command::new(raw_cmd.remove(0));
-
-        raw_cmd.iter().for_each(|arg| {
-            cleanup_cmd.arg(arg);
+    /// Run the cleanup command in the background and kill it after `timeout`. Engines rely on
+    /// the cleanup to happen, so failures and timeouts get stored in `failure` and published as
+    /// event.
+    fn spawn_cleanup_process(
+        raw_cmd: &[String],
+        timeout: Duration,
+        id: String,
+        pid: u32,
+        events: Arc<EventBus>,
+        failure: Arc<Mutex<Option<String>>>,
+    ) {
+        let cleanup_cmd = Helper::Exec.command(raw_cmd).map(|mut cmd| {
+            cmd.kill_on_drop(true);
+            cmd
         });
 
-        tokio::spawn(async move {
-            match cleanup_cmd.status().await {
-                Ok(status) => {
-                    if !status.success() {
-                        error!("Failed to execute cleanup command successfully: {}", status);
-                    }
+        task::spawn(
+            async move {
+                let status = async { Ok::<_, anyhow::Error>(cleanup_cmd?.status().await?) };
+                let (exit_code, message) = match time::timeout(timeout, status).await {
+                    Ok(Ok(status)) if status.success() => return,
+                    Ok(Ok(status)) => (
+                        status.code().unwrap_or(-1),
+                        format!("cleanup command failed: {}", status),
+                    ),
+                    Ok(Err(e)) => (-1, format!("unable to run cleanup command: {:#}", e)),
+                    Err(_) => (-1, format!("cleanup command timed out after {:?}", timeout)),
+                };
+                error!("{}", message);
+                match failure.lock() {
+                    Ok(mut failure) => *failure = Some(message),
+                    Err(e) => error!("Unable to store cleanup failure: {:#}", e),
                 }
-                Err(e) => error!(
-                    "Failed to spawn and execute cleanup command process successfully: {}",
-                    e
-                ),
+                events.publish(EventKind::CleanupFailed, &id, pid, exit_code, exit_code);
             }
-        });
+            .instrument(debug_span!("cleanup_cmd", pid)),
+        );
     }
 
-    fn wait_for_exit_code(token: &CancellationToken, pid: u32) -> i32 {
+    fn wait_for_exit_code(token: &CancellationToken, pid: u32, reaped: &Reaped) -> i32 {
         debug!("Waiting for exit code");
-        const FAILED_EXIT_CODE: i32 = -3;
+        // Wait without collecting the exit first, so that the PID only gets released while
+        // holding the lock of the reaped marker.
+        loop {
+            let mut info: siginfo_t = unsafe { mem::zeroed() };
+            if unsafe { libc::waitid(P_PID, pid, &mut info, WEXITED | WNOWAIT) } == 0
+                || Errno::last() != Errno::EINTR
+            {
+                break;
+            }
+        }
+        let mut reaped = match reaped.lock() {
+            Ok(reaped) => reaped,
+            Err(e) => {
+                error!("Unable to lock reaped marker: {:#}", e);
+                token.cancel();
+                return FAILED_EXIT_CODE;
+            }
+        };
         loop {
             match waitpid(Pid::from_raw(pid as pid_t), None) {
                 Ok(WaitStatus::Exited(_, exit_code)) => {
                     debug!("Exited {}", exit_code);
+                    *reaped = true;
                     token.cancel();
                     return exit_code;
                 }
                 Ok(WaitStatus::Signaled(_, sig, _)) => {
                     debug!("Signaled");
+                    *reaped = true;
                     token.cancel();
                     return (sig as i32) + 128;
                 }
@@ -397,6 +1146,7 @@ impl ReapableChild {
                 }
                 Err(err) => {
                     error!("Unable to waitpid on {:#}", err);
+                    *reaped = true;
                     token.cancel();
                     return FAILED_EXIT_CODE;
                 }
@@ -438,3 +1188,342 @@ impl ReapableChild {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{container_log::ContainerLog, exec_sessions::ExecKind};
+    use tempfile::tempdir;
+
+    fn add_child(sut: &ChildReaper, id: &str, exited: bool) -> Result<()> {
+        let io = ContainerIO::new(id, false, false, ContainerLog::new(), None)?;
+        let child = Child::new(
+            id.into(),
+            0,
+            vec![],
+            vec![],
+            None,
+            SharedContainerIO::new(io),
+            vec![],
+            None,
+            vec![],
+            vec![],
+            Overrides::default(),
+        );
+        let reapable_child = ReapableChild::from_child(&child);
+        if exited {
+            let exit_data = ExitChannelData {
+                exit_code: 0,
+                raw_exit_code: 0,
+                oomed: false,
+                timed_out: false,
+                exited_at: 0,
+            };
+            *lock!(reapable_child.exit_data) = Some((exit_data, BootInstant::now()));
+        }
+        sut.grandchildren().insert(id.into(), reapable_child)
+    }
+
+    #[tokio::test]
+    async fn evict_exited() -> Result<()> {
+        let sut = ChildReaper::default();
+        add_child(&sut, "exited", true)?;
+        add_child(&sut, "running", false)?;
+        add_child(&sut, "exec", true)?;
+        add_child(&sut, "exec", false)?;
+
+        // Listing returns the first child of every ID
+        assert!(sut.evict_exited(Duration::from_secs(60))?.is_empty());
+        assert_eq!(sut.list(&[])?.len(), 3);
+
+        let evicted = sut.evict_exited(Duration::ZERO)?;
+        assert_eq!(evicted.len(), 1);
+        assert_eq!(evicted[0].0, "exited");
+        assert!(sut.get("exited").is_err());
+        assert_eq!(sut.list(&[])?.len(), 2);
+
+        let (events, _, _) = sut.events().replay(0)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind(), EventKind::Evicted);
+        assert_eq!(events[0].container_id(), "exited");
+        Ok(())
+    }
+
+    #[test]
+    fn reserve_alias() -> Result<()> {
+        let sut = ChildReaper::default();
+        add_child(&sut, "ctr", false)?;
+        assert!(sut.reserve_alias("ctr", "other").is_err());
+
+        let reservation = sut.reserve_alias("name", "new")?;
+        assert!(sut.reserve_alias("name", "other").is_err());
+        assert!(sut.check_alias("name").is_err());
+        drop(reservation);
+        assert!(sut.check_alias("name").is_ok());
+
+        sut.reserve_alias("name", "new")?.keep();
+        assert!(sut.reserve_alias("name", "other").is_err());
+        assert_eq!(sut.resolve_id("name")?, "new");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn take_reinsert() -> Result<()> {
+        let sut = ChildReaper::default();
+        add_child(&sut, "exited", true)?;
+        add_child(&sut, "running", false)?;
+        lock!(sut.aliases).insert("name".into(), "exited".into());
+        let session = sut
+            .exec_sessions()
+            .register("exited", ExecKind::Sync, 1, vec![])?;
+
+        assert!(sut.take("running").is_err());
+        let (child, names) = sut.take("name")?;
+        assert_eq!(names, ["name"]);
+        assert!(sut.get("exited").is_err());
+        assert_eq!(sut.resolve_id("name")?, "name");
+        assert_eq!(sut.exec_sessions().list("exited")?[0].id(), &session);
+
+        sut.reinsert("exited", child, names)?;
+        assert!(sut.get("name")?.exit_data()?.is_some());
+
+        sut.remove("exited")?;
+        assert!(sut.exec_sessions().list("exited")?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn next_eviction() -> Result<()> {
+        let sut = ChildReaper::default();
+        add_child(&sut, "running", false)?;
+        add_child(&sut, "exec", true)?;
+        add_child(&sut, "exec", false)?;
+        assert_eq!(sut.next_eviction(Duration::ZERO)?, None);
+
+        add_child(&sut, "exited", true)?;
+        let next = sut
+            .next_eviction(Duration::from_secs(60))?
+            .context("no eviction")?;
+        assert!(next > Duration::ZERO && next <= Duration::from_secs(60));
+        assert_eq!(sut.next_eviction(Duration::ZERO)?, Some(Duration::ZERO));
+
+        sut.evict_exited(Duration::ZERO)?;
+        assert_eq!(sut.next_eviction(Duration::ZERO)?, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_exit() -> Result<()> {
+        let sut = ChildReaper::default();
+        add_child(&sut, "ctr", false)?;
+        let mut child = sut.get("ctr")?;
+        assert!(child.wait().await.is_err());
+
+        let (exit_tx, _) = broadcast::channel(1);
+        child.exit_tx = Some(exit_tx.clone());
+        let waiter = {
+            let child = child.clone();
+            task::spawn(async move { child.wait().await })
+        };
+        let exit_data = ExitChannelData {
+            exit_code: 137,
+            raw_exit_code: 137,
+            oomed: false,
+            timed_out: false,
+            exited_at: 1,
+        };
+        *lock!(child.exit_data) = Some((exit_data.clone(), BootInstant::now()));
+        let _ = exit_tx.send(exit_data);
+
+        let res = waiter.await??;
+        assert_eq!(*res.exit_code(), 137);
+        assert_eq!(res.signal(), Some(Signal::SIGKILL));
+        assert_eq!(*child.wait().await?.exited_at(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signal_reaped() -> Result<()> {
+        let sut = ChildReaper::default();
+        add_child(&sut, "ctr", false)?;
+        let mut child = sut.get("ctr")?;
+        child.pid = std::process::Command::new("true").spawn()?.id();
+
+        let exit_code =
+            ReapableChild::wait_for_exit_code(&CancellationToken::new(), child.pid, &child.reaped);
+        assert_eq!(exit_code, 0);
+
+        // The PID may be reused before the exit data gets stored
+        assert!(child.reaped()?);
+        assert!(child.exit_data()?.is_none());
+        assert!(child.signal(Signal::SIGTERM).is_err());
+        assert!(!child.kill_group(Signal::SIGTERM)?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify_deleted() -> Result<()> {
+        let sut = ChildReaper::default();
+        let runtime_args = vec![
+            "-c".into(),
+            r#"[ "$2" = leaked ] && echo '{"id":"leaked","status":"stopped"}'"#.into(),
+            "sh".into(),
+        ];
+        sut.verify_deleted(Path::new("sh"), &runtime_args, "deleted", 1)
+            .await;
+        sut.verify_deleted(Path::new("sh"), &runtime_args, "leaked", 2)
+            .await;
+
+        let (events, _, _) = sut.events().replay(0)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind(), EventKind::RuntimeStateLeaked);
+        assert_eq!(events[0].container_id(), "leaked");
+        assert_eq!(events[0].pid(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drain_output_on_exit() -> Result<()> {
+        const SIZE: u64 = 4 * 1024 * 1024;
+        let sut = ChildReaper::new(10, ReaperStrategy::Thread, false, Timeouts::default());
+
+        let mut io = ContainerIO::new("drain", false, false, ContainerLog::new(), None)?;
+        let size = SIZE.to_string();
+        let mut process = std::process::Command::new("head")
+            .args(["-c", size.as_str(), "/dev/zero"])
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let stdout = process.stdout.take().context("no stdout")?;
+        match io.typ_mut() {
+            ContainerIOType::Streams(streams) => streams.handle_stdio_receive(
+                None,
+                Some(tokio::process::ChildStdout::from_std(stdout)?),
+                None,
+            ),
+            ContainerIOType::Terminal(_) => bail!("unexpected terminal"),
+        }
+        let io = SharedContainerIO::new(io);
+        let stats = io.stats().await;
+
+        let child = Child::new(
+            "drain".into(),
+            process.id(),
+            vec![],
+            vec![],
+            None,
+            io,
+            vec![],
+            None,
+            vec![],
+            vec![],
+            Overrides::default(),
+        );
+        let mut exit_rx = sut.watch_grandchild(child, true)?;
+        let exit_data = exit_rx.recv().await?;
+
+        // The whole output has to be forwarded once the exit gets reported
+        assert_eq!(*exit_data.exit_code(), 0);
+        assert_eq!(stats.stdout_bytes(), SIZE);
+        Ok(())
+    }
+
+    // Killing the grandchildren blocks on their tasks, which requires other worker threads.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn shutdown_keeps_logs() -> Result<()> {
+        const LINES: usize = 100_000;
+        let sut = ChildReaper::new(10, ReaperStrategy::Thread, false, Timeouts::default());
+        let dir = tempdir()?;
+        let log_path = dir.path().join("ctr.log");
+
+        let logger = ContainerLog::cri(&log_path).await?;
+        let mut io = ContainerIO::new("ctr", false, false, logger, None)?;
+        // The shutdown signal gets ignored, so that the output is complete. The process reports
+        // on stderr once the signal is ignored.
+        let script = format!("trap '' TERM; echo >&2; seq 1 {}", LINES);
+        let mut process = std::process::Command::new("sh")
+            .args(["-c", script.as_str()])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let stdout = process.stdout.take().context("no stdout")?;
+        let mut stderr = process.stderr.take().context("no stderr")?;
+        std::io::Read::read_exact(&mut stderr, &mut [0])?;
+        match io.typ_mut() {
+            ContainerIOType::Streams(streams) => streams.handle_stdio_receive(
+                None,
+                Some(tokio::process::ChildStdout::from_std(stdout)?),
+                None,
+            ),
+            ContainerIOType::Terminal(_) => bail!("unexpected terminal"),
+        }
+        let child = Child::new(
+            "ctr".into(),
+            process.id(),
+            vec![],
+            vec![],
+            None,
+            SharedContainerIO::new(io),
+            vec![],
+            None,
+            vec![],
+            vec![],
+            Overrides::default(),
+        );
+        sut.watch_grandchild(child, false)?;
+
+        sut.kill_grandchildren(Signal::SIGTERM)?;
+        sut.sync_logs().await;
+
+        // Reassemble the output from the full and partial CRI log lines.
+        let mut output = String::new();
+        for line in std::fs::read_to_string(&log_path)?.lines() {
+            let fields: Vec<&str> = line.splitn(4, ' ').collect();
+            output.push_str(fields[3]);
+            if fields[2] == "F" {
+                output.push('\n');
+            }
+        }
+        let expected: String = (1..=LINES).map(|i| format!("{}\n", i)).collect();
+        assert_eq!(output, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cleanup_failure() -> Result<()> {
+        let sut = ChildReaper::new(10, ReaperStrategy::Thread, false, Timeouts::default());
+        let process = std::process::Command::new("true").spawn()?;
+        let io = ContainerIO::new("cleanup", false, false, ContainerLog::new(), None)?;
+        let child = Child::new(
+            "cleanup".into(),
+            process.id(),
+            vec![],
+            vec![],
+            None,
+            SharedContainerIO::new(io),
+            vec!["sh".into(), "-c".into(), "exit 3".into()],
+            None,
+            vec![],
+            vec![],
+            Overrides::default(),
+        );
+        let mut exit_rx = sut.watch_grandchild(child, false)?;
+        exit_rx.recv().await?;
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let failure = loop {
+            if let Some(failure) = sut.get("cleanup")?.cleanup_failure()? {
+                break failure;
+            }
+            assert!(Instant::now() < deadline, "no cleanup failure reported");
+            time::sleep(Duration::from_millis(10)).await;
+        };
+        assert!(failure.starts_with("cleanup command failed"));
+
+        let (events, _, _) = sut.events().replay(0)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind(), EventKind::CleanupFailed);
+        assert_eq!(events[0].container_id(), "cleanup");
+        assert_eq!(events[0].exit_code(), 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+//! Clocks for timeouts and the retention of exited state.
+//!
+//! Timeouts use the monotonic clock of tokio, which is not affected by steps of the wall clock.
+//! It does not advance while the system is suspended, which means that a suspend does not
+//! expire the timeouts of running processes on resume. The retention of exited containers,
+//! finished exec sessions and idempotent results is measured by the boot time clock instead,
+//! because it includes suspended time and therefore releases the state in real time.
+
+use nix::time::{clock_gettime, ClockId};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+/// A measurement of the boot time clock, which includes the time the system was suspended.
+pub struct BootInstant(Duration);
+
+impl BootInstant {
+    /// The current time of the boot time clock. The monotonic clock is used as fallback if the
+    /// kernel does not support it.
+    pub fn now() -> Self {
+        let time = clock_gettime(ClockId::CLOCK_BOOTTIME)
+            .or_else(|_| clock_gettime(ClockId::CLOCK_MONOTONIC))
+            .map(Duration::from)
+            .unwrap_or_default();
+        Self(time)
+    }
+
+    /// The time passed since this instant.
+    pub fn elapsed(&self) -> Duration {
+        Self::now().0.saturating_sub(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn elapsed() {
+        let sut = BootInstant::now();
+        thread::sleep(Duration::from_millis(10));
+        assert!(sut.elapsed() >= Duration::from_millis(10));
+        assert!(BootInstant::now() > sut);
+    }
+}
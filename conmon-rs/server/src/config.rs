@@ -1,9 +1,9 @@
 //! Configuration related structures
 use anyhow::{bail, Result};
-use clap::{AppSettings, Parser};
+use clap::{AppSettings, Args, CommandFactory, Parser, Subcommand};
 use getset::{CopyGetters, Getters, Setters};
 use serde::{Deserialize, Serialize};
-use std::{fs, path::PathBuf};
+use std::{collections::HashSet, fs, path::PathBuf, time::Duration};
 use strum::{EnumIter, EnumString, IntoEnumIterator, IntoStaticStr};
 
 macro_rules! prefix {
@@ -12,7 +12,9 @@ macro_rules! prefix {
     };
 }
 
-#[derive(CopyGetters, Debug, Deserialize, Eq, Getters, Parser, PartialEq, Serialize, Setters)]
+#[derive(
+    Clone, CopyGetters, Debug, Deserialize, Eq, Getters, Parser, PartialEq, Serialize, Setters,
+)]
 #[serde(rename_all = "kebab-case")]
 #[clap(
     after_help("More info at: https://github.com/containers/conmon-rs"),
@@ -26,6 +28,17 @@ pub struct Config {
     /// Show version information.
     version: bool,
 
+    #[get_copy = "pub"]
+    #[clap(long("print-config"))]
+    /// Print the effective configuration and exit.
+    print_config: bool,
+
+    #[get = "pub"]
+    #[clap(subcommand)]
+    #[serde(skip)]
+    /// Subcommand to run instead of the server.
+    command: Option<Command>,
+
     #[get = "pub"]
     #[clap(
         default_value("info"),
@@ -52,7 +65,7 @@ pub struct Config {
 
     #[get = "pub"]
     #[clap(
-        default_value_if("version", None, Some("")),
+        default_value_ifs(&[("version", None, Some("")), ("print-config", None, Some(""))]),
         env(concat!(prefix!(), "RUNTIME")),
         long("runtime"),
         short('r'),
@@ -62,8 +75,9 @@ pub struct Config {
     runtime: PathBuf,
 
     #[get = "pub"]
+    #[set = "pub"]
     #[clap(
-        default_value_if("version", None, Some("")),
+        default_value_ifs(&[("version", None, Some("")), ("print-config", None, Some(""))]),
         env(concat!(prefix!(), "RUNTIME_DIR")),
         long("runtime-dir"),
         value_name("RUNTIME_DIR")
@@ -100,6 +114,313 @@ pub struct Config {
     )]
     /// Select the cgroup manager to be used
     cgroup_manager: CgroupManager,
+
+    #[get_copy = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "TENANT_ISOLATION")),
+        long("tenant-isolation"),
+        value_name("TENANT_ISOLATION")
+    )]
+    /// Isolate temporary files and sockets of each connected caller in a dedicated runtime
+    /// subdirectory keyed by its peer UID.
+    tenant_isolation: bool,
+
+    #[get_copy = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "RECORD_RUNTIME_INVOCATIONS")),
+        long("record-runtime-invocations"),
+        value_name("RECORD_RUNTIME_INVOCATIONS")
+    )]
+    /// Append the command line, environment and working directory of every create and exec
+    /// runtime invocation as shell snippet to `<container id>.invocations` in the runtime
+    /// directory, to reproduce runtime failures by hand. The recorded environment may contain
+    /// secrets.
+    record_runtime_invocations: bool,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("0"),
+        env(concat!(prefix!(), "TENANT_MAX_FILES")),
+        long("tenant-max-files"),
+        value_name("TENANT_MAX_FILES")
+    )]
+    /// Maximum number of files a single caller can hold in its runtime subdirectory, 0 means
+    /// unlimited. Only used together with --tenant-isolation.
+    tenant_max_files: usize,
+
+    #[get = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "READ_ONLY_SOCKET")),
+        long("read-only-socket"),
+        value_name("READ_ONLY_SOCKET")
+    )]
+    /// Additional socket path serving only RPCs which do not modify any container, like
+    /// statuses, stats, log offsets and events. Meant for less trusted observers like dashboards
+    /// and auditors.
+    read_only_socket: Option<PathBuf>,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("67108864"),
+        env(concat!(prefix!(), "MAX_MESSAGE_SIZE")),
+        long("max-message-size"),
+        value_name("BYTES")
+    )]
+    /// Maximum size of a single RPC message in bytes.
+    max_message_size: usize,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("4096"),
+        env(concat!(prefix!(), "MAX_LIST_LEN")),
+        long("max-list-len"),
+        value_name("LEN")
+    )]
+    /// Maximum number of entries allowed in list fields of RPC requests, like commands or
+    /// exit paths.
+    max_list_len: u32,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("131072"),
+        env(concat!(prefix!(), "MAX_TEXT_LEN")),
+        long("max-text-len"),
+        value_name("BYTES")
+    )]
+    /// Maximum size in bytes of a single text field of RPC requests, like a command argument.
+    /// The default matches the kernel limit for a single argument. Paths are limited to
+    /// PATH_MAX.
+    max_text_len: u32,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("1024"),
+        env(concat!(prefix!(), "EVENT_HISTORY_SIZE")),
+        long("event-history-size"),
+        value_name("SIZE")
+    )]
+    /// Number of container lifecycle events kept in memory for replay.
+    event_history_size: usize,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("300"),
+        env(concat!(prefix!(), "EXEC_SESSION_TTL")),
+        long("exec-session-ttl"),
+        value_name("SECONDS")
+    )]
+    /// Time in seconds finished exec sessions and their temporary files are kept for debugging.
+    exec_session_ttl: u64,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("3600"),
+        env(concat!(prefix!(), "EXITED_CONTAINER_TTL")),
+        long("exited-container-ttl"),
+        value_name("SECONDS")
+    )]
+    /// Time in seconds exited containers are kept in memory before they get evicted
+    /// automatically. Set to 0 to keep them until they get removed explicitly.
+    exited_container_ttl: u64,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value(ReaperStrategy::Signal.into()),
+        env(concat!(prefix!(), "REAPER_STRATEGY")),
+        long("reaper-strategy"),
+        possible_values(ReaperStrategy::iter().map(|x| x.into()).collect::<Vec<&str>>()),
+        value_name("STRATEGY")
+    )]
+    /// Select how container exits are detected
+    reaper_strategy: ReaperStrategy,
+
+    #[get_copy = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "AUDIT_IDLE_WAKEUPS")),
+        long("audit-idle-wakeups"),
+        value_name("AUDIT_IDLE_WAKEUPS")
+    )]
+    /// Log every wakeup of the server while no containers are active. Meant for diagnosing the
+    /// energy consumption of idle servers.
+    audit_idle_wakeups: bool,
+
+    #[get_copy = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "SERVE_STDIO")),
+        long("serve-stdio"),
+        value_name("SERVE_STDIO")
+    )]
+    /// Serve a single RPC connection over the inherited stdin and stdout, for example a
+    /// socketpair created by the parent, instead of listening on the socket path. The stdout
+    /// log driver writes to stderr in this mode.
+    serve_stdio: bool,
+
+    #[get_copy = "pub"]
+    #[clap(
+        env(concat!(prefix!(), "LOG_XATTRS")),
+        long("log-xattrs"),
+        value_name("LOG_XATTRS")
+    )]
+    /// Tag created log files with the extended attributes user.conmon-rs.container-id,
+    /// user.conmon-rs.pod-uid and user.conmon-rs.created.
+    log_xattrs: bool,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("0"),
+        env(concat!(prefix!(), "LOG_DISK_QUOTA")),
+        long("log-disk-quota"),
+        value_name("BYTES")
+    )]
+    /// Maximum on-disk bytes of the CRI logs of a single container including their rotations.
+    /// The oldest rotations get removed on every reopen until the quota is met. Set to 0 for
+    /// no limit.
+    log_disk_quota: u64,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("0"),
+        env(concat!(prefix!(), "PSI_SAMPLE_INTERVAL")),
+        long("psi-sample-interval"),
+        value_name("SECONDS")
+    )]
+    /// Interval in seconds to sample the CPU, memory and IO pressure stall information of the
+    /// container cgroups, which requires cgroup v2. Set to 0 to disable sampling.
+    psi_sample_interval: u64,
+
+    #[get_copy = "pub"]
+    #[clap(
+        default_value("1000"),
+        env(concat!(prefix!(), "SLOW_REQUEST_THRESHOLD")),
+        long("slow-request-threshold"),
+        value_name("MILLISECONDS")
+    )]
+    /// RPC requests taking longer than this many milliseconds get logged as warning together
+    /// with a digest of their parameters. Set to 0 to disable the logging.
+    slow_request_threshold: u64,
+
+    #[get_copy = "pub"]
+    #[clap(flatten)]
+    /// Timeouts of the container lifecycle operations.
+    timeouts: Timeouts,
+}
+
+const DEFAULT_CREATE_TIMEOUT: u64 = 300;
+const DEFAULT_EXEC_TIMEOUT: u64 = 0;
+const DEFAULT_MAX_DRAIN_TIME: u64 = 1000;
+const DEFAULT_CLEANUP_CMD_TIMEOUT: u64 = 60;
+const DEFAULT_SHUTDOWN_TIMEOUT: u64 = 0;
+const DEFAULT_REQUEST_TIMEOUT: u64 = 0;
+
+#[derive(Args, Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+/// Timeouts of the container lifecycle operations.
+pub struct Timeouts {
+    #[clap(
+        default_value_t = DEFAULT_CREATE_TIMEOUT,
+        env(concat!(prefix!(), "CREATE_TIMEOUT")),
+        long("create-timeout"),
+        value_name("SECONDS")
+    )]
+    /// Maximum time in seconds to wait for the runtime to connect to the console socket of a
+    /// container or exec process.
+    create_timeout: u64,
+
+    #[clap(
+        default_value_t = DEFAULT_EXEC_TIMEOUT,
+        env(concat!(prefix!(), "EXEC_TIMEOUT")),
+        long("exec-timeout"),
+        value_name("SECONDS")
+    )]
+    /// Default timeout in seconds of exec processes which do not request their own. Set to 0
+    /// to let them run without a timeout.
+    exec_timeout: u64,
+
+    #[clap(
+        default_value_t = DEFAULT_MAX_DRAIN_TIME,
+        env(concat!(prefix!(), "MAX_DRAIN_TIME")),
+        long("max-drain-time"),
+        value_name("MILLISECONDS")
+    )]
+    /// Maximum time in milliseconds to keep reading the container output after its exit until
+    /// EOF, before the exit files get written.
+    max_drain_time: u64,
+
+    #[clap(
+        default_value_t = DEFAULT_CLEANUP_CMD_TIMEOUT,
+        env(concat!(prefix!(), "CLEANUP_CMD_TIMEOUT")),
+        long("cleanup-cmd-timeout"),
+        value_name("SECONDS")
+    )]
+    /// Maximum time in seconds the cleanup command of a container may run before it gets
+    /// killed.
+    cleanup_cmd_timeout: u64,
+
+    #[clap(
+        default_value_t = DEFAULT_SHUTDOWN_TIMEOUT,
+        env(concat!(prefix!(), "SHUTDOWN_TIMEOUT")),
+        long("shutdown-timeout"),
+        value_name("SECONDS")
+    )]
+    /// Maximum time in seconds to wait for every container to exit after forwarding the
+    /// shutdown signal, before it gets killed. Set to 0 to wait without a limit.
+    shutdown_timeout: u64,
+
+    #[clap(
+        default_value_t = DEFAULT_REQUEST_TIMEOUT,
+        env(concat!(prefix!(), "REQUEST_TIMEOUT")),
+        long("request-timeout"),
+        value_name("SECONDS")
+    )]
+    /// Maximum time in seconds to process a single RPC request, before it fails with a timeout
+    /// error and its pending work gets dropped. Set to 0 to process requests without a limit.
+    /// Requests waiting for containers or streaming their output are never limited.
+    request_timeout: u64,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self {
+            create_timeout: DEFAULT_CREATE_TIMEOUT,
+            exec_timeout: DEFAULT_EXEC_TIMEOUT,
+            max_drain_time: DEFAULT_MAX_DRAIN_TIME,
+            cleanup_cmd_timeout: DEFAULT_CLEANUP_CMD_TIMEOUT,
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+        }
+    }
+}
+
+impl Timeouts {
+    /// Maximum time to wait for the runtime to connect to the console socket.
+    pub fn create(&self) -> Duration {
+        Duration::from_secs(self.create_timeout)
+    }
+
+    /// Default timeout of exec processes, `None` if they run without a timeout.
+    pub fn exec(&self) -> Option<Duration> {
+        Some(Duration::from_secs(self.exec_timeout)).filter(|d| !d.is_zero())
+    }
+
+    /// Maximum time to drain the container output after its exit.
+    pub fn drain(&self) -> Duration {
+        Duration::from_millis(self.max_drain_time)
+    }
+
+    /// Maximum runtime of cleanup commands.
+    pub fn cleanup_cmd(&self) -> Duration {
+        Duration::from_secs(self.cleanup_cmd_timeout)
+    }
+
+    /// Maximum time to wait for the containers to exit on shutdown, `None` if unlimited.
+    pub fn shutdown(&self) -> Option<Duration> {
+        Some(Duration::from_secs(self.shutdown_timeout)).filter(|d| !d.is_zero())
+    }
+
+    /// Maximum time to process a single RPC request, `None` if unlimited.
+    pub fn request(&self) -> Option<Duration> {
+        Some(Duration::from_secs(self.request_timeout)).filter(|d| !d.is_zero())
+    }
 }
 
 #[derive(
@@ -148,21 +469,121 @@ pub enum CgroupManager {
     Cgroupfs,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    EnumIter,
+    EnumString,
+    Eq,
+    IntoStaticStr,
+    Hash,
+    PartialEq,
+    Serialize,
+)]
+#[strum(serialize_all = "lowercase")]
+/// Available strategies to detect container exits.
+pub enum ReaperStrategy {
+    /// Wake up on SIGCHLD and collect only the exited containers
+    Signal,
+
+    /// Use a blocking thread per container
+    Thread,
+}
+
+impl Default for ReaperStrategy {
+    fn default() -> Self {
+        Self::Signal
+    }
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self::parse()
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Subcommand)]
+/// Subcommands which run instead of the server.
+pub enum Command {
+    /// Inspect the configuration.
+    #[clap(subcommand)]
+    Config(ConfigCommand),
+
+    /// Loop the container lifecycle against a mock runtime and fail if the resource usage of
+    /// the server grows, for the release qualification.
+    #[clap(hide = true)]
+    Soak(SoakArgs),
+
+    /// Accept the connections on the socket and pass each one to a dedicated server instance of
+    /// its pod, which gets started on the first connection and stopped once idle. Clients send
+    /// the pod ID followed by a newline before the RPC stream. Has to be the last argument.
+    Dispatch,
+
+    /// Serve the connections passed by the dispatcher for a single pod.
+    #[clap(hide = true)]
+    ServePod(ServePodArgs),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Subcommand)]
+/// Subcommands operating on the configuration.
+pub enum ConfigCommand {
+    /// Validate the provided flags and environment variables without starting the server.
+    /// Warns about unknown and deprecated environment variables.
+    Validate,
+}
+
+#[derive(Args, Clone, CopyGetters, Debug, Eq, PartialEq)]
+/// Options of the soak mode.
+pub struct SoakArgs {
+    #[get_copy = "pub"]
+    #[clap(default_value("3600"), long("duration"), value_name("SECONDS"))]
+    /// Duration of the soak in seconds.
+    duration: u64,
+
+    #[get_copy = "pub"]
+    #[clap(default_value("20"), long("warmup"), value_name("ITERATIONS"))]
+    /// Amount of iterations before the baseline of the resource usage gets taken.
+    warmup: u64,
+}
+
+#[derive(Args, Clone, Debug, Eq, Getters, PartialEq)]
+/// Options of a server instance started by the dispatcher.
+pub struct ServePodArgs {
+    #[get = "pub"]
+    #[clap(long("pod-id"), value_name("ID"))]
+    /// ID of the served pod.
+    pod_id: String,
+}
+
+/// Version of the configuration schema, which gets increased if flags or environment variables
+/// get removed or change their meaning.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Deprecated environment variables mapped to their replacements. Deprecated variables are
+/// ignored by the server.
+const DEPRECATED_ENV: &[(&str, &str)] = &[];
+
+#[derive(Debug, Default, Eq, PartialEq)]
+/// Findings of validating the configuration.
+pub struct Findings {
+    /// Problems which prevent the server from starting.
+    pub errors: Vec<String>,
+
+    /// Problems which the server tolerates, like unknown or deprecated environment variables.
+    pub warnings: Vec<String>,
+}
+
 // Sync with `pkg/client/client.go`
 const SOCKET: &str = "conmon.sock";
-const PIDFILE: &str = "pidfile";
+pub const PIDFILE: &str = "pidfile";
 
 impl Config {
     /// Validate the configuration integrity.
     pub fn validate(&self) -> Result<()> {
-        if !self.runtime().exists() {
-            bail!("runtime path '{}' does not exist", self.runtime().display())
+        if let Some(error) = self.errors().into_iter().next() {
+            bail!(error)
         }
 
         if !self.runtime_dir().exists() {
@@ -172,8 +593,6 @@ impl Config {
         if let Some(rr) = self.runtime_root() {
             if !rr.exists() {
                 fs::create_dir_all(rr)?;
-            } else if !rr.is_dir() {
-                bail!("runtime root '{}' does not exist", rr.display())
             }
         }
 
@@ -181,8 +600,87 @@ impl Config {
             fs::remove_file(self.socket())?;
         }
 
+        if let Some(socket) = self.read_only_socket() {
+            if socket.exists() {
+                fs::remove_file(socket)?;
+            }
+        }
+
         Ok(())
     }
+
+    /// Validate the configuration without modifying the system, where `env` are the names of
+    /// all environment variables. Invalid values of flags and environment variables are
+    /// already rejected when parsing.
+    pub fn check<I>(&self, env: I) -> Findings
+    where
+        I: IntoIterator<Item = String>,
+    {
+        Findings {
+            errors: self.errors(),
+            warnings: Self::env_warnings(env, DEPRECATED_ENV),
+        }
+    }
+
+    fn errors(&self) -> Vec<String> {
+        let mut errors = vec![];
+        if !self.runtime().exists() {
+            errors.push(format!(
+                "runtime path '{}' does not exist",
+                self.runtime().display()
+            ));
+        }
+
+        if let Some(rr) = self.runtime_root() {
+            if rr.exists() && !rr.is_dir() {
+                errors.push(format!("runtime root '{}' does not exist", rr.display()));
+            }
+        }
+
+        if self.serve_stdio() && self.tenant_isolation() {
+            errors.push("serving RPC over stdio is not possible with tenant isolation".into());
+        }
+
+        if self.serve_stdio() && self.read_only_socket().is_some() {
+            errors.push("serving RPC over stdio is not possible with a read-only socket".into());
+        }
+
+        if self.command() == &Some(Command::Dispatch) {
+            if self.serve_stdio() {
+                errors.push("dispatching is not possible when serving RPC over stdio".into());
+            }
+            if self.read_only_socket().is_some() {
+                errors.push("dispatching is not possible with a read-only socket".into());
+            }
+        }
+        errors
+    }
+
+    /// Warn about environment variables with the server prefix, which are either unknown or
+    /// `deprecated`.
+    fn env_warnings<I>(env: I, deprecated: &[(&str, &str)]) -> Vec<String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let known: HashSet<String> = <Self as CommandFactory>::command()
+            .get_arguments()
+            .filter_map(|arg| arg.get_env())
+            .map(|env| env.to_string_lossy().into())
+            .collect();
+
+        let mut warnings: Vec<String> = env
+            .into_iter()
+            .filter(|key| key.starts_with(prefix!()) && !known.contains(key))
+            .map(|key| match deprecated.iter().find(|(old, _)| *old == key) {
+                Some((_, new)) => {
+                    format!("{} is deprecated and ignored, use {} instead", key, new)
+                }
+                None => format!("unknown environment variable {}", key),
+            })
+            .collect();
+        warnings.sort();
+        warnings
+    }
     pub fn socket(&self) -> PathBuf {
         self.runtime_dir().join(SOCKET)
     }
@@ -190,3 +688,135 @@ impl Config {
         self.runtime_dir().join(PIDFILE)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeouts() -> Result<()> {
+        let sut = Config::try_parse_from(["conmonrs", "--print-config"])?;
+        assert_eq!(sut.timeouts(), Timeouts::default());
+        assert_eq!(sut.timeouts().create(), Duration::from_secs(300));
+        assert_eq!(sut.timeouts().exec(), None);
+        assert_eq!(sut.timeouts().shutdown(), None);
+        assert_eq!(sut.timeouts().request(), None);
+
+        let sut = Config::try_parse_from([
+            "conmonrs",
+            "--print-config",
+            "--exec-timeout=10",
+            "--max-drain-time=50",
+            "--shutdown-timeout=5",
+            "--request-timeout=30",
+        ])?;
+        assert_eq!(sut.timeouts().exec(), Some(Duration::from_secs(10)));
+        assert_eq!(sut.timeouts().drain(), Duration::from_millis(50));
+        assert_eq!(sut.timeouts().shutdown(), Some(Duration::from_secs(5)));
+        assert_eq!(sut.timeouts().request(), Some(Duration::from_secs(30)));
+        Ok(())
+    }
+
+    #[test]
+    fn config_validate_command() -> Result<()> {
+        let sut = Config::try_parse_from([
+            "conmonrs",
+            "--runtime=/bin/true",
+            "--runtime-dir=/tmp",
+            "config",
+            "validate",
+        ])?;
+        assert_eq!(
+            sut.command(),
+            &Some(Command::Config(ConfigCommand::Validate))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn soak_command() -> Result<()> {
+        let sut = Config::try_parse_from([
+            "conmonrs",
+            "--runtime=/bin/true",
+            "--runtime-dir=/tmp",
+            "soak",
+            "--duration=60",
+        ])?;
+        match sut.command() {
+            Some(Command::Soak(args)) => {
+                assert_eq!(args.duration(), 60);
+                assert_eq!(args.warmup(), 20);
+            }
+            other => panic!("unexpected command {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dispatch_commands() -> Result<()> {
+        let sut = Config::try_parse_from([
+            "conmonrs",
+            "--runtime=/bin/true",
+            "--runtime-dir=/tmp",
+            "dispatch",
+        ])?;
+        assert_eq!(sut.command(), &Some(Command::Dispatch));
+
+        let sut = Config::try_parse_from([
+            "conmonrs",
+            "--runtime=/bin/true",
+            "--runtime-dir=/tmp",
+            "serve-pod",
+            "--pod-id=pod",
+        ])?;
+        match sut.command() {
+            Some(Command::ServePod(args)) => assert_eq!(args.pod_id(), "pod"),
+            other => panic!("unexpected command {:?}", other),
+        }
+
+        let sut = Config::try_parse_from([
+            "conmonrs",
+            "--runtime=/bin/true",
+            "--runtime-dir=/tmp",
+            // Flags with an environment variable take an optional value
+            "--serve-stdio=true",
+            "dispatch",
+        ])?;
+        assert!(sut
+            .errors()
+            .contains(&"dispatching is not possible when serving RPC over stdio".into()));
+        Ok(())
+    }
+
+    #[test]
+    fn check() -> Result<()> {
+        let sut = Config::try_parse_from([
+            "conmonrs",
+            "--print-config",
+            "--serve-stdio=true",
+            "--tenant-isolation=true",
+        ])?;
+        let findings = sut.check(vec!["CONMON_LOG_LEVEL".into(), "CONMON_TYPO".into()]);
+        assert!(findings
+            .errors
+            .contains(&"serving RPC over stdio is not possible with tenant isolation".into()));
+        assert_eq!(
+            findings.warnings,
+            vec!["unknown environment variable CONMON_TYPO".to_string()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn env_warnings() {
+        let env = vec![
+            "PATH".to_string(),
+            "CONMON_CREATE_TIMEOUT".into(),
+            "CONMON_OLD".into(),
+        ];
+        assert_eq!(
+            Config::env_warnings(env, &[("CONMON_OLD", "CONMON_NEW")]),
+            vec!["CONMON_OLD is deprecated and ignored, use CONMON_NEW instead".to_string()]
+        );
+    }
+}
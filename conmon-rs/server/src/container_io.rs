@@ -1,64 +1,111 @@
 use crate::{
-    attach::SharedContainerAttach, container_log::SharedContainerLog, streams::Streams,
-    terminal::Terminal,
+    attach::SharedContainerAttach,
+    container_log::SharedContainerLog,
+    io_stats::IOStats,
+    streams::Streams,
+    supervisor::Supervisor,
+    terminal::{Terminal, TerminalMode},
 };
 use anyhow::{bail, Context, Result};
-use getset::{Getters, MutGetters};
+use getset::{CopyGetters, Getters, MutGetters};
+use lazy_static::lazy_static;
 use nix::errno::Errno;
 use std::{
-    fmt,
+    collections::HashSet,
+    env, fmt,
+    fs::OpenOptions,
+    io::ErrorKind,
     marker::Unpin,
     os::unix::io::{FromRawFd, RawFd},
     path::{Path, PathBuf},
-    sync::Arc,
+    process,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
 };
 use strum::AsRefStr;
-use tempfile::Builder;
+use tempfile::TempPath;
 use tokio::{
     fs::File,
-    io::{AsyncRead, AsyncReadExt, AsyncWriteExt},
+    io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
     sync::{
-        mpsc::{UnboundedReceiver, UnboundedSender},
+        mpsc::{Receiver, Sender, UnboundedReceiver, UnboundedSender},
         RwLock,
     },
     time::{self, Instant},
 };
 use tracing::{debug, error};
 
+/// Counter making the names of temp files unique within the server.
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+lazy_static! {
+    /// The temp directories created by the server, which get removed on shutdown.
+    static ref TEMP_DIRS: Mutex<HashSet<PathBuf>> = Mutex::new(HashSet::new());
+}
+
 /// A shared container IO abstraction.
-#[derive(Debug, Clone)]
-pub struct SharedContainerIO(Arc<RwLock<ContainerIO>>);
+#[derive(Debug, Clone, CopyGetters, Getters)]
+pub struct SharedContainerIO {
+    io: Arc<RwLock<ContainerIO>>,
+
+    /// The supervisor of the IO tasks, which is accessible without locking.
+    #[getset(get = "pub")]
+    supervisor: Arc<Supervisor>,
+
+    /// Whether the container IO is a terminal, which is accessible without locking.
+    #[getset(get_copy = "pub")]
+    terminal: bool,
+}
 
 impl SharedContainerIO {
     /// Create a new SharedContainerIO instance from the provided ContainerIO.
     pub fn new(io: ContainerIO) -> Self {
-        Self(Arc::new(RwLock::new(io)))
+        Self {
+            supervisor: io.supervisor().clone(),
+            terminal: matches!(io.typ(), ContainerIOType::Terminal(_)),
+            io: Arc::new(RwLock::new(io)),
+        }
     }
 
     pub async fn read_all_with_timeout(
         &self,
         timeout: Option<Instant>,
     ) -> (Vec<u8>, Vec<u8>, bool) {
-        self.0.write().await.read_all_with_timeout(timeout).await
+        self.io.write().await.read_all_with_timeout(timeout).await
     }
 
     /// Resize the shared container IO to the provided with and height.
     /// Errors in case of no terminal containers.
     pub async fn resize(&self, width: u16, height: u16) -> Result<()> {
-        match self.0.read().await.typ() {
+        match self.io.read().await.typ() {
             ContainerIOType::Terminal(t) => t.resize(width, height).context("resize terminal"),
             ContainerIOType::Streams(_) => bail!("container has no terminal"),
         }
     }
 
+    /// Change the line discipline of the terminal. Errors in case of no terminal containers.
+    pub async fn set_terminal_mode(&self, mode: &TerminalMode) -> Result<()> {
+        match self.io.read().await.typ() {
+            ContainerIOType::Terminal(t) => t.set_mode(mode).await.context("set terminal mode"),
+            ContainerIOType::Streams(_) => bail!("container has no terminal"),
+        }
+    }
+
     /// Retrieve the underlying SharedContainerLog instance.
     pub async fn logger(&self) -> SharedContainerLog {
-        self.0.read().await.logger().clone()
+        self.io.read().await.logger().clone()
     }
 
     /// Retrieve the underlying SharedContainerAttach instance.
     pub async fn attach(&self) -> SharedContainerAttach {
-        self.0.read().await.attach().clone()
+        self.io.read().await.attach().clone()
+    }
+
+    /// Retrieve the IO statistics of the container.
+    pub async fn stats(&self) -> Arc<IOStats> {
+        self.io.read().await.stats().clone()
     }
 }
 
@@ -72,6 +119,12 @@ pub struct ContainerIO {
 
     #[getset(get = "pub")]
     attach: SharedContainerAttach,
+
+    #[getset(get = "pub")]
+    stats: Arc<IOStats>,
+
+    #[getset(get = "pub")]
+    supervisor: Arc<Supervisor>,
 }
 
 #[derive(Debug)]
@@ -88,7 +141,7 @@ pub enum Message {
     Done,
 }
 
-#[derive(AsRefStr, Clone, Copy, Debug)]
+#[derive(AsRefStr, Clone, Copy, Debug, Eq, PartialEq)]
 #[strum(serialize_all = "lowercase")]
 /// Available pipe types.
 pub enum Pipe {
@@ -120,58 +173,172 @@ impl From<Streams> for ContainerIOType {
 impl ContainerIO {
     const MAX_STDIO_STREAM_SIZE: usize = 16 * 1024 * 1024;
 
-    /// Create a new container IO instance.
-    pub fn new(terminal: bool, logger: SharedContainerLog) -> Result<Self> {
-        let logger_clone = logger.clone();
+    /// The maximum amount of names tried if a temp file already exists.
+    const MAX_TEMP_FILE_ATTEMPTS: usize = 100;
+
+    /// Create a new container IO instance for the container `id`. Temporary sockets are created
+    /// in `directory` or the default temp dir if not provided.
+    pub fn new(
+        id: &str,
+        terminal: bool,
+        serialize_output: bool,
+        logger: SharedContainerLog,
+        directory: Option<&Path>,
+    ) -> Result<Self> {
+        Self::with_type(id, logger, |logger, attach, stats, supervisor| {
+            Ok(if terminal {
+                Terminal::new(logger, attach, stats, supervisor, id, directory)
+                    .context("create new terminal")?
+                    .into()
+            } else {
+                Streams::new(logger, attach, stats, supervisor, serialize_output)
+                    .context("create new streams")?
+                    .into()
+            })
+        })
+    }
+
+    /// Create a new container IO instance for the container `id`, which uses a terminal
+    /// pre-allocated by the client. Its master is received from the unix socket listening at
+    /// `socket_path`.
+    pub fn pre_allocated_terminal(
+        id: &str,
+        socket_path: &Path,
+        logger: SharedContainerLog,
+    ) -> Result<Self> {
+        Self::with_type(id, logger, |logger, attach, stats, supervisor| {
+            Ok(
+                Terminal::pre_allocated(logger, attach, stats, supervisor, socket_path)
+                    .context("create pre-allocated terminal")?
+                    .into(),
+            )
+        })
+    }
+
+    fn with_type<F>(id: &str, logger: SharedContainerLog, typ: F) -> Result<Self>
+    where
+        F: FnOnce(
+            SharedContainerLog,
+            SharedContainerAttach,
+            Arc<IOStats>,
+            Arc<Supervisor>,
+        ) -> Result<ContainerIOType>,
+    {
         let attach = SharedContainerAttach::default();
-        let attach_clone = attach.clone();
-        let typ = if terminal {
-            Terminal::new(logger_clone, attach_clone)
-                .context("create new terminal")?
-                .into()
-        } else {
-            Streams::new(logger_clone, attach_clone)
-                .context("create new streams")?
-                .into()
-        };
+        let stats = Arc::new(IOStats::default());
+        let supervisor = Arc::new(Supervisor::new(id));
+        let typ = typ(
+            logger.clone(),
+            attach.clone(),
+            stats.clone(),
+            supervisor.clone(),
+        )?;
         Ok(Self {
             typ,
             logger,
             attach,
+            stats,
+            supervisor,
         })
     }
 
-    /// Generate a the temp file name without creating the file.
-    pub fn temp_file_name(directory: Option<&Path>, prefix: &str, suffix: &str) -> Result<PathBuf> {
-        let mut file = Builder::new();
-        file.prefix(prefix).suffix(suffix).rand_bytes(7);
-        let file = match directory {
-            Some(d) => file.tempfile_in(d),
-            None => file.tempfile(),
+    /// Stream the file or FIFO at `path` into the container stdin instead of the attach input,
+    /// which is only supported without a terminal.
+    pub fn set_stdin_path(&mut self, path: PathBuf) -> Result<()> {
+        match self.typ_mut() {
+            ContainerIOType::Streams(streams) => {
+                streams.set_stdin_path(Some(path));
+                Ok(())
+            }
+            ContainerIOType::Terminal(_) => bail!("stdin path is not supported with a terminal"),
+        }
+    }
+
+    /// Generate a unique temp file name for the container or exec session `id` in the
+    /// subdirectory of `directory` (or the default temp dir) which is dedicated to this server.
+    /// The name gets reserved by exclusively creating the file, whereas the file itself is
+    /// removed again because it will be created by the runtime or socket listener. The returned
+    /// path removes the file on drop, until it gets kept by the caller.
+    pub fn temp_file_name(
+        directory: Option<&Path>,
+        id: &str,
+        prefix: &str,
+        suffix: &str,
+    ) -> Result<TempPath> {
+        let dir = directory
+            .map(PathBuf::from)
+            .unwrap_or_else(env::temp_dir)
+            .join(format!("conmon-{}", process::id()));
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("create temp dir {}", dir.display()))?;
+        if let Ok(mut dirs) = TEMP_DIRS.lock() {
+            dirs.insert(dir.clone());
         }
-        .context("create tempfile")?;
 
-        let path: PathBuf = file.path().into();
-        drop(file);
-        Ok(path)
+        for _ in 0..Self::MAX_TEMP_FILE_ATTEMPTS {
+            let path = dir.join(format!(
+                "{}{}-{}{}",
+                prefix,
+                id,
+                TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed),
+                suffix
+            ));
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => {
+                    std::fs::remove_file(&path)
+                        .with_context(|| format!("remove temp file {}", path.display()))?;
+                    return Ok(TempPath::from_path(path));
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    debug!("Temp file {} already exists, retrying", path.display())
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| format!("create temp file {}", path.display()))
+                }
+            }
+        }
+        bail!(
+            "unable to find unused temp file name in {} after {} attempts",
+            dir.display(),
+            Self::MAX_TEMP_FILE_ATTEMPTS
+        )
+    }
+
+    /// Remove the temp directories created by `temp_file_name` together with their content.
+    pub fn remove_temp_dirs() {
+        let dirs = match TEMP_DIRS.lock() {
+            Ok(mut dirs) => std::mem::take(&mut *dirs),
+            Err(_) => return,
+        };
+        for dir in dirs {
+            debug!("Removing temp dir {}", dir.display());
+            match std::fs::remove_dir_all(&dir) {
+                Err(e) if e.kind() != ErrorKind::NotFound => {
+                    error!("Unable to remove temp dir {}: {:#}", dir.display(), e)
+                }
+                _ => {}
+            }
+        }
     }
 
     pub async fn read_all_with_timeout(
         &mut self,
         time_to_timeout: Option<Instant>,
     ) -> (Vec<u8>, Vec<u8>, bool) {
+        let stats = self.stats().clone();
         match self.typ_mut() {
             ContainerIOType::Terminal(t) => {
                 let (stdout, timed_out) =
-                    Self::read_stream_with_timeout(time_to_timeout, t.message_rx_mut()).await;
+                    Self::read_stream_with_timeout(time_to_timeout, t.message_rx_mut(), &stats)
+                        .await;
                 (stdout, vec![], timed_out)
             }
             ContainerIOType::Streams(s) => {
                 let stdout_rx = &mut s.message_rx_stdout;
                 let stderr_rx = &mut s.message_rx_stderr;
                 let (stdout, stderr) = tokio::join!(
-                    Self::read_stream_with_timeout(time_to_timeout, stdout_rx),
-                    Self::read_stream_with_timeout(time_to_timeout, stderr_rx),
+                    Self::read_stream_with_timeout(time_to_timeout, stdout_rx, &stats),
+                    Self::read_stream_with_timeout(time_to_timeout, stderr_rx, &stats),
                 );
                 let timed_out = stdout.1 || stderr.1;
                 (stdout.0, stderr.0, timed_out)
@@ -182,6 +349,7 @@ impl ContainerIO {
     async fn read_stream_with_timeout(
         time_to_timeout: Option<Instant>,
         receiver: &mut UnboundedReceiver<Message>,
+        stats: &IOStats,
     ) -> (Vec<u8>, bool) {
         let mut stdio = vec![];
         let mut timed_out = false;
@@ -204,6 +372,7 @@ impl ContainerIO {
 
             match msg {
                 Message::Data(data) => {
+                    stats.record_consumed(data.len());
                     if let Some(future_len) = stdio.len().checked_add(data.len()) {
                         if future_len < Self::MAX_STDIO_STREAM_SIZE {
                             stdio.extend(data)
@@ -226,49 +395,94 @@ impl ContainerIO {
         logger: SharedContainerLog,
         message_tx: UnboundedSender<Message>,
         mut attach: SharedContainerAttach,
+        stats: Arc<IOStats>,
     ) -> Result<()>
     where
         T: AsyncRead + Unpin,
     {
         let mut buf = vec![0; 1024];
 
+        while let Some(n) = Self::read_chunk(&mut reader, &mut buf).await? {
+            Self::forward(pipe, &buf[..n], &logger, &mut attach, &message_tx, &stats).await?;
+        }
+
+        message_tx
+            .send(Message::Done)
+            .context("send done message")?;
+        Ok(())
+    }
+
+    /// Read from the provided pipe like `read_loop`, but hand the data over to a single
+    /// `forward_loop` consumer instead of forwarding it directly.
+    pub async fn read_loop_serialized<T>(
+        mut reader: T,
+        pipe: Pipe,
+        chunk_tx: Sender<(Pipe, Message)>,
+    ) -> Result<()>
+    where
+        T: AsyncRead + Unpin,
+    {
+        let mut buf = vec![0; 1024];
+
+        while let Some(n) = Self::read_chunk(&mut reader, &mut buf).await? {
+            chunk_tx
+                .send((pipe, Message::Data(buf[..n].into())))
+                .await
+                .context("send data chunk")?;
+        }
+
+        chunk_tx
+            .send((pipe, Message::Done))
+            .await
+            .context("send done chunk")?;
+        Ok(())
+    }
+
+    /// Forward the chunks of all `read_loop_serialized` producers in their arrival order.
+    pub async fn forward_loop(
+        chunk_rx: &mut Receiver<(Pipe, Message)>,
+        logger: SharedContainerLog,
+        message_tx_stdout: UnboundedSender<Message>,
+        message_tx_stderr: UnboundedSender<Message>,
+        mut attach: SharedContainerAttach,
+        stats: Arc<IOStats>,
+    ) -> Result<()> {
+        while let Some((pipe, message)) = chunk_rx.recv().await {
+            let message_tx = match pipe {
+                Pipe::StdOut => &message_tx_stdout,
+                Pipe::StdErr => &message_tx_stderr,
+            };
+            match message {
+                Message::Data(data) => {
+                    Self::forward(pipe, &data, &logger, &mut attach, message_tx, &stats).await?
+                }
+                Message::Done => message_tx
+                    .send(Message::Done)
+                    .context("send done message")?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Read the next chunk into the buffer. Returns `None` if there is nothing more to read.
+    async fn read_chunk<T>(reader: &mut T, buf: &mut [u8]) -> Result<Option<usize>>
+    where
+        T: AsyncRead + Unpin,
+    {
         loop {
-            match reader.read(&mut buf).await {
+            match reader.read(buf).await {
                 Ok(n) if n > 0 => {
                     debug!("Read {} bytes", n);
-                    let data = &buf[..n];
-
-                    let mut locked_logger = logger.write().await;
-                    locked_logger
-                        .write(pipe, data)
-                        .await
-                        .context("write to log file")?;
-
-                    attach
-                        .write(pipe, data)
-                        .await
-                        .context("write to attach endpoints")?;
-
-                    message_tx
-                        .send(Message::Data(data.into()))
-                        .context("send data message")?;
+                    return Ok(Some(n));
                 }
-                Ok(n) if n == 0 => {
+                Ok(_) => {
                     debug!("No more to read");
-
-                    message_tx
-                        .send(Message::Done)
-                        .context("send done message")?;
-                    return Ok(());
+                    return Ok(None);
                 }
                 Err(e) => match Errno::from_i32(e.raw_os_error().context("get OS error")?) {
                     Errno::EIO => {
                         debug!("Stopping read loop");
-
-                        message_tx
-                            .send(Message::Done)
-                            .context("send done message")?;
-                        return Ok(());
+                        return Ok(None);
                     }
                     Errno::EBADF => {
                         return Err(Errno::EBADFD.into());
@@ -282,11 +496,46 @@ impl ContainerIO {
                         e.raw_os_error().context("get OS error")?
                     ),
                 },
-                _ => {}
             }
         }
     }
 
+    /// Write data read from the pipe to the logger, attach endpoints and the message channel.
+    async fn forward(
+        pipe: Pipe,
+        data: &[u8],
+        logger: &SharedContainerLog,
+        attach: &mut SharedContainerAttach,
+        message_tx: &UnboundedSender<Message>,
+        stats: &IOStats,
+    ) -> Result<()> {
+        let n = data.len();
+        stats.record_read(pipe, n);
+        let start = Instant::now();
+
+        logger
+            .write()
+            .await
+            .write(pipe, data)
+            .await
+            .context("write to log file")?;
+
+        if attach
+            .write(pipe, data)
+            .await
+            .context("write to attach endpoints")?
+        {
+            stats.record_attach(n);
+        }
+        stats.record_forward_duration(start.elapsed());
+
+        message_tx
+            .send(Message::Data(data.into()))
+            .context("send data message")?;
+        stats.record_buffered(n);
+        Ok(())
+    }
+
     pub async fn read_loop_stdin(fd: RawFd, mut attach: SharedContainerAttach) -> Result<()> {
         let mut writer = unsafe { File::from_raw_fd(fd) };
         loop {
@@ -300,4 +549,188 @@ impl ContainerIO {
                 .context("write attach stdin to stream")?;
         }
     }
+
+    /// Copy the contents of the file or FIFO at `path` into the stdin `writer`. Opening a FIFO
+    /// waits until its writing side got opened as well.
+    pub async fn copy_stdin_file<W>(path: &Path, writer: &mut W) -> Result<()>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut file = File::open(path)
+            .await
+            .with_context(|| format!("open stdin file {}", path.display()))?;
+        let n = io::copy(&mut file, writer)
+            .await
+            .with_context(|| format!("copy stdin file {}", path.display()))?;
+        debug!("Copied {} bytes from {} into stdin", n, path.display());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container_log::ContainerLog;
+    use nix::{sys::stat::Mode, unistd::mkfifo};
+    use std::fs;
+    use tempfile::{tempdir, NamedTempFile};
+    use tokio::sync::mpsc;
+
+    fn log_lines(path: &Path) -> Result<Vec<String>> {
+        Ok(fs::read_to_string(path)?
+            .lines()
+            .map(|l| l.split_once(' ').map(|(_, l)| l.into()).unwrap_or_default())
+            .collect())
+    }
+
+    #[tokio::test]
+    async fn read_loop_concurrent() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let logger = ContainerLog::cri(file.path()).await?;
+        let attach = SharedContainerAttach::default();
+        let stats = Arc::new(IOStats::default());
+        let (stdout_tx, mut stdout_rx) = mpsc::unbounded_channel();
+        let (stderr_tx, mut stderr_rx) = mpsc::unbounded_channel();
+
+        let (stdout, stderr) = tokio::join!(
+            ContainerIO::read_loop(
+                &b"out\n"[..],
+                Pipe::StdOut,
+                logger.clone(),
+                stdout_tx,
+                attach.clone(),
+                stats.clone(),
+            ),
+            ContainerIO::read_loop(
+                &b"err\n"[..],
+                Pipe::StdErr,
+                logger,
+                stderr_tx,
+                attach,
+                stats.clone(),
+            ),
+        );
+        stdout?;
+        stderr?;
+
+        let mut lines = log_lines(file.path())?;
+        lines.sort();
+        assert_eq!(lines, vec!["stderr F err", "stdout F out"]);
+        assert!(matches!(stdout_rx.recv().await, Some(Message::Data(d)) if d == b"out\n"));
+        assert!(matches!(stdout_rx.recv().await, Some(Message::Done)));
+        assert!(matches!(stderr_rx.recv().await, Some(Message::Data(d)) if d == b"err\n"));
+        assert!(matches!(stderr_rx.recv().await, Some(Message::Done)));
+        assert_eq!(stats.stdout_bytes(), 4);
+        assert_eq!(stats.stderr_bytes(), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn forward_loop_serialized() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let logger = ContainerLog::cri(file.path()).await?;
+        let stats = Arc::new(IOStats::default());
+        let (stdout_tx, mut stdout_rx) = mpsc::unbounded_channel();
+        let (stderr_tx, mut stderr_rx) = mpsc::unbounded_channel();
+        let (chunk_tx, mut chunk_rx) = mpsc::channel(10);
+
+        for (pipe, data) in [
+            (Pipe::StdOut, "a"),
+            (Pipe::StdErr, "b\n"),
+            (Pipe::StdOut, "c\n"),
+            (Pipe::StdErr, "d\n"),
+        ] {
+            chunk_tx.send((pipe, Message::Data(data.into()))).await?;
+        }
+        ContainerIO::read_loop_serialized(&b""[..], Pipe::StdOut, chunk_tx.clone()).await?;
+        ContainerIO::read_loop_serialized(&b""[..], Pipe::StdErr, chunk_tx).await?;
+
+        ContainerIO::forward_loop(
+            &mut chunk_rx,
+            logger,
+            stdout_tx,
+            stderr_tx,
+            SharedContainerAttach::default(),
+            stats.clone(),
+        )
+        .await?;
+
+        assert_eq!(
+            log_lines(file.path())?,
+            vec!["stdout P a", "stderr F b", "stdout F c", "stderr F d"]
+        );
+        assert!(matches!(stdout_rx.recv().await, Some(Message::Data(d)) if d == b"a"));
+        assert!(matches!(stdout_rx.recv().await, Some(Message::Data(d)) if d == b"c\n"));
+        assert!(matches!(stdout_rx.recv().await, Some(Message::Done)));
+        assert!(matches!(stderr_rx.recv().await, Some(Message::Data(d)) if d == b"b\n"));
+        assert!(matches!(stderr_rx.recv().await, Some(Message::Data(d)) if d == b"d\n"));
+        assert!(matches!(stderr_rx.recv().await, Some(Message::Done)));
+        assert_eq!(stats.stdout_bytes(), 3);
+        assert_eq!(stats.stderr_bytes(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn temp_file_name() -> Result<()> {
+        let dir = tempdir()?;
+        let first = ContainerIO::temp_file_name(Some(dir.path()), "id", "prefix-", ".sock")?;
+        let second = ContainerIO::temp_file_name(Some(dir.path()), "id", "prefix-", ".sock")?;
+        assert_ne!(first.to_path_buf(), second.to_path_buf());
+        assert!(!first.exists());
+
+        let parent = dir.path().join(format!("conmon-{}", process::id()));
+        assert_eq!(first.parent(), Some(parent.as_path()));
+        let name = first
+            .file_name()
+            .and_then(|x| x.to_str())
+            .unwrap_or_default();
+        assert!(name.starts_with("prefix-id-"));
+        assert!(name.ends_with(".sock"));
+
+        // Files get removed on drop, unless kept
+        fs::write(&first, "")?;
+        let first_path = first.to_path_buf();
+        drop(first);
+        assert!(!first_path.exists());
+
+        fs::write(&second, "")?;
+        let second = second.keep()?;
+        assert!(second.exists());
+
+        assert!(TEMP_DIRS
+            .lock()
+            .map(|dirs| dirs.contains(&parent))
+            .unwrap_or_default());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn copy_stdin_file() -> Result<()> {
+        let dir = tempdir()?;
+        let file = dir.path().join("file");
+        fs::write(&file, "from file")?;
+        let mut stdin: Vec<u8> = vec![];
+        ContainerIO::copy_stdin_file(&file, &mut stdin).await?;
+        assert_eq!(stdin, b"from file");
+
+        let fifo = dir.path().join("fifo");
+        mkfifo(&fifo, Mode::S_IRUSR | Mode::S_IWUSR)?;
+        let writer = {
+            let fifo = fifo.clone();
+            std::thread::spawn(move || fs::write(fifo, "from fifo"))
+        };
+        let mut stdin: Vec<u8> = vec![];
+        ContainerIO::copy_stdin_file(&fifo, &mut stdin).await?;
+        writer
+            .join()
+            .map_err(|_| anyhow::format_err!("join writer"))??;
+        assert_eq!(stdin, b"from fifo");
+
+        assert!(
+            ContainerIO::copy_stdin_file(&dir.path().join("missing"), &mut Vec::<u8>::new())
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
 }
@@ -1,21 +1,39 @@
-use crate::{container_io::Pipe, cri_logger::CriLogger};
-use anyhow::Result;
+use crate::{
+    container_io::Pipe,
+    cri_logger::{CriLogger, LogClock, Timestamp, TimestampFormat},
+    log_quota::DiskUsage,
+    log_xattrs::LogXattrs,
+    pod_logger::PodLogger,
+    tee::Tee,
+};
+use anyhow::{Context, Result};
 use capnp::struct_list::Reader;
-use conmon_common::conmon_capnp::conmon::log_driver::{Owned, Type};
-use futures::future::join_all;
-use std::sync::Arc;
-use tokio::{io::AsyncBufRead, sync::RwLock};
+use conmon_common::conmon_capnp::conmon::log_driver::{
+    Owned, TimestampFormat as CapnpTimestampFormat, Type,
+};
+use futures::{future::join_all, FutureExt};
+use std::{
+    mem,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tokio::sync::RwLock;
+use tracing::warn;
 
 pub type SharedContainerLog = Arc<RwLock<ContainerLog>>;
 
 #[derive(Debug, Default)]
 pub struct ContainerLog {
     drivers: Vec<LogDriver>,
+    tee: Option<Tee>,
+    xattrs: Option<LogXattrs>,
+    clock: LogClock,
 }
 
 #[derive(Debug)]
 enum LogDriver {
     ContainerRuntimeInterface(CriLogger),
+    Pod(PodLogger),
 }
 
 impl ContainerLog {
@@ -24,35 +42,182 @@ impl ContainerLog {
         Arc::new(RwLock::new(Self::default()))
     }
 
-    /// Create a new SharedContainerLog from an capnp owned reader.
-    pub fn from(reader: Reader<Owned>) -> Result<SharedContainerLog> {
+    /// Create a new initialized SharedContainerLog with a single CRI logger writing to `path`.
+    pub async fn cri(path: &Path) -> Result<SharedContainerLog> {
+        let mut logger = Self {
+            drivers: vec![LogDriver::ContainerRuntimeInterface(CriLogger::new(
+                path, None,
+            )?)],
+            ..Default::default()
+        };
+        logger.init().await?;
+        Ok(Arc::new(RwLock::new(logger)))
+    }
+
+    /// Create a new SharedContainerLog from an capnp owned reader. The container ID is used to
+    /// tag the lines of aggregated pod logs.
+    pub fn from(reader: Reader<Owned>, container_id: &str) -> Result<SharedContainerLog> {
         let drivers = reader
             .iter()
-            .flat_map(|x| -> Result<_> {
+            .map(|x| -> Result<_> {
+                let max_log_size = if x.get_max_size() > 0 {
+                    Some(x.get_max_size() as usize)
+                } else {
+                    None
+                };
+                let timestamp = Timestamp::new(
+                    match x.get_timestamp_format()? {
+                        CapnpTimestampFormat::Rfc3339Nano => TimestampFormat::Rfc3339Nano,
+                        CapnpTimestampFormat::EpochNanos => TimestampFormat::EpochNanos,
+                        CapnpTimestampFormat::None => TimestampFormat::None,
+                    },
+                    x.get_timestamp_utc(),
+                );
                 Ok(match x.get_type()? {
                     Type::ContainerRuntimeInterface => {
-                        LogDriver::ContainerRuntimeInterface(CriLogger::new(
+                        let mut cri_logger = CriLogger::new(x.get_path()?, max_log_size)?;
+                        cri_logger.set_timestamp(timestamp);
+                        LogDriver::ContainerRuntimeInterface(cri_logger)
+                    }
+                    Type::Pod => {
+                        let mut pod_logger = PodLogger::new(
+                            x.get_pod_id()?,
+                            container_id,
                             x.get_path()?,
-                            if x.get_max_size() > 0 {
-                                Some(x.get_max_size() as usize)
-                            } else {
-                                None
-                            },
-                        )?)
+                            max_log_size,
+                        )?;
+                        pod_logger.set_timestamp(timestamp);
+                        LogDriver::Pod(pod_logger)
                     }
                 })
             })
-            .collect();
-        Ok(Arc::new(RwLock::new(Self { drivers })))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Arc::new(RwLock::new(Self {
+            drivers,
+            ..Default::default()
+        })))
+    }
+
+    /// Lower the maximum log size of all CRI loggers to `max_log_size`, which never raises the
+    /// limit of a driver. Pod logs are shared with other containers and therefore keep their
+    /// limit.
+    pub fn limit_max_log_size(&mut self, max_log_size: usize) {
+        for driver in self.drivers.iter_mut() {
+            if let LogDriver::ContainerRuntimeInterface(ref mut cri_logger) = driver {
+                let limit = cri_logger
+                    .max_log_size()
+                    .map_or(max_log_size, |limit| limit.min(max_log_size));
+                cri_logger.set_max_log_size(Some(limit));
+            }
+        }
     }
 
-    /// Asynchronously initialize all loggers.
+    /// Set the maximum on-disk bytes including rotations of all CRI logs. Pod logs are shared
+    /// with other containers and therefore not limited.
+    pub fn set_disk_quota(&mut self, disk_quota: Option<u64>) {
+        for driver in self.drivers.iter_mut() {
+            if let LogDriver::ContainerRuntimeInterface(ref mut cri_logger) = driver {
+                cri_logger.set_disk_quota(disk_quota);
+            }
+        }
+    }
+
+    /// The combined on-disk bytes of all CRI logs including their rotations.
+    pub fn disk_usage(&self) -> DiskUsage {
+        self.drivers
+            .iter()
+            .filter_map(|driver| match driver {
+                LogDriver::ContainerRuntimeInterface(cri_logger) => Some(cri_logger.disk_usage()),
+                LogDriver::Pod(_) => None,
+            })
+            .fold(DiskUsage::default(), DiskUsage::combine)
+    }
+
+    /// Tag the log files created by all drivers with the provided extended attributes. Pod logs
+    /// only get the attributes of the pod.
+    pub fn set_xattrs(&mut self, xattrs: LogXattrs) {
+        for driver in self.drivers.iter_mut() {
+            match driver {
+                LogDriver::ContainerRuntimeInterface(ref mut cri_logger) => {
+                    cri_logger.set_xattrs(Some(xattrs.clone()));
+                }
+                LogDriver::Pod(ref mut pod_logger) => pod_logger.set_xattrs(xattrs.for_pod()),
+            }
+        }
+        self.xattrs = Some(xattrs);
+    }
+
+    /// The extended attributes of the log files, if enabled.
+    pub fn xattrs(&self) -> Option<&LogXattrs> {
+        self.xattrs.as_ref()
+    }
+
+    /// Additionally write the CRI formatted output into the provided tee.
+    pub fn set_tee(&mut self, tee: Tee) {
+        self.tee = Some(tee);
+    }
+
+    /// Replace the log drivers by the ones of `other`, which have to be initialized already
+    /// except for the CRI logs written by a current driver. Those keep their current driver, so
+    /// that their content is retained. The current drivers get flushed before and are handed
+    /// over to `other`.
+    pub async fn replace_drivers(&mut self, other: &mut ContainerLog) -> Result<()> {
+        self.sync().await.context("sync replaced log drivers")?;
+        for driver in other.drivers.iter_mut() {
+            if let LogDriver::ContainerRuntimeInterface(new) = driver {
+                let current = self.drivers.iter_mut().find_map(|driver| match driver {
+                    LogDriver::ContainerRuntimeInterface(current)
+                        if current.path() == new.path() =>
+                    {
+                        Some(current)
+                    }
+                    _ => None,
+                });
+                if let Some(current) = current {
+                    mem::swap(current, new);
+                }
+            }
+        }
+        mem::swap(&mut self.drivers, &mut other.drivers);
+        Ok(())
+    }
+
+    /// The paths of all CRI logs.
+    pub fn cri_paths(&self) -> Vec<PathBuf> {
+        self.drivers
+            .iter()
+            .filter_map(|driver| match driver {
+                LogDriver::ContainerRuntimeInterface(cri_logger) => Some(cri_logger.path().clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Asynchronously initialize all loggers and the tee.
     pub async fn init(&mut self) -> Result<()> {
+        self.init_except(&[]).await
+    }
+
+    /// Asynchronously initialize all loggers and the tee, except for the CRI logs at `skip`,
+    /// which would be truncated otherwise.
+    pub async fn init_except(&mut self, skip: &[PathBuf]) -> Result<()> {
+        if let Some(tee) = self.tee.as_mut() {
+            tee.init().await?;
+        }
         join_all(
             self.drivers
                 .iter_mut()
+                .filter(|x| match x {
+                    LogDriver::ContainerRuntimeInterface(cri_logger) => {
+                        !skip.iter().any(|path| path == cri_logger.path())
+                    }
+                    LogDriver::Pod(_) => true,
+                })
                 .map(|x| match x {
-                    LogDriver::ContainerRuntimeInterface(ref mut cri_logger) => cri_logger.init(),
+                    LogDriver::ContainerRuntimeInterface(ref mut cri_logger) => {
+                        cri_logger.init().boxed()
+                    }
+                    LogDriver::Pod(ref mut pod_logger) => pod_logger.init().boxed(),
                 })
                 .collect::<Vec<_>>(),
         )
@@ -68,7 +233,10 @@ impl ContainerLog {
             self.drivers
                 .iter_mut()
                 .map(|x| match x {
-                    LogDriver::ContainerRuntimeInterface(ref mut cri_logger) => cri_logger.reopen(),
+                    LogDriver::ContainerRuntimeInterface(ref mut cri_logger) => {
+                        cri_logger.reopen().boxed()
+                    }
+                    LogDriver::Pod(ref mut pod_logger) => pod_logger.reopen().boxed(),
                 })
                 .collect::<Vec<_>>(),
         )
@@ -78,17 +246,106 @@ impl ContainerLog {
         Ok(())
     }
 
-    /// Write the contents of the provided reader into all loggers.
-    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<()>
-    where
-        T: AsyncBufRead + Unpin + Copy,
-    {
+    /// Flush all loggers and synchronize their files to disk.
+    pub async fn sync(&mut self) -> Result<()> {
         join_all(
             self.drivers
                 .iter_mut()
                 .map(|x| match x {
                     LogDriver::ContainerRuntimeInterface(ref mut cri_logger) => {
-                        cri_logger.write(pipe, bytes)
+                        cri_logger.sync().boxed()
+                    }
+                    LogDriver::Pod(ref mut pod_logger) => pod_logger.sync().boxed(),
+                })
+                .collect::<Vec<_>>(),
+        )
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+        Ok(())
+    }
+
+    /// Remove the log files of all loggers. Pod logs are shared with other containers and
+    /// therefore kept.
+    pub async fn remove(&mut self) -> Result<()> {
+        for driver in self.drivers.iter_mut() {
+            if let LogDriver::ContainerRuntimeInterface(ref mut cri_logger) = driver {
+                cri_logger.remove().await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the first CRI log file together with the offset behind which all lines
+    /// written at or after `since` are located, in nanoseconds since the UNIX epoch.
+    pub async fn offset_before(&self, since: u64) -> Result<Option<(PathBuf, u64)>> {
+        for driver in &self.drivers {
+            if let LogDriver::ContainerRuntimeInterface(cri_logger) = driver {
+                let offset = cri_logger.offset_before(since).await?;
+                return Ok(Some((cri_logger.path().clone(), offset)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Write the provided bytes into all loggers. The log lines get formatted only once for
+    /// every distinct timestamp configuration and tag, and are shared between the drivers.
+    pub async fn write(&mut self, pipe: Pipe, bytes: &[u8]) -> Result<()> {
+        // Both streams share the clock, so that the lines of the log are in order.
+        let now = self.clock.now()?;
+        let mut keys = vec![];
+        let mut formatted = vec![];
+        let mut indices = Vec::with_capacity(self.drivers.len());
+        for driver in &self.drivers {
+            let key = match driver {
+                LogDriver::ContainerRuntimeInterface(cri_logger) => (cri_logger.timestamp(), None),
+                LogDriver::Pod(pod_logger) => (pod_logger.timestamp(), Some(pod_logger.tag())),
+            };
+            let index = match keys.iter().position(|k| *k == key) {
+                Some(index) => index,
+                None => {
+                    let (timestamp, tag) = key;
+                    formatted.push(CriLogger::format_lines(
+                        pipe,
+                        bytes,
+                        tag,
+                        &timestamp.format(&now)?,
+                    ));
+                    keys.push(key);
+                    formatted.len() - 1
+                }
+            };
+            indices.push(index);
+        }
+        let tee_index = match self.tee {
+            Some(_) => {
+                let key = (Timestamp::default(), None);
+                Some(match keys.iter().position(|k| *k == key) {
+                    Some(index) => index,
+                    None => {
+                        formatted.push(CriLogger::format_lines(
+                            pipe,
+                            bytes,
+                            None,
+                            &key.0.format(&now)?,
+                        ));
+                        formatted.len() - 1
+                    }
+                })
+            }
+            None => None,
+        };
+
+        join_all(
+            self.drivers
+                .iter_mut()
+                .zip(indices)
+                .map(|(x, i)| match x {
+                    LogDriver::ContainerRuntimeInterface(ref mut cri_logger) => {
+                        cri_logger.write_lines(&formatted[i]).boxed()
+                    }
+                    LogDriver::Pod(ref mut pod_logger) => {
+                        pod_logger.write_lines(&formatted[i]).boxed()
                     }
                 })
                 .collect::<Vec<_>>(),
@@ -96,6 +353,92 @@ impl ContainerLog {
         .await
         .into_iter()
         .collect::<Result<Vec<_>>>()?;
+
+        // A vanished tee reader must not stop the logging, so the tee gets closed instead.
+        if let (Some(tee), Some(i)) = (self.tee.as_mut(), tee_index) {
+            if let Err(e) = tee.write_lines(&formatted[i]) {
+                warn!("Closing tee {}: {:#}", tee.path().display(), e);
+                tee.close();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use conmon_common::conmon_capnp::conmon::create_container_request;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn from_invalid_driver() -> Result<()> {
+        let mut message = capnp::message::Builder::new_default();
+        let mut req = message.init_root::<create_container_request::Builder>();
+        let mut drivers = req.reborrow().init_log_drivers(2);
+        let mut cri = drivers.reborrow().get(0);
+        cri.set_type(Type::ContainerRuntimeInterface);
+        cri.set_path("/log");
+
+        // A pod log driver without a pod ID fails, which must not get dropped silently
+        let mut pod = drivers.get(1);
+        pod.set_type(Type::Pod);
+        pod.set_path("/pod.log");
+        assert!(ContainerLog::from(req.into_reader().get_log_drivers()?, "id").is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replace_drivers() -> Result<()> {
+        let dir = tempdir()?;
+        let old_path = dir.path().join("old");
+        let new_path = dir.path().join("new");
+        let sut = ContainerLog::cri(&old_path).await?;
+        sut.write().await.write(Pipe::StdOut, b"old\n").await?;
+
+        let new_logger = ContainerLog::cri(&new_path).await?;
+        sut.write()
+            .await
+            .replace_drivers(&mut *new_logger.write().await)
+            .await?;
+        sut.write().await.write(Pipe::StdOut, b"new\n").await?;
+        sut.write().await.sync().await?;
+
+        let old = fs::read_to_string(&old_path)?;
+        let new = fs::read_to_string(&new_path)?;
+        assert!(old.ends_with(" stdout F old\n"));
+        assert!(new.ends_with(" stdout F new\n"));
+        assert!(!new.contains("old"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replace_drivers_same_path() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("log");
+        let sut = ContainerLog::cri(&path).await?;
+        sut.write().await.write(Pipe::StdOut, b"old\n").await?;
+        sut.write().await.sync().await?;
+
+        let mut new_logger = ContainerLog {
+            drivers: vec![LogDriver::ContainerRuntimeInterface(CriLogger::new(
+                &path, None,
+            )?)],
+            ..Default::default()
+        };
+        let paths = sut.read().await.cri_paths();
+        assert_eq!(paths, vec![path.clone()]);
+        new_logger.init_except(&paths).await?;
+        assert!(fs::read_to_string(&path)?.ends_with(" stdout F old\n"));
+
+        sut.write().await.replace_drivers(&mut new_logger).await?;
+        sut.write().await.write(Pipe::StdOut, b"new\n").await?;
+        sut.write().await.sync().await?;
+
+        let content = fs::read_to_string(&path)?;
+        assert!(content.contains(" stdout F old\n"));
+        assert!(content.ends_with(" stdout F new\n"));
         Ok(())
     }
 }
@@ -0,0 +1,248 @@
+//! Best-effort exit records for still running containers on abnormal server termination.
+//!
+//! The exit paths of all monitored processes get registered up front, so that fatal signal
+//! handlers and the panic hook only have to create the files without allocating or blocking.
+//! The written `MONITOR_DIED_EXIT_CODE` allows engines to distinguish a container which may
+//! still run unmonitored from one which exited with an unknown code. Already existing exit
+//! files are never overwritten.
+//!
+//! SIGTERM terminates the server the same way as long as it does not start an orderly shutdown,
+//! like during startup or in modes without a shutdown sequence. The orderly shutdown writes the
+//! exit records itself once the containers got stopped.
+
+use anyhow::{Context, Result};
+use lazy_static::lazy_static;
+use libc::{c_int, c_void, stack_t, O_CLOEXEC, O_CREAT, O_EXCL, O_WRONLY, SS_DISABLE};
+use nix::sys::signal::{sigaction, SaFlags, SigAction, SigHandler, SigSet, Signal};
+use std::{
+    collections::HashMap,
+    ffi::CString,
+    os::unix::ffi::OsStrExt,
+    path::PathBuf,
+    ptr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+use tracing::{debug, warn};
+
+/// The exit code written if the server terminated while the process was still running.
+pub const MONITOR_DIED_EXIT_CODE: i32 = -4;
+
+/// Content of the exit files, which has to match `MONITOR_DIED_EXIT_CODE`. It is kept as
+/// constant to avoid formatting within signal handlers.
+const MONITOR_DIED_EXIT_RECORD: &[u8] = b"-4";
+
+/// Size of the alternate signal stack, which allows handling SIGSEGV on stack overflows.
+const ALT_STACK_SIZE: usize = 64 * 1024;
+
+/// Signals which terminate the server and trigger writing the exit records.
+const FATAL_SIGNALS: [Signal; 5] = [
+    Signal::SIGSEGV,
+    Signal::SIGBUS,
+    Signal::SIGILL,
+    Signal::SIGFPE,
+    Signal::SIGABRT,
+];
+
+/// Set once SIGTERM starts an orderly shutdown, after which the SIGTERM handler does nothing.
+static ORDERLY_SIGTERM: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    /// The exit paths of all running processes by their PID.
+    static ref EXIT_RECORDS: Mutex<HashMap<u32, Vec<CString>>> = Mutex::new(HashMap::new());
+}
+
+/// Register the exit paths of the running process `pid`. Paths containing a NUL byte are
+/// skipped, because they cannot be created anyway.
+pub fn register(pid: u32, exit_paths: &[PathBuf]) {
+    let paths: Vec<CString> = exit_paths
+        .iter()
+        .filter_map(|p| CString::new(p.as_os_str().as_bytes()).ok())
+        .collect();
+    if paths.is_empty() {
+        return;
+    }
+    match EXIT_RECORDS.lock() {
+        Ok(mut records) => {
+            records.insert(pid, paths);
+        }
+        Err(e) => warn!("Unable to register exit records of PID {}: {:#}", pid, e),
+    }
+}
+
+/// Unregister the exit paths of `pid`, usually because its real exit code got written.
+pub fn unregister(pid: u32) {
+    match EXIT_RECORDS.lock() {
+        Ok(mut records) => {
+            records.remove(&pid);
+        }
+        Err(e) => warn!("Unable to unregister exit records of PID {}: {:#}", pid, e),
+    }
+}
+
+/// Write the exit records of all still registered processes and return the number of created
+/// files. Only async-signal-safe functions are used, which means that nothing gets written if
+/// the registry is locked at the same time.
+pub fn write_exit_records() -> usize {
+    match EXIT_RECORDS.try_lock() {
+        Ok(records) => write_records(&records),
+        Err(_) => 0,
+    }
+}
+
+fn write_records(records: &HashMap<u32, Vec<CString>>) -> usize {
+    records
+        .values()
+        .flatten()
+        .filter(|path| unsafe {
+            let fd = libc::open(
+                path.as_ptr(),
+                O_WRONLY | O_CREAT | O_EXCL | O_CLOEXEC,
+                0o644,
+            );
+            if fd < 0 {
+                return false;
+            }
+            let len = MONITOR_DIED_EXIT_RECORD.len();
+            let written = libc::write(fd, MONITOR_DIED_EXIT_RECORD.as_ptr() as *const c_void, len);
+            libc::close(fd);
+            written == len as isize
+        })
+        .count()
+}
+
+/// Mark that SIGTERM starts an orderly shutdown from now on, which writes the exit records
+/// itself.
+pub fn set_orderly_sigterm() {
+    ORDERLY_SIGTERM.store(true, Ordering::SeqCst);
+}
+
+/// Install the handlers of the `FATAL_SIGNALS` and SIGTERM. They run on an alternate signal
+/// stack, which gets set up for the calling thread unless one is configured already. The
+/// standard library provides one for all other threads it spawns.
+///
+/// This replaces the stack overflow message of the standard library.
+pub fn install_signal_handlers() -> Result<()> {
+    // The handlers must not run the lazy initialization of the registry.
+    lazy_static::initialize(&EXIT_RECORDS);
+    ensure_alt_stack().context("set up alternate signal stack")?;
+    let action = SigAction::new(
+        SigHandler::Handler(handle_fatal_signal),
+        SaFlags::SA_ONSTACK | SaFlags::SA_RESETHAND,
+        SigSet::empty(),
+    );
+    for signal in FATAL_SIGNALS {
+        unsafe { sigaction(signal, &action) }
+            .with_context(|| format!("install {} handler", signal))?;
+    }
+
+    // Handlers registered for SIGTERM later on chain to this one.
+    let action = SigAction::new(
+        SigHandler::Handler(handle_sigterm),
+        SaFlags::SA_ONSTACK | SaFlags::SA_RESTART,
+        SigSet::empty(),
+    );
+    unsafe { sigaction(Signal::SIGTERM, &action) }.context("install SIGTERM handler")?;
+    debug!("Installed handlers for fatal signals");
+    Ok(())
+}
+
+fn ensure_alt_stack() -> Result<()> {
+    let mut current: stack_t = unsafe { std::mem::zeroed() };
+    if unsafe { libc::sigaltstack(ptr::null(), &mut current) } != 0 {
+        return Err(std::io::Error::last_os_error()).context("get current alternate stack");
+    }
+    if current.ss_flags & SS_DISABLE == 0 {
+        return Ok(());
+    }
+
+    // The stack has to live as long as the thread, which is the whole process for the main
+    // thread.
+    let stack = Box::leak(vec![0u8; ALT_STACK_SIZE].into_boxed_slice());
+    let new = stack_t {
+        ss_sp: stack.as_mut_ptr() as *mut c_void,
+        ss_flags: 0,
+        ss_size: ALT_STACK_SIZE,
+    };
+    if unsafe { libc::sigaltstack(&new, ptr::null_mut()) } != 0 {
+        return Err(std::io::Error::last_os_error()).context("set alternate stack");
+    }
+    Ok(())
+}
+
+extern "C" fn handle_fatal_signal(signal: c_int) {
+    write_exit_records();
+    // The default action got restored by SA_RESETHAND, so raising the signal again terminates
+    // the process once the handler returns, even if it was not caused by a fault.
+    unsafe { libc::raise(signal) };
+}
+
+extern "C" fn handle_sigterm(signal: c_int) {
+    if ORDERLY_SIGTERM.load(Ordering::SeqCst) {
+        return;
+    }
+    write_exit_records();
+    unsafe {
+        libc::signal(signal, libc::SIG_DFL);
+        libc::raise(signal);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, path::Path};
+    use tempfile::tempdir;
+
+    fn path(path: &Path) -> Result<CString> {
+        Ok(CString::new(path.as_os_str().as_bytes())?)
+    }
+
+    fn registered(pid: u32) -> bool {
+        EXIT_RECORDS
+            .lock()
+            .map(|records| records.contains_key(&pid))
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn exit_record() {
+        assert_eq!(
+            MONITOR_DIED_EXIT_RECORD,
+            MONITOR_DIED_EXIT_CODE.to_string().as_bytes()
+        );
+    }
+
+    #[test]
+    fn register() {
+        // Use a PID which cannot exist to not interfere with other tests
+        let pid = u32::MAX;
+        super::register(pid, &[]);
+        assert!(!registered(pid));
+
+        super::register(pid, &["exit".into()]);
+        assert!(registered(pid));
+
+        unregister(pid);
+        assert!(!registered(pid));
+    }
+
+    #[test]
+    fn write_records() -> Result<()> {
+        let dir = tempdir()?;
+        let running = dir.path().join("running");
+        let exited = dir.path().join("exited");
+        fs::write(&exited, "0")?;
+
+        let mut records = HashMap::new();
+        records.insert(1, vec![path(&running)?, path(&exited)?]);
+        records.insert(2, vec![path(&dir.path().join("missing/exit"))?]);
+
+        assert_eq!(super::write_records(&records), 1);
+        assert_eq!(fs::read_to_string(&running)?, "-4");
+        assert_eq!(fs::read_to_string(&exited)?, "0");
+        Ok(())
+    }
+}
@@ -1,24 +1,106 @@
 //! File logging functionalities.
 
-use crate::container_io::Pipe;
+use crate::{
+    container_io::Pipe,
+    log_index::LogIndex,
+    log_quota::{self, DiskUsage},
+    log_xattrs::LogXattrs,
+};
 use anyhow::{Context, Result};
 use getset::{CopyGetters, Getters, Setters};
 use memchr::memchr;
-use std::{
-    marker::Unpin,
-    path::{Path, PathBuf},
-};
+use nix::time::{clock_gettime, ClockId};
+use std::path::{Path, PathBuf};
 use tokio::{
-    fs::{File, OpenOptions},
-    io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter},
+    fs::{self, File, OpenOptions},
+    io::{AsyncWriteExt, BufWriter, ErrorKind},
 };
-use tracing::{debug, trace};
-use tz::{DateTime, TimeZone};
+use tracing::{debug, trace, warn};
+use tz::{TimeZone, UtcDateTime};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Available formats of the timestamp prefixing every log line.
+pub enum TimestampFormat {
+    /// RFC3339 with nanoseconds, like `2022-08-16T15:04:05.123456789+02:00`.
+    Rfc3339Nano,
+
+    /// Nanoseconds since the UNIX epoch.
+    EpochNanos,
+
+    /// No timestamp at all, the lines start with the pipe name.
+    None,
+}
+
+impl Default for TimestampFormat {
+    fn default() -> Self {
+        Self::Rfc3339Nano
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// The timestamp configuration of a log driver.
+pub struct Timestamp {
+    /// Format of the timestamp.
+    format: TimestampFormat,
+
+    /// Use UTC instead of the local timezone for RFC3339 timestamps.
+    utc: bool,
+}
+
+impl Timestamp {
+    /// Create a new timestamp configuration.
+    pub fn new(format: TimestampFormat, utc: bool) -> Self {
+        Self { format, utc }
+    }
+
+    /// Format the provided point in time, which results in an empty string for
+    /// `TimestampFormat::None`.
+    pub fn format(&self, time: &UtcDateTime) -> Result<String> {
+        Ok(match self.format {
+            TimestampFormat::Rfc3339Nano if self.utc => time.to_string(),
+            TimestampFormat::Rfc3339Nano => {
+                let local_tz = TimeZone::local().context("get local timezone")?;
+                time.project(local_tz.as_ref())
+                    .context("get local datetime")?
+                    .to_string()
+            }
+            TimestampFormat::EpochNanos => (i128::from(time.unix_time()) * 1_000_000_000
+                + i128::from(time.nanoseconds()))
+            .to_string(),
+            TimestampFormat::None => String::new(),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+/// Source of the log timestamps, which never goes backwards. Kubelet misbehaves on timestamps
+/// which decrease, like after the wall clock got stepped back by NTP or a leap second.
+pub struct LogClock {
+    /// The last returned point in time.
+    last: Option<UtcDateTime>,
+}
+
+impl LogClock {
+    /// The current time of the coarse real time clock, which is cheap enough to be read for
+    /// every batch of lines. Times before the last returned one are replaced by it.
+    pub fn now(&mut self) -> Result<UtcDateTime> {
+        let time = clock_gettime(ClockId::CLOCK_REALTIME_COARSE).context("get current time")?;
+        let now = UtcDateTime::from_timespec(time.tv_sec(), time.tv_nsec() as u32)
+            .context("convert current time")?;
+        Ok(self.advance(now))
+    }
+
+    fn advance(&mut self, now: UtcDateTime) -> UtcDateTime {
+        let now = self.last.map_or(now, |last| last.max(now));
+        self.last = Some(now);
+        now
+    }
+}
 
 #[derive(Debug, CopyGetters, Getters, Setters)]
 /// The main structure used for container log handling.
 pub struct CriLogger {
-    #[getset(get)]
+    #[getset(get = "pub")]
     /// Path to the file on disk.
     path: PathBuf,
 
@@ -26,13 +108,37 @@ pub struct CriLogger {
     /// Open file handle of the `path`.
     file: Option<BufWriter<File>>,
 
-    #[getset(get_copy)]
+    #[getset(get_copy = "pub", set = "pub")]
     /// Maximum allowed log size in bytes.
     max_log_size: Option<usize>,
 
     #[getset(get_copy, set)]
     /// Current bytes written to the log file.
     bytes_written: usize,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Timestamp configuration of the log lines.
+    timestamp: Timestamp,
+
+    /// Sidecar index of the log file.
+    index: LogIndex,
+
+    #[getset(set = "pub")]
+    /// Extended attributes set on every (re)created log file.
+    xattrs: Option<LogXattrs>,
+
+    #[getset(set = "pub")]
+    /// Maximum on-disk bytes of the log including its rotations.
+    disk_quota: Option<u64>,
+
+    /// On-disk bytes of the rotations and the index on the last initialization.
+    rotated_bytes: u64,
+
+    /// Bytes of the rotations removed because of the disk quota.
+    pruned_bytes: u64,
+
+    /// Source of the timestamps of lines written by the logger itself.
+    clock: LogClock,
 }
 
 impl CriLogger {
@@ -45,46 +151,114 @@ impl CriLogger {
             file: None,
             max_log_size,
             bytes_written: 0,
+            timestamp: Timestamp::default(),
+            index: LogIndex::new(path.as_ref()),
+            xattrs: None,
+            disk_quota: None,
+            rotated_bytes: 0,
+            pruned_bytes: 0,
+            clock: LogClock::default(),
         })
     }
 
     /// Asynchronously initialize the CRI logger.
     pub async fn init(&mut self) -> Result<()> {
         debug!("Initializing CRI logger in path {}", self.path().display());
-        self.set_file(Self::open(self.path()).await?.into());
+        let file = Self::open(self.path()).await?;
+        if let Some(xattrs) = &self.xattrs {
+            // Not all filesystems support user extended attributes
+            if let Err(e) = xattrs.apply(file.get_ref()) {
+                warn!("Unable to tag log file {}: {:#}", self.path().display(), e);
+            }
+        }
+        self.set_file(file.into());
+        self.set_bytes_written(0);
+        self.index.init().await?;
+
+        // The log got truncated, which means that only the rotations are left
+        match log_quota::enforce(self.path(), self.disk_quota).await {
+            Ok(usage) => {
+                self.rotated_bytes = usage.total_bytes();
+                self.pruned_bytes += usage.pruned_bytes();
+            }
+            Err(e) => warn!(
+                "Unable to enforce disk quota of log {}: {:#}",
+                self.path().display(),
+                e
+            ),
+        }
         Ok(())
     }
 
-    /// Write the contents of the provided reader into the file logger.
-    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<()>
-    where
-        T: AsyncBufRead + Unpin,
-    {
-        let mut reader = BufReader::new(bytes);
-
-        // Get the RFC3339 timestmap
-        let local_tz = TimeZone::local().context("get local timezone")?;
-        let timestamp = DateTime::now(local_tz.as_ref())
-            .context("get local datetime")?
-            .to_string();
-        let min_log_len = timestamp
-            .len()
-            .checked_add(10) // len of " stdout " + "P "
-            .context("min log line len exceeds usize")?;
-
-        loop {
-            // Read the line
-            let mut line_buf = Vec::with_capacity(min_log_len);
-            let (read, partial) = Self::read_line(&mut reader, &mut line_buf).await?;
-
-            if read == 0 {
-                break;
+    /// The on-disk bytes of the log including its rotations, where the growth of the index
+    /// since the last initialization is not taken into account.
+    pub fn disk_usage(&self) -> DiskUsage {
+        DiskUsage::new(
+            self.rotated_bytes + self.bytes_written() as u64,
+            self.pruned_bytes,
+        )
+    }
+
+    /// Write the provided bytes into the file logger.
+    pub async fn write(&mut self, pipe: Pipe, bytes: &[u8]) -> Result<()> {
+        let now = self.clock.now()?;
+        let lines = Self::format_lines(pipe, bytes, None, &self.timestamp().format(&now)?);
+        self.write_lines(&lines).await
+    }
+
+    /// Format the provided bytes into CRI log lines. The result can be shared between multiple
+    /// loggers to avoid formatting the same data more than once. The optional tag gets inserted
+    /// in front of the contents of every line. An empty timestamp is omitted.
+    pub fn format_lines(
+        pipe: Pipe,
+        bytes: &[u8],
+        tag: Option<&str>,
+        timestamp: &str,
+    ) -> Vec<Vec<u8>> {
+        let prefix = if timestamp.is_empty() {
+            format!("{} ", pipe)
+        } else {
+            format!("{} {} ", timestamp, pipe)
+        };
+        let tag = tag.map(|t| format!("{} ", t)).unwrap_or_default();
+
+        let mut lines = vec![];
+        let mut rest = bytes;
+        while !rest.is_empty() {
+            let (line, partial) = match memchr(b'\n', rest) {
+                Some(i) => (&rest[..=i], false),
+                None => (rest, true),
+            };
+            rest = &rest[line.len()..];
+
+            // prefix + "P " + tag + line + the added newline for partial lines
+            let mut buf = Vec::with_capacity(prefix.len() + tag.len() + line.len() + 3);
+            buf.extend_from_slice(prefix.as_bytes());
+
+            // Output log tag for partial or newline
+            if partial {
+                buf.extend_from_slice(b"P ");
+            } else {
+                buf.extend_from_slice(b"F ");
             }
+            buf.extend_from_slice(tag.as_bytes());
+
+            // Output the actual contents
+            buf.extend_from_slice(line);
 
-            let mut bytes_to_be_written = read + min_log_len;
+            // Output a newline for partial
             if partial {
-                bytes_to_be_written += 1; // the added newline
+                buf.push(b'\n');
             }
+            lines.push(buf);
+        }
+        lines
+    }
+
+    /// Write already formatted log lines into the file logger.
+    pub async fn write_lines(&mut self, lines: &[Vec<u8>]) -> Result<()> {
+        for line in lines {
+            let bytes_to_be_written = line.len();
 
             let mut new_bytes_written = match self.bytes_written().checked_add(bytes_to_be_written)
             {
@@ -111,36 +285,24 @@ impl CriLogger {
                 }
             }
 
-            // Write the timestmap
-            let file = self.file.as_mut().context(Self::ERR_UNINITIALIZED)?;
-            file.write_all(timestamp.as_bytes()).await?;
-
-            // Add the pipe name
-            match pipe {
-                Pipe::StdOut => file.write_all(b" stdout ").await,
-                Pipe::StdErr => file.write_all(b" stderr ").await,
-            }?;
-
-            // Output log tag for partial or newline
-            if partial {
-                file.write_all(b"P ").await?;
-            } else {
-                file.write_all(b"F ").await?;
-            }
-
-            // Output the actual contents
-            file.write_all(&line_buf).await?;
-
-            // Output a newline for partial
-            if partial {
-                file.write_all(b"\n").await?;
-            }
+            self.file
+                .as_mut()
+                .context(Self::ERR_UNINITIALIZED)?
+                .write_all(line)
+                .await?;
 
             self.set_bytes_written(new_bytes_written);
+            self.index.advance(bytes_to_be_written);
             trace!("Wrote log line of length {}", bytes_to_be_written);
         }
 
-        self.flush().await
+        self.flush().await?;
+        self.index.checkpoint().await
+    }
+
+    /// Returns true if the log file got already opened.
+    pub fn is_initialized(&self) -> bool {
+        self.file.is_some()
     }
 
     /// Reopen the container log file.
@@ -165,6 +327,36 @@ impl CriLogger {
             .context("flush file writer")
     }
 
+    /// Flush the log file and synchronize its contents to disk.
+    pub async fn sync(&mut self) -> Result<()> {
+        self.flush().await?;
+        self.file
+            .as_mut()
+            .context(Self::ERR_UNINITIALIZED)?
+            .get_ref()
+            .sync_all()
+            .await
+            .context("sync log file")
+    }
+
+    /// Close the log file and remove it from disk. A missing file is not considered an error.
+    pub async fn remove(&mut self) -> Result<()> {
+        debug!("Removing container log {}", self.path().display());
+        self.file = None;
+        match fs::remove_file(self.path()).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => {
+                Err(e).context(format!("remove log file '{}'", self.path().display()))
+            }
+            _ => self.index.remove().await,
+        }
+    }
+
+    /// Returns the offset of the log file behind which all lines written at or after `since`
+    /// are located, in nanoseconds since the UNIX epoch.
+    pub async fn offset_before(&self, since: u64) -> Result<u64> {
+        LogIndex::offset_before(self.path(), since).await
+    }
+
     /// Open the provided path with the default options.
     async fn open<T: AsRef<Path>>(path: T) -> Result<BufWriter<File>> {
         Ok(BufWriter::new(
@@ -179,27 +371,6 @@ impl CriLogger {
                 .context(format!("open log file path '{}'", path.as_ref().display()))?,
         ))
     }
-
-    async fn read_line<T>(r: &mut BufReader<T>, buf: &mut Vec<u8>) -> Result<(usize, bool)>
-    where
-        T: AsyncBufRead + Unpin,
-    {
-        let (partial, read) = {
-            let available = r.fill_buf().await?;
-            match memchr(b'\n', available) {
-                Some(i) => {
-                    buf.extend_from_slice(&available[..=i]);
-                    (false, i + 1)
-                }
-                None => {
-                    buf.extend_from_slice(available);
-                    (true, available.len())
-                }
-            }
-        };
-        r.consume(read);
-        Ok((read, partial))
-    }
 }
 
 #[cfg(test)]
@@ -295,10 +466,145 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn format_lines_partial() -> Result<()> {
+        let lines = CriLogger::format_lines(Pipe::StdErr, b"a\nb", None, "ts");
+        assert_eq!(lines.len(), 2);
+
+        let first = String::from_utf8(lines[0].clone())?;
+        assert!(first.ends_with(" stderr F a\n"));
+
+        let second = String::from_utf8(lines[1].clone())?;
+        assert!(second.ends_with(" stderr P b\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn format_lines_tagged() -> Result<()> {
+        let lines = CriLogger::format_lines(Pipe::StdOut, b"a\nb", Some("ctr"), "ts");
+        assert_eq!(lines.len(), 2);
+
+        let first = String::from_utf8(lines[0].clone())?;
+        assert!(first.ends_with(" stdout F ctr a\n"));
+
+        let second = String::from_utf8(lines[1].clone())?;
+        assert!(second.ends_with(" stdout P ctr b\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn format_lines_without_timestamp() {
+        let lines = CriLogger::format_lines(Pipe::StdOut, b"a\n", None, "");
+        assert_eq!(lines, vec![b"stdout F a\n".to_vec()]);
+    }
+
+    #[test]
+    fn timestamp_formats() -> Result<()> {
+        let time = UtcDateTime::from_timespec(1_660_000_000, 123)?;
+        assert_eq!(
+            Timestamp::new(TimestampFormat::Rfc3339Nano, true).format(&time)?,
+            "2022-08-08T23:06:40.000000123Z"
+        );
+        assert_eq!(
+            Timestamp::new(TimestampFormat::EpochNanos, false).format(&time)?,
+            "1660000000000000123"
+        );
+        assert!(Timestamp::new(TimestampFormat::None, false)
+            .format(&time)?
+            .is_empty());
+
+        let local = Timestamp::default().format(&time)?;
+        let parsed = OffsetDateTime::parse(&local, &Rfc3339)?;
+        assert_eq!(parsed.unix_timestamp_nanos(), 1_660_000_000_000_000_123);
+        Ok(())
+    }
+
+    #[test]
+    fn log_clock_never_goes_backwards() -> Result<()> {
+        let mut sut = LogClock::default();
+        let earlier = UtcDateTime::from_timespec(1_660_000_000, 0)?;
+        let later = UtcDateTime::from_timespec(1_660_000_001, 0)?;
+        assert_eq!(sut.advance(later), later);
+        assert_eq!(sut.advance(earlier), later);
+
+        let now = sut.now()?;
+        assert!(now > later);
+        assert!(sut.now()? >= now);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_lines_shared() -> Result<()> {
+        let file1 = NamedTempFile::new()?;
+        let file2 = NamedTempFile::new()?;
+        let mut sut1 = CriLogger::new(file1.path(), None)?;
+        let mut sut2 = CriLogger::new(file2.path(), None)?;
+        sut1.init().await?;
+        sut2.init().await?;
+
+        let lines = CriLogger::format_lines(Pipe::StdOut, b"a\nb\n", None, "ts");
+        sut1.write_lines(&lines).await?;
+        sut2.write_lines(&lines).await?;
+
+        let res1 = fs::read_to_string(file1.path())?;
+        assert!(res1.contains(" stdout F a"));
+        assert!(res1.contains(" stdout F b"));
+        assert_eq!(res1, fs::read_to_string(file2.path())?);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn init_failure() -> Result<()> {
         let mut sut = CriLogger::new("/file/does/not/exist", None)?;
         assert!(sut.init().await.is_err());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn sync() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut sut = CriLogger::new(file.path(), None)?;
+        assert!(sut.sync().await.is_err());
+
+        sut.init().await?;
+        sut.write(Pipe::StdOut, b"a\n").await?;
+        sut.sync().await?;
+        assert!(fs::read_to_string(file.path())?.ends_with(" stdout F a\n"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn remove() -> Result<()> {
+        let path = NamedTempFile::new()?.into_temp_path().keep()?;
+        let mut sut = CriLogger::new(&path, None)?;
+        sut.init().await?;
+        sut.write(Pipe::StdOut, b"a\n").await?;
+
+        sut.remove().await?;
+        assert!(!path.exists());
+        assert!(!sut.is_initialized());
+
+        // Removing twice is fine
+        sut.remove().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn index() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("log");
+        let mut sut = CriLogger::new(&path, None)?;
+        sut.init().await?;
+        sut.write(Pipe::StdOut, b"a\n").await?;
+        assert_eq!(sut.offset_before(u64::MAX).await?, 0);
+
+        sut.write(Pipe::StdOut, &vec![b'a'; LogIndex::INTERVAL as usize])
+            .await?;
+        let size = fs::metadata(&path)?.len();
+        assert_eq!(sut.offset_before(u64::MAX).await?, size);
+
+        sut.remove().await?;
+        assert!(!LogIndex::path_for(&path).exists());
+        Ok(())
+    }
 }
@@ -0,0 +1,128 @@
+//! Checkpoint and restore of containers by CRIU, which gets driven by the runtime.
+//!
+//! CRIU prefixes every line of its logs with the elapsed time and marks problems with their
+//! level, like `(00.011431) Error (criu/cr-dump.c:1787): Dumping FAILED.`. The full logs are
+//! too verbose to be returned, so only the problems and the last lines get reported.
+
+use getset::{CopyGetters, Getters};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// Name of the log which CRIU writes into the work path while dumping.
+pub const DUMP_LOG: &str = "dump.log";
+
+/// Name of the log which CRIU writes into the work path while restoring.
+pub const RESTORE_LOG: &str = "restore.log";
+
+#[derive(Clone, CopyGetters, Debug, Eq, Getters, PartialEq)]
+/// Options to restore a container from a checkpoint instead of creating it.
+pub struct Restore {
+    #[getset(get = "pub")]
+    /// Directory of the checkpoint image.
+    image_path: PathBuf,
+
+    #[getset(get = "pub")]
+    /// Directory for the CRIU logs.
+    work_path: PathBuf,
+
+    #[getset(get_copy = "pub")]
+    /// Restore established TCP connections.
+    tcp_established: bool,
+}
+
+impl Restore {
+    /// Create new restore options. The work path defaults to the image path if not provided.
+    pub fn new(image_path: PathBuf, work_path: Option<PathBuf>, tcp_established: bool) -> Self {
+        Self {
+            work_path: work_path.unwrap_or_else(|| image_path.clone()),
+            image_path,
+            tcp_established,
+        }
+    }
+}
+
+/// Maximum amount of lines of an excerpt.
+const MAX_LINES: usize = 32;
+
+/// Amount of trailing lines of the log which are always part of an excerpt.
+const TAIL_LINES: usize = 8;
+
+/// Excerpt of the CRIU log `content`: all error and warning lines followed by the last lines
+/// of the log, limited to `MAX_LINES` in total.
+pub fn excerpt(content: &str) -> Vec<String> {
+    let lines: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
+    let tail_start = lines.len().saturating_sub(TAIL_LINES);
+
+    let mut res: Vec<String> = lines[..tail_start]
+        .iter()
+        .filter(|line| is_problem(line))
+        .map(|line| line.to_string())
+        .collect();
+    // Keep the latest problems if there are too many of them.
+    let max_problems = MAX_LINES - (lines.len() - tail_start);
+    if res.len() > max_problems {
+        res.drain(..res.len() - max_problems);
+    }
+    res.extend(lines[tail_start..].iter().map(|line| line.to_string()));
+    res
+}
+
+/// Read the excerpt of the CRIU log at `path`, which is empty if CRIU did not write the log.
+pub async fn log_excerpt(path: &Path) -> Vec<String> {
+    fs::read_to_string(path)
+        .await
+        .map(|content| excerpt(&content))
+        .unwrap_or_default()
+}
+
+/// Attach the excerpt of the CRIU log at `path` as causes to `err`.
+pub async fn attach_excerpt(mut err: anyhow::Error, path: &Path) -> anyhow::Error {
+    for line in log_excerpt(path).await {
+        err = err.context(format!("criu: {}", line));
+    }
+    err
+}
+
+/// Whether the line got logged with the error or warning level.
+fn is_problem(line: &str) -> bool {
+    let message = match line.trim_start().strip_prefix('(') {
+        Some(rest) => rest.split_once(')').map_or(rest, |(_, msg)| msg),
+        None => line,
+    };
+    let message = message.trim_start();
+    message.starts_with("Error") || message.starts_with("Warn")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn excerpt_problems_and_tail() {
+        let mut log = String::from(
+            "(00.000010) Version: 3.17\n\
+             (00.000200) Warn  (criu/kerndat.c:1103): Can't load /run/criu.kdat\n\
+             (00.000300) Error (criu/sk-inet.c:188): inet: Connected TCP socket, consider using --tcp-established option.\n",
+        );
+        for i in 0..20 {
+            log.push_str(&format!("(00.{:06}) Dumping {}\n", 1000 + i, i));
+        }
+
+        let res = excerpt(&log);
+        assert_eq!(res.len(), 2 + TAIL_LINES);
+        assert!(res[0].contains("Warn"));
+        assert!(res[1].contains("--tcp-established"));
+        assert_eq!(
+            res.last().map(String::as_str),
+            Some("(00.001019) Dumping 19")
+        );
+    }
+
+    #[test]
+    fn excerpt_limit() {
+        let log = "(00.000001) Error (criu/cr-dump.c:1): failed\n".repeat(100);
+        assert_eq!(excerpt(&log).len(), MAX_LINES);
+        assert!(excerpt("").is_empty());
+        assert_eq!(excerpt("short\nlog\n"), vec!["short", "log"]);
+    }
+}
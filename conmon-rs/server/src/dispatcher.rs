@@ -0,0 +1,506 @@
+//! Dispatching of connections to dedicated server instances per pod.
+//!
+//! The dispatcher listens on the socket of the server and reads the pod ID from every accepted
+//! connection, which the client sends followed by a newline before the RPC stream. Every pod is
+//! served by its own instance of the server, which gets started on the first connection of the
+//! pod and keeps its files in a subdirectory of the runtime directory. A failing instance
+//! therefore only affects the containers of its own pod.
+//!
+//! The instances inherit one end of a socketpair as stdin, over which the dispatcher passes the
+//! connections by SCM_RIGHTS. The dispatcher records the PID of every instance in the pidfile of
+//! its runtime directory and stops all instances on shutdown.
+//!
+//! Instances without containers and connections report themselves as idle over the same
+//! socketpair after `IDLE_TIMEOUT`. The dispatcher releases them by closing the socketpair, and
+//! they shut down once all connections passed before got served. At most `MAX_INSTANCES`
+//! instances run at the same time.
+
+use crate::{
+    config::{Config, PIDFILE},
+    fd_inventory, helper, listener,
+};
+use anyhow::{bail, Context, Result};
+use nix::{
+    fcntl::{fcntl, FcntlArg},
+    libc::STDIN_FILENO,
+    sys::signal::{kill, Signal},
+    unistd::{dup2, Pid},
+};
+use sendfd::SendWithFd;
+use std::{
+    collections::HashMap,
+    env,
+    ffi::OsString,
+    fs::{self, OpenOptions},
+    io::ErrorKind,
+    os::unix::{
+        io::{AsRawFd, FromRawFd, IntoRawFd, RawFd},
+        net::UnixStream as StdUnixStream,
+    },
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncReadExt, Interest},
+    net::UnixStream,
+    process::Command,
+    signal::unix::{signal, SignalKind},
+    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    task, time,
+};
+use tracing::{debug, error, info};
+
+/// The command selecting the dispatcher, which has to be the last argument.
+const COMMAND: &str = "dispatch";
+
+/// The command of the server instances, which replaces the dispatcher command.
+const INSTANCE_COMMAND: &str = "serve-pod";
+
+/// Name of the directory inside the runtime dir which holds the runtime dirs of the instances.
+const PODS_DIR: &str = "pods";
+
+/// The maximum length of pod IDs in bytes.
+const MAX_POD_ID_LEN: usize = 253;
+
+/// The maximum time for clients to send the pod ID after connecting.
+const POD_ID_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The data sent along with every passed connection.
+const CONNECTION: &[u8] = b"c";
+
+/// The data sent by instances which became idle.
+const IDLE: &[u8] = b"i";
+
+/// The maximum amount of running instances.
+const MAX_INSTANCES: usize = 256;
+
+/// The time after which instances without containers and connections report themselves as idle.
+pub const IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Returns the runtime directory of the instance serving the pod `pod_id`.
+pub fn pod_dir(runtime_dir: &Path, pod_id: &str) -> PathBuf {
+    runtime_dir.join(PODS_DIR).join(pod_id)
+}
+
+/// Accept connections on the socket of the `config` and dispatch them to the instances of their
+/// pods until SIGTERM or SIGINT got received. The instances get stopped afterwards.
+pub async fn run(config: &Config) -> Result<()> {
+    let args: Vec<OsString> = env::args_os().skip(1).collect();
+    instance_args(&args, "").context("verify dispatcher arguments")?;
+
+    let socket = config.socket();
+    let listener = listener::bind_long_path(&socket)?;
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let mut sigint = signal(SignalKind::interrupt())?;
+    let (connections_tx, mut connections_rx) = mpsc::unbounded_channel();
+    let (exits_tx, mut exits_rx) = mpsc::unbounded_channel();
+    let (idle_tx, mut idle_rx) = mpsc::unbounded_channel();
+    let mut dispatcher = Dispatcher {
+        runtime_dir: config.runtime_dir().clone(),
+        args,
+        instances: HashMap::new(),
+        exits: exits_tx,
+        idle: idle_tx,
+    };
+    info!("Dispatching connections on {}", socket.display());
+
+    loop {
+        tokio::select! {
+            _ = sigterm.recv() => {
+                info!("Received SIGTERM");
+                break;
+            }
+            _ = sigint.recv() => {
+                info!("Received SIGINT");
+                break;
+            }
+            stream = listener.accept() => {
+                // Slow clients must not block the dispatching of other connections.
+                task::spawn(identify(stream?.0, connections_tx.clone()));
+            }
+            Some((pod_id, stream)) = connections_rx.recv() => {
+                if let Err(e) = dispatcher.dispatch(&pod_id, &stream).await {
+                    error!("Unable to dispatch connection of pod {}: {:#}", pod_id, e);
+                }
+            }
+            Some((pod_id, pid)) = exits_rx.recv() => dispatcher.exited(&pod_id, pid),
+            Some((pod_id, pid)) = idle_rx.recv() => dispatcher.release(&pod_id, pid),
+        }
+    }
+
+    drop(listener);
+    debug!("Removing socket file {}", socket.display());
+    if let Err(e) = fs::remove_file(&socket) {
+        error!("Unable to remove socket file {}: {:#}", socket.display(), e);
+    }
+    dispatcher.stop(&mut exits_rx).await;
+    Ok(())
+}
+
+/// Take the control channel to the dispatcher, which got inherited as stdin. Stdin gets
+/// replaced by /dev/null, so that spawned processes cannot interfere with the channel.
+pub fn take_control() -> Result<UnixStream> {
+    let fd = fcntl(STDIN_FILENO, FcntlArg::F_DUPFD_CLOEXEC(3)).context("duplicate stdin")?;
+    let control = unsafe { StdUnixStream::from_raw_fd(fd) };
+
+    let null = OpenOptions::new()
+        .read(true)
+        .open("/dev/null")
+        .context("open /dev/null")?;
+    dup2(null.as_raw_fd(), STDIN_FILENO).context("replace stdin")?;
+
+    control
+        .set_nonblocking(true)
+        .context("set control channel to non-blocking")?;
+    UnixStream::from_std(control).context("use stdin as control channel")
+}
+
+/// Receive the next connection passed by the dispatcher over the `control` channel. Returns
+/// `None` if the dispatcher closed the channel.
+pub async fn receive(control: &UnixStream) -> Result<Option<UnixStream>> {
+    let mut data = [0; 1];
+    let mut fds: [RawFd; 1] = [-1];
+    loop {
+        control
+            .readable()
+            .await
+            .context("wait for control channel to be readable")?;
+        match control.try_io(Interest::READABLE, || {
+            fd_inventory::recv_with_fd(control.as_raw_fd(), &mut data, &mut fds)
+        }) {
+            Ok((0, 0)) => return Ok(None),
+            Ok((_, 0)) => bail!("got no connection file descriptor"),
+            Ok(_) => break,
+            Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+            Err(e) => return Err(e).context("receive connection"),
+        }
+    }
+
+    let stream = unsafe { StdUnixStream::from_raw_fd(fds[0]) };
+    stream
+        .set_nonblocking(true)
+        .context("set connection to non-blocking")?;
+    Ok(Some(
+        UnixStream::from_std(stream).context("use passed connection")?,
+    ))
+}
+
+/// Report to the dispatcher over the `control` channel that the instance became idle.
+pub async fn report_idle(control: &UnixStream) -> Result<()> {
+    loop {
+        control
+            .writable()
+            .await
+            .context("wait for control channel to be writable")?;
+        match control.try_write(IDLE) {
+            Ok(_) => return Ok(()),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+            Err(e) => return Err(e).context("report idle instance"),
+        }
+    }
+}
+
+/// Wait until the instance reports itself as idle over the `control` channel. Fails if the
+/// channel got closed before.
+async fn wait_idle(control: &UnixStream) -> Result<()> {
+    let mut data = [0; 1];
+    loop {
+        control
+            .readable()
+            .await
+            .context("wait for control channel to be readable")?;
+        match control.try_read(&mut data) {
+            Ok(0) => bail!("control channel closed"),
+            Ok(_) => return Ok(()),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+            Err(e) => return Err(e).context("read control channel"),
+        }
+    }
+}
+
+/// The arguments of the instance serving `pod_id`, which are the ones of the dispatcher with its
+/// command replaced, so that the instances share the configuration of the dispatcher.
+fn instance_args(args: &[OsString], pod_id: &str) -> Result<Vec<OsString>> {
+    match args.split_last() {
+        Some((command, flags)) if command == COMMAND => {
+            let mut args = flags.to_vec();
+            args.push(INSTANCE_COMMAND.into());
+            args.push(format!("--pod-id={}", pod_id).into());
+            Ok(args)
+        }
+        _ => bail!("the {} command has to be the last argument", COMMAND),
+    }
+}
+
+/// Verify that `pod_id` can be used as name of the instance runtime directory.
+fn validate_pod_id(pod_id: &str) -> Result<()> {
+    if pod_id.is_empty() {
+        bail!("empty pod ID")
+    }
+    if pod_id.len() > MAX_POD_ID_LEN {
+        bail!("pod ID is longer than {} bytes", MAX_POD_ID_LEN)
+    }
+    if pod_id.starts_with('.')
+        || !pod_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        bail!("invalid pod ID {:?}", pod_id)
+    }
+    Ok(())
+}
+
+/// Read the pod ID followed by a newline from the `stream`. The ID is read byte by byte, so
+/// that no part of the following RPC stream gets consumed.
+async fn read_pod_id(stream: &mut UnixStream) -> Result<String> {
+    let mut pod_id = vec![];
+    loop {
+        match stream.read_u8().await.context("read pod ID")? {
+            b'\n' => break,
+            byte if pod_id.len() < MAX_POD_ID_LEN => pod_id.push(byte),
+            _ => bail!("pod ID is longer than {} bytes", MAX_POD_ID_LEN),
+        }
+    }
+    let pod_id = String::from_utf8(pod_id).context("pod ID is not UTF-8")?;
+    validate_pod_id(&pod_id)?;
+    Ok(pod_id)
+}
+
+/// Read the pod ID of the accepted `stream` and queue the connection for dispatching.
+async fn identify(mut stream: UnixStream, connections: UnboundedSender<(String, UnixStream)>) {
+    match time::timeout(POD_ID_TIMEOUT, read_pod_id(&mut stream)).await {
+        Ok(Ok(pod_id)) => {
+            // The dispatcher is shutting down otherwise.
+            let _ = connections.send((pod_id, stream));
+        }
+        Ok(Err(e)) => error!("Unable to identify pod, dropping connection: {:#}", e),
+        Err(_) => error!(
+            "No pod ID received within {:?}, dropping connection",
+            POD_ID_TIMEOUT
+        ),
+    }
+}
+
+/// The dispatcher state, which maps the pods to their instances.
+struct Dispatcher {
+    runtime_dir: PathBuf,
+    args: Vec<OsString>,
+    instances: HashMap<String, Instance>,
+    exits: UnboundedSender<(String, u32)>,
+    idle: UnboundedSender<(String, u32)>,
+}
+
+impl Dispatcher {
+    /// Pass the connection `stream` to the instance of `pod_id`, which gets started if required.
+    async fn dispatch(&mut self, pod_id: &str, stream: &UnixStream) -> Result<()> {
+        if let Some(instance) = self.instances.get(pod_id) {
+            // A failing instance gets replaced once its exit got processed.
+            return instance
+                .pass(stream)
+                .await
+                .with_context(|| format!("pass connection to instance {}", instance.pid));
+        }
+
+        if self.instances.len() >= MAX_INSTANCES {
+            bail!("reached the maximum of {} instances", MAX_INSTANCES)
+        }
+        let instance = self.start(pod_id)?;
+        let res = instance.pass(stream).await;
+        self.instances.insert(pod_id.into(), instance);
+        res.context("pass connection to new instance")
+    }
+
+    /// Start the instance serving `pod_id` and record its PID.
+    fn start(&self, pod_id: &str) -> Result<Instance> {
+        let dir = pod_dir(&self.runtime_dir, pod_id);
+        fs::create_dir_all(&dir).context("create pod runtime dir")?;
+
+        let (control, remote) = StdUnixStream::pair().context("create control channel")?;
+        let mut child = Command::new(helper::SELF_EXE)
+            .args(instance_args(&self.args, pod_id)?)
+            .stdin(unsafe { Stdio::from_raw_fd(remote.into_raw_fd()) })
+            .spawn()
+            .context("spawn instance")?;
+        let pid = child.id().context("no PID of spawned instance")?;
+        info!("Started instance {} for pod {}", pid, pod_id);
+
+        let exits = self.exits.clone();
+        let id = pod_id.to_string();
+        task::spawn(async move {
+            match child.wait().await {
+                Ok(status) => info!("Instance {} of pod {} exited: {}", pid, id, status),
+                Err(e) => error!("Unable to wait for instance {} of pod {}: {}", pid, id, e),
+            }
+            // The dispatcher is shutting down otherwise.
+            let _ = exits.send((id, pid));
+        });
+
+        fs::write(dir.join(PIDFILE), pid.to_string()).context("write instance pidfile")?;
+        control
+            .set_nonblocking(true)
+            .context("set control channel to non-blocking")?;
+        let control = Arc::new(UnixStream::from_std(control).context("use control channel")?);
+
+        // The task holds the control channel only until the instance became idle, so that
+        // releasing the instance closes the channel.
+        let idle = self.idle.clone();
+        let id = pod_id.to_string();
+        let idle_control = control.clone();
+        task::spawn(async move {
+            if wait_idle(&idle_control).await.is_ok() {
+                // The dispatcher is shutting down otherwise.
+                let _ = idle.send((id, pid));
+            }
+        });
+        Ok(Instance { pid, control })
+    }
+
+    /// Release the idle instance `pid` of `pod_id` by closing its control channel, unless it got
+    /// replaced already. The next connection of the pod starts a new instance.
+    fn release(&mut self, pod_id: &str, pid: u32) {
+        if self.instances.get(pod_id).map(|i| i.pid) == Some(pid) {
+            info!("Releasing idle instance {} of pod {}", pid, pod_id);
+            self.instances.remove(pod_id);
+        }
+    }
+
+    /// Remove the exited instance `pid` of `pod_id`, unless it got replaced already.
+    fn exited(&mut self, pod_id: &str, pid: u32) {
+        if self.instances.get(pod_id).map(|i| i.pid) == Some(pid) {
+            self.instances.remove(pod_id);
+        }
+
+        // Released instances may exit after their pod got a new instance.
+        let pidfile = pod_dir(&self.runtime_dir, pod_id).join(PIDFILE);
+        if fs::read_to_string(&pidfile).ok() != Some(pid.to_string()) {
+            return;
+        }
+        if let Err(e) = fs::remove_file(&pidfile) {
+            debug!("Unable to remove pidfile {}: {}", pidfile.display(), e);
+        }
+    }
+
+    /// Stop all instances by SIGTERM, which stop the containers of their pods in turn, and wait
+    /// for them to exit.
+    async fn stop(mut self, exits: &mut UnboundedReceiver<(String, u32)>) {
+        for (pod_id, instance) in &self.instances {
+            info!("Stopping instance {} of pod {}", instance.pid, pod_id);
+            if let Err(e) = kill(Pid::from_raw(instance.pid as i32), Signal::SIGTERM) {
+                error!("Unable to stop instance {}: {}", instance.pid, e);
+            }
+        }
+        while !self.instances.is_empty() {
+            match exits.recv().await {
+                Some((pod_id, pid)) => self.exited(&pod_id, pid),
+                None => break,
+            }
+        }
+    }
+}
+
+/// A running server instance serving a single pod.
+struct Instance {
+    pid: u32,
+    control: Arc<UnixStream>,
+}
+
+impl Instance {
+    /// Pass the connection `stream` to the instance, which takes over serving it.
+    async fn pass(&self, stream: &UnixStream) -> Result<()> {
+        loop {
+            self.control
+                .writable()
+                .await
+                .context("wait for control channel to be writable")?;
+            match self.control.send_with_fd(CONNECTION, &[stream.as_raw_fd()]) {
+                Ok(_) => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+                Err(e) => return Err(e).context("send connection"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncWriteExt;
+
+    #[test]
+    fn instance_args_replace_command() -> Result<()> {
+        let args: Vec<OsString> = vec!["--runtime=/bin/true".into(), "dispatch".into()];
+        assert_eq!(
+            instance_args(&args, "pod")?,
+            vec!["--runtime=/bin/true", "serve-pod", "--pod-id=pod"]
+        );
+
+        assert!(instance_args(&[], "pod").is_err());
+        assert!(instance_args(&["dispatch".into(), "--skip-fork".into()], "pod").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn validate_pod_ids() {
+        for pod_id in ["pod", "c0ffee-1_2.3"] {
+            assert!(validate_pod_id(pod_id).is_ok(), "{}", pod_id);
+        }
+        let long = "a".repeat(MAX_POD_ID_LEN + 1);
+        for pod_id in ["", ".", "..", "../pod", "pod/1", "pod id", long.as_str()] {
+            assert!(validate_pod_id(pod_id).is_err(), "{}", pod_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn read_pod_id_leaves_stream() -> Result<()> {
+        let (mut client, mut server) = UnixStream::pair()?;
+        client.write_all(b"pod\nrpc").await?;
+        drop(client);
+
+        assert_eq!(read_pod_id(&mut server).await?, "pod");
+        let mut rest = String::new();
+        server.read_to_string(&mut rest).await?;
+        assert_eq!(rest, "rpc");
+
+        let (mut client, mut server) = UnixStream::pair()?;
+        client.write_all(b"pod").await?;
+        drop(client);
+        assert!(read_pod_id(&mut server).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pass_connection() -> Result<()> {
+        let (control, remote) = UnixStream::pair()?;
+        let sut = Instance {
+            pid: 0,
+            control: Arc::new(control),
+        };
+        let (mut client, server) = UnixStream::pair()?;
+
+        sut.pass(&server).await?;
+        drop(server);
+        let mut passed = receive(&remote).await?.context("no connection")?;
+        client.write_all(b"hello").await?;
+        drop(client);
+        let mut content = String::new();
+        passed.read_to_string(&mut content).await?;
+        assert_eq!(content, "hello");
+
+        drop(sut);
+        assert!(receive(&remote).await?.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn report_idle_instance() -> Result<()> {
+        let (control, remote) = UnixStream::pair()?;
+        report_idle(&remote).await?;
+        wait_idle(&control).await?;
+
+        drop(remote);
+        assert!(wait_idle(&control).await.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+//! Translation between the encodings of attach clients and container processes.
+//!
+//! Legacy applications may write latin-1 into their terminal, which breaks web terminals
+//! expecting valid UTF-8. Attach clients therefore can declare their terminal encoding, which
+//! the output gets translated into and the input gets translated from. Characters which are
+//! not representable in the target encoding get replaced by `?`.
+
+use conmon_common::conmon_capnp::conmon::attach_request::Encoding as CapnpEncoding;
+use std::char::REPLACEMENT_CHARACTER;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Available character encodings.
+pub enum Encoding {
+    /// UTF-8, where invalid sequences get replaced.
+    Utf8,
+
+    /// ISO-8859-1, which maps every byte to the Unicode code point of the same value.
+    Latin1,
+
+    /// 7-bit ASCII.
+    Ascii,
+}
+
+impl Encoding {
+    /// The byte written for characters which are not representable.
+    const UNREPRESENTABLE: u8 = b'?';
+
+    /// Convert the encoding of an attach request, where passthrough results in `None`.
+    fn from_capnp(encoding: CapnpEncoding) -> Option<Self> {
+        match encoding {
+            CapnpEncoding::Passthrough => None,
+            CapnpEncoding::Utf8 => Some(Encoding::Utf8),
+            CapnpEncoding::Latin1 => Some(Encoding::Latin1),
+            CapnpEncoding::Ascii => Some(Encoding::Ascii),
+        }
+    }
+
+    fn encode(self, c: char, buf: &mut Vec<u8>) {
+        match self {
+            Encoding::Utf8 => buf.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            Encoding::Latin1 if (c as u32) <= 0xff => buf.push(c as u8),
+            Encoding::Ascii if c.is_ascii() => buf.push(c as u8),
+            Encoding::Latin1 | Encoding::Ascii => buf.push(Self::UNREPRESENTABLE),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// The encodings of both sides of an attach connection.
+pub struct Translation {
+    client: Encoding,
+    container: Encoding,
+}
+
+impl Translation {
+    /// Create a new translation between the `client` and `container` encoding.
+    pub fn new(client: Encoding, container: Encoding) -> Self {
+        Self { client, container }
+    }
+
+    /// The translation requested by an attach client, which is `None` if the client uses
+    /// passthrough. The container is assumed to use UTF-8 if not specified.
+    pub fn from_capnp(client: CapnpEncoding, container: CapnpEncoding) -> Option<Self> {
+        Some(Self::new(
+            Encoding::from_capnp(client)?,
+            Encoding::from_capnp(container).unwrap_or(Encoding::Utf8),
+        ))
+    }
+
+    /// A transcoder for the container output sent to the client.
+    pub fn output(&self) -> Transcoder {
+        Transcoder::new(self.container, self.client)
+    }
+
+    /// A transcoder for the client input sent to the container.
+    pub fn input(&self) -> Transcoder {
+        Transcoder::new(self.client, self.container)
+    }
+}
+
+#[derive(Debug)]
+/// A transcoder for a single stream, which keeps UTF-8 sequences split between two buffers.
+pub struct Transcoder {
+    from: Encoding,
+    to: Encoding,
+    pending: Vec<u8>,
+}
+
+impl Transcoder {
+    fn new(from: Encoding, to: Encoding) -> Self {
+        Self {
+            from,
+            to,
+            pending: vec![],
+        }
+    }
+
+    /// Translate the next buffer of the stream. An incomplete UTF-8 sequence at its end is
+    /// retained until the next call.
+    pub fn transcode(&mut self, buf: &[u8]) -> Vec<u8> {
+        let mut res = Vec::with_capacity(buf.len());
+        match self.from {
+            Encoding::Utf8 => {
+                let mut input = std::mem::take(&mut self.pending);
+                input.extend_from_slice(buf);
+                let mut rest = &input[..];
+                while !rest.is_empty() {
+                    match std::str::from_utf8(rest) {
+                        Ok(valid) => {
+                            self.encode_str(valid, &mut res);
+                            break;
+                        }
+                        Err(e) => {
+                            let (valid, invalid) = rest.split_at(e.valid_up_to());
+                            // Does not allocate, because the bytes are known to be valid
+                            self.encode_str(&String::from_utf8_lossy(valid), &mut res);
+                            match e.error_len() {
+                                Some(len) => {
+                                    self.to.encode(REPLACEMENT_CHARACTER, &mut res);
+                                    rest = &invalid[len..];
+                                }
+                                None => {
+                                    self.pending = invalid.to_vec();
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            Encoding::Latin1 => buf
+                .iter()
+                .for_each(|b| self.to.encode(*b as char, &mut res)),
+            Encoding::Ascii => buf.iter().for_each(|b| {
+                let c = if b.is_ascii() {
+                    *b as char
+                } else {
+                    REPLACEMENT_CHARACTER
+                };
+                self.to.encode(c, &mut res)
+            }),
+        }
+        res
+    }
+
+    fn encode_str(&self, s: &str, buf: &mut Vec<u8>) {
+        if self.to == Encoding::Utf8 {
+            buf.extend_from_slice(s.as_bytes());
+        } else {
+            s.chars().for_each(|c| self.to.encode(c, buf));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transcode() {
+        let transcode = |from, to, input: &[u8]| Transcoder::new(from, to).transcode(input);
+        assert_eq!(
+            transcode(Encoding::Latin1, Encoding::Utf8, b"caf\xe9"),
+            "café".as_bytes()
+        );
+        assert_eq!(
+            transcode(Encoding::Utf8, Encoding::Latin1, "café €".as_bytes()),
+            b"caf\xe9 ?"
+        );
+        assert_eq!(
+            transcode(Encoding::Utf8, Encoding::Ascii, "café".as_bytes()),
+            b"caf?"
+        );
+        assert_eq!(
+            transcode(Encoding::Utf8, Encoding::Utf8, b"a\xffb"),
+            "a\u{fffd}b".as_bytes()
+        );
+        assert_eq!(
+            transcode(Encoding::Utf8, Encoding::Latin1, b"a\xffb"),
+            b"a?b"
+        );
+        assert_eq!(
+            transcode(Encoding::Ascii, Encoding::Utf8, b"a\xe9"),
+            "a\u{fffd}".as_bytes()
+        );
+    }
+
+    #[test]
+    fn from_capnp() {
+        assert_eq!(
+            Translation::from_capnp(CapnpEncoding::Passthrough, CapnpEncoding::Latin1),
+            None
+        );
+        assert_eq!(
+            Translation::from_capnp(CapnpEncoding::Ascii, CapnpEncoding::Passthrough),
+            Some(Translation::new(Encoding::Ascii, Encoding::Utf8))
+        );
+    }
+
+    #[test]
+    fn transcode_split_sequence() {
+        let mut sut = Translation::new(Encoding::Latin1, Encoding::Utf8).output();
+        let input = "é€".as_bytes();
+        assert_eq!(sut.transcode(&input[..1]), b"");
+        assert_eq!(sut.transcode(&input[1..3]), b"\xe9");
+        assert_eq!(sut.transcode(&input[3..]), b"?");
+
+        let mut sut = Translation::new(Encoding::Latin1, Encoding::Utf8).input();
+        assert_eq!(sut.transcode(b"\xe9"), "é".as_bytes());
+    }
+}
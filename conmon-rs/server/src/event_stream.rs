@@ -0,0 +1,146 @@
+//! Streaming of container lifecycle events to subscribed clients.
+//!
+//! A client subscribes by passing a listener capability, which gets called for every event. The
+//! returned subscription capability keeps the stream alive, which means that it ends once the
+//! client drops the subscription or disconnects. Listeners are called one after another, so
+//! slow listeners lag behind the live events and catch up by the history.
+
+use crate::events::{Event, EventBus, EventKind};
+use anyhow::{bail, Context, Result};
+use conmon_common::conmon_capnp::conmon::{
+    event::{self, Type as EventType},
+    event_listener, event_subscription,
+};
+use std::sync::Arc;
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+use tokio_util::sync::{CancellationToken, DropGuard};
+use tracing::debug;
+
+/// Fill the capnp event from the provided one.
+pub fn set_event(mut builder: event::Builder, event: &Event) {
+    builder.set_sequence(event.sequence());
+    builder.set_type(match event.kind() {
+        EventKind::Created => EventType::Created,
+        EventKind::Exited => EventType::Exited,
+        EventKind::Oom => EventType::Oom,
+        EventKind::Evicted => EventType::Evicted,
+        EventKind::CleanupFailed => EventType::CleanupFailed,
+        EventKind::DescendantOom => EventType::DescendantOom,
+        EventKind::RuntimeStateLeaked => EventType::RuntimeStateLeaked,
+        EventKind::LogRotated => EventType::LogRotated,
+    });
+    builder.set_id(event.container_id());
+    builder.set_pid(event.pid());
+    builder.set_exit_code(event.exit_code());
+    builder.set_raw_exit_code(event.raw_exit_code());
+    builder.set_timestamp(event.timestamp());
+    if let Some(victim) = event.oom_victim() {
+        builder.set_killed_pid(victim.pid());
+        builder.set_killed_comm(victim.comm());
+    }
+}
+
+/// Server side of the subscription capability, which stops the stream when dropped.
+pub struct Subscription {
+    _guard: DropGuard,
+}
+
+impl Subscription {
+    /// Create a new subscription, which cancels the `token` when dropped.
+    pub fn new(token: CancellationToken) -> Self {
+        Self {
+            _guard: token.drop_guard(),
+        }
+    }
+}
+
+impl event_subscription::Server for Subscription {}
+
+/// Push the replayed `events` followed by the ones received by `rx` to the listener, until the
+/// `token` got cancelled or the listener failed.
+pub async fn stream(
+    bus: Arc<EventBus>,
+    events: Vec<Event>,
+    rx: Receiver<Event>,
+    since: u64,
+    listener: event_listener::Client,
+    token: CancellationToken,
+) {
+    tokio::select! {
+        _ = token.cancelled() => debug!("Event subscription got dropped"),
+        res = forward(&bus, events, rx, since, &listener) => match res {
+            Ok(()) => debug!("Event bus got closed"),
+            Err(e) => debug!("Stopped streaming events: {:#}", e),
+        },
+    }
+}
+
+async fn forward(
+    bus: &EventBus,
+    events: Vec<Event>,
+    mut rx: Receiver<Event>,
+    since: u64,
+    listener: &event_listener::Client,
+) -> Result<()> {
+    let mut last = since;
+    for event in events {
+        send(listener, &event).await?;
+        last = event.sequence();
+    }
+    loop {
+        match rx.recv().await {
+            // The subscription may receive events which got replayed already.
+            Ok(event) if event.sequence() <= last => {}
+            Ok(event) => {
+                send(listener, &event).await?;
+                last = event.sequence();
+            }
+            Err(RecvError::Lagged(skipped)) => {
+                debug!("Listener lagged behind by {} events", skipped);
+                let (events, _, truncated) = bus.replay(last)?;
+                if truncated {
+                    bail!("listener fell behind the event history")
+                }
+                for event in events {
+                    send(listener, &event).await?;
+                    last = event.sequence();
+                }
+            }
+            Err(RecvError::Closed) => return Ok(()),
+        }
+    }
+}
+
+async fn send(listener: &event_listener::Client, event: &Event) -> Result<()> {
+    let mut req = listener.event_request();
+    set_event(req.get().init_event(), event);
+    req.send()
+        .promise
+        .await
+        .with_context(|| format!("send event {} to listener", event.sequence()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::OomVictim;
+
+    #[test]
+    fn set_descendant_oom_event() -> Result<()> {
+        let bus = EventBus::new(10);
+        bus.publish_descendant_oom("id", 1, Some(OomVictim::new(2, "worker")));
+        let (events, _, _) = bus.replay(0)?;
+
+        let mut message = capnp::message::Builder::new_default();
+        set_event(message.init_root::<event::Builder>(), &events[0]);
+        let event = message.get_root_as_reader::<event::Reader>()?;
+        assert_eq!(event.get_sequence(), 1);
+        assert_eq!(event.get_type()?, EventType::DescendantOom);
+        assert_eq!(event.get_id()?, "id");
+        assert_eq!(event.get_pid(), 1);
+        assert_eq!(event.get_killed_pid(), 2);
+        assert_eq!(event.get_killed_comm()?, "worker");
+        Ok(())
+    }
+}
@@ -0,0 +1,338 @@
+//! Container lifecycle events and their history.
+
+use anyhow::{format_err, Result};
+use getset::{CopyGetters, Getters};
+use std::{
+    collections::VecDeque,
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use strum::AsRefStr;
+use tokio::sync::broadcast::{self, Receiver, Sender};
+use tracing::debug;
+
+#[derive(AsRefStr, Clone, Copy, Debug, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+/// Available event kinds.
+pub enum EventKind {
+    /// The container got created.
+    Created,
+
+    /// The container exited.
+    Exited,
+
+    /// The container got OOM killed.
+    Oom,
+
+    /// The exited container got removed after exceeding the retention time.
+    Evicted,
+
+    /// The cleanup command of the exited container failed or timed out.
+    CleanupFailed,
+
+    /// A process of the container other than its init process got OOM killed.
+    DescendantOom,
+
+    /// The runtime still reports the removed container as stopped.
+    RuntimeStateLeaked,
+
+    /// The container logs got reopened on request, for example after an external rotation.
+    LogRotated,
+}
+
+#[derive(Clone, CopyGetters, Debug, Eq, Getters, PartialEq)]
+/// A process killed by the OOM killer, as reported by the kernel log.
+pub struct OomVictim {
+    #[getset(get_copy = "pub")]
+    /// PID of the killed process.
+    pid: u32,
+
+    #[getset(get = "pub")]
+    /// Command name of the killed process.
+    comm: String,
+}
+
+impl OomVictim {
+    /// Create a new OOM victim.
+    pub fn new(pid: u32, comm: &str) -> Self {
+        Self {
+            pid,
+            comm: comm.into(),
+        }
+    }
+}
+
+#[derive(Clone, CopyGetters, Debug, Eq, Getters, PartialEq)]
+/// A single container lifecycle event.
+pub struct Event {
+    #[getset(get_copy = "pub")]
+    /// Monotonically increasing sequence number of the event, starting at 1.
+    sequence: u64,
+
+    #[getset(get_copy = "pub")]
+    /// Kind of the event.
+    kind: EventKind,
+
+    #[getset(get = "pub")]
+    /// Identifier of the container.
+    container_id: String,
+
+    #[getset(get_copy = "pub")]
+    /// PID of the container process.
+    pid: u32,
+
+    #[getset(get_copy = "pub")]
+    /// Exit code of the container, only valid for exit events.
+    exit_code: i32,
+
+    #[getset(get_copy = "pub")]
+    /// Exit code of the container before treating the configured codes as success.
+    raw_exit_code: i32,
+
+    #[getset(get_copy = "pub")]
+    /// Time of the event in nanoseconds since the UNIX epoch.
+    timestamp: u64,
+
+    #[getset(get = "pub")]
+    /// The killed process of descendant OOM events, if known.
+    oom_victim: Option<OomVictim>,
+}
+
+#[derive(Debug)]
+/// A bounded in-memory history of events, which can be replayed from a sequence number.
+/// Subscribers additionally receive every event once it got published.
+pub struct EventBus {
+    history: Mutex<History>,
+    sender: Sender<Event>,
+}
+
+#[derive(Debug)]
+struct History {
+    capacity: usize,
+    last_sequence: u64,
+    events: VecDeque<Event>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl EventBus {
+    /// The default amount of events kept in the history.
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// The amount of events buffered for every subscriber. Slower subscribers lag behind and
+    /// have to catch up by the history.
+    const SUBSCRIBER_CAPACITY: usize = 256;
+
+    /// Create a new event bus which keeps up to `capacity` events.
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(Self::SUBSCRIBER_CAPACITY);
+        Self {
+            history: Mutex::new(History {
+                capacity,
+                last_sequence: 0,
+                events: VecDeque::with_capacity(capacity),
+            }),
+            sender,
+        }
+    }
+
+    /// Publish a new event and return its sequence number.
+    pub fn publish(
+        &self,
+        kind: EventKind,
+        container_id: &str,
+        pid: u32,
+        exit_code: i32,
+        raw_exit_code: i32,
+    ) -> u64 {
+        self.push(kind, container_id, pid, exit_code, raw_exit_code, None)
+    }
+
+    /// Publish a descendant OOM event of the container with the init process `pid` and return
+    /// its sequence number.
+    pub fn publish_descendant_oom(
+        &self,
+        container_id: &str,
+        pid: u32,
+        victim: Option<OomVictim>,
+    ) -> u64 {
+        self.push(EventKind::DescendantOom, container_id, pid, 0, 0, victim)
+    }
+
+    fn push(
+        &self,
+        kind: EventKind,
+        container_id: &str,
+        pid: u32,
+        exit_code: i32,
+        raw_exit_code: i32,
+        oom_victim: Option<OomVictim>,
+    ) -> u64 {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+
+        let mut history = match self.history.lock() {
+            Ok(history) => history,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        history.last_sequence += 1;
+        let event = Event {
+            sequence: history.last_sequence,
+            kind,
+            container_id: container_id.into(),
+            pid,
+            exit_code,
+            raw_exit_code,
+            timestamp,
+            oom_victim,
+        };
+        debug!("Publishing event: {:?}", event);
+
+        // Sending fails only if there are no subscribers. Sending while holding the lock keeps
+        // the order of the events and prevents them from being missed on subscription.
+        let _ = self.sender.send(event.clone());
+        if history.capacity > 0 {
+            if history.events.len() >= history.capacity {
+                history.events.pop_front();
+            }
+            history.events.push_back(event);
+        }
+        history.last_sequence
+    }
+
+    /// Retrieve all events with a sequence number greater than `since` and the latest sequence
+    /// number. The returned flag indicates that some of the requested events are not part of the
+    /// history any more.
+    pub fn replay(&self, since: u64) -> Result<(Vec<Event>, u64, bool)> {
+        let history = self.history.lock().map_err(|e| format_err!("{:#}", e))?;
+        Ok(history.replay(since))
+    }
+
+    /// Replay the events like `replay` and subscribe to all events published afterwards.
+    pub fn subscribe(&self, since: u64) -> Result<(Vec<Event>, u64, bool, Receiver<Event>)> {
+        let history = self.history.lock().map_err(|e| format_err!("{:#}", e))?;
+        let (events, last_sequence, truncated) = history.replay(since);
+        Ok((events, last_sequence, truncated, self.sender.subscribe()))
+    }
+}
+
+impl History {
+    /// Events with a sequence number greater than `since`, see `EventBus::replay`.
+    fn replay(&self, since: u64) -> (Vec<Event>, u64, bool) {
+        let events: Vec<Event> = self
+            .events
+            .iter()
+            .filter(|e| e.sequence() > since)
+            .cloned()
+            .collect();
+
+        let first_available = self
+            .events
+            .front()
+            .map(Event::sequence)
+            .unwrap_or(self.last_sequence + 1);
+        let truncated = since + 1 < first_available && since < self.last_sequence;
+
+        (events, self.last_sequence, truncated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn publish_and_replay() -> Result<()> {
+        let sut = EventBus::new(10);
+        assert_eq!(sut.publish(EventKind::Created, "id", 1, 0, 0), 1);
+        assert_eq!(sut.publish(EventKind::Exited, "id", 1, 0, 2), 2);
+
+        let (events, last, truncated) = sut.replay(0)?;
+        assert_eq!(events.len(), 2);
+        assert_eq!(last, 2);
+        assert!(!truncated);
+        assert_eq!(events[1].kind(), EventKind::Exited);
+        assert_eq!(events[1].exit_code(), 0);
+        assert_eq!(events[1].raw_exit_code(), 2);
+
+        let (events, last, truncated) = sut.replay(1)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].sequence(), 2);
+        assert_eq!(last, 2);
+        assert!(!truncated);
+
+        let (events, _, truncated) = sut.replay(2)?;
+        assert!(events.is_empty());
+        assert!(!truncated);
+        Ok(())
+    }
+
+    #[test]
+    fn publish_descendant_oom() -> Result<()> {
+        let sut = EventBus::new(10);
+        sut.publish_descendant_oom("id", 1, Some(OomVictim::new(2, "worker")));
+        sut.publish_descendant_oom("id", 1, None);
+
+        let (events, _, _) = sut.replay(0)?;
+        assert_eq!(events[0].kind(), EventKind::DescendantOom);
+        assert_eq!(events[0].pid(), 1);
+        assert_eq!(events[0].oom_victim(), &Some(OomVictim::new(2, "worker")));
+        assert!(events[1].oom_victim().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn replay_truncated() -> Result<()> {
+        let sut = EventBus::new(2);
+        for _ in 0..5 {
+            sut.publish(EventKind::Created, "id", 1, 0, 0);
+        }
+
+        let (events, last, truncated) = sut.replay(0)?;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].sequence(), 4);
+        assert_eq!(last, 5);
+        assert!(truncated);
+
+        let (events, _, truncated) = sut.replay(3)?;
+        assert_eq!(events.len(), 2);
+        assert!(!truncated);
+        Ok(())
+    }
+
+    #[test]
+    fn zero_capacity() -> Result<()> {
+        let sut = EventBus::new(0);
+        sut.publish(EventKind::Created, "id", 1, 0, 0);
+
+        let (events, last, truncated) = sut.replay(0)?;
+        assert!(events.is_empty());
+        assert_eq!(last, 1);
+        assert!(truncated);
+        Ok(())
+    }
+
+    #[test]
+    fn subscribe() -> Result<()> {
+        let sut = EventBus::new(10);
+        sut.publish(EventKind::Created, "id", 1, 0, 0);
+
+        let (events, last, truncated, mut rx) = sut.subscribe(0)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(last, 1);
+        assert!(!truncated);
+        assert!(rx.try_recv().is_err());
+
+        sut.publish(EventKind::LogRotated, "id", 1, 0, 0);
+        let event = rx.try_recv()?;
+        assert_eq!(event.sequence(), 2);
+        assert_eq!(event.kind(), EventKind::LogRotated);
+        Ok(())
+    }
+}
@@ -0,0 +1,571 @@
+//! Registry of exec sessions and their garbage collection.
+
+use crate::{
+    clock::BootInstant,
+    container_io::{ContainerIO, Pipe, SharedContainerIO},
+};
+use anyhow::{format_err, Context, Result};
+use getset::{CopyGetters, Getters};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{ErrorKind, SeekFrom},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use strum::AsRefStr;
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncSeekExt},
+    sync::Notify,
+};
+use tracing::debug;
+use uuid::Uuid;
+
+#[derive(AsRefStr, Clone, Copy, Debug, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+/// Available exec session kinds.
+pub enum ExecKind {
+    /// The command got executed synchronously.
+    Sync,
+
+    /// The command runs detached and its IO gets streamed via attach endpoints.
+    Streaming,
+}
+
+#[derive(Clone, CopyGetters, Debug, Getters)]
+/// A single exec session inside of a container.
+pub struct ExecSession {
+    #[getset(get = "pub")]
+    /// Unique identifier of the session.
+    id: String,
+
+    #[getset(get = "pub")]
+    /// Identifier of the container the session belongs to.
+    container_id: String,
+
+    #[getset(get_copy = "pub")]
+    /// Kind of the session.
+    kind: ExecKind,
+
+    #[getset(get_copy = "pub")]
+    /// PID of the executed process.
+    pid: u32,
+
+    #[getset(get_copy = "pub")]
+    /// Exit code of the process, `None` while it is still running.
+    exit_code: Option<i32>,
+
+    #[getset(get_copy = "pub")]
+    /// Start time of the session in nanoseconds since the UNIX epoch.
+    started_at: u64,
+
+    #[getset(get_copy = "pub")]
+    /// End time of the session in nanoseconds since the UNIX epoch, 0 while still running.
+    finished_at: u64,
+
+    /// End time of the session by the boot time clock, used for the garbage collection.
+    finished: Option<BootInstant>,
+
+    /// Temporary files owned by the session, which get removed on garbage collection.
+    resources: Vec<PathBuf>,
+
+    /// Files containing the stdout and stderr of the process, if stored.
+    output: Option<(PathBuf, PathBuf)>,
+
+    /// IO of running streaming sessions, which attach endpoints get added to.
+    io: Option<SharedContainerIO>,
+}
+
+#[derive(Clone, CopyGetters, Debug, Default, Getters)]
+/// The result of a synchronously executed command.
+pub struct ExecSyncResult {
+    #[getset(get = "pub")]
+    /// Identifier of the exec session, empty if the process could not be created.
+    session_id: String,
+
+    #[getset(get_copy = "pub")]
+    /// PID of the executed process, 0 if it could not be created.
+    pid: u32,
+
+    #[getset(get_copy = "pub")]
+    /// Exit code of the process.
+    exit_code: i32,
+
+    #[getset(get = "pub")]
+    /// Captured standard output.
+    stdout: Vec<u8>,
+
+    #[getset(get = "pub")]
+    /// Captured standard error.
+    stderr: Vec<u8>,
+
+    #[getset(get_copy = "pub")]
+    /// Size of the captured standard output.
+    stdout_size: u64,
+
+    #[getset(get_copy = "pub")]
+    /// Size of the captured standard error.
+    stderr_size: u64,
+
+    #[getset(get_copy = "pub")]
+    /// Whether the output got moved into the exec session.
+    output_stored: bool,
+
+    #[getset(get_copy = "pub")]
+    /// Whether the process got killed because of the timeout.
+    timed_out: bool,
+}
+
+impl ExecSyncResult {
+    /// Create a new exec sync result.
+    pub fn new(
+        session_id: String,
+        pid: u32,
+        exit_code: i32,
+        stdout: Vec<u8>,
+        stderr: Vec<u8>,
+        timed_out: bool,
+    ) -> Self {
+        Self {
+            session_id,
+            pid,
+            exit_code,
+            stdout_size: stdout.len() as u64,
+            stderr_size: stderr.len() as u64,
+            stdout,
+            stderr,
+            output_stored: false,
+            timed_out,
+        }
+    }
+
+    /// Move the output out of the result, because it got stored in the exec session.
+    pub fn take_output(&mut self) -> (Vec<u8>, Vec<u8>) {
+        self.output_stored = true;
+        (
+            std::mem::take(&mut self.stdout),
+            std::mem::take(&mut self.stderr),
+        )
+    }
+
+    /// The result of a command which could not be executed.
+    pub fn failed() -> Self {
+        Self {
+            exit_code: -2,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+/// All known exec sessions. Finished sessions are kept for debugging until they get garbage
+/// collected.
+pub struct ExecSessions {
+    sessions: Mutex<HashMap<String, ExecSession>>,
+
+    /// Notified whenever a session finished, which may require a garbage collection.
+    finished: Notify,
+}
+
+impl ExecSessions {
+    /// The maximum amount of output bytes returned by a single `read_output` call.
+    const MAX_OUTPUT_CHUNK: u64 = 1024 * 1024;
+
+    /// Register a new running session and return its identifier. The provided resources are
+    /// removed once the session got garbage collected.
+    pub fn register(
+        &self,
+        container_id: &str,
+        kind: ExecKind,
+        pid: u32,
+        resources: Vec<PathBuf>,
+    ) -> Result<String> {
+        let id = Uuid::new_v4().to_string();
+        debug!("Registering {} exec session {}", kind.as_ref(), id);
+        lock!(self.sessions).insert(
+            id.clone(),
+            ExecSession {
+                id: id.clone(),
+                container_id: container_id.into(),
+                kind,
+                pid,
+                exit_code: None,
+                started_at: now(),
+                finished_at: 0,
+                finished: None,
+                resources,
+                output: None,
+                io: None,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Store the output of the session in files within `dir`, which get removed together with
+    /// the session.
+    pub async fn store_output(
+        &self,
+        id: &str,
+        dir: &Path,
+        stdout: Vec<u8>,
+        stderr: Vec<u8>,
+    ) -> Result<()> {
+        let stdout_path = ContainerIO::temp_file_name(Some(dir), id, "exec_sync-", ".stdout")?;
+        let stderr_path = ContainerIO::temp_file_name(Some(dir), id, "exec_sync-", ".stderr")?;
+        tokio::fs::write(&stdout_path, stdout)
+            .await
+            .context("write stdout")?;
+        tokio::fs::write(&stderr_path, stderr)
+            .await
+            .context("write stderr")?;
+
+        let mut sessions = lock!(self.sessions);
+        let session = sessions
+            .get_mut(id)
+            .ok_or_else(|| format_err!("exec session {} not found", id))?;
+        let output = (
+            stdout_path.keep().context("keep stdout file")?,
+            stderr_path.keep().context("keep stderr file")?,
+        );
+        session.resources.push(output.0.clone());
+        session.resources.push(output.1.clone());
+        session.output = Some(output);
+        Ok(())
+    }
+
+    /// Read up to `length` bytes of the stored output of `pipe` starting at `offset`, whereas
+    /// the length is capped by `MAX_OUTPUT_CHUNK`. The session has to belong to the container
+    /// `container_id`. Returns the data and whether the end of the output got reached.
+    pub async fn read_output(
+        &self,
+        container_id: &str,
+        id: &str,
+        pipe: Pipe,
+        offset: u64,
+        length: u64,
+    ) -> Result<(Vec<u8>, bool)> {
+        let path = {
+            let sessions = lock!(self.sessions);
+            let (stdout, stderr) = sessions
+                .get(id)
+                .filter(|s| s.container_id() == container_id)
+                .ok_or_else(|| format_err!("exec session {} not found", id))?
+                .output
+                .clone()
+                .ok_or_else(|| format_err!("exec session {} has no stored output", id))?;
+            match pipe {
+                Pipe::StdOut => stdout,
+                Pipe::StdErr => stderr,
+            }
+        };
+
+        let mut file = File::open(&path)
+            .await
+            .with_context(|| format!("open {}", path.display()))?;
+        let size = file.metadata().await.context("get output size")?.len();
+        file.seek(SeekFrom::Start(offset))
+            .await
+            .context("seek to offset")?;
+        let length = if length == 0 {
+            Self::MAX_OUTPUT_CHUNK
+        } else {
+            length.min(Self::MAX_OUTPUT_CHUNK)
+        };
+        let mut data = vec![];
+        file.take(length)
+            .read_to_end(&mut data)
+            .await
+            .context("read output")?;
+        let eof = offset.saturating_add(data.len() as u64) >= size;
+        Ok((data, eof))
+    }
+
+    /// Mark the session as finished with the provided exit code.
+    pub fn finish(&self, id: &str, exit_code: i32) -> Result<()> {
+        let mut sessions = lock!(self.sessions);
+        let session = sessions
+            .get_mut(id)
+            .ok_or_else(|| format_err!("exec session {} not found", id))?;
+        session.exit_code = Some(exit_code);
+        session.finished_at = now();
+        session.finished = Some(BootInstant::now());
+        session.io = None;
+        self.finished.notify_one();
+        Ok(())
+    }
+
+    /// Wait until a session finished. A session finishing while nobody waits completes the
+    /// next call immediately.
+    pub async fn wait_finished(&self) {
+        self.finished.notified().await
+    }
+
+    /// Returns the time until the next finished session expires after `ttl`, or `None` if no
+    /// finished sessions exist.
+    pub fn next_expiry(&self, ttl: Duration) -> Result<Option<Duration>> {
+        Ok(lock!(self.sessions)
+            .values()
+            .filter_map(|s| s.finished)
+            .map(|f| ttl.saturating_sub(f.elapsed()))
+            .min())
+    }
+
+    /// Remove the session including its resources, like after losing track of its process.
+    pub fn remove(&self, id: &str) -> Result<()> {
+        let session = lock!(self.sessions)
+            .remove(id)
+            .ok_or_else(|| format_err!("exec session {} not found", id))?;
+        debug!("Removing exec session {}", id);
+        session.remove_resources();
+        Ok(())
+    }
+
+    /// Set the IO of the running session, which allows attaching to it.
+    pub fn set_io(&self, id: &str, io: SharedContainerIO) -> Result<()> {
+        lock!(self.sessions)
+            .get_mut(id)
+            .ok_or_else(|| format_err!("exec session {} not found", id))?
+            .io = Some(io);
+        Ok(())
+    }
+
+    /// Returns the IO of the running session, which has to belong to the container
+    /// `container_id`.
+    pub fn io(&self, container_id: &str, id: &str) -> Result<SharedContainerIO> {
+        lock!(self.sessions)
+            .get(id)
+            .filter(|s| s.container_id() == container_id)
+            .ok_or_else(|| format_err!("exec session {} not found", id))?
+            .io
+            .clone()
+            .ok_or_else(|| format_err!("exec session {} is not attachable", id))
+    }
+
+    /// Returns all sessions of the provided container, ordered by their start time.
+    pub fn list(&self, container_id: &str) -> Result<Vec<ExecSession>> {
+        let mut sessions = lock!(self.sessions)
+            .values()
+            .filter(|s| s.container_id() == container_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        sessions.sort_by_key(|s| (s.started_at(), s.pid()));
+        Ok(sessions)
+    }
+
+    /// Remove all sessions which finished more than `ttl` ago, including their resources.
+    /// Returns the amount of removed sessions.
+    pub fn gc(&self, ttl: Duration) -> Result<usize> {
+        let mut sessions = lock!(self.sessions);
+        let expired = sessions
+            .values()
+            .filter(|s| s.finished.map_or(false, |f| f.elapsed() >= ttl))
+            .map(|s| s.id().clone())
+            .collect::<Vec<_>>();
+
+        for id in &expired {
+            if let Some(session) = sessions.remove(id) {
+                debug!("Garbage collecting exec session {}", id);
+                session.remove_resources();
+            }
+        }
+        Ok(expired.len())
+    }
+
+    /// Remove all sessions of the provided container, including their resources. Returns the
+    /// amount of removed sessions.
+    pub fn remove_container(&self, container_id: &str) -> Result<usize> {
+        let mut sessions = lock!(self.sessions);
+        let len = sessions.len();
+        sessions.retain(|id, s| {
+            if s.container_id() != container_id {
+                return true;
+            }
+            debug!("Removing exec session {}", id);
+            s.remove_resources();
+            false
+        });
+        Ok(len - sessions.len())
+    }
+}
+
+impl ExecSession {
+    fn remove_resources(&self) {
+        for path in &self.resources {
+            match fs::remove_file(path) {
+                Err(e) if e.kind() != ErrorKind::NotFound => {
+                    debug!("Unable to remove {}: {}", path.display(), e)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container_log::ContainerLog;
+    use tempfile::NamedTempFile;
+    use tokio::time;
+
+    #[test]
+    fn register_finish_list() -> Result<()> {
+        let sut = ExecSessions::default();
+        let first = sut.register("ctr", ExecKind::Sync, 1, vec![])?;
+        let second = sut.register("ctr", ExecKind::Sync, 2, vec![])?;
+        sut.register("other", ExecKind::Sync, 3, vec![])?;
+
+        sut.finish(&first, 1)?;
+        assert!(sut.finish("wrong", 0).is_err());
+
+        let sessions = sut.list("ctr")?;
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].id(), &first);
+        assert_eq!(sessions[0].exit_code(), Some(1));
+        assert!(sessions[0].finished_at() > 0);
+        assert_eq!(sessions[1].id(), &second);
+        assert_eq!(sessions[1].exit_code(), None);
+        assert_eq!(sessions[1].finished_at(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn store_read_output() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let sut = ExecSessions::default();
+        let id = sut.register("ctr", ExecKind::Sync, 1, vec![])?;
+        assert!(sut
+            .read_output("ctr", &id, Pipe::StdOut, 0, 0)
+            .await
+            .is_err());
+
+        sut.store_output(&id, dir.path(), b"hello world".to_vec(), vec![])
+            .await?;
+        assert_eq!(
+            sut.read_output("ctr", &id, Pipe::StdOut, 0, 5).await?,
+            (b"hello".to_vec(), false)
+        );
+        assert_eq!(
+            sut.read_output("ctr", &id, Pipe::StdOut, 6, 0).await?,
+            (b"world".to_vec(), true)
+        );
+        assert_eq!(
+            sut.read_output("ctr", &id, Pipe::StdOut, 20, 0).await?,
+            (vec![], true)
+        );
+        assert_eq!(
+            sut.read_output("ctr", &id, Pipe::StdErr, 0, 0).await?,
+            (vec![], true)
+        );
+
+        sut.finish(&id, 0)?;
+        assert!(sut
+            .read_output("other", &id, Pipe::StdOut, 0, 0)
+            .await
+            .is_err());
+        assert_eq!(sut.gc(Duration::ZERO)?, 1);
+        assert!(sut
+            .read_output("ctr", &id, Pipe::StdOut, 0, 0)
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn streaming_io() -> Result<()> {
+        let sut = ExecSessions::default();
+        let id = sut.register("ctr", ExecKind::Streaming, 1, vec![])?;
+        assert!(sut.io("ctr", &id).is_err());
+
+        let io = ContainerIO::new("ctr", false, false, ContainerLog::new(), None)?;
+        sut.set_io(&id, SharedContainerIO::new(io))?;
+        assert!(sut.io("ctr", &id).is_ok());
+        assert!(sut.io("other", &id).is_err());
+
+        sut.finish(&id, 0)?;
+        assert!(sut.io("ctr", &id).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn gc_finished() -> Result<()> {
+        let sut = ExecSessions::default();
+        let file = NamedTempFile::new()?;
+        let path = file.into_temp_path().keep()?;
+
+        let finished = sut.register("ctr", ExecKind::Sync, 1, vec![path.clone()])?;
+        sut.register("ctr", ExecKind::Sync, 2, vec![])?;
+
+        assert_eq!(sut.gc(Duration::ZERO)?, 0);
+        sut.finish(&finished, 0)?;
+        assert_eq!(sut.gc(Duration::from_secs(60))?, 0);
+        assert!(path.exists());
+
+        assert_eq!(sut.gc(Duration::ZERO)?, 1);
+        assert!(!path.exists());
+        assert_eq!(sut.list("ctr")?.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn next_expiry() -> Result<()> {
+        let sut = ExecSessions::default();
+        let id = sut.register("ctr", ExecKind::Sync, 1, vec![])?;
+        assert_eq!(sut.next_expiry(Duration::ZERO)?, None);
+
+        sut.finish(&id, 0)?;
+        time::timeout(Duration::from_secs(1), sut.wait_finished()).await?;
+        let next = sut
+            .next_expiry(Duration::from_secs(60))?
+            .context("no expiry")?;
+        assert!(next > Duration::ZERO && next <= Duration::from_secs(60));
+        assert_eq!(sut.next_expiry(Duration::ZERO)?, Some(Duration::ZERO));
+
+        sut.gc(Duration::ZERO)?;
+        assert_eq!(sut.next_expiry(Duration::ZERO)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn remove() -> Result<()> {
+        let sut = ExecSessions::default();
+        let file = NamedTempFile::new()?;
+        let path = file.into_temp_path().keep()?;
+
+        let id = sut.register("ctr", ExecKind::Streaming, 1, vec![path.clone()])?;
+        sut.register("ctr", ExecKind::Streaming, 2, vec![])?;
+
+        sut.remove(&id)?;
+        assert!(!path.exists());
+        assert_eq!(sut.list("ctr")?.len(), 1);
+        assert!(sut.remove(&id).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn remove_container() -> Result<()> {
+        let sut = ExecSessions::default();
+        let file = NamedTempFile::new()?;
+        let path = file.into_temp_path().keep()?;
+
+        sut.register("ctr", ExecKind::Sync, 1, vec![path.clone()])?;
+        sut.register("ctr", ExecKind::Sync, 2, vec![])?;
+        sut.register("other", ExecKind::Sync, 3, vec![])?;
+
+        assert_eq!(sut.remove_container("ctr")?, 2);
+        assert!(!path.exists());
+        assert!(sut.list("ctr")?.is_empty());
+        assert_eq!(sut.list("other")?.len(), 1);
+        assert_eq!(sut.remove_container("ctr")?, 0);
+        Ok(())
+    }
+}
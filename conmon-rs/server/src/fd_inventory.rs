@@ -0,0 +1,168 @@
+//! Inventory of the file descriptors inherited by spawned processes.
+//!
+//! Every file descriptor conmon-rs holds has to be close-on-exec, otherwise runtimes, cleanup
+//! commands and in the end the containers keep them open. For example, a leaked log file
+//! descriptor keeps the disk space of a rotated log allocated. Only the standard streams are
+//! inherited on purpose. The console socket is passed to the runtime by its path, which means
+//! that no further file descriptors have to be preserved.
+//!
+//! File descriptors are therefore created close-on-exec right away, because marking them
+//! afterwards races with processes spawned by other tasks in the meantime. The scan of all open
+//! file descriptors is only used by the tests to audit this.
+
+use anyhow::{Context, Result};
+use libc::{STDERR_FILENO, STDIN_FILENO, STDOUT_FILENO};
+use nix::{
+    sys::socket::{recvmsg, ControlMessageOwned, MsgFlags},
+    unistd::close,
+};
+use std::{
+    fs,
+    io::{self, IoSliceMut},
+    mem,
+    os::unix::io::RawFd,
+};
+
+/// File descriptors which are inherited by spawned processes on purpose.
+pub const PRESERVED: [RawFd; 3] = [STDIN_FILENO, STDOUT_FILENO, STDERR_FILENO];
+
+/// Directory listing the open file descriptors of the current process.
+const FD_DIR: &str = "/proc/self/fd";
+
+/// Receive `data` and up to `fds.len()` file descriptors from the unix `socket`, like
+/// `sendfd::RecvWithFd` does, but with the received file descriptors being close-on-exec.
+/// Surplus file descriptors are closed. Returns the number of received bytes and file
+/// descriptors.
+pub fn recv_with_fd(
+    socket: RawFd,
+    data: &mut [u8],
+    fds: &mut [RawFd],
+) -> io::Result<(usize, usize)> {
+    let mut iov = [IoSliceMut::new(data)];
+    let rights_len = mem::size_of_val(fds) as u32;
+    let mut cmsg_buffer = Vec::with_capacity(unsafe { libc::CMSG_SPACE(rights_len) } as usize);
+    let msg = recvmsg::<()>(
+        socket,
+        &mut iov,
+        Some(&mut cmsg_buffer),
+        MsgFlags::MSG_CMSG_CLOEXEC,
+    )?;
+
+    let mut received = 0;
+    for cmsg in msg.cmsgs() {
+        if let ControlMessageOwned::ScmRights(rights) = cmsg {
+            for fd in rights {
+                match fds.get_mut(received) {
+                    Some(slot) => {
+                        *slot = fd;
+                        received += 1;
+                    }
+                    None => {
+                        let _ = close(fd);
+                    }
+                }
+            }
+        }
+    }
+    Ok((msg.bytes, received))
+}
+
+/// All open file descriptors which are not `PRESERVED`, sorted in ascending order. The file
+/// descriptor used for reading the directory is closed before returning.
+pub fn open_fds() -> Result<Vec<RawFd>> {
+    let mut fds = vec![];
+    for entry in fs::read_dir(FD_DIR).with_context(|| format!("read {}", FD_DIR))? {
+        let entry = entry.with_context(|| format!("read entry of {}", FD_DIR))?;
+        if let Some(fd) = entry.file_name().to_str().and_then(|n| n.parse().ok()) {
+            if !PRESERVED.contains(&fd) {
+                fds.push(fd);
+            }
+        }
+    }
+    fds.sort_unstable();
+    Ok(fds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nix::{
+        fcntl::{fcntl, FcntlArg, FdFlag},
+        unistd::dup,
+    };
+    use sendfd::SendWithFd;
+    use std::{
+        fs::File,
+        os::unix::{
+            io::{AsRawFd, FromRawFd},
+            net::UnixStream,
+        },
+        process::{Command, Stdio},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    /// All open file descriptors which are not `PRESERVED` and would be inherited by spawned
+    /// processes, sorted in ascending order.
+    fn inheritable() -> Result<Vec<RawFd>> {
+        Ok(open_fds()?
+            .into_iter()
+            .filter(|fd| match fcntl(*fd, FcntlArg::F_GETFD) {
+                Ok(flags) => !FdFlag::from_bits_truncate(flags).contains(FdFlag::FD_CLOEXEC),
+                // Closed in the meantime
+                Err(_) => false,
+            })
+            .collect())
+    }
+
+    #[test]
+    fn inheritable_audit() -> Result<()> {
+        let file = File::open("/dev/null")?;
+        assert!(!inheritable()?.contains(&file.as_raw_fd()));
+
+        // dup does not set the close-on-exec flag
+        let leaked = dup(file.as_raw_fd())?;
+        assert!(inheritable()?.contains(&leaked));
+        close(leaked)?;
+        Ok(())
+    }
+
+    #[test]
+    fn recv_with_fd_cloexec() -> Result<()> {
+        let (tx, rx) = UnixStream::pair()?;
+        let file = File::open("/dev/null")?;
+        tx.send_with_fd(b"x", &[file.as_raw_fd(), file.as_raw_fd()])?;
+
+        let mut data = [0; 1];
+        let mut fds: [RawFd; 1] = [-1];
+        let (bytes, count) = recv_with_fd(rx.as_raw_fd(), &mut data, &mut fds)?;
+        assert_eq!(bytes, 1);
+        assert_eq!(count, 1);
+        let received = unsafe { File::from_raw_fd(fds[0]) };
+        assert!(!inheritable()?.contains(&received.as_raw_fd()));
+
+        let mut child = Command::new("sleep")
+            .arg("10")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let fd_dir = format!("/proc/{}/fd", child.id());
+
+        // The dynamic loader may reuse the same number for a short time
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let inherited = loop {
+            let inherited = fs::read_dir(&fd_dir)?
+                .filter_map(|e| e.ok()?.file_name().to_str()?.parse::<RawFd>().ok())
+                .any(|fd| fd == received.as_raw_fd());
+            if !inherited || Instant::now() > deadline {
+                break inherited;
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+        child.kill()?;
+        child.wait()?;
+        assert!(!inherited, "spawned process inherited file descriptor");
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+//! Event based waiting for filesystem paths.
+
+use anyhow::{bail, Context, Result};
+use notify::{Event, RecursiveMode, Watcher};
+use std::path::Path;
+use tokio::{
+    sync::mpsc,
+    time::{self, Instant},
+};
+use tracing::{debug, trace};
+
+/// Wait until the provided path exists or the deadline is reached.
+/// The parent directory of the path gets watched via inotify, so no polling is involved.
+pub async fn wait_for_path(path: &Path, deadline: Instant) -> Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+
+    let parent = path.parent().context("path has no parent directory")?;
+    let (tx, mut rx) = mpsc::channel(1);
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| match res {
+        // Events are coalesced, a full channel already signals a pending check.
+        Ok(_) => {
+            let _ = tx.try_send(());
+        }
+        Err(e) => trace!("Watch error: {:#}", e),
+    })
+    .context("create file watcher")?;
+    watcher
+        .watch(parent, RecursiveMode::NonRecursive)
+        .context("watch parent directory")?;
+
+    debug!("Waiting for path {}", path.display());
+    loop {
+        // Check after every event and once right after setting up the watch, because the path
+        // may have been created in the meantime.
+        if path.exists() {
+            return Ok(());
+        }
+        match time::timeout_at(deadline, rx.recv()).await {
+            Ok(Some(())) => continue,
+            Ok(None) => bail!("file watcher closed unexpectedly"),
+            Err(_) => bail!("timed out waiting for path {}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, time::Duration};
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn wait_for_path_existing() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("file");
+        fs::write(&path, "")?;
+        wait_for_path(&path, Instant::now()).await
+    }
+
+    #[tokio::test]
+    async fn wait_for_path_created() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("file");
+        let path_clone = path.clone();
+        tokio::spawn(async move {
+            time::sleep(Duration::from_millis(100)).await;
+            fs::write(path_clone, "").expect("write file");
+        });
+        wait_for_path(&path, Instant::now() + Duration::from_secs(10)).await
+    }
+
+    #[tokio::test]
+    async fn wait_for_path_timeout() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("file");
+        let res = wait_for_path(&path, Instant::now() + Duration::from_millis(100)).await;
+        assert!(res.is_err());
+        Ok(())
+    }
+}
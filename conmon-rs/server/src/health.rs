@@ -0,0 +1,99 @@
+//! Health of the server itself, which allows node agents to restart a wedged instance.
+
+use crate::{container_io::ContainerIO, sigchld::SigchldWaiter};
+use getset::{CopyGetters, Getters};
+use std::path::Path;
+use tokio::{
+    task,
+    time::{self, Duration, Instant},
+};
+
+#[derive(CopyGetters, Debug, Getters)]
+/// The result of a single health check.
+pub struct Health {
+    #[getset(get = "pub")]
+    /// Human readable descriptions of the detected problems, empty if healthy.
+    problems: Vec<String>,
+
+    #[getset(get_copy = "pub")]
+    /// Time it took until a newly spawned task got scheduled.
+    reactor_latency: Duration,
+
+    #[getset(get_copy = "pub")]
+    /// Time of the last SIGCHLD wakeup in nanoseconds since the UNIX epoch, 0 if none happened.
+    last_reaper_wakeup: u64,
+}
+
+impl Health {
+    /// The maximum scheduling latency of a healthy runtime.
+    pub const MAX_REACTOR_LATENCY: Duration = Duration::from_secs(1);
+
+    /// Check that the reap loop is alive, that temporary files can be created in `runtime_dir`
+    /// and that the async runtime is not stalled.
+    pub async fn check(sigchld_waiter: &SigchldWaiter, runtime_dir: &Path) -> Self {
+        let mut problems = vec![];
+        if !sigchld_waiter.is_alive() {
+            problems.push("SIGCHLD reap loop stopped".into());
+        }
+
+        // The reserved name gets removed again on drop
+        if let Err(e) = ContainerIO::temp_file_name(Some(runtime_dir), "health", "", "") {
+            problems.push(format!("runtime directory is not writable: {:#}", e));
+        }
+
+        let start = Instant::now();
+        let reactor_latency = match time::timeout(
+            Self::MAX_REACTOR_LATENCY,
+            task::spawn(async move { start.elapsed() }),
+        )
+        .await
+        {
+            Ok(Ok(latency)) if latency < Self::MAX_REACTOR_LATENCY => latency,
+            Ok(Err(e)) => {
+                problems.push(format!("unable to spawn task: {}", e));
+                start.elapsed()
+            }
+            _ => {
+                problems.push(format!(
+                    "async runtime stalled for more than {:?}",
+                    Self::MAX_REACTOR_LATENCY
+                ));
+                start.elapsed()
+            }
+        };
+
+        Self {
+            problems,
+            reactor_latency,
+            last_reaper_wakeup: sigchld_waiter.last_wakeup(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use std::{fs, os::unix::fs::PermissionsExt};
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn check() -> Result<()> {
+        let dir = tempdir()?;
+        let sut = Health::check(&SigchldWaiter::default(), dir.path()).await;
+        assert!(sut.problems().is_empty());
+        assert!(sut.reactor_latency() < Health::MAX_REACTOR_LATENCY);
+        assert_eq!(sut.last_reaper_wakeup(), 0);
+
+        let read_only = dir.path().join("read-only");
+        fs::create_dir(&read_only)?;
+        fs::set_permissions(&read_only, fs::Permissions::from_mode(0o555))?;
+        let sut = Health::check(&SigchldWaiter::default(), &read_only).await;
+        // Root is able to write anyway
+        if !nix::unistd::geteuid().is_root() {
+            assert_eq!(sut.problems().len(), 1);
+            assert!(sut.problems()[0].starts_with("runtime directory is not writable"));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+//! Re-exec based helper processes.
+//!
+//! Forking from the multi threaded async runtime only leaves the forking thread in the child,
+//! while locks held by other threads stay locked forever. Helper commands therefore re-execute
+//! the server binary with `--internal-helper`, which does the remaining work in a fresh single
+//! threaded process image before executing the target command. The PID stays the same, which
+//! means that the helper can be waited for and killed like the target command itself.
+
+use anyhow::{bail, format_err, Context, Result};
+use nix::{
+    sys::signal::{self, SigHandler, SigSet, SigmaskHow, Signal},
+    unistd::execvp,
+};
+use std::{
+    env,
+    ffi::{CString, OsString},
+    os::unix::ffi::OsStrExt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use strum::{AsRefStr, EnumString};
+use tokio::process::Command;
+
+/// The command line flag selecting the helper mode, which has to be the first argument.
+pub const FLAG: &str = "--internal-helper";
+
+/// Path to the executable of the running process, which stays valid even if the binary on
+/// disk got replaced.
+pub const SELF_EXE: &str = "/proc/self/exe";
+
+/// Whether the binary supports the helper mode, which is only known once
+/// `run_if_requested` got called.
+static AVAILABLE: AtomicBool = AtomicBool::new(false);
+
+#[derive(AsRefStr, Clone, Copy, Debug, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+/// Available helper kinds.
+pub enum Helper {
+    /// Reset the signal state and execute the provided command.
+    Exec,
+}
+
+impl Helper {
+    /// Build the command running `args` through the helper. The command gets spawned directly
+    /// if the binary does not support the helper mode, for example in tests.
+    pub fn command(self, args: &[String]) -> Result<Command> {
+        let (program, args) = args.split_first().context("no command provided")?;
+        if !AVAILABLE.load(Ordering::Relaxed) {
+            let mut cmd = Command::new(program);
+            cmd.args(args);
+            return Ok(cmd);
+        }
+        let mut cmd = Command::new(SELF_EXE);
+        cmd.arg(FLAG)
+            .arg(self.as_ref())
+            .arg("--")
+            .arg(program)
+            .args(args);
+        Ok(cmd)
+    }
+}
+
+/// Run the helper if the first command line argument is `FLAG`. Returns `None` if the process
+/// should continue as server, and otherwise only returns on failure. Has to be called before
+/// any threads get spawned.
+pub fn run_if_requested() -> Option<Result<()>> {
+    let args: Vec<OsString> = env::args_os().skip(1).collect();
+    if args.first().map(|arg| arg.as_bytes()) != Some(FLAG.as_bytes()) {
+        AVAILABLE.store(true, Ordering::Relaxed);
+        return None;
+    }
+    Some(run(&args[1..]).context("run internal helper"))
+}
+
+/// Run the helper for arguments in the format `<kind> -- <command> [args...]`.
+fn run(args: &[OsString]) -> Result<()> {
+    let (kind, args) = match args {
+        [kind, separator, args @ ..] if separator == "--" => (kind, args),
+        _ => bail!("usage: {} <kind> -- <command> [args...]", FLAG),
+    };
+    let kind: Helper = kind
+        .to_str()
+        .and_then(|k| k.parse().ok())
+        .with_context(|| format!("unknown helper kind {:?}", kind))?;
+    let args = args
+        .iter()
+        .map(|arg| CString::new(arg.as_bytes()))
+        .collect::<Result<Vec<_>, _>>()
+        .context("convert arguments")?;
+    let program = args.first().context("no command provided")?;
+
+    match kind {
+        Helper::Exec => {
+            reset_signals()?;
+            execvp(program, &args).with_context(|| format!("execute {:?}", program))?;
+        }
+    }
+    Ok(())
+}
+
+/// Restore the default signal dispositions and unblock all signals, because ignored and
+/// blocked signals are inherited over exec. The Rust runtime ignores SIGPIPE, for example.
+fn reset_signals() -> Result<()> {
+    for s in Signal::iterator().filter(|s| *s != Signal::SIGKILL && *s != Signal::SIGSTOP) {
+        unsafe { signal::signal(s, SigHandler::SigDfl) }
+            .map_err(|e| format_err!("reset {} handler: {}", s, e))?;
+    }
+    signal::sigprocmask(SigmaskHow::SIG_SETMASK, Some(&SigSet::empty()), None)
+        .context("unblock signals")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command() -> Result<()> {
+        let cmd = Helper::Exec.command(&["echo".into(), "hello".into()])?;
+        let cmd = cmd.as_std();
+        assert_eq!(cmd.get_program(), "echo");
+        assert_eq!(cmd.get_args().collect::<Vec<_>>(), vec!["hello"]);
+
+        assert!(Helper::Exec.command(&[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn run_invalid() {
+        for args in [
+            vec![],
+            vec!["exec"],
+            vec!["exec", "echo"],
+            vec!["exec", "--"],
+            vec!["unknown", "--", "echo"],
+        ] {
+            let args: Vec<OsString> = args.into_iter().map(OsString::from).collect();
+            assert!(run(&args).is_err(), "{:?}", args);
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Idempotency tokens, which allow clients to retry requests after network errors without
+//! running the operation twice.
+//!
+//! The first request carrying a token runs the operation, while all retries with the same token
+//! wait for and return its result. Failed operations are not recorded, which means that a retry
+//! runs them again. Recorded results expire after `IdempotencyCache::TTL`, and the oldest ones
+//! get evicted early if more than `IdempotencyCache::MAX_ENTRIES` tokens are claimed.
+
+use crate::{clock::BootInstant, tenant::Tenant};
+use anyhow::{bail, format_err, Context, Result};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::sync::watch::{self, Receiver, Sender};
+
+#[derive(Debug)]
+struct Entry<T> {
+    created: BootInstant,
+    rx: Receiver<Option<T>>,
+}
+
+type Entries<T> = Arc<Mutex<HashMap<String, Entry<T>>>>;
+
+#[derive(Debug)]
+/// The outcome of claiming an idempotency token.
+pub enum Claim<T> {
+    /// No token got provided, the operation is not recorded.
+    Untracked,
+
+    /// The operation has to be run and its result recorded.
+    Run(Completion<T>),
+
+    /// The operation already ran or is still running.
+    Existing(Receiver<Option<T>>),
+}
+
+#[derive(Debug)]
+/// Records the result of a claimed operation. Dropping it without completion releases the
+/// token, so that waiting retries fail and new ones run the operation again.
+pub struct Completion<T> {
+    key: String,
+    tx: Option<Sender<Option<T>>>,
+    entries: Entries<T>,
+}
+
+impl<T> Completion<T> {
+    /// Record the result of the operation and pass it to all waiting retries.
+    pub fn complete(mut self, result: T) {
+        if let Some(tx) = self.tx.take() {
+            // The cache holds a receiver as long as the entry exists.
+            let _ = tx.send(Some(result));
+        }
+    }
+}
+
+impl<T> Drop for Completion<T> {
+    fn drop(&mut self) {
+        if self.tx.is_none() {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(&self.key);
+        }
+    }
+}
+
+#[derive(Debug)]
+/// The results of operations by their idempotency token.
+pub struct IdempotencyCache<T> {
+    entries: Entries<T>,
+}
+
+impl<T> Default for IdempotencyCache<T> {
+    fn default() -> Self {
+        Self {
+            entries: Default::default(),
+        }
+    }
+}
+
+impl<T: Clone> IdempotencyCache<T> {
+    /// Time recorded results are kept for retries.
+    const TTL: Duration = Duration::from_secs(600);
+
+    /// Maximum number of claimed tokens, which bounds the memory used by recorded results.
+    const MAX_ENTRIES: usize = 1024;
+
+    /// Claim the `token` of an operation on the container `id`. Tokens are scoped to the
+    /// container and tenant, and an empty token is never recorded.
+    pub fn claim(&self, tenant: Option<Tenant>, id: &str, token: &str) -> Result<Claim<T>> {
+        if token.is_empty() {
+            return Ok(Claim::Untracked);
+        }
+        let key = format!(
+            "{}/{}/{}",
+            tenant.map(|t| t.uid().to_string()).unwrap_or_default(),
+            id,
+            token
+        );
+
+        let mut entries = self.entries.lock().map_err(|e| format_err!("{:#}", e))?;
+        // Running operations release their token on failure.
+        entries.retain(|_, e| e.created.elapsed() < Self::TTL || e.rx.borrow().is_none());
+        if let Some(entry) = entries.get(&key) {
+            return Ok(Claim::Existing(entry.rx.clone()));
+        }
+        if entries.len() >= Self::MAX_ENTRIES {
+            let oldest = entries
+                .iter()
+                .filter(|(_, e)| e.rx.borrow().is_some())
+                .min_by_key(|(_, e)| e.created)
+                .map(|(k, _)| k.clone());
+            match oldest {
+                Some(oldest) => {
+                    entries.remove(&oldest);
+                }
+                None => bail!("too many running operations with idempotency tokens"),
+            }
+        }
+
+        let (tx, rx) = watch::channel(None);
+        entries.insert(
+            key.clone(),
+            Entry {
+                created: BootInstant::now(),
+                rx,
+            },
+        );
+        Ok(Claim::Run(Completion {
+            key,
+            tx: Some(tx),
+            entries: self.entries.clone(),
+        }))
+    }
+
+    /// Wait for the result of an `Existing` operation.
+    pub async fn wait(mut rx: Receiver<Option<T>>) -> Result<T> {
+        loop {
+            if let Some(result) = rx.borrow().clone() {
+                return Ok(result);
+            }
+            rx.changed()
+                .await
+                .context("original operation with the same idempotency token failed")?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run<T: Clone>(claim: Claim<T>) -> Result<Completion<T>> {
+        match claim {
+            Claim::Run(completion) => Ok(completion),
+            _ => Err(format_err!("expected operation to run")),
+        }
+    }
+
+    fn existing<T: Clone>(claim: Claim<T>) -> Result<Receiver<Option<T>>> {
+        match claim {
+            Claim::Existing(rx) => Ok(rx),
+            _ => Err(format_err!("expected existing operation")),
+        }
+    }
+
+    #[tokio::test]
+    async fn claim() -> Result<()> {
+        let sut = IdempotencyCache::<u32>::default();
+        assert!(matches!(sut.claim(None, "id", "")?, Claim::Untracked));
+
+        let completion = run(sut.claim(None, "id", "token")?)?;
+        let rx = existing(sut.claim(None, "id", "token")?)?;
+        run(sut.claim(None, "other", "token")?)?;
+
+        completion.complete(42);
+        assert_eq!(IdempotencyCache::wait(rx).await?, 42);
+        let rx = existing(sut.claim(None, "id", "token")?)?;
+        assert_eq!(IdempotencyCache::wait(rx).await?, 42);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn claim_failed() -> Result<()> {
+        let sut = IdempotencyCache::<u32>::default();
+        let completion = run(sut.claim(None, "id", "token")?)?;
+        let rx = existing(sut.claim(None, "id", "token")?)?;
+
+        drop(completion);
+        assert!(IdempotencyCache::wait(rx).await.is_err());
+        run(sut.claim(None, "id", "token")?)?;
+        Ok(())
+    }
+
+    #[test]
+    fn claim_evicts_oldest() -> Result<()> {
+        let sut = IdempotencyCache::<u32>::default();
+        run(sut.claim(None, "id", "first")?)?.complete(1);
+        std::thread::sleep(Duration::from_millis(1));
+        for i in 1..IdempotencyCache::<u32>::MAX_ENTRIES {
+            run(sut.claim(None, "id", &i.to_string())?)?.complete(0);
+        }
+        run(sut.claim(None, "id", "last")?)?.complete(2);
+        run(sut.claim(None, "id", "first")?)?;
+        existing(sut.claim(None, "id", "last")?)?;
+        Ok(())
+    }
+
+    #[test]
+    fn claim_too_many_running() -> Result<()> {
+        let sut = IdempotencyCache::<u32>::default();
+        let _running = (0..IdempotencyCache::<u32>::MAX_ENTRIES)
+            .map(|i| run(sut.claim(None, "id", &i.to_string())?))
+            .collect::<Result<Vec<_>>>()?;
+        assert!(sut.claim(None, "id", "token").is_err());
+        Ok(())
+    }
+}
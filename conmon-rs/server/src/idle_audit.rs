@@ -0,0 +1,82 @@
+//! Diagnostics for wakeups of an idle server.
+
+use getset::CopyGetters;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use tracing::info;
+
+#[derive(CopyGetters, Debug, Default)]
+/// Records every wakeup source firing while no containers are active. An idle server is
+/// expected to sleep until a client connects, a signal arrives or a process exits.
+pub struct IdleAudit {
+    #[getset(get_copy = "pub")]
+    /// Whether wakeups get recorded at all.
+    enabled: bool,
+
+    active_containers: AtomicUsize,
+
+    idle_wakeups: AtomicU64,
+}
+
+impl IdleAudit {
+    /// Create a new idle audit, which only records wakeups if `enabled` is set.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            ..Default::default()
+        }
+    }
+
+    /// Mark a container as active.
+    pub fn container_started(&self) {
+        self.active_containers.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Mark a previously started container as inactive.
+    pub fn container_stopped(&self) {
+        let _ = self
+            .active_containers
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| x.checked_sub(1));
+    }
+
+    /// Record a wakeup caused by the provided source.
+    pub fn record(&self, source: &str) {
+        if !self.enabled || self.active_containers.load(Ordering::SeqCst) > 0 {
+            return;
+        }
+        let count = self.idle_wakeups.fetch_add(1, Ordering::SeqCst) + 1;
+        info!(source, count, "Wakeup while no containers are active");
+    }
+
+    /// The amount of wakeups recorded while no containers were active.
+    pub fn idle_wakeups(&self) -> u64 {
+        self.idle_wakeups.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_idle() {
+        let sut = IdleAudit::new(true);
+        sut.record("test");
+        assert_eq!(sut.idle_wakeups(), 1);
+
+        sut.container_started();
+        sut.record("test");
+        assert_eq!(sut.idle_wakeups(), 1);
+
+        sut.container_stopped();
+        sut.container_stopped();
+        sut.record("test");
+        assert_eq!(sut.idle_wakeups(), 2);
+    }
+
+    #[test]
+    fn record_disabled() {
+        let sut = IdleAudit::new(false);
+        sut.record("test");
+        assert_eq!(sut.idle_wakeups(), 0);
+    }
+}
@@ -0,0 +1,136 @@
+//! Recording of runtime invocations, which allows reproducing runtime failures by hand.
+//!
+//! Every invocation gets appended as shell snippet to the record file of the container:
+//!
+//! ```text
+//! # create at 1700000000.123456789
+//! cd '/var/lib/conmonrs'
+//! env -i 'PATH=/usr/bin' '/usr/bin/runc' 'create' '--bundle' '/bundle' 'ctr'
+//! ```
+
+use anyhow::{Context, Result};
+use std::{
+    env,
+    ffi::OsStr,
+    fmt::Write as _,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A single invocation of the runtime.
+pub struct Invocation {
+    operation: &'static str,
+    program: String,
+    args: Vec<String>,
+    env: Vec<(String, String)>,
+    cwd: PathBuf,
+}
+
+impl Invocation {
+    /// Capture the invocation of `program` with `args` for the provided operation. The
+    /// runtime inherits the environment and working directory of the server.
+    pub fn new<P, S>(operation: &'static str, program: P, args: &[S]) -> Self
+    where
+        P: AsRef<OsStr>,
+        S: AsRef<OsStr>,
+    {
+        Self {
+            operation,
+            program: program.as_ref().to_string_lossy().into_owned(),
+            args: args
+                .iter()
+                .map(|arg| arg.as_ref().to_string_lossy().into_owned())
+                .collect(),
+            env: env::vars_os()
+                .map(|(k, v)| (k.to_string_lossy().into(), v.to_string_lossy().into()))
+                .collect(),
+            cwd: env::current_dir().unwrap_or_default(),
+        }
+    }
+
+    /// The path of the record file of the container `id` in the directory `dir`.
+    pub fn record_path(dir: &Path, id: &str) -> PathBuf {
+        dir.join(format!("{}.invocations", id))
+    }
+
+    /// Append the invocation to the record file at `path`, which is only readable by its
+    /// owner because the environment may contain secrets.
+    pub async fn record(&self, path: &Path) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .mode(0o600)
+            .open(path)
+            .await
+            .with_context(|| format!("open {}", path.display()))?;
+        file.write_all(self.render().as_bytes())
+            .await
+            .with_context(|| format!("write {}", path.display()))
+    }
+
+    fn render(&self) -> String {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let mut script = format!(
+            "# {} at {}.{:09}\ncd {}\nenv -i",
+            self.operation,
+            time.as_secs(),
+            time.subsec_nanos(),
+            quote(&self.cwd.to_string_lossy())
+        );
+        for (key, value) in &self.env {
+            let _ = write!(script, " {}", quote(&format!("{}={}", key, value)));
+        }
+        let _ = write!(script, " {}", quote(&self.program));
+        for arg in &self.args {
+            let _ = write!(script, " {}", quote(arg));
+        }
+        script.push('\n');
+        script
+    }
+}
+
+/// Quote the value for a POSIX shell.
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn quote_value() {
+        assert_eq!(quote("runc"), "'runc'");
+        assert_eq!(quote("it's"), r"'it'\''s'");
+        assert_eq!(quote(""), "''");
+    }
+
+    #[tokio::test]
+    async fn record() -> Result<()> {
+        let dir = tempdir()?;
+        let path = Invocation::record_path(dir.path(), "ctr");
+        let mut sut = Invocation::new("create", "/usr/bin/runc", &["create", "ctr"]);
+        sut.env = vec![("PATH".into(), "/usr/bin".into())];
+        sut.cwd = "/".into();
+        sut.record(&path).await?;
+        sut.operation = "exec";
+        sut.record(&path).await?;
+
+        let content = tokio::fs::read_to_string(&path).await?;
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].starts_with("# create at "));
+        assert_eq!(lines[1], "cd '/'");
+        assert_eq!(
+            lines[2],
+            "env -i 'PATH=/usr/bin' '/usr/bin/runc' 'create' 'ctr'"
+        );
+        assert!(lines[3].starts_with("# exec at "));
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+//! Per container IO statistics.
+
+use crate::container_io::Pipe;
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+#[derive(Debug, Default)]
+/// IO counters of a single container, which can be updated concurrently.
+pub struct IOStats {
+    stdout_bytes: AtomicU64,
+    stderr_bytes: AtomicU64,
+    attach_bytes: AtomicU64,
+    buffered_bytes: AtomicU64,
+    stalls: AtomicU64,
+}
+
+impl IOStats {
+    /// Forwarding read data slower than this is counted as a stall.
+    pub const STALL_THRESHOLD: Duration = Duration::from_millis(100);
+
+    /// Record bytes read from the provided container pipe.
+    pub fn record_read(&self, pipe: Pipe, bytes: usize) {
+        let counter = match pipe {
+            Pipe::StdOut => &self.stdout_bytes,
+            Pipe::StdErr => &self.stderr_bytes,
+        };
+        counter.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// Record bytes forwarded to attach endpoints.
+    pub fn record_attach(&self, bytes: usize) {
+        self.attach_bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// Record bytes which got buffered for a later consumer.
+    pub fn record_buffered(&self, bytes: usize) {
+        self.buffered_bytes
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// Record bytes which got removed from the buffer by a consumer.
+    pub fn record_consumed(&self, bytes: usize) {
+        let _ = self
+            .buffered_bytes
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| {
+                Some(x.saturating_sub(bytes as u64))
+            });
+    }
+
+    /// Record the time it took to forward read data, which counts as stall if it exceeds the
+    /// threshold.
+    pub fn record_forward_duration(&self, duration: Duration) {
+        if duration > Self::STALL_THRESHOLD {
+            self.stalls.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Bytes read from the standard output of the container.
+    pub fn stdout_bytes(&self) -> u64 {
+        self.stdout_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Bytes read from the standard error of the container.
+    pub fn stderr_bytes(&self) -> u64 {
+        self.stderr_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Bytes forwarded to attach endpoints.
+    pub fn attach_bytes(&self) -> u64 {
+        self.attach_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Bytes read from the container but not consumed yet.
+    pub fn buffered_bytes(&self) -> u64 {
+        self.buffered_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Amount of times forwarding read data exceeded the stall threshold.
+    pub fn stalls(&self) -> u64 {
+        self.stalls.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record() {
+        let sut = IOStats::default();
+        sut.record_read(Pipe::StdOut, 10);
+        sut.record_read(Pipe::StdErr, 5);
+        sut.record_read(Pipe::StdOut, 1);
+        sut.record_attach(3);
+        assert_eq!(sut.stdout_bytes(), 11);
+        assert_eq!(sut.stderr_bytes(), 5);
+        assert_eq!(sut.attach_bytes(), 3);
+
+        sut.record_buffered(10);
+        sut.record_consumed(4);
+        assert_eq!(sut.buffered_bytes(), 6);
+        sut.record_consumed(10);
+        assert_eq!(sut.buffered_bytes(), 0);
+
+        sut.record_forward_duration(Duration::from_millis(1));
+        sut.record_forward_duration(IOStats::STALL_THRESHOLD * 2);
+        assert_eq!(sut.stalls(), 1);
+    }
+}
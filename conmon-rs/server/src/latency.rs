@@ -0,0 +1,211 @@
+//! Latency tracking of RPC requests.
+
+use anyhow::{format_err, Result};
+use capnp::{
+    capability::{Params, Promise},
+    message,
+    traits::Owned,
+    Error,
+};
+use futures::FutureExt;
+use getset::{CopyGetters, Getters};
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::Hasher,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// Upper bounds of the histogram buckets. Slower requests are counted in an additional
+/// overflow bucket.
+pub const BUCKETS: [Duration; 12] = [
+    Duration::from_millis(1),
+    Duration::from_millis(5),
+    Duration::from_millis(10),
+    Duration::from_millis(25),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+    Duration::from_secs(30),
+];
+
+#[derive(Clone, CopyGetters, Debug, Default, Eq, Getters, PartialEq)]
+/// Latency histogram of a single RPC method.
+pub struct Histogram {
+    #[getset(get = "pub")]
+    /// Requests per bucket of `BUCKETS`, followed by the overflow bucket.
+    buckets: [u64; BUCKETS.len() + 1],
+
+    #[getset(get_copy = "pub")]
+    /// Total amount of requests.
+    count: u64,
+
+    #[getset(get_copy = "pub")]
+    /// Sum of the latencies of all requests.
+    sum: Duration,
+
+    #[getset(get_copy = "pub")]
+    /// Highest latency of a single request.
+    max: Duration,
+
+    #[getset(get_copy = "pub")]
+    /// Amount of requests exceeding the slow request threshold.
+    slow: u64,
+}
+
+impl Histogram {
+    fn record(&mut self, latency: Duration, slow: bool) {
+        let bucket = BUCKETS
+            .iter()
+            .position(|bound| latency <= *bound)
+            .unwrap_or(BUCKETS.len());
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.sum += latency;
+        self.max = self.max.max(latency);
+        if slow {
+            self.slow += 1;
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+/// Latency histograms of all RPC methods, which can be updated concurrently.
+pub struct Latencies {
+    methods: Mutex<BTreeMap<&'static str, Histogram>>,
+}
+
+impl Latencies {
+    /// Start timing a request of `method`. The request is logged if it exceeds the optional
+    /// `slow_threshold`, in which case the digest of its `params` identifies it.
+    pub fn start<T>(
+        self: &Arc<Self>,
+        method: &'static str,
+        slow_threshold: Option<Duration>,
+        params: &Params<T>,
+    ) -> RequestTimer
+    where
+        T: for<'a> Owned<'a>,
+    {
+        RequestTimer {
+            latencies: self.clone(),
+            method,
+            start: Instant::now(),
+            slow: slow_threshold.map(|threshold| {
+                let digest = digest(params).unwrap_or_else(|e| format!("unavailable: {:#}", e));
+                (threshold, digest)
+            }),
+        }
+    }
+
+    /// The histograms of all methods which received at least one request, sorted by method.
+    pub fn snapshot(&self) -> Result<Vec<(&'static str, Histogram)>> {
+        Ok(self
+            .methods
+            .lock()
+            .map_err(|e| format_err!("{:#}", e))?
+            .iter()
+            .map(|(method, histogram)| (*method, histogram.clone()))
+            .collect())
+    }
+
+    fn record(&self, method: &'static str, latency: Duration, slow: bool) {
+        if let Ok(mut methods) = self.methods.lock() {
+            methods.entry(method).or_default().record(latency, slow);
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Timer of a single request, started by `Latencies::start`.
+pub struct RequestTimer {
+    latencies: Arc<Latencies>,
+    method: &'static str,
+    start: Instant,
+    slow: Option<(Duration, String)>,
+}
+
+impl RequestTimer {
+    /// Record the latency of the request once the promise completed.
+    pub fn observe(self, promise: Promise<(), Error>) -> Promise<(), Error> {
+        Promise::from_future(promise.map(move |res| {
+            self.finish(res.is_ok());
+            res
+        }))
+    }
+
+    fn finish(self, success: bool) {
+        let latency = self.start.elapsed();
+        let slow = match &self.slow {
+            Some((threshold, digest)) if latency > *threshold => {
+                warn!(
+                    success,
+                    "Slow {} request took {:?} (params digest {})", self.method, latency, digest
+                );
+                true
+            }
+            _ => false,
+        };
+        self.latencies.record(self.method, latency, slow);
+    }
+}
+
+/// Digest of the canonicalized request parameters, which identifies equal requests in logs
+/// without exposing their content.
+fn digest<T>(params: &Params<T>) -> Result<String>
+where
+    T: for<'a> Owned<'a>,
+{
+    let mut message = message::Builder::new_default();
+    message.set_root_canonical(params.get()?)?;
+    let mut hasher = DefaultHasher::new();
+    let mut size = 0;
+    for segment in message.get_segments_for_output().iter() {
+        hasher.write(segment);
+        size += segment.len();
+    }
+    Ok(format!("{:016x}/{}B", hasher.finish(), size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_record() {
+        let mut sut = Histogram::default();
+        sut.record(Duration::from_micros(500), false);
+        sut.record(Duration::from_millis(1), false);
+        sut.record(Duration::from_millis(7), false);
+        sut.record(Duration::from_secs(60), true);
+
+        assert_eq!(sut.buckets()[0], 2);
+        assert_eq!(sut.buckets()[2], 1);
+        assert_eq!(sut.buckets()[BUCKETS.len()], 1);
+        assert_eq!(sut.count(), 4);
+        assert_eq!(sut.sum(), Duration::from_micros(60_008_500));
+        assert_eq!(sut.max(), Duration::from_secs(60));
+        assert_eq!(sut.slow(), 1);
+    }
+
+    #[test]
+    fn latencies_snapshot() -> Result<()> {
+        let sut = Latencies::default();
+        sut.record("version", Duration::from_millis(2), false);
+        sut.record("create_container", Duration::from_secs(2), true);
+        sut.record("version", Duration::from_millis(3), false);
+
+        let snapshot = sut.snapshot()?;
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].0, "create_container");
+        assert_eq!(snapshot[0].1.slow(), 1);
+        assert_eq!(snapshot[1].0, "version");
+        assert_eq!(snapshot[1].1.count(), 2);
+        Ok(())
+    }
+}
@@ -1,18 +1,70 @@
+pub use helper::run_if_requested as run_helper_if_requested;
 pub use server::Server;
 pub use version::Version;
 
+#[macro_use]
+mod macros;
+
 mod attach;
+mod attach_protocol;
+mod bundle;
+mod cgroup_stats;
 mod child;
 mod child_reaper;
+mod clock;
 mod config;
 mod container_io;
 mod container_log;
+mod crash;
 mod cri_logger;
+#[cfg_attr(not(feature = "checkpoint"), allow(dead_code))]
+mod criu;
+mod dispatcher;
+mod encoding;
+mod event_stream;
+mod events;
+mod exec_sessions;
+mod fd_inventory;
+mod file_watcher;
+mod freezer;
+mod health;
+mod helper;
+mod idempotency;
+mod idle_audit;
 mod init;
+mod invocation;
+mod io_stats;
+mod latency;
+mod limits;
 mod listener;
+mod log_index;
+mod log_level;
+mod log_quota;
+mod log_xattrs;
+mod negotiate;
+mod oom_kills;
 mod oom_watcher;
+mod overrides;
+mod panic_guard;
+mod pidfd;
+mod pod_logger;
+mod pressure;
+mod processes;
+mod resources;
 mod rpc;
+mod rpc_error;
+mod runtime_config;
+mod runtime_log;
+mod rusage;
 mod server;
+mod sharded_map;
+mod shutdown;
+mod sigchld;
+mod soak;
 mod streams;
+mod supervisor;
+mod tee;
+mod tenant;
 mod terminal;
+mod validate;
 mod version;
@@ -0,0 +1,159 @@
+//! Size limits for RPC requests.
+
+use capnp::{message::ReaderOptions, text_list};
+use std::{error, fmt};
+
+/// Maximum length of a path field in bytes.
+pub const MAX_PATH_LEN: u32 = libc::PATH_MAX as u32;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// Error returned if a request exceeds a configured limit.
+pub enum LimitError {
+    /// A list field contains more entries than allowed.
+    ListTooLong {
+        /// Name of the request field.
+        field: &'static str,
+
+        /// Length of the provided list.
+        len: u32,
+
+        /// Maximum allowed length.
+        max: u32,
+    },
+
+    /// A text field is larger than allowed.
+    TextTooLong {
+        /// Name of the request field.
+        field: &'static str,
+
+        /// Size of the provided text in bytes.
+        len: usize,
+
+        /// Maximum allowed size in bytes.
+        max: u32,
+    },
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ListTooLong { field, len, max } => write!(
+                f,
+                "request field '{}' exceeds limit: {} entries (max {})",
+                field, len, max
+            ),
+            Self::TextTooLong { field, len, max } => write!(
+                f,
+                "request field '{}' exceeds limit: {} bytes (max {})",
+                field, len, max
+            ),
+        }
+    }
+}
+
+impl error::Error for LimitError {}
+
+/// Verify that a list field of length `len` does not exceed `max` entries.
+/// This should be called before iterating the list to avoid allocating oversized requests.
+pub fn check_list_len(field: &'static str, len: u32, max: u32) -> Result<(), LimitError> {
+    if len > max {
+        return Err(LimitError::ListTooLong { field, len, max });
+    }
+    Ok(())
+}
+
+/// Verify that a text field of `len` bytes does not exceed `max` bytes.
+/// This should be called before copying the text to avoid allocating oversized requests.
+pub fn check_text_len(field: &'static str, len: usize, max: u32) -> Result<(), LimitError> {
+    if len > max as usize {
+        return Err(LimitError::TextTooLong { field, len, max });
+    }
+    Ok(())
+}
+
+/// Verify that no entry of the text list field `list` exceeds `max` bytes. Entries which
+/// cannot be read are left to the caller, which fails on reading them.
+pub fn check_text_list(
+    field: &'static str,
+    list: text_list::Reader,
+    max: u32,
+) -> Result<(), LimitError> {
+    list.iter()
+        .flatten()
+        .try_for_each(|text| check_text_len(field, text.len(), max))
+}
+
+/// Create the capnp reader options for the provided maximum message size in bytes.
+pub fn reader_options(max_message_size: usize) -> ReaderOptions {
+    let mut options = ReaderOptions::new();
+    options.traversal_limit_in_words(Some(max_message_size / 8));
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_list_len_success() {
+        assert!(check_list_len("command", 0, 0).is_ok());
+        assert!(check_list_len("command", 10, 10).is_ok());
+    }
+
+    #[test]
+    fn check_list_len_failure() {
+        assert_eq!(
+            check_list_len("command", 11, 10),
+            Err(LimitError::ListTooLong {
+                field: "command",
+                len: 11,
+                max: 10
+            })
+        );
+    }
+
+    #[test]
+    fn check_text_len_success() {
+        assert!(check_text_len("id", 0, 0).is_ok());
+        assert!(check_text_len("id", 10, 10).is_ok());
+    }
+
+    #[test]
+    fn check_text_len_failure() {
+        assert_eq!(
+            check_text_len("bundlePath", 4097, MAX_PATH_LEN),
+            Err(LimitError::TextTooLong {
+                field: "bundlePath",
+                len: 4097,
+                max: 4096
+            })
+        );
+    }
+
+    #[test]
+    fn check_text_list_failure() {
+        let mut message = capnp::message::Builder::new_default();
+        let mut list = message
+            .init_root::<capnp::any_pointer::Builder>()
+            .initn_as::<text_list::Builder>(2);
+        list.set(0, "ls");
+        list.set(1, "too long");
+        let list = list.into_reader();
+
+        assert!(check_text_list("command", list, 8).is_ok());
+        assert_eq!(
+            check_text_list("command", list, 7),
+            Err(LimitError::TextTooLong {
+                field: "command",
+                len: 8,
+                max: 7
+            })
+        );
+    }
+
+    #[test]
+    fn reader_options_limit() {
+        let options = reader_options(1024);
+        assert_eq!(options.traversal_limit_in_words, Some(128));
+    }
+}
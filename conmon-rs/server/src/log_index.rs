@@ -0,0 +1,185 @@
+//! Sidecar index of log files, allowing to seek into them by time.
+
+use anyhow::{Context, Result};
+use getset::Getters;
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    fs::{self, File, OpenOptions},
+    io::{AsyncWriteExt, ErrorKind},
+};
+use tracing::debug;
+
+#[derive(Debug, Getters)]
+/// Index of a log file, which periodically records byte offsets of the log together with the
+/// time they got written. Every entry is a single line in the format:
+///
+/// ```text
+/// <offset> <nanoseconds since the UNIX epoch>
+/// ```
+///
+/// All log lines before an offset have been written at or before the time of its entry.
+pub struct LogIndex {
+    #[getset(get = "pub")]
+    /// Path to the index file on disk.
+    path: PathBuf,
+
+    /// Open file handle of the `path`.
+    file: Option<File>,
+
+    /// Current size of the indexed log file.
+    offset: u64,
+
+    /// Offset of the last written entry.
+    last_entry: u64,
+}
+
+impl LogIndex {
+    /// The amount of log bytes between two index entries.
+    pub const INTERVAL: u64 = 1024 * 1024;
+
+    /// Create a new index for the log file at `log_path`.
+    pub fn new(log_path: &Path) -> Self {
+        Self {
+            path: Self::path_for(log_path),
+            file: None,
+            offset: 0,
+            last_entry: 0,
+        }
+    }
+
+    /// The path of the index belonging to the log file at `log_path`.
+    pub fn path_for(log_path: &Path) -> PathBuf {
+        let mut path = OsString::from(log_path);
+        path.push(".idx");
+        path.into()
+    }
+
+    /// Open the index file, which truncates it together with the log file.
+    pub async fn init(&mut self) -> Result<()> {
+        debug!("Initializing log index in path {}", self.path().display());
+        self.file = OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .mode(0o600)
+            .open(self.path())
+            .await
+            .context(format!("open log index path '{}'", self.path().display()))?
+            .into();
+        self.offset = 0;
+        self.last_entry = 0;
+        Ok(())
+    }
+
+    /// Account for `bytes` written into the log file.
+    pub fn advance(&mut self, bytes: usize) {
+        self.offset += bytes as u64;
+    }
+
+    /// Write a new entry for the current offset if at least `INTERVAL` bytes have been written
+    /// since the last one.
+    pub async fn checkpoint(&mut self) -> Result<()> {
+        if self.offset - self.last_entry < Self::INTERVAL {
+            return Ok(());
+        }
+        let entry = format!("{} {}\n", self.offset, now());
+        if let Some(file) = self.file.as_mut() {
+            file.write_all(entry.as_bytes())
+                .await
+                .context("write log index entry")?;
+        }
+        self.last_entry = self.offset;
+        Ok(())
+    }
+
+    /// Close the index file and remove it from disk. A missing file is not considered an error.
+    pub async fn remove(&mut self) -> Result<()> {
+        self.file = None;
+        match fs::remove_file(self.path()).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => {
+                Err(e).context(format!("remove log index '{}'", self.path().display()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the greatest indexed offset of the log file at `log_path` which has been written
+    /// before `since`, in nanoseconds since the UNIX epoch. All log lines written at or after
+    /// `since` are located behind the offset. Returns zero if the log is not indexed.
+    pub async fn offset_before(log_path: &Path, since: u64) -> Result<u64> {
+        let path = Self::path_for(log_path);
+        let content = match fs::read_to_string(&path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e).context(format!("read log index '{}'", path.display())),
+        };
+        Ok(content
+            .lines()
+            .filter_map(|line| {
+                let (offset, timestamp) = line.split_once(' ')?;
+                Some((offset.parse::<u64>().ok()?, timestamp.parse::<u64>().ok()?))
+            })
+            .take_while(|(_, timestamp)| *timestamp < since)
+            .last()
+            .map(|(offset, _)| offset)
+            .unwrap_or_default())
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn checkpoint_and_lookup() -> Result<()> {
+        let dir = tempdir()?;
+        let log_path = dir.path().join("log");
+        let mut sut = LogIndex::new(&log_path);
+        assert_eq!(sut.path(), &dir.path().join("log.idx"));
+        assert_eq!(LogIndex::offset_before(&log_path, now()).await?, 0);
+
+        sut.init().await?;
+        sut.advance(10);
+        sut.checkpoint().await?;
+        assert_eq!(fs::read_to_string(sut.path()).await?, "");
+
+        sut.advance(LogIndex::INTERVAL as usize);
+        sut.checkpoint().await?;
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        let between = now();
+        sut.advance(LogIndex::INTERVAL as usize);
+        sut.checkpoint().await?;
+
+        assert_eq!(LogIndex::offset_before(&log_path, 0).await?, 0);
+        assert_eq!(
+            LogIndex::offset_before(&log_path, between).await?,
+            LogIndex::INTERVAL + 10
+        );
+        assert_eq!(
+            LogIndex::offset_before(&log_path, u64::MAX).await?,
+            2 * LogIndex::INTERVAL + 10
+        );
+
+        // Reinitializing truncates the index together with the log
+        sut.init().await?;
+        assert_eq!(LogIndex::offset_before(&log_path, u64::MAX).await?, 0);
+
+        sut.remove().await?;
+        assert!(!sut.path().exists());
+        sut.remove().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//! Runtime adjustable log level of the server.
+
+use anyhow::{bail, format_err, Context, Result};
+use std::str::FromStr;
+use tracing_subscriber::{filter::LevelFilter, reload, Registry};
+
+/// The filter layer which has to be attached to the logger to apply the log level.
+pub type LogLevelFilter = reload::Layer<LevelFilter, Registry>;
+
+#[derive(Clone, Debug)]
+/// The log level of the server, which can be changed without restarting it.
+pub struct LogLevel {
+    handle: reload::Handle<LevelFilter, Registry>,
+}
+
+impl LogLevel {
+    /// Levels in the order they get cycled through, from the least to the most verbose one.
+    const CYCLE: [LevelFilter; 5] = [
+        LevelFilter::ERROR,
+        LevelFilter::WARN,
+        LevelFilter::INFO,
+        LevelFilter::DEBUG,
+        LevelFilter::TRACE,
+    ];
+
+    /// Create a new log level as well as the filter for the logger.
+    pub fn new(level: &str) -> Result<(Self, LogLevelFilter)> {
+        let level = LevelFilter::from_str(level).context("convert log level filter")?;
+        let (filter, handle) = reload::Layer::new(level);
+        Ok((Self { handle }, filter))
+    }
+
+    /// The currently active log level.
+    pub fn current(&self) -> Result<LevelFilter> {
+        self.handle
+            .clone_current()
+            .context("logger does not exist any more")
+    }
+
+    /// Set a new log level and return the previous one.
+    pub fn set(&self, level: &str) -> Result<LevelFilter> {
+        if level.is_empty() {
+            bail!("no log level provided")
+        }
+        let level = LevelFilter::from_str(level).context("convert log level filter")?;
+        let previous = self.current()?;
+        self.reload(level)?;
+        Ok(previous)
+    }
+
+    /// Switch to the next more verbose log level, wrapping around to `error` after `trace`.
+    /// Returns the new log level.
+    pub fn cycle(&self) -> Result<LevelFilter> {
+        let current = self.current()?;
+        let next = Self::CYCLE
+            .iter()
+            .find(|x| **x > current)
+            .copied()
+            .unwrap_or(Self::CYCLE[0]);
+        self.reload(next)?;
+        Ok(next)
+    }
+
+    fn reload(&self, level: LevelFilter) -> Result<()> {
+        self.handle
+            .reload(level)
+            .map_err(|e| format_err!("reload log level: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set() -> Result<()> {
+        let (sut, _filter) = LogLevel::new("info")?;
+        assert_eq!(sut.current()?, LevelFilter::INFO);
+
+        assert_eq!(sut.set("trace")?, LevelFilter::INFO);
+        assert_eq!(sut.current()?, LevelFilter::TRACE);
+
+        assert!(sut.set("").is_err());
+        assert!(sut.set("wrong").is_err());
+        assert_eq!(sut.current()?, LevelFilter::TRACE);
+        Ok(())
+    }
+
+    #[test]
+    fn cycle() -> Result<()> {
+        let (sut, _filter) = LogLevel::new("debug")?;
+        assert_eq!(sut.cycle()?, LevelFilter::TRACE);
+        assert_eq!(sut.cycle()?, LevelFilter::ERROR);
+        assert_eq!(sut.cycle()?, LevelFilter::WARN);
+
+        sut.set("off")?;
+        assert_eq!(sut.cycle()?, LevelFilter::ERROR);
+        Ok(())
+    }
+
+    #[test]
+    fn filter_dropped() -> Result<()> {
+        let (sut, filter) = LogLevel::new("info")?;
+        drop(filter);
+        assert!(sut.current().is_err());
+        assert!(sut.cycle().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+//! Quota of the on-disk bytes of a CRI log including its rotations.
+//!
+//! Rotated files, like the ones created by the kubelet before it requests reopening the log,
+//! are located next to the log file. Their names start with the name of the log file followed
+//! by a dot, for example `0.log.20220816-150405` or `0.log.20220816-150405.gz`.
+
+use crate::log_index::LogIndex;
+use anyhow::{Context, Result};
+use getset::CopyGetters;
+use std::{io::ErrorKind, os::unix::ffi::OsStrExt, path::Path};
+use tokio::fs;
+use tracing::debug;
+
+#[derive(Clone, Copy, Debug, Default, CopyGetters, Eq, PartialEq)]
+#[getset(get_copy = "pub")]
+/// The on-disk bytes of one or more logs.
+pub struct DiskUsage {
+    /// Bytes of the log files, their indexes and all rotations.
+    total_bytes: u64,
+
+    /// Bytes of the rotations removed to stay within the quota.
+    pruned_bytes: u64,
+}
+
+impl DiskUsage {
+    /// Create a new disk usage.
+    pub fn new(total_bytes: u64, pruned_bytes: u64) -> Self {
+        Self {
+            total_bytes,
+            pruned_bytes,
+        }
+    }
+
+    /// Combine the disk usage of two logs.
+    pub fn combine(self, other: Self) -> Self {
+        Self::new(
+            self.total_bytes + other.total_bytes,
+            self.pruned_bytes + other.pruned_bytes,
+        )
+    }
+}
+
+/// Determine the disk usage of the log at `log_path` and remove its oldest rotations until the
+/// usage does not exceed the `quota` any more. The log file and its index are never removed.
+pub async fn enforce(log_path: &Path, quota: Option<u64>) -> Result<DiskUsage> {
+    let name = log_path
+        .file_name()
+        .context("log path has no file name")?
+        .to_os_string();
+    let index_name = LogIndex::path_for(Path::new(&name)).into_os_string();
+    let mut prefix = name.clone();
+    prefix.push(".");
+
+    let dir = log_path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let mut entries = fs::read_dir(dir)
+        .await
+        .with_context(|| format!("read log directory {}", dir.display()))?;
+
+    let mut total_bytes = 0;
+    let mut rotations = vec![];
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .with_context(|| format!("read entry of log directory {}", dir.display()))?
+    {
+        let file_name = entry.file_name();
+        let rotation =
+            file_name != index_name && file_name.as_bytes().starts_with(prefix.as_bytes());
+        if file_name != name && file_name != index_name && !rotation {
+            continue;
+        }
+        let metadata = match entry.metadata().await {
+            Ok(metadata) if metadata.is_file() => metadata,
+            // Removed in the meantime or not a regular file
+            _ => continue,
+        };
+        total_bytes += metadata.len();
+        if rotation {
+            rotations.push((metadata.modified()?, entry.path(), metadata.len()));
+        }
+    }
+
+    let mut pruned_bytes = 0;
+    if let Some(quota) = quota {
+        // Oldest first
+        rotations.sort();
+        for (_, path, len) in rotations {
+            if total_bytes <= quota {
+                break;
+            }
+            debug!("Pruning log rotation {}", path.display());
+            remove(&path).await?;
+            total_bytes -= len;
+            pruned_bytes += len;
+        }
+    }
+    Ok(DiskUsage::new(total_bytes, pruned_bytes))
+}
+
+async fn remove(path: &Path) -> Result<()> {
+    match fs::remove_file(path).await {
+        Err(e) if e.kind() != ErrorKind::NotFound => {
+            Err(e).with_context(|| format!("remove log rotation {}", path.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, thread, time::Duration};
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn enforce() -> Result<()> {
+        let dir = tempdir()?;
+        let log = dir.path().join("0.log");
+        fs::write(&log, [0; 10])?;
+        fs::write(LogIndex::path_for(&log), [0; 5])?;
+        fs::write(dir.path().join("1.log"), [0; 100])?;
+        for name in ["0.log.1", "0.log.2.gz", "0.log.3"] {
+            fs::write(dir.path().join(name), [0; 20])?;
+            // Ensure distinct modification times
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        let usage = super::enforce(&log, None).await?;
+        assert_eq!(usage, DiskUsage::new(75, 0));
+
+        let usage = super::enforce(&log, Some(60)).await?;
+        assert_eq!(usage, DiskUsage::new(55, 20));
+        assert!(!dir.path().join("0.log.1").exists());
+        assert!(dir.path().join("0.log.2.gz").exists());
+
+        // The log itself is never pruned
+        let usage = super::enforce(&log, Some(0)).await?;
+        assert_eq!(usage, DiskUsage::new(15, 40));
+        assert!(log.exists());
+        assert!(dir.path().join("1.log").exists());
+        Ok(())
+    }
+}
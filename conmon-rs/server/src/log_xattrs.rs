@@ -0,0 +1,138 @@
+//! Extended attributes identifying the owner of log files, which allows external collectors and
+//! retention tools to skip parsing the log paths.
+
+use anyhow::{Context, Result};
+use getset::Getters;
+use libc::c_void;
+use nix::errno::Errno;
+use std::{collections::HashMap, ffi::CString, os::unix::io::AsRawFd};
+use tz::UtcDateTime;
+
+#[derive(Clone, Debug, Default, Eq, Getters, PartialEq)]
+#[getset(get = "pub")]
+/// The owner of a log file, which gets written into its extended attributes.
+pub struct LogXattrs {
+    /// Identifier of the container, not set for the logs shared by a pod.
+    container_id: Option<String>,
+
+    /// UID of the pod the container belongs to, if known.
+    pod_uid: Option<String>,
+}
+
+impl LogXattrs {
+    /// Attribute containing the container identifier.
+    pub const CONTAINER_ID: &'static str = "user.conmon-rs.container-id";
+
+    /// Attribute containing the pod UID.
+    pub const POD_UID: &'static str = "user.conmon-rs.pod-uid";
+
+    /// Attribute containing the RFC3339 UTC time when the file got (re)created.
+    pub const CREATED: &'static str = "user.conmon-rs.created";
+
+    /// Bundle annotations containing the pod UID, as set by CRI-O and containerd.
+    const POD_UID_ANNOTATIONS: [&'static str; 2] =
+        ["io.kubernetes.pod.uid", "io.kubernetes.cri.sandbox-uid"];
+
+    /// Create the attributes of the logs of the container `container_id`. The pod UID is taken
+    /// from the bundle annotations.
+    pub fn new(container_id: &str, annotations: &HashMap<String, String>) -> Self {
+        Self {
+            container_id: Some(container_id.into()),
+            pod_uid: Self::POD_UID_ANNOTATIONS
+                .iter()
+                .find_map(|key| annotations.get(*key))
+                .cloned(),
+        }
+    }
+
+    /// The attributes of a log file shared by all containers of the pod.
+    pub fn for_pod(&self) -> Self {
+        Self {
+            container_id: None,
+            pod_uid: self.pod_uid.clone(),
+        }
+    }
+
+    /// Set the attributes on the provided file, including the current time as creation time.
+    pub fn apply<T: AsRawFd>(&self, file: &T) -> Result<()> {
+        if let Some(container_id) = self.container_id() {
+            set(file, Self::CONTAINER_ID, container_id)?;
+        }
+        if let Some(pod_uid) = self.pod_uid() {
+            set(file, Self::POD_UID, pod_uid)?;
+        }
+        let now = UtcDateTime::now().context("get current time")?;
+        set(file, Self::CREATED, &now.to_string())
+    }
+}
+
+fn set<T: AsRawFd>(file: &T, name: &str, value: &str) -> Result<()> {
+    let c_name = CString::new(name)?;
+    let res = unsafe {
+        libc::fsetxattr(
+            file.as_raw_fd(),
+            c_name.as_ptr(),
+            value.as_ptr() as *const c_void,
+            value.len(),
+            0,
+        )
+    };
+    Errno::result(res).with_context(|| format!("set extended attribute {}", name))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempfile;
+
+    fn get<T: AsRawFd>(file: &T, name: &str) -> Result<Option<String>> {
+        let c_name = CString::new(name)?;
+        let mut buf = [0u8; 256];
+        let res = unsafe {
+            libc::fgetxattr(
+                file.as_raw_fd(),
+                c_name.as_ptr(),
+                buf.as_mut_ptr() as *mut c_void,
+                buf.len(),
+            )
+        };
+        match Errno::result(res) {
+            Ok(n) => Ok(Some(String::from_utf8(buf[..n as usize].to_vec())?)),
+            Err(Errno::ENODATA) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[test]
+    fn new() {
+        let mut annotations = HashMap::new();
+        annotations.insert("io.kubernetes.pod.uid".to_string(), "uid".to_string());
+        let sut = LogXattrs::new("id", &annotations);
+        assert_eq!(sut.container_id().as_deref(), Some("id"));
+        assert_eq!(sut.pod_uid().as_deref(), Some("uid"));
+
+        let sut = sut.for_pod();
+        assert!(sut.container_id().is_none());
+        assert_eq!(sut.pod_uid().as_deref(), Some("uid"));
+
+        assert!(LogXattrs::new("id", &HashMap::new()).pod_uid().is_none());
+    }
+
+    #[test]
+    fn apply() -> Result<()> {
+        let file = tempfile()?;
+        let sut = LogXattrs::new("id", &HashMap::new());
+        match sut.apply(&file) {
+            Err(e) if e.downcast_ref::<Errno>() == Some(&Errno::EOPNOTSUPP) => {
+                // The filesystem does not support user extended attributes
+                return Ok(());
+            }
+            res => res?,
+        }
+        assert_eq!(get(&file, LogXattrs::CONTAINER_ID)?.as_deref(), Some("id"));
+        assert_eq!(get(&file, LogXattrs::POD_UID)?, None);
+        assert!(get(&file, LogXattrs::CREATED)?.is_some());
+        Ok(())
+    }
+}
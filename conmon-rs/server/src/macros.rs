@@ -0,0 +1,22 @@
+//! Macros shared by the modules of the server.
+
+/// Lock the provided mutex, where a poisoned lock returns an error.
+macro_rules! lock {
+    ($x:expr) => {
+        $x.lock().map_err(|e| anyhow::format_err!("{:#}", e))?
+    };
+}
+
+/// Lock the provided read-write lock for reading, where a poisoned lock returns an error.
+macro_rules! lock_read {
+    ($x:expr) => {
+        $x.read().map_err(|e| anyhow::format_err!("{:#}", e))?
+    };
+}
+
+/// Lock the provided read-write lock for writing, where a poisoned lock returns an error.
+macro_rules! lock_write {
+    ($x:expr) => {
+        $x.write().map_err(|e| anyhow::format_err!("{:#}", e))?
+    };
+}
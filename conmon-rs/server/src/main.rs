@@ -2,6 +2,9 @@ use anyhow::{Context, Result};
 use conmonrs::Server;
 
 fn main() -> Result<()> {
+    if let Some(res) = conmonrs::run_helper_if_requested() {
+        return res;
+    }
     Server::new()
         .context("create server")?
         .start()
@@ -0,0 +1,69 @@
+//! Schema version and feature negotiation between client and server.
+
+use strum::{AsRefStr, EnumIter, IntoEnumIterator};
+
+/// The latest schema version supported by the server.
+///
+/// - 1: the methods up to `requestLatencies`.
+/// - 2: the container lifecycle methods from `killContainer` up to `streamEvents`.
+pub const SCHEMA_VERSION: u32 = 2;
+
+#[derive(AsRefStr, Clone, Copy, Debug, EnumIter, Eq, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+/// Optional features supported by the server.
+pub enum Feature {
+    /// Attach sockets support the versioned protocol.
+    AttachProtocol,
+
+    /// Oversized requests are rejected before processing them.
+    RequestLimits,
+
+    /// Temporary files and sockets are isolated per caller.
+    TenantIsolation,
+
+    /// Containers can be checkpointed and restored.
+    #[cfg(feature = "checkpoint")]
+    Checkpoint,
+}
+
+/// Select the schema version to be used for a client supporting up to `client_version`.
+/// Older clients do not set the version at all, which is treated as version 1.
+pub fn schema_version(client_version: u32) -> u32 {
+    client_version.max(1).min(SCHEMA_VERSION)
+}
+
+/// Returns all server features which are supported by the client, too.
+pub fn common_features(client_features: &[&str]) -> Vec<Feature> {
+    Feature::iter()
+        .filter(|f| client_features.contains(&f.as_ref()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_version_old_client() {
+        assert_eq!(schema_version(0), 1);
+    }
+
+    #[test]
+    fn schema_version_older_client() {
+        assert_eq!(schema_version(1), 1);
+    }
+
+    #[test]
+    fn schema_version_newer_client() {
+        assert_eq!(schema_version(SCHEMA_VERSION + 1), SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn common_features_filters_unknown() {
+        assert_eq!(
+            common_features(&["unknown", "tenant-isolation", "request-limits"]),
+            vec![Feature::RequestLimits, Feature::TenantIsolation]
+        );
+        assert!(common_features(&[]).is_empty());
+    }
+}
@@ -0,0 +1,285 @@
+//! Detection of OOM kills of descendant container processes.
+//!
+//! The OOM killer may pick any process of the container cgroup, in which case the container
+//! init keeps running and no OOM gets reported by its exit. Those kills are detected by the
+//! `oom_kill` counters of cgroup v2: `memory.events` counts the kills of the whole subtree,
+//! while `memory.events.local` only counts the ones of the container cgroup itself. The killed
+//! PID and command name get correlated from the kernel log if it is readable.
+
+use crate::{
+    events::{EventBus, OomVictim},
+    oom_watcher::{OOMWatcher, CGROUP_ROOT},
+};
+use anyhow::{Context, Result};
+use nix::errno::Errno;
+use notify::{RecursiveMode, Watcher};
+use std::{
+    fs::{File, OpenOptions},
+    io::{ErrorKind, Read, Seek, SeekFrom},
+    os::unix::fs::OpenOptionsExt,
+    path::Path,
+    sync::Arc,
+};
+use tokio::{fs, task};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, debug_span, Instrument};
+
+/// Start watching the container with the init `pid` for OOM kills of other processes until
+/// the `token` gets cancelled. Does nothing on cgroup v1, which lacks the required counters.
+pub fn watch(token: CancellationToken, container_id: String, pid: u32, events: Arc<EventBus>) {
+    if !OOMWatcher::is_cgroup_v2() {
+        return;
+    }
+    task::spawn(
+        async move {
+            if let Err(e) = run(token, &container_id, pid, &events).await {
+                debug!("Stopped watching for descendant OOM kills: {:#}", e);
+            }
+        }
+        .instrument(debug_span!("oom_kills", pid)),
+    );
+}
+
+async fn run(
+    token: CancellationToken,
+    container_id: &str,
+    pid: u32,
+    events: &EventBus,
+) -> Result<()> {
+    let cgroup = OOMWatcher::process_cgroup_subsystem_path_cgroup_v2(pid).await?;
+    let memcg = cgroup
+        .strip_prefix(CGROUP_ROOT)
+        .map(|p| Path::new("/").join(p))
+        .context("strip cgroup root")?;
+    let memory_events = cgroup.join("memory.events");
+
+    let mut kernel_log = KernelLog::open()
+        .map_err(|e| debug!("Kernel log not available for OOM victims: {:#}", e))
+        .ok();
+    let mut last = Counters::read(&cgroup).await?;
+
+    let (mut watcher, mut rx) = OOMWatcher::async_watcher()?;
+    watcher.watch(&memory_events, RecursiveMode::NonRecursive)?;
+
+    loop {
+        tokio::select! {
+            _ = token.cancelled() => break,
+            res = rx.recv() => match res {
+                Some(Ok(event)) if event.kind.is_remove() => break,
+                Some(Ok(event)) if event.kind.is_modify() => {},
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => {
+                    debug!("Watch error: {:#}", e);
+                    break;
+                }
+                None => break,
+            }
+        }
+
+        // The cgroup disappears with the container.
+        let counters = match Counters::read(&cgroup).await {
+            Ok(counters) => counters,
+            Err(_) => break,
+        };
+        let (nested, local) = counters.since(&last);
+        last = counters;
+        if nested == 0 && local == 0 {
+            continue;
+        }
+
+        let victims = kernel_log
+            .as_mut()
+            .map(|log| log.oom_victims(&memcg.to_string_lossy()))
+            .unwrap_or_default();
+        if victims.is_empty() {
+            // Without the kernel log, kills in nested cgroups never hit the init process, while
+            // local kills are only attributed to other processes if the init process survives.
+            let kills = nested + if is_alive(pid).await { local } else { 0 };
+            for _ in 0..kills {
+                events.publish_descendant_oom(container_id, pid, None);
+            }
+            continue;
+        }
+        for victim in victims.into_iter().filter(|v| v.pid() != pid) {
+            debug!(victim = victim.pid(), "Found descendant OOM kill");
+            events.publish_descendant_oom(container_id, pid, Some(victim));
+        }
+    }
+
+    watcher.unwatch(&memory_events)?;
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// The `oom_kill` counters of a cgroup.
+struct Counters {
+    hierarchical: u64,
+    local: u64,
+}
+
+impl Counters {
+    async fn read(cgroup: &Path) -> Result<Self> {
+        let read = |name: &'static str| async move {
+            let path = cgroup.join(name);
+            fs::read_to_string(&path)
+                .await
+                .with_context(|| format!("read {}", path.display()))
+                .map(|content| oom_kill_counter(&content))
+        };
+        Ok(Self {
+            hierarchical: read("memory.events").await?,
+            local: read("memory.events.local").await?,
+        })
+    }
+
+    /// The number of new kills in nested cgroups and in the cgroup itself.
+    fn since(&self, last: &Self) -> (u64, u64) {
+        let local = self.local.saturating_sub(last.local);
+        let nested = self
+            .hierarchical
+            .saturating_sub(last.hierarchical)
+            .saturating_sub(local);
+        (nested, local)
+    }
+}
+
+/// Parse the `oom_kill` counter of a `memory.events` file.
+fn oom_kill_counter(content: &str) -> u64 {
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix("oom_kill "))
+        .and_then(|counter| counter.trim().parse().ok())
+        .unwrap_or_default()
+}
+
+/// Reads new records of `/dev/kmsg`, starting at the time of opening.
+struct KernelLog(File);
+
+impl KernelLog {
+    /// Maximum size of a single kernel log record.
+    const RECORD_SIZE: usize = 8192;
+
+    fn open() -> Result<Self> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open("/dev/kmsg")
+            .context("open /dev/kmsg")?;
+        file.seek(SeekFrom::End(0))
+            .context("seek to end of kernel log")?;
+        Ok(Self(file))
+    }
+
+    /// Read all pending records and return the OOM victims of the cgroup `memcg`, which is
+    /// relative to the cgroup root.
+    fn oom_victims(&mut self, memcg: &str) -> Vec<OomVictim> {
+        let mut victims = vec![];
+        let mut buf = vec![0; Self::RECORD_SIZE];
+        loop {
+            match self.0.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => {
+                    if let Some(victim) = parse_oom_kill(&String::from_utf8_lossy(&buf[..n]), memcg)
+                    {
+                        victims.push(victim);
+                    }
+                }
+                // Records got overwritten before they were read.
+                Err(e) if e.raw_os_error() == Some(Errno::EPIPE as i32) => continue,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    debug!("Unable to read kernel log: {:#}", e);
+                    break;
+                }
+            }
+        }
+        victims
+    }
+}
+
+/// Parse a kernel log record of the form `<prefix>;oom-kill:...,task_memcg=<cgroup>,task=<comm>,pid=<pid>,...`
+/// and return its victim if it belongs to the cgroup `memcg` or one of its children.
+fn parse_oom_kill(record: &str, memcg: &str) -> Option<OomVictim> {
+    let (_, message) = record.split_once(';')?;
+    let fields = message.lines().next()?.strip_prefix("oom-kill:")?;
+
+    let (mut task_memcg, mut comm, mut pid) = (None, None, None);
+    for field in fields.split(',') {
+        match field.split_once('=') {
+            Some(("task_memcg", value)) => task_memcg = Some(value),
+            Some(("task", value)) => comm = Some(value),
+            Some(("pid", value)) => pid = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    let task_memcg = task_memcg?;
+    let in_memcg = task_memcg == memcg
+        || task_memcg
+            .strip_prefix(memcg)
+            .map_or(false, |rest| rest.starts_with('/'));
+    if !in_memcg {
+        return None;
+    }
+    Some(OomVictim::new(pid?, comm.unwrap_or_default()))
+}
+
+/// Whether the process `pid` is still running and not a zombie.
+async fn is_alive(pid: u32) -> bool {
+    match fs::read_to_string(format!("/proc/{}/stat", pid)).await {
+        Ok(stat) => stat
+            .rsplit_once(')')
+            .and_then(|(_, rest)| rest.trim_start().chars().next())
+            .map_or(false, |state| state != 'Z' && state != 'X'),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_oom_kill_record() {
+        let record = "6,1234,5678,-;oom-kill:constraint=CONSTRAINT_MEMCG,nodemask=(null),\
+            cpuset=/,mems_allowed=0,oom_memcg=/pod/ctr,task_memcg=/pod/ctr/nested,\
+            task=stress,pid=42,uid=0\n";
+        assert_eq!(
+            parse_oom_kill(record, "/pod/ctr"),
+            Some(OomVictim::new(42, "stress"))
+        );
+        assert_eq!(
+            parse_oom_kill(record, "/pod/ctr/nested").map(|v| v.pid()),
+            Some(42)
+        );
+        assert_eq!(parse_oom_kill(record, "/pod/ct"), None);
+        assert_eq!(parse_oom_kill(record, "/other"), None);
+        assert_eq!(parse_oom_kill("6,1,2,-;Out of memory", "/pod/ctr"), None);
+    }
+
+    #[test]
+    fn counters() {
+        assert_eq!(
+            oom_kill_counter("low 0\nhigh 0\nmax 3\noom 2\noom_kill 2\n"),
+            2
+        );
+        assert_eq!(oom_kill_counter("low 0\n"), 0);
+
+        let last = Counters {
+            hierarchical: 1,
+            local: 1,
+        };
+        let current = Counters {
+            hierarchical: 4,
+            local: 2,
+        };
+        assert_eq!(current.since(&last), (2, 1));
+        assert_eq!(last.since(&last), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn alive() {
+        assert!(is_alive(std::process::id()).await);
+        assert!(!is_alive(u32::MAX).await);
+    }
+}
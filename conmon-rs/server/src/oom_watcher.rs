@@ -27,7 +27,7 @@ pub const CGROUP2_SUPER_MAGIC: FsType = FsType(libc::CGROUP2_SUPER_MAGIC as i32)
 )))]
 pub const CGROUP2_SUPER_MAGIC: FsType = FsType(libc::CGROUP2_SUPER_MAGIC as i64);
 
-static CGROUP_ROOT: &str = "/sys/fs/cgroup";
+pub static CGROUP_ROOT: &str = "/sys/fs/cgroup";
 
 lazy_static! {
     static ref IS_CGROUP_V2: bool = {
@@ -50,6 +50,11 @@ pub struct OOMEvent {
 }
 
 impl OOMWatcher {
+    /// Whether the host uses the unified cgroup v2 hierarchy.
+    pub fn is_cgroup_v2() -> bool {
+        *IS_CGROUP_V2
+    }
+
     pub async fn new(
         token: &CancellationToken,
         pid: u32,
@@ -154,7 +159,7 @@ impl OOMWatcher {
         Ok(())
     }
 
-    fn async_watcher() -> Result<(RecommendedWatcher, Receiver<notify::Result<Event>>)> {
+    pub fn async_watcher() -> Result<(RecommendedWatcher, Receiver<notify::Result<Event>>)> {
         let (tx, rx) = channel(1);
 
         let watcher = notify::recommended_watcher(move |res: Result<Event, Error>| {
@@ -297,7 +302,7 @@ impl OOMWatcher {
         Ok(())
     }
 
-    async fn process_cgroup_subsystem_path(
+    pub async fn process_cgroup_subsystem_path(
         pid: u32,
         is_cgroupv2: bool,
         subsystem: &str,
@@ -331,7 +336,7 @@ impl OOMWatcher {
         Err(anyhow!("no path found"))
     }
 
-    async fn process_cgroup_subsystem_path_cgroup_v2(pid: u32) -> Result<PathBuf> {
+    pub async fn process_cgroup_subsystem_path_cgroup_v2(pid: u32) -> Result<PathBuf> {
         lazy_static! {
             static ref RE: Regex = Regex::new(".*:.*:/(.*)").expect("could not compile regex");
         }
@@ -0,0 +1,190 @@
+//! Per container behavior overrides, configured by OCI annotations of the bundle.
+//!
+//! The following annotations are supported:
+//!
+//! - `io.conmon-rs/log-max-size`: maximum size of the CRI logs in bytes, which has to be greater
+//!   than zero. Pod logs are shared between containers and keep their limit.
+//! - `io.conmon-rs/max-drain-time`: time in milliseconds to keep forwarding the container
+//!   output after its exit.
+//! - `io.conmon-rs/log-disk-quota`: maximum on-disk bytes of the CRI logs including their
+//!   rotations, which has to be greater than zero.
+//!
+//! The annotations are controlled by the users creating pods, which means that the size and time
+//! overrides can only lower the limits of the server and the log drivers, but never raise them.
+//! Any other annotation with the `io.conmon-rs/` prefix gets ignored with a warning.
+
+use anyhow::{bail, Context, Result};
+use getset::CopyGetters;
+use std::{collections::HashMap, str::FromStr, time::Duration};
+use tracing::warn;
+
+#[derive(Clone, Copy, Debug, Default, CopyGetters, Eq, PartialEq)]
+#[getset(get_copy = "pub")]
+/// The validated overrides of a single container. `None` values keep the server defaults.
+pub struct Overrides {
+    /// Maximum size of the CRI logs.
+    log_max_size: Option<usize>,
+
+    /// Time to keep forwarding the container output after its exit.
+    max_drain_time: Option<Duration>,
+
+    /// Maximum on-disk bytes of the CRI logs.
+    log_disk_quota: Option<u64>,
+}
+
+impl Overrides {
+    /// Prefix of all annotations interpreted by conmon-rs.
+    pub const PREFIX: &'static str = "io.conmon-rs/";
+
+    /// Annotation overriding the maximum size of the CRI logs in bytes.
+    pub const LOG_MAX_SIZE: &'static str = "io.conmon-rs/log-max-size";
+
+    /// Annotation overriding the maximum drain time in milliseconds.
+    pub const MAX_DRAIN_TIME: &'static str = "io.conmon-rs/max-drain-time";
+
+    /// Annotation overriding the log disk quota in bytes.
+    pub const LOG_DISK_QUOTA: &'static str = "io.conmon-rs/log-disk-quota";
+
+    /// Parse and validate the overrides from the provided annotations. Annotations without the
+    /// `PREFIX` are ignored.
+    pub fn from_annotations(annotations: &HashMap<String, String>) -> Result<Self> {
+        let mut overrides = Self::default();
+        for (key, value) in annotations {
+            match key.as_str() {
+                Self::LOG_MAX_SIZE => {
+                    let size: usize = parse(key, value)?;
+                    if size == 0 {
+                        bail!("annotation {} has to be greater than zero", key)
+                    }
+                    overrides.log_max_size = Some(size);
+                }
+                Self::MAX_DRAIN_TIME => {
+                    overrides.max_drain_time = Some(Duration::from_millis(parse(key, value)?))
+                }
+                Self::LOG_DISK_QUOTA => {
+                    let quota: u64 = parse(key, value)?;
+                    if quota == 0 {
+                        bail!("annotation {} has to be greater than zero", key)
+                    }
+                    overrides.log_disk_quota = Some(quota);
+                }
+                _ if key.starts_with(Self::PREFIX) => warn!("Ignoring unknown annotation {}", key),
+                _ => {}
+            }
+        }
+        Ok(overrides)
+    }
+
+    /// The maximum drain time, where the override can only lower the server `limit`.
+    pub fn drain_time(&self, limit: Duration) -> Duration {
+        self.max_drain_time().map_or(limit, |time| time.min(limit))
+    }
+
+    /// The log disk quota, where the override can only lower the server `limit`. `None` if
+    /// unlimited.
+    pub fn disk_quota(&self, limit: Option<u64>) -> Option<u64> {
+        match (self.log_disk_quota(), limit) {
+            (Some(quota), Some(limit)) => Some(quota.min(limit)),
+            (quota, limit) => quota.or(limit),
+        }
+    }
+
+    /// The active overrides as annotation key and value pairs, sorted by key.
+    pub fn entries(&self) -> Vec<(&'static str, String)> {
+        let mut entries = vec![];
+        if let Some(quota) = self.log_disk_quota() {
+            entries.push((Self::LOG_DISK_QUOTA, quota.to_string()));
+        }
+        if let Some(size) = self.log_max_size() {
+            entries.push((Self::LOG_MAX_SIZE, size.to_string()));
+        }
+        if let Some(time) = self.max_drain_time() {
+            entries.push((Self::MAX_DRAIN_TIME, time.as_millis().to_string()));
+        }
+        entries
+    }
+}
+
+fn parse<T: FromStr>(key: &str, value: &str) -> Result<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    value
+        .parse()
+        .with_context(|| format!("invalid value '{}' of annotation {}", value, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annotations(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn from_annotations() -> Result<()> {
+        let sut = Overrides::from_annotations(&annotations(&[("other", "value")]))?;
+        assert_eq!(sut, Overrides::default());
+        assert!(sut.entries().is_empty());
+
+        let sut = Overrides::from_annotations(&annotations(&[
+            (Overrides::MAX_DRAIN_TIME, "250"),
+            (Overrides::LOG_MAX_SIZE, "1024"),
+        ]))?;
+        assert_eq!(sut.log_max_size(), Some(1024));
+        assert_eq!(sut.max_drain_time(), Some(Duration::from_millis(250)));
+        assert_eq!(
+            sut.drain_time(Duration::from_secs(1)),
+            Duration::from_millis(250)
+        );
+        assert_eq!(
+            sut.drain_time(Duration::from_millis(100)),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            sut.entries(),
+            vec![
+                (Overrides::LOG_MAX_SIZE, "1024".into()),
+                (Overrides::MAX_DRAIN_TIME, "250".into())
+            ]
+        );
+
+        let sut =
+            Overrides::from_annotations(&annotations(&[(Overrides::LOG_DISK_QUOTA, "4096")]))?;
+        assert_eq!(sut.log_disk_quota(), Some(4096));
+        assert_eq!(sut.disk_quota(None), Some(4096));
+        assert_eq!(sut.disk_quota(Some(8192)), Some(4096));
+        assert_eq!(sut.disk_quota(Some(1024)), Some(1024));
+        assert_eq!(
+            sut.entries(),
+            vec![(Overrides::LOG_DISK_QUOTA, "4096".into())]
+        );
+
+        let sut = Overrides::from_annotations(&annotations(&[("io.conmon-rs/unknown", "1")]))?;
+        assert_eq!(sut, Overrides::default());
+        assert_eq!(
+            sut.drain_time(Duration::from_secs(1)),
+            Duration::from_secs(1)
+        );
+        assert_eq!(sut.disk_quota(None), None);
+        Ok(())
+    }
+
+    #[test]
+    fn from_invalid_annotations() {
+        for (key, value) in [
+            (Overrides::LOG_MAX_SIZE, "-1"),
+            (Overrides::LOG_MAX_SIZE, "1k"),
+            (Overrides::LOG_MAX_SIZE, "0"),
+            (Overrides::MAX_DRAIN_TIME, ""),
+            (Overrides::LOG_DISK_QUOTA, "-1"),
+            (Overrides::LOG_DISK_QUOTA, "0"),
+        ] {
+            assert!(Overrides::from_annotations(&annotations(&[(key, value)])).is_err());
+        }
+    }
+}
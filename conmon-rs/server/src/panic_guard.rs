@@ -0,0 +1,266 @@
+//! Isolation of panics and hanging futures in RPC request handlers, which also tracks their
+//! latency.
+
+use crate::{
+    crash,
+    rpc_error::{self, Timeout},
+    server::Server,
+};
+use capnp::{capability::Promise, Error};
+use conmon_common::conmon_capnp::conmon;
+use futures::FutureExt;
+use std::{
+    panic::{self, AssertUnwindSafe},
+    thread,
+    time::Duration,
+};
+use tokio::time;
+use tracing::{error, warn};
+
+/// Install a panic hook which logs the panic message and location, because the default hook
+/// only writes to stderr, which may not be connected to anything. Panics of the main thread
+/// terminate the server, which is why they also write the exit records of all still running
+/// containers. The previous hook runs afterwards, which keeps the backtrace on stderr.
+pub fn install_hook() {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        error!("{}", info);
+        if thread::current().name() == Some("main") {
+            let written = crash::write_exit_records();
+            error!("Wrote {} exit records before terminating", written);
+        }
+        previous_hook(info);
+    }));
+}
+
+/// Run the provided RPC handler and turn any panic, either in the handler or in its returned
+/// promise, into an error for the calling client. The promise gets dropped together with all
+/// its resources if it does not complete within the optional `timeout`.
+pub fn guard<F>(method: &'static str, timeout: Option<Duration>, handler: F) -> Promise<(), Error>
+where
+    F: FnOnce() -> Promise<(), Error>,
+{
+    let promise = match panic::catch_unwind(AssertUnwindSafe(handler)) {
+        Ok(promise) => AssertUnwindSafe(promise)
+            .catch_unwind()
+            .map(move |res| res.unwrap_or_else(|_| Err(panicked(method)))),
+        Err(_) => return Promise::err(panicked(method)),
+    };
+    match timeout {
+        Some(timeout) => Promise::from_future(
+            time::timeout(timeout, promise)
+                .map(move |res| res.unwrap_or_else(|_| Err(timed_out(method, timeout)))),
+        ),
+        None => Promise::from_future(promise),
+    }
+}
+
+/// Methods which wait for containers or stream data without a bound, so that they are exempt
+/// from the request timeout.
+const UNBOUNDED_METHODS: &[&str] = &[
+    "exec_sync_container",
+    "attach_container",
+    "attach_stream_container",
+    "wait_container",
+    "stream_events",
+];
+
+/// The request timeout of `method`, which is `None` for unbounded methods.
+fn request_timeout(method: &str, timeout: Option<Duration>) -> Option<Duration> {
+    timeout.filter(|_| !UNBOUNDED_METHODS.contains(&method))
+}
+
+fn panicked(method: &str) -> Error {
+    error!("Handler of {} request panicked", method);
+    Error::failed(format!("internal error: {} request panicked", method))
+}
+
+fn timed_out(method: &'static str, timeout: Duration) -> Error {
+    warn!("Dropping {} request after timeout of {:?}", method, timeout);
+    rpc_error::failed(Timeout { method, timeout })
+}
+
+#[derive(Clone, Debug)]
+/// RPC server which guards every request handler of the wrapped server against panics.
+pub struct PanicGuard {
+    server: Server,
+    read_only: bool,
+}
+
+impl PanicGuard {
+    /// Create a new guard for the provided server. Methods modifying any container are
+    /// rejected if `read_only` is set.
+    pub fn new(server: Server, read_only: bool) -> Self {
+        Self { server, read_only }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Whether an RPC method only observes the server state or modifies it.
+enum Access {
+    Read,
+    Write,
+}
+
+/// Implement the RPC interface by delegating to the wrapped server. Every method of the
+/// interface has to be listed here together with its access, otherwise it would not be
+/// reachable. Methods of disabled features are answered as unimplemented.
+macro_rules! delegate {
+    ($($(#[$attr:meta])* $method:ident($params:ident, $results:ident, $access:ident)),* $(,)?) => {
+        impl conmon::Server for PanicGuard {
+            $(
+                $(#[$attr])*
+                fn $method(
+                    &mut self,
+                    params: conmon::$params,
+                    results: conmon::$results,
+                ) -> Promise<(), Error> {
+                    if self.read_only && Access::$access == Access::Write {
+                        return Promise::err(Error::failed(format!(
+                            "{} is not allowed on a read-only connection",
+                            stringify!($method)
+                        )));
+                    }
+                    let slow_threshold =
+                        Some(Duration::from_millis(self.server.config().slow_request_threshold()))
+                            .filter(|d| !d.is_zero());
+                    let timer = self.server.latencies().start(
+                        stringify!($method),
+                        slow_threshold,
+                        &params,
+                    );
+                    let timeout = request_timeout(
+                        stringify!($method),
+                        self.server.config().timeouts().request(),
+                    );
+                    timer.observe(guard(stringify!($method), timeout, || {
+                        conmon::Server::$method(&mut self.server, params, results)
+                    }))
+                }
+            )*
+        }
+    };
+}
+
+delegate!(
+    version(VersionParams, VersionResults, Read),
+    create_container(CreateContainerParams, CreateContainerResults, Write),
+    exec_sync_container(ExecSyncContainerParams, ExecSyncContainerResults, Write),
+    attach_container(AttachContainerParams, AttachContainerResults, Write),
+    reopen_log_container(ReopenLogContainerParams, ReopenLogContainerResults, Write),
+    set_window_size_container(
+        SetWindowSizeContainerParams,
+        SetWindowSizeContainerResults,
+        Write
+    ),
+    negotiate(NegotiateParams, NegotiateResults, Read),
+    attach_stream_container(
+        AttachStreamContainerParams,
+        AttachStreamContainerResults,
+        Write
+    ),
+    list_container_statuses(
+        ListContainerStatusesParams,
+        ListContainerStatusesResults,
+        Read
+    ),
+    get_events(GetEventsParams, GetEventsResults, Read),
+    list_exec_sessions(ListExecSessionsParams, ListExecSessionsResults, Read),
+    container_i_o_stats(ContainerIOStatsParams, ContainerIOStatsResults, Read),
+    set_log_level(SetLogLevelParams, SetLogLevelResults, Write),
+    remove_container(RemoveContainerParams, RemoveContainerResults, Write),
+    get_container_log_offset(
+        GetContainerLogOffsetParams,
+        GetContainerLogOffsetResults,
+        Read
+    ),
+    flush_container_logs(FlushContainerLogsParams, FlushContainerLogsResults, Write),
+    validate_create(ValidateCreateParams, ValidateCreateResults, Read),
+    update_log_drivers(UpdateLogDriversParams, UpdateLogDriversResults, Write),
+    health(HealthParams, HealthResults, Read),
+    set_terminal_mode_container(
+        SetTerminalModeContainerParams,
+        SetTerminalModeContainerResults,
+        Write
+    ),
+    read_exec_output(ReadExecOutputParams, ReadExecOutputResults, Read),
+    mirror_container_i_o(MirrorContainerIOParams, MirrorContainerIOResults, Write),
+    container_stats(ContainerStatsParams, ContainerStatsResults, Read),
+    freeze_container(FreezeContainerParams, FreezeContainerResults, Write),
+    thaw_container(ThawContainerParams, ThawContainerResults, Write),
+    container_processes(ContainerProcessesParams, ContainerProcessesResults, Read),
+    request_latencies(RequestLatenciesParams, RequestLatenciesResults, Read),
+    kill_container(KillContainerParams, KillContainerResults, Write),
+    start_container(StartContainerParams, StartContainerResults, Write),
+    list_containers(ListContainersParams, ListContainersResults, Read),
+    update_container_resources(
+        UpdateContainerResourcesParams,
+        UpdateContainerResourcesResults,
+        Write
+    ),
+    pause_container(PauseContainerParams, PauseContainerResults, Write),
+    unpause_container(UnpauseContainerParams, UnpauseContainerResults, Write),
+    #[cfg(feature = "checkpoint")]
+    checkpoint_container(CheckpointContainerParams, CheckpointContainerResults, Write),
+    #[cfg(feature = "checkpoint")]
+    restore_container(RestoreContainerParams, RestoreContainerResults, Write),
+    exec_container(ExecContainerParams, ExecContainerResults, Write),
+    wait_container(WaitContainerParams, WaitContainerResults, Read),
+    shutdown(ShutdownParams, ShutdownResults, Write),
+    reload_config(ReloadConfigParams, ReloadConfigResults, Write),
+    container_status(ContainerStatusParams, ContainerStatusResults, Read),
+    stream_events(StreamEventsParams, StreamEventsResults, Read),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future;
+
+    #[tokio::test]
+    async fn guard_success() {
+        assert!(guard("test", None, || Promise::ok(())).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn guard_handler_panic() {
+        let res = guard("test", None, || panic!("handler")).await;
+        assert!(res
+            .unwrap_err()
+            .description
+            .contains("test request panicked"));
+    }
+
+    #[tokio::test]
+    async fn guard_promise_panic() {
+        let res = guard("test", None, || {
+            Promise::from_future(future::lazy(|_| -> Result<(), Error> { panic!("promise") }))
+        })
+        .await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn guard_timeout() {
+        let res = guard("test", Some(Duration::from_millis(10)), || {
+            Promise::from_future(future::pending())
+        })
+        .await;
+        assert!(res
+            .unwrap_err()
+            .description
+            .starts_with("test request timed out after 10ms"));
+
+        let res = guard("test", Some(Duration::from_secs(1)), || Promise::ok(())).await;
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn request_timeout_unbounded_methods() {
+        let timeout = Some(Duration::from_secs(1));
+        assert_eq!(request_timeout("version", timeout), timeout);
+        assert_eq!(request_timeout("version", None), None);
+        assert_eq!(request_timeout("wait_container", timeout), None);
+        assert_eq!(request_timeout("stream_events", timeout), None);
+    }
+}
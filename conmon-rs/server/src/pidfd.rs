@@ -0,0 +1,91 @@
+//! Process file descriptors, which refer to a process independently of PID reuse.
+
+use anyhow::{Context, Result};
+use libc::pid_t;
+use nix::{errno::Errno, unistd::close};
+use sendfd::SendWithFd;
+use std::{io::ErrorKind, os::unix::io::RawFd, path::Path, time::Duration};
+use tokio::net::UnixStream;
+use tracing::debug;
+
+/// Maximum time for sending a pidfd, so that a stalled receiver cannot block the caller.
+pub const SEND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Open a new pidfd for the process `pid`, which requires Linux 5.3 or newer. The caller is
+/// responsible for closing it.
+pub fn open(pid: u32) -> Result<RawFd> {
+    let res = unsafe { libc::syscall(libc::SYS_pidfd_open, pid as pid_t, 0) };
+    let fd = Errno::result(res).with_context(|| format!("open pidfd of pid {}", pid))?;
+    Ok(fd as RawFd)
+}
+
+/// Send a pidfd of the process `pid` to the unix socket listening at `socket_path` by using
+/// SCM_RIGHTS. The `payload` is sent together with the file descriptor.
+pub async fn send(pid: u32, socket_path: &Path, payload: &[u8]) -> Result<()> {
+    let fd = open(pid)?;
+    let res = send_fd(fd, socket_path, payload).await;
+    if let Err(e) = close(fd) {
+        debug!("Unable to close pidfd {}: {}", fd, e);
+    }
+    res
+}
+
+async fn send_fd(fd: RawFd, socket_path: &Path, payload: &[u8]) -> Result<()> {
+    let stream = UnixStream::connect(socket_path)
+        .await
+        .with_context(|| format!("connect to pidfd socket {}", socket_path.display()))?;
+    loop {
+        stream
+            .writable()
+            .await
+            .context("wait for pidfd socket to be writable")?;
+        match stream.send_with_fd(payload, &[fd]) {
+            Ok(_) => return Ok(()),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+            Err(e) => return Err(e).context("send pidfd"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nix::fcntl::{fcntl, FcntlArg};
+    use sendfd::RecvWithFd;
+    use std::{os::unix::net::UnixListener, process, thread};
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn send_success() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("pidfd.sock");
+        let listener = UnixListener::bind(&path)?;
+        let receiver = thread::spawn(move || -> Result<(Vec<u8>, RawFd)> {
+            let (stream, _) = listener.accept()?;
+            let mut buf = [0; 16];
+            let mut fds = [0; 1];
+            let (n, fd_count) = stream.recv_with_fd(&mut buf, &mut fds)?;
+            assert_eq!(fd_count, 1);
+            Ok((buf[..n].to_vec(), fds[0]))
+        });
+
+        send(process::id(), &path, b"session").await?;
+
+        let (payload, fd) = receiver
+            .join()
+            .map_err(|_| anyhow::format_err!("join receiver"))??;
+        assert_eq!(payload, b"session");
+        fcntl(fd, FcntlArg::F_GETFD)?;
+        close(fd)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_failure() -> Result<()> {
+        let dir = tempdir()?;
+        assert!(send(process::id(), &dir.path().join("missing.sock"), b"")
+            .await
+            .is_err());
+        Ok(())
+    }
+}
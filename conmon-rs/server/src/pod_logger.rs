@@ -0,0 +1,201 @@
+//! Aggregated logging of all containers belonging to the same pod.
+
+use crate::{
+    cri_logger::{CriLogger, Timestamp},
+    log_xattrs::LogXattrs,
+};
+use anyhow::{bail, format_err, Result};
+use lazy_static::lazy_static;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex as StdMutex, Weak},
+};
+use tokio::sync::Mutex;
+use tracing::debug;
+
+lazy_static! {
+    /// The log files of all pods which have at least one container using them.
+    static ref POD_LOGS: StdMutex<HashMap<String, (PathBuf, Weak<Mutex<CriLogger>>)>> =
+        StdMutex::new(HashMap::new());
+}
+
+#[derive(Debug)]
+/// Logger writing into a single file shared by all containers of a pod. The lines use the CRI
+/// format, whereas the contents are prefixed by the tag of the container:
+///
+/// ```text
+/// <timestamp> <stdout|stderr> <F|P> <tag> <contents>
+/// ```
+pub struct PodLogger {
+    /// Tag of the container, added to every line.
+    tag: String,
+
+    /// Timestamp configuration of the lines of the container.
+    timestamp: Timestamp,
+
+    /// The shared logger of the pod.
+    logger: Arc<Mutex<CriLogger>>,
+
+    /// Extended attributes of the pod log, set if the container creates it.
+    xattrs: Option<LogXattrs>,
+}
+
+impl PodLogger {
+    /// Create a new pod logger for the container identified by `tag`. All containers using the
+    /// same `pod_id` share the log file at `path`.
+    pub fn new<T: AsRef<Path>>(
+        pod_id: &str,
+        tag: &str,
+        path: T,
+        max_log_size: Option<usize>,
+    ) -> Result<Self> {
+        if pod_id.is_empty() {
+            bail!("pod log driver requires a pod ID")
+        }
+
+        let mut pod_logs = POD_LOGS.lock().map_err(|e| format_err!("{:#}", e))?;
+        pod_logs.retain(|_, (_, v)| v.strong_count() > 0);
+
+        let path = path.as_ref();
+        let logger = match pod_logs
+            .get(pod_id)
+            .and_then(|(p, l)| Some((p.clone(), l.upgrade()?)))
+        {
+            Some((existing, logger)) => {
+                if existing != *path {
+                    bail!(
+                        "pod {} already logs into {}, not {}",
+                        pod_id,
+                        existing.display(),
+                        path.display()
+                    )
+                }
+                logger
+            }
+            None => {
+                debug!("Creating new pod log for {}", pod_id);
+                let logger = Arc::new(Mutex::new(CriLogger::new(path, max_log_size)?));
+                pod_logs.insert(pod_id.into(), (path.into(), Arc::downgrade(&logger)));
+                logger
+            }
+        };
+
+        Ok(Self {
+            tag: tag.into(),
+            timestamp: Timestamp::default(),
+            logger,
+            xattrs: None,
+        })
+    }
+
+    /// The tag added to every line of the container.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// The timestamp configuration of the lines of the container.
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// Set the timestamp configuration of the lines of the container. Containers sharing the
+    /// same pod log may use different configurations.
+    pub fn set_timestamp(&mut self, timestamp: Timestamp) {
+        self.timestamp = timestamp;
+    }
+
+    /// Set the extended attributes of the pod log, which must not identify the container.
+    pub fn set_xattrs(&mut self, xattrs: LogXattrs) {
+        self.xattrs = Some(xattrs);
+    }
+
+    /// Asynchronously initialize the pod log, which only opens the file for the first
+    /// container of the pod.
+    pub async fn init(&mut self) -> Result<()> {
+        let mut logger = self.logger.lock().await;
+        if !logger.is_initialized() {
+            if let Some(xattrs) = self.xattrs.take() {
+                logger.set_xattrs(Some(xattrs));
+            }
+            logger.init().await?;
+        }
+        Ok(())
+    }
+
+    /// Reopen the pod log file.
+    pub async fn reopen(&mut self) -> Result<()> {
+        self.logger.lock().await.reopen().await
+    }
+
+    /// Flush the pod log file and synchronize its contents to disk.
+    pub async fn sync(&mut self) -> Result<()> {
+        self.logger.lock().await.sync().await
+    }
+
+    /// Write already formatted log lines into the pod log.
+    pub async fn write_lines(&mut self, lines: &[Vec<u8>]) -> Result<()> {
+        self.logger.lock().await.write_lines(lines).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container_io::Pipe;
+    use std::fs;
+    use tempfile::NamedTempFile;
+
+    #[tokio::test]
+    async fn shared_between_containers() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut first = PodLogger::new("shared", "first", file.path(), None)?;
+        let mut second = PodLogger::new("shared", "second", file.path(), None)?;
+        first.init().await?;
+        first
+            .write_lines(&CriLogger::format_lines(
+                Pipe::StdOut,
+                b"a\n",
+                Some(first.tag()),
+                "ts",
+            ))
+            .await?;
+
+        // Initializing another container must not truncate the pod log
+        second.init().await?;
+        second
+            .write_lines(&CriLogger::format_lines(
+                Pipe::StdErr,
+                b"b\n",
+                Some(second.tag()),
+                "ts",
+            ))
+            .await?;
+
+        let res = fs::read_to_string(file.path())?;
+        let lines = res.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with(" stdout F first a"));
+        assert!(lines[1].ends_with(" stderr F second b"));
+        Ok(())
+    }
+
+    #[test]
+    fn path_mismatch() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let other = NamedTempFile::new()?;
+        let _first = PodLogger::new("mismatch", "first", file.path(), None)?;
+        assert!(PodLogger::new("mismatch", "second", other.path(), None).is_err());
+        assert!(PodLogger::new("", "second", file.path(), None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn released_after_drop() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let other = NamedTempFile::new()?;
+        drop(PodLogger::new("released", "first", file.path(), None)?);
+        PodLogger::new("released", "second", other.path(), None)?;
+        Ok(())
+    }
+}
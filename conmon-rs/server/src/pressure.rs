@@ -0,0 +1,192 @@
+//! Sampling of the pressure stall information (PSI) of container cgroups.
+//!
+//! PSI reports the share of time tasks of a cgroup were stalled waiting for CPU, memory or IO,
+//! which makes thrashing containers detectable without any external agent. It requires
+//! cgroup v2 and a kernel with PSI enabled.
+
+use crate::oom_watcher::OOMWatcher;
+use anyhow::{format_err, Context, Result};
+use getset::CopyGetters;
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{fs, task, time};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, debug_span, Instrument};
+
+#[derive(Clone, Copy, CopyGetters, Debug, Default, PartialEq)]
+#[getset(get_copy = "pub")]
+/// Stall averages and total of a single PSI line.
+pub struct Pressure {
+    /// Percentage of time stalled over the last 10 seconds.
+    avg10: f64,
+
+    /// Percentage of time stalled over the last 60 seconds.
+    avg60: f64,
+
+    /// Percentage of time stalled over the last 300 seconds.
+    avg300: f64,
+
+    /// Total stall time in microseconds.
+    total: u64,
+}
+
+#[derive(Clone, Copy, CopyGetters, Debug, Default, PartialEq)]
+#[getset(get_copy = "pub")]
+/// The pressure of a single resource.
+pub struct ResourcePressure {
+    /// Time at least one task was stalled.
+    some: Pressure,
+
+    /// Time all non-idle tasks were stalled at once.
+    full: Pressure,
+}
+
+impl ResourcePressure {
+    /// Parse the content of a `*.pressure` file.
+    fn parse(content: &str) -> Result<Self> {
+        let mut res = Self::default();
+        for line in content.lines() {
+            let mut fields = line.split_whitespace();
+            let target = match fields.next() {
+                Some("some") => &mut res.some,
+                Some("full") => &mut res.full,
+                _ => continue,
+            };
+            for field in fields {
+                let (key, value) = field
+                    .split_once('=')
+                    .with_context(|| format!("invalid pressure field: {}", field))?;
+                match key {
+                    "avg10" => target.avg10 = value.parse()?,
+                    "avg60" => target.avg60 = value.parse()?,
+                    "avg300" => target.avg300 = value.parse()?,
+                    "total" => target.total = value.parse()?,
+                    _ => {}
+                }
+            }
+        }
+        Ok(res)
+    }
+}
+
+#[derive(Clone, Copy, CopyGetters, Debug, Default, PartialEq)]
+#[getset(get_copy = "pub")]
+/// The pressure of all resources of a cgroup at a point in time.
+pub struct PressureSample {
+    /// Time of the sample in nanoseconds since the UNIX epoch.
+    timestamp: u64,
+
+    /// CPU pressure.
+    cpu: ResourcePressure,
+
+    /// Memory pressure.
+    memory: ResourcePressure,
+
+    /// IO pressure.
+    io: ResourcePressure,
+}
+
+impl PressureSample {
+    async fn read(cgroup: &Path) -> Result<Self> {
+        let read = |name: &'static str| async move {
+            let path = cgroup.join(name);
+            let content = fs::read_to_string(&path)
+                .await
+                .with_context(|| format!("read {}", path.display()))?;
+            ResourcePressure::parse(&content).with_context(|| format!("parse {}", name))
+        };
+        Ok(Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or_default(),
+            cpu: read("cpu.pressure").await?,
+            memory: read("memory.pressure").await?,
+            io: read("io.pressure").await?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+/// Keeps the latest pressure sample of a container.
+pub struct PressureMonitor {
+    latest: Arc<Mutex<Option<PressureSample>>>,
+}
+
+impl PressureMonitor {
+    /// The latest sample, or `None` if sampling is disabled or did not succeed yet.
+    pub fn latest(&self) -> Result<Option<PressureSample>> {
+        Ok(*self.latest.lock().map_err(|e| format_err!("{:#}", e))?)
+    }
+
+    /// Sample the cgroup of the process `pid` every `interval` until the `token` gets
+    /// cancelled. Does nothing on cgroup v1.
+    pub fn start(&self, token: CancellationToken, pid: u32, interval: Duration) {
+        if !OOMWatcher::is_cgroup_v2() {
+            return;
+        }
+        let latest = self.latest.clone();
+        task::spawn(
+            async move {
+                if let Err(e) = Self::run(token, pid, interval, &latest).await {
+                    debug!("Stopped sampling pressure: {:#}", e);
+                }
+            }
+            .instrument(debug_span!("pressure", pid)),
+        );
+    }
+
+    async fn run(
+        token: CancellationToken,
+        pid: u32,
+        interval: Duration,
+        latest: &Mutex<Option<PressureSample>>,
+    ) -> Result<()> {
+        let cgroup = OOMWatcher::process_cgroup_subsystem_path_cgroup_v2(pid).await?;
+        let mut interval = time::interval(interval);
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => return Ok(()),
+                _ = interval.tick() => {},
+            }
+            // Fails if PSI is disabled or the cgroup got removed.
+            let sample = PressureSample::read(&cgroup).await?;
+            *latest.lock().map_err(|e| format_err!("{:#}", e))? = Some(sample);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_resource_pressure() -> Result<()> {
+        let sut = ResourcePressure::parse(
+            "some avg10=1.50 avg60=0.25 avg300=0.00 total=12345\n\
+             full avg10=0.10 avg60=0.00 avg300=0.00 total=42\n",
+        )?;
+        assert_eq!(sut.some().avg10(), 1.5);
+        assert_eq!(sut.some().avg60(), 0.25);
+        assert_eq!(sut.some().total(), 12345);
+        assert_eq!(sut.full().avg10(), 0.1);
+        assert_eq!(sut.full().total(), 42);
+
+        // Older kernels report no full line for the CPU.
+        let sut = ResourcePressure::parse("some avg10=0.00 avg60=0.00 avg300=0.00 total=7\n")?;
+        assert_eq!(sut.some().total(), 7);
+        assert_eq!(sut.full(), Pressure::default());
+
+        assert!(ResourcePressure::parse("some avg10=invalid\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn latest_without_sampling() -> Result<()> {
+        assert!(PressureMonitor::default().latest()?.is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+//! Listing of the processes of a container by its cgroup.
+
+use crate::oom_watcher::OOMWatcher;
+use anyhow::{Context, Result};
+use getset::{CopyGetters, Getters};
+use nix::unistd::{sysconf, SysconfVar};
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+use tokio::fs;
+
+#[derive(Clone, CopyGetters, Debug, Eq, Getters, PartialEq)]
+/// A single process of a container.
+pub struct Process {
+    #[getset(get_copy = "pub")]
+    /// PID of the process.
+    pid: u32,
+
+    #[getset(get_copy = "pub")]
+    /// PID of the parent process.
+    ppid: u32,
+
+    #[getset(get = "pub")]
+    /// Command name of the process.
+    comm: String,
+
+    #[getset(get_copy = "pub")]
+    /// Start time of the process in nanoseconds since the UNIX epoch.
+    start_time: u64,
+}
+
+impl Process {
+    /// Parse the content of `/proc/<pid>/stat`, where `boot_time` is in nanoseconds since the
+    /// UNIX epoch.
+    fn parse_stat(stat: &str, boot_time: u64, clock_ticks: u64) -> Result<Self> {
+        // The command name may contain spaces and parentheses itself.
+        let (head, tail) = stat.rsplit_once(')').context("no end of command name")?;
+        let (pid, comm) = head.split_once(" (").context("no start of command name")?;
+        // The tail starts with the state, which is the third field.
+        let fields: Vec<&str> = tail.split_whitespace().collect();
+        let field = |n: usize| fields.get(n - 3).with_context(|| format!("no field {}", n));
+
+        let start_ticks: u64 = field(22)?.parse().context("parse start time")?;
+        let clock_ticks = clock_ticks.max(1);
+        let since_boot = start_ticks / clock_ticks * 1_000_000_000
+            + start_ticks % clock_ticks * 1_000_000_000 / clock_ticks;
+        Ok(Self {
+            pid: pid.trim().parse().context("parse pid")?,
+            ppid: field(4)?.parse().context("parse ppid")?,
+            comm: comm.into(),
+            start_time: boot_time + since_boot,
+        })
+    }
+}
+
+/// List all processes of the container with the init process `pid`, including the ones of
+/// nested cgroups, sorted by PID.
+pub async fn list(pid: u32) -> Result<Vec<Process>> {
+    let cgroup = OOMWatcher::process_cgroup_subsystem_path(pid, OOMWatcher::is_cgroup_v2(), "pids")
+        .await
+        .context("get cgroup path")?;
+    let boot_time = boot_time().await?;
+    let clock_ticks = clock_ticks()?;
+
+    let mut processes = vec![];
+    for pid in cgroup_pids(&cgroup).await? {
+        match fs::read_to_string(format!("/proc/{}/stat", pid)).await {
+            Ok(stat) => processes.push(
+                Process::parse_stat(&stat, boot_time, clock_ticks)
+                    .with_context(|| format!("parse stat of pid {}", pid))?,
+            ),
+            // The process exited in the meantime.
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(e).with_context(|| format!("read stat of pid {}", pid)),
+        }
+    }
+    processes.sort_by_key(|p| p.pid());
+    Ok(processes)
+}
+
+/// The single process `pid`.
+pub async fn get(pid: u32) -> Result<Process> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid))
+        .await
+        .with_context(|| format!("read stat of pid {}", pid))?;
+    Process::parse_stat(&stat, boot_time().await?, clock_ticks()?)
+        .with_context(|| format!("parse stat of pid {}", pid))
+}
+
+fn clock_ticks() -> Result<u64> {
+    Ok(sysconf(SysconfVar::CLK_TCK)
+        .context("get clock ticks")?
+        .context("clock ticks not available")? as u64)
+}
+
+/// Collect the PIDs of `cgroup.procs` of the cgroup and all its descendants.
+async fn cgroup_pids(cgroup: &Path) -> Result<Vec<u32>> {
+    let mut pids = vec![];
+    let mut dirs: Vec<PathBuf> = vec![cgroup.into()];
+    while let Some(dir) = dirs.pop() {
+        let procs = dir.join("cgroup.procs");
+        let content = match fs::read_to_string(&procs).await {
+            Ok(content) => content,
+            // Nested cgroups may be removed concurrently.
+            Err(e) if e.kind() == ErrorKind::NotFound && dir != cgroup => continue,
+            Err(e) => return Err(e).with_context(|| format!("read {}", procs.display())),
+        };
+        for line in content.lines() {
+            pids.push(line.trim().parse().context("parse pid")?);
+        }
+
+        let mut entries = match fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => continue,
+            Err(e) => return Err(e).with_context(|| format!("read dir {}", dir.display())),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_dir() {
+                dirs.push(entry.path());
+            }
+        }
+    }
+    Ok(pids)
+}
+
+/// The boot time of the system in nanoseconds since the UNIX epoch.
+async fn boot_time() -> Result<u64> {
+    let stat = fs::read_to_string("/proc/stat")
+        .await
+        .context("read /proc/stat")?;
+    parse_boot_time(&stat)
+}
+
+fn parse_boot_time(stat: &str) -> Result<u64> {
+    let secs: u64 = stat
+        .lines()
+        .find_map(|line| line.strip_prefix("btime "))
+        .context("no btime in /proc/stat")?
+        .trim()
+        .parse()
+        .context("parse btime")?;
+    Ok(secs * 1_000_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn parse_stat() -> Result<()> {
+        let stat = "42 (my (weird) cmd) S 7 42 42 0 -1 4194560 100 0 0 0 1 2 0 0 20 0 1 0 \
+            250 1000 100 18446744073709551615 1 1 0 0 0 0 0 0 0 0 0 0 17 0 0 0 0 0 0\n";
+        let sut = Process::parse_stat(stat, 1_000_000_000, 100)?;
+        assert_eq!(sut.pid(), 42);
+        assert_eq!(sut.ppid(), 7);
+        assert_eq!(sut.comm(), "my (weird) cmd");
+        assert_eq!(sut.start_time(), 3_500_000_000);
+
+        assert!(Process::parse_stat("42 broken", 0, 100).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn btime() -> Result<()> {
+        assert_eq!(
+            parse_boot_time("cpu 1 2 3\nbtime 1700000000\n")?,
+            1_700_000_000_000_000_000
+        );
+        assert!(parse_boot_time("cpu 1 2 3\n").is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_self() -> Result<()> {
+        let sut = get(std::process::id()).await?;
+        assert_eq!(sut.pid(), std::process::id());
+        assert!(sut.start_time() > 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nested_cgroup_pids() -> Result<()> {
+        let dir = tempdir()?;
+        let nested = dir.path().join("nested");
+        fs::create_dir(&nested).await?;
+        fs::write(dir.path().join("cgroup.procs"), "1\n2\n").await?;
+        fs::write(nested.join("cgroup.procs"), "3\n").await?;
+
+        let mut pids = cgroup_pids(dir.path()).await?;
+        pids.sort_unstable();
+        assert_eq!(pids, vec![1, 2, 3]);
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+//! Linux resources of running containers, which get updated by the runtime.
+
+use anyhow::{bail, Result};
+use getset::{CopyGetters, Getters};
+use std::fmt::Write;
+
+#[derive(Clone, CopyGetters, Debug, Default, Eq, Getters, PartialEq)]
+/// Linux resources to be applied to a container. Zero and empty values keep the current
+/// setting.
+pub struct Resources {
+    #[getset(get_copy = "pub")]
+    /// Relative CPU weight.
+    cpu_shares: u64,
+
+    #[getset(get_copy = "pub")]
+    /// CPU time in microseconds per period, -1 for unlimited.
+    cpu_quota: i64,
+
+    #[getset(get_copy = "pub")]
+    /// Length of the CPU period in microseconds.
+    cpu_period: u64,
+
+    #[getset(get = "pub")]
+    /// CPUs the container may run on, like `0-3,6`.
+    cpuset_cpus: String,
+
+    #[getset(get = "pub")]
+    /// Memory nodes the container may use, like `0-1`.
+    cpuset_mems: String,
+
+    #[getset(get_copy = "pub")]
+    /// Memory limit in bytes, -1 for unlimited.
+    memory_limit: i64,
+
+    #[getset(get_copy = "pub")]
+    /// Limit of memory and swap together in bytes, -1 for unlimited swap.
+    memory_swap: i64,
+
+    #[getset(get_copy = "pub")]
+    /// Maximum number of processes, -1 for unlimited.
+    pids_limit: i64,
+}
+
+impl Resources {
+    /// The default CPU period of the kernel in microseconds.
+    const DEFAULT_CPU_PERIOD: u64 = 100_000;
+
+    /// The minimum CPU quota accepted by the kernel in microseconds.
+    const MIN_CPU_QUOTA: i64 = 1000;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        cpu_shares: u64,
+        cpu_quota: i64,
+        cpu_period: u64,
+        cpuset_cpus: String,
+        cpuset_mems: String,
+        memory_limit: i64,
+        memory_swap: i64,
+        pids_limit: i64,
+    ) -> Self {
+        Self {
+            cpu_shares,
+            cpu_quota,
+            cpu_period,
+            cpuset_cpus,
+            cpuset_mems,
+            memory_limit,
+            memory_swap,
+            pids_limit,
+        }
+    }
+
+    /// Validate the resources and resolve the corner cases the runtime would reject or apply
+    /// unexpectedly, which returns the resources that actually get applied.
+    pub fn normalize(mut self) -> Result<Self> {
+        // A quota without period would be applied to the current period, which may differ
+        // from the period the quota got calculated for.
+        if self.cpu_quota > 0 && self.cpu_period == 0 {
+            self.cpu_period = Self::DEFAULT_CPU_PERIOD;
+        }
+        if self.cpu_quota > 0 && self.cpu_quota < Self::MIN_CPU_QUOTA {
+            self.cpu_quota = Self::MIN_CPU_QUOTA;
+        }
+        if self.cpu_quota < -1 {
+            bail!("invalid CPU quota {}", self.cpu_quota)
+        }
+
+        if self.memory_limit < -1 || self.memory_swap < -1 || self.pids_limit < -1 {
+            bail!("resource limits must not be negative except for -1")
+        }
+        // Lowering the memory below the current swap limit fails, so swap gets disabled if
+        // not requested explicitly.
+        if self.memory_limit > 0 && self.memory_swap == 0 {
+            self.memory_swap = self.memory_limit;
+        }
+        if self.memory_swap > 0 && self.memory_limit == 0 {
+            bail!("memory swap requires a memory limit")
+        }
+        if self.memory_swap > 0 && self.memory_swap < self.memory_limit {
+            bail!(
+                "memory swap {} must not be lower than the memory limit {}",
+                self.memory_swap,
+                self.memory_limit
+            )
+        }
+
+        for (name, set) in [("cpus", &self.cpuset_cpus), ("mems", &self.cpuset_mems)] {
+            if !set
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == ',' || c == '-')
+            {
+                bail!("invalid cpuset {}: {}", name, set)
+            }
+        }
+        Ok(self)
+    }
+
+    /// Render the resources in the format of the OCI runtime spec, as expected by
+    /// `runtime update --resources`.
+    pub fn to_json(&self) -> String {
+        let mut memory = vec![];
+        if self.memory_limit != 0 {
+            memory.push(format!(r#""limit":{}"#, self.memory_limit));
+        }
+        if self.memory_swap != 0 {
+            memory.push(format!(r#""swap":{}"#, self.memory_swap));
+        }
+
+        let mut cpu = vec![];
+        if self.cpu_shares != 0 {
+            cpu.push(format!(r#""shares":{}"#, self.cpu_shares));
+        }
+        if self.cpu_quota != 0 {
+            cpu.push(format!(r#""quota":{}"#, self.cpu_quota));
+        }
+        if self.cpu_period != 0 {
+            cpu.push(format!(r#""period":{}"#, self.cpu_period));
+        }
+        if !self.cpuset_cpus.is_empty() {
+            cpu.push(format!(r#""cpus":"{}""#, self.cpuset_cpus));
+        }
+        if !self.cpuset_mems.is_empty() {
+            cpu.push(format!(r#""mems":"{}""#, self.cpuset_mems));
+        }
+
+        let mut pids = vec![];
+        if self.pids_limit != 0 {
+            pids.push(format!(r#""limit":{}"#, self.pids_limit));
+        }
+
+        let mut json = String::from("{");
+        for (name, fields) in [("memory", memory), ("cpu", cpu), ("pids", pids)] {
+            if fields.is_empty() {
+                continue;
+            }
+            if json.len() > 1 {
+                json.push(',');
+            }
+            let _ = write!(json, r#""{}":{{{}}}"#, name, fields.join(","));
+        }
+        json.push('}');
+        json
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resources(
+        cpu_quota: i64,
+        cpu_period: u64,
+        memory_limit: i64,
+        memory_swap: i64,
+    ) -> Resources {
+        Resources::new(
+            0,
+            cpu_quota,
+            cpu_period,
+            String::new(),
+            String::new(),
+            memory_limit,
+            memory_swap,
+            0,
+        )
+    }
+
+    #[test]
+    fn normalize_cpu() -> Result<()> {
+        let sut = resources(50_000, 0, 0, 0).normalize()?;
+        assert_eq!(sut.cpu_period(), 100_000);
+
+        let sut = resources(500, 10_000, 0, 0).normalize()?;
+        assert_eq!(sut.cpu_quota(), 1000);
+        assert_eq!(sut.cpu_period(), 10_000);
+
+        let sut = resources(-1, 0, 0, 0).normalize()?;
+        assert_eq!(sut.cpu_period(), 0);
+
+        assert!(resources(-2, 0, 0, 0).normalize().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn normalize_swap() -> Result<()> {
+        let sut = resources(0, 0, 1024, 0).normalize()?;
+        assert_eq!(sut.memory_swap(), 1024);
+
+        let sut = resources(0, 0, 1024, -1).normalize()?;
+        assert_eq!(sut.memory_swap(), -1);
+
+        assert!(resources(0, 0, 0, 1024).normalize().is_err());
+        assert!(resources(0, 0, 2048, 1024).normalize().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn to_json() -> Result<()> {
+        assert_eq!(Resources::default().to_json(), "{}");
+
+        let sut = Resources::new(512, 50_000, 0, "0-1".into(), String::new(), 1024, 0, 100)
+            .normalize()?;
+        assert_eq!(
+            sut.to_json(),
+            r#"{"memory":{"limit":1024,"swap":1024},"cpu":{"shares":512,"quota":50000,"period":100000,"cpus":"0-1"},"pids":{"limit":100}}"#
+        );
+
+        assert!(
+            Resources::new(0, 0, 0, "0\"".into(), String::new(), 0, 0, 0)
+                .normalize()
+                .is_err()
+        );
+        Ok(())
+    }
+}
@@ -1,21 +1,59 @@
 use crate::{
+    attach_protocol,
+    bundle::BundleConfig,
+    cgroup_stats::CgroupStats,
     child::Child,
-    container_io::{ContainerIO, SharedContainerIO},
+    child_reaper::{kill_grandchild, CreateTimings},
+    container_io::{ContainerIO, Pipe, SharedContainerIO},
     container_log::ContainerLog,
+    criu::{self, Restore},
+    encoding::Translation,
+    event_stream::{self, Subscription},
+    events::EventKind,
+    exec_sessions::{ExecKind, ExecSyncResult},
+    freezer::Freezer,
+    health::Health,
+    idempotency::{Claim, IdempotencyCache},
+    invocation::Invocation,
+    latency, limits,
+    log_xattrs::LogXattrs,
+    negotiate,
+    overrides::Overrides,
+    pidfd,
+    pressure::{Pressure, ResourcePressure},
+    processes,
+    resources::Resources,
+    rpc_error::{self, Phase},
+    runtime_log,
+    rusage::ResourceUsage,
     server::Server,
+    shutdown::Stop,
+    tee::Tee,
+    terminal::TerminalMode,
+    validate::Validator,
     version::Version,
 };
-use anyhow::format_err;
+use anyhow::Context;
 use capnp::{capability::Promise, Error};
 use capnp_rpc::pry;
-use conmon_common::conmon_capnp::conmon;
+use conmon_common::conmon_capnp::conmon::{
+    self, container_status_response::State as ContainerState,
+    exec_session::Kind as ExecSessionKind, mirror_container_i_o_request::Pipe as MirrorPipe,
+    read_exec_output_request::Pipe as ReadExecOutputPipe, set_terminal_mode_request::Toggle,
+};
+use nix::sys::signal::Signal;
 use std::{
+    convert::TryFrom,
     path::{Path, PathBuf},
     str,
     time::Duration,
 };
-use tokio::time::Instant;
-use tracing::{debug, debug_span, error, Instrument};
+use tokio::{
+    fs, task,
+    time::{self, Instant},
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, debug_span, error, info, warn, Instrument};
 use uuid::Uuid;
 
 macro_rules! pry_err {
@@ -26,10 +64,66 @@ macro_rules! pry_err {
 
 macro_rules! capnp_err {
     ($x:expr) => {
-        $x.map_err(|e| Error::failed(format!("{:#}", e)))
+        $x.map_err(rpc_error::failed)
     };
 }
 
+macro_rules! pry_list {
+    ($self:ident, $field:expr, $list:expr) => {{
+        let list = pry!($list);
+        pry_err!(limits::check_list_len(
+            $field,
+            list.len(),
+            $self.config().max_list_len()
+        ));
+        list
+    }};
+}
+
+macro_rules! pry_text_list {
+    ($self:ident, $field:expr, $list:expr) => {{
+        let list = pry_list!($self, $field, $list);
+        pry_err!(limits::check_text_list(
+            $field,
+            list,
+            $self.config().max_text_len()
+        ));
+        list
+    }};
+}
+
+macro_rules! pry_path_list {
+    ($self:ident, $field:expr, $list:expr) => {{
+        let list = pry_list!($self, $field, $list);
+        pry_err!(limits::check_text_list($field, list, limits::MAX_PATH_LEN));
+        list
+    }};
+}
+
+macro_rules! pry_text {
+    ($self:ident, $field:expr, $text:expr) => {{
+        let text = pry!($text);
+        pry_err!(limits::check_text_len(
+            $field,
+            text.len(),
+            $self.config().max_text_len()
+        ));
+        text
+    }};
+}
+
+macro_rules! pry_path {
+    ($field:expr, $path:expr) => {{
+        let path = pry!($path);
+        pry_err!(limits::check_text_len(
+            $field,
+            path.len(),
+            limits::MAX_PATH_LEN
+        ));
+        path
+    }};
+}
+
 macro_rules! new_root_span {
     ($name:expr, $container_id:expr) => {
         debug_span!(
@@ -40,78 +134,335 @@ macro_rules! new_root_span {
     };
 }
 
-impl conmon::Server for Server {
-    /// Retrieve version information from the server.
-    fn version(
-        &mut self,
-        _: conmon::VersionParams,
-        mut results: conmon::VersionResults,
-    ) -> Promise<(), capnp::Error> {
-        debug!("Got a version request");
-        let mut response = results.get().init_response();
-        let version = Version::new();
-        response.set_version(version.version());
-        response.set_tag(version.tag());
-        response.set_commit(version.commit());
-        response.set_build_date(version.build_date());
-        response.set_rust_version(version.rust_version());
-        response.set_process_id(std::process::id());
-        Promise::ok(())
+/// Set the response of an exec sync request from the result of the command.
+fn set_exec_sync_response(result: &ExecSyncResult, results: &mut conmon::ExecSyncContainerResults) {
+    let mut resp = results.get().init_response();
+    resp.set_pid(result.pid());
+    resp.set_exit_code(result.exit_code());
+    resp.set_stdout(result.stdout());
+    resp.set_stderr(result.stderr());
+    resp.set_timed_out(result.timed_out());
+    resp.set_session_id(result.session_id());
+    resp.set_stdout_size(result.stdout_size());
+    resp.set_stderr_size(result.stderr_size());
+    resp.set_output_stored(result.output_stored());
+}
+
+/// Append the runtime invocation to its record file, without failing the request.
+async fn record_invocation(invocation: &Option<(Invocation, PathBuf)>) {
+    if let Some((invocation, path)) = invocation {
+        if let Err(e) = invocation.record(path).await {
+            warn!("Unable to record runtime invocation: {:#}", e);
+        }
     }
+}
 
-    /// Create a new container for the provided parameters.
-    fn create_container(
+/// Run the lifecycle `command` of the runtime on the running container `id`. Runtime errors
+/// are reported with the messages of the runtime log attached.
+fn run_lifecycle_command(server: &Server, command: &'static str, id: &str) -> Promise<(), Error> {
+    let child = pry_err!(server.reaper().get(id));
+    if pry_err!(child.reaped()) {
+        return Promise::err(Error::failed(format!("container {} is not running", id)));
+    }
+
+    let tenant_dir = pry_err!(server.tenant_dir());
+    let runtime_log = pry_err!(ContainerIO::temp_file_name(
+        Some(
+            tenant_dir
+                .as_deref()
+                .unwrap_or_else(|| server.config().runtime_dir().as_path())
+        ),
+        id,
+        "runtime-",
+        ".log"
+    ));
+    let args = server.generate_lifecycle_args(command, id, &runtime_log);
+    let runtime = server.runtime_config().runtime();
+    let child_reaper = server.reaper().clone();
+
+    Promise::from_future(
+        async move {
+            if let Err(e) = child_reaper.run_runtime(&runtime, &args).await {
+                return Err(rpc_error::failed(
+                    runtime_log::attach_errors(
+                        e.context(format!("runtime {}", command)),
+                        &runtime_log,
+                    )
+                    .await,
+                ));
+            }
+            Ok(())
+        }
+        .instrument(debug_span!("promise")),
+    )
+}
+
+#[derive(Debug, Default)]
+/// A created or restored container.
+struct Created {
+    pid: u32,
+    timings: CreateTimings,
+    log_init: Duration,
+}
+
+/// Set the response of a create or restore container request.
+fn set_create_response(
+    mut response: conmon::create_container_response::Builder,
+    created: &Created,
+) {
+    response.set_container_pid(created.pid);
+    let mut t = response.init_timings();
+    t.set_runtime_spawn_micros(created.timings.runtime_spawn().as_micros() as u64);
+    t.set_console_handshake_micros(created.timings.console_handshake().as_micros() as u64);
+    t.set_runtime_micros(created.timings.runtime().as_micros() as u64);
+    t.set_pidfile_wait_micros(created.timings.pidfile_wait().as_micros() as u64);
+    t.set_log_init_micros(created.log_init.as_micros() as u64);
+}
+
+/// Set the Linux resources of a response.
+fn set_linux_resources(mut builder: conmon::linux_resources::Builder, resources: &Resources) {
+    builder.set_cpu_shares(resources.cpu_shares());
+    builder.set_cpu_quota(resources.cpu_quota());
+    builder.set_cpu_period(resources.cpu_period());
+    builder.set_cpuset_cpus(resources.cpuset_cpus());
+    builder.set_cpuset_mems(resources.cpuset_mems());
+    builder.set_memory_limit(resources.memory_limit());
+    builder.set_memory_swap(resources.memory_swap());
+    builder.set_pids_limit(resources.pids_limit());
+}
+
+/// Set the `some` and `full` pressure of a single resource.
+fn set_resource_pressure(mut builder: conmon::resource_pressure::Builder, res: ResourcePressure) {
+    let set = |mut builder: conmon::pressure::Builder, pressure: Pressure| {
+        builder.set_avg10(pressure.avg10());
+        builder.set_avg60(pressure.avg60());
+        builder.set_avg300(pressure.avg300());
+        builder.set_total(pressure.total());
+    };
+    set(builder.reborrow().init_some(), res.some());
+    set(builder.init_full(), res.full());
+}
+
+impl Server {
+    /// Create the container of the request by the runtime and register it with the reaper, or
+    /// restore it from a checkpoint if `restore` is set.
+    fn spawn_container(
         &mut self,
-        params: conmon::CreateContainerParams,
-        mut results: conmon::CreateContainerResults,
-    ) -> Promise<(), capnp::Error> {
-        let req = pry!(pry!(params.get()).get_request());
-        let id = pry!(req.get_id()).to_string();
-        let cleanup_cmd: Vec<String> = pry!(pry!(req.get_cleanup_cmd())
-            .iter()
-            .map(|s| s.map(String::from))
-            .collect());
+        req: conmon::create_container_request::Reader,
+        restore: Option<Restore>,
+    ) -> Promise<Created, capnp::Error> {
+        let id = pry_text!(self, "id", req.get_id()).to_string();
+        let cleanup_cmd: Vec<String> =
+            pry!(pry_text_list!(self, "cleanupCmd", req.get_cleanup_cmd())
+                .iter()
+                .map(|s| s.map(String::from))
+                .collect());
 
-        let span = new_root_span!("create_container", id.as_str());
-        let _enter = span.enter();
+        let token = pry_text!(self, "idempotencyToken", req.get_idempotency_token());
+        let completion = match pry_err!(self.create_tokens().claim(self.tenant(), &id, token)) {
+            Claim::Untracked => None,
+            Claim::Run(completion) => Some(completion),
+            Claim::Existing(rx) => {
+                debug!("Returning the result of the request with the same idempotency token");
+                return Promise::from_future(
+                    async move {
+                        let pid = capnp_err!(IdempotencyCache::wait(rx).await)?;
+                        Ok(Created {
+                            pid,
+                            ..Default::default()
+                        })
+                    }
+                    .instrument(debug_span!("promise")),
+                );
+            }
+        };
 
-        debug!("Got a create container request");
+        // The name stays reserved while the container gets created, so that concurrent creates
+        // cannot take it as well.
+        let name = pry_text!(self, "name", req.get_name());
+        let (name, reservation) = if name.is_empty() {
+            (None, None)
+        } else {
+            let reservation = pry_err!(self.reaper().reserve_alias(name, &id));
+            (Some(name.to_string()), Some(reservation))
+        };
+
+        let bundle_path = Path::new(pry_path!("bundlePath", req.get_bundle_path()));
+        let bundle_config = match BundleConfig::from_bundle(bundle_path) {
+            Ok(config) => config,
+            Err(e) => {
+                // The runtime reports invalid bundles itself.
+                warn!(
+                    "Unable to read bundle config, continuing without annotations: {:#}",
+                    e
+                );
+                BundleConfig::default()
+            }
+        };
+        debug!(
+            "Container process working directory is {}",
+            bundle_config.cwd().display()
+        );
+        let pty_socket_path = pry_path!("ptySocketPath", req.get_pty_socket_path());
+        if pty_socket_path.is_empty() && bundle_config.terminal() != req.get_terminal() {
+            warn!(
+                "Requested terminal ({}) differs from the bundle config ({})",
+                req.get_terminal(),
+                bundle_config.terminal()
+            );
+        }
+        if !pty_socket_path.is_empty() && bundle_config.terminal() {
+            return Promise::err(Error::failed(
+                "pre-allocated terminal requires the bundle to disable the terminal".into(),
+            ));
+        }
+        let overrides = pry_err!(Overrides::from_annotations(bundle_config.annotations()));
+        let disk_quota = overrides.disk_quota(self.default_log_disk_quota());
+        let psi_interval =
+            Some(Duration::from_secs(self.config().psi_sample_interval())).filter(|d| !d.is_zero());
+        let xattrs = if self.config().log_xattrs() {
+            Some(LogXattrs::new(&id, bundle_config.annotations()))
+        } else {
+            None
+        };
 
-        let log_drivers = pry!(req.get_log_drivers());
-        let container_log = pry_err!(ContainerLog::from(log_drivers));
-        let mut container_io =
-            pry_err!(ContainerIO::new(req.get_terminal(), container_log.clone()));
+        let log_drivers = pry_list!(self, "logDrivers", req.get_log_drivers());
+        let container_log = pry_err!(ContainerLog::from(log_drivers, &id));
+        let tenant_dir = pry_err!(self.tenant_dir());
+        let mut container_io = if pty_socket_path.is_empty() {
+            pry_err!(ContainerIO::new(
+                &id,
+                req.get_terminal(),
+                req.get_serialize_output(),
+                container_log.clone(),
+                tenant_dir.as_deref()
+            ))
+        } else {
+            pry_err!(ContainerIO::pre_allocated_terminal(
+                &id,
+                Path::new(pty_socket_path),
+                container_log.clone()
+            ))
+        };
+        let tee_path = pry_path!("teePath", req.get_tee_path());
+        let tee = if tee_path.is_empty() {
+            None
+        } else {
+            Some(Tee::new(tee_path))
+        };
+        let stdin_path = pry_path!("stdinPath", req.get_stdin_path());
+        if !stdin_path.is_empty() {
+            pry_err!(container_io.set_stdin_path(stdin_path.into()));
+        }
 
-        let bundle_path = Path::new(pry!(req.get_bundle_path()));
         let pidfile = bundle_path.join("pidfile");
         debug!("PID file is {}", pidfile.display());
 
+        // Removed on drop, after the runtime errors got collected.
+        let runtime_log = pry_err!(ContainerIO::temp_file_name(
+            Some(
+                tenant_dir
+                    .as_deref()
+                    .unwrap_or_else(|| self.config().runtime_dir().as_path())
+            ),
+            &id,
+            "runtime-",
+            ".log"
+        ));
+
         let child_reaper = self.reaper().clone();
-        let args = pry_err!(self.generate_runtime_args(&id, bundle_path, &container_io, &pidfile));
-        let runtime = self.config().runtime().clone();
-        let exit_paths: Vec<PathBuf> = pry!(pry!(req.get_exit_paths())
-            .iter()
-            .map(|r| r.map(PathBuf::from))
-            .collect());
-        let oom_exit_paths: Vec<PathBuf> = pry!(pry!(req.get_oom_exit_paths())
-            .iter()
-            .map(|r| r.map(PathBuf::from))
-            .collect());
+        let args = pry_err!(self.generate_runtime_args(
+            &id,
+            bundle_path,
+            &container_io,
+            &pidfile,
+            &runtime_log,
+            restore.as_ref()
+        ));
+        let mut delete_args = self.generate_global_args();
+        delete_args.extend(["delete".into(), "--force".into(), id.clone()]);
+        let runtime = self.runtime_config().runtime();
+        let invocation = self.config().record_runtime_invocations().then(|| {
+            let dir = tenant_dir
+                .as_deref()
+                .unwrap_or_else(|| self.config().runtime_dir().as_path());
+            let path = Invocation::record_path(dir, &id);
+            let operation = restore.as_ref().map_or("create", |_| "restore");
+            (Invocation::new(operation, &runtime, &args), path)
+        });
+        let exit_paths: Vec<PathBuf> =
+            pry!(pry_path_list!(self, "exitPaths", req.get_exit_paths())
+                .iter()
+                .map(|r| r.map(PathBuf::from))
+                .collect());
+        let oom_exit_paths: Vec<PathBuf> =
+            pry!(
+                pry_path_list!(self, "oomExitPaths", req.get_oom_exit_paths())
+                    .iter()
+                    .map(|r| r.map(PathBuf::from))
+                    .collect()
+            );
+        let success_exit_codes: Vec<i32> =
+            pry_list!(self, "successExitCodes", req.get_success_exit_codes())
+                .iter()
+                .collect();
 
         Promise::from_future(
             async move {
-                capnp_err!(container_log.write().await.init().await)?;
+                record_invocation(&invocation).await;
 
-                let grandchild_pid = capnp_err!(match child_reaper
-                    .create_child(runtime, args, &mut container_io, &pidfile)
-                    .await
-                {
+                // Initialize the log drivers concurrently to the runtime invocation. The
+                // logger lock is held until the initialization is done, which blocks the IO
+                // read loops from writing to uninitialized drivers.
+                let (init_res, child_res) = {
+                    let mut logger = container_log.write().await;
+                    if let Some(max_log_size) = overrides.log_max_size() {
+                        logger.limit_max_log_size(max_log_size);
+                    }
+                    logger.set_disk_quota(disk_quota);
+                    if let Some(tee) = tee {
+                        logger.set_tee(tee);
+                    }
+                    if let Some(xattrs) = xattrs {
+                        logger.set_xattrs(xattrs);
+                    }
+                    let init = async {
+                        let start = Instant::now();
+                        let res = logger.init().await;
+                        (res, start.elapsed())
+                    };
+                    tokio::join!(
+                        init.instrument(debug_span!("log_init")),
+                        child_reaper.create_child(&runtime, args, &mut container_io, &pidfile),
+                    )
+                };
+
+                let (init_res, log_init) = init_res;
+                if let Err(e) = init_res {
+                    if let Ok((pid, _)) = child_res {
+                        kill_grandchild(pid, Signal::SIGKILL);
+
+                        // The runtime state would block reusing the container name
+                        if let Err(e) = child_reaper.run_runtime(&runtime, &delete_args).await {
+                            warn!("Unable to delete container {}: {:#}", id, e);
+                        }
+                    }
+                    return capnp_err!(Err(e.context(Phase::LogInit)));
+                }
+
+                let (grandchild_pid, timings) = capnp_err!(match child_res {
                     Err(e) => {
+                        let mut e = runtime_log::attach_errors(e, &runtime_log).await;
+                        if let Some(restore) = &restore {
+                            let path = restore.work_path().join(criu::RESTORE_LOG);
+                            e = criu::attach_excerpt(e, &path).await;
+                        }
+
                         // Attach the stderr output to the error message
                         let (_, stderr, _) = container_io.read_all_with_timeout(None).await;
                         if !stderr.is_empty() {
                             let stderr_str = str::from_utf8(&stderr)?;
-                            Err(format_err!("{:#}: {}", e, stderr_str))
+                            Err(e.context(format!("runtime stderr: {}", stderr_str.trim_end())))
                         } else {
                             Err(e)
                         }
@@ -121,26 +472,90 @@ impl conmon::Server for Server {
 
                 // register grandchild with server
                 let io = SharedContainerIO::new(container_io);
+                let mut cleanup_paths = vec![pidfile];
+                cleanup_paths.extend(invocation.map(|(_, path)| path));
                 let child = Child::new(
-                    id,
+                    id.clone(),
                     grandchild_pid,
                     exit_paths,
                     oom_exit_paths,
                     None,
                     io,
                     cleanup_cmd,
+                    name,
+                    success_exit_codes,
+                    cleanup_paths,
+                    overrides,
                 );
-                capnp_err!(child_reaper.watch_grandchild(child))?;
+                let exit_rx = capnp_err!(child_reaper.watch_grandchild(child, false))?;
+                if let Some(reservation) = reservation {
+                    reservation.keep();
+                }
+                if let Some(interval) = psi_interval {
+                    if let Ok(child) = child_reaper.get(&id) {
+                        child.sample_pressure(interval);
+                    }
+                }
+                child_reaper.publish_container_events(id, grandchild_pid, exit_rx);
+                if let Some(completion) = completion {
+                    completion.complete(grandchild_pid);
+                }
 
-                results
-                    .get()
-                    .init_response()
-                    .set_container_pid(grandchild_pid);
-                Ok(())
+                debug!(
+                    ?timings,
+                    ?log_init,
+                    "Created container with PID {}",
+                    grandchild_pid
+                );
+                Ok(Created {
+                    pid: grandchild_pid,
+                    timings,
+                    log_init,
+                })
             }
             .instrument(debug_span!("promise")),
         )
     }
+}
+
+impl conmon::Server for Server {
+    /// Retrieve version information from the server.
+    fn version(
+        &mut self,
+        _: conmon::VersionParams,
+        mut results: conmon::VersionResults,
+    ) -> Promise<(), capnp::Error> {
+        debug!("Got a version request");
+        let mut response = results.get().init_response();
+        let version = Version::new();
+        response.set_version(version.version());
+        response.set_tag(version.tag());
+        response.set_commit(version.commit());
+        response.set_build_date(version.build_date());
+        response.set_rust_version(version.rust_version());
+        response.set_process_id(std::process::id());
+        Promise::ok(())
+    }
+
+    /// Create a new container for the provided parameters.
+    fn create_container(
+        &mut self,
+        params: conmon::CreateContainerParams,
+        mut results: conmon::CreateContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+
+        let span = new_root_span!("create_container", pry!(req.get_id()));
+        let _enter = span.enter();
+
+        debug!("Got a create container request");
+
+        let created = self.spawn_container(req, None);
+        Promise::from_future(async move {
+            set_create_response(results.get().init_response(), &created.await?);
+            Ok(())
+        })
+    }
 
     /// Execute a command in sync inside of a container.
     fn exec_sync_container(
@@ -149,45 +564,89 @@ impl conmon::Server for Server {
         mut results: conmon::ExecSyncContainerResults,
     ) -> Promise<(), capnp::Error> {
         let req = pry!(pry!(params.get()).get_request());
-        let id = pry!(req.get_id()).to_string();
-        let timeout = req.get_timeout_sec();
+        let id = pry_text!(self, "id", req.get_id());
+        let id = pry_err!(self.reaper().resolve_id(id));
+        let timeout = match req.get_timeout_sec() {
+            0 => self.config().timeouts().exec(),
+            secs => Some(Duration::from_secs(secs)),
+        };
 
+        let token = pry_text!(self, "idempotencyToken", req.get_idempotency_token());
+        let completion = match pry_err!(self.exec_sync_tokens().claim(self.tenant(), &id, token)) {
+            Claim::Untracked => None,
+            Claim::Run(completion) => Some(completion),
+            Claim::Existing(rx) => {
+                debug!("Returning the result of the request with the same idempotency token");
+                return Promise::from_future(
+                    async move {
+                        let result = capnp_err!(IdempotencyCache::wait(rx).await)?;
+                        set_exec_sync_response(&result, &mut results);
+                        Ok(())
+                    }
+                    .instrument(debug_span!("promise")),
+                );
+            }
+        };
+
+        let tenant_dir = pry_err!(self.tenant_dir());
+        let runtime_dir = tenant_dir
+            .as_deref()
+            .unwrap_or_else(|| self.config().runtime_dir().as_path());
         let pidfile = pry_err!(ContainerIO::temp_file_name(
-            Some(self.config().runtime_dir()),
-            "exec_sync",
-            "pid"
+            Some(runtime_dir),
+            &id,
+            "exec_sync-",
+            ".pid"
         ));
 
         let span = new_root_span!("exec_sync_container", id.as_str());
         let _enter = span.enter();
 
-        debug!("Got exec sync container request with timeout {}", timeout);
+        debug!("Got exec sync container request with timeout {:?}", timeout);
 
-        let runtime = self.config().runtime().clone();
+        let runtime = self.runtime_config().runtime();
         let child_reaper = self.reaper().clone();
+        let exec_sessions = child_reaper.exec_sessions().clone();
+        pry_err!(exec_sessions.gc(Duration::from_secs(self.config().exec_session_ttl())));
 
         let logger = ContainerLog::new();
-        let mut container_io = pry_err!(ContainerIO::new(req.get_terminal(), logger));
+        let mut container_io = pry_err!(ContainerIO::new(
+            &id,
+            req.get_terminal(),
+            false,
+            logger,
+            tenant_dir.as_deref()
+        ));
 
-        let command = pry!(req.get_command());
+        let command = pry_text_list!(self, "command", req.get_command());
         let args = pry_err!(self.generate_exec_sync_args(&id, &pidfile, &container_io, &command));
+        let pidfd_socket_path =
+            PathBuf::from(pry_path!("pidfdSocketPath", req.get_pidfd_socket_path()));
+        let max_inline_output = req.get_max_inline_output();
+        let output_dir = runtime_dir.to_path_buf();
+        let invocation = self.config().record_runtime_invocations().then(|| {
+            let path = Invocation::record_path(runtime_dir, &id);
+            (Invocation::new("exec", &runtime, &args), path)
+        });
 
         Promise::from_future(
             async move {
-                match child_reaper
+                record_invocation(&invocation).await;
+                let result = match child_reaper
                     .create_child(&runtime, &args, &mut container_io, &pidfile)
                     .await
                 {
-                    Ok(grandchild_pid) => {
-                        let time_to_timeout = if timeout > 0 {
-                            Some(Instant::now() + Duration::from_secs(timeout))
-                        } else {
-                            None
-                        };
-                        let mut resp = results.get().init_response();
+                    Ok((grandchild_pid, _)) => {
+                        let time_to_timeout = timeout.map(|t| Instant::now() + t);
                         // register grandchild with server
                         let io = SharedContainerIO::new(container_io);
                         let io_clone = io.clone();
+                        let session_id = capnp_err!(exec_sessions.register(
+                            &id,
+                            ExecKind::Sync,
+                            grandchild_pid,
+                            vec![capnp_err!(pidfile.keep())?],
+                        ))?;
                         let child = Child::new(
                             id,
                             grandchild_pid,
@@ -196,26 +655,62 @@ impl conmon::Server for Server {
                             time_to_timeout,
                             io_clone,
                             vec![],
+                            None,
+                            vec![],
+                            vec![],
+                            Overrides::default(),
                         );
 
-                        let mut exit_rx = capnp_err!(child_reaper.watch_grandchild(child))?;
+                        let mut exit_rx = capnp_err!(child_reaper.watch_grandchild(child, true))?;
+                        if !pidfd_socket_path.as_os_str().is_empty() {
+                            let send = pidfd::send(
+                                grandchild_pid,
+                                &pidfd_socket_path,
+                                session_id.as_bytes(),
+                            );
+                            match time::timeout(pidfd::SEND_TIMEOUT, send).await {
+                                Ok(Ok(())) => {}
+                                Ok(Err(e)) => {
+                                    error!("Unable to send pidfd of exec process: {:#}", e)
+                                }
+                                Err(_) => error!("Sending pidfd of exec process timed out"),
+                            }
+                        }
 
                         let (stdout, stderr, timed_out) =
                             io.read_all_with_timeout(time_to_timeout).await;
 
-                        let exit_data = capnp_err!(exit_rx.recv().await)?;
-                        resp.set_stdout(&stdout);
-                        resp.set_stderr(&stderr);
-                        resp.set_exit_code(*exit_data.exit_code());
-                        if timed_out || exit_data.timed_out {
-                            resp.set_timed_out(true);
+                        let exit_data = capnp_err!(exit_rx.recv().await.context(Phase::ExecWait))?;
+                        capnp_err!(exec_sessions.finish(&session_id, *exit_data.exit_code()))?;
+                        let mut result = ExecSyncResult::new(
+                            session_id.clone(),
+                            grandchild_pid,
+                            *exit_data.exit_code(),
+                            stdout,
+                            stderr,
+                            timed_out || *exit_data.timed_out(),
+                        );
+                        let size = result.stdout_size() + result.stderr_size();
+                        if max_inline_output > 0 && size > max_inline_output {
+                            debug!("Storing {} output bytes in exec session", size);
+                            let (stdout, stderr) = result.take_output();
+                            capnp_err!(
+                                exec_sessions
+                                    .store_output(&session_id, &output_dir, stdout, stderr)
+                                    .await
+                            )?;
                         }
+                        result
                     }
                     Err(e) => {
                         error!("Unable to create child: {:#}", e);
-                        let mut resp = results.get().init_response();
-                        resp.set_exit_code(-2);
+                        ExecSyncResult::failed()
                     }
+                };
+                set_exec_sync_response(&result, &mut results);
+                // Failed executions are not recorded, so that retries run them again
+                if let Some(completion) = completion.filter(|_| result.pid() > 0) {
+                    completion.complete(result);
                 }
                 Ok(())
             }
@@ -227,10 +722,10 @@ impl conmon::Server for Server {
     fn attach_container(
         &mut self,
         params: conmon::AttachContainerParams,
-        _: conmon::AttachContainerResults,
+        mut results: conmon::AttachContainerResults,
     ) -> Promise<(), capnp::Error> {
         let req = pry!(pry!(params.get()).get_request());
-        let container_id = pry_err!(req.get_id());
+        let container_id = pry_text!(self, "id", req.get_id());
 
         let span = new_root_span!("attach_container", container_id);
         let _enter = span.enter();
@@ -238,16 +733,39 @@ impl conmon::Server for Server {
         debug!("Got a attach container request",);
 
         let exec_session_id = pry_err!(req.get_exec_session_id());
-        if !exec_session_id.is_empty() {
+        let io = if exec_session_id.is_empty() {
+            pry_err!(self.reaper().get(container_id)).io().clone()
+        } else {
             debug!("Using exec session id {}", exec_session_id);
-        }
+            let id = pry_err!(self.reaper().resolve_id(container_id));
+            pry_err!(self.reaper().exec_sessions().io(&id, exec_session_id))
+        };
 
-        let socket_path = pry!(req.get_socket_path()).to_string();
-        let child = pry_err!(self.reaper().get(container_id));
+        let socket_path = pry_path!("socketPath", req.get_socket_path()).to_string();
+        let version = attach_protocol::version(req.get_protocol_version());
+        let translation = Translation::from_capnp(
+            pry!(req.get_client_encoding()),
+            pry!(req.get_container_encoding()),
+        );
+        if let Some(translation) = translation {
+            debug!("Using attach encoding translation {:?}", translation);
+        }
 
         Promise::from_future(
-            async move { capnp_err!(child.io().attach().await.add(&socket_path).await) }
-                .instrument(debug_span!("promise")),
+            async move {
+                capnp_err!(io
+                    .attach()
+                    .await
+                    .add(&socket_path, version, translation)
+                    .await
+                    .context(Phase::AttachSocket))?;
+                results
+                    .get()
+                    .init_response()
+                    .set_protocol_version(version.into());
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
         )
     }
 
@@ -258,7 +776,7 @@ impl conmon::Server for Server {
         _: conmon::ReopenLogContainerResults,
     ) -> Promise<(), capnp::Error> {
         let req = pry!(pry!(params.get()).get_request());
-        let container_id = pry_err!(req.get_id());
+        let container_id = pry_text!(self, "id", req.get_id());
 
         let span = new_root_span!("reopen_log_container", container_id);
         let _enter = span.enter();
@@ -266,10 +784,16 @@ impl conmon::Server for Server {
         debug!("Got a reopen container log request");
 
         let child = pry_err!(self.reaper().get(container_id));
+        let id = pry_err!(self.reaper().resolve_id(container_id));
+        let events = self.reaper().events().clone();
 
         Promise::from_future(
-            async move { capnp_err!(child.io().logger().await.write().await.reopen().await) }
-                .instrument(debug_span!("promise")),
+            async move {
+                capnp_err!(child.io().logger().await.write().await.reopen().await)?;
+                events.publish(EventKind::LogRotated, &id, child.pid(), 0, 0);
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
         )
     }
 
@@ -280,7 +804,7 @@ impl conmon::Server for Server {
         _: conmon::SetWindowSizeContainerResults,
     ) -> Promise<(), capnp::Error> {
         let req = pry!(pry!(params.get()).get_request());
-        let container_id = pry_err!(req.get_id());
+        let container_id = pry_text!(self, "id", req.get_id());
 
         let span = new_root_span!("set_window_size_container", container_id);
         let _enter = span.enter();
@@ -296,4 +820,1482 @@ impl conmon::Server for Server {
                 .instrument(debug_span!("promise")),
         )
     }
+
+    /// Negotiate the schema version and features between client and server.
+    fn negotiate(
+        &mut self,
+        params: conmon::NegotiateParams,
+        mut results: conmon::NegotiateResults,
+    ) -> Promise<(), capnp::Error> {
+        debug!("Got a negotiate request");
+        let req = pry!(pry!(params.get()).get_request());
+        let client_features: Vec<&str> = pry!(pry_text_list!(self, "features", req.get_features())
+            .iter()
+            .collect());
+
+        let schema_version = negotiate::schema_version(req.get_schema_version());
+        let features = negotiate::common_features(&client_features);
+        debug!(
+            "Negotiated schema version {} and features {:?}",
+            schema_version, features
+        );
+
+        let mut response = results.get().init_response();
+        response.set_schema_version(schema_version);
+        let mut list = response.init_features(features.len() as u32);
+        for (i, feature) in features.iter().enumerate() {
+            list.set(i as u32, feature.as_ref());
+        }
+        Promise::ok(())
+    }
+
+    /// Create a one-shot raw stream socket for a running container.
+    fn attach_stream_container(
+        &mut self,
+        params: conmon::AttachStreamContainerParams,
+        mut results: conmon::AttachStreamContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("attach_stream_container", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a attach stream container request");
+
+        let child = pry_err!(self.reaper().get(container_id));
+        let tenant_dir = pry_err!(self.tenant_dir());
+        let socket_path = pry_err!(ContainerIO::temp_file_name(
+            Some(
+                tenant_dir
+                    .as_deref()
+                    .unwrap_or_else(|| self.config().runtime_dir().as_path())
+            ),
+            container_id,
+            "attach-",
+            ".sock"
+        ));
+        let token = Uuid::new_v4().to_string();
+
+        Promise::from_future(
+            async move {
+                capnp_err!(child
+                    .io()
+                    .attach()
+                    .await
+                    .add_passthrough(&socket_path, &token)
+                    .await
+                    .context(Phase::AttachSocket))?;
+                let socket_path = capnp_err!(socket_path.keep())?;
+
+                let mut response = results.get().init_response();
+                response.set_socket_path(&socket_path.display().to_string());
+                response.set_token(&token);
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Retrieve the status of multiple containers at once.
+    fn list_container_statuses(
+        &mut self,
+        params: conmon::ListContainerStatusesParams,
+        mut results: conmon::ListContainerStatusesResults,
+    ) -> Promise<(), capnp::Error> {
+        debug!("Got a list container statuses request");
+        let req = pry!(pry!(params.get()).get_request());
+        let ids: Vec<&str> = pry!(pry_text_list!(self, "ids", req.get_ids()).iter().collect());
+        let fields = pry!(req.get_fields());
+        let children = pry_err!(self.reaper().list(&ids));
+
+        let mut statuses = results
+            .get()
+            .init_response()
+            .init_statuses(children.len() as u32);
+        for (i, (id, child)) in children.iter().enumerate() {
+            let mut status = statuses.reborrow().get(i as u32);
+            status.set_id(id);
+            status.set_pid(child.pid());
+
+            if fields.get_state() {
+                match pry_err!(child.exit_data()) {
+                    Some(exit_data) => {
+                        status.set_exit_code(*exit_data.exit_code());
+                        status.set_raw_exit_code(*exit_data.raw_exit_code());
+                    }
+                    None => status.set_running(true),
+                }
+                status.set_degraded(child.io().supervisor().degraded());
+                if let Some(failure) = pry_err!(child.cleanup_failure()) {
+                    status.set_cleanup_failure(&failure);
+                }
+            }
+
+            if fields.get_overrides() {
+                let entries = child.overrides().entries();
+                let mut overrides = status.reborrow().init_overrides(entries.len() as u32);
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    let mut o = overrides.reborrow().get(i as u32);
+                    o.set_key(key);
+                    o.set_value(value);
+                }
+            }
+
+            if fields.get_resource_usage() {
+                match ResourceUsage::from_pid(child.pid()) {
+                    Ok(usage) => {
+                        let mut resource_usage = status.init_resource_usage();
+                        resource_usage.set_user_time_micros(usage.user_time_micros());
+                        resource_usage.set_system_time_micros(usage.system_time_micros());
+                        resource_usage.set_rss_bytes(usage.rss_bytes());
+                    }
+                    Err(e) => debug!("Unable to get resource usage for {}: {:#}", id, e),
+                }
+            }
+        }
+        Promise::ok(())
+    }
+
+    /// Retrieve the container lifecycle events after the provided sequence number.
+    fn get_events(
+        &mut self,
+        params: conmon::GetEventsParams,
+        mut results: conmon::GetEventsResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let since = req.get_since_sequence();
+        debug!("Got a get events request since sequence {}", since);
+
+        let (events, last_sequence, truncated) = pry_err!(self.reaper().events().replay(since));
+
+        let mut response = results.get().init_response();
+        response.set_last_sequence(last_sequence);
+        response.set_truncated(truncated);
+        let mut list = response.init_events(events.len() as u32);
+        for (i, event) in events.iter().enumerate() {
+            event_stream::set_event(list.reborrow().get(i as u32), event);
+        }
+        Promise::ok(())
+    }
+
+    /// Retrieve the exec sessions of a container.
+    fn list_exec_sessions(
+        &mut self,
+        params: conmon::ListExecSessionsParams,
+        mut results: conmon::ListExecSessionsResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+        let id = pry_err!(self.reaper().resolve_id(id));
+
+        let span = new_root_span!("list_exec_sessions", id.as_str());
+        let _enter = span.enter();
+        debug!("Got a list exec sessions request");
+
+        let exec_sessions = self.reaper().exec_sessions();
+        pry_err!(exec_sessions.gc(Duration::from_secs(self.config().exec_session_ttl())));
+        let sessions = pry_err!(exec_sessions.list(&id));
+
+        let mut list = results
+            .get()
+            .init_response()
+            .init_sessions(sessions.len() as u32);
+        for (i, session) in sessions.iter().enumerate() {
+            let mut s = list.reborrow().get(i as u32);
+            s.set_id(session.id());
+            s.set_kind(match session.kind() {
+                ExecKind::Sync => ExecSessionKind::Sync,
+                ExecKind::Streaming => ExecSessionKind::Streaming,
+            });
+            s.set_pid(session.pid());
+            match session.exit_code() {
+                Some(exit_code) => s.set_exit_code(exit_code),
+                None => s.set_running(true),
+            }
+            s.set_started_at(session.started_at());
+            s.set_finished_at(session.finished_at());
+        }
+        Promise::ok(())
+    }
+
+    /// Retrieve the IO statistics of a container.
+    fn container_i_o_stats(
+        &mut self,
+        params: conmon::ContainerIOStatsParams,
+        mut results: conmon::ContainerIOStatsResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("container_io_stats", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a container IO stats request");
+
+        let child = pry_err!(self.reaper().get(container_id));
+
+        Promise::from_future(
+            async move {
+                let stats = child.io().stats().await;
+                let disk_usage = child.io().logger().await.read().await.disk_usage();
+                let mut response = results.get().init_response();
+                response.set_stdout_bytes(stats.stdout_bytes());
+                response.set_stderr_bytes(stats.stderr_bytes());
+                response.set_attach_bytes(stats.attach_bytes());
+                response.set_buffered_bytes(stats.buffered_bytes());
+                response.set_stalls(stats.stalls());
+                response.set_log_disk_bytes(disk_usage.total_bytes());
+                response.set_pruned_log_bytes(disk_usage.pruned_bytes());
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Set the log level of the server.
+    fn set_log_level(
+        &mut self,
+        params: conmon::SetLogLevelParams,
+        mut results: conmon::SetLogLevelResults,
+    ) -> Promise<(), capnp::Error> {
+        debug!("Got a set log level request");
+        let req = pry!(pry!(params.get()).get_request());
+        let level = pry!(req.get_level());
+
+        let previous = pry_err!(self.log_level().set(level));
+        info!("Set log level to: {}", level);
+
+        results
+            .get()
+            .init_response()
+            .set_previous_level(&previous.to_string());
+        Promise::ok(())
+    }
+
+    /// Remove an exited container including its on-disk artifacts.
+    fn remove_container(
+        &mut self,
+        params: conmon::RemoveContainerParams,
+        mut results: conmon::RemoveContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("remove_container", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a remove container request");
+
+        // Aliases are gone after the removal, but the runtime only knows the identifier.
+        let id = pry_err!(self.reaper().resolve_id(container_id));
+        let child = pry_err!(self.reaper().remove(&id));
+        let remove_logs = req.get_remove_logs();
+        let child_reaper = self.reaper().clone();
+        let runtime = self.runtime_config().runtime();
+        let runtime_args = self.generate_global_args();
+
+        Promise::from_future(
+            async move {
+                capnp_err!(child.remove_artifacts(remove_logs).await)?;
+                task::spawn(
+                    async move {
+                        child_reaper
+                            .verify_deleted(&runtime, &runtime_args, &id, child.pid())
+                            .await
+                    }
+                    .instrument(debug_span!("verify_deleted")),
+                );
+                results.get().init_response();
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Retrieve the offset of the CRI log file of a container to seek to for reading the lines
+    /// written since a point in time.
+    fn get_container_log_offset(
+        &mut self,
+        params: conmon::GetContainerLogOffsetParams,
+        mut results: conmon::GetContainerLogOffsetResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("get_container_log_offset", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a get container log offset request");
+
+        let child = pry_err!(self.reaper().get(container_id));
+        let since = req.get_since();
+
+        Promise::from_future(
+            async move {
+                let logger = child.io().logger().await;
+                let (path, offset) = capnp_err!(logger.read().await.offset_before(since).await)?
+                    .ok_or_else(|| Error::failed("container has no CRI log driver".into()))?;
+
+                let mut response = results.get().init_response();
+                response.set_path(&path.display().to_string());
+                response.set_offset(offset);
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Write all buffered log data of a container and synchronize the log files to disk.
+    fn flush_container_logs(
+        &mut self,
+        params: conmon::FlushContainerLogsParams,
+        mut results: conmon::FlushContainerLogsResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("flush_container_logs", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a flush container logs request");
+
+        let child = pry_err!(self.reaper().get(container_id));
+
+        Promise::from_future(
+            async move {
+                capnp_err!(child.io().logger().await.write().await.sync().await)?;
+                results.get().init_response();
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Check a container creation request without spawning the runtime.
+    fn validate_create(
+        &mut self,
+        params: conmon::ValidateCreateParams,
+        mut results: conmon::ValidateCreateResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("validate_create", id);
+        let _enter = span.enter();
+
+        debug!("Got a validate create request");
+
+        let mut validator = Validator::default();
+        if id.is_empty() {
+            validator.add("id", "no container ID provided");
+        } else if self.reaper().get(id).is_ok() {
+            validator.add("id", format!("container {} already exists", id));
+        }
+
+        let name = pry_text!(self, "name", req.get_name());
+        if !name.is_empty() {
+            if let Err(e) = self.reaper().check_alias(name) {
+                validator.add("name", format!("{:#}", e));
+            }
+        }
+
+        validator.bundle(Path::new(pry_path!("bundlePath", req.get_bundle_path())));
+        validator.stdin(
+            Path::new(pry_path!("stdinPath", req.get_stdin_path())),
+            req.get_terminal(),
+        );
+        validator.tee(Path::new(pry_path!("teePath", req.get_tee_path())));
+        validator.runtime(&self.runtime_config().runtime());
+
+        for (i, driver) in pry_list!(self, "logDrivers", req.get_log_drivers())
+            .iter()
+            .enumerate()
+        {
+            match driver.get_type() {
+                Ok(typ) => validator.log_driver(
+                    i,
+                    typ,
+                    Path::new(pry!(driver.get_path())),
+                    pry!(driver.get_pod_id()),
+                ),
+                Err(e) => validator.add(format!("logDrivers[{}]", i), e.to_string()),
+            }
+        }
+
+        for (field, paths) in [
+            (
+                "exitPaths",
+                pry_path_list!(self, "exitPaths", req.get_exit_paths()),
+            ),
+            (
+                "oomExitPaths",
+                pry_path_list!(self, "oomExitPaths", req.get_oom_exit_paths()),
+            ),
+        ] {
+            for path in paths.iter() {
+                validator.writable_path(field, Path::new(pry!(path)));
+            }
+        }
+
+        let problems = validator.problems();
+        let mut list = results
+            .get()
+            .init_response()
+            .init_problems(problems.len() as u32);
+        for (i, problem) in problems.iter().enumerate() {
+            let mut p = list.reborrow().get(i as u32);
+            p.set_field(problem.field());
+            p.set_message(problem.message());
+        }
+        Promise::ok(())
+    }
+
+    /// Replace the log drivers of a container without restarting it.
+    fn update_log_drivers(
+        &mut self,
+        params: conmon::UpdateLogDriversParams,
+        mut results: conmon::UpdateLogDriversResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("update_log_drivers", container_id);
+        let _enter = span.enter();
+
+        debug!("Got an update log drivers request");
+
+        let id = pry_err!(self.reaper().resolve_id(container_id));
+        let child = pry_err!(self.reaper().get(&id));
+        let log_drivers = pry_list!(self, "logDrivers", req.get_log_drivers());
+        let container_log = pry_err!(ContainerLog::from(log_drivers, &id));
+        let max_log_size = child.overrides().log_max_size();
+        let disk_quota = child.overrides().disk_quota(self.default_log_disk_quota());
+
+        Promise::from_future(
+            async move {
+                // Initialize the new drivers first, so that the output keeps being forwarded
+                // to the current ones until the swap.
+                let mut new_logger = container_log.write().await;
+                if let Some(max_log_size) = max_log_size {
+                    new_logger.limit_max_log_size(max_log_size);
+                }
+                new_logger.set_disk_quota(disk_quota);
+                let xattrs = child.io().logger().await.read().await.xattrs().cloned();
+                if let Some(xattrs) = xattrs {
+                    new_logger.set_xattrs(xattrs);
+                }
+                // Logs which are written already must not be truncated.
+                let current_paths = child.io().logger().await.read().await.cri_paths();
+                capnp_err!(new_logger.init_except(&current_paths).await)?;
+                capnp_err!(
+                    child
+                        .io()
+                        .logger()
+                        .await
+                        .write()
+                        .await
+                        .replace_drivers(&mut new_logger)
+                        .await
+                )?;
+                results.get().init_response();
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Check the health of the server itself.
+    fn health(
+        &mut self,
+        _: conmon::HealthParams,
+        mut results: conmon::HealthResults,
+    ) -> Promise<(), capnp::Error> {
+        debug!("Got a health request");
+        let sigchld_waiter = self.reaper().sigchld_waiter().clone();
+        let runtime_dir = self.config().runtime_dir().clone();
+
+        Promise::from_future(
+            async move {
+                let health = Health::check(&sigchld_waiter, &runtime_dir).await;
+                for problem in health.problems() {
+                    warn!("Health check failed: {}", problem);
+                }
+
+                let mut response = results.get().init_response();
+                response.set_healthy(health.problems().is_empty());
+                let mut problems = response
+                    .reborrow()
+                    .init_problems(health.problems().len() as u32);
+                for (i, problem) in health.problems().iter().enumerate() {
+                    problems.set(i as u32, problem);
+                }
+                response.set_reactor_latency_micros(health.reactor_latency().as_micros() as u64);
+                response.set_last_reaper_wakeup(health.last_reaper_wakeup());
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Change the echo and raw mode of a container running inside of a terminal.
+    fn set_terminal_mode_container(
+        &mut self,
+        params: conmon::SetTerminalModeContainerParams,
+        _: conmon::SetTerminalModeContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("set_terminal_mode_container", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a set terminal mode container request");
+
+        let child = pry_err!(self.reaper().get(container_id));
+        let toggle = |t: Toggle| match t {
+            Toggle::Unchanged => None,
+            Toggle::Enabled => Some(true),
+            Toggle::Disabled => Some(false),
+        };
+        let mode = TerminalMode {
+            echo: toggle(pry!(req.get_echo())),
+            raw: toggle(pry!(req.get_raw())),
+            send_break: req.get_send_break(),
+        };
+
+        Promise::from_future(
+            async move { capnp_err!(child.io().set_terminal_mode(&mode).await) }
+                .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Read a chunk of the output stored in an exec session.
+    fn read_exec_output(
+        &mut self,
+        params: conmon::ReadExecOutputParams,
+        mut results: conmon::ReadExecOutputResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+        let id = pry_err!(self.reaper().resolve_id(id));
+        let session_id = pry_text!(self, "sessionId", req.get_session_id()).to_string();
+
+        let span = new_root_span!("read_exec_output", id.as_str());
+        let _enter = span.enter();
+
+        debug!("Got a read exec output request for session {}", session_id);
+
+        let pipe = match pry!(req.get_pipe()) {
+            ReadExecOutputPipe::Stdout => Pipe::StdOut,
+            ReadExecOutputPipe::Stderr => Pipe::StdErr,
+        };
+        let offset = req.get_offset();
+        let length = req.get_length();
+        let exec_sessions = self.reaper().exec_sessions().clone();
+
+        Promise::from_future(
+            async move {
+                let (data, eof) = capnp_err!(
+                    exec_sessions
+                        .read_output(&id, &session_id, pipe, offset, length)
+                        .await
+                )?;
+                let mut response = results.get().init_response();
+                response.set_data(&data);
+                response.set_eof(eof);
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Duplicate the output of a container to a short-lived debug socket.
+    fn mirror_container_i_o(
+        &mut self,
+        params: conmon::MirrorContainerIOParams,
+        _: conmon::MirrorContainerIOResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("mirror_container_i_o", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a mirror container IO request");
+
+        let child = pry_err!(self.reaper().get(container_id));
+        let socket_path = PathBuf::from(pry_path!("socketPath", req.get_socket_path()));
+        let pipe = match pry!(req.get_pipe()) {
+            MirrorPipe::All => None,
+            MirrorPipe::Stdout => Some(Pipe::StdOut),
+            MirrorPipe::Stderr => Some(Pipe::StdErr),
+        };
+        let duration = match req.get_duration_sec() {
+            0 => Duration::from_secs(60),
+            secs => Duration::from_secs(secs.into()),
+        };
+
+        Promise::from_future(
+            async move {
+                capnp_err!(child
+                    .io()
+                    .attach()
+                    .await
+                    .add_mirror(&socket_path, pipe, duration))
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Retrieve the resource statistics of a container.
+    fn container_stats(
+        &mut self,
+        params: conmon::ContainerStatsParams,
+        mut results: conmon::ContainerStatsResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("container_stats", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a container stats request");
+
+        let child = pry_err!(self.reaper().get(container_id));
+        let running = pry_err!(child.exit_data()).is_none();
+
+        Promise::from_future(
+            async move {
+                // The cgroup gets removed together with the container.
+                let stats = if running {
+                    Some(capnp_err!(CgroupStats::for_pid(child.pid()).await)?)
+                } else {
+                    None
+                };
+
+                let mut response = results.get().init_response();
+                if let Some(sample) = capnp_err!(child.pressure().latest())? {
+                    response.set_pressure_sampled(true);
+                    response.set_pressure_timestamp(sample.timestamp());
+                    set_resource_pressure(response.reborrow().init_cpu_pressure(), sample.cpu());
+                    set_resource_pressure(
+                        response.reborrow().init_memory_pressure(),
+                        sample.memory(),
+                    );
+                    set_resource_pressure(response.reborrow().init_io_pressure(), sample.io());
+                }
+                if let Some(stats) = stats {
+                    response.set_cgroup_sampled(true);
+                    response.set_cpu_usage_nanos(stats.cpu_usage());
+                    response.set_cpu_user_nanos(stats.cpu_user());
+                    response.set_cpu_system_nanos(stats.cpu_system());
+                    response.set_memory_current_bytes(stats.memory_current());
+                    response.set_memory_peak_bytes(stats.memory_peak());
+                    response.set_pids_current(stats.pids_current());
+                    response.set_io_read_bytes(stats.io_read_bytes());
+                    response.set_io_write_bytes(stats.io_write_bytes());
+                }
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Freeze all processes of a container by its cgroup.
+    fn freeze_container(
+        &mut self,
+        params: conmon::FreezeContainerParams,
+        _: conmon::FreezeContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("freeze_container", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a freeze container request");
+
+        let child = pry_err!(self.reaper().get(container_id));
+        if pry_err!(child.reaped()) {
+            return Promise::err(Error::failed(format!(
+                "container {} is not running",
+                container_id
+            )));
+        }
+
+        Promise::from_future(
+            async move {
+                let freezer = capnp_err!(Freezer::for_pid(child.pid()).await)?;
+                capnp_err!(freezer.freeze().await)
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Thaw all processes of a previously frozen container.
+    fn thaw_container(
+        &mut self,
+        params: conmon::ThawContainerParams,
+        _: conmon::ThawContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("thaw_container", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a thaw container request");
+
+        let child = pry_err!(self.reaper().get(container_id));
+
+        Promise::from_future(
+            async move {
+                let freezer = capnp_err!(Freezer::for_pid(child.pid()).await)?;
+                capnp_err!(freezer.thaw().await)
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// List the processes of a container.
+    fn container_processes(
+        &mut self,
+        params: conmon::ContainerProcessesParams,
+        mut results: conmon::ContainerProcessesResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("container_processes", container_id);
+        let _enter = span.enter();
+
+        debug!("Got a container processes request");
+
+        let child = pry_err!(self.reaper().get(container_id));
+        if pry_err!(child.reaped()) {
+            return Promise::err(Error::failed(format!(
+                "container {} is not running",
+                container_id
+            )));
+        }
+
+        Promise::from_future(
+            async move {
+                let processes = capnp_err!(processes::list(child.pid()).await)?;
+                let mut list = results
+                    .get()
+                    .init_response()
+                    .init_processes(processes.len() as u32);
+                for (i, process) in processes.iter().enumerate() {
+                    let mut p = list.reborrow().get(i as u32);
+                    p.set_pid(process.pid());
+                    p.set_ppid(process.ppid());
+                    p.set_comm(process.comm());
+                    p.set_start_time(process.start_time());
+                }
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Retrieve the latency histograms of all RPC methods.
+    fn request_latencies(
+        &mut self,
+        _: conmon::RequestLatenciesParams,
+        mut results: conmon::RequestLatenciesResults,
+    ) -> Promise<(), capnp::Error> {
+        debug!("Got a request latencies request");
+        let snapshot = pry_err!(self.latencies().snapshot());
+
+        let mut methods = results
+            .get()
+            .init_response()
+            .init_methods(snapshot.len() as u32);
+        for (i, (method, histogram)) in snapshot.iter().enumerate() {
+            let mut m = methods.reborrow().get(i as u32);
+            m.set_method(method);
+            m.set_count(histogram.count());
+            m.set_sum_micros(histogram.sum().as_micros() as u64);
+            m.set_max_micros(histogram.max().as_micros() as u64);
+            m.set_slow(histogram.slow());
+            let mut buckets = m.init_buckets(histogram.buckets().len() as u32);
+            for (j, count) in histogram.buckets().iter().enumerate() {
+                let mut bucket = buckets.reborrow().get(j as u32);
+                bucket.set_upper_bound_micros(
+                    latency::BUCKETS
+                        .get(j)
+                        .map_or(0, |bound| bound.as_micros() as u64),
+                );
+                bucket.set_count(*count);
+            }
+        }
+        Promise::ok(())
+    }
+
+    /// Send a signal to the init process of a running container.
+    fn kill_container(
+        &mut self,
+        params: conmon::KillContainerParams,
+        _: conmon::KillContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container_id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("kill_container", container_id);
+        let _enter = span.enter();
+
+        debug!(
+            "Got a kill container request for signal {}",
+            req.get_signal()
+        );
+
+        let signal = pry_err!(Signal::try_from(req.get_signal() as i32));
+        let child = pry_err!(self.reaper().get(container_id));
+        pry_err!(child.signal(signal));
+        Promise::ok(())
+    }
+
+    /// Start a container which got created by createContainer.
+    fn start_container(
+        &mut self,
+        params: conmon::StartContainerParams,
+        _: conmon::StartContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+        let id = pry_err!(self.reaper().resolve_id(id));
+
+        let span = new_root_span!("start_container", id.as_str());
+        let _enter = span.enter();
+
+        debug!("Got a start container request");
+        run_lifecycle_command(self, "start", &id)
+    }
+
+    /// List all containers tracked by the reaper.
+    fn list_containers(
+        &mut self,
+        _: conmon::ListContainersParams,
+        mut results: conmon::ListContainersResults,
+    ) -> Promise<(), capnp::Error> {
+        debug!("Got a list containers request");
+        let children = pry_err!(self.reaper().list(&[]));
+
+        let mut containers = results
+            .get()
+            .init_response()
+            .init_containers(children.len() as u32);
+        for (i, (id, child)) in children.iter().enumerate() {
+            let mut container = containers.reborrow().get(i as u32);
+            container.set_id(id);
+            container.set_pid(child.pid());
+            container.set_created(child.created());
+            container.set_terminal(child.io().terminal());
+            container.set_running(pry_err!(child.exit_data()).is_none());
+
+            let mut exit_paths = container
+                .reborrow()
+                .init_exit_paths(child.exit_paths().len() as u32);
+            for (j, path) in child.exit_paths().iter().enumerate() {
+                exit_paths.set(j as u32, &path.display().to_string());
+            }
+            let mut oom_exit_paths =
+                container.init_oom_exit_paths(child.oom_exit_paths().len() as u32);
+            for (j, path) in child.oom_exit_paths().iter().enumerate() {
+                oom_exit_paths.set(j as u32, &path.display().to_string());
+            }
+        }
+        Promise::ok(())
+    }
+
+    /// Update the Linux resources of a running container.
+    fn update_container_resources(
+        &mut self,
+        params: conmon::UpdateContainerResourcesParams,
+        mut results: conmon::UpdateContainerResourcesResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+        let id = pry_err!(self.reaper().resolve_id(id));
+
+        let span = new_root_span!("update_container_resources", id.as_str());
+        let _enter = span.enter();
+
+        debug!("Got an update container resources request");
+
+        let r = pry!(req.get_resources());
+        let resources = pry_err!(Resources::new(
+            r.get_cpu_shares(),
+            r.get_cpu_quota(),
+            r.get_cpu_period(),
+            pry!(r.get_cpuset_cpus()).into(),
+            pry!(r.get_cpuset_mems()).into(),
+            r.get_memory_limit(),
+            r.get_memory_swap(),
+            r.get_pids_limit(),
+        )
+        .normalize());
+
+        let child = pry_err!(self.reaper().get(&id));
+        if pry_err!(child.reaped()) {
+            return Promise::err(Error::failed(format!("container {} is not running", id)));
+        }
+
+        let tenant_dir = pry_err!(self.tenant_dir());
+        let dir = tenant_dir
+            .as_deref()
+            .unwrap_or_else(|| self.config().runtime_dir().as_path());
+        let resources_file = pry_err!(ContainerIO::temp_file_name(
+            Some(dir),
+            &id,
+            "resources-",
+            ".json"
+        ));
+        let runtime_log = pry_err!(ContainerIO::temp_file_name(
+            Some(dir),
+            &id,
+            "runtime-",
+            ".log"
+        ));
+        let args = self.generate_update_args(&id, &resources_file, &runtime_log);
+        let runtime = self.runtime_config().runtime();
+        let child_reaper = self.reaper().clone();
+
+        Promise::from_future(
+            async move {
+                capnp_err!(fs::write(&resources_file, resources.to_json())
+                    .await
+                    .context("write resources file"))?;
+                if let Err(e) = child_reaper.run_runtime(&runtime, &args).await {
+                    return Err(rpc_error::failed(
+                        runtime_log::attach_errors(e, &runtime_log).await,
+                    ));
+                }
+                debug!("Applied resources {}", resources.to_json());
+                set_linux_resources(results.get().init_response().init_applied(), &resources);
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Pause all processes of a running container by the runtime.
+    fn pause_container(
+        &mut self,
+        params: conmon::PauseContainerParams,
+        _: conmon::PauseContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+        let id = pry_err!(self.reaper().resolve_id(id));
+
+        let span = new_root_span!("pause_container", id.as_str());
+        let _enter = span.enter();
+
+        debug!("Got a pause container request");
+        run_lifecycle_command(self, "pause", &id)
+    }
+
+    /// Resume all processes of a paused container by the runtime.
+    fn unpause_container(
+        &mut self,
+        params: conmon::UnpauseContainerParams,
+        _: conmon::UnpauseContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+        let id = pry_err!(self.reaper().resolve_id(id));
+
+        let span = new_root_span!("unpause_container", id.as_str());
+        let _enter = span.enter();
+
+        debug!("Got an unpause container request");
+        run_lifecycle_command(self, "resume", &id)
+    }
+
+    /// Checkpoint a running container by the runtime using CRIU.
+    #[cfg(feature = "checkpoint")]
+    fn checkpoint_container(
+        &mut self,
+        params: conmon::CheckpointContainerParams,
+        mut results: conmon::CheckpointContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+        let id = pry_err!(self.reaper().resolve_id(id));
+
+        let span = new_root_span!("checkpoint_container", id.as_str());
+        let _enter = span.enter();
+
+        debug!("Got a checkpoint container request");
+
+        let image_path = PathBuf::from(pry_path!("imagePath", req.get_image_path()));
+        if image_path.as_os_str().is_empty() {
+            return Promise::err(Error::failed("no image path provided".into()));
+        }
+        let work_path = match pry_path!("workPath", req.get_work_path()) {
+            "" => image_path.clone(),
+            path => path.into(),
+        };
+
+        let child = pry_err!(self.reaper().get(&id));
+        if pry_err!(child.reaped()) {
+            return Promise::err(Error::failed(format!("container {} is not running", id)));
+        }
+
+        let tenant_dir = pry_err!(self.tenant_dir());
+        let runtime_log = pry_err!(ContainerIO::temp_file_name(
+            Some(
+                tenant_dir
+                    .as_deref()
+                    .unwrap_or_else(|| self.config().runtime_dir().as_path())
+            ),
+            &id,
+            "runtime-",
+            ".log"
+        ));
+        let args = self.generate_checkpoint_args(
+            &id,
+            &image_path,
+            &work_path,
+            req.get_leave_running(),
+            req.get_tcp_established(),
+            &runtime_log,
+        );
+        let runtime = self.runtime_config().runtime();
+        let child_reaper = self.reaper().clone();
+
+        // Unlike `create_child`, no pidfile or container IO is involved. The runtime gets
+        // killed and reaped if the request is dropped, like on the request timeout.
+        Promise::from_future(
+            async move {
+                if let Err(e) = child_reaper.run_runtime(&runtime, &args).await {
+                    let e = runtime_log::attach_errors(e, &runtime_log).await;
+                    return Err(rpc_error::failed(
+                        criu::attach_excerpt(e, &work_path.join(criu::DUMP_LOG)).await,
+                    ));
+                }
+
+                let excerpt = criu::log_excerpt(&work_path.join(criu::DUMP_LOG)).await;
+                let mut criu_log = results
+                    .get()
+                    .init_response()
+                    .init_criu_log(excerpt.len() as u32);
+                for (i, line) in excerpt.iter().enumerate() {
+                    criu_log.set(i as u32, line);
+                }
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Restore a container from a checkpoint by the runtime and register the restored process
+    /// like a created container.
+    #[cfg(feature = "checkpoint")]
+    fn restore_container(
+        &mut self,
+        params: conmon::RestoreContainerParams,
+        mut results: conmon::RestoreContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let container = pry!(req.get_container());
+        let id = pry_text!(self, "id", container.get_id());
+
+        let span = new_root_span!("restore_container", id);
+        let _enter = span.enter();
+
+        debug!("Got a restore container request");
+
+        let image_path = PathBuf::from(pry_path!("imagePath", req.get_image_path()));
+        if image_path.as_os_str().is_empty() {
+            return Promise::err(Error::failed("no image path provided".into()));
+        }
+        let work_path = match pry_path!("workPath", req.get_work_path()) {
+            "" => None,
+            path => Some(path.into()),
+        };
+        let restore = Restore::new(image_path, work_path, req.get_tcp_established());
+
+        // The restored container replaces the exited one of the checkpoint, including its name.
+        // The checkpoint gets registered again if the restore fails.
+        let checkpoint_id = pry_err!(self.reaper().resolve_id(id));
+        let checkpointed = if self.reaper().get(&checkpoint_id).is_ok() {
+            Some(pry_err!(self.reaper().take(&checkpoint_id)))
+        } else {
+            None
+        };
+
+        let child_reaper = self.reaper().clone();
+        let created = self.spawn_container(container, Some(restore));
+        Promise::from_future(
+            async move {
+                let created = match created.await {
+                    Ok(created) => created,
+                    Err(e) => {
+                        if let Some((child, names)) = checkpointed {
+                            if let Err(e) = child_reaper.reinsert(&checkpoint_id, child, names) {
+                                error!("Unable to register checkpoint again: {:#}", e);
+                            }
+                        }
+                        return Err(e);
+                    }
+                };
+                if let Some((child, _)) = checkpointed {
+                    if let Err(e) = child_reaper
+                        .exec_sessions()
+                        .remove_container(&checkpoint_id)
+                    {
+                        warn!("Unable to remove exec sessions of checkpoint: {:#}", e);
+                    }
+                    if let Err(e) = child.io().attach().await.close() {
+                        warn!("Unable to close attach endpoints of checkpoint: {:#}", e);
+                    }
+                }
+                set_create_response(results.get().init_response(), &created);
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Execute a command detached inside of a running container. The IO of the returned exec
+    /// session gets served by attach endpoints.
+    fn exec_container(
+        &mut self,
+        params: conmon::ExecContainerParams,
+        mut results: conmon::ExecContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+        let id = pry_err!(self.reaper().resolve_id(id));
+        let timeout = match req.get_timeout_sec() {
+            0 => self.config().timeouts().exec(),
+            secs => Some(Duration::from_secs(secs)),
+        };
+
+        let span = new_root_span!("exec_container", id.as_str());
+        let _enter = span.enter();
+
+        debug!("Got an exec container request with timeout {:?}", timeout);
+
+        let child = pry_err!(self.reaper().get(&id));
+        if pry_err!(child.reaped()) {
+            return Promise::err(Error::failed(format!("container {} is not running", id)));
+        }
+
+        let tenant_dir = pry_err!(self.tenant_dir());
+        let runtime_dir = tenant_dir
+            .as_deref()
+            .unwrap_or_else(|| self.config().runtime_dir().as_path());
+        let pidfile = pry_err!(ContainerIO::temp_file_name(
+            Some(runtime_dir),
+            &id,
+            "exec-",
+            ".pid"
+        ));
+
+        let runtime = self.runtime_config().runtime();
+        let child_reaper = self.reaper().clone();
+        let exec_sessions = child_reaper.exec_sessions().clone();
+        pry_err!(exec_sessions.gc(Duration::from_secs(self.config().exec_session_ttl())));
+
+        let mut container_io = pry_err!(ContainerIO::new(
+            &id,
+            req.get_terminal(),
+            false,
+            ContainerLog::new(),
+            tenant_dir.as_deref()
+        ));
+
+        let command = pry_text_list!(self, "command", req.get_command());
+        let args = pry_err!(self.generate_exec_sync_args(&id, &pidfile, &container_io, &command));
+        let exit_paths: Vec<PathBuf> =
+            pry!(pry_path_list!(self, "exitPaths", req.get_exit_paths())
+                .iter()
+                .map(|r| r.map(PathBuf::from))
+                .collect());
+        let socket_path = pry_path!("attachSocketPath", req.get_attach_socket_path()).to_string();
+        let version = attach_protocol::version(req.get_attach_protocol_version());
+        let invocation = self.config().record_runtime_invocations().then(|| {
+            let path = Invocation::record_path(runtime_dir, &id);
+            (Invocation::new("exec", &runtime, &args), path)
+        });
+
+        Promise::from_future(
+            async move {
+                record_invocation(&invocation).await;
+                if !socket_path.is_empty() {
+                    // Added before the process starts to not miss its first output.
+                    capnp_err!(container_io
+                        .attach()
+                        .clone()
+                        .add(&socket_path, version, None)
+                        .await
+                        .context(Phase::AttachSocket))?;
+                }
+
+                let (grandchild_pid, _) = capnp_err!(
+                    child_reaper
+                        .create_child(&runtime, &args, &mut container_io, &pidfile)
+                        .await
+                )?;
+                let time_to_timeout = timeout.map(|t| Instant::now() + t);
+                let io = SharedContainerIO::new(container_io);
+                let session_id = capnp_err!(exec_sessions.register(
+                    &id,
+                    ExecKind::Streaming,
+                    grandchild_pid,
+                    vec![capnp_err!(pidfile.keep())?],
+                ))?;
+                capnp_err!(exec_sessions.set_io(&session_id, io.clone()))?;
+
+                let child = Child::new(
+                    id,
+                    grandchild_pid,
+                    exit_paths,
+                    vec![],
+                    time_to_timeout,
+                    io,
+                    vec![],
+                    None,
+                    vec![],
+                    vec![],
+                    Overrides::default(),
+                );
+                let mut exit_rx = capnp_err!(child_reaper.watch_grandchild(child, true))?;
+                let finished_id = session_id.clone();
+                task::spawn(
+                    async move {
+                        match exit_rx.recv().await {
+                            Ok(exit_data) => {
+                                if let Err(e) =
+                                    exec_sessions.finish(&finished_id, *exit_data.exit_code())
+                                {
+                                    error!("Unable to finish exec session: {:#}", e);
+                                }
+                            }
+                            Err(e) => {
+                                // The session would never finish and therefore leak
+                                error!("Unable to receive exit data: {:#}", e);
+                                if let Err(e) = exec_sessions.remove(&finished_id) {
+                                    error!("Unable to remove exec session: {:#}", e);
+                                }
+                            }
+                        }
+                    }
+                    .instrument(debug_span!("exec_session", pid = grandchild_pid)),
+                );
+
+                let mut response = results.get().init_response();
+                response.set_session_id(&session_id);
+                response.set_pid(grandchild_pid);
+                response.set_attach_protocol_version(version.into());
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Wait until a container exited and return its exit data.
+    fn wait_container(
+        &mut self,
+        params: conmon::WaitContainerParams,
+        mut results: conmon::WaitContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+        let id = pry_err!(self.reaper().resolve_id(id));
+
+        let span = new_root_span!("wait_container", id.as_str());
+        let _enter = span.enter();
+
+        debug!("Got a wait container request");
+
+        let child = pry_err!(self.reaper().get(&id));
+
+        Promise::from_future(
+            async move {
+                let exit_data = capnp_err!(child.wait().await)?;
+                let mut response = results.get().init_response();
+                response.set_exit_code(*exit_data.exit_code());
+                response.set_raw_exit_code(*exit_data.raw_exit_code());
+                response.set_signal(exit_data.signal().map_or(0, |s| s as i32));
+                response.set_oom_killed(*exit_data.oomed());
+                response.set_timed_out(*exit_data.timed_out());
+                response.set_exited_at(*exit_data.exited_at());
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Shut the server down in order, optionally after waiting for the containers to exit.
+    fn shutdown(
+        &mut self,
+        params: conmon::ShutdownParams,
+        mut results: conmon::ShutdownResults,
+    ) -> Promise<(), capnp::Error> {
+        debug!("Got a shutdown request");
+        let req = pry!(pry!(params.get()).get_request());
+
+        let stop = if req.get_wait_for_containers() {
+            let timeout = match req.get_timeout_sec() {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            };
+            Stop::Wait(timeout)
+        } else {
+            Stop::Signal(Signal::SIGTERM)
+        };
+        info!(
+            "Shutting down by request, stopping containers by {:?}",
+            stop
+        );
+
+        // The response is queued in the same poll the promise resolves in, so requesting the
+        // shutdown afterwards lets the connection flush it before disconnecting.
+        let shutdown = Server::shutdown(self).clone();
+        Promise::from_future(async move {
+            results.get().init_response();
+            shutdown.request(stop);
+            Ok(())
+        })
+    }
+
+    /// Reload the runtime settings and the log level without restarting the server.
+    fn reload_config(
+        &mut self,
+        params: conmon::ReloadConfigParams,
+        mut results: conmon::ReloadConfigResults,
+    ) -> Promise<(), capnp::Error> {
+        debug!("Got a reload config request");
+        let req = pry!(pry!(params.get()).get_request());
+
+        let path = |value: &str| Some(PathBuf::from(value)).filter(|_| !value.is_empty());
+        let runtime = path(pry_path!("runtime", req.get_runtime()));
+        let root = path(pry_path!("runtimeRoot", req.get_runtime_root()));
+        let level = pry!(req.get_log_level());
+
+        // The log level gets validated by setting it, so it has to be restored if the runtime
+        // settings are invalid.
+        let previous = if level.is_empty() {
+            None
+        } else {
+            Some(pry_err!(self.log_level().set(level)))
+        };
+        if let Err(e) = self.runtime_config().reload(runtime, root) {
+            if let Some(previous) = previous {
+                pry_err!(self.log_level().set(&previous.to_string()));
+            }
+            return Promise::err(rpc_error::failed(e));
+        }
+
+        let runtime = self.runtime_config().runtime();
+        let root = self.runtime_config().root().unwrap_or_default();
+        let level = pry_err!(self.log_level().current());
+        info!(
+            "Reloaded config: runtime {}, runtime root {}, log level {}",
+            runtime.display(),
+            root.display(),
+            level
+        );
+
+        let mut response = results.get().init_response();
+        response.set_runtime(&runtime.display().to_string());
+        response.set_runtime_root(&root.display().to_string());
+        response.set_log_level(&level.to_string());
+        Promise::ok(())
+    }
+
+    /// Report the lifecycle state of a container, which is consistent with its exit paths.
+    fn container_status(
+        &mut self,
+        params: conmon::ContainerStatusParams,
+        mut results: conmon::ContainerStatusResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_text!(self, "id", req.get_id());
+
+        let span = new_root_span!("container_status", id);
+        let _enter = span.enter();
+
+        debug!("Got a container status request");
+
+        let child = match pry_err!(self.reaper().list(&[id])).pop() {
+            Some((_, child)) => child,
+            None => {
+                debug!("Container is not tracked");
+                results
+                    .get()
+                    .init_response()
+                    .set_state(ContainerState::Unknown);
+                return Promise::ok(());
+            }
+        };
+
+        Promise::from_future(
+            async move {
+                let exit_data = capnp_err!(child.exit_data())?;
+                let started_at = match exit_data {
+                    Some(_) => 0,
+                    None => match processes::get(child.pid()).await {
+                        Ok(process) => process.start_time(),
+                        Err(e) => {
+                            debug!("Unable to get the start time: {:#}", e);
+                            0
+                        }
+                    },
+                };
+                let socket_paths = capnp_err!(child.io().attach().await.socket_paths())?;
+
+                let mut response = results.get().init_response();
+                response.set_pid(child.pid());
+                response.set_started_at(started_at);
+                match exit_data {
+                    Some(exit_data) => {
+                        response.set_state(ContainerState::Exited);
+                        response.set_exit_code(*exit_data.exit_code());
+                        response.set_exited_at(*exit_data.exited_at());
+                    }
+                    None => response.set_state(ContainerState::Running),
+                }
+                let mut paths = response.init_attach_socket_paths(socket_paths.len() as u32);
+                for (i, path) in socket_paths.iter().enumerate() {
+                    paths.set(i as u32, &path.display().to_string());
+                }
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Push the container lifecycle events to the provided listener until the returned
+    /// subscription gets dropped.
+    fn stream_events(
+        &mut self,
+        params: conmon::StreamEventsParams,
+        mut results: conmon::StreamEventsResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let since = req.get_since_sequence();
+        let listener = pry!(req.get_listener());
+        debug!("Got a stream events request since sequence {}", since);
+
+        let bus = self.reaper().events().clone();
+        let (events, last_sequence, truncated, rx) = pry_err!(bus.subscribe(since));
+        let token = CancellationToken::new();
+        task::spawn_local(
+            event_stream::stream(bus, events, rx, since, listener, token.clone())
+                .instrument(debug_span!("stream_events")),
+        );
+
+        let mut response = results.get().init_response();
+        response.set_subscription(capnp_rpc::new_client(Subscription::new(token)));
+        response.set_last_sequence(last_sequence);
+        response.set_truncated(truncated);
+        Promise::ok(())
+    }
 }
@@ -0,0 +1,182 @@
+//! Conversion of internal errors into RPC errors.
+//!
+//! The description of an RPC error starts with the whole error chain on a single line, like
+//! it gets printed by `{:#}`. The following lines carry the same information in a structured
+//! form, which debugging tools can render or translate without parsing free text:
+//!
+//! ```text
+//! create child: wait for pidfile: timed out waiting for /run/pidfile
+//! phase: pidfile-wait
+//! cause: create child
+//! cause: wait for pidfile
+//! cause: timed out waiting for /run/pidfile
+//! ```
+//!
+//! Descriptions are bounded in size, because runtime output may end up in error messages.
+
+use capnp::Error;
+use std::{
+    error,
+    fmt::{self, Write},
+    time::Duration,
+};
+use strum::AsRefStr;
+
+/// Maximum size of an RPC error description in bytes.
+const MAX_LEN: usize = 4096;
+
+/// Maximum size of the summary line in bytes.
+const MAX_SUMMARY_LEN: usize = 2048;
+
+/// Maximum size of a single cause in bytes.
+const MAX_CAUSE_LEN: usize = 512;
+
+/// Marker appended to truncated text.
+const TRUNCATED: &str = "...";
+
+#[derive(AsRefStr, Clone, Copy, Debug, Eq, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+/// The phase of a container operation in which an error occurred. Attached as context to
+/// errors, it gets reported as machine readable `phase` of the RPC error.
+pub enum Phase {
+    /// Spawning the runtime process.
+    RuntimeSpawn,
+
+    /// Waiting for the runtime to connect to the console socket.
+    TerminalWait,
+
+    /// Waiting for the runtime to write the pidfile.
+    PidfileWait,
+
+    /// Initializing the log drivers of a container.
+    LogInit,
+
+    /// Waiting for an exec process to exit.
+    ExecWait,
+
+    /// Creating the socket of an attach session.
+    AttachSocket,
+}
+
+impl Phase {
+    /// The outermost phase attached to the error, if any.
+    pub fn of(err: &anyhow::Error) -> Option<Self> {
+        err.downcast_ref::<Self>().copied()
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::RuntimeSpawn => "spawn runtime",
+            Self::TerminalWait => "wait for terminal socket connection",
+            Self::PidfileWait => "wait for pidfile",
+            Self::LogInit => "initialize log drivers",
+            Self::ExecWait => "wait for exec process",
+            Self::AttachSocket => "create attach socket",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Error of an RPC request which did not complete within the request timeout.
+pub struct Timeout {
+    /// Name of the RPC method.
+    pub method: &'static str,
+
+    /// The exceeded request timeout.
+    pub timeout: Duration,
+}
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} request timed out after {:?}",
+            self.method, self.timeout
+        )
+    }
+}
+
+impl error::Error for Timeout {}
+
+/// Convert an error into a failed RPC error, preserving its chain of causes.
+pub fn failed<E: Into<anyhow::Error>>(err: E) -> Error {
+    Error::failed(describe(&err.into()))
+}
+
+fn describe(err: &anyhow::Error) -> String {
+    let mut description = single_line(&format!("{:#}", err), MAX_SUMMARY_LEN);
+    if let Some(phase) = Phase::of(err) {
+        let _ = write!(description, "\nphase: {}", phase.as_ref());
+    }
+
+    let causes: Vec<String> = err
+        .chain()
+        .map(|cause| single_line(&cause.to_string(), MAX_CAUSE_LEN))
+        .collect();
+    for (i, cause) in causes.iter().enumerate() {
+        let line = format!("\ncause: {}", cause);
+        if description.len() + line.len() > MAX_LEN {
+            let _ = write!(description, "\ntruncated: {}", causes.len() - i);
+            break;
+        }
+        description.push_str(&line);
+    }
+    description
+}
+
+/// Escape line breaks and truncate the text to at most `max` bytes on a character boundary.
+fn single_line(text: &str, max: usize) -> String {
+    let mut line = text.trim_end().replace('\n', "\\n");
+    if line.len() > max {
+        let mut end = max - TRUNCATED.len();
+        while !line.is_char_boundary(end) {
+            end -= 1;
+        }
+        line.truncate(end);
+        line.push_str(TRUNCATED);
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::{format_err, Context};
+
+    #[test]
+    fn describe_chain() {
+        let err = Err::<(), _>(format_err!("timed out"))
+            .context(Phase::PidfileWait)
+            .context("create child")
+            .unwrap_err();
+        assert_eq!(
+            describe(&err),
+            "create child: wait for pidfile: timed out\n\
+             phase: pidfile-wait\n\
+             cause: create child\n\
+             cause: wait for pidfile\n\
+             cause: timed out"
+        );
+        assert_eq!(describe(&format_err!("failed")), "failed\ncause: failed");
+    }
+
+    #[test]
+    fn describe_bounded() {
+        let err = format_err!("runtime failed:\n{}", "x".repeat(10_000));
+        let err = (0..100).fold(err, |err, i| err.context(format!("context {}", i)));
+        let description = describe(&err);
+        assert!(description.len() <= MAX_LEN + "\ntruncated: 100".len());
+        assert!(description.lines().next().unwrap().ends_with(TRUNCATED));
+        assert!(description.contains("runtime failed:\\nxxx"));
+        assert!(description.contains("\ntruncated: "));
+    }
+
+    #[test]
+    fn single_line_char_boundary() {
+        assert_eq!(single_line("äää", 6), "äää");
+        assert_eq!(single_line("ääää", 6), "ä...");
+        assert_eq!(single_line("a\nb\n", 10), "a\\nb");
+    }
+}
@@ -0,0 +1,110 @@
+//! Settings of the OCI runtime, which can be reloaded without restarting the server.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{Arc, RwLock},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Settings {
+    runtime: PathBuf,
+    root: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug)]
+/// The shared runtime settings, which get initialized from the configuration.
+pub struct RuntimeConfig {
+    settings: Arc<RwLock<Settings>>,
+}
+
+impl RuntimeConfig {
+    /// Create the runtime settings from the validated configuration.
+    pub fn new(config: &Config) -> Self {
+        Self {
+            settings: Arc::new(RwLock::new(Settings {
+                runtime: config.runtime().clone(),
+                root: config.runtime_root().clone(),
+            })),
+        }
+    }
+
+    /// Binary path of the OCI runtime.
+    pub fn runtime(&self) -> PathBuf {
+        self.read().runtime
+    }
+
+    /// Root directory used by the OCI runtime, if configured.
+    pub fn root(&self) -> Option<PathBuf> {
+        self.read().root
+    }
+
+    /// Replace the provided settings while keeping the others. The runtime binary has to exist
+    /// and the root directory gets created if necessary, as on startup.
+    pub fn reload(&self, runtime: Option<PathBuf>, root: Option<PathBuf>) -> Result<()> {
+        if let Some(runtime) = &runtime {
+            if !runtime.exists() {
+                bail!("runtime path '{}' does not exist", runtime.display())
+            }
+        }
+        if let Some(root) = &root {
+            if root.exists() && !root.is_dir() {
+                bail!("runtime root '{}' is not a directory", root.display())
+            }
+            fs::create_dir_all(root)
+                .with_context(|| format!("create runtime root {}", root.display()))?;
+        }
+
+        let mut settings = self
+            .settings
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(runtime) = runtime {
+            settings.runtime = runtime;
+        }
+        if root.is_some() {
+            settings.root = root;
+        }
+        Ok(())
+    }
+
+    fn read(&self) -> Settings {
+        self.settings
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use tempfile::tempdir;
+
+    #[test]
+    fn reload() -> Result<()> {
+        let config =
+            Config::try_parse_from(["conmonrs", "--runtime=/bin/sh", "--runtime-dir=/tmp"])?;
+        let sut = RuntimeConfig::new(&config);
+        assert_eq!(sut.runtime(), PathBuf::from("/bin/sh"));
+        assert_eq!(sut.root(), None);
+
+        let dir = tempdir()?;
+        let root = dir.path().join("root");
+        sut.reload(None, Some(root.clone()))?;
+        assert!(root.is_dir());
+        assert_eq!(sut.runtime(), PathBuf::from("/bin/sh"));
+        assert_eq!(sut.root(), Some(root.clone()));
+
+        assert!(sut.reload(Some(dir.path().join("missing")), None).is_err());
+        assert!(sut
+            .reload(Some("/bin/true".into()), Some("/bin/sh".into()))
+            .is_err());
+        assert_eq!(sut.runtime(), PathBuf::from("/bin/sh"));
+        assert_eq!(sut.root(), Some(root));
+        Ok(())
+    }
+}
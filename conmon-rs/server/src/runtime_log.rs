@@ -0,0 +1,171 @@
+//! Parsing of the JSON log of OCI runtimes.
+//!
+//! runc and crun write one JSON object per line if invoked with `--log-format=json`, like
+//! `{"level":"error","msg":"container_linux.go:380: starting container process caused: ...","time":"..."}`.
+//! The messages of failed invocations are reported as structured error causes instead of raw
+//! stderr output.
+//!
+//! The JSON output of `runtime state` is parsed by the same means.
+
+use std::{iter::Peekable, path::Path, str::Chars};
+use tokio::fs;
+
+/// Levels of log entries which describe why the runtime failed.
+const ERROR_LEVELS: &[&str] = &["error", "fatal", "panic"];
+
+/// The messages of all error entries of the runtime log `content`. Lines which are no valid
+/// log entries are ignored.
+pub fn errors(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(parse_line)
+        .filter(|(level, _)| ERROR_LEVELS.contains(&level.as_str()))
+        .map(|(_, msg)| msg)
+        .filter(|msg| !msg.is_empty())
+        .collect()
+}
+
+/// Attach the messages of the error entries of the runtime log at `path` as causes to `err`.
+pub async fn attach_errors(mut err: anyhow::Error, path: &Path) -> anyhow::Error {
+    let log = fs::read_to_string(path).await.unwrap_or_default();
+    for msg in errors(&log) {
+        err = err.context(format!("runtime error: {}", msg));
+    }
+    err
+}
+
+/// The status of the container from the output of `runtime state`, like `stopped`.
+pub fn state_status(output: &str) -> Option<String> {
+    // The status precedes the nested annotations, so the first occurrence is the top-level one.
+    let (_, rest) = output.split_once(r#""status""#)?;
+    let mut chars = rest.chars().peekable();
+    skip_whitespace(&mut chars);
+    expect(&mut chars, ':')?;
+    skip_whitespace(&mut chars);
+    parse_string(&mut chars)
+}
+
+/// Parse the level and message of a single log line, which is a flat JSON object.
+fn parse_line(line: &str) -> Option<(String, String)> {
+    let mut chars = line.trim().chars().peekable();
+    expect(&mut chars, '{')?;
+    let (mut level, mut msg) = (None, None);
+    loop {
+        skip_whitespace(&mut chars);
+        if chars.peek() == Some(&'}') {
+            break;
+        }
+        let key = parse_string(&mut chars)?;
+        skip_whitespace(&mut chars);
+        expect(&mut chars, ':')?;
+        skip_whitespace(&mut chars);
+        if chars.peek() == Some(&'"') {
+            let value = parse_string(&mut chars)?;
+            match key.as_str() {
+                "level" => level = Some(value),
+                "msg" => msg = Some(value),
+                _ => {}
+            }
+        } else {
+            // Numbers, booleans and null of additional fields.
+            while !matches!(chars.peek(), Some(',') | Some('}') | None) {
+                chars.next();
+            }
+        }
+        skip_whitespace(&mut chars);
+        match chars.next()? {
+            ',' => continue,
+            '}' => break,
+            _ => return None,
+        }
+    }
+    Some((level?, msg?))
+}
+
+fn expect(chars: &mut Peekable<Chars>, expected: char) -> Option<()> {
+    if chars.next()? == expected {
+        Some(())
+    } else {
+        None
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.peek().map_or(false, |c| c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+/// Parse a JSON string including its quotes.
+fn parse_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    expect(chars, '"')?;
+    let mut res = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(res),
+            '\\' => match chars.next()? {
+                'n' => res.push('\n'),
+                't' => res.push('\t'),
+                'r' => res.push('\r'),
+                'b' => res.push('\u{8}'),
+                'f' => res.push('\u{c}'),
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    // Surrogate pairs are not combined, which only affects characters
+                    // outside of the basic multilingual plane.
+                    let c = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32);
+                    res.push(c.unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+                c => res.push(c),
+            },
+            c => res.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        let log = r#"{"level":"warning","msg":"cgroup v1 is deprecated","time":"2022-09-01T10:00:00Z"}
+{"level":"error","msg":"runc create failed: unable to start container process: exec: \"sh\": executable file not found in $PATH","time":"2022-09-01T10:00:00Z"}
+not json
+{ "msg" : "tab\tand ä", "level" : "fatal", "pid": 42 }
+"#;
+        assert_eq!(
+            errors(log),
+            vec![
+                "runc create failed: unable to start container process: exec: \"sh\": executable file not found in $PATH",
+                "tab\tand ä",
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_state_status() {
+        let output = r#"{
+  "ociVersion": "1.0.2-dev",
+  "id": "ctr",
+  "pid": 0,
+  "status" : "stopped",
+  "bundle": "/bundle",
+  "annotations": {"status": "running"}
+}"#;
+        assert_eq!(state_status(output), Some("stopped".into()));
+        assert_eq!(state_status(r#"{"id":"ctr"}"#), None);
+        assert_eq!(state_status(r#"{"status":0}"#), None);
+    }
+
+    #[test]
+    fn parse_invalid_lines() {
+        assert_eq!(parse_line(""), None);
+        assert_eq!(parse_line(r#"{"level":"error"}"#), None);
+        assert_eq!(parse_line(r#"{"level":"error","msg":"unterminated}"#), None);
+        assert_eq!(
+            parse_line(r#"{"level":"error","msg":"ok"}"#),
+            Some(("error".into(), "ok".into()))
+        );
+    }
+}
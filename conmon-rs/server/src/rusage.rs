@@ -0,0 +1,88 @@
+//! Resource usage of container processes.
+
+use anyhow::{Context, Result};
+use getset::CopyGetters;
+use nix::unistd::{sysconf, SysconfVar};
+use std::fs;
+
+#[derive(Clone, Copy, CopyGetters, Debug, Default, Eq, PartialEq)]
+#[getset(get_copy = "pub")]
+/// Resource usage of a single process.
+pub struct ResourceUsage {
+    /// CPU time spent in user mode in microseconds.
+    user_time_micros: u64,
+
+    /// CPU time spent in kernel mode in microseconds.
+    system_time_micros: u64,
+
+    /// Resident set size in bytes.
+    rss_bytes: u64,
+}
+
+impl ResourceUsage {
+    /// Retrieve the resource usage for the provided PID.
+    pub fn from_pid(pid: u32) -> Result<Self> {
+        let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).context("read proc stat")?;
+        let clock_ticks =
+            sysconf(SysconfVar::CLK_TCK)?.context("clock ticks not available")? as u64;
+        let page_size = sysconf(SysconfVar::PAGE_SIZE)?.context("page size not available")? as u64;
+        Self::parse(&stat, clock_ticks, page_size)
+    }
+
+    fn parse(stat: &str, clock_ticks: u64, page_size: u64) -> Result<Self> {
+        // The command name is enclosed in parentheses and can contain whitespace, so the fields
+        // start after the last closing parenthesis with field number 3 (state).
+        let fields = stat
+            .rsplit_once(')')
+            .context("no command name in stat")?
+            .1
+            .split_whitespace()
+            .collect::<Vec<_>>();
+
+        let field = |nr: usize| -> Result<u64> {
+            fields
+                .get(nr - 3)
+                .context(format!("stat field {} not available", nr))?
+                .parse::<u64>()
+                .context(format!("parse stat field {}", nr))
+        };
+
+        let ticks_to_micros = |ticks: u64| ticks.saturating_mul(1_000_000) / clock_ticks.max(1);
+
+        Ok(Self {
+            user_time_micros: ticks_to_micros(field(14)?),
+            system_time_micros: ticks_to_micros(field(15)?),
+            rss_bytes: field(24)?.saturating_mul(page_size),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STAT: &str = "1234 (my (cmd) x) S 1 1234 1234 0 -1 4194560 100 0 0 0 \
+                        250 50 0 0 20 0 1 0 12345 10000000 512 18446744073709551615";
+
+    #[test]
+    fn parse_success() -> Result<()> {
+        let sut = ResourceUsage::parse(STAT, 100, 4096)?;
+        assert_eq!(sut.user_time_micros(), 2_500_000);
+        assert_eq!(sut.system_time_micros(), 500_000);
+        assert_eq!(sut.rss_bytes(), 512 * 4096);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_failure() {
+        assert!(ResourceUsage::parse("1234 no-parens", 100, 4096).is_err());
+        assert!(ResourceUsage::parse("1234 (cmd) S 1", 100, 4096).is_err());
+    }
+
+    #[test]
+    fn from_pid_self() -> Result<()> {
+        let sut = ResourceUsage::from_pid(std::process::id())?;
+        assert!(sut.rss_bytes() > 0);
+        Ok(())
+    }
+}
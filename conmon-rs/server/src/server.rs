@@ -2,37 +2,64 @@
 
 use crate::{
     child_reaper::ChildReaper,
-    config::{CgroupManager, Config, LogDriver},
+    config::{self, CgroupManager, Command, Config, ConfigCommand, LogDriver},
     container_io::{ContainerIO, ContainerIOType},
+    crash,
+    criu::Restore,
+    dispatcher,
+    exec_sessions::ExecSyncResult,
+    idempotency::IdempotencyCache,
     init::{DefaultInit, Init},
+    latency::Latencies,
+    limits,
+    log_level::{LogLevel, LogLevelFilter},
+    panic_guard::{self, PanicGuard},
+    runtime_config::RuntimeConfig,
+    shutdown::{Shutdown, Stop},
+    soak,
+    tenant::Tenant,
     version::Version,
 };
-use anyhow::{format_err, Context, Result};
+use anyhow::{bail, Context, Result};
 use capnp::text_list::Reader;
 use capnp_rpc::{rpc_twoparty_capnp::Side, twoparty, RpcSystem};
+use clap::Parser;
 use conmon_common::conmon_capnp::conmon;
-use futures::{AsyncReadExt, FutureExt};
-use getset::Getters;
+use futures::{future, AsyncReadExt, TryFutureExt};
+use getset::{CopyGetters, Getters};
 use nix::{
     errno,
-    libc::_exit,
+    fcntl::{fcntl, FcntlArg},
+    libc::{_exit, STDIN_FILENO, STDOUT_FILENO},
     sys::signal::Signal,
-    unistd::{fork, ForkResult},
+    unistd::{dup2, fork, ForkResult},
+};
+use std::{
+    convert::TryFrom,
+    env,
+    ffi::OsString,
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    os::unix::io::AsRawFd,
+    path::{Path, PathBuf},
+    process,
+    sync::Arc,
+    time::Duration,
 };
-use std::{fs::File, io::Write, path::Path, process, str::FromStr, sync::Arc};
 use tokio::{
-    fs,
+    net::{unix::SocketAddr, UnixListener, UnixStream},
     runtime::{Builder, Handle},
     signal::unix::{signal, SignalKind},
-    sync::oneshot,
-    task::{self, LocalSet},
+    task::{self, JoinHandle, LocalSet},
+    time::{self, Instant},
 };
-use tokio_util::compat::TokioAsyncReadCompatExt;
-use tracing::{debug, debug_span, info, Instrument};
-use tracing_subscriber::{filter::LevelFilter, prelude::*};
+use tokio_fd::AsyncFd;
+use tokio_util::compat::{TokioAsyncReadCompatExt, TokioAsyncWriteCompatExt};
+use tracing::{debug, debug_span, error, info, warn, Instrument};
+use tracing_subscriber::{fmt::writer::BoxMakeWriter, prelude::*};
 use twoparty::VatNetwork;
 
-#[derive(Debug, Getters)]
+#[derive(Clone, CopyGetters, Debug, Getters)]
 /// The main server structure.
 pub struct Server {
     /// Server configuration.
@@ -42,36 +69,156 @@ pub struct Server {
     /// Child reaper instance.
     #[getset(get = "pub(crate)")]
     reaper: Arc<ChildReaper>,
+
+    /// Tenant of the connection served by this instance, if tenant isolation is enabled.
+    #[getset(get_copy = "pub(crate)")]
+    tenant: Option<Tenant>,
+
+    /// Runtime adjustable log level.
+    #[getset(get = "pub(crate)")]
+    log_level: LogLevel,
+
+    /// Results of create requests by their idempotency token.
+    #[getset(get = "pub(crate)")]
+    create_tokens: Arc<IdempotencyCache<u32>>,
+
+    /// Results of exec sync requests by their idempotency token.
+    #[getset(get = "pub(crate)")]
+    exec_sync_tokens: Arc<IdempotencyCache<ExecSyncResult>>,
+
+    /// Latency histograms of all RPC methods.
+    #[getset(get = "pub(crate)")]
+    latencies: Arc<Latencies>,
+
+    /// Ordered shutdown of the server.
+    #[getset(get = "pub(crate)")]
+    shutdown: Shutdown,
+
+    /// Runtime settings, which can be reloaded.
+    #[getset(get = "pub(crate)")]
+    runtime_config: RuntimeConfig,
 }
 
 impl Server {
+    /// The maximum time between two evictions while exited containers exist.
+    const MAX_EVICTION_INTERVAL: Duration = Duration::from_secs(60);
+
+    /// The maximum time between two garbage collections while finished exec sessions exist.
+    const MAX_EXEC_SESSION_GC_INTERVAL: Duration = Duration::from_secs(60);
+
+    /// The time between two checks whether an instance started by the dispatcher is idle.
+    const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+    /// The maximum time to flush the pending responses of a connection on shutdown.
+    const DISCONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
     /// Create a new `Server` instance.
     pub fn new() -> Result<Self> {
-        let server = Self {
-            config: Default::default(),
-            reaper: Default::default(),
-        };
+        let (server, log_level_filter) = Self::with_config(Config::default())?;
 
         if server.config().version() {
             Version::new().print();
             process::exit(0);
         }
 
-        server.init_logging().context("set log verbosity")?;
+        if server.config().print_config() {
+            println!("{:#?}", server.config());
+            process::exit(0);
+        }
+
+        if let Some(Command::Config(ConfigCommand::Validate)) = server.config().command() {
+            let env = env::vars_os().map(|(key, _)| key.to_string_lossy().into_owned());
+            let findings = server.config().check(env);
+            println!("Config schema version {}", config::SCHEMA_VERSION);
+            for warning in &findings.warnings {
+                println!("warning: {}", warning);
+            }
+            for error in &findings.errors {
+                println!("error: {}", error);
+            }
+            process::exit(if findings.errors.is_empty() { 0 } else { 1 });
+        }
+
+        server
+            .init_logging(log_level_filter)
+            .context("set log verbosity")?;
+        panic_guard::install_hook();
+        crash::install_signal_handlers().context("install fatal signal handlers")?;
         server.config().validate().context("validate config")?;
 
         Self::init().context("init self")?;
         Ok(server)
     }
 
+    /// Create a new `Server` instance from the provided command line arguments, which is
+    /// intended to run next to other code in the same process, like in integration tests.
+    /// Unlike `new`, the logging, the signal handlers and the OOM score of the process are left
+    /// untouched. The server has to be started with `--skip-fork`.
+    pub fn from_args<I, T>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let config = Config::try_parse_from(args).context("parse arguments")?;
+        if !config.skip_fork() {
+            bail!("in-process servers require --skip-fork")
+        }
+        let (server, _) = Self::with_config(config)?;
+        server.config().validate().context("validate config")?;
+        Ok(server)
+    }
+
+    fn with_config(mut config: Config) -> Result<(Self, LogLevelFilter)> {
+        // Instances started by the dispatcher keep their files apart from the ones of other pods.
+        let pod_dir = match config.command() {
+            Some(Command::ServePod(args)) => {
+                Some(dispatcher::pod_dir(config.runtime_dir(), args.pod_id()))
+            }
+            _ => None,
+        };
+        if let Some(dir) = pod_dir {
+            config.set_runtime_dir(dir);
+        }
+
+        let (log_level, log_level_filter) =
+            LogLevel::new(config.log_level()).context("create log level")?;
+        let server = Self {
+            reaper: Arc::new(ChildReaper::new(
+                config.event_history_size(),
+                config.reaper_strategy(),
+                config.audit_idle_wakeups(),
+                config.timeouts(),
+            )),
+            runtime_config: RuntimeConfig::new(&config),
+            config,
+            tenant: None,
+            log_level,
+            create_tokens: Arc::default(),
+            exec_sync_tokens: Arc::default(),
+            latencies: Arc::default(),
+            shutdown: Shutdown::default(),
+        };
+        Ok((server, log_level_filter))
+    }
+
+    /// Request the shutdown of the started server, which stops the containers by `SIGTERM`.
+    /// `start` returns once the shutdown completed.
+    pub fn request_shutdown(&self) {
+        self.shutdown().request(Stop::default())
+    }
+
     /// Start the `Server` instance and consume it.
     pub fn start(self) -> Result<()> {
+        // The soak and the instances started by the dispatcher run in the foreground.
+        let command = self.config().command().clone();
+        let foreground = matches!(command, Some(Command::Soak(_)) | Some(Command::ServePod(_)));
+
         // We need to fork as early as possible, especially before setting up tokio.
         // If we don't, the child will have a strange thread space and we're at risk of deadlocking.
         // We also have to treat the parent as the child (as described in [1]) to ensure we don't
         // interrupt the child's execution.
         // 1: https://docs.rs/nix/0.23.0/nix/unistd/fn.fork.html#safety
-        if !self.config().skip_fork() {
+        if !self.config().skip_fork() && !foreground {
             match unsafe { fork()? } {
                 ForkResult::Parent { child, .. } => {
                     let child_str = format!("{}", child);
@@ -83,15 +230,30 @@ impl Server {
             }
         }
 
-        // now that we've forked, set self to childreaper
-        prctl::set_child_subreaper(true)
-            .map_err(errno::from_i32)
-            .context("set child subreaper")?;
+        // now that we've forked, set self to childreaper. The dispatcher does not monitor any
+        // containers, which would only leave the ones of failed instances as zombies.
+        if command != Some(Command::Dispatch) {
+            prctl::set_child_subreaper(true)
+                .map_err(errno::from_i32)
+                .context("set child subreaper")?;
+        }
 
         let rt = Builder::new_multi_thread().enable_all().build()?;
-        rt.block_on(self.spawn_tasks())?;
+        let res = match command {
+            Some(Command::Soak(args)) => {
+                rt.block_on(soak::run(self.reaper(), &args, self.config().runtime_dir()))
+            }
+            Some(Command::Dispatch) => rt.block_on(dispatcher::run(self.config())),
+            _ => rt.block_on(self.spawn_tasks()),
+        };
         rt.shutdown_background();
-        Ok(())
+
+        // Containers which are still running are not monitored any more
+        let written = crash::write_exit_records();
+        if written > 0 {
+            warn!("Wrote {} exit records of unmonitored containers", written);
+        }
+        res
     }
 
     fn init() -> Result<()> {
@@ -102,14 +264,19 @@ impl Server {
         init.set_oom_score("-1000")
     }
 
-    fn init_logging(&self) -> Result<()> {
-        let level =
-            LevelFilter::from_str(self.config().log_level()).context("convert log level filter")?;
+    fn init_logging(&self, level: LogLevelFilter) -> Result<()> {
         let registry = tracing_subscriber::registry();
 
         match self.config().log_driver() {
             LogDriver::Stdout => {
+                let writer = if self.config().serve_stdio() {
+                    // stdout is reserved for the RPC connection
+                    BoxMakeWriter::new(io::stderr)
+                } else {
+                    BoxMakeWriter::new(io::stdout)
+                };
                 let layer = tracing_subscriber::fmt::layer()
+                    .with_writer(writer)
                     .with_target(true)
                     .with_line_number(true)
                     .with_filter(level);
@@ -119,6 +286,9 @@ impl Server {
                     .context("init stdout fmt layer")?;
                 info!("Using stdout logger");
             }
+            #[cfg(not(feature = "journald"))]
+            LogDriver::Systemd => anyhow::bail!("built without journald support"),
+            #[cfg(feature = "journald")]
             LogDriver::Systemd => {
                 let layer = tracing_journald::layer()
                     .context("unable to connect to journald")?
@@ -130,125 +300,465 @@ impl Server {
                 info!("Using systemd/journald logger");
             }
         }
-        info!("Set log level to: {}", self.config().log_level());
+        info!("Set log level to: {}", self.log_level().current()?);
         Ok(())
     }
 
-    /// Spwans all required tokio tasks.
+    /// Spwans all required tokio tasks and shuts down in order once requested.
     async fn spawn_tasks(self) -> Result<()> {
-        let (shutdown_tx, shutdown_rx) = oneshot::channel();
-        let socket = self.config().socket();
+        let socket = if self.config().serve_stdio() || self.dispatched() {
+            None
+        } else {
+            Some(self.config().socket())
+        };
         let reaper = self.reaper.clone();
+        let shutdown = self.shutdown.clone();
         task::spawn(
-            Self::start_signal_handler(reaper, socket, shutdown_tx)
+            Self::start_signal_handler(reaper.clone(), self.log_level.clone(), shutdown.clone())
                 .instrument(debug_span!("signal_handler")),
         );
 
-        task::spawn_blocking(move || {
+        task::spawn(
+            Self::start_exec_session_gc(
+                reaper.clone(),
+                Duration::from_secs(self.config().exec_session_ttl()),
+            )
+            .instrument(debug_span!("exec_session_gc")),
+        );
+
+        let ttl = self.config().exited_container_ttl();
+        if ttl > 0 {
+            task::spawn(
+                Self::start_eviction(self.clone(), Duration::from_secs(ttl))
+                    .instrument(debug_span!("eviction")),
+            );
+        }
+
+        // Dropping the connections of the backend stops the listeners, which is the first step of
+        // the shutdown. A failing backend requests the shutdown by its guard.
+        let res = task::spawn_blocking(move || {
             Handle::current().block_on(
                 async {
-                    LocalSet::new()
-                        .run_until(self.start_backend(shutdown_rx))
-                        .await
+                    let _guard = self.shutdown().guard();
+                    LocalSet::new().run_until(self.start_backend()).await
                 }
                 .instrument(debug_span!("backend")),
             )
         })
-        .await?
+        .await;
+        debug!("Stopped listeners");
+
+        shutdown
+            .run(&reaper, socket.as_deref())
+            .instrument(debug_span!("shutdown"))
+            .await;
+        ContainerIO::remove_temp_dirs();
+        res?
     }
 
-    async fn start_signal_handler<T: AsRef<Path>>(
+    async fn start_signal_handler(
         reaper: Arc<ChildReaper>,
-        socket: T,
-        shutdown_tx: oneshot::Sender<()>,
+        log_level: LogLevel,
+        shutdown: Shutdown,
     ) -> Result<()> {
         let mut sigterm = signal(SignalKind::terminate())?;
+        crash::set_orderly_sigterm();
         let mut sigint = signal(SignalKind::interrupt())?;
-        let handled_sig: Signal;
-
-        tokio::select! {
-            _ = sigterm.recv() => {
-                info!("Received SIGTERM");
-                handled_sig = Signal::SIGTERM;
-            }
-            _ = sigint.recv() => {
-                info!("Received SIGINT");
-                handled_sig = Signal::SIGINT;
-            }
+        let mut sigusr2 = signal(SignalKind::user_defined2())?;
+
+        let handled_sig = loop {
+            tokio::select! {
+                _ = shutdown.requested() => return Ok(()),
+                _ = sigterm.recv() => {
+                    info!("Received SIGTERM");
+                    break Signal::SIGTERM;
+                }
+                _ = sigint.recv() => {
+                    info!("Received SIGINT");
+                    break Signal::SIGINT;
+                }
+                _ = sigusr2.recv() => {
+                    reaper.idle_audit().record("signal");
+                    match log_level.cycle() {
+                        Ok(level) => info!("Received SIGUSR2, set log level to: {}", level),
+                        Err(e) => error!("Unable to cycle log level: {:#}", e),
+                    }
+                }
+            };
         };
+        reaper.idle_audit().record("signal");
 
-        debug!("Starting grandchildren cleanup task");
-        reaper
-            .kill_grandchildren(handled_sig)
-            .context("unable to kill grandchildren")?;
+        debug!("Requesting shutdown");
+        shutdown.request(Stop::Signal(handled_sig));
+        Ok(())
+    }
 
-        debug!("Sending shutdown message");
-        shutdown_tx
-            .send(())
-            .map_err(|_| format_err!("unable to send shutdown message"))?;
+    /// Remove all exec sessions which finished more than `ttl` ago, so that they do not pile up
+    /// if no further exec requests arrive. The timer is only armed while finished sessions
+    /// exist, which keeps idle servers asleep.
+    async fn start_exec_session_gc(reaper: Arc<ChildReaper>, ttl: Duration) {
+        loop {
+            let next = reaper.exec_sessions().next_expiry(ttl).unwrap_or_else(|e| {
+                error!("Unable to get next exec session expiry: {:#}", e);
+                None
+            });
+            match next {
+                // The boot time clock may advance further than the sleep, so it gets capped.
+                Some(next) => time::sleep(next.min(Self::MAX_EXEC_SESSION_GC_INTERVAL)).await,
+                None => {
+                    reaper.exec_sessions().wait_finished().await;
+                    continue;
+                }
+            }
+            reaper.idle_audit().record("exec_session_gc");
+            match reaper.exec_sessions().gc(ttl) {
+                Ok(0) => {}
+                Ok(removed) => debug!("Garbage collected {} exec sessions", removed),
+                Err(e) => error!("Unable to garbage collect exec sessions: {:#}", e),
+            }
+        }
+    }
 
-        debug!("Removing socket file {}", socket.as_ref().display());
-        fs::remove_file(socket)
-            .await
-            .context("remove existing socket file")
+    /// Evict all containers which exited more than `ttl` ago and verify that the runtime
+    /// deleted them. The timer is only armed while exited containers exist, which keeps idle
+    /// servers asleep.
+    async fn start_eviction(server: Server, ttl: Duration) {
+        let reaper = server.reaper();
+        loop {
+            let next = reaper.next_eviction(ttl).unwrap_or_else(|e| {
+                error!("Unable to get next container eviction: {:#}", e);
+                None
+            });
+            match next {
+                // The boot time clock may advance further than the sleep, so it gets capped.
+                Some(next) => time::sleep(next.min(Self::MAX_EVICTION_INTERVAL)).await,
+                None => {
+                    reaper.wait_exited().await;
+                    continue;
+                }
+            }
+            reaper.idle_audit().record("eviction");
+            let evicted = match reaper.evict_exited(ttl) {
+                Ok(evicted) => evicted,
+                Err(e) => {
+                    error!("Unable to evict exited containers: {:#}", e);
+                    continue;
+                }
+            };
+            for (id, child) in evicted {
+                info!("Evicted exited container {}", id);
+                if let Err(e) = child.io().attach().await.close() {
+                    error!("Unable to close attach endpoints of {}: {:#}", id, e);
+                }
+                reaper
+                    .verify_deleted(
+                        &server.runtime_config().runtime(),
+                        &server.generate_global_args(),
+                        &id,
+                        child.pid(),
+                    )
+                    .await;
+            }
+        }
     }
 
-    async fn start_backend(self, mut shutdown_rx: oneshot::Receiver<()>) -> Result<()> {
+    async fn start_backend(self) -> Result<()> {
+        if self.config().serve_stdio() {
+            return self.serve_stdio().await;
+        }
+        if self.dispatched() {
+            return self.serve_dispatched().await;
+        }
+
         let listener = crate::listener::bind_long_path(&self.config().socket())?;
-        let client: conmon::Client = capnp_rpc::new_client(self);
+        let read_only_listener = self
+            .config()
+            .read_only_socket()
+            .as_ref()
+            .map(|socket| crate::listener::bind_long_path(socket))
+            .transpose()
+            .context("bind read-only socket")?;
+        let shared_client: conmon::Client =
+            capnp_rpc::new_client(PanicGuard::new(self.clone(), false));
+        let read_only_client: conmon::Client =
+            capnp_rpc::new_client(PanicGuard::new(self.clone(), true));
+        let mut connections: Vec<JoinHandle<()>> = vec![];
 
         loop {
-            let stream = tokio::select! {
-                _ = &mut shutdown_rx => {
-                    debug!("Received shutdown message");
+            let (stream, read_only) = tokio::select! {
+                _ = self.shutdown().requested() => {
+                    debug!("Received shutdown request");
+                    future::join_all(connections).await;
                     return Ok(())
                 }
                 stream = listener.accept() => {
-                    stream?.0
+                    (stream?.0, false)
+                },
+                stream = Self::accept_optional(read_only_listener.as_ref()) => {
+                    (stream?.0, true)
                 },
             };
-            let (reader, writer) = TokioAsyncReadCompatExt::compat(stream).split();
-            let network = Box::new(VatNetwork::new(
-                reader,
-                writer,
-                Side::Server,
-                Default::default(),
-            ));
-            let rpc_system = RpcSystem::new(network, Some(client.clone().client));
-            task::spawn_local(Box::pin(rpc_system.map(|_| ())));
+            let shared = if read_only {
+                &read_only_client
+            } else {
+                &shared_client
+            };
+            connections.retain(|c| !c.is_finished());
+            connections.extend(self.serve_connection(stream, shared, read_only));
+        }
+    }
+
+    /// Serve the RPC connection of the accepted `stream` by the `shared` client, or by a
+    /// dedicated client of the peer if tenant isolation is enabled. Returns the task serving
+    /// the connection, or `None` if it got dropped.
+    fn serve_connection(
+        &self,
+        stream: UnixStream,
+        shared: &conmon::Client,
+        read_only: bool,
+    ) -> Option<JoinHandle<()>> {
+        self.reaper().idle_audit().record("accept");
+        let client: conmon::Client = if self.config().tenant_isolation() {
+            match Tenant::from_stream(&stream) {
+                Ok(tenant) => {
+                    debug!("Serving connection for tenant {}", tenant.uid());
+                    capnp_rpc::new_client(PanicGuard::new(self.with_tenant(tenant), read_only))
+                }
+                Err(e) => {
+                    error!("Unable to identify tenant, dropping connection: {:#}", e);
+                    return None;
+                }
+            }
+        } else {
+            shared.clone()
+        };
+        let (reader, writer) = TokioAsyncReadCompatExt::compat(stream).split();
+        let network = Box::new(VatNetwork::new(
+            reader,
+            writer,
+            Side::Server,
+            limits::reader_options(self.config().max_message_size()),
+        ));
+        let rpc_system = RpcSystem::new(network, Some(client.client));
+        Some(self.spawn_rpc_system(rpc_system))
+    }
+
+    /// Drive the `rpc_system` until the connection closes. Once the shutdown is requested, the
+    /// connection gets disconnected cleanly, which still flushes the queued responses.
+    fn spawn_rpc_system(&self, rpc_system: RpcSystem<Side>) -> JoinHandle<()> {
+        let shutdown = self.shutdown().clone();
+        let disconnector = rpc_system.get_disconnector();
+        let mut rpc_system = Box::pin(rpc_system);
+        task::spawn_local(async move {
+            let res = tokio::select! {
+                res = &mut rpc_system => res,
+                _ = shutdown.requested() => {
+                    let disconnect = future::try_join(disconnector, rpc_system).map_ok(|_| ());
+                    match time::timeout(Self::DISCONNECT_TIMEOUT, disconnect).await {
+                        Ok(res) => res,
+                        Err(_) => {
+                            warn!("Timed out flushing RPC connection on shutdown");
+                            Ok(())
+                        }
+                    }
+                }
+            };
+            match res {
+                Ok(()) => debug!("RPC connection closed"),
+                Err(e) => error!("RPC connection failure: {}", e),
+            }
+        })
+    }
+
+    /// Whether the server is an instance started by the dispatcher.
+    fn dispatched(&self) -> bool {
+        matches!(self.config().command(), Some(Command::ServePod(_)))
+    }
+
+    /// Serve the connections passed by the dispatcher until shutdown. The instance reports
+    /// itself as idle once it had no containers and connections for the idle timeout of the
+    /// dispatcher. It shuts down if it is idle after the dispatcher closed the control channel,
+    /// which means that the existing containers are still served if the dispatcher stopped.
+    async fn serve_dispatched(self) -> Result<()> {
+        let control = dispatcher::take_control().context("take dispatcher control channel")?;
+        let shared_client: conmon::Client =
+            capnp_rpc::new_client(PanicGuard::new(self.clone(), false));
+        let mut connections: Vec<JoinHandle<()>> = vec![];
+        let mut idle_check = time::interval(Self::IDLE_CHECK_INTERVAL);
+        let mut idle_since = None;
+        let mut reported = false;
+        let mut closed = false;
+
+        loop {
+            let stream = tokio::select! {
+                _ = self.shutdown().requested() => {
+                    debug!("Received shutdown request");
+                    future::join_all(connections).await;
+                    return Ok(())
+                }
+                stream = dispatcher::receive(&control), if !closed => stream?,
+                _ = idle_check.tick() => {
+                    connections.retain(|c| !c.is_finished());
+                    if !connections.is_empty() || !self.reaper().list(&[])?.is_empty() {
+                        idle_since = None;
+                    } else if closed {
+                        info!("Stopping idle instance");
+                        self.shutdown().request(Stop::default());
+                    } else if !reported
+                        && idle_since.get_or_insert_with(Instant::now).elapsed()
+                            >= dispatcher::IDLE_TIMEOUT
+                    {
+                        info!("Reporting idle instance to the dispatcher");
+                        dispatcher::report_idle(&control).await?;
+                        reported = true;
+                    }
+                    continue;
+                }
+            };
+            match stream {
+                Some(stream) => {
+                    idle_since = None;
+                    connections.extend(self.serve_connection(stream, &shared_client, false));
+                }
+                None => {
+                    info!("Dispatcher closed the control channel");
+                    closed = true;
+                }
+            }
+        }
+    }
+
+    /// Accept a connection on the provided listener, or wait forever if there is none.
+    async fn accept_optional(
+        listener: Option<&UnixListener>,
+    ) -> io::Result<(UnixStream, SocketAddr)> {
+        match listener {
+            Some(listener) => listener.accept().await,
+            None => future::pending().await,
+        }
+    }
+
+    /// Serve a single RPC connection over the inherited stdin and stdout until shutdown.
+    async fn serve_stdio(self) -> Result<()> {
+        let (reader, writer) = Self::take_stdio().context("take stdio for RPC")?;
+        let network = Box::new(VatNetwork::new(
+            TokioAsyncReadCompatExt::compat(reader),
+            TokioAsyncWriteCompatExt::compat_write(writer),
+            Side::Server,
+            limits::reader_options(self.config().max_message_size()),
+        ));
+        let shutdown = self.shutdown().clone();
+        let client: conmon::Client = capnp_rpc::new_client(PanicGuard::new(self.clone(), false));
+        let rpc_system = RpcSystem::new(network, Some(client.client));
+        let connection = self.spawn_rpc_system(rpc_system);
+
+        shutdown.requested().await;
+        debug!("Received shutdown request");
+        connection.await.context("join stdio RPC connection")
+    }
+
+    /// Move stdin and stdout to new file descriptors and replace them with /dev/null, so that
+    /// spawned processes cannot interfere with the RPC connection.
+    fn take_stdio() -> Result<(AsyncFd, AsyncFd)> {
+        let reader =
+            fcntl(STDIN_FILENO, FcntlArg::F_DUPFD_CLOEXEC(3)).context("duplicate stdin")?;
+        let writer =
+            fcntl(STDOUT_FILENO, FcntlArg::F_DUPFD_CLOEXEC(3)).context("duplicate stdout")?;
+
+        let null = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/null")
+            .context("open /dev/null")?;
+        dup2(null.as_raw_fd(), STDIN_FILENO).context("replace stdin")?;
+        dup2(null.as_raw_fd(), STDOUT_FILENO).context("replace stdout")?;
+
+        Ok((
+            AsyncFd::try_from(reader).context("use stdin as RPC reader")?,
+            AsyncFd::try_from(writer).context("use stdout as RPC writer")?,
+        ))
+    }
+
+    /// Create a copy of the server which serves the provided tenant.
+    fn with_tenant(&self, tenant: Tenant) -> Self {
+        Self {
+            tenant: Some(tenant),
+            ..self.clone()
+        }
+    }
+
+    /// The log disk quota of containers which do not override it, `None` if unlimited.
+    pub(crate) fn default_log_disk_quota(&self) -> Option<u64> {
+        Some(self.config().log_disk_quota()).filter(|quota| *quota > 0)
+    }
+
+    /// Retrieve the isolated runtime directory of the served tenant, if tenant isolation is
+    /// enabled. Errors if the tenant exceeds its file quota.
+    pub(crate) fn tenant_dir(&self) -> Result<Option<PathBuf>> {
+        match self.tenant() {
+            Some(tenant) => {
+                let dir = tenant
+                    .runtime_dir(self.config().runtime_dir())
+                    .context("get tenant runtime dir")?;
+                tenant
+                    .check_quota(&dir, self.config().tenant_max_files())
+                    .context("check tenant quota")?;
+                Ok(Some(dir))
+            }
+            None => Ok(None),
         }
     }
 
     const SYSTEMD_CGROUP_ARG: &'static str = "--systemd-cgroup";
 
-    /// Generate the OCI runtime CLI arguments from the provided parameters.
+    /// Generate the OCI runtime CLI arguments from the provided parameters. The container gets
+    /// restored from the checkpoint instead of created if `restore` is set. The runtime writes
+    /// its log in JSON format to `runtime_log`.
     pub(crate) fn generate_runtime_args(
         &self,
         id: &str,
         bundle_path: &Path,
         container_io: &ContainerIO,
         pidfile: &Path,
+        runtime_log: &Path,
+        restore: Option<&Restore>,
     ) -> Result<Vec<String>> {
         let mut args = vec![];
 
-        if let Some(rr) = self.config().runtime_root() {
+        if let Some(rr) = self.runtime_config().root() {
             args.push(format!("--root={}", rr.display()));
         }
 
+        args.push(format!("--log={}", runtime_log.display()));
+        args.push("--log-format=json".into());
+
         if self.config().cgroup_manager() == CgroupManager::Systemd {
             args.push(Self::SYSTEMD_CGROUP_ARG.into());
         }
 
         args.extend([
-            "create".to_string(),
+            restore.map_or("create", |_| "restore").to_string(),
             "--bundle".to_string(),
             bundle_path.display().to_string(),
             "--pid-file".to_string(),
             pidfile.display().to_string(),
         ]);
 
+        if let Some(restore) = restore {
+            // Return once the container got restored, like for created containers.
+            args.push("--detach".into());
+            args.push(format!("--image-path={}", restore.image_path().display()));
+            args.push(format!("--work-path={}", restore.work_path().display()));
+            if restore.tcp_established() {
+                args.push("--tcp-established".into());
+            }
+        }
+
         if let ContainerIOType::Terminal(terminal) = container_io.typ() {
-            args.push(format!("--console-socket={}", terminal.path().display()));
+            // Pre-allocated terminals are inherited as stdio instead.
+            if let Some(path) = terminal.path() {
+                args.push(format!("--console-socket={}", path.display()));
+            }
         }
         args.push(id.into());
         debug!("Runtime args {:?}", args.join(" "));
@@ -265,7 +775,7 @@ impl Server {
     ) -> Result<Vec<String>> {
         let mut args = vec![];
 
-        if let Some(rr) = self.config().runtime_root() {
+        if let Some(rr) = self.runtime_config().root() {
             args.push(format!("--root={}", rr.display()));
         }
 
@@ -277,7 +787,11 @@ impl Server {
         args.push("-d".to_string());
 
         if let ContainerIOType::Terminal(terminal) = container_io.typ() {
-            args.push(format!("--console-socket={}", terminal.path().display()));
+            let path = terminal
+                .path()
+                .as_ref()
+                .context("exec requires a console socket")?;
+            args.push(format!("--console-socket={}", path.display()));
             args.push("--tty".to_string());
         }
 
@@ -291,4 +805,85 @@ impl Server {
         debug!("Exec args {:?}", args.join(" "));
         Ok(args)
     }
+
+    /// Generate the global OCI runtime CLI arguments, which precede the command.
+    pub(crate) fn generate_global_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if let Some(rr) = self.runtime_config().root() {
+            args.push(format!("--root={}", rr.display()));
+        }
+
+        if self.config().cgroup_manager() == CgroupManager::Systemd {
+            args.push(Self::SYSTEMD_CGROUP_ARG.into());
+        }
+        args
+    }
+
+    /// Generate the OCI runtime CLI arguments to run a lifecycle `command` like `start`,
+    /// `pause` or `resume` on a created container. The runtime writes its log in JSON format to
+    /// `runtime_log`.
+    pub(crate) fn generate_lifecycle_args(
+        &self,
+        command: &str,
+        id: &str,
+        runtime_log: &Path,
+    ) -> Vec<String> {
+        let mut args = self.generate_global_args();
+        args.push(format!("--log={}", runtime_log.display()));
+        args.push("--log-format=json".into());
+        args.push(command.into());
+        args.push(id.into());
+        debug!("Lifecycle args {:?}", args.join(" "));
+        args
+    }
+
+    /// Generate the OCI runtime CLI arguments to update the resources of a container to the
+    /// ones of the JSON file `resources`. The runtime writes its log in JSON format to
+    /// `runtime_log`.
+    pub(crate) fn generate_update_args(
+        &self,
+        id: &str,
+        resources: &Path,
+        runtime_log: &Path,
+    ) -> Vec<String> {
+        let mut args = self.generate_global_args();
+        args.push(format!("--log={}", runtime_log.display()));
+        args.push("--log-format=json".into());
+        args.push("update".into());
+        args.push(format!("--resources={}", resources.display()));
+        args.push(id.into());
+        debug!("Update args {:?}", args.join(" "));
+        args
+    }
+
+    /// Generate the OCI runtime CLI arguments to checkpoint a container into `image_path`, while
+    /// CRIU writes its logs into `work_path`. The runtime writes its log in JSON format to
+    /// `runtime_log`.
+    #[cfg(feature = "checkpoint")]
+    pub(crate) fn generate_checkpoint_args(
+        &self,
+        id: &str,
+        image_path: &Path,
+        work_path: &Path,
+        leave_running: bool,
+        tcp_established: bool,
+        runtime_log: &Path,
+    ) -> Vec<String> {
+        let mut args = self.generate_global_args();
+        args.push(format!("--log={}", runtime_log.display()));
+        args.push("--log-format=json".into());
+        args.push("checkpoint".into());
+        args.push(format!("--image-path={}", image_path.display()));
+        args.push(format!("--work-path={}", work_path.display()));
+        if leave_running {
+            args.push("--leave-running".into());
+        }
+        if tcp_established {
+            args.push("--tcp-established".into());
+        }
+        args.push(id.into());
+        debug!("Checkpoint args {:?}", args.join(" "));
+        args
+    }
 }
@@ -0,0 +1,198 @@
+//! A multi map split into independently locked shards.
+
+use anyhow::Result;
+use multimap::MultiMap;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    sync::RwLock,
+};
+
+#[derive(Debug)]
+/// A multi map keyed by strings, where every key is assigned to one of multiple shards. Lookups
+/// only lock the shard of their key for reading, which means that they do not contend with
+/// modifications of other keys.
+pub struct ShardedMultiMap<V> {
+    shards: Vec<RwLock<MultiMap<String, V>>>,
+}
+
+impl<V> Default for ShardedMultiMap<V> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SHARDS)
+    }
+}
+
+impl<V> ShardedMultiMap<V> {
+    /// The default amount of shards.
+    pub const DEFAULT_SHARDS: usize = 16;
+
+    /// Create a new map with the provided amount of shards, which is at least one.
+    pub fn new(shards: usize) -> Self {
+        Self {
+            shards: (0..shards.max(1))
+                .map(|_| RwLock::new(MultiMap::new()))
+                .collect(),
+        }
+    }
+
+    /// Returns true if the map contains at least one value for the key.
+    pub fn contains_key(&self, key: &str) -> Result<bool> {
+        Ok(lock_read!(self.shard(key)).contains_key(key))
+    }
+
+    /// Insert a new value for the key.
+    pub fn insert(&self, key: String, value: V) -> Result<()> {
+        lock_write!(self.shard(&key)).insert(key, value);
+        Ok(())
+    }
+
+    /// Remove all values of the key if `check` returns true for them. Returns `None` if the key
+    /// does not exist or did not pass the check.
+    pub fn remove_if<F>(&self, key: &str, check: F) -> Result<Option<Vec<V>>>
+    where
+        F: FnOnce(&[V]) -> Result<bool>,
+    {
+        let mut shard = lock_write!(self.shard(key));
+        let remove = match shard.get_vec(key) {
+            Some(values) => check(values)?,
+            None => false,
+        };
+        Ok(if remove { shard.remove(key) } else { None })
+    }
+
+    /// Retain only the values for which `f` returns true.
+    pub fn retain<F>(&self, mut f: F) -> Result<()>
+    where
+        F: FnMut(&String, &V) -> bool,
+    {
+        for shard in &self.shards {
+            lock_write!(shard).retain(&mut f);
+        }
+        Ok(())
+    }
+
+    /// Returns all keys of the map.
+    pub fn keys(&self) -> Result<Vec<String>> {
+        let mut keys = vec![];
+        for shard in &self.shards {
+            keys.extend(lock_read!(shard).keys().cloned());
+        }
+        Ok(keys)
+    }
+
+    fn shard(&self, key: &str) -> &RwLock<MultiMap<String, V>> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        &self.shards[hasher.finish() as usize % self.shards.len()]
+    }
+}
+
+impl<V: Clone> ShardedMultiMap<V> {
+    /// Returns the first value of the key.
+    pub fn get(&self, key: &str) -> Result<Option<V>> {
+        Ok(lock_read!(self.shard(key)).get(key).cloned())
+    }
+
+    /// Returns a snapshot of the first value of every key. The shards are locked one after
+    /// another, so the snapshot is not consistent across shards.
+    pub fn entries(&self) -> Result<Vec<(String, V)>> {
+        let mut entries = vec![];
+        for shard in &self.shards {
+            entries.extend(
+                lock_read!(shard)
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone())),
+            );
+        }
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::{bail, format_err};
+    use std::{sync::Arc, thread, time::Instant};
+
+    #[test]
+    fn multi_values() -> Result<()> {
+        let sut = ShardedMultiMap::new(4);
+        sut.insert("a".into(), 1)?;
+        sut.insert("a".into(), 2)?;
+        sut.insert("b".into(), 3)?;
+
+        assert!(sut.contains_key("a")?);
+        assert!(!sut.contains_key("c")?);
+        assert_eq!(sut.get("a")?, Some(1));
+        assert_eq!(sut.get("c")?, None);
+
+        let mut keys = sut.keys()?;
+        keys.sort();
+        assert_eq!(keys, vec!["a", "b"]);
+
+        let mut entries = sut.entries()?;
+        entries.sort();
+        assert_eq!(entries, vec![("a".into(), 1), ("b".into(), 3)]);
+
+        sut.retain(|_, v| *v != 1)?;
+        assert_eq!(sut.get("a")?, Some(2));
+        Ok(())
+    }
+
+    #[test]
+    fn remove_if() -> Result<()> {
+        let sut = ShardedMultiMap::default();
+        sut.insert("a".into(), 1)?;
+        sut.insert("a".into(), 2)?;
+
+        assert_eq!(sut.remove_if("a", |v| Ok(v.len() > 2))?, None);
+        assert!(sut.remove_if("a", |_| bail!("error")).is_err());
+        assert!(sut.contains_key("a")?);
+
+        assert_eq!(sut.remove_if("a", |_| Ok(true))?, Some(vec![1, 2]));
+        assert!(!sut.contains_key("a")?);
+        assert_eq!(sut.remove_if("a", |_| Ok(true))?, None);
+        Ok(())
+    }
+
+    /// Manual benchmark comparing concurrent lookups and modifications of a single shard with
+    /// the default sharding. It only prints the timings and never fails on them. Run via
+    /// `cargo test --release -- --ignored --nocapture bench_contention`.
+    #[test]
+    #[ignore = "manual benchmark"]
+    fn bench_contention() -> Result<()> {
+        const THREADS: usize = 8;
+        const KEYS: usize = 1000;
+        const ROUNDS: usize = 100;
+
+        for shards in [1, ShardedMultiMap::<usize>::DEFAULT_SHARDS] {
+            let sut = Arc::new(ShardedMultiMap::new(shards));
+            let start = Instant::now();
+            let handles = (0..THREADS)
+                .map(|t| {
+                    let sut = sut.clone();
+                    thread::spawn(move || -> Result<()> {
+                        for round in 0..ROUNDS {
+                            for k in 0..KEYS {
+                                let key = format!("{}-{}", t, k);
+                                if t % 2 == 0 {
+                                    sut.insert(key.clone(), round)?;
+                                    sut.remove_if(&key, |_| Ok(true))?;
+                                } else {
+                                    sut.get(&key)?;
+                                }
+                            }
+                        }
+                        Ok(())
+                    })
+                })
+                .collect::<Vec<_>>();
+            for handle in handles {
+                handle.join().map_err(|_| format_err!("join thread"))??;
+            }
+            println!("{} shard(s): {:?}", shards, start.elapsed());
+            assert!(sut.get("0-0")?.is_none());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+//! Ordered shutdown of the server.
+//!
+//! Once requested, the shutdown runs the following steps one after another, so that no output
+//! of the containers gets lost:
+//!
+//! 1. stop the listeners, which closes all RPC connections and removes the socket,
+//! 2. quiesce the IO by stopping all containers, or waiting for them to exit, and draining their
+//!    output,
+//! 3. flush the loggers and synchronize them to disk,
+//! 4. write the exit records of containers which are still running.
+//!
+//! The last step happens in `Server::start` after the runtime got shut down, because it must
+//! not race with exits reported by still running tasks.
+
+use crate::child_reaper::ChildReaper;
+use futures::future::join_all;
+use nix::sys::signal::Signal;
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{fs, time};
+use tokio_util::sync::{CancellationToken, DropGuard};
+use tracing::{debug, error, info, warn};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// How the containers get stopped on shutdown.
+pub enum Stop {
+    /// Forward the signal to the containers.
+    Signal(Signal),
+
+    /// Wait for the containers to exit, at most for the optional timeout. Remaining containers
+    /// get stopped by `SIGTERM` afterwards.
+    Wait(Option<Duration>),
+}
+
+impl Default for Stop {
+    fn default() -> Self {
+        Self::Signal(Signal::SIGTERM)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+/// Shared handle to request and await the shutdown of the server.
+pub struct Shutdown {
+    token: CancellationToken,
+    stop: Arc<Mutex<Option<Stop>>>,
+}
+
+impl Shutdown {
+    /// Request the shutdown, which stops the containers as specified by `stop`. Only the first
+    /// request is recorded.
+    pub fn request(&self, stop: Stop) {
+        if let Ok(mut current) = self.stop.lock() {
+            current.get_or_insert(stop);
+        }
+        self.token.cancel();
+    }
+
+    /// A guard which requests the shutdown when dropped, so that the server shuts down in
+    /// order even if the task holding the guard fails.
+    pub fn guard(&self) -> DropGuard {
+        self.token.clone().drop_guard()
+    }
+
+    /// Wait until the shutdown got requested.
+    pub async fn requested(&self) {
+        self.token.cancelled().await
+    }
+
+    /// How to stop the containers, by `SIGTERM` if the shutdown got requested by a guard.
+    pub fn stop(&self) -> Stop {
+        self.stop
+            .lock()
+            .ok()
+            .and_then(|stop| *stop)
+            .unwrap_or_default()
+    }
+
+    /// Run the shutdown steps which follow stopping the listeners. The socket gets removed if
+    /// provided. Failing steps are logged, because the remaining ones have to run anyway.
+    pub async fn run(&self, reaper: &ChildReaper, socket: Option<&Path>) {
+        if let Some(socket) = socket {
+            debug!("Removing socket file {}", socket.display());
+            if let Err(e) = fs::remove_file(socket).await {
+                error!("Unable to remove socket file {}: {:#}", socket.display(), e);
+            }
+        }
+
+        let signal = match self.stop() {
+            Stop::Signal(signal) => signal,
+            Stop::Wait(timeout) => {
+                Self::wait_for_containers(reaper, timeout).await;
+                Signal::SIGTERM
+            }
+        };
+        info!("Stopping containers with {}", signal);
+        if let Err(e) = reaper.kill_grandchildren(signal) {
+            // The remaining containers get their exit records on exit
+            error!("Unable to kill grandchildren: {:#}", e);
+        }
+
+        debug!("Flushing container logs");
+        reaper.sync_logs().await;
+    }
+
+    /// Wait for all containers to exit, at most for the provided timeout.
+    async fn wait_for_containers(reaper: &ChildReaper, timeout: Option<Duration>) {
+        let children = match reaper.list(&[]) {
+            Ok(children) => children,
+            Err(e) => {
+                error!("Unable to list containers: {:#}", e);
+                return;
+            }
+        };
+        info!("Waiting for {} containers to exit", children.len());
+        let exits = join_all(children.iter().map(|(_, child)| child.wait()));
+        match timeout {
+            Some(timeout) => {
+                if time::timeout(timeout, exits).await.is_err() {
+                    warn!("Containers did not exit within {:?}", timeout);
+                }
+            }
+            None => {
+                exits.await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn request_once() {
+        let sut = Shutdown::default();
+        assert!(!sut.token.is_cancelled());
+        assert_eq!(sut.stop(), Stop::Signal(Signal::SIGTERM));
+
+        sut.request(Stop::Signal(Signal::SIGINT));
+        sut.request(Stop::Wait(None));
+        sut.requested().await;
+        assert_eq!(sut.stop(), Stop::Signal(Signal::SIGINT));
+    }
+
+    #[tokio::test]
+    async fn request_by_guard() {
+        let sut = Shutdown::default();
+        let guard = sut.guard();
+        assert!(!sut.token.is_cancelled());
+        drop(guard);
+        sut.requested().await;
+        assert_eq!(sut.stop(), Stop::Signal(Signal::SIGTERM));
+    }
+
+    #[tokio::test]
+    async fn run_removes_socket() -> Result<()> {
+        let dir = tempdir()?;
+        let socket = dir.path().join("conmon.sock");
+        std::fs::write(&socket, "")?;
+
+        let sut = Shutdown::default();
+        sut.request(Stop::Wait(Some(Duration::from_secs(1))));
+        sut.run(&ChildReaper::default(), Some(&socket)).await;
+        assert!(!socket.exists());
+        Ok(())
+    }
+}
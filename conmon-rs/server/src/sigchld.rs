@@ -0,0 +1,215 @@
+//! SIGCHLD driven waiting for child process exits.
+
+use crate::idle_audit::IdleAudit;
+use anyhow::{Context, Result};
+use libc::pid_t;
+use nix::{
+    errno::Errno,
+    sys::wait::{waitpid, WaitPidFlag, WaitStatus},
+    unistd::Pid,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    signal::unix::{signal, SignalKind},
+    sync::oneshot,
+    task,
+};
+use tracing::{debug, debug_span, error, Instrument};
+
+/// The exit code reported if waiting for a process failed.
+pub const FAILED_EXIT_CODE: i32 = -3;
+
+/// Set once a process got reaped, after which its PID may be reused. The process only gets
+/// reaped while holding the lock, so signals sent while holding it cannot hit another process.
+pub type Reaped = Arc<Mutex<bool>>;
+
+/// The processes to be collected by their PID.
+type Pending = Arc<Mutex<HashMap<u32, (oneshot::Sender<i32>, Reaped)>>>;
+
+#[derive(Debug, Default)]
+/// Waits for the exit of registered processes. A single task gets woken up on SIGCHLD and only
+/// collects the registered processes, which means that no thread or timer is involved while no
+/// process exits.
+pub struct SigchldWaiter {
+    pending: Pending,
+    running: AtomicBool,
+    stopped: Arc<AtomicBool>,
+    last_wakeup: Arc<AtomicU64>,
+    idle_audit: Arc<IdleAudit>,
+}
+
+/// Marks the SIGCHLD task as stopped when dropped, which happens on return and on panic.
+struct StopGuard(Arc<AtomicBool>);
+
+impl Drop for StopGuard {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+impl SigchldWaiter {
+    /// Create a new waiter which records its wakeups in the provided idle audit.
+    pub fn new(idle_audit: Arc<IdleAudit>) -> Self {
+        Self {
+            idle_audit,
+            ..Default::default()
+        }
+    }
+
+    /// Wait for the process with the provided PID to exit and return its exit code. The
+    /// `reaped` marker gets set once the process got collected.
+    pub async fn wait(&self, pid: u32, reaped: Reaped) -> Result<i32> {
+        self.start().context("start SIGCHLD handler")?;
+
+        let (tx, rx) = oneshot::channel();
+        lock!(self.pending).insert(pid, (tx, reaped));
+
+        // The process may have exited before it got registered.
+        Self::reap(&self.pending)?;
+
+        rx.await.context("receive exit code")
+    }
+
+    fn start(&self) -> Result<()> {
+        if self.running.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        let mut sigchld = match signal(SignalKind::child()) {
+            Ok(s) => s,
+            Err(e) => {
+                self.running.store(false, Ordering::SeqCst);
+                return Err(e.into());
+            }
+        };
+
+        let pending = self.pending.clone();
+        let idle_audit = self.idle_audit.clone();
+        let stop_guard = StopGuard(self.stopped.clone());
+        let last_wakeup = self.last_wakeup.clone();
+        task::spawn(
+            async move {
+                let _stop_guard = stop_guard;
+                while sigchld.recv().await.is_some() {
+                    idle_audit.record("sigchld");
+                    last_wakeup.store(
+                        SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .map(|d| d.as_nanos() as u64)
+                            .unwrap_or_default(),
+                        Ordering::Relaxed,
+                    );
+                    if let Err(e) = Self::reap(&pending) {
+                        error!("Unable to reap processes: {:#}", e);
+                    }
+                }
+            }
+            .instrument(debug_span!("sigchld")),
+        );
+        Ok(())
+    }
+
+    /// Returns false if the SIGCHLD task stopped, which means that exits are not detected any
+    /// more. A waiter which did not start yet is considered alive.
+    pub fn is_alive(&self) -> bool {
+        !self.stopped.load(Ordering::SeqCst)
+    }
+
+    /// Time of the last SIGCHLD wakeup in nanoseconds since the UNIX epoch, 0 if none happened.
+    pub fn last_wakeup(&self) -> u64 {
+        self.last_wakeup.load(Ordering::Relaxed)
+    }
+
+    /// Collect all registered processes which already exited. Signals are coalesced, so every
+    /// registered process has to be checked on each wakeup.
+    fn reap(pending: &Pending) -> Result<()> {
+        let mut pending = lock!(pending);
+        let mut exited = vec![];
+        for (pid, (_, reaped)) in pending.iter() {
+            let mut reaped = lock!(reaped);
+            if let Some(exit_code) = Self::try_wait(*pid) {
+                *reaped = true;
+                exited.push((*pid, exit_code));
+            }
+        }
+
+        for (pid, exit_code) in exited {
+            if let Some((tx, _)) = pending.remove(&pid) {
+                if tx.send(exit_code).is_err() {
+                    debug!(pid, "Exit code receiver dropped");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn try_wait(pid: u32) -> Option<i32> {
+        loop {
+            match waitpid(Pid::from_raw(pid as pid_t), Some(WaitPidFlag::WNOHANG)) {
+                Ok(WaitStatus::Exited(_, exit_code)) => {
+                    debug!(pid, "Exited {}", exit_code);
+                    return Some(exit_code);
+                }
+                Ok(WaitStatus::Signaled(_, sig, _)) => {
+                    debug!(pid, "Signaled");
+                    return Some((sig as i32) + 128);
+                }
+                Ok(WaitStatus::StillAlive) => return None,
+                Ok(_) => continue,
+                Err(Errno::EINTR) => continue,
+                Err(err) => {
+                    error!(pid, "Unable to waitpid: {:#}", err);
+                    return Some(FAILED_EXIT_CODE);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    #[tokio::test]
+    async fn wait_success() -> Result<()> {
+        let sut = SigchldWaiter::default();
+        let child = Command::new("sh").args(["-c", "exit 3"]).spawn()?;
+        let reaped = Reaped::default();
+        assert_eq!(sut.wait(child.id(), reaped.clone()).await?, 3);
+        assert!(*lock!(reaped));
+        assert!(sut.is_alive());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_multiple() -> Result<()> {
+        let sut = SigchldWaiter::default();
+        let first = Command::new("sleep").arg("0.2").spawn()?;
+        let second = Command::new("true").spawn()?;
+        let (first, second) = tokio::join!(
+            sut.wait(first.id(), Reaped::default()),
+            sut.wait(second.id(), Reaped::default())
+        );
+        assert_eq!(first?, 0);
+        assert_eq!(second?, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_no_child() -> Result<()> {
+        let sut = SigchldWaiter::default();
+        assert_eq!(
+            sut.wait(u32::MAX >> 2, Reaped::default()).await?,
+            FAILED_EXIT_CODE
+        );
+        Ok(())
+    }
+}
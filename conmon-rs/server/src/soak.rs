@@ -0,0 +1,334 @@
+//! Soak mode for the release qualification of the long-lived server.
+//!
+//! The soak runs the lifecycle of a container in a loop: create, log, exec and exit, followed by
+//! its removal. Processes get spawned by a mock runtime, a shell script which starts the command
+//! in the background and writes its PID into the pidfile, like an OCI runtime does for the
+//! container process. The usage of file descriptors, resident memory, threads and tracked
+//! processes after the warm-up is the baseline, which must stay flat for the rest of the soak.
+
+use crate::{
+    child::Child,
+    child_reaper::ChildReaper,
+    config::SoakArgs,
+    container_io::{ContainerIO, SharedContainerIO},
+    container_log::ContainerLog,
+    fd_inventory,
+    overrides::Overrides,
+};
+use anyhow::{bail, Context, Result};
+use nix::sys::signal::Signal;
+use std::{path::Path, time::Duration};
+use tokio::{
+    fs,
+    time::{self, Instant},
+};
+use tracing::info;
+
+/// Mock runtime, which gets the pidfile followed by the command to run.
+const MOCK_RUNTIME: &str = r#"pidfile=$1; shift; "$@" & echo $! > "$pidfile""#;
+
+/// Amount of lines logged by every container.
+const LOG_LINES: usize = 100;
+
+/// The container logs `LOG_LINES` lines and keeps running until it gets stopped.
+const CONTAINER: &str =
+    r#"i=0; while [ $i -lt 100 ]; do echo "soak line $i"; i=$((i + 1)); done; exec sleep 600"#;
+
+/// Output of the exec process.
+const EXEC_OUTPUT: &[u8] = b"soak exec\n";
+
+/// Additional file descriptors tolerated above the baseline.
+const FD_TOLERANCE: usize = 4;
+
+/// Additional resident memory in KiB tolerated above the baseline, because of allocator
+/// fragmentation.
+const RSS_TOLERANCE_KIB: u64 = 16 * 1024;
+
+/// Additional threads tolerated above the baseline, because the blocking pool of tokio grows and
+/// shrinks on demand.
+const THREAD_TOLERANCE: u64 = 16;
+
+/// Interval of the progress reports.
+const REPORT_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Resource usage of the server.
+struct Usage {
+    /// Open file descriptors.
+    fds: usize,
+
+    /// Resident memory in KiB.
+    rss_kib: u64,
+
+    /// Threads of the server.
+    threads: u64,
+
+    /// Processes tracked by the reaper.
+    tracked: usize,
+}
+
+impl Usage {
+    /// Sample the current resource usage.
+    async fn sample(reaper: &ChildReaper) -> Result<Self> {
+        let status = fs::read_to_string("/proc/self/status")
+            .await
+            .context("read process status")?;
+        let (rss_kib, threads) = parse_status(&status)?;
+        Ok(Self {
+            fds: fd_inventory::open_fds()?.len(),
+            rss_kib,
+            threads,
+            tracked: reaper.list(&[])?.len(),
+        })
+    }
+
+    /// The maximum of both usages for each resource.
+    fn max(self, other: Self) -> Self {
+        Self {
+            fds: self.fds.max(other.fds),
+            rss_kib: self.rss_kib.max(other.rss_kib),
+            threads: self.threads.max(other.threads),
+            tracked: self.tracked.max(other.tracked),
+        }
+    }
+
+    /// Fail if any resource grew beyond its tolerance above the `baseline`.
+    fn check(&self, baseline: &Self) -> Result<()> {
+        if self.fds > baseline.fds + FD_TOLERANCE {
+            bail!(
+                "file descriptors grew from {} to {}",
+                baseline.fds,
+                self.fds
+            )
+        }
+        if self.rss_kib > baseline.rss_kib + RSS_TOLERANCE_KIB {
+            bail!(
+                "resident memory grew from {} KiB to {} KiB",
+                baseline.rss_kib,
+                self.rss_kib
+            )
+        }
+        if self.threads > baseline.threads + THREAD_TOLERANCE {
+            bail!("threads grew from {} to {}", baseline.threads, self.threads)
+        }
+        if self.tracked > baseline.tracked {
+            bail!(
+                "tracked processes grew from {} to {}",
+                baseline.tracked,
+                self.tracked
+            )
+        }
+        Ok(())
+    }
+}
+
+/// Parse the resident memory in KiB and the amount of threads from the content of
+/// `/proc/<pid>/status`.
+fn parse_status(status: &str) -> Result<(u64, u64)> {
+    let field = |name: &str| -> Result<u64> {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+            .and_then(|value| value.split_whitespace().next())
+            .with_context(|| format!("no field {}", name))?
+            .parse()
+            .with_context(|| format!("parse field {}", name))
+    };
+    Ok((field("VmRSS")?, field("Threads")?))
+}
+
+/// Run the soak in `runtime_dir` until its duration passed or the resource usage grew. The
+/// server has to be the subreaper of the spawned processes.
+pub async fn run(reaper: &ChildReaper, args: &SoakArgs, runtime_dir: &Path) -> Result<()> {
+    let dir = tempfile::Builder::new()
+        .prefix("soak-")
+        .tempdir_in(runtime_dir)
+        .context("create soak directory")?;
+    let deadline = Instant::now() + Duration::from_secs(args.duration());
+    info!(
+        "Soaking for {}s in {}",
+        args.duration(),
+        dir.path().display()
+    );
+
+    let mut baseline = Usage::default();
+    let mut iterations = 0;
+    let mut last_report = Instant::now();
+    while Instant::now() < deadline {
+        lifecycle(reaper, dir.path(), iterations)
+            .await
+            .with_context(|| format!("run iteration {}", iterations))?;
+        iterations += 1;
+
+        let usage = Usage::sample(reaper).await?;
+        if iterations <= args.warmup() {
+            baseline = baseline.max(usage);
+            continue;
+        }
+        usage
+            .check(&baseline)
+            .with_context(|| format!("check usage after {} iterations", iterations))?;
+        if last_report.elapsed() >= REPORT_INTERVAL {
+            info!(
+                "Ran {} iterations, usage {:?}, baseline {:?}",
+                iterations, usage, baseline
+            );
+            last_report = Instant::now();
+        }
+    }
+
+    info!("Soak passed after {} iterations", iterations);
+    Ok(())
+}
+
+/// Run the lifecycle of the container with the number `n` in `dir`.
+async fn lifecycle(reaper: &ChildReaper, dir: &Path, n: u64) -> Result<()> {
+    let id = format!("soak-{}", n);
+    let log_path = dir.join(format!("{}.log", id));
+
+    let logger = ContainerLog::cri(&log_path).await?;
+    let mut io = ContainerIO::new(&id, false, false, logger, Some(dir))?;
+    let pid = spawn(reaper, dir, &mut io, &["sh", "-c", CONTAINER])
+        .await
+        .context("create container")?;
+    let mut exit_rx =
+        reaper.watch_grandchild(child(&id, pid, SharedContainerIO::new(io)), false)?;
+
+    let mut io = ContainerIO::new(&id, false, false, ContainerLog::new(), Some(dir))?;
+    let exec_pid = spawn(reaper, dir, &mut io, &["echo", "soak", "exec"])
+        .await
+        .context("create exec process")?;
+    let io = SharedContainerIO::new(io);
+    let mut exec_exit_rx = reaper.watch_grandchild(child(&id, exec_pid, io.clone()), true)?;
+    let (stdout, _, _) = io.read_all_with_timeout(None).await;
+    exec_exit_rx.recv().await.context("wait for exec process")?;
+    if stdout != EXEC_OUTPUT {
+        bail!(
+            "unexpected exec output {:?}",
+            String::from_utf8_lossy(&stdout)
+        )
+    }
+
+    // The container stops logging by replacing itself with sleep.
+    wait_for_log_lines(&log_path, LOG_LINES).await?;
+    reaper.get(&id)?.signal(Signal::SIGTERM)?;
+    let exit_data = exit_rx.recv().await.context("wait for container")?;
+    if *exit_data.exit_code() != Signal::SIGTERM as i32 + 128 {
+        bail!("unexpected container exit code {}", exit_data.exit_code())
+    }
+
+    reaper
+        .remove(&id)?
+        .remove_artifacts(true)
+        .await
+        .context("remove container")
+}
+
+/// Spawn the command by the mock runtime and return its PID.
+async fn spawn(
+    reaper: &ChildReaper,
+    dir: &Path,
+    io: &mut ContainerIO,
+    command: &[&str],
+) -> Result<u32> {
+    let pidfile = dir.join("pidfile");
+    let mut args = vec![
+        "-c",
+        MOCK_RUNTIME,
+        "mock-runtime",
+        pidfile.to_str().context("pidfile path is not UTF-8")?,
+    ];
+    args.extend_from_slice(command);
+    let (pid, _) = reaper.create_child("sh", args, io, &pidfile).await?;
+    fs::remove_file(&pidfile).await.context("remove pidfile")?;
+    Ok(pid)
+}
+
+fn child(id: &str, pid: u32, io: SharedContainerIO) -> Child {
+    Child::new(
+        id.into(),
+        pid,
+        vec![],
+        vec![],
+        None,
+        io,
+        vec![],
+        None,
+        vec![],
+        vec![],
+        Overrides::default(),
+    )
+}
+
+/// Wait until the CRI log at `path` contains `lines` full lines.
+async fn wait_for_log_lines(path: &Path, lines: usize) -> Result<()> {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        let content = fs::read_to_string(path)
+            .await
+            .with_context(|| format!("read log {}", path.display()))?;
+        let full = content
+            .lines()
+            .filter(|line| line.split(' ').nth(2) == Some("F"))
+            .count();
+        if full >= lines {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            bail!("logged {} of {} lines", full, lines)
+        }
+        time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_status_fields() -> Result<()> {
+        let status = "Name:\tconmonrs\nVmRSS:\t    8192 kB\nThreads:\t4\n";
+        assert_eq!(parse_status(status)?, (8192, 4));
+        assert!(parse_status("Name:\tconmonrs\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_usage() {
+        let baseline = Usage {
+            fds: 10,
+            rss_kib: 1024,
+            threads: 4,
+            tracked: 0,
+        };
+        assert!(baseline.check(&baseline).is_ok());
+        assert!(Usage {
+            fds: 20,
+            ..baseline
+        }
+        .check(&baseline)
+        .is_err());
+        assert!(Usage {
+            rss_kib: 1024 + RSS_TOLERANCE_KIB + 1,
+            ..baseline
+        }
+        .check(&baseline)
+        .is_err());
+        assert!(Usage {
+            tracked: 1,
+            ..baseline
+        }
+        .check(&baseline)
+        .is_err());
+        assert_eq!(
+            baseline.max(Usage {
+                threads: 8,
+                ..Default::default()
+            }),
+            Usage {
+                threads: 8,
+                ..baseline
+            }
+        );
+    }
+}
@@ -4,19 +4,29 @@ use crate::{
     attach::SharedContainerAttach,
     container_io::{ContainerIO, Message, Pipe},
     container_log::SharedContainerLog,
+    io_stats::IOStats,
+    supervisor::Supervisor,
 };
 use anyhow::Result;
-use getset::{Getters, MutGetters};
-use std::os::unix::io::AsRawFd;
+use futures::FutureExt;
+use getset::{Getters, MutGetters, Setters};
+use std::{os::unix::io::AsRawFd, path::PathBuf, sync::Arc};
 use tokio::{
+    io::AsyncRead,
     process::{ChildStderr, ChildStdin, ChildStdout},
     sync::mpsc,
     task,
 };
 use tracing::{debug, debug_span, error, Instrument};
 
-#[derive(Debug, Getters, MutGetters)]
+#[derive(Debug, Getters, MutGetters, Setters)]
 #[getset(get)]
+/// The standard IO streams of a container without terminal.
+///
+/// By default stdout and stderr are read and forwarded to the log drivers by independent tasks,
+/// which means that the order between both pipes depends on the scheduling of those tasks. If
+/// `serialize_output` is set, both pipes are only read concurrently, whereas a single task
+/// forwards the data in its arrival order.
 pub struct Streams {
     #[getset(get = "pub")]
     logger: SharedContainerLog,
@@ -24,6 +34,15 @@ pub struct Streams {
     #[getset(get = "pub")]
     attach: SharedContainerAttach,
 
+    #[getset(get = "pub")]
+    stats: Arc<IOStats>,
+
+    #[getset(get = "pub")]
+    supervisor: Arc<Supervisor>,
+
+    #[getset(get = "pub")]
+    serialize_output: bool,
+
     #[getset(get = "pub")]
     pub message_rx_stdout: mpsc::UnboundedReceiver<Message>,
 
@@ -35,11 +54,24 @@ pub struct Streams {
 
     #[getset(get = "pub")]
     message_tx_stderr: mpsc::UnboundedSender<Message>,
+
+    #[getset(get = "pub", set = "pub")]
+    /// File or FIFO streamed into stdin instead of the attach input.
+    stdin_path: Option<PathBuf>,
 }
 
 impl Streams {
+    /// Maximum amount of read chunks waiting to be forwarded in serialized mode.
+    const CHUNK_BUFFER_SIZE: usize = 64;
+
     /// Create a new Streams instance.
-    pub fn new(logger: SharedContainerLog, attach: SharedContainerAttach) -> Result<Self> {
+    pub fn new(
+        logger: SharedContainerLog,
+        attach: SharedContainerAttach,
+        stats: Arc<IOStats>,
+        supervisor: Arc<Supervisor>,
+        serialize_output: bool,
+    ) -> Result<Self> {
         debug!("Creating new IO streams");
 
         let (message_tx_stdout, message_rx_stdout) = mpsc::unbounded_channel();
@@ -48,10 +80,14 @@ impl Streams {
         Ok(Self {
             logger,
             attach,
+            stats,
+            supervisor,
+            serialize_output,
             message_rx_stdout,
             message_tx_stdout,
             message_rx_stderr,
             message_tx_stderr,
+            stdin_path: None,
         })
     }
 
@@ -62,51 +98,161 @@ impl Streams {
         stderr: Option<ChildStderr>,
     ) {
         debug!("Start reading from IO streams");
-        let logger = self.logger().clone();
-        let attach = self.attach().clone();
-        let message_tx = self.message_tx_stdout().clone();
 
-        if let Some(stdin) = stdin {
-            task::spawn(
-                async move {
-                    if let Err(e) = ContainerIO::read_loop_stdin(stdin.as_raw_fd(), attach).await {
-                        error!("Stdin read loop failure: {:#}", e);
+        match (stdin, self.stdin_path().clone()) {
+            (Some(mut stdin), Some(path)) => {
+                let supervisor = self.supervisor().clone();
+                task::spawn(
+                    async move {
+                        // Dropping stdin afterwards closes it, which signals EOF to the container.
+                        let res = supervisor
+                            .run_once("stdin", async move {
+                                ContainerIO::copy_stdin_file(&path, &mut stdin).await
+                            })
+                            .await;
+                        if let Some(Err(e)) = res {
+                            error!("Stdin file copy failure: {:#}", e);
+                        }
                     }
-                }
-                .instrument(debug_span!("stdin")),
-            );
+                    .instrument(debug_span!("stdin")),
+                );
+            }
+            (Some(stdin), None) => {
+                let attach = self.attach().clone();
+                let supervisor = self.supervisor().clone();
+                task::spawn(
+                    async move {
+                        // The file descriptor gets closed on panic, so the loop cannot be restarted.
+                        let res = supervisor
+                            .run_once(
+                                "stdin",
+                                ContainerIO::read_loop_stdin(stdin.as_raw_fd(), attach),
+                            )
+                            .await;
+                        if let Some(Err(e)) = res {
+                            error!("Stdin read loop failure: {:#}", e);
+                        }
+                    }
+                    .instrument(debug_span!("stdin")),
+                );
+            }
+            (None, _) => {}
+        }
+
+        if *self.serialize_output() {
+            self.handle_serialized(stdout, stderr);
+            return;
         }
 
-        let attach = self.attach().clone();
         if let Some(stdout) = stdout {
-            task::spawn(
-                async move {
-                    if let Err(e) =
-                        ContainerIO::read_loop(stdout, Pipe::StdOut, logger, message_tx, attach)
-                            .await
-                    {
-                        error!("Stdout read loop failure: {:#}", e);
-                    }
-                }
-                .instrument(debug_span!("stdout")),
-            );
+            self.spawn_read_loop(stdout, Pipe::StdOut, self.message_tx_stdout().clone());
+        }
+
+        if let Some(stderr) = stderr {
+            self.spawn_read_loop(stderr, Pipe::StdErr, self.message_tx_stderr().clone());
         }
+    }
 
+    /// Spawn a supervised task which reads from the provided pipe and forwards the data.
+    fn spawn_read_loop<T>(&self, reader: T, pipe: Pipe, message_tx: mpsc::UnboundedSender<Message>)
+    where
+        T: AsyncRead + Unpin + Send + 'static,
+    {
         let logger = self.logger().clone();
         let attach = self.attach().clone();
-        let message_tx = self.message_tx_stderr().clone();
-        if let Some(stderr) = stderr {
-            task::spawn(
-                async move {
-                    if let Err(e) =
-                        ContainerIO::read_loop(stderr, Pipe::StdErr, logger, message_tx, attach)
-                            .await
-                    {
-                        error!("Stderr read loop failure: {:#}", e);
-                    }
+        let stats = self.stats().clone();
+        let supervisor = self.supervisor().clone();
+        let reader_guard = supervisor.track_reader();
+        task::spawn(
+            async move {
+                let res = supervisor
+                    .run(pipe.as_ref(), reader, |reader| {
+                        ContainerIO::read_loop(
+                            reader,
+                            pipe,
+                            logger.clone(),
+                            message_tx.clone(),
+                            attach.clone(),
+                            stats.clone(),
+                        )
+                        .boxed()
+                    })
+                    .await;
+                drop(reader_guard);
+                if let Some(Err(e)) = res {
+                    error!("Read loop failure for {}: {:#}", pipe, e);
                 }
-                .instrument(debug_span!("stderr")),
-            );
+            }
+            .instrument(debug_span!("read_loop", pipe = pipe.as_ref())),
+        );
+    }
+
+    /// Read stdout and stderr into a single channel, which gets consumed by one forwarding task.
+    fn handle_serialized(&self, stdout: Option<ChildStdout>, stderr: Option<ChildStderr>) {
+        let (chunk_tx, chunk_rx) = mpsc::channel(Self::CHUNK_BUFFER_SIZE);
+
+        if let Some(stdout) = stdout {
+            self.spawn_read_loop_serialized(stdout, Pipe::StdOut, chunk_tx.clone());
         }
+
+        if let Some(stderr) = stderr {
+            self.spawn_read_loop_serialized(stderr, Pipe::StdErr, chunk_tx);
+        }
+
+        let logger = self.logger().clone();
+        let message_tx_stdout = self.message_tx_stdout().clone();
+        let message_tx_stderr = self.message_tx_stderr().clone();
+        let attach = self.attach().clone();
+        let stats = self.stats().clone();
+        let supervisor = self.supervisor().clone();
+        // The forward loop finishes after all readers reached EOF.
+        let reader_guard = supervisor.track_reader();
+        task::spawn(
+            async move {
+                let res = supervisor
+                    .run("forward", chunk_rx, |chunk_rx| {
+                        ContainerIO::forward_loop(
+                            chunk_rx,
+                            logger.clone(),
+                            message_tx_stdout.clone(),
+                            message_tx_stderr.clone(),
+                            attach.clone(),
+                            stats.clone(),
+                        )
+                        .boxed()
+                    })
+                    .await;
+                drop(reader_guard);
+                if let Some(Err(e)) = res {
+                    error!("Forward loop failure: {:#}", e);
+                }
+            }
+            .instrument(debug_span!("forward")),
+        );
+    }
+
+    /// Spawn a supervised task which reads from the provided pipe into the chunk channel.
+    fn spawn_read_loop_serialized<T>(
+        &self,
+        reader: T,
+        pipe: Pipe,
+        chunk_tx: mpsc::Sender<(Pipe, Message)>,
+    ) where
+        T: AsyncRead + Unpin + Send + 'static,
+    {
+        let supervisor = self.supervisor().clone();
+        task::spawn(
+            async move {
+                let res = supervisor
+                    .run(pipe.as_ref(), reader, |reader| {
+                        ContainerIO::read_loop_serialized(reader, pipe, chunk_tx.clone()).boxed()
+                    })
+                    .await;
+                if let Some(Err(e)) = res {
+                    error!("Read loop failure for {}: {:#}", pipe, e);
+                }
+            }
+            .instrument(debug_span!("read_loop_serialized", pipe = pipe.as_ref())),
+        );
     }
 }
@@ -0,0 +1,220 @@
+//! Panic isolation of the tasks belonging to a single container.
+
+use futures::{future::BoxFuture, Future, FutureExt};
+use std::{
+    any::Any,
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::{sync::Notify, time};
+use tracing::{error, warn};
+
+#[derive(Debug)]
+/// Catches panics of the tasks of a container, like the IO read loops. A container with at
+/// least one panicked task is considered degraded, because parts of its monitoring may be
+/// missing. The supervisor additionally tracks the tasks reading the container output, which
+/// allows to drain the output after the container exited.
+pub struct Supervisor {
+    /// Identifier of the supervised container.
+    id: String,
+
+    /// Amount of panics of all supervised tasks.
+    panics: AtomicU64,
+
+    /// Amount of running tasks which read the container output.
+    readers: AtomicUsize,
+
+    /// Notified whenever a reader finished.
+    reader_done: Notify,
+}
+
+#[derive(Debug)]
+/// Marks a task reading the container output as running until it gets dropped.
+pub struct ReaderGuard(Arc<Supervisor>);
+
+impl Drop for ReaderGuard {
+    fn drop(&mut self) {
+        self.0.readers.fetch_sub(1, Ordering::SeqCst);
+        self.0.reader_done.notify_waiters();
+    }
+}
+
+impl Supervisor {
+    /// The maximum amount of times a single panicked task gets restarted.
+    pub const MAX_RESTARTS: u64 = 3;
+
+    /// Create a new supervisor for the container with the provided ID.
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.into(),
+            panics: AtomicU64::new(0),
+            readers: AtomicUsize::new(0),
+            reader_done: Notify::new(),
+        }
+    }
+
+    /// Track a task reading the container output until the returned guard gets dropped. The
+    /// guard has to be created before spawning the task, so that it is never missed by a drain.
+    pub fn track_reader(self: &Arc<Self>) -> ReaderGuard {
+        self.readers.fetch_add(1, Ordering::SeqCst);
+        ReaderGuard(self.clone())
+    }
+
+    /// Wait until all tasks reading the container output finished, which means that they
+    /// reached EOF. Returns false if some readers are still running after `max_time`, for
+    /// example because another process inherited the output pipes.
+    pub async fn drain(&self, max_time: Duration) -> bool {
+        let drained = async {
+            loop {
+                let notified = self.reader_done.notified();
+                if self.readers.load(Ordering::SeqCst) == 0 {
+                    return;
+                }
+                notified.await;
+            }
+        };
+        if time::timeout(max_time, drained).await.is_err() {
+            warn!(
+                "Output of container {} not drained within {:?}, {} reader(s) still running",
+                self.id,
+                max_time,
+                self.readers.load(Ordering::SeqCst)
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Run the task created by `task` on the provided state until it completes. The state is
+    /// kept if the task panics, which allows recreating the task up to `MAX_RESTARTS` times.
+    /// Returns `None` if the task never completed.
+    pub async fn run<S, O, F>(&self, name: &str, mut state: S, mut task: F) -> Option<O>
+    where
+        F: for<'a> FnMut(&'a mut S) -> BoxFuture<'a, O>,
+    {
+        for attempt in 0..=Self::MAX_RESTARTS {
+            if attempt > 0 {
+                warn!(
+                    "Restarting task {} of container {} ({}/{})",
+                    name,
+                    self.id,
+                    attempt,
+                    Self::MAX_RESTARTS
+                );
+            }
+            if let Some(output) = self.run_once(name, task(&mut state)).await {
+                return Some(output);
+            }
+        }
+        error!("Giving up on task {} of container {}", name, self.id);
+        None
+    }
+
+    /// Run the provided future and record a panic without restarting it. Returns `None` if the
+    /// future panicked.
+    pub async fn run_once<F>(&self, name: &str, future: F) -> Option<F::Output>
+    where
+        F: Future,
+    {
+        match AssertUnwindSafe(future).catch_unwind().await {
+            Ok(output) => Some(output),
+            Err(e) => {
+                self.panics.fetch_add(1, Ordering::Relaxed);
+                error!(
+                    "Task {} of container {} panicked: {}",
+                    name,
+                    self.id,
+                    Self::panic_message(&*e)
+                );
+                None
+            }
+        }
+    }
+
+    /// Amount of panics of all supervised tasks.
+    pub fn panics(&self) -> u64 {
+        self.panics.load(Ordering::Relaxed)
+    }
+
+    /// Returns true if at least one supervised task panicked.
+    pub fn degraded(&self) -> bool {
+        self.panics() > 0
+    }
+
+    fn panic_message(e: &(dyn Any + Send)) -> &str {
+        if let Some(s) = e.downcast_ref::<&str>() {
+            *s
+        } else if let Some(s) = e.downcast_ref::<String>() {
+            s.as_str()
+        } else {
+            "unknown panic payload"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn restart_on_panic() {
+        let sut = Supervisor::new("id");
+        let res = sut
+            .run("test", 0, |attempts| {
+                async move {
+                    *attempts += 1;
+                    if *attempts < 3 {
+                        panic!("attempt {}", attempts);
+                    }
+                    *attempts
+                }
+                .boxed()
+            })
+            .await;
+        assert_eq!(res, Some(3));
+        assert_eq!(sut.panics(), 2);
+        assert!(sut.degraded());
+    }
+
+    #[tokio::test]
+    async fn give_up() {
+        let sut = Supervisor::new("id");
+        let res: Option<()> = sut
+            .run("test", (), |_| async { panic!("always") }.boxed())
+            .await;
+        assert!(res.is_none());
+        assert_eq!(sut.panics(), Supervisor::MAX_RESTARTS + 1);
+    }
+
+    #[tokio::test]
+    async fn drain() {
+        let sut = Arc::new(Supervisor::new("id"));
+        assert!(sut.drain(Duration::from_millis(10)).await);
+
+        let guard = sut.track_reader();
+        assert!(!sut.drain(Duration::from_millis(10)).await);
+
+        let handle = tokio::spawn(async move {
+            time::sleep(Duration::from_millis(10)).await;
+            drop(guard);
+        });
+        assert!(sut.drain(Duration::from_secs(10)).await);
+        handle.await.expect("join task");
+    }
+
+    #[tokio::test]
+    async fn run_once() {
+        let sut = Supervisor::new("id");
+        assert_eq!(sut.run_once("test", async { 1 }).await, Some(1));
+        assert!(!sut.degraded());
+        assert_eq!(
+            sut.run_once("test", async { panic!("once") }).await,
+            None::<()>
+        );
+        assert_eq!(sut.panics(), 1);
+    }
+}
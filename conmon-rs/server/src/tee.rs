@@ -0,0 +1,134 @@
+//! Copy of the container output provided to the engine in addition to the log drivers.
+
+use anyhow::{bail, Context, Result};
+use getset::Getters;
+use std::{
+    fs::File,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+use tokio::fs::OpenOptions;
+use tracing::{debug, warn};
+
+#[derive(Debug, Getters)]
+/// Writes the CRI formatted container output into an engine provided file or FIFO, which allows
+/// live log streaming without a second reader of the container pipes.
+pub struct Tee {
+    #[getset(get = "pub")]
+    /// Path to the file or FIFO.
+    path: PathBuf,
+
+    /// Open non-blocking file handle of the `path`.
+    file: Option<File>,
+}
+
+impl Tee {
+    /// Create a new tee writing into `path`. The file has to exist already.
+    pub fn new<T: AsRef<Path>>(path: T) -> Self {
+        Self {
+            path: path.as_ref().into(),
+            file: None,
+        }
+    }
+
+    /// Open the file for appending. The file gets opened non-blocking, so that a FIFO without a
+    /// reader does not block the container creation. The output is discarded in that case.
+    pub async fn init(&mut self) -> Result<()> {
+        debug!("Initializing tee in path {}", self.path().display());
+        let file = match OpenOptions::new()
+            .append(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(self.path())
+            .await
+        {
+            Ok(file) => file,
+            Err(e) if e.raw_os_error() == Some(libc::ENXIO) => {
+                warn!(
+                    "Discarding output of tee {} without a reader",
+                    self.path().display()
+                );
+                return Ok(());
+            }
+            Err(e) => return Err(e).context(format!("open tee path '{}'", self.path().display())),
+        };
+        self.file = Some(file.into_std().await);
+        Ok(())
+    }
+
+    /// Write already formatted log lines without blocking. The lines are discarded if the tee is
+    /// not initialized. Fails if the reader of a FIFO does not keep up, because the remaining
+    /// output would be incomplete.
+    pub fn write_lines(&mut self, lines: &[Vec<u8>]) -> Result<()> {
+        if let Some(file) = self.file.as_mut() {
+            for line in lines {
+                match file.write_all(line) {
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                        bail!("tee reader does not keep up")
+                    }
+                    res => res.context("write to tee")?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Close the file, which makes all further writes a no-op.
+    pub fn close(&mut self) {
+        self.file = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nix::{sys::stat::Mode, unistd::mkfifo};
+    use std::{fs, os::unix::fs::OpenOptionsExt};
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn write_lines() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("tee");
+        let mut sut = Tee::new(&path);
+        assert!(sut.init().await.is_err());
+
+        sut.write_lines(&[b"discarded\n".to_vec()])?;
+        fs::write(&path, "existing\n")?;
+        sut.init().await?;
+        sut.write_lines(&[b"first\n".to_vec(), b"second\n".to_vec()])?;
+        assert_eq!(fs::read_to_string(&path)?, "existing\nfirst\nsecond\n");
+
+        sut.close();
+        sut.write_lines(&[b"closed\n".to_vec()])?;
+        assert_eq!(fs::read_to_string(&path)?, "existing\nfirst\nsecond\n");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fifo() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("fifo");
+        mkfifo(&path, Mode::S_IRUSR | Mode::S_IWUSR)?;
+
+        let mut sut = Tee::new(&path);
+        sut.init().await?;
+        sut.write_lines(&[b"discarded\n".to_vec()])?;
+
+        let reader = fs::OpenOptions::new()
+            .read(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(&path)?;
+        sut.init().await?;
+        let line = vec![b'a'; 1024];
+        let mut res = Ok(());
+        for _ in 0..1024 {
+            res = sut.write_lines(&[line.clone()]);
+            if res.is_err() {
+                break;
+            }
+        }
+        assert!(res.is_err());
+        drop(reader);
+        Ok(())
+    }
+}
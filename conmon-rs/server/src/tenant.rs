@@ -0,0 +1,125 @@
+//! Per caller runtime directory isolation.
+
+use anyhow::{bail, Context, Result};
+use getset::CopyGetters;
+use std::{
+    fs,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+};
+use tokio::net::UnixStream;
+use tracing::debug;
+
+#[derive(Clone, Copy, CopyGetters, Debug, Eq, PartialEq)]
+/// A tenant is a single caller of the server, identified by its peer credentials.
+pub struct Tenant {
+    #[getset(get_copy = "pub")]
+    /// User ID of the connected peer.
+    uid: u32,
+}
+
+impl Tenant {
+    /// Name of the directory inside the runtime dir which holds all tenant subdirectories.
+    const TENANTS_DIR: &'static str = "tenants";
+
+    /// Create a new tenant for the peer of the provided stream.
+    pub fn from_stream(stream: &UnixStream) -> Result<Self> {
+        let cred = stream.peer_cred().context("get peer credentials")?;
+        Ok(Self { uid: cred.uid() })
+    }
+
+    /// Returns the runtime directory of the tenant below `base` and creates it if required.
+    pub fn runtime_dir(&self, base: &Path) -> Result<PathBuf> {
+        let dir = base.join(Self::TENANTS_DIR).join(self.uid().to_string());
+        if !dir.exists() {
+            debug!("Creating tenant runtime dir {}", dir.display());
+            fs::create_dir_all(&dir).context("create tenant runtime dir")?;
+            fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))
+                .context("set tenant runtime dir permissions")?;
+        }
+        Ok(dir)
+    }
+
+    /// Verify that the tenant runtime directory holds less than `max_files` files, including
+    /// the ones in the per server subdirectories created by `ContainerIO::temp_file_name`.
+    /// A `max_files` value of `0` means unlimited.
+    pub fn check_quota(&self, dir: &Path, max_files: usize) -> Result<()> {
+        if max_files == 0 {
+            return Ok(());
+        }
+        let count = count_files(dir).context("read tenant runtime dir")?;
+        if count >= max_files {
+            bail!(
+                "tenant {} exceeds file quota: {} of {} files in use",
+                self.uid(),
+                count,
+                max_files
+            )
+        }
+        Ok(())
+    }
+}
+
+/// Count all files below `dir`, where directories are descended into instead of being counted.
+fn count_files(dir: &Path) -> Result<usize> {
+    let mut count = 0;
+    for entry in fs::read_dir(dir).with_context(|| format!("read {}", dir.display()))? {
+        let entry = entry.context("read dir entry")?;
+        if entry.file_type().context("get file type")?.is_dir() {
+            count += count_files(&entry.path())?;
+        } else {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container_io::ContainerIO;
+    use tempfile::tempdir;
+
+    #[test]
+    fn runtime_dir_success() -> Result<()> {
+        let base = tempdir()?;
+        let sut = Tenant { uid: 1000 };
+
+        let dir = sut.runtime_dir(base.path())?;
+        assert!(dir.is_dir());
+        assert!(dir.ends_with("tenants/1000"));
+        assert_eq!(fs::metadata(&dir)?.permissions().mode() & 0o777, 0o700);
+        Ok(())
+    }
+
+    #[test]
+    fn check_quota() -> Result<()> {
+        let base = tempdir()?;
+        let sut = Tenant { uid: 1000 };
+        let dir = sut.runtime_dir(base.path())?;
+
+        sut.check_quota(&dir, 0)?;
+        sut.check_quota(&dir, 1)?;
+
+        fs::write(dir.join("file"), "")?;
+        assert!(sut.check_quota(&dir, 1).is_err());
+        sut.check_quota(&dir, 2)?;
+        Ok(())
+    }
+
+    #[test]
+    fn check_quota_temp_files() -> Result<()> {
+        let base = tempdir()?;
+        let sut = Tenant { uid: 1000 };
+        let dir = sut.runtime_dir(base.path())?;
+
+        for i in 0..2 {
+            let path = ContainerIO::temp_file_name(Some(&dir), &i.to_string(), "", ".pid")?;
+            fs::write(&path, "")?;
+            path.keep()?;
+        }
+        sut.check_quota(&dir, 3)?;
+        assert!(sut.check_quota(&dir, 2).is_err());
+        Ok(())
+    }
+}
@@ -4,25 +4,40 @@ use crate::{
     attach::SharedContainerAttach,
     container_io::{ContainerIO, Message, Pipe},
     container_log::SharedContainerLog,
+    fd_inventory,
+    io_stats::IOStats,
     listener,
+    supervisor::Supervisor,
 };
 use anyhow::{bail, format_err, Context, Result};
+use futures::FutureExt;
 use getset::{Getters, MutGetters, Setters};
 use libc::{self, winsize, TIOCSWINSZ};
-use nix::sys::termios::{self, OutputFlags, SetArg};
-use sendfd::RecvWithFd;
+use nix::{
+    errno::Errno,
+    sys::termios::{self, InputFlags, LocalFlags, OutputFlags, SetArg},
+};
 use std::{
     convert::TryFrom,
+    fs::File,
     io::{Error as IOError, ErrorKind},
-    os::unix::{fs::PermissionsExt, io::RawFd},
-    path::PathBuf,
-    sync::mpsc::Sender as StdSender,
+    os::unix::{
+        fs::PermissionsExt,
+        io::{AsRawFd, FromRawFd, IntoRawFd, RawFd},
+        net::UnixStream as StdUnixStream,
+    },
+    path::{Path, PathBuf},
+    sync::{mpsc::Sender as StdSender, Arc},
+    time::Duration,
 };
 use tokio::{
     fs,
     io::{AsyncWriteExt, Interest},
     net::UnixStream,
-    sync::mpsc::{self, Receiver, Sender, UnboundedReceiver, UnboundedSender},
+    sync::{
+        broadcast::{self, error::RecvError},
+        mpsc::{self, Receiver, Sender, UnboundedReceiver, UnboundedSender},
+    },
     task,
 };
 use tokio_fd::AsyncFd;
@@ -31,7 +46,8 @@ use tracing::{debug, debug_span, error, trace, Instrument};
 #[derive(Debug, Getters, MutGetters, Setters)]
 pub struct Terminal {
     #[getset(get = "pub")]
-    path: PathBuf,
+    /// The console socket path, which is `None` for pre-allocated terminals.
+    path: Option<PathBuf>,
 
     connected_rx: Receiver<RawFd>,
 
@@ -40,29 +56,56 @@ pub struct Terminal {
 
     #[getset(get, set)]
     tty: Option<RawFd>,
+
+    /// The slave of a pre-allocated terminal, which is passed to the runtime as stdio.
+    slave: Option<File>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// Changes of the terminal line discipline, where `None` keeps the current setting.
+pub struct TerminalMode {
+    /// Echo the input back to the terminal.
+    pub echo: Option<bool>,
+
+    /// Pass the input byte by byte without line editing, signal generation and echo, or restore
+    /// the cooked mode including echo if disabled. Applied before `echo`.
+    pub raw: Option<bool>,
+
+    /// Send a break after the modes got changed.
+    pub send_break: bool,
 }
 
 #[derive(Debug, Getters)]
 struct Config {
     #[get]
-    path: PathBuf,
+    connected_tx: Sender<RawFd>,
 
     #[get]
-    ready_tx: StdSender<()>,
+    message_tx: UnboundedSender<Message>,
 
     #[get]
-    connected_tx: Sender<RawFd>,
+    stats: Arc<IOStats>,
 
     #[get]
-    message_tx: UnboundedSender<Message>,
+    supervisor: Arc<Supervisor>,
 }
 
 impl Terminal {
+    /// Maximum time to wait for the master of a pre-allocated terminal.
+    const MASTER_TIMEOUT: Duration = Duration::from_secs(5);
+
     /// Setup a new terminal instance.
-    pub fn new(logger: SharedContainerLog, attach: SharedContainerAttach) -> Result<Self> {
+    pub fn new(
+        logger: SharedContainerLog,
+        attach: SharedContainerAttach,
+        stats: Arc<IOStats>,
+        supervisor: Arc<Supervisor>,
+        id: &str,
+        directory: Option<&Path>,
+    ) -> Result<Self> {
         debug!("Creating new terminal");
-        let path = ContainerIO::temp_file_name(None, "conmon-term-", ".sock")?;
-        let path_clone = path.clone();
+        let path = ContainerIO::temp_file_name(directory, id, "conmon-term-", ".sock")?;
+        let path_clone = path.to_path_buf();
 
         let (ready_tx, ready_rx) = std::sync::mpsc::channel();
         let (connected_tx, connected_rx) = mpsc::channel(1);
@@ -71,11 +114,13 @@ impl Terminal {
         task::spawn(
             async move {
                 if let Err(e) = Self::listen(
+                    path_clone,
+                    ready_tx,
                     Config {
-                        path: path_clone,
-                        ready_tx,
                         connected_tx,
                         message_tx,
+                        stats,
+                        supervisor,
                     },
                     logger,
                     attach,
@@ -90,13 +135,88 @@ impl Terminal {
         ready_rx.recv().context("wait for listener to be ready")?;
 
         Ok(Self {
-            path,
+            path: Some(path.keep().context("keep terminal socket path")?),
+            connected_rx,
+            message_rx,
+            tty: None,
+            slave: None,
+        })
+    }
+
+    /// Setup a terminal pre-allocated by the client, which sends its master to the unix socket
+    /// listening at `socket_path` by using SCM_RIGHTS. The runtime inherits the slave as stdio
+    /// instead of connecting to a console socket, which requires the bundle to disable the
+    /// terminal.
+    pub fn pre_allocated(
+        logger: SharedContainerLog,
+        attach: SharedContainerAttach,
+        stats: Arc<IOStats>,
+        supervisor: Arc<Supervisor>,
+        socket_path: &Path,
+    ) -> Result<Self> {
+        debug!("Receiving pre-allocated terminal");
+        let master = Self::receive_master(socket_path).context("receive terminal master")?;
+        // Close the master again if opening the slave fails.
+        let master_file = unsafe { File::from_raw_fd(master) };
+        let slave = Errno::result(unsafe {
+            libc::ioctl(
+                master,
+                libc::TIOCGPTPEER,
+                libc::O_RDWR | libc::O_NOCTTY | libc::O_CLOEXEC,
+            )
+        })
+        .context("open terminal slave")?;
+        let slave = unsafe { File::from_raw_fd(slave) };
+
+        let (connected_tx, connected_rx) = mpsc::channel(1);
+        let (message_tx, message_rx) = mpsc::unbounded_channel();
+        let resize_rx = attach.subscribe_resize();
+        Self::start(
+            master_file.into_raw_fd(),
+            Config {
+                connected_tx,
+                message_tx,
+                stats,
+                supervisor,
+            },
+            logger,
+            attach,
+            resize_rx,
+        )?;
+
+        Ok(Self {
+            path: None,
             connected_rx,
             message_rx,
             tty: None,
+            slave: Some(slave),
         })
     }
 
+    /// Take the slave of a pre-allocated terminal, which has to be closed after passing it to
+    /// the runtime.
+    pub fn take_slave(&mut self) -> Option<File> {
+        self.slave.take()
+    }
+
+    fn receive_master(socket_path: &Path) -> Result<RawFd> {
+        let stream = StdUnixStream::connect(socket_path)
+            .with_context(|| format!("connect to {}", socket_path.display()))?;
+        stream
+            .set_read_timeout(Some(Self::MASTER_TIMEOUT))
+            .context("set read timeout")?;
+
+        let mut data_buffer = [0; 1];
+        let mut fd_buffer: [RawFd; 1] = [-1];
+        let (_, fd_read) =
+            fd_inventory::recv_with_fd(stream.as_raw_fd(), &mut data_buffer, &mut fd_buffer)
+                .context("receive file descriptor")?;
+        if fd_read == 0 {
+            bail!("got no file descriptor");
+        }
+        Ok(fd_buffer[0])
+    }
+
     /// Waits for the socket client to be connected.
     pub async fn wait_connected(&mut self) -> Result<()> {
         debug!("Waiting for terminal socket connection");
@@ -111,6 +231,50 @@ impl Terminal {
 
     /// Resize the terminal width and height.
     pub fn resize(&self, width: u16, height: u16) -> Result<()> {
+        Self::resize_fd(self.tty().context("terminal not connected")?, width, height)
+    }
+
+    /// Change the line discipline of the terminal, like `stty` would do inside the container.
+    pub async fn set_mode(&self, mode: &TerminalMode) -> Result<()> {
+        let fd = self.tty().context("terminal not connected")?;
+        let mode = *mode;
+        // Draining the pending output and sending a break block until the terminal caught up.
+        task::spawn_blocking(move || Self::set_mode_fd(fd, &mode))
+            .await
+            .context("wait for terminal mode change")?
+    }
+
+    fn set_mode_fd(fd: RawFd, mode: &TerminalMode) -> Result<()> {
+        debug!("Changing terminal mode to {:?}", mode);
+        if mode.raw.is_some() || mode.echo.is_some() {
+            let mut term = termios::tcgetattr(fd).context("get terminal attributes")?;
+            match mode.raw {
+                Some(true) => termios::cfmakeraw(&mut term),
+                Some(false) => {
+                    term.input_flags |= InputFlags::BRKINT | InputFlags::ICRNL | InputFlags::IXON;
+                    term.output_flags |= OutputFlags::OPOST | OutputFlags::ONLCR;
+                    term.local_flags |= LocalFlags::ICANON
+                        | LocalFlags::ISIG
+                        | LocalFlags::IEXTEN
+                        | LocalFlags::ECHO;
+                }
+                None => {}
+            }
+            match mode.echo {
+                Some(true) => term.local_flags |= LocalFlags::ECHO,
+                Some(false) => term.local_flags &= !LocalFlags::ECHO,
+                None => {}
+            }
+            // Pending output is still written using the previous settings.
+            termios::tcsetattr(fd, SetArg::TCSADRAIN, &term).context("set terminal attributes")?;
+        }
+        if mode.send_break {
+            termios::tcsendbreak(fd, 0).context("send break")?;
+        }
+        Ok(())
+    }
+
+    fn resize_fd(fd: RawFd, width: u16, height: u16) -> Result<()> {
         debug!("Resizing terminal to width {} and height {}", width, height);
         let ws = winsize {
             ws_row: height,
@@ -118,48 +282,62 @@ impl Terminal {
             ws_xpixel: 0,
             ws_ypixel: 0,
         };
-        match unsafe {
-            libc::ioctl(
-                self.tty().context("terminal not connected")?,
-                TIOCSWINSZ,
-                &ws,
-            )
-        } {
+        match unsafe { libc::ioctl(fd, TIOCSWINSZ, &ws) } {
             0 => Ok(()),
             _ => Err(IOError::last_os_error().into()),
         }
     }
 
+    /// Apply the resize requests of the attach endpoints to the terminal.
+    async fn resize_loop(fd: RawFd, mut resize_rx: broadcast::Receiver<(u16, u16)>) {
+        loop {
+            match resize_rx.recv().await {
+                Ok((width, height)) => {
+                    if let Err(e) = Self::resize_fd(fd, width, height) {
+                        error!("Unable to resize terminal: {:#}", e);
+                    }
+                }
+                Err(RecvError::Lagged(n)) => debug!("Skipped {} resize requests", n),
+                Err(RecvError::Closed) => return,
+            }
+        }
+    }
+
     async fn listen(
+        path: PathBuf,
+        ready_tx: StdSender<()>,
         config: Config,
         logger: SharedContainerLog,
         attach: SharedContainerAttach,
     ) -> Result<()> {
-        let path = config.path();
         debug!("Listening terminal socket on {}", path.display());
-        let listener = listener::bind_long_path(path)?;
+        let listener = listener::bind_long_path(&path)?;
 
         // Update the permissions
-        let mut perms = fs::metadata(path).await?.permissions();
+        let mut perms = fs::metadata(&path).await?.permissions();
         perms.set_mode(0o700);
-        fs::set_permissions(path, perms).await?;
+        fs::set_permissions(&path, perms).await?;
 
-        config
-            .ready_tx()
+        ready_tx
             .send(())
             .map_err(|_| format_err!("unable to send ready message"))?;
 
+        // Subscribe before the runtime connects, to offer resizing to the first attach clients.
+        let resize_rx = attach.subscribe_resize();
+
         let stream = listener.accept().await?.0;
         debug!("Got terminal socket stream: {:?}", stream);
 
-        Self::handle_fd_receive(stream, config, logger, attach).await
+        Self::handle_fd_receive(stream, &path, config, logger, attach, resize_rx).await
     }
 
     async fn handle_fd_receive(
         mut stream: UnixStream,
+        path: &Path,
         config: Config,
         logger: SharedContainerLog,
         attach: SharedContainerAttach,
+        resize_rx: broadcast::Receiver<(u16, u16)>,
     ) -> Result<()> {
         loop {
             if !stream.ready(Interest::READABLE).await?.is_readable() {
@@ -169,10 +347,11 @@ impl Terminal {
             let mut data_buffer = [];
             let mut fd_buffer: [RawFd; 1] = [0];
 
-            match stream.recv_with_fd(&mut data_buffer, &mut fd_buffer) {
+            match stream.try_io(Interest::READABLE, || {
+                fd_inventory::recv_with_fd(stream.as_raw_fd(), &mut data_buffer, &mut fd_buffer)
+            }) {
                 Ok((_, fd_read)) => {
                     // Allow only one single read
-                    let path = config.path();
                     debug!("Removing socket path {}", path.display());
                     fs::remove_file(path).await?;
 
@@ -185,47 +364,7 @@ impl Terminal {
                     }
 
                     debug!("Received terminal file descriptor");
-                    let fd = fd_buffer[0];
-
-                    debug!("Changing terminal settings");
-                    let mut term = termios::tcgetattr(fd)?;
-                    term.output_flags |= OutputFlags::ONLCR;
-                    termios::tcsetattr(fd, SetArg::TCSANOW, &term)?;
-
-                    let stdio = AsyncFd::try_from(fd)?;
-
-                    let attach_clone = attach.clone();
-                    task::spawn(
-                        async move {
-                            config
-                                .connected_tx
-                                .send(fd)
-                                .await
-                                .context("send connected channel")?;
-                            if let Err(e) = ContainerIO::read_loop(
-                                stdio,
-                                Pipe::StdOut,
-                                logger,
-                                config.message_tx,
-                                attach_clone,
-                            )
-                            .await
-                            {
-                                error!("Stdout read loop failure: {:#}", e)
-                            }
-                            Ok::<_, anyhow::Error>(())
-                        }
-                        .instrument(debug_span!("read_loop")),
-                    );
-
-                    task::spawn(
-                        async move {
-                            if let Err(e) = ContainerIO::read_loop_stdin(fd, attach).await {
-                                error!("Stdin read loop failure: {:#}", e);
-                            }
-                        }
-                        .instrument(debug_span!("read_loop_stdin")),
-                    );
+                    Self::start(fd_buffer[0], config, logger, attach, resize_rx)?;
 
                     debug!("Shutting down listener thread");
                     return Ok(());
@@ -241,16 +380,87 @@ impl Terminal {
             }
         }
     }
+
+    /// Start forwarding the IO of the terminal master `fd`, which is owned by the read loops
+    /// afterwards.
+    fn start(
+        fd: RawFd,
+        config: Config,
+        logger: SharedContainerLog,
+        attach: SharedContainerAttach,
+        resize_rx: broadcast::Receiver<(u16, u16)>,
+    ) -> Result<()> {
+        debug!("Changing terminal settings");
+        let mut term = termios::tcgetattr(fd)?;
+        term.output_flags |= OutputFlags::ONLCR;
+        termios::tcsetattr(fd, SetArg::TCSANOW, &term)?;
+
+        let stdio = AsyncFd::try_from(fd)?;
+
+        let attach_clone = attach.clone();
+        let supervisor = config.supervisor.clone();
+        let reader_guard = supervisor.track_reader();
+        task::spawn(
+            async move {
+                config
+                    .connected_tx
+                    .send(fd)
+                    .await
+                    .context("send connected channel")?;
+                // The terminal file descriptor gets closed together with the read
+                // loop, so resizing has to stop at the same time.
+                let read_loop = config.supervisor.run("stdout", stdio, |stdio| {
+                    ContainerIO::read_loop(
+                        stdio,
+                        Pipe::StdOut,
+                        logger.clone(),
+                        config.message_tx.clone(),
+                        attach_clone.clone(),
+                        config.stats.clone(),
+                    )
+                    .boxed()
+                });
+                tokio::select! {
+                    res = read_loop => {
+                        if let Some(Err(e)) = res {
+                            error!("Stdout read loop failure: {:#}", e)
+                        }
+                    }
+                    _ = Self::resize_loop(fd, resize_rx) => {}
+                }
+                drop(reader_guard);
+                Ok::<_, anyhow::Error>(())
+            }
+            .instrument(debug_span!("read_loop")),
+        );
+
+        task::spawn(
+            async move {
+                // The file descriptor gets closed on panic, so the loop cannot be
+                // restarted.
+                let res = supervisor
+                    .run_once("stdin", ContainerIO::read_loop_stdin(fd, attach))
+                    .await;
+                if let Some(Err(e)) = res {
+                    error!("Stdin read loop failure: {:#}", e);
+                }
+            }
+            .instrument(debug_span!("read_loop_stdin")),
+        );
+        Ok(())
+    }
 }
 
 impl Drop for Terminal {
     fn drop(&mut self) {
-        if let Err(e) = std::fs::remove_file(self.path()) {
-            trace!(
-                "Unable to remove socket file path {}: {}",
-                self.path().display(),
-                e
-            )
+        if let Some(path) = self.path() {
+            if let Err(e) = std::fs::remove_file(path) {
+                trace!(
+                    "Unable to remove socket file path {}: {}",
+                    path.display(),
+                    e
+                )
+            }
         }
     }
 }
@@ -259,21 +469,28 @@ impl Drop for Terminal {
 mod tests {
     use super::*;
     use crate::{attach::SharedContainerAttach, container_log::ContainerLog};
-    use nix::pty;
+    use nix::{pty, unistd::close};
     use sendfd::SendWithFd;
-    use std::os::unix::io::FromRawFd;
-
-    #[tokio::test]
+    use std::{
+        os::unix::{io::AsRawFd, net::UnixListener},
+        thread,
+    };
+    use tempfile::tempdir;
+
+    // Creating the terminal blocks until the listener is ready, which requires another worker.
+    #[tokio::test(flavor = "multi_thread")]
     async fn new_success() -> Result<()> {
         let logger = ContainerLog::new();
         let attach = SharedContainerAttach::default();
 
-        let mut sut = Terminal::new(logger, attach)?;
-        assert!(sut.path().exists());
+        let supervisor = Arc::new(Supervisor::new("id"));
+        let mut sut = Terminal::new(logger, attach, Arc::default(), supervisor, "id", None)?;
+        let path = sut.path().clone().context("no socket path")?;
+        assert!(path.exists());
 
         let res = pty::openpty(None, None)?;
 
-        let stream = UnixStream::connect(sut.path()).await?;
+        let stream = UnixStream::connect(&path).await?;
         loop {
             let ready = stream.ready(Interest::WRITABLE).await?;
             if ready.is_writable() {
@@ -286,7 +503,7 @@ mod tests {
         }
 
         sut.wait_connected().await?;
-        assert!(!sut.path().exists());
+        assert!(!path.exists());
 
         // Write to the slave
         let mut file = unsafe { fs::File::from_raw_fd(res.slave) };
@@ -294,4 +511,77 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn pre_allocated_success() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("pty.sock");
+        let listener = UnixListener::bind(&path)?;
+        let sender = thread::spawn(move || -> Result<()> {
+            let res = pty::openpty(None, None)?;
+            let (stream, _) = listener.accept()?;
+            stream.send_with_fd(b"m", &[res.master])?;
+            close(res.master)?;
+            close(res.slave)?;
+            Ok(())
+        });
+
+        let supervisor = Arc::new(Supervisor::new("id"));
+        let mut sut = Terminal::pre_allocated(
+            ContainerLog::new(),
+            SharedContainerAttach::default(),
+            Arc::default(),
+            supervisor,
+            &path,
+        )?;
+        sender.join().map_err(|_| format_err!("join sender"))??;
+        assert!(sut.path().is_none());
+
+        let slave = sut.take_slave().context("no slave")?;
+        assert!(termios::tcgetattr(slave.as_raw_fd()).is_ok());
+        assert!(sut.take_slave().is_none());
+
+        sut.wait_connected().await?;
+        Ok(())
+    }
+
+    #[test]
+    fn set_mode_fd() -> Result<()> {
+        let res = pty::openpty(None, None)?;
+        let local_flags =
+            || -> Result<LocalFlags> { Ok(termios::tcgetattr(res.slave)?.local_flags) };
+
+        Terminal::set_mode_fd(
+            res.master,
+            &TerminalMode {
+                raw: Some(true),
+                ..Default::default()
+            },
+        )?;
+        assert!(!local_flags()?.intersects(LocalFlags::ICANON | LocalFlags::ECHO));
+
+        Terminal::set_mode_fd(
+            res.master,
+            &TerminalMode {
+                raw: Some(false),
+                echo: Some(true),
+                send_break: true,
+            },
+        )?;
+        assert!(local_flags()?.contains(LocalFlags::ICANON | LocalFlags::ISIG | LocalFlags::ECHO));
+
+        Terminal::set_mode_fd(
+            res.master,
+            &TerminalMode {
+                echo: Some(false),
+                ..Default::default()
+            },
+        )?;
+        let flags = local_flags()?;
+        assert!(flags.contains(LocalFlags::ICANON) && !flags.contains(LocalFlags::ECHO));
+
+        close(res.master)?;
+        close(res.slave)?;
+        Ok(())
+    }
 }
@@ -0,0 +1,263 @@
+//! Validation of container creation requests without spawning the runtime.
+
+use crate::{bundle::BundleConfig, overrides::Overrides};
+use conmon_common::conmon_capnp::conmon::log_driver::Type;
+use getset::Getters;
+use nix::unistd::{access, AccessFlags};
+use std::path::Path;
+
+#[derive(Clone, Debug, Eq, Getters, PartialEq)]
+#[getset(get = "pub")]
+/// A single problem of a container creation request.
+pub struct Problem {
+    /// The request field causing the problem.
+    field: String,
+
+    /// Human readable description of the problem.
+    message: String,
+}
+
+#[derive(Debug, Default)]
+/// Collects the problems of a container creation request instead of failing on the first one.
+pub struct Validator {
+    problems: Vec<Problem>,
+}
+
+impl Validator {
+    /// Record a new problem of the provided request field.
+    pub fn add<F, M>(&mut self, field: F, message: M)
+    where
+        F: Into<String>,
+        M: Into<String>,
+    {
+        self.problems.push(Problem {
+            field: field.into(),
+            message: message.into(),
+        })
+    }
+
+    /// Check that the bundle is an accessible directory containing a valid `config.json`,
+    /// including the values of the annotations interpreted by conmon-rs.
+    pub fn bundle(&mut self, bundle: &Path) {
+        const FIELD: &str = "bundlePath";
+        if bundle.as_os_str().is_empty() {
+            return self.add(FIELD, "no bundle path provided");
+        }
+        if !bundle.is_dir() {
+            return self.add(FIELD, format!("{} is not a directory", bundle.display()));
+        }
+        if let Err(e) = access(bundle, AccessFlags::R_OK | AccessFlags::X_OK) {
+            return self.add(
+                FIELD,
+                format!("{} is not accessible: {}", bundle.display(), e),
+            );
+        }
+
+        match BundleConfig::from_bundle(bundle) {
+            Ok(config) => {
+                if let Err(e) = Overrides::from_annotations(config.annotations()) {
+                    self.add(FIELD, format!("{:#}", e))
+                }
+            }
+            Err(e) => self.add(FIELD, format!("{:#}", e)),
+        }
+    }
+
+    /// Check that the runtime is an executable file.
+    pub fn runtime(&mut self, runtime: &Path) {
+        const FIELD: &str = "runtime";
+        if !runtime.is_file() {
+            return self.add(FIELD, format!("{} is not a file", runtime.display()));
+        }
+        if let Err(e) = access(runtime, AccessFlags::X_OK) {
+            self.add(
+                FIELD,
+                format!("{} is not executable: {}", runtime.display(), e),
+            )
+        }
+    }
+
+    /// Check that the optional stdin file or FIFO is readable and not combined with a terminal.
+    pub fn stdin(&mut self, path: &Path, terminal: bool) {
+        const FIELD: &str = "stdinPath";
+        if path.as_os_str().is_empty() {
+            return;
+        }
+        if terminal {
+            return self.add(FIELD, "not supported with a terminal");
+        }
+        if let Err(e) = access(path, AccessFlags::R_OK) {
+            self.add(FIELD, format!("{} is not readable: {}", path.display(), e))
+        }
+    }
+
+    /// Check that the optional tee file or FIFO exists and is writable.
+    pub fn tee(&mut self, path: &Path) {
+        if path.as_os_str().is_empty() {
+            return;
+        }
+        if let Err(e) = access(path, AccessFlags::W_OK) {
+            self.add(
+                "teePath",
+                format!("{} is not writable: {}", path.display(), e),
+            )
+        }
+    }
+
+    /// Check that a file can be created at `path`, which requires a writable parent directory.
+    pub fn writable_path(&mut self, field: &str, path: &Path) {
+        let parent = path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        if let Err(e) = access(parent, AccessFlags::W_OK | AccessFlags::X_OK) {
+            self.add(
+                field,
+                format!("directory {} is not writable: {}", parent.display(), e),
+            )
+        }
+    }
+
+    /// Check the configuration of the log driver at position `index`.
+    pub fn log_driver(&mut self, index: usize, typ: Type, path: &Path, pod_id: &str) {
+        let field = format!("logDrivers[{}]", index);
+        if path.as_os_str().is_empty() {
+            self.add(&field, "no path provided");
+        } else {
+            self.writable_path(&field, path);
+        }
+        if typ == Type::Pod && pod_id.is_empty() {
+            self.add(&field, "pod log driver requires a pod ID");
+        }
+    }
+
+    /// The problems found so far.
+    pub fn problems(&self) -> &[Problem] {
+        &self.problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        fs::{self, File},
+        os::unix::fs::PermissionsExt,
+    };
+    use tempfile::tempdir;
+
+    fn fields(sut: &Validator) -> Vec<&str> {
+        sut.problems().iter().map(|p| p.field().as_str()).collect()
+    }
+
+    #[test]
+    fn bundle() -> anyhow::Result<()> {
+        let dir = tempdir()?;
+        let mut sut = Validator::default();
+
+        sut.bundle(Path::new(""));
+        sut.bundle(&dir.path().join("missing"));
+        sut.bundle(dir.path());
+        assert_eq!(sut.problems().len(), 3);
+        assert!(sut.problems()[2]
+            .message()
+            .starts_with("read bundle config"));
+
+        fs::write(dir.path().join("config.json"), "invalid")?;
+        sut.bundle(dir.path());
+        assert_eq!(sut.problems().len(), 4);
+        assert!(sut.problems()[3]
+            .message()
+            .starts_with("parse bundle config"));
+
+        fs::write(
+            dir.path().join("config.json"),
+            "{\"annotations\": {\"io.conmon-rs/log-max-size\": \"-1\"}}",
+        )?;
+        sut.bundle(dir.path());
+        assert_eq!(sut.problems().len(), 5);
+        assert!(sut.problems()[4].message().starts_with("invalid value"));
+
+        fs::write(
+            dir.path().join("config.json"),
+            "{\"ociVersion\": \"1.0.2\"}\n",
+        )?;
+        sut.bundle(dir.path());
+        assert_eq!(sut.problems().len(), 5);
+        assert!(fields(&sut).iter().all(|f| *f == "bundlePath"));
+        Ok(())
+    }
+
+    #[test]
+    fn runtime() -> anyhow::Result<()> {
+        let dir = tempdir()?;
+        let runtime = dir.path().join("runtime");
+        let mut sut = Validator::default();
+
+        sut.runtime(&runtime);
+        File::create(&runtime)?.set_permissions(fs::Permissions::from_mode(0o644))?;
+        sut.runtime(&runtime);
+        assert_eq!(fields(&sut), vec!["runtime"; 2]);
+
+        fs::set_permissions(&runtime, fs::Permissions::from_mode(0o755))?;
+        sut.runtime(&runtime);
+        assert_eq!(sut.problems().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn stdin() -> anyhow::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("stdin");
+        let mut sut = Validator::default();
+
+        sut.stdin(Path::new(""), true);
+        File::create(&path)?;
+        sut.stdin(&path, false);
+        assert!(sut.problems().is_empty());
+
+        sut.stdin(&path, true);
+        sut.stdin(&dir.path().join("missing"), false);
+        assert_eq!(fields(&sut), vec!["stdinPath"; 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn tee() -> anyhow::Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("tee");
+        let mut sut = Validator::default();
+
+        sut.tee(Path::new(""));
+        File::create(&path)?;
+        sut.tee(&path);
+        assert!(sut.problems().is_empty());
+
+        sut.tee(&dir.path().join("missing"));
+        assert_eq!(fields(&sut), vec!["teePath"]);
+        Ok(())
+    }
+
+    #[test]
+    fn log_driver() -> anyhow::Result<()> {
+        let dir = tempdir()?;
+        let mut sut = Validator::default();
+
+        sut.log_driver(
+            0,
+            Type::ContainerRuntimeInterface,
+            &dir.path().join("log"),
+            "",
+        );
+        sut.log_driver(1, Type::Pod, &dir.path().join("log"), "pod");
+        assert!(sut.problems().is_empty());
+
+        sut.log_driver(2, Type::ContainerRuntimeInterface, Path::new(""), "");
+        sut.log_driver(3, Type::Pod, &dir.path().join("missing/log"), "");
+        assert_eq!(
+            fields(&sut),
+            vec!["logDrivers[2]", "logDrivers[3]", "logDrivers[3]"]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+//! Assertions over the CRI logs and exit files written by conmon-rs.
+
+use anyhow::{bail, Context, Result};
+use std::{path::Path, time::Duration};
+use tokio::{
+    fs,
+    time::{self, Instant},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// A line of a CRI log, which got reassembled from its partial lines.
+pub struct LogLine {
+    /// Timestamp of the last part of the line.
+    pub timestamp: String,
+
+    /// The stream of the line, either `stdout` or `stderr`.
+    pub stream: String,
+
+    /// Content of the line without the trailing newline.
+    pub content: String,
+}
+
+/// Parse the CRI log `content`. Partial lines (`P`) are joined with the following ones of the
+/// same stream up to the full line (`F`), a trailing partial line is returned as is.
+pub fn parse_cri_log(content: &str) -> Result<Vec<LogLine>> {
+    let mut lines: Vec<LogLine> = vec![];
+    let mut partial: Vec<LogLine> = vec![];
+    for (n, line) in content.lines().enumerate() {
+        let mut fields = line.splitn(4, ' ');
+        let mut field = |name: &str| {
+            fields
+                .next()
+                .with_context(|| format!("no {} in line {}", name, n + 1))
+        };
+        let timestamp = field("timestamp")?;
+        let stream = field("stream")?;
+        let tag = field("tag")?;
+        let content = field("content").unwrap_or_default();
+
+        let pending = partial.iter().position(|l| l.stream == stream);
+        let mut line = match pending {
+            Some(i) => partial.remove(i),
+            None => LogLine {
+                timestamp: String::new(),
+                stream: stream.into(),
+                content: String::new(),
+            },
+        };
+        line.timestamp = timestamp.into();
+        line.content.push_str(content);
+        match tag {
+            "F" => lines.push(line),
+            "P" => partial.push(line),
+            _ => bail!("unknown tag {} in line {}", tag, n + 1),
+        }
+    }
+    lines.extend(partial);
+    Ok(lines)
+}
+
+/// Read and parse the CRI log at `path`.
+pub async fn read_cri_log(path: &Path) -> Result<Vec<LogLine>> {
+    let content = fs::read_to_string(path)
+        .await
+        .with_context(|| format!("read log {}", path.display()))?;
+    parse_cri_log(&content)
+}
+
+/// Assert that the CRI log at `path` contains exactly the `expected` lines of both streams.
+pub async fn assert_log_lines(path: &Path, expected: &[&str]) {
+    let lines = read_cri_log(path).await.expect("read CRI log");
+    let contents: Vec<&str> = lines.iter().map(|l| l.content.as_str()).collect();
+    assert_eq!(contents, expected, "lines of log {}", path.display());
+}
+
+/// Wait until the exit file at `path` got written and return its exit code, which fails after
+/// the `timeout`.
+pub async fn wait_for_exit_file(path: &Path, timeout: Duration) -> Result<i32> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        // The exit code is written at once after the file got created.
+        if let Ok(content) = fs::read_to_string(path).await {
+            if !content.is_empty() {
+                return content
+                    .trim()
+                    .parse()
+                    .with_context(|| format!("parse exit file {}", path.display()));
+            }
+        }
+        if Instant::now() >= deadline {
+            bail!(
+                "exit file {} not written within {:?}",
+                path.display(),
+                timeout
+            )
+        }
+        time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn parse_partial_lines() -> Result<()> {
+        let lines = parse_cri_log(
+            "2022-01-01T00:00:00.000000000Z stdout P hel\n\
+             2022-01-01T00:00:00.000000001Z stderr F error\n\
+             2022-01-01T00:00:00.000000002Z stdout F lo world\n\
+             2022-01-01T00:00:00.000000003Z stdout F \n\
+             2022-01-01T00:00:00.000000004Z stdout P tail\n",
+        )?;
+        let contents: Vec<(&str, &str)> = lines
+            .iter()
+            .map(|l| (l.stream.as_str(), l.content.as_str()))
+            .collect();
+        assert_eq!(
+            contents,
+            [
+                ("stderr", "error"),
+                ("stdout", "hello world"),
+                ("stdout", ""),
+                ("stdout", "tail")
+            ]
+        );
+        assert_eq!(lines[1].timestamp, "2022-01-01T00:00:00.000000002Z");
+
+        assert!(parse_cri_log("2022-01-01T00:00:00Z stdout\n").is_err());
+        assert!(parse_cri_log("2022-01-01T00:00:00Z stdout X line\n").is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exit_file() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("exit");
+        assert!(wait_for_exit_file(&path, Duration::from_millis(50))
+            .await
+            .is_err());
+
+        fs::write(&path, "137").await?;
+        assert_eq!(wait_for_exit_file(&path, Duration::ZERO).await?, 137);
+        Ok(())
+    }
+}
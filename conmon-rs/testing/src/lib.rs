@@ -0,0 +1,13 @@
+//! Fixtures for hermetic integration tests against conmon-rs.
+//!
+//! A `TestServer` runs conmon-rs in the current process, listening on a socket in a temporary
+//! directory. Containers are run by the `FakeRuntime` directly on the host, which neither
+//! requires root privileges nor an OCI runtime. The written CRI logs and exit files can be
+//! checked by the helpers of the `files` module.
+
+pub use runtime::{Bundle, FakeRuntime};
+pub use server::TestServer;
+
+pub mod files;
+mod runtime;
+mod server;
@@ -0,0 +1,274 @@
+//! A fake OCI runtime and container bundles for it.
+//!
+//! The fake runtime is a shell script, which runs the `command` script of the bundle on the
+//! host instead of in a container. It supports the `create`, `start`, `state`, `kill`, `delete`
+//! and `exec` commands without a terminal, which covers the lifecycle driven by conmon-rs. The
+//! state of the containers is kept in the runtime root directory.
+
+use anyhow::{Context, Result};
+use std::{
+    fs,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+};
+
+/// The fake runtime, which fails with a JSON error log entry like runc and crun.
+const SCRIPT: &str = r#"#!/bin/sh
+root=
+log=
+while [ $# -gt 0 ]; do
+    case "$1" in
+    --root=*) root=${1#--root=} ;;
+    --log=*) log=${1#--log=} ;;
+    --*) ;;
+    *) break ;;
+    esac
+    shift
+done
+
+fail() {
+    if [ -n "$log" ]; then
+        printf '{"level":"error","msg":"%s"}\n' "$*" >> "$log"
+    fi
+    echo "fake-runtime: $*" >&2
+    exit 1
+}
+
+[ -n "$root" ] || fail "no root directory"
+[ $# -gt 0 ] || fail "no command"
+command=$1
+shift
+
+bundle=
+pidfile=
+while [ $# -gt 0 ]; do
+    case "$1" in
+    --bundle) bundle=$2; shift ;;
+    --pid-file) pidfile=$2; shift ;;
+    --pid-file=*) pidfile=${1#--pid-file=} ;;
+    --console-socket* | --tty) fail "terminals are not supported" ;;
+    -*) ;;
+    *) break ;;
+    esac
+    shift
+done
+
+id=$1
+[ -n "$id" ] || fail "no container id"
+shift
+state=$root/$id
+if [ "$command" != create ] && [ ! -d "$state" ]; then
+    fail "container $id does not exist"
+fi
+
+case "$command" in
+create)
+    [ ! -e "$state" ] || fail "container $id exists already"
+    mkdir -p "$state" || fail "unable to create state of $id"
+    echo "$bundle" > "$state/bundle"
+    # Explicitly inherit stdin, which background processes do not by default.
+    (
+        while [ ! -e "$state/started" ]; do sleep 0.01; done
+        cd "$bundle" && exec sh ./command
+    ) 0<&0 &
+    echo $! > "$state/pid"
+    echo $! > "$pidfile"
+    ;;
+start)
+    touch "$state/started"
+    ;;
+state)
+    pid=$(cat "$state/pid")
+    if [ ! -e "$state/started" ]; then
+        status=created
+    elif kill -0 "$pid" 2> /dev/null; then
+        status=running
+    else
+        status=stopped
+    fi
+    printf '{"ociVersion":"1.0.2","id":"%s","status":"%s","pid":%s,"bundle":"%s"}\n' \
+        "$id" "$status" "$pid" "$(cat "$state/bundle")"
+    ;;
+kill)
+    signal=${1:-TERM}
+    kill -s "${signal#SIG}" "$(cat "$state/pid")" || fail "unable to signal $id"
+    ;;
+delete)
+    kill -s KILL "$(cat "$state/pid")" 2> /dev/null
+    rm -rf "$state"
+    ;;
+exec)
+    [ $# -gt 0 ] || fail "no exec command"
+    "$@" 0<&0 &
+    echo $! > "$pidfile"
+    ;;
+*)
+    fail "unsupported command $command"
+    ;;
+esac
+"#;
+
+#[derive(Clone, Debug)]
+/// The fake runtime installed into a directory.
+pub struct FakeRuntime {
+    path: PathBuf,
+    root: PathBuf,
+}
+
+impl FakeRuntime {
+    /// Install the fake runtime into `dir`, which keeps the state of the containers in a
+    /// subdirectory.
+    pub fn install(dir: &Path) -> Result<Self> {
+        let path = dir.join("fake-runtime");
+        fs::write(&path, SCRIPT).context("write fake runtime")?;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))
+            .context("make fake runtime executable")?;
+        let root = dir.join("fake-runtime-root");
+        fs::create_dir_all(&root).context("create fake runtime root")?;
+        Ok(Self { path, root })
+    }
+
+    /// Path of the runtime binary.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Root directory of the runtime, which has to be passed as `--root`.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Whether the runtime still knows the container `id`.
+    pub fn exists(&self, id: &str) -> bool {
+        self.root.join(id).exists()
+    }
+}
+
+#[derive(Clone, Debug)]
+/// A container bundle for the fake runtime.
+pub struct Bundle {
+    path: PathBuf,
+}
+
+impl Bundle {
+    /// Create the bundle at `path`, whose container runs the shell script `command` in the
+    /// bundle directory.
+    pub fn new(path: &Path, command: &str) -> Result<Self> {
+        fs::create_dir_all(path).context("create bundle directory")?;
+        fs::write(
+            path.join("config.json"),
+            r#"{"ociVersion":"1.0.2","process":{"terminal":false,"cwd":"/"}}"#,
+        )
+        .context("write bundle config")?;
+        fs::write(path.join("command"), command).context("write bundle command")?;
+        Ok(Self { path: path.into() })
+    }
+
+    /// Path of the bundle directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        process::{Command, ExitStatus, Output, Stdio},
+        thread,
+        time::Duration,
+    };
+    use tempfile::tempdir;
+
+    fn run(runtime: &FakeRuntime, args: &[&str]) -> Result<Output> {
+        Ok(Command::new(runtime.path())
+            .arg(format!("--root={}", runtime.root().display()))
+            .arg("--log-format=json")
+            .args(args)
+            .output()?)
+    }
+
+    /// Run the runtime without capturing its output, which the created container inherits.
+    fn run_detached(runtime: &FakeRuntime, args: &[&str]) -> Result<ExitStatus> {
+        Ok(Command::new(runtime.path())
+            .arg(format!("--root={}", runtime.root().display()))
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?)
+    }
+
+    fn status(runtime: &FakeRuntime, id: &str) -> Result<String> {
+        let output = run(runtime, &["state", id])?;
+        let stdout = String::from_utf8(output.stdout)?;
+        let (_, rest) = stdout.split_once(r#""status":""#).context("no status")?;
+        Ok(rest.split('"').next().unwrap_or_default().into())
+    }
+
+    #[test]
+    fn lifecycle() -> Result<()> {
+        let dir = tempdir()?;
+        let runtime = FakeRuntime::install(dir.path())?;
+        let bundle = Bundle::new(
+            &dir.path().join("bundle"),
+            "echo started > out; exec sleep 10",
+        )?;
+        let pidfile = dir.path().join("pidfile");
+        let bundle_path = bundle.path().display().to_string();
+        let pidfile_path = pidfile.display().to_string();
+
+        let created = run_detached(
+            &runtime,
+            &[
+                "create",
+                "--bundle",
+                &bundle_path,
+                "--pid-file",
+                &pidfile_path,
+                "ctr",
+            ],
+        )?;
+        assert!(created.success());
+        assert!(fs::read_to_string(&pidfile)?.trim().parse::<u32>().is_ok());
+        assert_eq!(status(&runtime, "ctr")?, "created");
+        assert!(
+            !run(&runtime, &["create", "--bundle", &bundle_path, "ctr"])?
+                .status
+                .success()
+        );
+
+        assert!(run(&runtime, &["start", "ctr"])?.status.success());
+        for _ in 0..100 {
+            if bundle.path().join("out").exists() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(fs::read_to_string(bundle.path().join("out"))?, "started\n");
+        assert_eq!(status(&runtime, "ctr")?, "running");
+
+        assert!(run(&runtime, &["kill", "ctr", "SIGKILL"])?.status.success());
+        assert!(run(&runtime, &["delete", "ctr"])?.status.success());
+        assert!(!runtime.exists("ctr"));
+        assert!(!run(&runtime, &["state", "ctr"])?.status.success());
+        Ok(())
+    }
+
+    #[test]
+    fn failure_log() -> Result<()> {
+        let dir = tempdir()?;
+        let runtime = FakeRuntime::install(dir.path())?;
+        let log = dir.path().join("runtime.log");
+
+        let output = run(
+            &runtime,
+            &[&format!("--log={}", log.display()), "pause", "ctr"],
+        )?;
+        assert!(!output.status.success());
+        assert_eq!(
+            fs::read_to_string(&log)?,
+            "{\"level\":\"error\",\"msg\":\"container ctr does not exist\"}\n"
+        );
+        Ok(())
+    }
+}
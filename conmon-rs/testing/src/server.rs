@@ -0,0 +1,266 @@
+//! An in-process server for integration tests.
+
+use crate::runtime::{Bundle, FakeRuntime};
+use anyhow::{bail, format_err, Context, Result};
+use capnp_rpc::{rpc_twoparty_capnp::Side, twoparty, RpcSystem};
+use conmon_common::conmon_capnp::conmon;
+use conmonrs::Server;
+use futures::{AsyncReadExt, FutureExt};
+use std::{
+    path::{Path, PathBuf},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+use tempfile::TempDir;
+use tokio::{net::UnixStream, task};
+use tokio_util::compat::TokioAsyncReadCompatExt;
+
+/// Name of the socket, which the server creates in its runtime directory.
+const SOCKET: &str = "conmon.sock";
+
+/// A conmon-rs server running in a thread of the current process, which uses the fake runtime.
+/// All files are kept in a temporary directory, which gets removed together with the server.
+///
+/// The server makes the process the subreaper of the containers, which is required to monitor
+/// them. It gets shut down when dropped, which stops all its containers.
+pub struct TestServer {
+    server: Server,
+    thread: Option<JoinHandle<Result<()>>>,
+    runtime: FakeRuntime,
+    runtime_dir: PathBuf,
+    dir: TempDir,
+}
+
+impl TestServer {
+    /// The maximum time to wait for the server to listen on its socket.
+    const STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Start a new server with the default configuration.
+    pub fn start() -> Result<Self> {
+        Self::with_args::<&str>(&[])
+    }
+
+    /// Start a new server with additional command line arguments, like `--exec-timeout=10`.
+    pub fn with_args<S: AsRef<str>>(args: &[S]) -> Result<Self> {
+        let dir = tempfile::Builder::new()
+            .prefix("conmon-rs-testing-")
+            .tempdir()
+            .context("create temp dir")?;
+        let runtime = FakeRuntime::install(dir.path())?;
+        let runtime_dir = dir.path().join("run");
+
+        let mut server_args = vec![
+            "conmonrs".to_string(),
+            "--skip-fork=true".into(),
+            format!("--runtime={}", runtime.path().display()),
+            format!("--runtime-dir={}", runtime_dir.display()),
+            format!("--runtime-root={}", runtime.root().display()),
+        ];
+        server_args.extend(args.iter().map(|arg| arg.as_ref().to_string()));
+        let server = Server::from_args(server_args).context("create server")?;
+
+        let thread = thread::Builder::new()
+            .name("conmon-rs".into())
+            .spawn({
+                let server = server.clone();
+                move || server.start()
+            })
+            .context("spawn server thread")?;
+        let mut test_server = Self {
+            server,
+            thread: Some(thread),
+            runtime,
+            runtime_dir,
+            dir,
+        };
+        test_server.wait_listening()?;
+        Ok(test_server)
+    }
+
+    /// Path of the socket the server listens on.
+    pub fn socket(&self) -> PathBuf {
+        self.runtime_dir.join(SOCKET)
+    }
+
+    /// The runtime directory of the server.
+    pub fn runtime_dir(&self) -> &Path {
+        &self.runtime_dir
+    }
+
+    /// The temporary directory which contains all files of the server.
+    pub fn dir(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// The fake runtime used by the server.
+    pub fn runtime(&self) -> &FakeRuntime {
+        &self.runtime
+    }
+
+    /// Create a bundle for the container `id` in the temporary directory, whose container runs
+    /// the shell script `command`.
+    pub fn bundle(&self, id: &str, command: &str) -> Result<Bundle> {
+        Bundle::new(&self.dir().join("bundles").join(id), command)
+    }
+
+    /// Connect a new client to the server. The connection is served by a local task, which
+    /// means that this has to be called within a `tokio::task::LocalSet`.
+    pub async fn connect(&self) -> Result<conmon::Client> {
+        let stream = UnixStream::connect(self.socket())
+            .await
+            .context("connect to server")?;
+        let (reader, writer) = TokioAsyncReadCompatExt::compat(stream).split();
+        let network = Box::new(twoparty::VatNetwork::new(
+            reader,
+            writer,
+            Side::Client,
+            Default::default(),
+        ));
+        let mut rpc_system = RpcSystem::new(network, None);
+        let client: conmon::Client = rpc_system.bootstrap(Side::Server);
+        task::spawn_local(Box::pin(rpc_system.map(|_| ())));
+        Ok(client)
+    }
+
+    /// Set a single CRI log driver writing to `path` as the log drivers of the create `request`.
+    pub fn set_cri_log_driver(request: conmon::create_container_request::Builder, path: &Path) {
+        let mut driver = request.init_log_drivers(1).get(0);
+        driver.set_type(conmon::log_driver::Type::ContainerRuntimeInterface);
+        driver.set_path(&path.display().to_string());
+    }
+
+    /// Shut the server down and return the result of running it.
+    pub fn stop(mut self) -> Result<()> {
+        self.join()
+    }
+
+    fn wait_listening(&mut self) -> Result<()> {
+        let deadline = Instant::now() + Self::STARTUP_TIMEOUT;
+        while !self.socket().exists() {
+            if self.thread.as_ref().map_or(true, JoinHandle::is_finished) {
+                self.join().context("start server")?;
+                bail!("server stopped during startup")
+            }
+            if Instant::now() >= deadline {
+                bail!("server not listening within {:?}", Self::STARTUP_TIMEOUT)
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        Ok(())
+    }
+
+    fn join(&mut self) -> Result<()> {
+        match self.thread.take() {
+            Some(thread) => {
+                self.server.request_shutdown();
+                thread
+                    .join()
+                    .map_err(|_| format_err!("server thread panicked"))?
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        // Errors got reported by the server already.
+        let _ = self.join();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::files;
+    use tokio::task::LocalSet;
+
+    #[tokio::test]
+    async fn container_lifecycle() -> Result<()> {
+        let sut = TestServer::start()?;
+        let bundle = sut.bundle("ctr", "echo hello; echo world >&2; exit 3")?;
+        let log_path = sut.dir().join("ctr.log");
+        let exit_path = sut.dir().join("ctr.exit");
+
+        LocalSet::new()
+            .run_until(async {
+                let client = sut.connect().await?;
+
+                let mut req = client.create_container_request();
+                let mut r = req.get().init_request();
+                r.set_id("ctr");
+                r.set_bundle_path(&bundle.path().display().to_string());
+                r.reborrow()
+                    .init_exit_paths(1)
+                    .set(0, &exit_path.display().to_string());
+                TestServer::set_cri_log_driver(r, &log_path);
+                req.send().promise.await?;
+
+                let mut req = client.start_container_request();
+                req.get().init_request().set_id("ctr");
+                req.send().promise.await?;
+                Ok::<_, anyhow::Error>(())
+            })
+            .await?;
+
+        assert_eq!(
+            files::wait_for_exit_file(&exit_path, Duration::from_secs(10)).await?,
+            3
+        );
+        let mut lines = files::read_cri_log(&log_path).await?;
+        lines.sort_by(|a, b| a.stream.cmp(&b.stream));
+        let contents: Vec<&str> = lines.iter().map(|l| l.content.as_str()).collect();
+        assert_eq!(contents, ["world", "hello"]);
+        sut.stop()
+    }
+
+    #[tokio::test]
+    async fn failed_restore_keeps_checkpoint() -> Result<()> {
+        let sut = TestServer::start()?;
+        let bundle = sut.bundle("ctr", "exit 3")?;
+        let exit_path = sut.dir().join("ctr.exit");
+
+        LocalSet::new()
+            .run_until(async {
+                let client = sut.connect().await?;
+
+                let mut req = client.create_container_request();
+                let mut r = req.get().init_request();
+                r.set_id("ctr");
+                r.set_name("name");
+                r.set_bundle_path(&bundle.path().display().to_string());
+                r.reborrow()
+                    .init_exit_paths(1)
+                    .set(0, &exit_path.display().to_string());
+                req.send().promise.await?;
+
+                let mut req = client.start_container_request();
+                req.get().init_request().set_id("ctr");
+                req.send().promise.await?;
+                files::wait_for_exit_file(&exit_path, Duration::from_secs(10)).await?;
+
+                // The fake runtime does not support restoring containers.
+                let mut req = client.restore_container_request();
+                let mut r = req.get().init_request();
+                r.set_image_path(&sut.dir().join("checkpoint").display().to_string());
+                let mut c = r.init_container();
+                c.set_id("ctr");
+                c.set_name("name");
+                c.set_bundle_path(&bundle.path().display().to_string());
+                assert!(req.send().promise.await.is_err());
+
+                let mut req = client.container_status_request();
+                req.get().init_request().set_id("name");
+                let res = req.send().promise.await?;
+                let status = res.get()?.get_response()?;
+                assert_eq!(
+                    status.get_state()?,
+                    conmon::container_status_response::State::Exited
+                );
+                assert_eq!(status.get_exit_code(), 3);
+                Ok::<_, anyhow::Error>(())
+            })
+            .await?;
+        sut.stop()
+    }
+}
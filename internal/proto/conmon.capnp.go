@@ -8,6 +8,7 @@ import (
 	schemas "capnproto.org/go/capnp/v3/schemas"
 	server "capnproto.org/go/capnp/v3/server"
 	context "context"
+	math "math"
 )
 
 type Conmon capnp.Client
@@ -111,2774 +112,20815 @@ func (c Conmon) SetWindowSizeContainer(ctx context.Context, params func(Conmon_s
 	ans, release := capnp.Client(c).SendCall(ctx, s)
 	return Conmon_setWindowSizeContainer_Results_Future{Future: ans.Future()}, release
 }
-
-func (c Conmon) AddRef() Conmon {
-	return Conmon(capnp.Client(c).AddRef())
+func (c Conmon) Negotiate(ctx context.Context, params func(Conmon_negotiate_Params) error) (Conmon_negotiate_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      6,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "negotiate",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_negotiate_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_negotiate_Results_Future{Future: ans.Future()}, release
 }
-
-func (c Conmon) Release() {
-	capnp.Client(c).Release()
+func (c Conmon) AttachStreamContainer(ctx context.Context, params func(Conmon_attachStreamContainer_Params) error) (Conmon_attachStreamContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      7,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "attachStreamContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_attachStreamContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_attachStreamContainer_Results_Future{Future: ans.Future()}, release
 }
-
-func (c Conmon) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
-	return capnp.Client(c).EncodeAsPtr(seg)
+func (c Conmon) ListContainerStatuses(ctx context.Context, params func(Conmon_listContainerStatuses_Params) error) (Conmon_listContainerStatuses_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      8,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "listContainerStatuses",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_listContainerStatuses_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_listContainerStatuses_Results_Future{Future: ans.Future()}, release
 }
-
-func (Conmon) DecodeFromPtr(p capnp.Ptr) Conmon {
-	return Conmon(capnp.Client{}.DecodeFromPtr(p))
+func (c Conmon) GetEvents(ctx context.Context, params func(Conmon_getEvents_Params) error) (Conmon_getEvents_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      9,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "getEvents",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_getEvents_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_getEvents_Results_Future{Future: ans.Future()}, release
 }
-
-func (c Conmon) IsValid() bool {
-	return capnp.Client(c).IsValid()
+func (c Conmon) ListExecSessions(ctx context.Context, params func(Conmon_listExecSessions_Params) error) (Conmon_listExecSessions_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      10,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "listExecSessions",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_listExecSessions_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_listExecSessions_Results_Future{Future: ans.Future()}, release
 }
-
-// A Conmon_Server is a Conmon with a local implementation.
-type Conmon_Server interface {
-	Version(context.Context, Conmon_version) error
-
-	CreateContainer(context.Context, Conmon_createContainer) error
-
-	ExecSyncContainer(context.Context, Conmon_execSyncContainer) error
-
-	AttachContainer(context.Context, Conmon_attachContainer) error
-
-	ReopenLogContainer(context.Context, Conmon_reopenLogContainer) error
-
-	SetWindowSizeContainer(context.Context, Conmon_setWindowSizeContainer) error
+func (c Conmon) ContainerIOStats(ctx context.Context, params func(Conmon_containerIOStats_Params) error) (Conmon_containerIOStats_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      11,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "containerIOStats",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_containerIOStats_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_containerIOStats_Results_Future{Future: ans.Future()}, release
 }
-
-// Conmon_NewServer creates a new Server from an implementation of Conmon_Server.
-func Conmon_NewServer(s Conmon_Server) *server.Server {
-	c, _ := s.(server.Shutdowner)
-	return server.New(Conmon_Methods(nil, s), s, c)
+func (c Conmon) SetLogLevel(ctx context.Context, params func(Conmon_setLogLevel_Params) error) (Conmon_setLogLevel_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      12,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "setLogLevel",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_setLogLevel_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_setLogLevel_Results_Future{Future: ans.Future()}, release
 }
-
-// Conmon_ServerToClient creates a new Client from an implementation of Conmon_Server.
-// The caller is responsible for calling Release on the returned Client.
-func Conmon_ServerToClient(s Conmon_Server) Conmon {
-	return Conmon(capnp.NewClient(Conmon_NewServer(s)))
+func (c Conmon) RemoveContainer(ctx context.Context, params func(Conmon_removeContainer_Params) error) (Conmon_removeContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      13,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "removeContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_removeContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_removeContainer_Results_Future{Future: ans.Future()}, release
 }
-
-// Conmon_Methods appends Methods to a slice that invoke the methods on s.
-// This can be used to create a more complicated Server.
-func Conmon_Methods(methods []server.Method, s Conmon_Server) []server.Method {
-	if cap(methods) == 0 {
-		methods = make([]server.Method, 0, 6)
+func (c Conmon) GetContainerLogOffset(ctx context.Context, params func(Conmon_getContainerLogOffset_Params) error) (Conmon_getContainerLogOffset_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      14,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "getContainerLogOffset",
+		},
 	}
-
-	methods = append(methods, server.Method{
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_getContainerLogOffset_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_getContainerLogOffset_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) FlushContainerLogs(ctx context.Context, params func(Conmon_flushContainerLogs_Params) error) (Conmon_flushContainerLogs_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
 		Method: capnp.Method{
 			InterfaceID:   0xb737e899dd6633f1,
-			MethodID:      0,
+			MethodID:      15,
 			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
-			MethodName:    "version",
+			MethodName:    "flushContainerLogs",
 		},
-		Impl: func(ctx context.Context, call *server.Call) error {
-			return s.Version(ctx, Conmon_version{call})
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_flushContainerLogs_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_flushContainerLogs_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) ValidateCreate(ctx context.Context, params func(Conmon_validateCreate_Params) error) (Conmon_validateCreate_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      16,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "validateCreate",
 		},
-	})
-
-	methods = append(methods, server.Method{
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_validateCreate_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_validateCreate_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) UpdateLogDrivers(ctx context.Context, params func(Conmon_updateLogDrivers_Params) error) (Conmon_updateLogDrivers_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
 		Method: capnp.Method{
 			InterfaceID:   0xb737e899dd6633f1,
-			MethodID:      1,
+			MethodID:      17,
 			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
-			MethodName:    "createContainer",
+			MethodName:    "updateLogDrivers",
 		},
-		Impl: func(ctx context.Context, call *server.Call) error {
-			return s.CreateContainer(ctx, Conmon_createContainer{call})
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_updateLogDrivers_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_updateLogDrivers_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) Health(ctx context.Context, params func(Conmon_health_Params) error) (Conmon_health_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      18,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "health",
 		},
-	})
-
-	methods = append(methods, server.Method{
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_health_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_health_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) SetTerminalModeContainer(ctx context.Context, params func(Conmon_setTerminalModeContainer_Params) error) (Conmon_setTerminalModeContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
 		Method: capnp.Method{
 			InterfaceID:   0xb737e899dd6633f1,
-			MethodID:      2,
+			MethodID:      19,
 			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
-			MethodName:    "execSyncContainer",
+			MethodName:    "setTerminalModeContainer",
 		},
-		Impl: func(ctx context.Context, call *server.Call) error {
-			return s.ExecSyncContainer(ctx, Conmon_execSyncContainer{call})
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_setTerminalModeContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_setTerminalModeContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) ReadExecOutput(ctx context.Context, params func(Conmon_readExecOutput_Params) error) (Conmon_readExecOutput_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      20,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "readExecOutput",
 		},
-	})
-
-	methods = append(methods, server.Method{
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_readExecOutput_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_readExecOutput_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) MirrorContainerIO(ctx context.Context, params func(Conmon_mirrorContainerIO_Params) error) (Conmon_mirrorContainerIO_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
 		Method: capnp.Method{
 			InterfaceID:   0xb737e899dd6633f1,
-			MethodID:      3,
+			MethodID:      21,
 			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
-			MethodName:    "attachContainer",
+			MethodName:    "mirrorContainerIO",
 		},
-		Impl: func(ctx context.Context, call *server.Call) error {
-			return s.AttachContainer(ctx, Conmon_attachContainer{call})
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_mirrorContainerIO_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_mirrorContainerIO_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) ContainerStats(ctx context.Context, params func(Conmon_containerStats_Params) error) (Conmon_containerStats_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      22,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "containerStats",
 		},
-	})
-
-	methods = append(methods, server.Method{
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_containerStats_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_containerStats_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) FreezeContainer(ctx context.Context, params func(Conmon_freezeContainer_Params) error) (Conmon_freezeContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
 		Method: capnp.Method{
 			InterfaceID:   0xb737e899dd6633f1,
-			MethodID:      4,
+			MethodID:      23,
 			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
-			MethodName:    "reopenLogContainer",
+			MethodName:    "freezeContainer",
 		},
-		Impl: func(ctx context.Context, call *server.Call) error {
-			return s.ReopenLogContainer(ctx, Conmon_reopenLogContainer{call})
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_freezeContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_freezeContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) ThawContainer(ctx context.Context, params func(Conmon_thawContainer_Params) error) (Conmon_thawContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      24,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "thawContainer",
 		},
-	})
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_thawContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_thawContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) ContainerProcesses(ctx context.Context, params func(Conmon_containerProcesses_Params) error) (Conmon_containerProcesses_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      25,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "containerProcesses",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_containerProcesses_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_containerProcesses_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) RequestLatencies(ctx context.Context, params func(Conmon_requestLatencies_Params) error) (Conmon_requestLatencies_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      26,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "requestLatencies",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_requestLatencies_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_requestLatencies_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) KillContainer(ctx context.Context, params func(Conmon_killContainer_Params) error) (Conmon_killContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      27,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "killContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_killContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_killContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) StartContainer(ctx context.Context, params func(Conmon_startContainer_Params) error) (Conmon_startContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      28,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "startContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_startContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_startContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) ListContainers(ctx context.Context, params func(Conmon_listContainers_Params) error) (Conmon_listContainers_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      29,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "listContainers",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_listContainers_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_listContainers_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) UpdateContainerResources(ctx context.Context, params func(Conmon_updateContainerResources_Params) error) (Conmon_updateContainerResources_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      30,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "updateContainerResources",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_updateContainerResources_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_updateContainerResources_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) PauseContainer(ctx context.Context, params func(Conmon_pauseContainer_Params) error) (Conmon_pauseContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      31,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "pauseContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_pauseContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_pauseContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) UnpauseContainer(ctx context.Context, params func(Conmon_unpauseContainer_Params) error) (Conmon_unpauseContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      32,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "unpauseContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_unpauseContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_unpauseContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) CheckpointContainer(ctx context.Context, params func(Conmon_checkpointContainer_Params) error) (Conmon_checkpointContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      33,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "checkpointContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_checkpointContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_checkpointContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) RestoreContainer(ctx context.Context, params func(Conmon_restoreContainer_Params) error) (Conmon_restoreContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      34,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "restoreContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_restoreContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_restoreContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) ExecContainer(ctx context.Context, params func(Conmon_execContainer_Params) error) (Conmon_execContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      35,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "execContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_execContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_execContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) WaitContainer(ctx context.Context, params func(Conmon_waitContainer_Params) error) (Conmon_waitContainer_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      36,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "waitContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_waitContainer_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_waitContainer_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) Shutdown(ctx context.Context, params func(Conmon_shutdown_Params) error) (Conmon_shutdown_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      37,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "shutdown",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_shutdown_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_shutdown_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) ReloadConfig(ctx context.Context, params func(Conmon_reloadConfig_Params) error) (Conmon_reloadConfig_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      38,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "reloadConfig",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_reloadConfig_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_reloadConfig_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) ContainerStatus(ctx context.Context, params func(Conmon_containerStatus_Params) error) (Conmon_containerStatus_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      39,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "containerStatus",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_containerStatus_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_containerStatus_Results_Future{Future: ans.Future()}, release
+}
+func (c Conmon) StreamEvents(ctx context.Context, params func(Conmon_streamEvents_Params) error) (Conmon_streamEvents_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      40,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "streamEvents",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_streamEvents_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_streamEvents_Results_Future{Future: ans.Future()}, release
+}
+
+func (c Conmon) AddRef() Conmon {
+	return Conmon(capnp.Client(c).AddRef())
+}
+
+func (c Conmon) Release() {
+	capnp.Client(c).Release()
+}
+
+func (c Conmon) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Client(c).EncodeAsPtr(seg)
+}
+
+func (Conmon) DecodeFromPtr(p capnp.Ptr) Conmon {
+	return Conmon(capnp.Client{}.DecodeFromPtr(p))
+}
+
+func (c Conmon) IsValid() bool {
+	return capnp.Client(c).IsValid()
+}
+
+// A Conmon_Server is a Conmon with a local implementation.
+type Conmon_Server interface {
+	Version(context.Context, Conmon_version) error
+
+	CreateContainer(context.Context, Conmon_createContainer) error
+
+	ExecSyncContainer(context.Context, Conmon_execSyncContainer) error
+
+	AttachContainer(context.Context, Conmon_attachContainer) error
+
+	ReopenLogContainer(context.Context, Conmon_reopenLogContainer) error
+
+	SetWindowSizeContainer(context.Context, Conmon_setWindowSizeContainer) error
+
+	Negotiate(context.Context, Conmon_negotiate) error
+
+	AttachStreamContainer(context.Context, Conmon_attachStreamContainer) error
+
+	ListContainerStatuses(context.Context, Conmon_listContainerStatuses) error
+
+	GetEvents(context.Context, Conmon_getEvents) error
+
+	ListExecSessions(context.Context, Conmon_listExecSessions) error
+
+	ContainerIOStats(context.Context, Conmon_containerIOStats) error
+
+	SetLogLevel(context.Context, Conmon_setLogLevel) error
+
+	RemoveContainer(context.Context, Conmon_removeContainer) error
+
+	GetContainerLogOffset(context.Context, Conmon_getContainerLogOffset) error
+
+	FlushContainerLogs(context.Context, Conmon_flushContainerLogs) error
+
+	ValidateCreate(context.Context, Conmon_validateCreate) error
+
+	UpdateLogDrivers(context.Context, Conmon_updateLogDrivers) error
+
+	Health(context.Context, Conmon_health) error
+
+	SetTerminalModeContainer(context.Context, Conmon_setTerminalModeContainer) error
+
+	ReadExecOutput(context.Context, Conmon_readExecOutput) error
+
+	MirrorContainerIO(context.Context, Conmon_mirrorContainerIO) error
+
+	ContainerStats(context.Context, Conmon_containerStats) error
+
+	FreezeContainer(context.Context, Conmon_freezeContainer) error
+
+	ThawContainer(context.Context, Conmon_thawContainer) error
+
+	ContainerProcesses(context.Context, Conmon_containerProcesses) error
+
+	RequestLatencies(context.Context, Conmon_requestLatencies) error
+
+	KillContainer(context.Context, Conmon_killContainer) error
+
+	StartContainer(context.Context, Conmon_startContainer) error
+
+	ListContainers(context.Context, Conmon_listContainers) error
+
+	UpdateContainerResources(context.Context, Conmon_updateContainerResources) error
+
+	PauseContainer(context.Context, Conmon_pauseContainer) error
+
+	UnpauseContainer(context.Context, Conmon_unpauseContainer) error
+
+	CheckpointContainer(context.Context, Conmon_checkpointContainer) error
+
+	RestoreContainer(context.Context, Conmon_restoreContainer) error
+
+	ExecContainer(context.Context, Conmon_execContainer) error
+
+	WaitContainer(context.Context, Conmon_waitContainer) error
+
+	Shutdown(context.Context, Conmon_shutdown) error
+
+	ReloadConfig(context.Context, Conmon_reloadConfig) error
+
+	ContainerStatus(context.Context, Conmon_containerStatus) error
+
+	StreamEvents(context.Context, Conmon_streamEvents) error
+}
+
+// Conmon_NewServer creates a new Server from an implementation of Conmon_Server.
+func Conmon_NewServer(s Conmon_Server) *server.Server {
+	c, _ := s.(server.Shutdowner)
+	return server.New(Conmon_Methods(nil, s), s, c)
+}
+
+// Conmon_ServerToClient creates a new Client from an implementation of Conmon_Server.
+// The caller is responsible for calling Release on the returned Client.
+func Conmon_ServerToClient(s Conmon_Server) Conmon {
+	return Conmon(capnp.NewClient(Conmon_NewServer(s)))
+}
+
+// Conmon_Methods appends Methods to a slice that invoke the methods on s.
+// This can be used to create a more complicated Server.
+func Conmon_Methods(methods []server.Method, s Conmon_Server) []server.Method {
+	if cap(methods) == 0 {
+		methods = make([]server.Method, 0, 41)
+	}
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      0,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "version",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.Version(ctx, Conmon_version{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      1,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "createContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.CreateContainer(ctx, Conmon_createContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      2,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "execSyncContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ExecSyncContainer(ctx, Conmon_execSyncContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      3,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "attachContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.AttachContainer(ctx, Conmon_attachContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      4,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "reopenLogContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ReopenLogContainer(ctx, Conmon_reopenLogContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      5,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "setWindowSizeContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.SetWindowSizeContainer(ctx, Conmon_setWindowSizeContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      6,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "negotiate",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.Negotiate(ctx, Conmon_negotiate{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      7,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "attachStreamContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.AttachStreamContainer(ctx, Conmon_attachStreamContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      8,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "listContainerStatuses",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ListContainerStatuses(ctx, Conmon_listContainerStatuses{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      9,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "getEvents",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.GetEvents(ctx, Conmon_getEvents{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      10,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "listExecSessions",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ListExecSessions(ctx, Conmon_listExecSessions{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      11,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "containerIOStats",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ContainerIOStats(ctx, Conmon_containerIOStats{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      12,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "setLogLevel",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.SetLogLevel(ctx, Conmon_setLogLevel{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      13,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "removeContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.RemoveContainer(ctx, Conmon_removeContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      14,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "getContainerLogOffset",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.GetContainerLogOffset(ctx, Conmon_getContainerLogOffset{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      15,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "flushContainerLogs",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.FlushContainerLogs(ctx, Conmon_flushContainerLogs{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      16,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "validateCreate",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ValidateCreate(ctx, Conmon_validateCreate{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      17,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "updateLogDrivers",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.UpdateLogDrivers(ctx, Conmon_updateLogDrivers{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      18,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "health",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.Health(ctx, Conmon_health{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      19,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "setTerminalModeContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.SetTerminalModeContainer(ctx, Conmon_setTerminalModeContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      20,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "readExecOutput",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ReadExecOutput(ctx, Conmon_readExecOutput{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      21,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "mirrorContainerIO",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.MirrorContainerIO(ctx, Conmon_mirrorContainerIO{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      22,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "containerStats",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ContainerStats(ctx, Conmon_containerStats{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      23,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "freezeContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.FreezeContainer(ctx, Conmon_freezeContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      24,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "thawContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ThawContainer(ctx, Conmon_thawContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      25,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "containerProcesses",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ContainerProcesses(ctx, Conmon_containerProcesses{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      26,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "requestLatencies",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.RequestLatencies(ctx, Conmon_requestLatencies{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      27,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "killContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.KillContainer(ctx, Conmon_killContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      28,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "startContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.StartContainer(ctx, Conmon_startContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      29,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "listContainers",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ListContainers(ctx, Conmon_listContainers{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      30,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "updateContainerResources",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.UpdateContainerResources(ctx, Conmon_updateContainerResources{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      31,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "pauseContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.PauseContainer(ctx, Conmon_pauseContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      32,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "unpauseContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.UnpauseContainer(ctx, Conmon_unpauseContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      33,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "checkpointContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.CheckpointContainer(ctx, Conmon_checkpointContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      34,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "restoreContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.RestoreContainer(ctx, Conmon_restoreContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      35,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "execContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ExecContainer(ctx, Conmon_execContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      36,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "waitContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.WaitContainer(ctx, Conmon_waitContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      37,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "shutdown",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.Shutdown(ctx, Conmon_shutdown{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      38,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "reloadConfig",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ReloadConfig(ctx, Conmon_reloadConfig{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      39,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "containerStatus",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ContainerStatus(ctx, Conmon_containerStatus{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      40,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
+			MethodName:    "streamEvents",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.StreamEvents(ctx, Conmon_streamEvents{call})
+		},
+	})
+
+	return methods
+}
+
+// Conmon_version holds the state for a server call to Conmon.version.
+// See server.Call for documentation.
+type Conmon_version struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_version) Args() Conmon_version_Params {
+	return Conmon_version_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_version) AllocResults() (Conmon_version_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_version_Results(r), err
+}
+
+// Conmon_createContainer holds the state for a server call to Conmon.createContainer.
+// See server.Call for documentation.
+type Conmon_createContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_createContainer) Args() Conmon_createContainer_Params {
+	return Conmon_createContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_createContainer) AllocResults() (Conmon_createContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Results(r), err
+}
+
+// Conmon_execSyncContainer holds the state for a server call to Conmon.execSyncContainer.
+// See server.Call for documentation.
+type Conmon_execSyncContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_execSyncContainer) Args() Conmon_execSyncContainer_Params {
+	return Conmon_execSyncContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_execSyncContainer) AllocResults() (Conmon_execSyncContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Results(r), err
+}
+
+// Conmon_attachContainer holds the state for a server call to Conmon.attachContainer.
+// See server.Call for documentation.
+type Conmon_attachContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_attachContainer) Args() Conmon_attachContainer_Params {
+	return Conmon_attachContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_attachContainer) AllocResults() (Conmon_attachContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Results(r), err
+}
+
+// Conmon_reopenLogContainer holds the state for a server call to Conmon.reopenLogContainer.
+// See server.Call for documentation.
+type Conmon_reopenLogContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_reopenLogContainer) Args() Conmon_reopenLogContainer_Params {
+	return Conmon_reopenLogContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_reopenLogContainer) AllocResults() (Conmon_reopenLogContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Results(r), err
+}
+
+// Conmon_setWindowSizeContainer holds the state for a server call to Conmon.setWindowSizeContainer.
+// See server.Call for documentation.
+type Conmon_setWindowSizeContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_setWindowSizeContainer) Args() Conmon_setWindowSizeContainer_Params {
+	return Conmon_setWindowSizeContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_setWindowSizeContainer) AllocResults() (Conmon_setWindowSizeContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Results(r), err
+}
+
+// Conmon_negotiate holds the state for a server call to Conmon.negotiate.
+// See server.Call for documentation.
+type Conmon_negotiate struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_negotiate) Args() Conmon_negotiate_Params {
+	return Conmon_negotiate_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_negotiate) AllocResults() (Conmon_negotiate_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_negotiate_Results(r), err
+}
+
+// Conmon_attachStreamContainer holds the state for a server call to Conmon.attachStreamContainer.
+// See server.Call for documentation.
+type Conmon_attachStreamContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_attachStreamContainer) Args() Conmon_attachStreamContainer_Params {
+	return Conmon_attachStreamContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_attachStreamContainer) AllocResults() (Conmon_attachStreamContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachStreamContainer_Results(r), err
+}
+
+// Conmon_listContainerStatuses holds the state for a server call to Conmon.listContainerStatuses.
+// See server.Call for documentation.
+type Conmon_listContainerStatuses struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_listContainerStatuses) Args() Conmon_listContainerStatuses_Params {
+	return Conmon_listContainerStatuses_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_listContainerStatuses) AllocResults() (Conmon_listContainerStatuses_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainerStatuses_Results(r), err
+}
+
+// Conmon_getEvents holds the state for a server call to Conmon.getEvents.
+// See server.Call for documentation.
+type Conmon_getEvents struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_getEvents) Args() Conmon_getEvents_Params {
+	return Conmon_getEvents_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_getEvents) AllocResults() (Conmon_getEvents_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_getEvents_Results(r), err
+}
+
+// Conmon_listExecSessions holds the state for a server call to Conmon.listExecSessions.
+// See server.Call for documentation.
+type Conmon_listExecSessions struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_listExecSessions) Args() Conmon_listExecSessions_Params {
+	return Conmon_listExecSessions_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_listExecSessions) AllocResults() (Conmon_listExecSessions_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listExecSessions_Results(r), err
+}
+
+// Conmon_containerIOStats holds the state for a server call to Conmon.containerIOStats.
+// See server.Call for documentation.
+type Conmon_containerIOStats struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_containerIOStats) Args() Conmon_containerIOStats_Params {
+	return Conmon_containerIOStats_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_containerIOStats) AllocResults() (Conmon_containerIOStats_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerIOStats_Results(r), err
+}
+
+// Conmon_setLogLevel holds the state for a server call to Conmon.setLogLevel.
+// See server.Call for documentation.
+type Conmon_setLogLevel struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_setLogLevel) Args() Conmon_setLogLevel_Params {
+	return Conmon_setLogLevel_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_setLogLevel) AllocResults() (Conmon_setLogLevel_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setLogLevel_Results(r), err
+}
+
+// Conmon_removeContainer holds the state for a server call to Conmon.removeContainer.
+// See server.Call for documentation.
+type Conmon_removeContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_removeContainer) Args() Conmon_removeContainer_Params {
+	return Conmon_removeContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_removeContainer) AllocResults() (Conmon_removeContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_removeContainer_Results(r), err
+}
+
+// Conmon_getContainerLogOffset holds the state for a server call to Conmon.getContainerLogOffset.
+// See server.Call for documentation.
+type Conmon_getContainerLogOffset struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_getContainerLogOffset) Args() Conmon_getContainerLogOffset_Params {
+	return Conmon_getContainerLogOffset_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_getContainerLogOffset) AllocResults() (Conmon_getContainerLogOffset_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_getContainerLogOffset_Results(r), err
+}
+
+// Conmon_flushContainerLogs holds the state for a server call to Conmon.flushContainerLogs.
+// See server.Call for documentation.
+type Conmon_flushContainerLogs struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_flushContainerLogs) Args() Conmon_flushContainerLogs_Params {
+	return Conmon_flushContainerLogs_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_flushContainerLogs) AllocResults() (Conmon_flushContainerLogs_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_flushContainerLogs_Results(r), err
+}
+
+// Conmon_validateCreate holds the state for a server call to Conmon.validateCreate.
+// See server.Call for documentation.
+type Conmon_validateCreate struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_validateCreate) Args() Conmon_validateCreate_Params {
+	return Conmon_validateCreate_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_validateCreate) AllocResults() (Conmon_validateCreate_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_validateCreate_Results(r), err
+}
+
+// Conmon_updateLogDrivers holds the state for a server call to Conmon.updateLogDrivers.
+// See server.Call for documentation.
+type Conmon_updateLogDrivers struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_updateLogDrivers) Args() Conmon_updateLogDrivers_Params {
+	return Conmon_updateLogDrivers_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_updateLogDrivers) AllocResults() (Conmon_updateLogDrivers_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_updateLogDrivers_Results(r), err
+}
+
+// Conmon_health holds the state for a server call to Conmon.health.
+// See server.Call for documentation.
+type Conmon_health struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_health) Args() Conmon_health_Params {
+	return Conmon_health_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_health) AllocResults() (Conmon_health_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_health_Results(r), err
+}
+
+// Conmon_setTerminalModeContainer holds the state for a server call to Conmon.setTerminalModeContainer.
+// See server.Call for documentation.
+type Conmon_setTerminalModeContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_setTerminalModeContainer) Args() Conmon_setTerminalModeContainer_Params {
+	return Conmon_setTerminalModeContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_setTerminalModeContainer) AllocResults() (Conmon_setTerminalModeContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setTerminalModeContainer_Results(r), err
+}
+
+// Conmon_readExecOutput holds the state for a server call to Conmon.readExecOutput.
+// See server.Call for documentation.
+type Conmon_readExecOutput struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_readExecOutput) Args() Conmon_readExecOutput_Params {
+	return Conmon_readExecOutput_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_readExecOutput) AllocResults() (Conmon_readExecOutput_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_readExecOutput_Results(r), err
+}
+
+// Conmon_mirrorContainerIO holds the state for a server call to Conmon.mirrorContainerIO.
+// See server.Call for documentation.
+type Conmon_mirrorContainerIO struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_mirrorContainerIO) Args() Conmon_mirrorContainerIO_Params {
+	return Conmon_mirrorContainerIO_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_mirrorContainerIO) AllocResults() (Conmon_mirrorContainerIO_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_mirrorContainerIO_Results(r), err
+}
+
+// Conmon_containerStats holds the state for a server call to Conmon.containerStats.
+// See server.Call for documentation.
+type Conmon_containerStats struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_containerStats) Args() Conmon_containerStats_Params {
+	return Conmon_containerStats_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_containerStats) AllocResults() (Conmon_containerStats_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerStats_Results(r), err
+}
+
+// Conmon_freezeContainer holds the state for a server call to Conmon.freezeContainer.
+// See server.Call for documentation.
+type Conmon_freezeContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_freezeContainer) Args() Conmon_freezeContainer_Params {
+	return Conmon_freezeContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_freezeContainer) AllocResults() (Conmon_freezeContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_freezeContainer_Results(r), err
+}
+
+// Conmon_thawContainer holds the state for a server call to Conmon.thawContainer.
+// See server.Call for documentation.
+type Conmon_thawContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_thawContainer) Args() Conmon_thawContainer_Params {
+	return Conmon_thawContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_thawContainer) AllocResults() (Conmon_thawContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_thawContainer_Results(r), err
+}
+
+// Conmon_containerProcesses holds the state for a server call to Conmon.containerProcesses.
+// See server.Call for documentation.
+type Conmon_containerProcesses struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_containerProcesses) Args() Conmon_containerProcesses_Params {
+	return Conmon_containerProcesses_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_containerProcesses) AllocResults() (Conmon_containerProcesses_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerProcesses_Results(r), err
+}
+
+// Conmon_requestLatencies holds the state for a server call to Conmon.requestLatencies.
+// See server.Call for documentation.
+type Conmon_requestLatencies struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_requestLatencies) Args() Conmon_requestLatencies_Params {
+	return Conmon_requestLatencies_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_requestLatencies) AllocResults() (Conmon_requestLatencies_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_requestLatencies_Results(r), err
+}
+
+// Conmon_killContainer holds the state for a server call to Conmon.killContainer.
+// See server.Call for documentation.
+type Conmon_killContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_killContainer) Args() Conmon_killContainer_Params {
+	return Conmon_killContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_killContainer) AllocResults() (Conmon_killContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_killContainer_Results(r), err
+}
+
+// Conmon_startContainer holds the state for a server call to Conmon.startContainer.
+// See server.Call for documentation.
+type Conmon_startContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_startContainer) Args() Conmon_startContainer_Params {
+	return Conmon_startContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_startContainer) AllocResults() (Conmon_startContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_startContainer_Results(r), err
+}
+
+// Conmon_listContainers holds the state for a server call to Conmon.listContainers.
+// See server.Call for documentation.
+type Conmon_listContainers struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_listContainers) Args() Conmon_listContainers_Params {
+	return Conmon_listContainers_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_listContainers) AllocResults() (Conmon_listContainers_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainers_Results(r), err
+}
+
+// Conmon_updateContainerResources holds the state for a server call to Conmon.updateContainerResources.
+// See server.Call for documentation.
+type Conmon_updateContainerResources struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_updateContainerResources) Args() Conmon_updateContainerResources_Params {
+	return Conmon_updateContainerResources_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_updateContainerResources) AllocResults() (Conmon_updateContainerResources_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_updateContainerResources_Results(r), err
+}
+
+// Conmon_pauseContainer holds the state for a server call to Conmon.pauseContainer.
+// See server.Call for documentation.
+type Conmon_pauseContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_pauseContainer) Args() Conmon_pauseContainer_Params {
+	return Conmon_pauseContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_pauseContainer) AllocResults() (Conmon_pauseContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_pauseContainer_Results(r), err
+}
+
+// Conmon_unpauseContainer holds the state for a server call to Conmon.unpauseContainer.
+// See server.Call for documentation.
+type Conmon_unpauseContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_unpauseContainer) Args() Conmon_unpauseContainer_Params {
+	return Conmon_unpauseContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_unpauseContainer) AllocResults() (Conmon_unpauseContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_unpauseContainer_Results(r), err
+}
+
+// Conmon_checkpointContainer holds the state for a server call to Conmon.checkpointContainer.
+// See server.Call for documentation.
+type Conmon_checkpointContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_checkpointContainer) Args() Conmon_checkpointContainer_Params {
+	return Conmon_checkpointContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_checkpointContainer) AllocResults() (Conmon_checkpointContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_checkpointContainer_Results(r), err
+}
+
+// Conmon_restoreContainer holds the state for a server call to Conmon.restoreContainer.
+// See server.Call for documentation.
+type Conmon_restoreContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_restoreContainer) Args() Conmon_restoreContainer_Params {
+	return Conmon_restoreContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_restoreContainer) AllocResults() (Conmon_restoreContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_restoreContainer_Results(r), err
+}
+
+// Conmon_execContainer holds the state for a server call to Conmon.execContainer.
+// See server.Call for documentation.
+type Conmon_execContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_execContainer) Args() Conmon_execContainer_Params {
+	return Conmon_execContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_execContainer) AllocResults() (Conmon_execContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execContainer_Results(r), err
+}
+
+// Conmon_waitContainer holds the state for a server call to Conmon.waitContainer.
+// See server.Call for documentation.
+type Conmon_waitContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_waitContainer) Args() Conmon_waitContainer_Params {
+	return Conmon_waitContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_waitContainer) AllocResults() (Conmon_waitContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_waitContainer_Results(r), err
+}
+
+// Conmon_shutdown holds the state for a server call to Conmon.shutdown.
+// See server.Call for documentation.
+type Conmon_shutdown struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_shutdown) Args() Conmon_shutdown_Params {
+	return Conmon_shutdown_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_shutdown) AllocResults() (Conmon_shutdown_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_shutdown_Results(r), err
+}
+
+// Conmon_reloadConfig holds the state for a server call to Conmon.reloadConfig.
+// See server.Call for documentation.
+type Conmon_reloadConfig struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_reloadConfig) Args() Conmon_reloadConfig_Params {
+	return Conmon_reloadConfig_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_reloadConfig) AllocResults() (Conmon_reloadConfig_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reloadConfig_Results(r), err
+}
+
+// Conmon_containerStatus holds the state for a server call to Conmon.containerStatus.
+// See server.Call for documentation.
+type Conmon_containerStatus struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_containerStatus) Args() Conmon_containerStatus_Params {
+	return Conmon_containerStatus_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_containerStatus) AllocResults() (Conmon_containerStatus_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerStatus_Results(r), err
+}
+
+// Conmon_streamEvents holds the state for a server call to Conmon.streamEvents.
+// See server.Call for documentation.
+type Conmon_streamEvents struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_streamEvents) Args() Conmon_streamEvents_Params {
+	return Conmon_streamEvents_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_streamEvents) AllocResults() (Conmon_streamEvents_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_streamEvents_Results(r), err
+}
+
+// Conmon_List is a list of Conmon.
+type Conmon_List = capnp.CapList[Conmon]
+
+// NewConmon creates a new list of Conmon.
+func NewConmon_List(s *capnp.Segment, sz int32) (Conmon_List, error) {
+	l, err := capnp.NewPointerList(s, sz)
+	return capnp.CapList[Conmon](l), err
+}
+
+type Conmon_VersionResponse capnp.Struct
+
+// Conmon_VersionResponse_TypeID is the unique identifier for the type Conmon_VersionResponse.
+const Conmon_VersionResponse_TypeID = 0xf34be5cbac1feed1
+
+func NewConmon_VersionResponse(s *capnp.Segment) (Conmon_VersionResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
+	return Conmon_VersionResponse(st), err
+}
+
+func NewRootConmon_VersionResponse(s *capnp.Segment) (Conmon_VersionResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
+	return Conmon_VersionResponse(st), err
+}
+
+func ReadRootConmon_VersionResponse(msg *capnp.Message) (Conmon_VersionResponse, error) {
+	root, err := msg.Root()
+	return Conmon_VersionResponse(root.Struct()), err
+}
+
+func (s Conmon_VersionResponse) String() string {
+	str, _ := text.Marshal(0xf34be5cbac1feed1, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_VersionResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_VersionResponse) DecodeFromPtr(p capnp.Ptr) Conmon_VersionResponse {
+	return Conmon_VersionResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_VersionResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_VersionResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_VersionResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_VersionResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_VersionResponse) Version() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_VersionResponse) HasVersion() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_VersionResponse) VersionBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_VersionResponse) SetVersion(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_VersionResponse) Tag() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_VersionResponse) HasTag() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_VersionResponse) TagBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_VersionResponse) SetTag(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_VersionResponse) Commit() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_VersionResponse) HasCommit() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_VersionResponse) CommitBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_VersionResponse) SetCommit(v string) error {
+	return capnp.Struct(s).SetText(2, v)
+}
+
+func (s Conmon_VersionResponse) BuildDate() (string, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return p.Text(), err
+}
+
+func (s Conmon_VersionResponse) HasBuildDate() bool {
+	return capnp.Struct(s).HasPtr(3)
+}
+
+func (s Conmon_VersionResponse) BuildDateBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_VersionResponse) SetBuildDate(v string) error {
+	return capnp.Struct(s).SetText(3, v)
+}
+
+func (s Conmon_VersionResponse) RustVersion() (string, error) {
+	p, err := capnp.Struct(s).Ptr(4)
+	return p.Text(), err
+}
+
+func (s Conmon_VersionResponse) HasRustVersion() bool {
+	return capnp.Struct(s).HasPtr(4)
+}
+
+func (s Conmon_VersionResponse) RustVersionBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(4)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_VersionResponse) SetRustVersion(v string) error {
+	return capnp.Struct(s).SetText(4, v)
+}
+
+func (s Conmon_VersionResponse) ProcessId() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_VersionResponse) SetProcessId(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+// Conmon_VersionResponse_List is a list of Conmon_VersionResponse.
+type Conmon_VersionResponse_List = capnp.StructList[Conmon_VersionResponse]
+
+// NewConmon_VersionResponse creates a new list of Conmon_VersionResponse.
+func NewConmon_VersionResponse_List(s *capnp.Segment, sz int32) (Conmon_VersionResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5}, sz)
+	return capnp.StructList[Conmon_VersionResponse](l), err
+}
+
+// Conmon_VersionResponse_Future is a wrapper for a Conmon_VersionResponse promised by a client call.
+type Conmon_VersionResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_VersionResponse_Future) Struct() (Conmon_VersionResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_VersionResponse(s), err
+}
+
+type Conmon_CreateContainerRequest capnp.Struct
+
+// Conmon_CreateContainerRequest_TypeID is the unique identifier for the type Conmon_CreateContainerRequest.
+const Conmon_CreateContainerRequest_TypeID = 0xba77e3fa3aa9b6ca
+
+func NewConmon_CreateContainerRequest(s *capnp.Segment) (Conmon_CreateContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 12})
+	return Conmon_CreateContainerRequest(st), err
+}
+
+func NewRootConmon_CreateContainerRequest(s *capnp.Segment) (Conmon_CreateContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 12})
+	return Conmon_CreateContainerRequest(st), err
+}
+
+func ReadRootConmon_CreateContainerRequest(msg *capnp.Message) (Conmon_CreateContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_CreateContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_CreateContainerRequest) String() string {
+	str, _ := text.Marshal(0xba77e3fa3aa9b6ca, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_CreateContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_CreateContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_CreateContainerRequest {
+	return Conmon_CreateContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_CreateContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_CreateContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_CreateContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_CreateContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_CreateContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_CreateContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_CreateContainerRequest) BundlePath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateContainerRequest) HasBundlePath() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_CreateContainerRequest) BundlePathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateContainerRequest) SetBundlePath(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_CreateContainerRequest) Terminal() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_CreateContainerRequest) SetTerminal(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+func (s Conmon_CreateContainerRequest) ExitPaths() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_CreateContainerRequest) HasExitPaths() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_CreateContainerRequest) SetExitPaths(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+}
+
+// NewExitPaths sets the exitPaths field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_CreateContainerRequest) NewExitPaths(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_CreateContainerRequest) OomExitPaths() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_CreateContainerRequest) HasOomExitPaths() bool {
+	return capnp.Struct(s).HasPtr(3)
+}
+
+func (s Conmon_CreateContainerRequest) SetOomExitPaths(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(3, v.ToPtr())
+}
+
+// NewOomExitPaths sets the oomExitPaths field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_CreateContainerRequest) NewOomExitPaths(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(3, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_CreateContainerRequest) LogDrivers() (Conmon_LogDriver_List, error) {
+	p, err := capnp.Struct(s).Ptr(4)
+	return Conmon_LogDriver_List(p.List()), err
+}
+
+func (s Conmon_CreateContainerRequest) HasLogDrivers() bool {
+	return capnp.Struct(s).HasPtr(4)
+}
+
+func (s Conmon_CreateContainerRequest) SetLogDrivers(v Conmon_LogDriver_List) error {
+	return capnp.Struct(s).SetPtr(4, v.ToPtr())
+}
+
+// NewLogDrivers sets the logDrivers field to a newly
+// allocated Conmon_LogDriver_List, preferring placement in s's segment.
+func (s Conmon_CreateContainerRequest) NewLogDrivers(n int32) (Conmon_LogDriver_List, error) {
+	l, err := NewConmon_LogDriver_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_LogDriver_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(4, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_CreateContainerRequest) CleanupCmd() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(5)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_CreateContainerRequest) HasCleanupCmd() bool {
+	return capnp.Struct(s).HasPtr(5)
+}
+
+func (s Conmon_CreateContainerRequest) SetCleanupCmd(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(5, v.ToPtr())
+}
+
+// NewCleanupCmd sets the cleanupCmd field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_CreateContainerRequest) NewCleanupCmd(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(5, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_CreateContainerRequest) Name() (string, error) {
+	p, err := capnp.Struct(s).Ptr(6)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateContainerRequest) HasName() bool {
+	return capnp.Struct(s).HasPtr(6)
+}
+
+func (s Conmon_CreateContainerRequest) NameBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(6)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateContainerRequest) SetName(v string) error {
+	return capnp.Struct(s).SetText(6, v)
+}
+
+func (s Conmon_CreateContainerRequest) SerializeOutput() bool {
+	return capnp.Struct(s).Bit(1)
+}
+
+func (s Conmon_CreateContainerRequest) SetSerializeOutput(v bool) {
+	capnp.Struct(s).SetBit(1, v)
+}
+
+func (s Conmon_CreateContainerRequest) SuccessExitCodes() (capnp.Int32List, error) {
+	p, err := capnp.Struct(s).Ptr(7)
+	return capnp.Int32List(p.List()), err
+}
+
+func (s Conmon_CreateContainerRequest) HasSuccessExitCodes() bool {
+	return capnp.Struct(s).HasPtr(7)
+}
+
+func (s Conmon_CreateContainerRequest) SetSuccessExitCodes(v capnp.Int32List) error {
+	return capnp.Struct(s).SetPtr(7, v.ToPtr())
+}
+
+// NewSuccessExitCodes sets the successExitCodes field to a newly
+// allocated capnp.Int32List, preferring placement in s's segment.
+func (s Conmon_CreateContainerRequest) NewSuccessExitCodes(n int32) (capnp.Int32List, error) {
+	l, err := capnp.NewInt32List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.Int32List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(7, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_CreateContainerRequest) StdinPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(8)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateContainerRequest) HasStdinPath() bool {
+	return capnp.Struct(s).HasPtr(8)
+}
+
+func (s Conmon_CreateContainerRequest) StdinPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(8)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateContainerRequest) SetStdinPath(v string) error {
+	return capnp.Struct(s).SetText(8, v)
+}
+
+func (s Conmon_CreateContainerRequest) TeePath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(9)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateContainerRequest) HasTeePath() bool {
+	return capnp.Struct(s).HasPtr(9)
+}
+
+func (s Conmon_CreateContainerRequest) TeePathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(9)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateContainerRequest) SetTeePath(v string) error {
+	return capnp.Struct(s).SetText(9, v)
+}
+
+func (s Conmon_CreateContainerRequest) IdempotencyToken() (string, error) {
+	p, err := capnp.Struct(s).Ptr(10)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateContainerRequest) HasIdempotencyToken() bool {
+	return capnp.Struct(s).HasPtr(10)
+}
+
+func (s Conmon_CreateContainerRequest) IdempotencyTokenBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(10)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateContainerRequest) SetIdempotencyToken(v string) error {
+	return capnp.Struct(s).SetText(10, v)
+}
+
+func (s Conmon_CreateContainerRequest) PtySocketPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(11)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateContainerRequest) HasPtySocketPath() bool {
+	return capnp.Struct(s).HasPtr(11)
+}
+
+func (s Conmon_CreateContainerRequest) PtySocketPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(11)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateContainerRequest) SetPtySocketPath(v string) error {
+	return capnp.Struct(s).SetText(11, v)
+}
+
+// Conmon_CreateContainerRequest_List is a list of Conmon_CreateContainerRequest.
+type Conmon_CreateContainerRequest_List = capnp.StructList[Conmon_CreateContainerRequest]
+
+// NewConmon_CreateContainerRequest creates a new list of Conmon_CreateContainerRequest.
+func NewConmon_CreateContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_CreateContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 12}, sz)
+	return capnp.StructList[Conmon_CreateContainerRequest](l), err
+}
+
+// Conmon_CreateContainerRequest_Future is a wrapper for a Conmon_CreateContainerRequest promised by a client call.
+type Conmon_CreateContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_CreateContainerRequest_Future) Struct() (Conmon_CreateContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_CreateContainerRequest(s), err
+}
+
+type Conmon_LogDriver capnp.Struct
+
+// Conmon_LogDriver_TypeID is the unique identifier for the type Conmon_LogDriver.
+const Conmon_LogDriver_TypeID = 0xae78ee8eb6b3a134
+
+func NewConmon_LogDriver(s *capnp.Segment) (Conmon_LogDriver, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 2})
+	return Conmon_LogDriver(st), err
+}
+
+func NewRootConmon_LogDriver(s *capnp.Segment) (Conmon_LogDriver, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 2})
+	return Conmon_LogDriver(st), err
+}
+
+func ReadRootConmon_LogDriver(msg *capnp.Message) (Conmon_LogDriver, error) {
+	root, err := msg.Root()
+	return Conmon_LogDriver(root.Struct()), err
+}
+
+func (s Conmon_LogDriver) String() string {
+	str, _ := text.Marshal(0xae78ee8eb6b3a134, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_LogDriver) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_LogDriver) DecodeFromPtr(p capnp.Ptr) Conmon_LogDriver {
+	return Conmon_LogDriver(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_LogDriver) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_LogDriver) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_LogDriver) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_LogDriver) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_LogDriver) Type() Conmon_LogDriver_Type {
+	return Conmon_LogDriver_Type(capnp.Struct(s).Uint16(0))
+}
+
+func (s Conmon_LogDriver) SetType(v Conmon_LogDriver_Type) {
+	capnp.Struct(s).SetUint16(0, uint16(v))
+}
+
+func (s Conmon_LogDriver) Path() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_LogDriver) HasPath() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_LogDriver) PathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_LogDriver) SetPath(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_LogDriver) MaxSize() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_LogDriver) SetMaxSize(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_LogDriver) PodId() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_LogDriver) HasPodId() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_LogDriver) PodIdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_LogDriver) SetPodId(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_LogDriver) TimestampFormat() Conmon_LogDriver_TimestampFormat {
+	return Conmon_LogDriver_TimestampFormat(capnp.Struct(s).Uint16(2))
+}
+
+func (s Conmon_LogDriver) SetTimestampFormat(v Conmon_LogDriver_TimestampFormat) {
+	capnp.Struct(s).SetUint16(2, uint16(v))
+}
+
+func (s Conmon_LogDriver) TimestampUtc() bool {
+	return capnp.Struct(s).Bit(32)
+}
+
+func (s Conmon_LogDriver) SetTimestampUtc(v bool) {
+	capnp.Struct(s).SetBit(32, v)
+}
+
+// Conmon_LogDriver_List is a list of Conmon_LogDriver.
+type Conmon_LogDriver_List = capnp.StructList[Conmon_LogDriver]
+
+// NewConmon_LogDriver creates a new list of Conmon_LogDriver.
+func NewConmon_LogDriver_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_LogDriver](l), err
+}
+
+// Conmon_LogDriver_Future is a wrapper for a Conmon_LogDriver promised by a client call.
+type Conmon_LogDriver_Future struct{ *capnp.Future }
+
+func (p Conmon_LogDriver_Future) Struct() (Conmon_LogDriver, error) {
+	s, err := p.Future.Struct()
+	return Conmon_LogDriver(s), err
+}
+
+type Conmon_LogDriver_Type uint16
+
+// Conmon_LogDriver_Type_TypeID is the unique identifier for the type Conmon_LogDriver_Type.
+const Conmon_LogDriver_Type_TypeID = 0xf026e3d750335bc1
+
+// Values of Conmon_LogDriver_Type.
+const (
+	Conmon_LogDriver_Type_containerRuntimeInterface Conmon_LogDriver_Type = 0
+	Conmon_LogDriver_Type_pod                       Conmon_LogDriver_Type = 1
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_Type) String() string {
+	switch c {
+	case Conmon_LogDriver_Type_containerRuntimeInterface:
+		return "containerRuntimeInterface"
+	case Conmon_LogDriver_Type_pod:
+		return "pod"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_TypeFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_TypeFromString(c string) Conmon_LogDriver_Type {
+	switch c {
+	case "containerRuntimeInterface":
+		return Conmon_LogDriver_Type_containerRuntimeInterface
+	case "pod":
+		return Conmon_LogDriver_Type_pod
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_Type_List = capnp.EnumList[Conmon_LogDriver_Type]
+
+func NewConmon_LogDriver_Type_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_Type_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_Type](s, sz)
+}
+
+type Conmon_LogDriver_TimestampFormat uint16
+
+// Conmon_LogDriver_TimestampFormat_TypeID is the unique identifier for the type Conmon_LogDriver_TimestampFormat.
+const Conmon_LogDriver_TimestampFormat_TypeID = 0x8ab9d39681015734
+
+// Values of Conmon_LogDriver_TimestampFormat.
+const (
+	Conmon_LogDriver_TimestampFormat_rfc3339Nano Conmon_LogDriver_TimestampFormat = 0
+	Conmon_LogDriver_TimestampFormat_epochNanos  Conmon_LogDriver_TimestampFormat = 1
+	Conmon_LogDriver_TimestampFormat_none        Conmon_LogDriver_TimestampFormat = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_TimestampFormat) String() string {
+	switch c {
+	case Conmon_LogDriver_TimestampFormat_rfc3339Nano:
+		return "rfc3339Nano"
+	case Conmon_LogDriver_TimestampFormat_epochNanos:
+		return "epochNanos"
+	case Conmon_LogDriver_TimestampFormat_none:
+		return "none"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_TimestampFormatFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_TimestampFormatFromString(c string) Conmon_LogDriver_TimestampFormat {
+	switch c {
+	case "rfc3339Nano":
+		return Conmon_LogDriver_TimestampFormat_rfc3339Nano
+	case "epochNanos":
+		return Conmon_LogDriver_TimestampFormat_epochNanos
+	case "none":
+		return Conmon_LogDriver_TimestampFormat_none
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_TimestampFormat_List = capnp.EnumList[Conmon_LogDriver_TimestampFormat]
+
+func NewConmon_LogDriver_TimestampFormat_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_TimestampFormat_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_TimestampFormat](s, sz)
+}
+
+type Conmon_CreateTimings capnp.Struct
+
+// Conmon_CreateTimings_TypeID is the unique identifier for the type Conmon_CreateTimings.
+const Conmon_CreateTimings_TypeID = 0xb3c3d48e8891ae7a
+
+func NewConmon_CreateTimings(s *capnp.Segment) (Conmon_CreateTimings, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 40, PointerCount: 0})
+	return Conmon_CreateTimings(st), err
+}
+
+func NewRootConmon_CreateTimings(s *capnp.Segment) (Conmon_CreateTimings, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 40, PointerCount: 0})
+	return Conmon_CreateTimings(st), err
+}
+
+func ReadRootConmon_CreateTimings(msg *capnp.Message) (Conmon_CreateTimings, error) {
+	root, err := msg.Root()
+	return Conmon_CreateTimings(root.Struct()), err
+}
+
+func (s Conmon_CreateTimings) String() string {
+	str, _ := text.Marshal(0xb3c3d48e8891ae7a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_CreateTimings) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_CreateTimings) DecodeFromPtr(p capnp.Ptr) Conmon_CreateTimings {
+	return Conmon_CreateTimings(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_CreateTimings) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_CreateTimings) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_CreateTimings) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_CreateTimings) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_CreateTimings) RuntimeSpawnMicros() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_CreateTimings) SetRuntimeSpawnMicros(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_CreateTimings) ConsoleHandshakeMicros() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_CreateTimings) SetConsoleHandshakeMicros(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_CreateTimings) RuntimeMicros() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_CreateTimings) SetRuntimeMicros(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+func (s Conmon_CreateTimings) PidfileWaitMicros() uint64 {
+	return capnp.Struct(s).Uint64(24)
+}
+
+func (s Conmon_CreateTimings) SetPidfileWaitMicros(v uint64) {
+	capnp.Struct(s).SetUint64(24, v)
+}
+
+func (s Conmon_CreateTimings) LogInitMicros() uint64 {
+	return capnp.Struct(s).Uint64(32)
+}
+
+func (s Conmon_CreateTimings) SetLogInitMicros(v uint64) {
+	capnp.Struct(s).SetUint64(32, v)
+}
+
+// Conmon_CreateTimings_List is a list of Conmon_CreateTimings.
+type Conmon_CreateTimings_List = capnp.StructList[Conmon_CreateTimings]
+
+// NewConmon_CreateTimings creates a new list of Conmon_CreateTimings.
+func NewConmon_CreateTimings_List(s *capnp.Segment, sz int32) (Conmon_CreateTimings_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 40, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_CreateTimings](l), err
+}
+
+// Conmon_CreateTimings_Future is a wrapper for a Conmon_CreateTimings promised by a client call.
+type Conmon_CreateTimings_Future struct{ *capnp.Future }
+
+func (p Conmon_CreateTimings_Future) Struct() (Conmon_CreateTimings, error) {
+	s, err := p.Future.Struct()
+	return Conmon_CreateTimings(s), err
+}
+
+type Conmon_CreateContainerResponse capnp.Struct
+
+// Conmon_CreateContainerResponse_TypeID is the unique identifier for the type Conmon_CreateContainerResponse.
+const Conmon_CreateContainerResponse_TypeID = 0xde3a625e70772b9a
+
+func NewConmon_CreateContainerResponse(s *capnp.Segment) (Conmon_CreateContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_CreateContainerResponse(st), err
+}
+
+func NewRootConmon_CreateContainerResponse(s *capnp.Segment) (Conmon_CreateContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_CreateContainerResponse(st), err
+}
+
+func ReadRootConmon_CreateContainerResponse(msg *capnp.Message) (Conmon_CreateContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_CreateContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_CreateContainerResponse) String() string {
+	str, _ := text.Marshal(0xde3a625e70772b9a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_CreateContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_CreateContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_CreateContainerResponse {
+	return Conmon_CreateContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_CreateContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_CreateContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_CreateContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_CreateContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_CreateContainerResponse) ContainerPid() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_CreateContainerResponse) SetContainerPid(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+func (s Conmon_CreateContainerResponse) Timings() (Conmon_CreateTimings, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_CreateTimings(p.Struct()), err
+}
+
+func (s Conmon_CreateContainerResponse) HasTimings() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_CreateContainerResponse) SetTimings(v Conmon_CreateTimings) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewTimings sets the timings field to a newly
+// allocated Conmon_CreateTimings struct, preferring placement in s's segment.
+func (s Conmon_CreateContainerResponse) NewTimings() (Conmon_CreateTimings, error) {
+	ss, err := NewConmon_CreateTimings(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_CreateTimings{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_CreateContainerResponse_List is a list of Conmon_CreateContainerResponse.
+type Conmon_CreateContainerResponse_List = capnp.StructList[Conmon_CreateContainerResponse]
+
+// NewConmon_CreateContainerResponse creates a new list of Conmon_CreateContainerResponse.
+func NewConmon_CreateContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_CreateContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_CreateContainerResponse](l), err
+}
+
+// Conmon_CreateContainerResponse_Future is a wrapper for a Conmon_CreateContainerResponse promised by a client call.
+type Conmon_CreateContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_CreateContainerResponse_Future) Struct() (Conmon_CreateContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_CreateContainerResponse(s), err
+}
+
+func (p Conmon_CreateContainerResponse_Future) Timings() Conmon_CreateTimings_Future {
+	return Conmon_CreateTimings_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_ExecSyncContainerRequest capnp.Struct
+
+// Conmon_ExecSyncContainerRequest_TypeID is the unique identifier for the type Conmon_ExecSyncContainerRequest.
+const Conmon_ExecSyncContainerRequest_TypeID = 0xf41122f890a371a6
+
+func NewConmon_ExecSyncContainerRequest(s *capnp.Segment) (Conmon_ExecSyncContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 4})
+	return Conmon_ExecSyncContainerRequest(st), err
+}
+
+func NewRootConmon_ExecSyncContainerRequest(s *capnp.Segment) (Conmon_ExecSyncContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 4})
+	return Conmon_ExecSyncContainerRequest(st), err
+}
+
+func ReadRootConmon_ExecSyncContainerRequest(msg *capnp.Message) (Conmon_ExecSyncContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ExecSyncContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) String() string {
+	str, _ := text.Marshal(0xf41122f890a371a6, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ExecSyncContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ExecSyncContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ExecSyncContainerRequest {
+	return Conmon_ExecSyncContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ExecSyncContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ExecSyncContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ExecSyncContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ExecSyncContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ExecSyncContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ExecSyncContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ExecSyncContainerRequest) TimeoutSec() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetTimeoutSec(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_ExecSyncContainerRequest) Command() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasCommand() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetCommand(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewCommand sets the command field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_ExecSyncContainerRequest) NewCommand(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_ExecSyncContainerRequest) Terminal() bool {
+	return capnp.Struct(s).Bit(64)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetTerminal(v bool) {
+	capnp.Struct(s).SetBit(64, v)
+}
+
+func (s Conmon_ExecSyncContainerRequest) PidfdSocketPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasPidfdSocketPath() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_ExecSyncContainerRequest) PidfdSocketPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetPidfdSocketPath(v string) error {
+	return capnp.Struct(s).SetText(2, v)
+}
+
+func (s Conmon_ExecSyncContainerRequest) IdempotencyToken() (string, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return p.Text(), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasIdempotencyToken() bool {
+	return capnp.Struct(s).HasPtr(3)
+}
+
+func (s Conmon_ExecSyncContainerRequest) IdempotencyTokenBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetIdempotencyToken(v string) error {
+	return capnp.Struct(s).SetText(3, v)
+}
+
+func (s Conmon_ExecSyncContainerRequest) MaxInlineOutput() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetMaxInlineOutput(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+// Conmon_ExecSyncContainerRequest_List is a list of Conmon_ExecSyncContainerRequest.
+type Conmon_ExecSyncContainerRequest_List = capnp.StructList[Conmon_ExecSyncContainerRequest]
+
+// NewConmon_ExecSyncContainerRequest creates a new list of Conmon_ExecSyncContainerRequest.
+func NewConmon_ExecSyncContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_ExecSyncContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 24, PointerCount: 4}, sz)
+	return capnp.StructList[Conmon_ExecSyncContainerRequest](l), err
+}
+
+// Conmon_ExecSyncContainerRequest_Future is a wrapper for a Conmon_ExecSyncContainerRequest promised by a client call.
+type Conmon_ExecSyncContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ExecSyncContainerRequest_Future) Struct() (Conmon_ExecSyncContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ExecSyncContainerRequest(s), err
+}
+
+type Conmon_ExecSyncContainerResponse capnp.Struct
+
+// Conmon_ExecSyncContainerResponse_TypeID is the unique identifier for the type Conmon_ExecSyncContainerResponse.
+const Conmon_ExecSyncContainerResponse_TypeID = 0xd9d61d1d803c85fc
+
+func NewConmon_ExecSyncContainerResponse(s *capnp.Segment) (Conmon_ExecSyncContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 3})
+	return Conmon_ExecSyncContainerResponse(st), err
+}
+
+func NewRootConmon_ExecSyncContainerResponse(s *capnp.Segment) (Conmon_ExecSyncContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 3})
+	return Conmon_ExecSyncContainerResponse(st), err
+}
+
+func ReadRootConmon_ExecSyncContainerResponse(msg *capnp.Message) (Conmon_ExecSyncContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ExecSyncContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_ExecSyncContainerResponse) String() string {
+	str, _ := text.Marshal(0xd9d61d1d803c85fc, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ExecSyncContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ExecSyncContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ExecSyncContainerResponse {
+	return Conmon_ExecSyncContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ExecSyncContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ExecSyncContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ExecSyncContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ExecSyncContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ExecSyncContainerResponse) ExitCode() int32 {
+	return int32(capnp.Struct(s).Uint32(0))
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetExitCode(v int32) {
+	capnp.Struct(s).SetUint32(0, uint32(v))
+}
+
+func (s Conmon_ExecSyncContainerResponse) Stdout() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_ExecSyncContainerResponse) HasStdout() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetStdout(v []byte) error {
+	return capnp.Struct(s).SetData(0, v)
+}
+
+func (s Conmon_ExecSyncContainerResponse) Stderr() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_ExecSyncContainerResponse) HasStderr() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetStderr(v []byte) error {
+	return capnp.Struct(s).SetData(1, v)
+}
+
+func (s Conmon_ExecSyncContainerResponse) TimedOut() bool {
+	return capnp.Struct(s).Bit(32)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetTimedOut(v bool) {
+	capnp.Struct(s).SetBit(32, v)
+}
+
+func (s Conmon_ExecSyncContainerResponse) Pid() uint32 {
+	return capnp.Struct(s).Uint32(8)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetPid(v uint32) {
+	capnp.Struct(s).SetUint32(8, v)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SessionId() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_ExecSyncContainerResponse) HasSessionId() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SessionIdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetSessionId(v string) error {
+	return capnp.Struct(s).SetText(2, v)
+}
+
+func (s Conmon_ExecSyncContainerResponse) StdoutSize() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetStdoutSize(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+func (s Conmon_ExecSyncContainerResponse) StderrSize() uint64 {
+	return capnp.Struct(s).Uint64(24)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetStderrSize(v uint64) {
+	capnp.Struct(s).SetUint64(24, v)
+}
+
+func (s Conmon_ExecSyncContainerResponse) OutputStored() bool {
+	return capnp.Struct(s).Bit(33)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetOutputStored(v bool) {
+	capnp.Struct(s).SetBit(33, v)
+}
+
+// Conmon_ExecSyncContainerResponse_List is a list of Conmon_ExecSyncContainerResponse.
+type Conmon_ExecSyncContainerResponse_List = capnp.StructList[Conmon_ExecSyncContainerResponse]
+
+// NewConmon_ExecSyncContainerResponse creates a new list of Conmon_ExecSyncContainerResponse.
+func NewConmon_ExecSyncContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_ExecSyncContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 32, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_ExecSyncContainerResponse](l), err
+}
+
+// Conmon_ExecSyncContainerResponse_Future is a wrapper for a Conmon_ExecSyncContainerResponse promised by a client call.
+type Conmon_ExecSyncContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ExecSyncContainerResponse_Future) Struct() (Conmon_ExecSyncContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ExecSyncContainerResponse(s), err
+}
+
+type Conmon_AttachRequest capnp.Struct
+
+// Conmon_AttachRequest_TypeID is the unique identifier for the type Conmon_AttachRequest.
+const Conmon_AttachRequest_TypeID = 0xdf703ca0befc3afc
+
+func NewConmon_AttachRequest(s *capnp.Segment) (Conmon_AttachRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_AttachRequest(st), err
+}
+
+func NewRootConmon_AttachRequest(s *capnp.Segment) (Conmon_AttachRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_AttachRequest(st), err
+}
+
+func ReadRootConmon_AttachRequest(msg *capnp.Message) (Conmon_AttachRequest, error) {
+	root, err := msg.Root()
+	return Conmon_AttachRequest(root.Struct()), err
+}
+
+func (s Conmon_AttachRequest) String() string {
+	str, _ := text.Marshal(0xdf703ca0befc3afc, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_AttachRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_AttachRequest) DecodeFromPtr(p capnp.Ptr) Conmon_AttachRequest {
+	return Conmon_AttachRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_AttachRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_AttachRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_AttachRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_AttachRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_AttachRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_AttachRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_AttachRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AttachRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_AttachRequest) SocketPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_AttachRequest) HasSocketPath() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_AttachRequest) SocketPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AttachRequest) SetSocketPath(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_AttachRequest) ExecSessionId() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_AttachRequest) HasExecSessionId() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_AttachRequest) ExecSessionIdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AttachRequest) SetExecSessionId(v string) error {
+	return capnp.Struct(s).SetText(2, v)
+}
+
+func (s Conmon_AttachRequest) ProtocolVersion() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_AttachRequest) SetProtocolVersion(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+func (s Conmon_AttachRequest) ClientEncoding() Conmon_AttachRequest_Encoding {
+	return Conmon_AttachRequest_Encoding(capnp.Struct(s).Uint16(4))
+}
+
+func (s Conmon_AttachRequest) SetClientEncoding(v Conmon_AttachRequest_Encoding) {
+	capnp.Struct(s).SetUint16(4, uint16(v))
+}
+
+func (s Conmon_AttachRequest) ContainerEncoding() Conmon_AttachRequest_Encoding {
+	return Conmon_AttachRequest_Encoding(capnp.Struct(s).Uint16(6))
+}
+
+func (s Conmon_AttachRequest) SetContainerEncoding(v Conmon_AttachRequest_Encoding) {
+	capnp.Struct(s).SetUint16(6, uint16(v))
+}
+
+// Conmon_AttachRequest_List is a list of Conmon_AttachRequest.
+type Conmon_AttachRequest_List = capnp.StructList[Conmon_AttachRequest]
+
+// NewConmon_AttachRequest creates a new list of Conmon_AttachRequest.
+func NewConmon_AttachRequest_List(s *capnp.Segment, sz int32) (Conmon_AttachRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_AttachRequest](l), err
+}
+
+// Conmon_AttachRequest_Future is a wrapper for a Conmon_AttachRequest promised by a client call.
+type Conmon_AttachRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_AttachRequest_Future) Struct() (Conmon_AttachRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_AttachRequest(s), err
+}
+
+type Conmon_AttachRequest_Encoding uint16
+
+// Conmon_AttachRequest_Encoding_TypeID is the unique identifier for the type Conmon_AttachRequest_Encoding.
+const Conmon_AttachRequest_Encoding_TypeID = 0xf769e31c9771e7c7
+
+// Values of Conmon_AttachRequest_Encoding.
+const (
+	Conmon_AttachRequest_Encoding_passthrough Conmon_AttachRequest_Encoding = 0
+	Conmon_AttachRequest_Encoding_utf8        Conmon_AttachRequest_Encoding = 1
+	Conmon_AttachRequest_Encoding_latin1      Conmon_AttachRequest_Encoding = 2
+	Conmon_AttachRequest_Encoding_ascii       Conmon_AttachRequest_Encoding = 3
+)
+
+// String returns the enum's constant name.
+func (c Conmon_AttachRequest_Encoding) String() string {
+	switch c {
+	case Conmon_AttachRequest_Encoding_passthrough:
+		return "passthrough"
+	case Conmon_AttachRequest_Encoding_utf8:
+		return "utf8"
+	case Conmon_AttachRequest_Encoding_latin1:
+		return "latin1"
+	case Conmon_AttachRequest_Encoding_ascii:
+		return "ascii"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_AttachRequest_EncodingFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_AttachRequest_EncodingFromString(c string) Conmon_AttachRequest_Encoding {
+	switch c {
+	case "passthrough":
+		return Conmon_AttachRequest_Encoding_passthrough
+	case "utf8":
+		return Conmon_AttachRequest_Encoding_utf8
+	case "latin1":
+		return Conmon_AttachRequest_Encoding_latin1
+	case "ascii":
+		return Conmon_AttachRequest_Encoding_ascii
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_AttachRequest_Encoding_List = capnp.EnumList[Conmon_AttachRequest_Encoding]
+
+func NewConmon_AttachRequest_Encoding_List(s *capnp.Segment, sz int32) (Conmon_AttachRequest_Encoding_List, error) {
+	return capnp.NewEnumList[Conmon_AttachRequest_Encoding](s, sz)
+}
+
+type Conmon_AttachResponse capnp.Struct
+
+// Conmon_AttachResponse_TypeID is the unique identifier for the type Conmon_AttachResponse.
+const Conmon_AttachResponse_TypeID = 0xace5517aafc86077
+
+func NewConmon_AttachResponse(s *capnp.Segment) (Conmon_AttachResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_AttachResponse(st), err
+}
+
+func NewRootConmon_AttachResponse(s *capnp.Segment) (Conmon_AttachResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_AttachResponse(st), err
+}
+
+func ReadRootConmon_AttachResponse(msg *capnp.Message) (Conmon_AttachResponse, error) {
+	root, err := msg.Root()
+	return Conmon_AttachResponse(root.Struct()), err
+}
+
+func (s Conmon_AttachResponse) String() string {
+	str, _ := text.Marshal(0xace5517aafc86077, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_AttachResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_AttachResponse) DecodeFromPtr(p capnp.Ptr) Conmon_AttachResponse {
+	return Conmon_AttachResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_AttachResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_AttachResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_AttachResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_AttachResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_AttachResponse) ProtocolVersion() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_AttachResponse) SetProtocolVersion(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+// Conmon_AttachResponse_List is a list of Conmon_AttachResponse.
+type Conmon_AttachResponse_List = capnp.StructList[Conmon_AttachResponse]
+
+// NewConmon_AttachResponse creates a new list of Conmon_AttachResponse.
+func NewConmon_AttachResponse_List(s *capnp.Segment, sz int32) (Conmon_AttachResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_AttachResponse](l), err
+}
+
+// Conmon_AttachResponse_Future is a wrapper for a Conmon_AttachResponse promised by a client call.
+type Conmon_AttachResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_AttachResponse_Future) Struct() (Conmon_AttachResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_AttachResponse(s), err
+}
+
+type Conmon_ReopenLogRequest capnp.Struct
+
+// Conmon_ReopenLogRequest_TypeID is the unique identifier for the type Conmon_ReopenLogRequest.
+const Conmon_ReopenLogRequest_TypeID = 0xd0476e0f34d1411a
+
+func NewConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ReopenLogRequest(st), err
+}
+
+func NewRootConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ReopenLogRequest(st), err
+}
+
+func ReadRootConmon_ReopenLogRequest(msg *capnp.Message) (Conmon_ReopenLogRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ReopenLogRequest(root.Struct()), err
+}
+
+func (s Conmon_ReopenLogRequest) String() string {
+	str, _ := text.Marshal(0xd0476e0f34d1411a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ReopenLogRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ReopenLogRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ReopenLogRequest {
+	return Conmon_ReopenLogRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ReopenLogRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ReopenLogRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ReopenLogRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ReopenLogRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ReopenLogRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ReopenLogRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ReopenLogRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReopenLogRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_ReopenLogRequest_List is a list of Conmon_ReopenLogRequest.
+type Conmon_ReopenLogRequest_List = capnp.StructList[Conmon_ReopenLogRequest]
+
+// NewConmon_ReopenLogRequest creates a new list of Conmon_ReopenLogRequest.
+func NewConmon_ReopenLogRequest_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ReopenLogRequest](l), err
+}
+
+// Conmon_ReopenLogRequest_Future is a wrapper for a Conmon_ReopenLogRequest promised by a client call.
+type Conmon_ReopenLogRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ReopenLogRequest_Future) Struct() (Conmon_ReopenLogRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ReopenLogRequest(s), err
+}
+
+type Conmon_ReopenLogResponse capnp.Struct
+
+// Conmon_ReopenLogResponse_TypeID is the unique identifier for the type Conmon_ReopenLogResponse.
+const Conmon_ReopenLogResponse_TypeID = 0xa20f49456be85b99
+
+func NewConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ReopenLogResponse(st), err
+}
+
+func NewRootConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ReopenLogResponse(st), err
+}
+
+func ReadRootConmon_ReopenLogResponse(msg *capnp.Message) (Conmon_ReopenLogResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ReopenLogResponse(root.Struct()), err
+}
+
+func (s Conmon_ReopenLogResponse) String() string {
+	str, _ := text.Marshal(0xa20f49456be85b99, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ReopenLogResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ReopenLogResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ReopenLogResponse {
+	return Conmon_ReopenLogResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ReopenLogResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ReopenLogResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ReopenLogResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ReopenLogResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_ReopenLogResponse_List is a list of Conmon_ReopenLogResponse.
+type Conmon_ReopenLogResponse_List = capnp.StructList[Conmon_ReopenLogResponse]
+
+// NewConmon_ReopenLogResponse creates a new list of Conmon_ReopenLogResponse.
+func NewConmon_ReopenLogResponse_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ReopenLogResponse](l), err
+}
+
+// Conmon_ReopenLogResponse_Future is a wrapper for a Conmon_ReopenLogResponse promised by a client call.
+type Conmon_ReopenLogResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ReopenLogResponse_Future) Struct() (Conmon_ReopenLogResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ReopenLogResponse(s), err
+}
+
+type Conmon_SetWindowSizeRequest capnp.Struct
+
+// Conmon_SetWindowSizeRequest_TypeID is the unique identifier for the type Conmon_SetWindowSizeRequest.
+const Conmon_SetWindowSizeRequest_TypeID = 0xb5418b8ea8ead17b
+
+func NewConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_SetWindowSizeRequest(st), err
+}
+
+func NewRootConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_SetWindowSizeRequest(st), err
+}
+
+func ReadRootConmon_SetWindowSizeRequest(msg *capnp.Message) (Conmon_SetWindowSizeRequest, error) {
+	root, err := msg.Root()
+	return Conmon_SetWindowSizeRequest(root.Struct()), err
+}
+
+func (s Conmon_SetWindowSizeRequest) String() string {
+	str, _ := text.Marshal(0xb5418b8ea8ead17b, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_SetWindowSizeRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_SetWindowSizeRequest) DecodeFromPtr(p capnp.Ptr) Conmon_SetWindowSizeRequest {
+	return Conmon_SetWindowSizeRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_SetWindowSizeRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_SetWindowSizeRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_SetWindowSizeRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_SetWindowSizeRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_SetWindowSizeRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_SetWindowSizeRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_SetWindowSizeRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_SetWindowSizeRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_SetWindowSizeRequest) Width() uint16 {
+	return capnp.Struct(s).Uint16(0)
+}
+
+func (s Conmon_SetWindowSizeRequest) SetWidth(v uint16) {
+	capnp.Struct(s).SetUint16(0, v)
+}
+
+func (s Conmon_SetWindowSizeRequest) Height() uint16 {
+	return capnp.Struct(s).Uint16(2)
+}
+
+func (s Conmon_SetWindowSizeRequest) SetHeight(v uint16) {
+	capnp.Struct(s).SetUint16(2, v)
+}
+
+// Conmon_SetWindowSizeRequest_List is a list of Conmon_SetWindowSizeRequest.
+type Conmon_SetWindowSizeRequest_List = capnp.StructList[Conmon_SetWindowSizeRequest]
+
+// NewConmon_SetWindowSizeRequest creates a new list of Conmon_SetWindowSizeRequest.
+func NewConmon_SetWindowSizeRequest_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_SetWindowSizeRequest](l), err
+}
+
+// Conmon_SetWindowSizeRequest_Future is a wrapper for a Conmon_SetWindowSizeRequest promised by a client call.
+type Conmon_SetWindowSizeRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_SetWindowSizeRequest_Future) Struct() (Conmon_SetWindowSizeRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_SetWindowSizeRequest(s), err
+}
+
+type Conmon_SetWindowSizeResponse capnp.Struct
+
+// Conmon_SetWindowSizeResponse_TypeID is the unique identifier for the type Conmon_SetWindowSizeResponse.
+const Conmon_SetWindowSizeResponse_TypeID = 0xf9b3cd8033aba1f8
+
+func NewConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_SetWindowSizeResponse(st), err
+}
+
+func NewRootConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_SetWindowSizeResponse(st), err
+}
+
+func ReadRootConmon_SetWindowSizeResponse(msg *capnp.Message) (Conmon_SetWindowSizeResponse, error) {
+	root, err := msg.Root()
+	return Conmon_SetWindowSizeResponse(root.Struct()), err
+}
+
+func (s Conmon_SetWindowSizeResponse) String() string {
+	str, _ := text.Marshal(0xf9b3cd8033aba1f8, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_SetWindowSizeResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_SetWindowSizeResponse) DecodeFromPtr(p capnp.Ptr) Conmon_SetWindowSizeResponse {
+	return Conmon_SetWindowSizeResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_SetWindowSizeResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_SetWindowSizeResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_SetWindowSizeResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_SetWindowSizeResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_SetWindowSizeResponse_List is a list of Conmon_SetWindowSizeResponse.
+type Conmon_SetWindowSizeResponse_List = capnp.StructList[Conmon_SetWindowSizeResponse]
+
+// NewConmon_SetWindowSizeResponse creates a new list of Conmon_SetWindowSizeResponse.
+func NewConmon_SetWindowSizeResponse_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_SetWindowSizeResponse](l), err
+}
+
+// Conmon_SetWindowSizeResponse_Future is a wrapper for a Conmon_SetWindowSizeResponse promised by a client call.
+type Conmon_SetWindowSizeResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_SetWindowSizeResponse_Future) Struct() (Conmon_SetWindowSizeResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_SetWindowSizeResponse(s), err
+}
+
+type Conmon_NegotiateRequest capnp.Struct
+
+// Conmon_NegotiateRequest_TypeID is the unique identifier for the type Conmon_NegotiateRequest.
+const Conmon_NegotiateRequest_TypeID = 0xb9a3cf4b6d466820
+
+func NewConmon_NegotiateRequest(s *capnp.Segment) (Conmon_NegotiateRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_NegotiateRequest(st), err
+}
+
+func NewRootConmon_NegotiateRequest(s *capnp.Segment) (Conmon_NegotiateRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_NegotiateRequest(st), err
+}
+
+func ReadRootConmon_NegotiateRequest(msg *capnp.Message) (Conmon_NegotiateRequest, error) {
+	root, err := msg.Root()
+	return Conmon_NegotiateRequest(root.Struct()), err
+}
+
+func (s Conmon_NegotiateRequest) String() string {
+	str, _ := text.Marshal(0xb9a3cf4b6d466820, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_NegotiateRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_NegotiateRequest) DecodeFromPtr(p capnp.Ptr) Conmon_NegotiateRequest {
+	return Conmon_NegotiateRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_NegotiateRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_NegotiateRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_NegotiateRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_NegotiateRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_NegotiateRequest) SchemaVersion() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_NegotiateRequest) SetSchemaVersion(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+func (s Conmon_NegotiateRequest) Features() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_NegotiateRequest) HasFeatures() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_NegotiateRequest) SetFeatures(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewFeatures sets the features field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_NegotiateRequest) NewFeatures(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_NegotiateRequest_List is a list of Conmon_NegotiateRequest.
+type Conmon_NegotiateRequest_List = capnp.StructList[Conmon_NegotiateRequest]
+
+// NewConmon_NegotiateRequest creates a new list of Conmon_NegotiateRequest.
+func NewConmon_NegotiateRequest_List(s *capnp.Segment, sz int32) (Conmon_NegotiateRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_NegotiateRequest](l), err
+}
+
+// Conmon_NegotiateRequest_Future is a wrapper for a Conmon_NegotiateRequest promised by a client call.
+type Conmon_NegotiateRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_NegotiateRequest_Future) Struct() (Conmon_NegotiateRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_NegotiateRequest(s), err
+}
+
+type Conmon_NegotiateResponse capnp.Struct
+
+// Conmon_NegotiateResponse_TypeID is the unique identifier for the type Conmon_NegotiateResponse.
+const Conmon_NegotiateResponse_TypeID = 0x9eeaaff965e45456
+
+func NewConmon_NegotiateResponse(s *capnp.Segment) (Conmon_NegotiateResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_NegotiateResponse(st), err
+}
+
+func NewRootConmon_NegotiateResponse(s *capnp.Segment) (Conmon_NegotiateResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_NegotiateResponse(st), err
+}
+
+func ReadRootConmon_NegotiateResponse(msg *capnp.Message) (Conmon_NegotiateResponse, error) {
+	root, err := msg.Root()
+	return Conmon_NegotiateResponse(root.Struct()), err
+}
+
+func (s Conmon_NegotiateResponse) String() string {
+	str, _ := text.Marshal(0x9eeaaff965e45456, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_NegotiateResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_NegotiateResponse) DecodeFromPtr(p capnp.Ptr) Conmon_NegotiateResponse {
+	return Conmon_NegotiateResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_NegotiateResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_NegotiateResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_NegotiateResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_NegotiateResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_NegotiateResponse) SchemaVersion() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_NegotiateResponse) SetSchemaVersion(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+func (s Conmon_NegotiateResponse) Features() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_NegotiateResponse) HasFeatures() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_NegotiateResponse) SetFeatures(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewFeatures sets the features field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_NegotiateResponse) NewFeatures(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_NegotiateResponse_List is a list of Conmon_NegotiateResponse.
+type Conmon_NegotiateResponse_List = capnp.StructList[Conmon_NegotiateResponse]
+
+// NewConmon_NegotiateResponse creates a new list of Conmon_NegotiateResponse.
+func NewConmon_NegotiateResponse_List(s *capnp.Segment, sz int32) (Conmon_NegotiateResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_NegotiateResponse](l), err
+}
+
+// Conmon_NegotiateResponse_Future is a wrapper for a Conmon_NegotiateResponse promised by a client call.
+type Conmon_NegotiateResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_NegotiateResponse_Future) Struct() (Conmon_NegotiateResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_NegotiateResponse(s), err
+}
+
+type Conmon_AttachStreamRequest capnp.Struct
+
+// Conmon_AttachStreamRequest_TypeID is the unique identifier for the type Conmon_AttachStreamRequest.
+const Conmon_AttachStreamRequest_TypeID = 0xacebf6dc0e874b1c
+
+func NewConmon_AttachStreamRequest(s *capnp.Segment) (Conmon_AttachStreamRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_AttachStreamRequest(st), err
+}
+
+func NewRootConmon_AttachStreamRequest(s *capnp.Segment) (Conmon_AttachStreamRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_AttachStreamRequest(st), err
+}
+
+func ReadRootConmon_AttachStreamRequest(msg *capnp.Message) (Conmon_AttachStreamRequest, error) {
+	root, err := msg.Root()
+	return Conmon_AttachStreamRequest(root.Struct()), err
+}
+
+func (s Conmon_AttachStreamRequest) String() string {
+	str, _ := text.Marshal(0xacebf6dc0e874b1c, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_AttachStreamRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_AttachStreamRequest) DecodeFromPtr(p capnp.Ptr) Conmon_AttachStreamRequest {
+	return Conmon_AttachStreamRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_AttachStreamRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_AttachStreamRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_AttachStreamRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_AttachStreamRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_AttachStreamRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_AttachStreamRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_AttachStreamRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AttachStreamRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_AttachStreamRequest_List is a list of Conmon_AttachStreamRequest.
+type Conmon_AttachStreamRequest_List = capnp.StructList[Conmon_AttachStreamRequest]
+
+// NewConmon_AttachStreamRequest creates a new list of Conmon_AttachStreamRequest.
+func NewConmon_AttachStreamRequest_List(s *capnp.Segment, sz int32) (Conmon_AttachStreamRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_AttachStreamRequest](l), err
+}
+
+// Conmon_AttachStreamRequest_Future is a wrapper for a Conmon_AttachStreamRequest promised by a client call.
+type Conmon_AttachStreamRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_AttachStreamRequest_Future) Struct() (Conmon_AttachStreamRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_AttachStreamRequest(s), err
+}
+
+type Conmon_AttachStreamResponse capnp.Struct
+
+// Conmon_AttachStreamResponse_TypeID is the unique identifier for the type Conmon_AttachStreamResponse.
+const Conmon_AttachStreamResponse_TypeID = 0xd8de4302b6d23065
+
+func NewConmon_AttachStreamResponse(s *capnp.Segment) (Conmon_AttachStreamResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_AttachStreamResponse(st), err
+}
+
+func NewRootConmon_AttachStreamResponse(s *capnp.Segment) (Conmon_AttachStreamResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_AttachStreamResponse(st), err
+}
+
+func ReadRootConmon_AttachStreamResponse(msg *capnp.Message) (Conmon_AttachStreamResponse, error) {
+	root, err := msg.Root()
+	return Conmon_AttachStreamResponse(root.Struct()), err
+}
+
+func (s Conmon_AttachStreamResponse) String() string {
+	str, _ := text.Marshal(0xd8de4302b6d23065, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_AttachStreamResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_AttachStreamResponse) DecodeFromPtr(p capnp.Ptr) Conmon_AttachStreamResponse {
+	return Conmon_AttachStreamResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_AttachStreamResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_AttachStreamResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_AttachStreamResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_AttachStreamResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_AttachStreamResponse) SocketPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_AttachStreamResponse) HasSocketPath() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_AttachStreamResponse) SocketPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AttachStreamResponse) SetSocketPath(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_AttachStreamResponse) Token() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_AttachStreamResponse) HasToken() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_AttachStreamResponse) TokenBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AttachStreamResponse) SetToken(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+// Conmon_AttachStreamResponse_List is a list of Conmon_AttachStreamResponse.
+type Conmon_AttachStreamResponse_List = capnp.StructList[Conmon_AttachStreamResponse]
+
+// NewConmon_AttachStreamResponse creates a new list of Conmon_AttachStreamResponse.
+func NewConmon_AttachStreamResponse_List(s *capnp.Segment, sz int32) (Conmon_AttachStreamResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_AttachStreamResponse](l), err
+}
+
+// Conmon_AttachStreamResponse_Future is a wrapper for a Conmon_AttachStreamResponse promised by a client call.
+type Conmon_AttachStreamResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_AttachStreamResponse_Future) Struct() (Conmon_AttachStreamResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_AttachStreamResponse(s), err
+}
+
+type Conmon_ListContainerStatusesRequest capnp.Struct
+
+// Conmon_ListContainerStatusesRequest_TypeID is the unique identifier for the type Conmon_ListContainerStatusesRequest.
+const Conmon_ListContainerStatusesRequest_TypeID = 0xa365dd6ce4fb8685
+
+func NewConmon_ListContainerStatusesRequest(s *capnp.Segment) (Conmon_ListContainerStatusesRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ListContainerStatusesRequest(st), err
+}
+
+func NewRootConmon_ListContainerStatusesRequest(s *capnp.Segment) (Conmon_ListContainerStatusesRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ListContainerStatusesRequest(st), err
+}
+
+func ReadRootConmon_ListContainerStatusesRequest(msg *capnp.Message) (Conmon_ListContainerStatusesRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ListContainerStatusesRequest(root.Struct()), err
+}
+
+func (s Conmon_ListContainerStatusesRequest) String() string {
+	str, _ := text.Marshal(0xa365dd6ce4fb8685, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ListContainerStatusesRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ListContainerStatusesRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ListContainerStatusesRequest {
+	return Conmon_ListContainerStatusesRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ListContainerStatusesRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ListContainerStatusesRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ListContainerStatusesRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ListContainerStatusesRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ListContainerStatusesRequest) Ids() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_ListContainerStatusesRequest) HasIds() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ListContainerStatusesRequest) SetIds(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewIds sets the ids field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_ListContainerStatusesRequest) NewIds(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_ListContainerStatusesRequest) Fields() (Conmon_StatusFields, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_StatusFields(p.Struct()), err
+}
+
+func (s Conmon_ListContainerStatusesRequest) HasFields() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ListContainerStatusesRequest) SetFields(v Conmon_StatusFields) error {
+	return capnp.Struct(s).SetPtr(1, capnp.Struct(v).ToPtr())
+}
+
+// NewFields sets the fields field to a newly
+// allocated Conmon_StatusFields struct, preferring placement in s's segment.
+func (s Conmon_ListContainerStatusesRequest) NewFields() (Conmon_StatusFields, error) {
+	ss, err := NewConmon_StatusFields(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_StatusFields{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_ListContainerStatusesRequest_List is a list of Conmon_ListContainerStatusesRequest.
+type Conmon_ListContainerStatusesRequest_List = capnp.StructList[Conmon_ListContainerStatusesRequest]
+
+// NewConmon_ListContainerStatusesRequest creates a new list of Conmon_ListContainerStatusesRequest.
+func NewConmon_ListContainerStatusesRequest_List(s *capnp.Segment, sz int32) (Conmon_ListContainerStatusesRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_ListContainerStatusesRequest](l), err
+}
+
+// Conmon_ListContainerStatusesRequest_Future is a wrapper for a Conmon_ListContainerStatusesRequest promised by a client call.
+type Conmon_ListContainerStatusesRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ListContainerStatusesRequest_Future) Struct() (Conmon_ListContainerStatusesRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ListContainerStatusesRequest(s), err
+}
+
+func (p Conmon_ListContainerStatusesRequest_Future) Fields() Conmon_StatusFields_Future {
+	return Conmon_StatusFields_Future{Future: p.Future.Field(1, nil)}
+}
+
+type Conmon_StatusFields capnp.Struct
+
+// Conmon_StatusFields_TypeID is the unique identifier for the type Conmon_StatusFields.
+const Conmon_StatusFields_TypeID = 0x8bb527ab52b03cf5
+
+func NewConmon_StatusFields(s *capnp.Segment) (Conmon_StatusFields, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_StatusFields(st), err
+}
+
+func NewRootConmon_StatusFields(s *capnp.Segment) (Conmon_StatusFields, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_StatusFields(st), err
+}
+
+func ReadRootConmon_StatusFields(msg *capnp.Message) (Conmon_StatusFields, error) {
+	root, err := msg.Root()
+	return Conmon_StatusFields(root.Struct()), err
+}
+
+func (s Conmon_StatusFields) String() string {
+	str, _ := text.Marshal(0x8bb527ab52b03cf5, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_StatusFields) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_StatusFields) DecodeFromPtr(p capnp.Ptr) Conmon_StatusFields {
+	return Conmon_StatusFields(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_StatusFields) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_StatusFields) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_StatusFields) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_StatusFields) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_StatusFields) State() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_StatusFields) SetState(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+func (s Conmon_StatusFields) ResourceUsage() bool {
+	return capnp.Struct(s).Bit(1)
+}
+
+func (s Conmon_StatusFields) SetResourceUsage(v bool) {
+	capnp.Struct(s).SetBit(1, v)
+}
+
+func (s Conmon_StatusFields) Overrides() bool {
+	return capnp.Struct(s).Bit(2)
+}
+
+func (s Conmon_StatusFields) SetOverrides(v bool) {
+	capnp.Struct(s).SetBit(2, v)
+}
+
+// Conmon_StatusFields_List is a list of Conmon_StatusFields.
+type Conmon_StatusFields_List = capnp.StructList[Conmon_StatusFields]
+
+// NewConmon_StatusFields creates a new list of Conmon_StatusFields.
+func NewConmon_StatusFields_List(s *capnp.Segment, sz int32) (Conmon_StatusFields_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_StatusFields](l), err
+}
+
+// Conmon_StatusFields_Future is a wrapper for a Conmon_StatusFields promised by a client call.
+type Conmon_StatusFields_Future struct{ *capnp.Future }
+
+func (p Conmon_StatusFields_Future) Struct() (Conmon_StatusFields, error) {
+	s, err := p.Future.Struct()
+	return Conmon_StatusFields(s), err
+}
+
+type Conmon_ContainerStatus capnp.Struct
+
+// Conmon_ContainerStatus_TypeID is the unique identifier for the type Conmon_ContainerStatus.
+const Conmon_ContainerStatus_TypeID = 0xdfc2b469c920ffca
+
+func NewConmon_ContainerStatus(s *capnp.Segment) (Conmon_ContainerStatus, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 4})
+	return Conmon_ContainerStatus(st), err
+}
+
+func NewRootConmon_ContainerStatus(s *capnp.Segment) (Conmon_ContainerStatus, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 4})
+	return Conmon_ContainerStatus(st), err
+}
+
+func ReadRootConmon_ContainerStatus(msg *capnp.Message) (Conmon_ContainerStatus, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerStatus(root.Struct()), err
+}
+
+func (s Conmon_ContainerStatus) String() string {
+	str, _ := text.Marshal(0xdfc2b469c920ffca, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerStatus) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerStatus) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerStatus {
+	return Conmon_ContainerStatus(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerStatus) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerStatus) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerStatus) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerStatus) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerStatus) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ContainerStatus) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ContainerStatus) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ContainerStatus) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ContainerStatus) Pid() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_ContainerStatus) SetPid(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+func (s Conmon_ContainerStatus) Running() bool {
+	return capnp.Struct(s).Bit(32)
+}
+
+func (s Conmon_ContainerStatus) SetRunning(v bool) {
+	capnp.Struct(s).SetBit(32, v)
+}
+
+func (s Conmon_ContainerStatus) ExitCode() int32 {
+	return int32(capnp.Struct(s).Uint32(8))
+}
+
+func (s Conmon_ContainerStatus) SetExitCode(v int32) {
+	capnp.Struct(s).SetUint32(8, uint32(v))
+}
+
+func (s Conmon_ContainerStatus) ResourceUsage() (Conmon_ResourceUsage, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_ResourceUsage(p.Struct()), err
+}
+
+func (s Conmon_ContainerStatus) HasResourceUsage() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ContainerStatus) SetResourceUsage(v Conmon_ResourceUsage) error {
+	return capnp.Struct(s).SetPtr(1, capnp.Struct(v).ToPtr())
+}
+
+// NewResourceUsage sets the resourceUsage field to a newly
+// allocated Conmon_ResourceUsage struct, preferring placement in s's segment.
+func (s Conmon_ContainerStatus) NewResourceUsage() (Conmon_ResourceUsage, error) {
+	ss, err := NewConmon_ResourceUsage(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ResourceUsage{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+func (s Conmon_ContainerStatus) RawExitCode() int32 {
+	return int32(capnp.Struct(s).Uint32(12))
+}
+
+func (s Conmon_ContainerStatus) SetRawExitCode(v int32) {
+	capnp.Struct(s).SetUint32(12, uint32(v))
+}
+
+func (s Conmon_ContainerStatus) Degraded() bool {
+	return capnp.Struct(s).Bit(33)
+}
+
+func (s Conmon_ContainerStatus) SetDegraded(v bool) {
+	capnp.Struct(s).SetBit(33, v)
+}
+
+func (s Conmon_ContainerStatus) Overrides() (Conmon_ContainerStatus_Override_List, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return Conmon_ContainerStatus_Override_List(p.List()), err
+}
+
+func (s Conmon_ContainerStatus) HasOverrides() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_ContainerStatus) SetOverrides(v Conmon_ContainerStatus_Override_List) error {
+	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+}
+
+// NewOverrides sets the overrides field to a newly
+// allocated Conmon_ContainerStatus_Override_List, preferring placement in s's segment.
+func (s Conmon_ContainerStatus) NewOverrides(n int32) (Conmon_ContainerStatus_Override_List, error) {
+	l, err := NewConmon_ContainerStatus_Override_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_ContainerStatus_Override_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_ContainerStatus) CleanupFailure() (string, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return p.Text(), err
+}
+
+func (s Conmon_ContainerStatus) HasCleanupFailure() bool {
+	return capnp.Struct(s).HasPtr(3)
+}
+
+func (s Conmon_ContainerStatus) CleanupFailureBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ContainerStatus) SetCleanupFailure(v string) error {
+	return capnp.Struct(s).SetText(3, v)
+}
+
+// Conmon_ContainerStatus_List is a list of Conmon_ContainerStatus.
+type Conmon_ContainerStatus_List = capnp.StructList[Conmon_ContainerStatus]
+
+// NewConmon_ContainerStatus creates a new list of Conmon_ContainerStatus.
+func NewConmon_ContainerStatus_List(s *capnp.Segment, sz int32) (Conmon_ContainerStatus_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 4}, sz)
+	return capnp.StructList[Conmon_ContainerStatus](l), err
+}
+
+// Conmon_ContainerStatus_Future is a wrapper for a Conmon_ContainerStatus promised by a client call.
+type Conmon_ContainerStatus_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerStatus_Future) Struct() (Conmon_ContainerStatus, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerStatus(s), err
+}
+
+func (p Conmon_ContainerStatus_Future) ResourceUsage() Conmon_ResourceUsage_Future {
+	return Conmon_ResourceUsage_Future{Future: p.Future.Field(1, nil)}
+}
+
+type Conmon_ContainerStatus_Override capnp.Struct
+
+// Conmon_ContainerStatus_Override_TypeID is the unique identifier for the type Conmon_ContainerStatus_Override.
+const Conmon_ContainerStatus_Override_TypeID = 0x8489b9d20466f5d5
+
+func NewConmon_ContainerStatus_Override(s *capnp.Segment) (Conmon_ContainerStatus_Override, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ContainerStatus_Override(st), err
+}
+
+func NewRootConmon_ContainerStatus_Override(s *capnp.Segment) (Conmon_ContainerStatus_Override, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ContainerStatus_Override(st), err
+}
+
+func ReadRootConmon_ContainerStatus_Override(msg *capnp.Message) (Conmon_ContainerStatus_Override, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerStatus_Override(root.Struct()), err
+}
+
+func (s Conmon_ContainerStatus_Override) String() string {
+	str, _ := text.Marshal(0x8489b9d20466f5d5, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerStatus_Override) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerStatus_Override) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerStatus_Override {
+	return Conmon_ContainerStatus_Override(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerStatus_Override) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerStatus_Override) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerStatus_Override) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerStatus_Override) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerStatus_Override) Key() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ContainerStatus_Override) HasKey() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ContainerStatus_Override) KeyBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ContainerStatus_Override) SetKey(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ContainerStatus_Override) Value() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_ContainerStatus_Override) HasValue() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ContainerStatus_Override) ValueBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ContainerStatus_Override) SetValue(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+// Conmon_ContainerStatus_Override_List is a list of Conmon_ContainerStatus_Override.
+type Conmon_ContainerStatus_Override_List = capnp.StructList[Conmon_ContainerStatus_Override]
+
+// NewConmon_ContainerStatus_Override creates a new list of Conmon_ContainerStatus_Override.
+func NewConmon_ContainerStatus_Override_List(s *capnp.Segment, sz int32) (Conmon_ContainerStatus_Override_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_ContainerStatus_Override](l), err
+}
+
+// Conmon_ContainerStatus_Override_Future is a wrapper for a Conmon_ContainerStatus_Override promised by a client call.
+type Conmon_ContainerStatus_Override_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerStatus_Override_Future) Struct() (Conmon_ContainerStatus_Override, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerStatus_Override(s), err
+}
+
+type Conmon_ResourceUsage capnp.Struct
+
+// Conmon_ResourceUsage_TypeID is the unique identifier for the type Conmon_ResourceUsage.
+const Conmon_ResourceUsage_TypeID = 0xc9fd278024a95a99
+
+func NewConmon_ResourceUsage(s *capnp.Segment) (Conmon_ResourceUsage, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 0})
+	return Conmon_ResourceUsage(st), err
+}
+
+func NewRootConmon_ResourceUsage(s *capnp.Segment) (Conmon_ResourceUsage, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 0})
+	return Conmon_ResourceUsage(st), err
+}
+
+func ReadRootConmon_ResourceUsage(msg *capnp.Message) (Conmon_ResourceUsage, error) {
+	root, err := msg.Root()
+	return Conmon_ResourceUsage(root.Struct()), err
+}
+
+func (s Conmon_ResourceUsage) String() string {
+	str, _ := text.Marshal(0xc9fd278024a95a99, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ResourceUsage) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ResourceUsage) DecodeFromPtr(p capnp.Ptr) Conmon_ResourceUsage {
+	return Conmon_ResourceUsage(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ResourceUsage) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ResourceUsage) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ResourceUsage) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ResourceUsage) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ResourceUsage) UserTimeMicros() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_ResourceUsage) SetUserTimeMicros(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_ResourceUsage) SystemTimeMicros() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_ResourceUsage) SetSystemTimeMicros(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_ResourceUsage) RssBytes() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_ResourceUsage) SetRssBytes(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+// Conmon_ResourceUsage_List is a list of Conmon_ResourceUsage.
+type Conmon_ResourceUsage_List = capnp.StructList[Conmon_ResourceUsage]
+
+// NewConmon_ResourceUsage creates a new list of Conmon_ResourceUsage.
+func NewConmon_ResourceUsage_List(s *capnp.Segment, sz int32) (Conmon_ResourceUsage_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 24, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ResourceUsage](l), err
+}
+
+// Conmon_ResourceUsage_Future is a wrapper for a Conmon_ResourceUsage promised by a client call.
+type Conmon_ResourceUsage_Future struct{ *capnp.Future }
+
+func (p Conmon_ResourceUsage_Future) Struct() (Conmon_ResourceUsage, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ResourceUsage(s), err
+}
+
+type Conmon_ListContainerStatusesResponse capnp.Struct
+
+// Conmon_ListContainerStatusesResponse_TypeID is the unique identifier for the type Conmon_ListContainerStatusesResponse.
+const Conmon_ListContainerStatusesResponse_TypeID = 0x8bade35f60a94dc8
+
+func NewConmon_ListContainerStatusesResponse(s *capnp.Segment) (Conmon_ListContainerStatusesResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListContainerStatusesResponse(st), err
+}
+
+func NewRootConmon_ListContainerStatusesResponse(s *capnp.Segment) (Conmon_ListContainerStatusesResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListContainerStatusesResponse(st), err
+}
+
+func ReadRootConmon_ListContainerStatusesResponse(msg *capnp.Message) (Conmon_ListContainerStatusesResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ListContainerStatusesResponse(root.Struct()), err
+}
+
+func (s Conmon_ListContainerStatusesResponse) String() string {
+	str, _ := text.Marshal(0x8bade35f60a94dc8, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ListContainerStatusesResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ListContainerStatusesResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ListContainerStatusesResponse {
+	return Conmon_ListContainerStatusesResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ListContainerStatusesResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ListContainerStatusesResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ListContainerStatusesResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ListContainerStatusesResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ListContainerStatusesResponse) Statuses() (Conmon_ContainerStatus_List, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ContainerStatus_List(p.List()), err
+}
+
+func (s Conmon_ListContainerStatusesResponse) HasStatuses() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ListContainerStatusesResponse) SetStatuses(v Conmon_ContainerStatus_List) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewStatuses sets the statuses field to a newly
+// allocated Conmon_ContainerStatus_List, preferring placement in s's segment.
+func (s Conmon_ListContainerStatusesResponse) NewStatuses(n int32) (Conmon_ContainerStatus_List, error) {
+	l, err := NewConmon_ContainerStatus_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_ContainerStatus_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_ListContainerStatusesResponse_List is a list of Conmon_ListContainerStatusesResponse.
+type Conmon_ListContainerStatusesResponse_List = capnp.StructList[Conmon_ListContainerStatusesResponse]
+
+// NewConmon_ListContainerStatusesResponse creates a new list of Conmon_ListContainerStatusesResponse.
+func NewConmon_ListContainerStatusesResponse_List(s *capnp.Segment, sz int32) (Conmon_ListContainerStatusesResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ListContainerStatusesResponse](l), err
+}
+
+// Conmon_ListContainerStatusesResponse_Future is a wrapper for a Conmon_ListContainerStatusesResponse promised by a client call.
+type Conmon_ListContainerStatusesResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ListContainerStatusesResponse_Future) Struct() (Conmon_ListContainerStatusesResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ListContainerStatusesResponse(s), err
+}
+
+type Conmon_GetEventsRequest capnp.Struct
+
+// Conmon_GetEventsRequest_TypeID is the unique identifier for the type Conmon_GetEventsRequest.
+const Conmon_GetEventsRequest_TypeID = 0xd8998defc6b19abf
+
+func NewConmon_GetEventsRequest(s *capnp.Segment) (Conmon_GetEventsRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_GetEventsRequest(st), err
+}
+
+func NewRootConmon_GetEventsRequest(s *capnp.Segment) (Conmon_GetEventsRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_GetEventsRequest(st), err
+}
+
+func ReadRootConmon_GetEventsRequest(msg *capnp.Message) (Conmon_GetEventsRequest, error) {
+	root, err := msg.Root()
+	return Conmon_GetEventsRequest(root.Struct()), err
+}
+
+func (s Conmon_GetEventsRequest) String() string {
+	str, _ := text.Marshal(0xd8998defc6b19abf, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_GetEventsRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_GetEventsRequest) DecodeFromPtr(p capnp.Ptr) Conmon_GetEventsRequest {
+	return Conmon_GetEventsRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_GetEventsRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_GetEventsRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_GetEventsRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_GetEventsRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_GetEventsRequest) SinceSequence() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_GetEventsRequest) SetSinceSequence(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+// Conmon_GetEventsRequest_List is a list of Conmon_GetEventsRequest.
+type Conmon_GetEventsRequest_List = capnp.StructList[Conmon_GetEventsRequest]
+
+// NewConmon_GetEventsRequest creates a new list of Conmon_GetEventsRequest.
+func NewConmon_GetEventsRequest_List(s *capnp.Segment, sz int32) (Conmon_GetEventsRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_GetEventsRequest](l), err
+}
+
+// Conmon_GetEventsRequest_Future is a wrapper for a Conmon_GetEventsRequest promised by a client call.
+type Conmon_GetEventsRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_GetEventsRequest_Future) Struct() (Conmon_GetEventsRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_GetEventsRequest(s), err
+}
+
+type Conmon_Event capnp.Struct
+
+// Conmon_Event_TypeID is the unique identifier for the type Conmon_Event.
+const Conmon_Event_TypeID = 0xeabc17418056c495
+
+func NewConmon_Event(s *capnp.Segment) (Conmon_Event, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 40, PointerCount: 2})
+	return Conmon_Event(st), err
+}
+
+func NewRootConmon_Event(s *capnp.Segment) (Conmon_Event, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 40, PointerCount: 2})
+	return Conmon_Event(st), err
+}
+
+func ReadRootConmon_Event(msg *capnp.Message) (Conmon_Event, error) {
+	root, err := msg.Root()
+	return Conmon_Event(root.Struct()), err
+}
+
+func (s Conmon_Event) String() string {
+	str, _ := text.Marshal(0xeabc17418056c495, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_Event) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_Event) DecodeFromPtr(p capnp.Ptr) Conmon_Event {
+	return Conmon_Event(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_Event) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_Event) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_Event) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_Event) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_Event) Sequence() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_Event) SetSequence(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_Event) Type() Conmon_Event_Type {
+	return Conmon_Event_Type(capnp.Struct(s).Uint16(8))
+}
+
+func (s Conmon_Event) SetType(v Conmon_Event_Type) {
+	capnp.Struct(s).SetUint16(8, uint16(v))
+}
+
+func (s Conmon_Event) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_Event) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_Event) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_Event) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_Event) Pid() uint32 {
+	return capnp.Struct(s).Uint32(12)
+}
+
+func (s Conmon_Event) SetPid(v uint32) {
+	capnp.Struct(s).SetUint32(12, v)
+}
+
+func (s Conmon_Event) ExitCode() int32 {
+	return int32(capnp.Struct(s).Uint32(16))
+}
+
+func (s Conmon_Event) SetExitCode(v int32) {
+	capnp.Struct(s).SetUint32(16, uint32(v))
+}
+
+func (s Conmon_Event) Timestamp() uint64 {
+	return capnp.Struct(s).Uint64(24)
+}
+
+func (s Conmon_Event) SetTimestamp(v uint64) {
+	capnp.Struct(s).SetUint64(24, v)
+}
+
+func (s Conmon_Event) RawExitCode() int32 {
+	return int32(capnp.Struct(s).Uint32(20))
+}
+
+func (s Conmon_Event) SetRawExitCode(v int32) {
+	capnp.Struct(s).SetUint32(20, uint32(v))
+}
+
+func (s Conmon_Event) KilledPid() uint32 {
+	return capnp.Struct(s).Uint32(32)
+}
+
+func (s Conmon_Event) SetKilledPid(v uint32) {
+	capnp.Struct(s).SetUint32(32, v)
+}
+
+func (s Conmon_Event) KilledComm() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_Event) HasKilledComm() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_Event) KilledCommBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_Event) SetKilledComm(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+// Conmon_Event_List is a list of Conmon_Event.
+type Conmon_Event_List = capnp.StructList[Conmon_Event]
+
+// NewConmon_Event creates a new list of Conmon_Event.
+func NewConmon_Event_List(s *capnp.Segment, sz int32) (Conmon_Event_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 40, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_Event](l), err
+}
+
+// Conmon_Event_Future is a wrapper for a Conmon_Event promised by a client call.
+type Conmon_Event_Future struct{ *capnp.Future }
+
+func (p Conmon_Event_Future) Struct() (Conmon_Event, error) {
+	s, err := p.Future.Struct()
+	return Conmon_Event(s), err
+}
+
+type Conmon_Event_Type uint16
+
+// Conmon_Event_Type_TypeID is the unique identifier for the type Conmon_Event_Type.
+const Conmon_Event_Type_TypeID = 0xda0e292b1d45f674
+
+// Values of Conmon_Event_Type.
+const (
+	Conmon_Event_Type_created            Conmon_Event_Type = 0
+	Conmon_Event_Type_exited             Conmon_Event_Type = 1
+	Conmon_Event_Type_oom                Conmon_Event_Type = 2
+	Conmon_Event_Type_evicted            Conmon_Event_Type = 3
+	Conmon_Event_Type_cleanupFailed      Conmon_Event_Type = 4
+	Conmon_Event_Type_descendantOom      Conmon_Event_Type = 5
+	Conmon_Event_Type_runtimeStateLeaked Conmon_Event_Type = 6
+	Conmon_Event_Type_logRotated         Conmon_Event_Type = 7
+)
+
+// String returns the enum's constant name.
+func (c Conmon_Event_Type) String() string {
+	switch c {
+	case Conmon_Event_Type_created:
+		return "created"
+	case Conmon_Event_Type_exited:
+		return "exited"
+	case Conmon_Event_Type_oom:
+		return "oom"
+	case Conmon_Event_Type_evicted:
+		return "evicted"
+	case Conmon_Event_Type_cleanupFailed:
+		return "cleanupFailed"
+	case Conmon_Event_Type_descendantOom:
+		return "descendantOom"
+	case Conmon_Event_Type_runtimeStateLeaked:
+		return "runtimeStateLeaked"
+	case Conmon_Event_Type_logRotated:
+		return "logRotated"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_Event_TypeFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_Event_TypeFromString(c string) Conmon_Event_Type {
+	switch c {
+	case "created":
+		return Conmon_Event_Type_created
+	case "exited":
+		return Conmon_Event_Type_exited
+	case "oom":
+		return Conmon_Event_Type_oom
+	case "evicted":
+		return Conmon_Event_Type_evicted
+	case "cleanupFailed":
+		return Conmon_Event_Type_cleanupFailed
+	case "descendantOom":
+		return Conmon_Event_Type_descendantOom
+	case "runtimeStateLeaked":
+		return Conmon_Event_Type_runtimeStateLeaked
+	case "logRotated":
+		return Conmon_Event_Type_logRotated
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_Event_Type_List = capnp.EnumList[Conmon_Event_Type]
+
+func NewConmon_Event_Type_List(s *capnp.Segment, sz int32) (Conmon_Event_Type_List, error) {
+	return capnp.NewEnumList[Conmon_Event_Type](s, sz)
+}
+
+type Conmon_GetEventsResponse capnp.Struct
+
+// Conmon_GetEventsResponse_TypeID is the unique identifier for the type Conmon_GetEventsResponse.
+const Conmon_GetEventsResponse_TypeID = 0xd45fb0bc9ddaf48d
+
+func NewConmon_GetEventsResponse(s *capnp.Segment) (Conmon_GetEventsResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
+	return Conmon_GetEventsResponse(st), err
+}
+
+func NewRootConmon_GetEventsResponse(s *capnp.Segment) (Conmon_GetEventsResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
+	return Conmon_GetEventsResponse(st), err
+}
+
+func ReadRootConmon_GetEventsResponse(msg *capnp.Message) (Conmon_GetEventsResponse, error) {
+	root, err := msg.Root()
+	return Conmon_GetEventsResponse(root.Struct()), err
+}
+
+func (s Conmon_GetEventsResponse) String() string {
+	str, _ := text.Marshal(0xd45fb0bc9ddaf48d, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_GetEventsResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_GetEventsResponse) DecodeFromPtr(p capnp.Ptr) Conmon_GetEventsResponse {
+	return Conmon_GetEventsResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_GetEventsResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_GetEventsResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_GetEventsResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_GetEventsResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_GetEventsResponse) Events() (Conmon_Event_List, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_Event_List(p.List()), err
+}
+
+func (s Conmon_GetEventsResponse) HasEvents() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_GetEventsResponse) SetEvents(v Conmon_Event_List) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewEvents sets the events field to a newly
+// allocated Conmon_Event_List, preferring placement in s's segment.
+func (s Conmon_GetEventsResponse) NewEvents(n int32) (Conmon_Event_List, error) {
+	l, err := NewConmon_Event_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_Event_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_GetEventsResponse) LastSequence() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_GetEventsResponse) SetLastSequence(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_GetEventsResponse) Truncated() bool {
+	return capnp.Struct(s).Bit(64)
+}
+
+func (s Conmon_GetEventsResponse) SetTruncated(v bool) {
+	capnp.Struct(s).SetBit(64, v)
+}
+
+// Conmon_GetEventsResponse_List is a list of Conmon_GetEventsResponse.
+type Conmon_GetEventsResponse_List = capnp.StructList[Conmon_GetEventsResponse]
+
+// NewConmon_GetEventsResponse creates a new list of Conmon_GetEventsResponse.
+func NewConmon_GetEventsResponse_List(s *capnp.Segment, sz int32) (Conmon_GetEventsResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_GetEventsResponse](l), err
+}
+
+// Conmon_GetEventsResponse_Future is a wrapper for a Conmon_GetEventsResponse promised by a client call.
+type Conmon_GetEventsResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_GetEventsResponse_Future) Struct() (Conmon_GetEventsResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_GetEventsResponse(s), err
+}
+
+type Conmon_ListExecSessionsRequest capnp.Struct
+
+// Conmon_ListExecSessionsRequest_TypeID is the unique identifier for the type Conmon_ListExecSessionsRequest.
+const Conmon_ListExecSessionsRequest_TypeID = 0xba317e1d56859b29
+
+func NewConmon_ListExecSessionsRequest(s *capnp.Segment) (Conmon_ListExecSessionsRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListExecSessionsRequest(st), err
+}
+
+func NewRootConmon_ListExecSessionsRequest(s *capnp.Segment) (Conmon_ListExecSessionsRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListExecSessionsRequest(st), err
+}
+
+func ReadRootConmon_ListExecSessionsRequest(msg *capnp.Message) (Conmon_ListExecSessionsRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ListExecSessionsRequest(root.Struct()), err
+}
+
+func (s Conmon_ListExecSessionsRequest) String() string {
+	str, _ := text.Marshal(0xba317e1d56859b29, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ListExecSessionsRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ListExecSessionsRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ListExecSessionsRequest {
+	return Conmon_ListExecSessionsRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ListExecSessionsRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ListExecSessionsRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ListExecSessionsRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ListExecSessionsRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ListExecSessionsRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ListExecSessionsRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ListExecSessionsRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ListExecSessionsRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_ListExecSessionsRequest_List is a list of Conmon_ListExecSessionsRequest.
+type Conmon_ListExecSessionsRequest_List = capnp.StructList[Conmon_ListExecSessionsRequest]
+
+// NewConmon_ListExecSessionsRequest creates a new list of Conmon_ListExecSessionsRequest.
+func NewConmon_ListExecSessionsRequest_List(s *capnp.Segment, sz int32) (Conmon_ListExecSessionsRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ListExecSessionsRequest](l), err
+}
+
+// Conmon_ListExecSessionsRequest_Future is a wrapper for a Conmon_ListExecSessionsRequest promised by a client call.
+type Conmon_ListExecSessionsRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ListExecSessionsRequest_Future) Struct() (Conmon_ListExecSessionsRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ListExecSessionsRequest(s), err
+}
+
+type Conmon_ExecSession capnp.Struct
+
+// Conmon_ExecSession_TypeID is the unique identifier for the type Conmon_ExecSession.
+const Conmon_ExecSession_TypeID = 0x866cb27128e3b59f
+
+func NewConmon_ExecSession(s *capnp.Segment) (Conmon_ExecSession, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 1})
+	return Conmon_ExecSession(st), err
+}
+
+func NewRootConmon_ExecSession(s *capnp.Segment) (Conmon_ExecSession, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 1})
+	return Conmon_ExecSession(st), err
+}
+
+func ReadRootConmon_ExecSession(msg *capnp.Message) (Conmon_ExecSession, error) {
+	root, err := msg.Root()
+	return Conmon_ExecSession(root.Struct()), err
+}
+
+func (s Conmon_ExecSession) String() string {
+	str, _ := text.Marshal(0x866cb27128e3b59f, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ExecSession) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ExecSession) DecodeFromPtr(p capnp.Ptr) Conmon_ExecSession {
+	return Conmon_ExecSession(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ExecSession) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ExecSession) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ExecSession) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ExecSession) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ExecSession) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ExecSession) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ExecSession) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ExecSession) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ExecSession) Kind() Conmon_ExecSession_Kind {
+	return Conmon_ExecSession_Kind(capnp.Struct(s).Uint16(0))
+}
+
+func (s Conmon_ExecSession) SetKind(v Conmon_ExecSession_Kind) {
+	capnp.Struct(s).SetUint16(0, uint16(v))
+}
+
+func (s Conmon_ExecSession) Pid() uint32 {
+	return capnp.Struct(s).Uint32(4)
+}
+
+func (s Conmon_ExecSession) SetPid(v uint32) {
+	capnp.Struct(s).SetUint32(4, v)
+}
+
+func (s Conmon_ExecSession) Running() bool {
+	return capnp.Struct(s).Bit(16)
+}
+
+func (s Conmon_ExecSession) SetRunning(v bool) {
+	capnp.Struct(s).SetBit(16, v)
+}
+
+func (s Conmon_ExecSession) ExitCode() int32 {
+	return int32(capnp.Struct(s).Uint32(8))
+}
+
+func (s Conmon_ExecSession) SetExitCode(v int32) {
+	capnp.Struct(s).SetUint32(8, uint32(v))
+}
+
+func (s Conmon_ExecSession) StartedAt() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_ExecSession) SetStartedAt(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+func (s Conmon_ExecSession) FinishedAt() uint64 {
+	return capnp.Struct(s).Uint64(24)
+}
+
+func (s Conmon_ExecSession) SetFinishedAt(v uint64) {
+	capnp.Struct(s).SetUint64(24, v)
+}
+
+// Conmon_ExecSession_List is a list of Conmon_ExecSession.
+type Conmon_ExecSession_List = capnp.StructList[Conmon_ExecSession]
+
+// NewConmon_ExecSession creates a new list of Conmon_ExecSession.
+func NewConmon_ExecSession_List(s *capnp.Segment, sz int32) (Conmon_ExecSession_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 32, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ExecSession](l), err
+}
+
+// Conmon_ExecSession_Future is a wrapper for a Conmon_ExecSession promised by a client call.
+type Conmon_ExecSession_Future struct{ *capnp.Future }
+
+func (p Conmon_ExecSession_Future) Struct() (Conmon_ExecSession, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ExecSession(s), err
+}
+
+type Conmon_ExecSession_Kind uint16
+
+// Conmon_ExecSession_Kind_TypeID is the unique identifier for the type Conmon_ExecSession_Kind.
+const Conmon_ExecSession_Kind_TypeID = 0x9f1a1356451b379a
+
+// Values of Conmon_ExecSession_Kind.
+const (
+	Conmon_ExecSession_Kind_sync      Conmon_ExecSession_Kind = 0
+	Conmon_ExecSession_Kind_streaming Conmon_ExecSession_Kind = 1
+)
+
+// String returns the enum's constant name.
+func (c Conmon_ExecSession_Kind) String() string {
+	switch c {
+	case Conmon_ExecSession_Kind_sync:
+		return "sync"
+	case Conmon_ExecSession_Kind_streaming:
+		return "streaming"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_ExecSession_KindFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_ExecSession_KindFromString(c string) Conmon_ExecSession_Kind {
+	switch c {
+	case "sync":
+		return Conmon_ExecSession_Kind_sync
+	case "streaming":
+		return Conmon_ExecSession_Kind_streaming
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_ExecSession_Kind_List = capnp.EnumList[Conmon_ExecSession_Kind]
+
+func NewConmon_ExecSession_Kind_List(s *capnp.Segment, sz int32) (Conmon_ExecSession_Kind_List, error) {
+	return capnp.NewEnumList[Conmon_ExecSession_Kind](s, sz)
+}
+
+type Conmon_ListExecSessionsResponse capnp.Struct
+
+// Conmon_ListExecSessionsResponse_TypeID is the unique identifier for the type Conmon_ListExecSessionsResponse.
+const Conmon_ListExecSessionsResponse_TypeID = 0x81ae0ac668cca1f3
+
+func NewConmon_ListExecSessionsResponse(s *capnp.Segment) (Conmon_ListExecSessionsResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListExecSessionsResponse(st), err
+}
+
+func NewRootConmon_ListExecSessionsResponse(s *capnp.Segment) (Conmon_ListExecSessionsResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListExecSessionsResponse(st), err
+}
+
+func ReadRootConmon_ListExecSessionsResponse(msg *capnp.Message) (Conmon_ListExecSessionsResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ListExecSessionsResponse(root.Struct()), err
+}
+
+func (s Conmon_ListExecSessionsResponse) String() string {
+	str, _ := text.Marshal(0x81ae0ac668cca1f3, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ListExecSessionsResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ListExecSessionsResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ListExecSessionsResponse {
+	return Conmon_ListExecSessionsResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ListExecSessionsResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ListExecSessionsResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ListExecSessionsResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ListExecSessionsResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ListExecSessionsResponse) Sessions() (Conmon_ExecSession_List, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ExecSession_List(p.List()), err
+}
+
+func (s Conmon_ListExecSessionsResponse) HasSessions() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ListExecSessionsResponse) SetSessions(v Conmon_ExecSession_List) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewSessions sets the sessions field to a newly
+// allocated Conmon_ExecSession_List, preferring placement in s's segment.
+func (s Conmon_ListExecSessionsResponse) NewSessions(n int32) (Conmon_ExecSession_List, error) {
+	l, err := NewConmon_ExecSession_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_ExecSession_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_ListExecSessionsResponse_List is a list of Conmon_ListExecSessionsResponse.
+type Conmon_ListExecSessionsResponse_List = capnp.StructList[Conmon_ListExecSessionsResponse]
+
+// NewConmon_ListExecSessionsResponse creates a new list of Conmon_ListExecSessionsResponse.
+func NewConmon_ListExecSessionsResponse_List(s *capnp.Segment, sz int32) (Conmon_ListExecSessionsResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ListExecSessionsResponse](l), err
+}
+
+// Conmon_ListExecSessionsResponse_Future is a wrapper for a Conmon_ListExecSessionsResponse promised by a client call.
+type Conmon_ListExecSessionsResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ListExecSessionsResponse_Future) Struct() (Conmon_ListExecSessionsResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ListExecSessionsResponse(s), err
+}
+
+type Conmon_ContainerIOStatsRequest capnp.Struct
+
+// Conmon_ContainerIOStatsRequest_TypeID is the unique identifier for the type Conmon_ContainerIOStatsRequest.
+const Conmon_ContainerIOStatsRequest_TypeID = 0xacc575e9c260e2b7
+
+func NewConmon_ContainerIOStatsRequest(s *capnp.Segment) (Conmon_ContainerIOStatsRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ContainerIOStatsRequest(st), err
+}
+
+func NewRootConmon_ContainerIOStatsRequest(s *capnp.Segment) (Conmon_ContainerIOStatsRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ContainerIOStatsRequest(st), err
+}
+
+func ReadRootConmon_ContainerIOStatsRequest(msg *capnp.Message) (Conmon_ContainerIOStatsRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerIOStatsRequest(root.Struct()), err
+}
+
+func (s Conmon_ContainerIOStatsRequest) String() string {
+	str, _ := text.Marshal(0xacc575e9c260e2b7, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerIOStatsRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerIOStatsRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerIOStatsRequest {
+	return Conmon_ContainerIOStatsRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerIOStatsRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerIOStatsRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerIOStatsRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerIOStatsRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerIOStatsRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ContainerIOStatsRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ContainerIOStatsRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ContainerIOStatsRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_ContainerIOStatsRequest_List is a list of Conmon_ContainerIOStatsRequest.
+type Conmon_ContainerIOStatsRequest_List = capnp.StructList[Conmon_ContainerIOStatsRequest]
+
+// NewConmon_ContainerIOStatsRequest creates a new list of Conmon_ContainerIOStatsRequest.
+func NewConmon_ContainerIOStatsRequest_List(s *capnp.Segment, sz int32) (Conmon_ContainerIOStatsRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ContainerIOStatsRequest](l), err
+}
+
+// Conmon_ContainerIOStatsRequest_Future is a wrapper for a Conmon_ContainerIOStatsRequest promised by a client call.
+type Conmon_ContainerIOStatsRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerIOStatsRequest_Future) Struct() (Conmon_ContainerIOStatsRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerIOStatsRequest(s), err
+}
+
+type Conmon_ContainerIOStatsResponse capnp.Struct
+
+// Conmon_ContainerIOStatsResponse_TypeID is the unique identifier for the type Conmon_ContainerIOStatsResponse.
+const Conmon_ContainerIOStatsResponse_TypeID = 0xdf5ca9795f6cfe95
+
+func NewConmon_ContainerIOStatsResponse(s *capnp.Segment) (Conmon_ContainerIOStatsResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 56, PointerCount: 0})
+	return Conmon_ContainerIOStatsResponse(st), err
+}
+
+func NewRootConmon_ContainerIOStatsResponse(s *capnp.Segment) (Conmon_ContainerIOStatsResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 56, PointerCount: 0})
+	return Conmon_ContainerIOStatsResponse(st), err
+}
+
+func ReadRootConmon_ContainerIOStatsResponse(msg *capnp.Message) (Conmon_ContainerIOStatsResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerIOStatsResponse(root.Struct()), err
+}
+
+func (s Conmon_ContainerIOStatsResponse) String() string {
+	str, _ := text.Marshal(0xdf5ca9795f6cfe95, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerIOStatsResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerIOStatsResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerIOStatsResponse {
+	return Conmon_ContainerIOStatsResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerIOStatsResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerIOStatsResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerIOStatsResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerIOStatsResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerIOStatsResponse) StdoutBytes() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_ContainerIOStatsResponse) SetStdoutBytes(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_ContainerIOStatsResponse) StderrBytes() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_ContainerIOStatsResponse) SetStderrBytes(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_ContainerIOStatsResponse) AttachBytes() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_ContainerIOStatsResponse) SetAttachBytes(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+func (s Conmon_ContainerIOStatsResponse) BufferedBytes() uint64 {
+	return capnp.Struct(s).Uint64(24)
+}
+
+func (s Conmon_ContainerIOStatsResponse) SetBufferedBytes(v uint64) {
+	capnp.Struct(s).SetUint64(24, v)
+}
+
+func (s Conmon_ContainerIOStatsResponse) Stalls() uint64 {
+	return capnp.Struct(s).Uint64(32)
+}
+
+func (s Conmon_ContainerIOStatsResponse) SetStalls(v uint64) {
+	capnp.Struct(s).SetUint64(32, v)
+}
+
+func (s Conmon_ContainerIOStatsResponse) LogDiskBytes() uint64 {
+	return capnp.Struct(s).Uint64(40)
+}
+
+func (s Conmon_ContainerIOStatsResponse) SetLogDiskBytes(v uint64) {
+	capnp.Struct(s).SetUint64(40, v)
+}
+
+func (s Conmon_ContainerIOStatsResponse) PrunedLogBytes() uint64 {
+	return capnp.Struct(s).Uint64(48)
+}
+
+func (s Conmon_ContainerIOStatsResponse) SetPrunedLogBytes(v uint64) {
+	capnp.Struct(s).SetUint64(48, v)
+}
+
+// Conmon_ContainerIOStatsResponse_List is a list of Conmon_ContainerIOStatsResponse.
+type Conmon_ContainerIOStatsResponse_List = capnp.StructList[Conmon_ContainerIOStatsResponse]
+
+// NewConmon_ContainerIOStatsResponse creates a new list of Conmon_ContainerIOStatsResponse.
+func NewConmon_ContainerIOStatsResponse_List(s *capnp.Segment, sz int32) (Conmon_ContainerIOStatsResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 56, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ContainerIOStatsResponse](l), err
+}
+
+// Conmon_ContainerIOStatsResponse_Future is a wrapper for a Conmon_ContainerIOStatsResponse promised by a client call.
+type Conmon_ContainerIOStatsResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerIOStatsResponse_Future) Struct() (Conmon_ContainerIOStatsResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerIOStatsResponse(s), err
+}
+
+type Conmon_SetLogLevelRequest capnp.Struct
+
+// Conmon_SetLogLevelRequest_TypeID is the unique identifier for the type Conmon_SetLogLevelRequest.
+const Conmon_SetLogLevelRequest_TypeID = 0xc437ab0d294724d9
+
+func NewConmon_SetLogLevelRequest(s *capnp.Segment) (Conmon_SetLogLevelRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_SetLogLevelRequest(st), err
+}
+
+func NewRootConmon_SetLogLevelRequest(s *capnp.Segment) (Conmon_SetLogLevelRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_SetLogLevelRequest(st), err
+}
+
+func ReadRootConmon_SetLogLevelRequest(msg *capnp.Message) (Conmon_SetLogLevelRequest, error) {
+	root, err := msg.Root()
+	return Conmon_SetLogLevelRequest(root.Struct()), err
+}
+
+func (s Conmon_SetLogLevelRequest) String() string {
+	str, _ := text.Marshal(0xc437ab0d294724d9, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_SetLogLevelRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_SetLogLevelRequest) DecodeFromPtr(p capnp.Ptr) Conmon_SetLogLevelRequest {
+	return Conmon_SetLogLevelRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_SetLogLevelRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_SetLogLevelRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_SetLogLevelRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_SetLogLevelRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_SetLogLevelRequest) Level() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_SetLogLevelRequest) HasLevel() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_SetLogLevelRequest) LevelBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_SetLogLevelRequest) SetLevel(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_SetLogLevelRequest_List is a list of Conmon_SetLogLevelRequest.
+type Conmon_SetLogLevelRequest_List = capnp.StructList[Conmon_SetLogLevelRequest]
+
+// NewConmon_SetLogLevelRequest creates a new list of Conmon_SetLogLevelRequest.
+func NewConmon_SetLogLevelRequest_List(s *capnp.Segment, sz int32) (Conmon_SetLogLevelRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_SetLogLevelRequest](l), err
+}
+
+// Conmon_SetLogLevelRequest_Future is a wrapper for a Conmon_SetLogLevelRequest promised by a client call.
+type Conmon_SetLogLevelRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_SetLogLevelRequest_Future) Struct() (Conmon_SetLogLevelRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_SetLogLevelRequest(s), err
+}
+
+type Conmon_SetLogLevelResponse capnp.Struct
+
+// Conmon_SetLogLevelResponse_TypeID is the unique identifier for the type Conmon_SetLogLevelResponse.
+const Conmon_SetLogLevelResponse_TypeID = 0xc8865f0ec25b4ef8
+
+func NewConmon_SetLogLevelResponse(s *capnp.Segment) (Conmon_SetLogLevelResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_SetLogLevelResponse(st), err
+}
+
+func NewRootConmon_SetLogLevelResponse(s *capnp.Segment) (Conmon_SetLogLevelResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_SetLogLevelResponse(st), err
+}
+
+func ReadRootConmon_SetLogLevelResponse(msg *capnp.Message) (Conmon_SetLogLevelResponse, error) {
+	root, err := msg.Root()
+	return Conmon_SetLogLevelResponse(root.Struct()), err
+}
+
+func (s Conmon_SetLogLevelResponse) String() string {
+	str, _ := text.Marshal(0xc8865f0ec25b4ef8, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_SetLogLevelResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_SetLogLevelResponse) DecodeFromPtr(p capnp.Ptr) Conmon_SetLogLevelResponse {
+	return Conmon_SetLogLevelResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_SetLogLevelResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_SetLogLevelResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_SetLogLevelResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_SetLogLevelResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_SetLogLevelResponse) PreviousLevel() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_SetLogLevelResponse) HasPreviousLevel() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_SetLogLevelResponse) PreviousLevelBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_SetLogLevelResponse) SetPreviousLevel(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_SetLogLevelResponse_List is a list of Conmon_SetLogLevelResponse.
+type Conmon_SetLogLevelResponse_List = capnp.StructList[Conmon_SetLogLevelResponse]
+
+// NewConmon_SetLogLevelResponse creates a new list of Conmon_SetLogLevelResponse.
+func NewConmon_SetLogLevelResponse_List(s *capnp.Segment, sz int32) (Conmon_SetLogLevelResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_SetLogLevelResponse](l), err
+}
+
+// Conmon_SetLogLevelResponse_Future is a wrapper for a Conmon_SetLogLevelResponse promised by a client call.
+type Conmon_SetLogLevelResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_SetLogLevelResponse_Future) Struct() (Conmon_SetLogLevelResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_SetLogLevelResponse(s), err
+}
+
+type Conmon_RemoveContainerRequest capnp.Struct
+
+// Conmon_RemoveContainerRequest_TypeID is the unique identifier for the type Conmon_RemoveContainerRequest.
+const Conmon_RemoveContainerRequest_TypeID = 0xbc21bf853f72a228
+
+func NewConmon_RemoveContainerRequest(s *capnp.Segment) (Conmon_RemoveContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_RemoveContainerRequest(st), err
+}
+
+func NewRootConmon_RemoveContainerRequest(s *capnp.Segment) (Conmon_RemoveContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_RemoveContainerRequest(st), err
+}
+
+func ReadRootConmon_RemoveContainerRequest(msg *capnp.Message) (Conmon_RemoveContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_RemoveContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_RemoveContainerRequest) String() string {
+	str, _ := text.Marshal(0xbc21bf853f72a228, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_RemoveContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_RemoveContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_RemoveContainerRequest {
+	return Conmon_RemoveContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_RemoveContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_RemoveContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_RemoveContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_RemoveContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_RemoveContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_RemoveContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_RemoveContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_RemoveContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_RemoveContainerRequest) RemoveLogs() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_RemoveContainerRequest) SetRemoveLogs(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+// Conmon_RemoveContainerRequest_List is a list of Conmon_RemoveContainerRequest.
+type Conmon_RemoveContainerRequest_List = capnp.StructList[Conmon_RemoveContainerRequest]
+
+// NewConmon_RemoveContainerRequest creates a new list of Conmon_RemoveContainerRequest.
+func NewConmon_RemoveContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_RemoveContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_RemoveContainerRequest](l), err
+}
+
+// Conmon_RemoveContainerRequest_Future is a wrapper for a Conmon_RemoveContainerRequest promised by a client call.
+type Conmon_RemoveContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_RemoveContainerRequest_Future) Struct() (Conmon_RemoveContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_RemoveContainerRequest(s), err
+}
+
+type Conmon_RemoveContainerResponse capnp.Struct
+
+// Conmon_RemoveContainerResponse_TypeID is the unique identifier for the type Conmon_RemoveContainerResponse.
+const Conmon_RemoveContainerResponse_TypeID = 0xcbb1707a6436eddd
+
+func NewConmon_RemoveContainerResponse(s *capnp.Segment) (Conmon_RemoveContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_RemoveContainerResponse(st), err
+}
+
+func NewRootConmon_RemoveContainerResponse(s *capnp.Segment) (Conmon_RemoveContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_RemoveContainerResponse(st), err
+}
+
+func ReadRootConmon_RemoveContainerResponse(msg *capnp.Message) (Conmon_RemoveContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_RemoveContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_RemoveContainerResponse) String() string {
+	str, _ := text.Marshal(0xcbb1707a6436eddd, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_RemoveContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_RemoveContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_RemoveContainerResponse {
+	return Conmon_RemoveContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_RemoveContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_RemoveContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_RemoveContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_RemoveContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_RemoveContainerResponse_List is a list of Conmon_RemoveContainerResponse.
+type Conmon_RemoveContainerResponse_List = capnp.StructList[Conmon_RemoveContainerResponse]
+
+// NewConmon_RemoveContainerResponse creates a new list of Conmon_RemoveContainerResponse.
+func NewConmon_RemoveContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_RemoveContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_RemoveContainerResponse](l), err
+}
+
+// Conmon_RemoveContainerResponse_Future is a wrapper for a Conmon_RemoveContainerResponse promised by a client call.
+type Conmon_RemoveContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_RemoveContainerResponse_Future) Struct() (Conmon_RemoveContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_RemoveContainerResponse(s), err
+}
+
+type Conmon_GetContainerLogOffsetRequest capnp.Struct
+
+// Conmon_GetContainerLogOffsetRequest_TypeID is the unique identifier for the type Conmon_GetContainerLogOffsetRequest.
+const Conmon_GetContainerLogOffsetRequest_TypeID = 0xb231e9cbb2c1d991
+
+func NewConmon_GetContainerLogOffsetRequest(s *capnp.Segment) (Conmon_GetContainerLogOffsetRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_GetContainerLogOffsetRequest(st), err
+}
+
+func NewRootConmon_GetContainerLogOffsetRequest(s *capnp.Segment) (Conmon_GetContainerLogOffsetRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_GetContainerLogOffsetRequest(st), err
+}
+
+func ReadRootConmon_GetContainerLogOffsetRequest(msg *capnp.Message) (Conmon_GetContainerLogOffsetRequest, error) {
+	root, err := msg.Root()
+	return Conmon_GetContainerLogOffsetRequest(root.Struct()), err
+}
+
+func (s Conmon_GetContainerLogOffsetRequest) String() string {
+	str, _ := text.Marshal(0xb231e9cbb2c1d991, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_GetContainerLogOffsetRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_GetContainerLogOffsetRequest) DecodeFromPtr(p capnp.Ptr) Conmon_GetContainerLogOffsetRequest {
+	return Conmon_GetContainerLogOffsetRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_GetContainerLogOffsetRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_GetContainerLogOffsetRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_GetContainerLogOffsetRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_GetContainerLogOffsetRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_GetContainerLogOffsetRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_GetContainerLogOffsetRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_GetContainerLogOffsetRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_GetContainerLogOffsetRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_GetContainerLogOffsetRequest) Since() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_GetContainerLogOffsetRequest) SetSince(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+// Conmon_GetContainerLogOffsetRequest_List is a list of Conmon_GetContainerLogOffsetRequest.
+type Conmon_GetContainerLogOffsetRequest_List = capnp.StructList[Conmon_GetContainerLogOffsetRequest]
+
+// NewConmon_GetContainerLogOffsetRequest creates a new list of Conmon_GetContainerLogOffsetRequest.
+func NewConmon_GetContainerLogOffsetRequest_List(s *capnp.Segment, sz int32) (Conmon_GetContainerLogOffsetRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_GetContainerLogOffsetRequest](l), err
+}
+
+// Conmon_GetContainerLogOffsetRequest_Future is a wrapper for a Conmon_GetContainerLogOffsetRequest promised by a client call.
+type Conmon_GetContainerLogOffsetRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_GetContainerLogOffsetRequest_Future) Struct() (Conmon_GetContainerLogOffsetRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_GetContainerLogOffsetRequest(s), err
+}
+
+type Conmon_GetContainerLogOffsetResponse capnp.Struct
+
+// Conmon_GetContainerLogOffsetResponse_TypeID is the unique identifier for the type Conmon_GetContainerLogOffsetResponse.
+const Conmon_GetContainerLogOffsetResponse_TypeID = 0xc09b02a80f2f64e9
+
+func NewConmon_GetContainerLogOffsetResponse(s *capnp.Segment) (Conmon_GetContainerLogOffsetResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_GetContainerLogOffsetResponse(st), err
+}
+
+func NewRootConmon_GetContainerLogOffsetResponse(s *capnp.Segment) (Conmon_GetContainerLogOffsetResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_GetContainerLogOffsetResponse(st), err
+}
+
+func ReadRootConmon_GetContainerLogOffsetResponse(msg *capnp.Message) (Conmon_GetContainerLogOffsetResponse, error) {
+	root, err := msg.Root()
+	return Conmon_GetContainerLogOffsetResponse(root.Struct()), err
+}
+
+func (s Conmon_GetContainerLogOffsetResponse) String() string {
+	str, _ := text.Marshal(0xc09b02a80f2f64e9, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_GetContainerLogOffsetResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_GetContainerLogOffsetResponse) DecodeFromPtr(p capnp.Ptr) Conmon_GetContainerLogOffsetResponse {
+	return Conmon_GetContainerLogOffsetResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_GetContainerLogOffsetResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_GetContainerLogOffsetResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_GetContainerLogOffsetResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_GetContainerLogOffsetResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_GetContainerLogOffsetResponse) Path() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_GetContainerLogOffsetResponse) HasPath() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_GetContainerLogOffsetResponse) PathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_GetContainerLogOffsetResponse) SetPath(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_GetContainerLogOffsetResponse) Offset() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_GetContainerLogOffsetResponse) SetOffset(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+// Conmon_GetContainerLogOffsetResponse_List is a list of Conmon_GetContainerLogOffsetResponse.
+type Conmon_GetContainerLogOffsetResponse_List = capnp.StructList[Conmon_GetContainerLogOffsetResponse]
+
+// NewConmon_GetContainerLogOffsetResponse creates a new list of Conmon_GetContainerLogOffsetResponse.
+func NewConmon_GetContainerLogOffsetResponse_List(s *capnp.Segment, sz int32) (Conmon_GetContainerLogOffsetResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_GetContainerLogOffsetResponse](l), err
+}
+
+// Conmon_GetContainerLogOffsetResponse_Future is a wrapper for a Conmon_GetContainerLogOffsetResponse promised by a client call.
+type Conmon_GetContainerLogOffsetResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_GetContainerLogOffsetResponse_Future) Struct() (Conmon_GetContainerLogOffsetResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_GetContainerLogOffsetResponse(s), err
+}
+
+type Conmon_FlushContainerLogsRequest capnp.Struct
+
+// Conmon_FlushContainerLogsRequest_TypeID is the unique identifier for the type Conmon_FlushContainerLogsRequest.
+const Conmon_FlushContainerLogsRequest_TypeID = 0xecb6b95b8291c07d
+
+func NewConmon_FlushContainerLogsRequest(s *capnp.Segment) (Conmon_FlushContainerLogsRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_FlushContainerLogsRequest(st), err
+}
+
+func NewRootConmon_FlushContainerLogsRequest(s *capnp.Segment) (Conmon_FlushContainerLogsRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_FlushContainerLogsRequest(st), err
+}
+
+func ReadRootConmon_FlushContainerLogsRequest(msg *capnp.Message) (Conmon_FlushContainerLogsRequest, error) {
+	root, err := msg.Root()
+	return Conmon_FlushContainerLogsRequest(root.Struct()), err
+}
+
+func (s Conmon_FlushContainerLogsRequest) String() string {
+	str, _ := text.Marshal(0xecb6b95b8291c07d, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_FlushContainerLogsRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_FlushContainerLogsRequest) DecodeFromPtr(p capnp.Ptr) Conmon_FlushContainerLogsRequest {
+	return Conmon_FlushContainerLogsRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_FlushContainerLogsRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_FlushContainerLogsRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_FlushContainerLogsRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_FlushContainerLogsRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_FlushContainerLogsRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_FlushContainerLogsRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_FlushContainerLogsRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_FlushContainerLogsRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_FlushContainerLogsRequest_List is a list of Conmon_FlushContainerLogsRequest.
+type Conmon_FlushContainerLogsRequest_List = capnp.StructList[Conmon_FlushContainerLogsRequest]
+
+// NewConmon_FlushContainerLogsRequest creates a new list of Conmon_FlushContainerLogsRequest.
+func NewConmon_FlushContainerLogsRequest_List(s *capnp.Segment, sz int32) (Conmon_FlushContainerLogsRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_FlushContainerLogsRequest](l), err
+}
+
+// Conmon_FlushContainerLogsRequest_Future is a wrapper for a Conmon_FlushContainerLogsRequest promised by a client call.
+type Conmon_FlushContainerLogsRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_FlushContainerLogsRequest_Future) Struct() (Conmon_FlushContainerLogsRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_FlushContainerLogsRequest(s), err
+}
+
+type Conmon_FlushContainerLogsResponse capnp.Struct
+
+// Conmon_FlushContainerLogsResponse_TypeID is the unique identifier for the type Conmon_FlushContainerLogsResponse.
+const Conmon_FlushContainerLogsResponse_TypeID = 0x850e9c47f95ce0ba
+
+func NewConmon_FlushContainerLogsResponse(s *capnp.Segment) (Conmon_FlushContainerLogsResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_FlushContainerLogsResponse(st), err
+}
+
+func NewRootConmon_FlushContainerLogsResponse(s *capnp.Segment) (Conmon_FlushContainerLogsResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_FlushContainerLogsResponse(st), err
+}
+
+func ReadRootConmon_FlushContainerLogsResponse(msg *capnp.Message) (Conmon_FlushContainerLogsResponse, error) {
+	root, err := msg.Root()
+	return Conmon_FlushContainerLogsResponse(root.Struct()), err
+}
+
+func (s Conmon_FlushContainerLogsResponse) String() string {
+	str, _ := text.Marshal(0x850e9c47f95ce0ba, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_FlushContainerLogsResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_FlushContainerLogsResponse) DecodeFromPtr(p capnp.Ptr) Conmon_FlushContainerLogsResponse {
+	return Conmon_FlushContainerLogsResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_FlushContainerLogsResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_FlushContainerLogsResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_FlushContainerLogsResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_FlushContainerLogsResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_FlushContainerLogsResponse_List is a list of Conmon_FlushContainerLogsResponse.
+type Conmon_FlushContainerLogsResponse_List = capnp.StructList[Conmon_FlushContainerLogsResponse]
+
+// NewConmon_FlushContainerLogsResponse creates a new list of Conmon_FlushContainerLogsResponse.
+func NewConmon_FlushContainerLogsResponse_List(s *capnp.Segment, sz int32) (Conmon_FlushContainerLogsResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_FlushContainerLogsResponse](l), err
+}
+
+// Conmon_FlushContainerLogsResponse_Future is a wrapper for a Conmon_FlushContainerLogsResponse promised by a client call.
+type Conmon_FlushContainerLogsResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_FlushContainerLogsResponse_Future) Struct() (Conmon_FlushContainerLogsResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_FlushContainerLogsResponse(s), err
+}
+
+type Conmon_ValidateCreateResponse capnp.Struct
+
+// Conmon_ValidateCreateResponse_TypeID is the unique identifier for the type Conmon_ValidateCreateResponse.
+const Conmon_ValidateCreateResponse_TypeID = 0xf73deda1e0d05941
+
+func NewConmon_ValidateCreateResponse(s *capnp.Segment) (Conmon_ValidateCreateResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ValidateCreateResponse(st), err
+}
+
+func NewRootConmon_ValidateCreateResponse(s *capnp.Segment) (Conmon_ValidateCreateResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ValidateCreateResponse(st), err
+}
+
+func ReadRootConmon_ValidateCreateResponse(msg *capnp.Message) (Conmon_ValidateCreateResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ValidateCreateResponse(root.Struct()), err
+}
+
+func (s Conmon_ValidateCreateResponse) String() string {
+	str, _ := text.Marshal(0xf73deda1e0d05941, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ValidateCreateResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ValidateCreateResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ValidateCreateResponse {
+	return Conmon_ValidateCreateResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ValidateCreateResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ValidateCreateResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ValidateCreateResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ValidateCreateResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ValidateCreateResponse) Problems() (Conmon_ValidateCreateResponse_Problem_List, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ValidateCreateResponse_Problem_List(p.List()), err
+}
+
+func (s Conmon_ValidateCreateResponse) HasProblems() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ValidateCreateResponse) SetProblems(v Conmon_ValidateCreateResponse_Problem_List) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewProblems sets the problems field to a newly
+// allocated Conmon_ValidateCreateResponse_Problem_List, preferring placement in s's segment.
+func (s Conmon_ValidateCreateResponse) NewProblems(n int32) (Conmon_ValidateCreateResponse_Problem_List, error) {
+	l, err := NewConmon_ValidateCreateResponse_Problem_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_ValidateCreateResponse_Problem_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_ValidateCreateResponse_List is a list of Conmon_ValidateCreateResponse.
+type Conmon_ValidateCreateResponse_List = capnp.StructList[Conmon_ValidateCreateResponse]
+
+// NewConmon_ValidateCreateResponse creates a new list of Conmon_ValidateCreateResponse.
+func NewConmon_ValidateCreateResponse_List(s *capnp.Segment, sz int32) (Conmon_ValidateCreateResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ValidateCreateResponse](l), err
+}
+
+// Conmon_ValidateCreateResponse_Future is a wrapper for a Conmon_ValidateCreateResponse promised by a client call.
+type Conmon_ValidateCreateResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ValidateCreateResponse_Future) Struct() (Conmon_ValidateCreateResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ValidateCreateResponse(s), err
+}
+
+type Conmon_ValidateCreateResponse_Problem capnp.Struct
+
+// Conmon_ValidateCreateResponse_Problem_TypeID is the unique identifier for the type Conmon_ValidateCreateResponse_Problem.
+const Conmon_ValidateCreateResponse_Problem_TypeID = 0xa4b1582163cd9942
+
+func NewConmon_ValidateCreateResponse_Problem(s *capnp.Segment) (Conmon_ValidateCreateResponse_Problem, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ValidateCreateResponse_Problem(st), err
+}
+
+func NewRootConmon_ValidateCreateResponse_Problem(s *capnp.Segment) (Conmon_ValidateCreateResponse_Problem, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ValidateCreateResponse_Problem(st), err
+}
+
+func ReadRootConmon_ValidateCreateResponse_Problem(msg *capnp.Message) (Conmon_ValidateCreateResponse_Problem, error) {
+	root, err := msg.Root()
+	return Conmon_ValidateCreateResponse_Problem(root.Struct()), err
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) String() string {
+	str, _ := text.Marshal(0xa4b1582163cd9942, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ValidateCreateResponse_Problem) DecodeFromPtr(p capnp.Ptr) Conmon_ValidateCreateResponse_Problem {
+	return Conmon_ValidateCreateResponse_Problem(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ValidateCreateResponse_Problem) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ValidateCreateResponse_Problem) Field() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) HasField() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) FieldBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) SetField(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) Message_() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) HasMessage_() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) Message_Bytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ValidateCreateResponse_Problem) SetMessage_(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+// Conmon_ValidateCreateResponse_Problem_List is a list of Conmon_ValidateCreateResponse_Problem.
+type Conmon_ValidateCreateResponse_Problem_List = capnp.StructList[Conmon_ValidateCreateResponse_Problem]
+
+// NewConmon_ValidateCreateResponse_Problem creates a new list of Conmon_ValidateCreateResponse_Problem.
+func NewConmon_ValidateCreateResponse_Problem_List(s *capnp.Segment, sz int32) (Conmon_ValidateCreateResponse_Problem_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_ValidateCreateResponse_Problem](l), err
+}
+
+// Conmon_ValidateCreateResponse_Problem_Future is a wrapper for a Conmon_ValidateCreateResponse_Problem promised by a client call.
+type Conmon_ValidateCreateResponse_Problem_Future struct{ *capnp.Future }
+
+func (p Conmon_ValidateCreateResponse_Problem_Future) Struct() (Conmon_ValidateCreateResponse_Problem, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ValidateCreateResponse_Problem(s), err
+}
+
+type Conmon_UpdateLogDriversRequest capnp.Struct
+
+// Conmon_UpdateLogDriversRequest_TypeID is the unique identifier for the type Conmon_UpdateLogDriversRequest.
+const Conmon_UpdateLogDriversRequest_TypeID = 0xcc3f479fbfe6ceff
+
+func NewConmon_UpdateLogDriversRequest(s *capnp.Segment) (Conmon_UpdateLogDriversRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_UpdateLogDriversRequest(st), err
+}
+
+func NewRootConmon_UpdateLogDriversRequest(s *capnp.Segment) (Conmon_UpdateLogDriversRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_UpdateLogDriversRequest(st), err
+}
+
+func ReadRootConmon_UpdateLogDriversRequest(msg *capnp.Message) (Conmon_UpdateLogDriversRequest, error) {
+	root, err := msg.Root()
+	return Conmon_UpdateLogDriversRequest(root.Struct()), err
+}
+
+func (s Conmon_UpdateLogDriversRequest) String() string {
+	str, _ := text.Marshal(0xcc3f479fbfe6ceff, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_UpdateLogDriversRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_UpdateLogDriversRequest) DecodeFromPtr(p capnp.Ptr) Conmon_UpdateLogDriversRequest {
+	return Conmon_UpdateLogDriversRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_UpdateLogDriversRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_UpdateLogDriversRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_UpdateLogDriversRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_UpdateLogDriversRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_UpdateLogDriversRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_UpdateLogDriversRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_UpdateLogDriversRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_UpdateLogDriversRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_UpdateLogDriversRequest) LogDrivers() (Conmon_LogDriver_List, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_LogDriver_List(p.List()), err
+}
+
+func (s Conmon_UpdateLogDriversRequest) HasLogDrivers() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_UpdateLogDriversRequest) SetLogDrivers(v Conmon_LogDriver_List) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewLogDrivers sets the logDrivers field to a newly
+// allocated Conmon_LogDriver_List, preferring placement in s's segment.
+func (s Conmon_UpdateLogDriversRequest) NewLogDrivers(n int32) (Conmon_LogDriver_List, error) {
+	l, err := NewConmon_LogDriver_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_LogDriver_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+
+// Conmon_UpdateLogDriversRequest_List is a list of Conmon_UpdateLogDriversRequest.
+type Conmon_UpdateLogDriversRequest_List = capnp.StructList[Conmon_UpdateLogDriversRequest]
+
+// NewConmon_UpdateLogDriversRequest creates a new list of Conmon_UpdateLogDriversRequest.
+func NewConmon_UpdateLogDriversRequest_List(s *capnp.Segment, sz int32) (Conmon_UpdateLogDriversRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_UpdateLogDriversRequest](l), err
+}
+
+// Conmon_UpdateLogDriversRequest_Future is a wrapper for a Conmon_UpdateLogDriversRequest promised by a client call.
+type Conmon_UpdateLogDriversRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_UpdateLogDriversRequest_Future) Struct() (Conmon_UpdateLogDriversRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_UpdateLogDriversRequest(s), err
+}
+
+type Conmon_UpdateLogDriversResponse capnp.Struct
+
+// Conmon_UpdateLogDriversResponse_TypeID is the unique identifier for the type Conmon_UpdateLogDriversResponse.
+const Conmon_UpdateLogDriversResponse_TypeID = 0xda7cf4f7252fd4a4
+
+func NewConmon_UpdateLogDriversResponse(s *capnp.Segment) (Conmon_UpdateLogDriversResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_UpdateLogDriversResponse(st), err
+}
+
+func NewRootConmon_UpdateLogDriversResponse(s *capnp.Segment) (Conmon_UpdateLogDriversResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_UpdateLogDriversResponse(st), err
+}
+
+func ReadRootConmon_UpdateLogDriversResponse(msg *capnp.Message) (Conmon_UpdateLogDriversResponse, error) {
+	root, err := msg.Root()
+	return Conmon_UpdateLogDriversResponse(root.Struct()), err
+}
+
+func (s Conmon_UpdateLogDriversResponse) String() string {
+	str, _ := text.Marshal(0xda7cf4f7252fd4a4, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_UpdateLogDriversResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_UpdateLogDriversResponse) DecodeFromPtr(p capnp.Ptr) Conmon_UpdateLogDriversResponse {
+	return Conmon_UpdateLogDriversResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_UpdateLogDriversResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_UpdateLogDriversResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_UpdateLogDriversResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_UpdateLogDriversResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_UpdateLogDriversResponse_List is a list of Conmon_UpdateLogDriversResponse.
+type Conmon_UpdateLogDriversResponse_List = capnp.StructList[Conmon_UpdateLogDriversResponse]
+
+// NewConmon_UpdateLogDriversResponse creates a new list of Conmon_UpdateLogDriversResponse.
+func NewConmon_UpdateLogDriversResponse_List(s *capnp.Segment, sz int32) (Conmon_UpdateLogDriversResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_UpdateLogDriversResponse](l), err
+}
+
+// Conmon_UpdateLogDriversResponse_Future is a wrapper for a Conmon_UpdateLogDriversResponse promised by a client call.
+type Conmon_UpdateLogDriversResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_UpdateLogDriversResponse_Future) Struct() (Conmon_UpdateLogDriversResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_UpdateLogDriversResponse(s), err
+}
+
+type Conmon_HealthRequest capnp.Struct
+
+// Conmon_HealthRequest_TypeID is the unique identifier for the type Conmon_HealthRequest.
+const Conmon_HealthRequest_TypeID = 0xf6bec8a61075119a
+
+func NewConmon_HealthRequest(s *capnp.Segment) (Conmon_HealthRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_HealthRequest(st), err
+}
+
+func NewRootConmon_HealthRequest(s *capnp.Segment) (Conmon_HealthRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_HealthRequest(st), err
+}
+
+func ReadRootConmon_HealthRequest(msg *capnp.Message) (Conmon_HealthRequest, error) {
+	root, err := msg.Root()
+	return Conmon_HealthRequest(root.Struct()), err
+}
+
+func (s Conmon_HealthRequest) String() string {
+	str, _ := text.Marshal(0xf6bec8a61075119a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_HealthRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_HealthRequest) DecodeFromPtr(p capnp.Ptr) Conmon_HealthRequest {
+	return Conmon_HealthRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_HealthRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_HealthRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_HealthRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_HealthRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_HealthRequest_List is a list of Conmon_HealthRequest.
+type Conmon_HealthRequest_List = capnp.StructList[Conmon_HealthRequest]
+
+// NewConmon_HealthRequest creates a new list of Conmon_HealthRequest.
+func NewConmon_HealthRequest_List(s *capnp.Segment, sz int32) (Conmon_HealthRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_HealthRequest](l), err
+}
+
+// Conmon_HealthRequest_Future is a wrapper for a Conmon_HealthRequest promised by a client call.
+type Conmon_HealthRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_HealthRequest_Future) Struct() (Conmon_HealthRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_HealthRequest(s), err
+}
+
+type Conmon_HealthResponse capnp.Struct
+
+// Conmon_HealthResponse_TypeID is the unique identifier for the type Conmon_HealthResponse.
+const Conmon_HealthResponse_TypeID = 0xbf563be589299eae
+
+func NewConmon_HealthResponse(s *capnp.Segment) (Conmon_HealthResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 1})
+	return Conmon_HealthResponse(st), err
+}
+
+func NewRootConmon_HealthResponse(s *capnp.Segment) (Conmon_HealthResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 1})
+	return Conmon_HealthResponse(st), err
+}
+
+func ReadRootConmon_HealthResponse(msg *capnp.Message) (Conmon_HealthResponse, error) {
+	root, err := msg.Root()
+	return Conmon_HealthResponse(root.Struct()), err
+}
+
+func (s Conmon_HealthResponse) String() string {
+	str, _ := text.Marshal(0xbf563be589299eae, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_HealthResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_HealthResponse) DecodeFromPtr(p capnp.Ptr) Conmon_HealthResponse {
+	return Conmon_HealthResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_HealthResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_HealthResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_HealthResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_HealthResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_HealthResponse) Healthy() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_HealthResponse) SetHealthy(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+func (s Conmon_HealthResponse) Problems() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_HealthResponse) HasProblems() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_HealthResponse) SetProblems(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewProblems sets the problems field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_HealthResponse) NewProblems(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_HealthResponse) ReactorLatencyMicros() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_HealthResponse) SetReactorLatencyMicros(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_HealthResponse) LastReaperWakeup() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_HealthResponse) SetLastReaperWakeup(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+// Conmon_HealthResponse_List is a list of Conmon_HealthResponse.
+type Conmon_HealthResponse_List = capnp.StructList[Conmon_HealthResponse]
+
+// NewConmon_HealthResponse creates a new list of Conmon_HealthResponse.
+func NewConmon_HealthResponse_List(s *capnp.Segment, sz int32) (Conmon_HealthResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 24, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_HealthResponse](l), err
+}
+
+// Conmon_HealthResponse_Future is a wrapper for a Conmon_HealthResponse promised by a client call.
+type Conmon_HealthResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_HealthResponse_Future) Struct() (Conmon_HealthResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_HealthResponse(s), err
+}
+
+type Conmon_SetTerminalModeRequest capnp.Struct
+
+// Conmon_SetTerminalModeRequest_TypeID is the unique identifier for the type Conmon_SetTerminalModeRequest.
+const Conmon_SetTerminalModeRequest_TypeID = 0xac051338e49b068e
+
+func NewConmon_SetTerminalModeRequest(s *capnp.Segment) (Conmon_SetTerminalModeRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_SetTerminalModeRequest(st), err
+}
+
+func NewRootConmon_SetTerminalModeRequest(s *capnp.Segment) (Conmon_SetTerminalModeRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_SetTerminalModeRequest(st), err
+}
+
+func ReadRootConmon_SetTerminalModeRequest(msg *capnp.Message) (Conmon_SetTerminalModeRequest, error) {
+	root, err := msg.Root()
+	return Conmon_SetTerminalModeRequest(root.Struct()), err
+}
+
+func (s Conmon_SetTerminalModeRequest) String() string {
+	str, _ := text.Marshal(0xac051338e49b068e, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_SetTerminalModeRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_SetTerminalModeRequest) DecodeFromPtr(p capnp.Ptr) Conmon_SetTerminalModeRequest {
+	return Conmon_SetTerminalModeRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_SetTerminalModeRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_SetTerminalModeRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_SetTerminalModeRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_SetTerminalModeRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_SetTerminalModeRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_SetTerminalModeRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_SetTerminalModeRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_SetTerminalModeRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_SetTerminalModeRequest) Echo() Conmon_SetTerminalModeRequest_Toggle {
+	return Conmon_SetTerminalModeRequest_Toggle(capnp.Struct(s).Uint16(0))
+}
+
+func (s Conmon_SetTerminalModeRequest) SetEcho(v Conmon_SetTerminalModeRequest_Toggle) {
+	capnp.Struct(s).SetUint16(0, uint16(v))
+}
+
+func (s Conmon_SetTerminalModeRequest) Raw() Conmon_SetTerminalModeRequest_Toggle {
+	return Conmon_SetTerminalModeRequest_Toggle(capnp.Struct(s).Uint16(2))
+}
+
+func (s Conmon_SetTerminalModeRequest) SetRaw(v Conmon_SetTerminalModeRequest_Toggle) {
+	capnp.Struct(s).SetUint16(2, uint16(v))
+}
+
+func (s Conmon_SetTerminalModeRequest) SendBreak() bool {
+	return capnp.Struct(s).Bit(32)
+}
+
+func (s Conmon_SetTerminalModeRequest) SetSendBreak(v bool) {
+	capnp.Struct(s).SetBit(32, v)
+}
+
+// Conmon_SetTerminalModeRequest_List is a list of Conmon_SetTerminalModeRequest.
+type Conmon_SetTerminalModeRequest_List = capnp.StructList[Conmon_SetTerminalModeRequest]
+
+// NewConmon_SetTerminalModeRequest creates a new list of Conmon_SetTerminalModeRequest.
+func NewConmon_SetTerminalModeRequest_List(s *capnp.Segment, sz int32) (Conmon_SetTerminalModeRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_SetTerminalModeRequest](l), err
+}
+
+// Conmon_SetTerminalModeRequest_Future is a wrapper for a Conmon_SetTerminalModeRequest promised by a client call.
+type Conmon_SetTerminalModeRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_SetTerminalModeRequest_Future) Struct() (Conmon_SetTerminalModeRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_SetTerminalModeRequest(s), err
+}
+
+type Conmon_SetTerminalModeRequest_Toggle uint16
+
+// Conmon_SetTerminalModeRequest_Toggle_TypeID is the unique identifier for the type Conmon_SetTerminalModeRequest_Toggle.
+const Conmon_SetTerminalModeRequest_Toggle_TypeID = 0x8092334775ad2247
+
+// Values of Conmon_SetTerminalModeRequest_Toggle.
+const (
+	Conmon_SetTerminalModeRequest_Toggle_unchanged Conmon_SetTerminalModeRequest_Toggle = 0
+	Conmon_SetTerminalModeRequest_Toggle_enabled   Conmon_SetTerminalModeRequest_Toggle = 1
+	Conmon_SetTerminalModeRequest_Toggle_disabled  Conmon_SetTerminalModeRequest_Toggle = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_SetTerminalModeRequest_Toggle) String() string {
+	switch c {
+	case Conmon_SetTerminalModeRequest_Toggle_unchanged:
+		return "unchanged"
+	case Conmon_SetTerminalModeRequest_Toggle_enabled:
+		return "enabled"
+	case Conmon_SetTerminalModeRequest_Toggle_disabled:
+		return "disabled"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_SetTerminalModeRequest_ToggleFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_SetTerminalModeRequest_ToggleFromString(c string) Conmon_SetTerminalModeRequest_Toggle {
+	switch c {
+	case "unchanged":
+		return Conmon_SetTerminalModeRequest_Toggle_unchanged
+	case "enabled":
+		return Conmon_SetTerminalModeRequest_Toggle_enabled
+	case "disabled":
+		return Conmon_SetTerminalModeRequest_Toggle_disabled
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_SetTerminalModeRequest_Toggle_List = capnp.EnumList[Conmon_SetTerminalModeRequest_Toggle]
+
+func NewConmon_SetTerminalModeRequest_Toggle_List(s *capnp.Segment, sz int32) (Conmon_SetTerminalModeRequest_Toggle_List, error) {
+	return capnp.NewEnumList[Conmon_SetTerminalModeRequest_Toggle](s, sz)
+}
+
+type Conmon_SetTerminalModeResponse capnp.Struct
+
+// Conmon_SetTerminalModeResponse_TypeID is the unique identifier for the type Conmon_SetTerminalModeResponse.
+const Conmon_SetTerminalModeResponse_TypeID = 0x9c9b74080d20d473
+
+func NewConmon_SetTerminalModeResponse(s *capnp.Segment) (Conmon_SetTerminalModeResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_SetTerminalModeResponse(st), err
+}
+
+func NewRootConmon_SetTerminalModeResponse(s *capnp.Segment) (Conmon_SetTerminalModeResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_SetTerminalModeResponse(st), err
+}
+
+func ReadRootConmon_SetTerminalModeResponse(msg *capnp.Message) (Conmon_SetTerminalModeResponse, error) {
+	root, err := msg.Root()
+	return Conmon_SetTerminalModeResponse(root.Struct()), err
+}
+
+func (s Conmon_SetTerminalModeResponse) String() string {
+	str, _ := text.Marshal(0x9c9b74080d20d473, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_SetTerminalModeResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_SetTerminalModeResponse) DecodeFromPtr(p capnp.Ptr) Conmon_SetTerminalModeResponse {
+	return Conmon_SetTerminalModeResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_SetTerminalModeResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_SetTerminalModeResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_SetTerminalModeResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_SetTerminalModeResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_SetTerminalModeResponse_List is a list of Conmon_SetTerminalModeResponse.
+type Conmon_SetTerminalModeResponse_List = capnp.StructList[Conmon_SetTerminalModeResponse]
+
+// NewConmon_SetTerminalModeResponse creates a new list of Conmon_SetTerminalModeResponse.
+func NewConmon_SetTerminalModeResponse_List(s *capnp.Segment, sz int32) (Conmon_SetTerminalModeResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_SetTerminalModeResponse](l), err
+}
+
+// Conmon_SetTerminalModeResponse_Future is a wrapper for a Conmon_SetTerminalModeResponse promised by a client call.
+type Conmon_SetTerminalModeResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_SetTerminalModeResponse_Future) Struct() (Conmon_SetTerminalModeResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_SetTerminalModeResponse(s), err
+}
+
+type Conmon_ReadExecOutputRequest capnp.Struct
+
+// Conmon_ReadExecOutputRequest_TypeID is the unique identifier for the type Conmon_ReadExecOutputRequest.
+const Conmon_ReadExecOutputRequest_TypeID = 0x85d91084036492b6
+
+func NewConmon_ReadExecOutputRequest(s *capnp.Segment) (Conmon_ReadExecOutputRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 2})
+	return Conmon_ReadExecOutputRequest(st), err
+}
+
+func NewRootConmon_ReadExecOutputRequest(s *capnp.Segment) (Conmon_ReadExecOutputRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 2})
+	return Conmon_ReadExecOutputRequest(st), err
+}
+
+func ReadRootConmon_ReadExecOutputRequest(msg *capnp.Message) (Conmon_ReadExecOutputRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ReadExecOutputRequest(root.Struct()), err
+}
+
+func (s Conmon_ReadExecOutputRequest) String() string {
+	str, _ := text.Marshal(0x85d91084036492b6, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ReadExecOutputRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ReadExecOutputRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ReadExecOutputRequest {
+	return Conmon_ReadExecOutputRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ReadExecOutputRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ReadExecOutputRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ReadExecOutputRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ReadExecOutputRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ReadExecOutputRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ReadExecOutputRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ReadExecOutputRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReadExecOutputRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ReadExecOutputRequest) SessionId() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_ReadExecOutputRequest) HasSessionId() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ReadExecOutputRequest) SessionIdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReadExecOutputRequest) SetSessionId(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_ReadExecOutputRequest) Pipe() Conmon_ReadExecOutputRequest_Pipe {
+	return Conmon_ReadExecOutputRequest_Pipe(capnp.Struct(s).Uint16(0))
+}
+
+func (s Conmon_ReadExecOutputRequest) SetPipe(v Conmon_ReadExecOutputRequest_Pipe) {
+	capnp.Struct(s).SetUint16(0, uint16(v))
+}
+
+func (s Conmon_ReadExecOutputRequest) Offset() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_ReadExecOutputRequest) SetOffset(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_ReadExecOutputRequest) Length() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_ReadExecOutputRequest) SetLength(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+// Conmon_ReadExecOutputRequest_List is a list of Conmon_ReadExecOutputRequest.
+type Conmon_ReadExecOutputRequest_List = capnp.StructList[Conmon_ReadExecOutputRequest]
+
+// NewConmon_ReadExecOutputRequest creates a new list of Conmon_ReadExecOutputRequest.
+func NewConmon_ReadExecOutputRequest_List(s *capnp.Segment, sz int32) (Conmon_ReadExecOutputRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 24, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_ReadExecOutputRequest](l), err
+}
+
+// Conmon_ReadExecOutputRequest_Future is a wrapper for a Conmon_ReadExecOutputRequest promised by a client call.
+type Conmon_ReadExecOutputRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ReadExecOutputRequest_Future) Struct() (Conmon_ReadExecOutputRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ReadExecOutputRequest(s), err
+}
+
+type Conmon_ReadExecOutputRequest_Pipe uint16
+
+// Conmon_ReadExecOutputRequest_Pipe_TypeID is the unique identifier for the type Conmon_ReadExecOutputRequest_Pipe.
+const Conmon_ReadExecOutputRequest_Pipe_TypeID = 0xcc1007203694c512
+
+// Values of Conmon_ReadExecOutputRequest_Pipe.
+const (
+	Conmon_ReadExecOutputRequest_Pipe_stdout Conmon_ReadExecOutputRequest_Pipe = 0
+	Conmon_ReadExecOutputRequest_Pipe_stderr Conmon_ReadExecOutputRequest_Pipe = 1
+)
+
+// String returns the enum's constant name.
+func (c Conmon_ReadExecOutputRequest_Pipe) String() string {
+	switch c {
+	case Conmon_ReadExecOutputRequest_Pipe_stdout:
+		return "stdout"
+	case Conmon_ReadExecOutputRequest_Pipe_stderr:
+		return "stderr"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_ReadExecOutputRequest_PipeFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_ReadExecOutputRequest_PipeFromString(c string) Conmon_ReadExecOutputRequest_Pipe {
+	switch c {
+	case "stdout":
+		return Conmon_ReadExecOutputRequest_Pipe_stdout
+	case "stderr":
+		return Conmon_ReadExecOutputRequest_Pipe_stderr
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_ReadExecOutputRequest_Pipe_List = capnp.EnumList[Conmon_ReadExecOutputRequest_Pipe]
+
+func NewConmon_ReadExecOutputRequest_Pipe_List(s *capnp.Segment, sz int32) (Conmon_ReadExecOutputRequest_Pipe_List, error) {
+	return capnp.NewEnumList[Conmon_ReadExecOutputRequest_Pipe](s, sz)
+}
+
+type Conmon_ReadExecOutputResponse capnp.Struct
+
+// Conmon_ReadExecOutputResponse_TypeID is the unique identifier for the type Conmon_ReadExecOutputResponse.
+const Conmon_ReadExecOutputResponse_TypeID = 0xa1d10ffd58496af1
+
+func NewConmon_ReadExecOutputResponse(s *capnp.Segment) (Conmon_ReadExecOutputResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_ReadExecOutputResponse(st), err
+}
+
+func NewRootConmon_ReadExecOutputResponse(s *capnp.Segment) (Conmon_ReadExecOutputResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_ReadExecOutputResponse(st), err
+}
+
+func ReadRootConmon_ReadExecOutputResponse(msg *capnp.Message) (Conmon_ReadExecOutputResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ReadExecOutputResponse(root.Struct()), err
+}
+
+func (s Conmon_ReadExecOutputResponse) String() string {
+	str, _ := text.Marshal(0xa1d10ffd58496af1, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ReadExecOutputResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ReadExecOutputResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ReadExecOutputResponse {
+	return Conmon_ReadExecOutputResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ReadExecOutputResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ReadExecOutputResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ReadExecOutputResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ReadExecOutputResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ReadExecOutputResponse) Data() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_ReadExecOutputResponse) HasData() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ReadExecOutputResponse) SetData(v []byte) error {
+	return capnp.Struct(s).SetData(0, v)
+}
+
+func (s Conmon_ReadExecOutputResponse) Eof() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_ReadExecOutputResponse) SetEof(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+// Conmon_ReadExecOutputResponse_List is a list of Conmon_ReadExecOutputResponse.
+type Conmon_ReadExecOutputResponse_List = capnp.StructList[Conmon_ReadExecOutputResponse]
+
+// NewConmon_ReadExecOutputResponse creates a new list of Conmon_ReadExecOutputResponse.
+func NewConmon_ReadExecOutputResponse_List(s *capnp.Segment, sz int32) (Conmon_ReadExecOutputResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ReadExecOutputResponse](l), err
+}
+
+// Conmon_ReadExecOutputResponse_Future is a wrapper for a Conmon_ReadExecOutputResponse promised by a client call.
+type Conmon_ReadExecOutputResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ReadExecOutputResponse_Future) Struct() (Conmon_ReadExecOutputResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ReadExecOutputResponse(s), err
+}
+
+type Conmon_MirrorContainerIORequest capnp.Struct
+
+// Conmon_MirrorContainerIORequest_TypeID is the unique identifier for the type Conmon_MirrorContainerIORequest.
+const Conmon_MirrorContainerIORequest_TypeID = 0xcab5a8bc4ea76d78
+
+func NewConmon_MirrorContainerIORequest(s *capnp.Segment) (Conmon_MirrorContainerIORequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_MirrorContainerIORequest(st), err
+}
+
+func NewRootConmon_MirrorContainerIORequest(s *capnp.Segment) (Conmon_MirrorContainerIORequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_MirrorContainerIORequest(st), err
+}
+
+func ReadRootConmon_MirrorContainerIORequest(msg *capnp.Message) (Conmon_MirrorContainerIORequest, error) {
+	root, err := msg.Root()
+	return Conmon_MirrorContainerIORequest(root.Struct()), err
+}
+
+func (s Conmon_MirrorContainerIORequest) String() string {
+	str, _ := text.Marshal(0xcab5a8bc4ea76d78, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_MirrorContainerIORequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_MirrorContainerIORequest) DecodeFromPtr(p capnp.Ptr) Conmon_MirrorContainerIORequest {
+	return Conmon_MirrorContainerIORequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_MirrorContainerIORequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_MirrorContainerIORequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_MirrorContainerIORequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_MirrorContainerIORequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_MirrorContainerIORequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_MirrorContainerIORequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_MirrorContainerIORequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_MirrorContainerIORequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_MirrorContainerIORequest) SocketPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_MirrorContainerIORequest) HasSocketPath() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_MirrorContainerIORequest) SocketPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_MirrorContainerIORequest) SetSocketPath(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_MirrorContainerIORequest) Pipe() Conmon_MirrorContainerIORequest_Pipe {
+	return Conmon_MirrorContainerIORequest_Pipe(capnp.Struct(s).Uint16(0))
+}
+
+func (s Conmon_MirrorContainerIORequest) SetPipe(v Conmon_MirrorContainerIORequest_Pipe) {
+	capnp.Struct(s).SetUint16(0, uint16(v))
+}
+
+func (s Conmon_MirrorContainerIORequest) DurationSec() uint32 {
+	return capnp.Struct(s).Uint32(4)
+}
+
+func (s Conmon_MirrorContainerIORequest) SetDurationSec(v uint32) {
+	capnp.Struct(s).SetUint32(4, v)
+}
+
+// Conmon_MirrorContainerIORequest_List is a list of Conmon_MirrorContainerIORequest.
+type Conmon_MirrorContainerIORequest_List = capnp.StructList[Conmon_MirrorContainerIORequest]
+
+// NewConmon_MirrorContainerIORequest creates a new list of Conmon_MirrorContainerIORequest.
+func NewConmon_MirrorContainerIORequest_List(s *capnp.Segment, sz int32) (Conmon_MirrorContainerIORequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_MirrorContainerIORequest](l), err
+}
+
+// Conmon_MirrorContainerIORequest_Future is a wrapper for a Conmon_MirrorContainerIORequest promised by a client call.
+type Conmon_MirrorContainerIORequest_Future struct{ *capnp.Future }
+
+func (p Conmon_MirrorContainerIORequest_Future) Struct() (Conmon_MirrorContainerIORequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_MirrorContainerIORequest(s), err
+}
+
+type Conmon_MirrorContainerIORequest_Pipe uint16
+
+// Conmon_MirrorContainerIORequest_Pipe_TypeID is the unique identifier for the type Conmon_MirrorContainerIORequest_Pipe.
+const Conmon_MirrorContainerIORequest_Pipe_TypeID = 0x8e73064b14079816
+
+// Values of Conmon_MirrorContainerIORequest_Pipe.
+const (
+	Conmon_MirrorContainerIORequest_Pipe_all    Conmon_MirrorContainerIORequest_Pipe = 0
+	Conmon_MirrorContainerIORequest_Pipe_stdout Conmon_MirrorContainerIORequest_Pipe = 1
+	Conmon_MirrorContainerIORequest_Pipe_stderr Conmon_MirrorContainerIORequest_Pipe = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_MirrorContainerIORequest_Pipe) String() string {
+	switch c {
+	case Conmon_MirrorContainerIORequest_Pipe_all:
+		return "all"
+	case Conmon_MirrorContainerIORequest_Pipe_stdout:
+		return "stdout"
+	case Conmon_MirrorContainerIORequest_Pipe_stderr:
+		return "stderr"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_MirrorContainerIORequest_PipeFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_MirrorContainerIORequest_PipeFromString(c string) Conmon_MirrorContainerIORequest_Pipe {
+	switch c {
+	case "all":
+		return Conmon_MirrorContainerIORequest_Pipe_all
+	case "stdout":
+		return Conmon_MirrorContainerIORequest_Pipe_stdout
+	case "stderr":
+		return Conmon_MirrorContainerIORequest_Pipe_stderr
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_MirrorContainerIORequest_Pipe_List = capnp.EnumList[Conmon_MirrorContainerIORequest_Pipe]
+
+func NewConmon_MirrorContainerIORequest_Pipe_List(s *capnp.Segment, sz int32) (Conmon_MirrorContainerIORequest_Pipe_List, error) {
+	return capnp.NewEnumList[Conmon_MirrorContainerIORequest_Pipe](s, sz)
+}
+
+type Conmon_MirrorContainerIOResponse capnp.Struct
+
+// Conmon_MirrorContainerIOResponse_TypeID is the unique identifier for the type Conmon_MirrorContainerIOResponse.
+const Conmon_MirrorContainerIOResponse_TypeID = 0xf3c6b4290560ba4b
+
+func NewConmon_MirrorContainerIOResponse(s *capnp.Segment) (Conmon_MirrorContainerIOResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_MirrorContainerIOResponse(st), err
+}
+
+func NewRootConmon_MirrorContainerIOResponse(s *capnp.Segment) (Conmon_MirrorContainerIOResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_MirrorContainerIOResponse(st), err
+}
+
+func ReadRootConmon_MirrorContainerIOResponse(msg *capnp.Message) (Conmon_MirrorContainerIOResponse, error) {
+	root, err := msg.Root()
+	return Conmon_MirrorContainerIOResponse(root.Struct()), err
+}
+
+func (s Conmon_MirrorContainerIOResponse) String() string {
+	str, _ := text.Marshal(0xf3c6b4290560ba4b, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_MirrorContainerIOResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_MirrorContainerIOResponse) DecodeFromPtr(p capnp.Ptr) Conmon_MirrorContainerIOResponse {
+	return Conmon_MirrorContainerIOResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_MirrorContainerIOResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_MirrorContainerIOResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_MirrorContainerIOResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_MirrorContainerIOResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_MirrorContainerIOResponse_List is a list of Conmon_MirrorContainerIOResponse.
+type Conmon_MirrorContainerIOResponse_List = capnp.StructList[Conmon_MirrorContainerIOResponse]
+
+// NewConmon_MirrorContainerIOResponse creates a new list of Conmon_MirrorContainerIOResponse.
+func NewConmon_MirrorContainerIOResponse_List(s *capnp.Segment, sz int32) (Conmon_MirrorContainerIOResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_MirrorContainerIOResponse](l), err
+}
+
+// Conmon_MirrorContainerIOResponse_Future is a wrapper for a Conmon_MirrorContainerIOResponse promised by a client call.
+type Conmon_MirrorContainerIOResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_MirrorContainerIOResponse_Future) Struct() (Conmon_MirrorContainerIOResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_MirrorContainerIOResponse(s), err
+}
+
+type Conmon_ContainerStatsRequest capnp.Struct
+
+// Conmon_ContainerStatsRequest_TypeID is the unique identifier for the type Conmon_ContainerStatsRequest.
+const Conmon_ContainerStatsRequest_TypeID = 0x870856d7715ebfde
+
+func NewConmon_ContainerStatsRequest(s *capnp.Segment) (Conmon_ContainerStatsRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ContainerStatsRequest(st), err
+}
+
+func NewRootConmon_ContainerStatsRequest(s *capnp.Segment) (Conmon_ContainerStatsRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ContainerStatsRequest(st), err
+}
+
+func ReadRootConmon_ContainerStatsRequest(msg *capnp.Message) (Conmon_ContainerStatsRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerStatsRequest(root.Struct()), err
+}
+
+func (s Conmon_ContainerStatsRequest) String() string {
+	str, _ := text.Marshal(0x870856d7715ebfde, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerStatsRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerStatsRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerStatsRequest {
+	return Conmon_ContainerStatsRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerStatsRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerStatsRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerStatsRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerStatsRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerStatsRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ContainerStatsRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ContainerStatsRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ContainerStatsRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_ContainerStatsRequest_List is a list of Conmon_ContainerStatsRequest.
+type Conmon_ContainerStatsRequest_List = capnp.StructList[Conmon_ContainerStatsRequest]
+
+// NewConmon_ContainerStatsRequest creates a new list of Conmon_ContainerStatsRequest.
+func NewConmon_ContainerStatsRequest_List(s *capnp.Segment, sz int32) (Conmon_ContainerStatsRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ContainerStatsRequest](l), err
+}
+
+// Conmon_ContainerStatsRequest_Future is a wrapper for a Conmon_ContainerStatsRequest promised by a client call.
+type Conmon_ContainerStatsRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerStatsRequest_Future) Struct() (Conmon_ContainerStatsRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerStatsRequest(s), err
+}
+
+type Conmon_Pressure capnp.Struct
+
+// Conmon_Pressure_TypeID is the unique identifier for the type Conmon_Pressure.
+const Conmon_Pressure_TypeID = 0xf214a606e1c68565
+
+func NewConmon_Pressure(s *capnp.Segment) (Conmon_Pressure, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 0})
+	return Conmon_Pressure(st), err
+}
+
+func NewRootConmon_Pressure(s *capnp.Segment) (Conmon_Pressure, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 0})
+	return Conmon_Pressure(st), err
+}
+
+func ReadRootConmon_Pressure(msg *capnp.Message) (Conmon_Pressure, error) {
+	root, err := msg.Root()
+	return Conmon_Pressure(root.Struct()), err
+}
+
+func (s Conmon_Pressure) String() string {
+	str, _ := text.Marshal(0xf214a606e1c68565, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_Pressure) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_Pressure) DecodeFromPtr(p capnp.Ptr) Conmon_Pressure {
+	return Conmon_Pressure(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_Pressure) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_Pressure) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_Pressure) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_Pressure) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_Pressure) Avg10() float64 {
+	return math.Float64frombits(capnp.Struct(s).Uint64(0))
+}
+
+func (s Conmon_Pressure) SetAvg10(v float64) {
+	capnp.Struct(s).SetUint64(0, math.Float64bits(v))
+}
+
+func (s Conmon_Pressure) Avg60() float64 {
+	return math.Float64frombits(capnp.Struct(s).Uint64(8))
+}
+
+func (s Conmon_Pressure) SetAvg60(v float64) {
+	capnp.Struct(s).SetUint64(8, math.Float64bits(v))
+}
+
+func (s Conmon_Pressure) Avg300() float64 {
+	return math.Float64frombits(capnp.Struct(s).Uint64(16))
+}
+
+func (s Conmon_Pressure) SetAvg300(v float64) {
+	capnp.Struct(s).SetUint64(16, math.Float64bits(v))
+}
+
+func (s Conmon_Pressure) Total() uint64 {
+	return capnp.Struct(s).Uint64(24)
+}
+
+func (s Conmon_Pressure) SetTotal(v uint64) {
+	capnp.Struct(s).SetUint64(24, v)
+}
+
+// Conmon_Pressure_List is a list of Conmon_Pressure.
+type Conmon_Pressure_List = capnp.StructList[Conmon_Pressure]
+
+// NewConmon_Pressure creates a new list of Conmon_Pressure.
+func NewConmon_Pressure_List(s *capnp.Segment, sz int32) (Conmon_Pressure_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 32, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_Pressure](l), err
+}
+
+// Conmon_Pressure_Future is a wrapper for a Conmon_Pressure promised by a client call.
+type Conmon_Pressure_Future struct{ *capnp.Future }
+
+func (p Conmon_Pressure_Future) Struct() (Conmon_Pressure, error) {
+	s, err := p.Future.Struct()
+	return Conmon_Pressure(s), err
+}
+
+type Conmon_ResourcePressure capnp.Struct
+
+// Conmon_ResourcePressure_TypeID is the unique identifier for the type Conmon_ResourcePressure.
+const Conmon_ResourcePressure_TypeID = 0x91025b8ad8bd0617
+
+func NewConmon_ResourcePressure(s *capnp.Segment) (Conmon_ResourcePressure, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ResourcePressure(st), err
+}
+
+func NewRootConmon_ResourcePressure(s *capnp.Segment) (Conmon_ResourcePressure, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ResourcePressure(st), err
+}
+
+func ReadRootConmon_ResourcePressure(msg *capnp.Message) (Conmon_ResourcePressure, error) {
+	root, err := msg.Root()
+	return Conmon_ResourcePressure(root.Struct()), err
+}
+
+func (s Conmon_ResourcePressure) String() string {
+	str, _ := text.Marshal(0x91025b8ad8bd0617, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ResourcePressure) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ResourcePressure) DecodeFromPtr(p capnp.Ptr) Conmon_ResourcePressure {
+	return Conmon_ResourcePressure(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ResourcePressure) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ResourcePressure) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ResourcePressure) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ResourcePressure) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ResourcePressure) Some() (Conmon_Pressure, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_Pressure(p.Struct()), err
+}
+
+func (s Conmon_ResourcePressure) HasSome() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ResourcePressure) SetSome(v Conmon_Pressure) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewSome sets the some field to a newly
+// allocated Conmon_Pressure struct, preferring placement in s's segment.
+func (s Conmon_ResourcePressure) NewSome() (Conmon_Pressure, error) {
+	ss, err := NewConmon_Pressure(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_Pressure{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+func (s Conmon_ResourcePressure) Full() (Conmon_Pressure, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_Pressure(p.Struct()), err
+}
+
+func (s Conmon_ResourcePressure) HasFull() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ResourcePressure) SetFull(v Conmon_Pressure) error {
+	return capnp.Struct(s).SetPtr(1, capnp.Struct(v).ToPtr())
+}
+
+// NewFull sets the full field to a newly
+// allocated Conmon_Pressure struct, preferring placement in s's segment.
+func (s Conmon_ResourcePressure) NewFull() (Conmon_Pressure, error) {
+	ss, err := NewConmon_Pressure(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_Pressure{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_ResourcePressure_List is a list of Conmon_ResourcePressure.
+type Conmon_ResourcePressure_List = capnp.StructList[Conmon_ResourcePressure]
+
+// NewConmon_ResourcePressure creates a new list of Conmon_ResourcePressure.
+func NewConmon_ResourcePressure_List(s *capnp.Segment, sz int32) (Conmon_ResourcePressure_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_ResourcePressure](l), err
+}
+
+// Conmon_ResourcePressure_Future is a wrapper for a Conmon_ResourcePressure promised by a client call.
+type Conmon_ResourcePressure_Future struct{ *capnp.Future }
+
+func (p Conmon_ResourcePressure_Future) Struct() (Conmon_ResourcePressure, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ResourcePressure(s), err
+}
+
+func (p Conmon_ResourcePressure_Future) Some() Conmon_Pressure_Future {
+	return Conmon_Pressure_Future{Future: p.Future.Field(0, nil)}
+}
+
+func (p Conmon_ResourcePressure_Future) Full() Conmon_Pressure_Future {
+	return Conmon_Pressure_Future{Future: p.Future.Field(1, nil)}
+}
+
+type Conmon_ContainerStatsResponse capnp.Struct
+
+// Conmon_ContainerStatsResponse_TypeID is the unique identifier for the type Conmon_ContainerStatsResponse.
+const Conmon_ContainerStatsResponse_TypeID = 0x8ada080957d5db5d
+
+func NewConmon_ContainerStatsResponse(s *capnp.Segment) (Conmon_ContainerStatsResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 80, PointerCount: 3})
+	return Conmon_ContainerStatsResponse(st), err
+}
+
+func NewRootConmon_ContainerStatsResponse(s *capnp.Segment) (Conmon_ContainerStatsResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 80, PointerCount: 3})
+	return Conmon_ContainerStatsResponse(st), err
+}
+
+func ReadRootConmon_ContainerStatsResponse(msg *capnp.Message) (Conmon_ContainerStatsResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerStatsResponse(root.Struct()), err
+}
+
+func (s Conmon_ContainerStatsResponse) String() string {
+	str, _ := text.Marshal(0x8ada080957d5db5d, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerStatsResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerStatsResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerStatsResponse {
+	return Conmon_ContainerStatsResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerStatsResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerStatsResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerStatsResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerStatsResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerStatsResponse) PressureSampled() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_ContainerStatsResponse) SetPressureSampled(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+func (s Conmon_ContainerStatsResponse) PressureTimestamp() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_ContainerStatsResponse) SetPressureTimestamp(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_ContainerStatsResponse) CpuPressure() (Conmon_ResourcePressure, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ResourcePressure(p.Struct()), err
+}
+
+func (s Conmon_ContainerStatsResponse) HasCpuPressure() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ContainerStatsResponse) SetCpuPressure(v Conmon_ResourcePressure) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewCpuPressure sets the cpuPressure field to a newly
+// allocated Conmon_ResourcePressure struct, preferring placement in s's segment.
+func (s Conmon_ContainerStatsResponse) NewCpuPressure() (Conmon_ResourcePressure, error) {
+	ss, err := NewConmon_ResourcePressure(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ResourcePressure{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+func (s Conmon_ContainerStatsResponse) MemoryPressure() (Conmon_ResourcePressure, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_ResourcePressure(p.Struct()), err
+}
+
+func (s Conmon_ContainerStatsResponse) HasMemoryPressure() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ContainerStatsResponse) SetMemoryPressure(v Conmon_ResourcePressure) error {
+	return capnp.Struct(s).SetPtr(1, capnp.Struct(v).ToPtr())
+}
+
+// NewMemoryPressure sets the memoryPressure field to a newly
+// allocated Conmon_ResourcePressure struct, preferring placement in s's segment.
+func (s Conmon_ContainerStatsResponse) NewMemoryPressure() (Conmon_ResourcePressure, error) {
+	ss, err := NewConmon_ResourcePressure(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ResourcePressure{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+func (s Conmon_ContainerStatsResponse) IoPressure() (Conmon_ResourcePressure, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return Conmon_ResourcePressure(p.Struct()), err
+}
+
+func (s Conmon_ContainerStatsResponse) HasIoPressure() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_ContainerStatsResponse) SetIoPressure(v Conmon_ResourcePressure) error {
+	return capnp.Struct(s).SetPtr(2, capnp.Struct(v).ToPtr())
+}
+
+// NewIoPressure sets the ioPressure field to a newly
+// allocated Conmon_ResourcePressure struct, preferring placement in s's segment.
+func (s Conmon_ContainerStatsResponse) NewIoPressure() (Conmon_ResourcePressure, error) {
+	ss, err := NewConmon_ResourcePressure(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ResourcePressure{}, err
+	}
+	err = capnp.Struct(s).SetPtr(2, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+func (s Conmon_ContainerStatsResponse) CgroupSampled() bool {
+	return capnp.Struct(s).Bit(1)
+}
+
+func (s Conmon_ContainerStatsResponse) SetCgroupSampled(v bool) {
+	capnp.Struct(s).SetBit(1, v)
+}
+
+func (s Conmon_ContainerStatsResponse) CpuUsageNanos() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_ContainerStatsResponse) SetCpuUsageNanos(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+func (s Conmon_ContainerStatsResponse) CpuUserNanos() uint64 {
+	return capnp.Struct(s).Uint64(24)
+}
+
+func (s Conmon_ContainerStatsResponse) SetCpuUserNanos(v uint64) {
+	capnp.Struct(s).SetUint64(24, v)
+}
+
+func (s Conmon_ContainerStatsResponse) CpuSystemNanos() uint64 {
+	return capnp.Struct(s).Uint64(32)
+}
+
+func (s Conmon_ContainerStatsResponse) SetCpuSystemNanos(v uint64) {
+	capnp.Struct(s).SetUint64(32, v)
+}
+
+func (s Conmon_ContainerStatsResponse) MemoryCurrentBytes() uint64 {
+	return capnp.Struct(s).Uint64(40)
+}
+
+func (s Conmon_ContainerStatsResponse) SetMemoryCurrentBytes(v uint64) {
+	capnp.Struct(s).SetUint64(40, v)
+}
+
+func (s Conmon_ContainerStatsResponse) MemoryPeakBytes() uint64 {
+	return capnp.Struct(s).Uint64(48)
+}
+
+func (s Conmon_ContainerStatsResponse) SetMemoryPeakBytes(v uint64) {
+	capnp.Struct(s).SetUint64(48, v)
+}
+
+func (s Conmon_ContainerStatsResponse) PidsCurrent() uint64 {
+	return capnp.Struct(s).Uint64(56)
+}
+
+func (s Conmon_ContainerStatsResponse) SetPidsCurrent(v uint64) {
+	capnp.Struct(s).SetUint64(56, v)
+}
+
+func (s Conmon_ContainerStatsResponse) IoReadBytes() uint64 {
+	return capnp.Struct(s).Uint64(64)
+}
+
+func (s Conmon_ContainerStatsResponse) SetIoReadBytes(v uint64) {
+	capnp.Struct(s).SetUint64(64, v)
+}
+
+func (s Conmon_ContainerStatsResponse) IoWriteBytes() uint64 {
+	return capnp.Struct(s).Uint64(72)
+}
+
+func (s Conmon_ContainerStatsResponse) SetIoWriteBytes(v uint64) {
+	capnp.Struct(s).SetUint64(72, v)
+}
+
+// Conmon_ContainerStatsResponse_List is a list of Conmon_ContainerStatsResponse.
+type Conmon_ContainerStatsResponse_List = capnp.StructList[Conmon_ContainerStatsResponse]
+
+// NewConmon_ContainerStatsResponse creates a new list of Conmon_ContainerStatsResponse.
+func NewConmon_ContainerStatsResponse_List(s *capnp.Segment, sz int32) (Conmon_ContainerStatsResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 80, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_ContainerStatsResponse](l), err
+}
+
+// Conmon_ContainerStatsResponse_Future is a wrapper for a Conmon_ContainerStatsResponse promised by a client call.
+type Conmon_ContainerStatsResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerStatsResponse_Future) Struct() (Conmon_ContainerStatsResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerStatsResponse(s), err
+}
+
+func (p Conmon_ContainerStatsResponse_Future) CpuPressure() Conmon_ResourcePressure_Future {
+	return Conmon_ResourcePressure_Future{Future: p.Future.Field(0, nil)}
+}
+
+func (p Conmon_ContainerStatsResponse_Future) MemoryPressure() Conmon_ResourcePressure_Future {
+	return Conmon_ResourcePressure_Future{Future: p.Future.Field(1, nil)}
+}
+
+func (p Conmon_ContainerStatsResponse_Future) IoPressure() Conmon_ResourcePressure_Future {
+	return Conmon_ResourcePressure_Future{Future: p.Future.Field(2, nil)}
+}
+
+type Conmon_FreezeContainerRequest capnp.Struct
+
+// Conmon_FreezeContainerRequest_TypeID is the unique identifier for the type Conmon_FreezeContainerRequest.
+const Conmon_FreezeContainerRequest_TypeID = 0xf88413404057f991
+
+func NewConmon_FreezeContainerRequest(s *capnp.Segment) (Conmon_FreezeContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_FreezeContainerRequest(st), err
+}
+
+func NewRootConmon_FreezeContainerRequest(s *capnp.Segment) (Conmon_FreezeContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_FreezeContainerRequest(st), err
+}
+
+func ReadRootConmon_FreezeContainerRequest(msg *capnp.Message) (Conmon_FreezeContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_FreezeContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_FreezeContainerRequest) String() string {
+	str, _ := text.Marshal(0xf88413404057f991, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_FreezeContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_FreezeContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_FreezeContainerRequest {
+	return Conmon_FreezeContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_FreezeContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_FreezeContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_FreezeContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_FreezeContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_FreezeContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_FreezeContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_FreezeContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_FreezeContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_FreezeContainerRequest_List is a list of Conmon_FreezeContainerRequest.
+type Conmon_FreezeContainerRequest_List = capnp.StructList[Conmon_FreezeContainerRequest]
+
+// NewConmon_FreezeContainerRequest creates a new list of Conmon_FreezeContainerRequest.
+func NewConmon_FreezeContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_FreezeContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_FreezeContainerRequest](l), err
+}
+
+// Conmon_FreezeContainerRequest_Future is a wrapper for a Conmon_FreezeContainerRequest promised by a client call.
+type Conmon_FreezeContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_FreezeContainerRequest_Future) Struct() (Conmon_FreezeContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_FreezeContainerRequest(s), err
+}
+
+type Conmon_FreezeContainerResponse capnp.Struct
+
+// Conmon_FreezeContainerResponse_TypeID is the unique identifier for the type Conmon_FreezeContainerResponse.
+const Conmon_FreezeContainerResponse_TypeID = 0xf05e62cf9998896b
+
+func NewConmon_FreezeContainerResponse(s *capnp.Segment) (Conmon_FreezeContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_FreezeContainerResponse(st), err
+}
+
+func NewRootConmon_FreezeContainerResponse(s *capnp.Segment) (Conmon_FreezeContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_FreezeContainerResponse(st), err
+}
+
+func ReadRootConmon_FreezeContainerResponse(msg *capnp.Message) (Conmon_FreezeContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_FreezeContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_FreezeContainerResponse) String() string {
+	str, _ := text.Marshal(0xf05e62cf9998896b, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_FreezeContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_FreezeContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_FreezeContainerResponse {
+	return Conmon_FreezeContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_FreezeContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_FreezeContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_FreezeContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_FreezeContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_FreezeContainerResponse_List is a list of Conmon_FreezeContainerResponse.
+type Conmon_FreezeContainerResponse_List = capnp.StructList[Conmon_FreezeContainerResponse]
+
+// NewConmon_FreezeContainerResponse creates a new list of Conmon_FreezeContainerResponse.
+func NewConmon_FreezeContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_FreezeContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_FreezeContainerResponse](l), err
+}
+
+// Conmon_FreezeContainerResponse_Future is a wrapper for a Conmon_FreezeContainerResponse promised by a client call.
+type Conmon_FreezeContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_FreezeContainerResponse_Future) Struct() (Conmon_FreezeContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_FreezeContainerResponse(s), err
+}
+
+type Conmon_ThawContainerRequest capnp.Struct
+
+// Conmon_ThawContainerRequest_TypeID is the unique identifier for the type Conmon_ThawContainerRequest.
+const Conmon_ThawContainerRequest_TypeID = 0x835910c9b39b7ee1
+
+func NewConmon_ThawContainerRequest(s *capnp.Segment) (Conmon_ThawContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ThawContainerRequest(st), err
+}
+
+func NewRootConmon_ThawContainerRequest(s *capnp.Segment) (Conmon_ThawContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ThawContainerRequest(st), err
+}
+
+func ReadRootConmon_ThawContainerRequest(msg *capnp.Message) (Conmon_ThawContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ThawContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_ThawContainerRequest) String() string {
+	str, _ := text.Marshal(0x835910c9b39b7ee1, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ThawContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ThawContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ThawContainerRequest {
+	return Conmon_ThawContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ThawContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ThawContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ThawContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ThawContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ThawContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ThawContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ThawContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ThawContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_ThawContainerRequest_List is a list of Conmon_ThawContainerRequest.
+type Conmon_ThawContainerRequest_List = capnp.StructList[Conmon_ThawContainerRequest]
+
+// NewConmon_ThawContainerRequest creates a new list of Conmon_ThawContainerRequest.
+func NewConmon_ThawContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_ThawContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ThawContainerRequest](l), err
+}
+
+// Conmon_ThawContainerRequest_Future is a wrapper for a Conmon_ThawContainerRequest promised by a client call.
+type Conmon_ThawContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ThawContainerRequest_Future) Struct() (Conmon_ThawContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ThawContainerRequest(s), err
+}
+
+type Conmon_ThawContainerResponse capnp.Struct
+
+// Conmon_ThawContainerResponse_TypeID is the unique identifier for the type Conmon_ThawContainerResponse.
+const Conmon_ThawContainerResponse_TypeID = 0xce09e0e0707785e3
+
+func NewConmon_ThawContainerResponse(s *capnp.Segment) (Conmon_ThawContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ThawContainerResponse(st), err
+}
+
+func NewRootConmon_ThawContainerResponse(s *capnp.Segment) (Conmon_ThawContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ThawContainerResponse(st), err
+}
+
+func ReadRootConmon_ThawContainerResponse(msg *capnp.Message) (Conmon_ThawContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ThawContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_ThawContainerResponse) String() string {
+	str, _ := text.Marshal(0xce09e0e0707785e3, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ThawContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ThawContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ThawContainerResponse {
+	return Conmon_ThawContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ThawContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ThawContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ThawContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ThawContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_ThawContainerResponse_List is a list of Conmon_ThawContainerResponse.
+type Conmon_ThawContainerResponse_List = capnp.StructList[Conmon_ThawContainerResponse]
+
+// NewConmon_ThawContainerResponse creates a new list of Conmon_ThawContainerResponse.
+func NewConmon_ThawContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_ThawContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ThawContainerResponse](l), err
+}
+
+// Conmon_ThawContainerResponse_Future is a wrapper for a Conmon_ThawContainerResponse promised by a client call.
+type Conmon_ThawContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ThawContainerResponse_Future) Struct() (Conmon_ThawContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ThawContainerResponse(s), err
+}
+
+type Conmon_ContainerProcessesRequest capnp.Struct
+
+// Conmon_ContainerProcessesRequest_TypeID is the unique identifier for the type Conmon_ContainerProcessesRequest.
+const Conmon_ContainerProcessesRequest_TypeID = 0xfc33a518596cc0f7
+
+func NewConmon_ContainerProcessesRequest(s *capnp.Segment) (Conmon_ContainerProcessesRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ContainerProcessesRequest(st), err
+}
+
+func NewRootConmon_ContainerProcessesRequest(s *capnp.Segment) (Conmon_ContainerProcessesRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ContainerProcessesRequest(st), err
+}
+
+func ReadRootConmon_ContainerProcessesRequest(msg *capnp.Message) (Conmon_ContainerProcessesRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerProcessesRequest(root.Struct()), err
+}
+
+func (s Conmon_ContainerProcessesRequest) String() string {
+	str, _ := text.Marshal(0xfc33a518596cc0f7, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerProcessesRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerProcessesRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerProcessesRequest {
+	return Conmon_ContainerProcessesRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerProcessesRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerProcessesRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerProcessesRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerProcessesRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerProcessesRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ContainerProcessesRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ContainerProcessesRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ContainerProcessesRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_ContainerProcessesRequest_List is a list of Conmon_ContainerProcessesRequest.
+type Conmon_ContainerProcessesRequest_List = capnp.StructList[Conmon_ContainerProcessesRequest]
+
+// NewConmon_ContainerProcessesRequest creates a new list of Conmon_ContainerProcessesRequest.
+func NewConmon_ContainerProcessesRequest_List(s *capnp.Segment, sz int32) (Conmon_ContainerProcessesRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ContainerProcessesRequest](l), err
+}
+
+// Conmon_ContainerProcessesRequest_Future is a wrapper for a Conmon_ContainerProcessesRequest promised by a client call.
+type Conmon_ContainerProcessesRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerProcessesRequest_Future) Struct() (Conmon_ContainerProcessesRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerProcessesRequest(s), err
+}
+
+type Conmon_ContainerProcess capnp.Struct
+
+// Conmon_ContainerProcess_TypeID is the unique identifier for the type Conmon_ContainerProcess.
+const Conmon_ContainerProcess_TypeID = 0x87eac53779263cee
+
+func NewConmon_ContainerProcess(s *capnp.Segment) (Conmon_ContainerProcess, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
+	return Conmon_ContainerProcess(st), err
+}
+
+func NewRootConmon_ContainerProcess(s *capnp.Segment) (Conmon_ContainerProcess, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
+	return Conmon_ContainerProcess(st), err
+}
+
+func ReadRootConmon_ContainerProcess(msg *capnp.Message) (Conmon_ContainerProcess, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerProcess(root.Struct()), err
+}
+
+func (s Conmon_ContainerProcess) String() string {
+	str, _ := text.Marshal(0x87eac53779263cee, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerProcess) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerProcess) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerProcess {
+	return Conmon_ContainerProcess(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerProcess) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerProcess) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerProcess) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerProcess) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerProcess) Pid() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_ContainerProcess) SetPid(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+func (s Conmon_ContainerProcess) Ppid() uint32 {
+	return capnp.Struct(s).Uint32(4)
+}
+
+func (s Conmon_ContainerProcess) SetPpid(v uint32) {
+	capnp.Struct(s).SetUint32(4, v)
+}
+
+func (s Conmon_ContainerProcess) Comm() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ContainerProcess) HasComm() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ContainerProcess) CommBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ContainerProcess) SetComm(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ContainerProcess) StartTime() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_ContainerProcess) SetStartTime(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+// Conmon_ContainerProcess_List is a list of Conmon_ContainerProcess.
+type Conmon_ContainerProcess_List = capnp.StructList[Conmon_ContainerProcess]
+
+// NewConmon_ContainerProcess creates a new list of Conmon_ContainerProcess.
+func NewConmon_ContainerProcess_List(s *capnp.Segment, sz int32) (Conmon_ContainerProcess_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ContainerProcess](l), err
+}
+
+// Conmon_ContainerProcess_Future is a wrapper for a Conmon_ContainerProcess promised by a client call.
+type Conmon_ContainerProcess_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerProcess_Future) Struct() (Conmon_ContainerProcess, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerProcess(s), err
+}
+
+type Conmon_ContainerProcessesResponse capnp.Struct
+
+// Conmon_ContainerProcessesResponse_TypeID is the unique identifier for the type Conmon_ContainerProcessesResponse.
+const Conmon_ContainerProcessesResponse_TypeID = 0xbeae16fd63482d9a
+
+func NewConmon_ContainerProcessesResponse(s *capnp.Segment) (Conmon_ContainerProcessesResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ContainerProcessesResponse(st), err
+}
+
+func NewRootConmon_ContainerProcessesResponse(s *capnp.Segment) (Conmon_ContainerProcessesResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ContainerProcessesResponse(st), err
+}
+
+func ReadRootConmon_ContainerProcessesResponse(msg *capnp.Message) (Conmon_ContainerProcessesResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerProcessesResponse(root.Struct()), err
+}
+
+func (s Conmon_ContainerProcessesResponse) String() string {
+	str, _ := text.Marshal(0xbeae16fd63482d9a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerProcessesResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerProcessesResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerProcessesResponse {
+	return Conmon_ContainerProcessesResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerProcessesResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerProcessesResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerProcessesResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerProcessesResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerProcessesResponse) Processes() (Conmon_ContainerProcess_List, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ContainerProcess_List(p.List()), err
+}
+
+func (s Conmon_ContainerProcessesResponse) HasProcesses() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ContainerProcessesResponse) SetProcesses(v Conmon_ContainerProcess_List) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewProcesses sets the processes field to a newly
+// allocated Conmon_ContainerProcess_List, preferring placement in s's segment.
+func (s Conmon_ContainerProcessesResponse) NewProcesses(n int32) (Conmon_ContainerProcess_List, error) {
+	l, err := NewConmon_ContainerProcess_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_ContainerProcess_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_ContainerProcessesResponse_List is a list of Conmon_ContainerProcessesResponse.
+type Conmon_ContainerProcessesResponse_List = capnp.StructList[Conmon_ContainerProcessesResponse]
+
+// NewConmon_ContainerProcessesResponse creates a new list of Conmon_ContainerProcessesResponse.
+func NewConmon_ContainerProcessesResponse_List(s *capnp.Segment, sz int32) (Conmon_ContainerProcessesResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ContainerProcessesResponse](l), err
+}
+
+// Conmon_ContainerProcessesResponse_Future is a wrapper for a Conmon_ContainerProcessesResponse promised by a client call.
+type Conmon_ContainerProcessesResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerProcessesResponse_Future) Struct() (Conmon_ContainerProcessesResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerProcessesResponse(s), err
+}
+
+type Conmon_RequestLatenciesRequest capnp.Struct
+
+// Conmon_RequestLatenciesRequest_TypeID is the unique identifier for the type Conmon_RequestLatenciesRequest.
+const Conmon_RequestLatenciesRequest_TypeID = 0x8d3e03c157278fae
+
+func NewConmon_RequestLatenciesRequest(s *capnp.Segment) (Conmon_RequestLatenciesRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_RequestLatenciesRequest(st), err
+}
+
+func NewRootConmon_RequestLatenciesRequest(s *capnp.Segment) (Conmon_RequestLatenciesRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_RequestLatenciesRequest(st), err
+}
+
+func ReadRootConmon_RequestLatenciesRequest(msg *capnp.Message) (Conmon_RequestLatenciesRequest, error) {
+	root, err := msg.Root()
+	return Conmon_RequestLatenciesRequest(root.Struct()), err
+}
+
+func (s Conmon_RequestLatenciesRequest) String() string {
+	str, _ := text.Marshal(0x8d3e03c157278fae, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_RequestLatenciesRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_RequestLatenciesRequest) DecodeFromPtr(p capnp.Ptr) Conmon_RequestLatenciesRequest {
+	return Conmon_RequestLatenciesRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_RequestLatenciesRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_RequestLatenciesRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_RequestLatenciesRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_RequestLatenciesRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_RequestLatenciesRequest_List is a list of Conmon_RequestLatenciesRequest.
+type Conmon_RequestLatenciesRequest_List = capnp.StructList[Conmon_RequestLatenciesRequest]
+
+// NewConmon_RequestLatenciesRequest creates a new list of Conmon_RequestLatenciesRequest.
+func NewConmon_RequestLatenciesRequest_List(s *capnp.Segment, sz int32) (Conmon_RequestLatenciesRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_RequestLatenciesRequest](l), err
+}
+
+// Conmon_RequestLatenciesRequest_Future is a wrapper for a Conmon_RequestLatenciesRequest promised by a client call.
+type Conmon_RequestLatenciesRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_RequestLatenciesRequest_Future) Struct() (Conmon_RequestLatenciesRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_RequestLatenciesRequest(s), err
+}
+
+type Conmon_LatencyBucket capnp.Struct
+
+// Conmon_LatencyBucket_TypeID is the unique identifier for the type Conmon_LatencyBucket.
+const Conmon_LatencyBucket_TypeID = 0xee874453d8f8a4a3
+
+func NewConmon_LatencyBucket(s *capnp.Segment) (Conmon_LatencyBucket, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 0})
+	return Conmon_LatencyBucket(st), err
+}
+
+func NewRootConmon_LatencyBucket(s *capnp.Segment) (Conmon_LatencyBucket, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 0})
+	return Conmon_LatencyBucket(st), err
+}
+
+func ReadRootConmon_LatencyBucket(msg *capnp.Message) (Conmon_LatencyBucket, error) {
+	root, err := msg.Root()
+	return Conmon_LatencyBucket(root.Struct()), err
+}
+
+func (s Conmon_LatencyBucket) String() string {
+	str, _ := text.Marshal(0xee874453d8f8a4a3, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_LatencyBucket) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_LatencyBucket) DecodeFromPtr(p capnp.Ptr) Conmon_LatencyBucket {
+	return Conmon_LatencyBucket(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_LatencyBucket) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_LatencyBucket) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_LatencyBucket) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_LatencyBucket) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_LatencyBucket) UpperBoundMicros() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_LatencyBucket) SetUpperBoundMicros(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_LatencyBucket) Count() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_LatencyBucket) SetCount(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+// Conmon_LatencyBucket_List is a list of Conmon_LatencyBucket.
+type Conmon_LatencyBucket_List = capnp.StructList[Conmon_LatencyBucket]
+
+// NewConmon_LatencyBucket creates a new list of Conmon_LatencyBucket.
+func NewConmon_LatencyBucket_List(s *capnp.Segment, sz int32) (Conmon_LatencyBucket_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_LatencyBucket](l), err
+}
+
+// Conmon_LatencyBucket_Future is a wrapper for a Conmon_LatencyBucket promised by a client call.
+type Conmon_LatencyBucket_Future struct{ *capnp.Future }
+
+func (p Conmon_LatencyBucket_Future) Struct() (Conmon_LatencyBucket, error) {
+	s, err := p.Future.Struct()
+	return Conmon_LatencyBucket(s), err
+}
+
+type Conmon_MethodLatency capnp.Struct
+
+// Conmon_MethodLatency_TypeID is the unique identifier for the type Conmon_MethodLatency.
+const Conmon_MethodLatency_TypeID = 0xd2220742c9b518d2
+
+func NewConmon_MethodLatency(s *capnp.Segment) (Conmon_MethodLatency, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 2})
+	return Conmon_MethodLatency(st), err
+}
+
+func NewRootConmon_MethodLatency(s *capnp.Segment) (Conmon_MethodLatency, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 2})
+	return Conmon_MethodLatency(st), err
+}
+
+func ReadRootConmon_MethodLatency(msg *capnp.Message) (Conmon_MethodLatency, error) {
+	root, err := msg.Root()
+	return Conmon_MethodLatency(root.Struct()), err
+}
+
+func (s Conmon_MethodLatency) String() string {
+	str, _ := text.Marshal(0xd2220742c9b518d2, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_MethodLatency) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_MethodLatency) DecodeFromPtr(p capnp.Ptr) Conmon_MethodLatency {
+	return Conmon_MethodLatency(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_MethodLatency) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_MethodLatency) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_MethodLatency) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_MethodLatency) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_MethodLatency) Method() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_MethodLatency) HasMethod() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_MethodLatency) MethodBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_MethodLatency) SetMethod(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_MethodLatency) Count() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_MethodLatency) SetCount(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_MethodLatency) SumMicros() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_MethodLatency) SetSumMicros(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_MethodLatency) MaxMicros() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_MethodLatency) SetMaxMicros(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+func (s Conmon_MethodLatency) Slow() uint64 {
+	return capnp.Struct(s).Uint64(24)
+}
+
+func (s Conmon_MethodLatency) SetSlow(v uint64) {
+	capnp.Struct(s).SetUint64(24, v)
+}
+
+func (s Conmon_MethodLatency) Buckets() (Conmon_LatencyBucket_List, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_LatencyBucket_List(p.List()), err
+}
+
+func (s Conmon_MethodLatency) HasBuckets() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_MethodLatency) SetBuckets(v Conmon_LatencyBucket_List) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewBuckets sets the buckets field to a newly
+// allocated Conmon_LatencyBucket_List, preferring placement in s's segment.
+func (s Conmon_MethodLatency) NewBuckets(n int32) (Conmon_LatencyBucket_List, error) {
+	l, err := NewConmon_LatencyBucket_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_LatencyBucket_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+
+// Conmon_MethodLatency_List is a list of Conmon_MethodLatency.
+type Conmon_MethodLatency_List = capnp.StructList[Conmon_MethodLatency]
+
+// NewConmon_MethodLatency creates a new list of Conmon_MethodLatency.
+func NewConmon_MethodLatency_List(s *capnp.Segment, sz int32) (Conmon_MethodLatency_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 32, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_MethodLatency](l), err
+}
+
+// Conmon_MethodLatency_Future is a wrapper for a Conmon_MethodLatency promised by a client call.
+type Conmon_MethodLatency_Future struct{ *capnp.Future }
+
+func (p Conmon_MethodLatency_Future) Struct() (Conmon_MethodLatency, error) {
+	s, err := p.Future.Struct()
+	return Conmon_MethodLatency(s), err
+}
+
+type Conmon_RequestLatenciesResponse capnp.Struct
+
+// Conmon_RequestLatenciesResponse_TypeID is the unique identifier for the type Conmon_RequestLatenciesResponse.
+const Conmon_RequestLatenciesResponse_TypeID = 0x881f619e1f61ed13
+
+func NewConmon_RequestLatenciesResponse(s *capnp.Segment) (Conmon_RequestLatenciesResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_RequestLatenciesResponse(st), err
+}
+
+func NewRootConmon_RequestLatenciesResponse(s *capnp.Segment) (Conmon_RequestLatenciesResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_RequestLatenciesResponse(st), err
+}
+
+func ReadRootConmon_RequestLatenciesResponse(msg *capnp.Message) (Conmon_RequestLatenciesResponse, error) {
+	root, err := msg.Root()
+	return Conmon_RequestLatenciesResponse(root.Struct()), err
+}
+
+func (s Conmon_RequestLatenciesResponse) String() string {
+	str, _ := text.Marshal(0x881f619e1f61ed13, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_RequestLatenciesResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_RequestLatenciesResponse) DecodeFromPtr(p capnp.Ptr) Conmon_RequestLatenciesResponse {
+	return Conmon_RequestLatenciesResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_RequestLatenciesResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_RequestLatenciesResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_RequestLatenciesResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_RequestLatenciesResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_RequestLatenciesResponse) Methods() (Conmon_MethodLatency_List, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_MethodLatency_List(p.List()), err
+}
+
+func (s Conmon_RequestLatenciesResponse) HasMethods() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_RequestLatenciesResponse) SetMethods(v Conmon_MethodLatency_List) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewMethods sets the methods field to a newly
+// allocated Conmon_MethodLatency_List, preferring placement in s's segment.
+func (s Conmon_RequestLatenciesResponse) NewMethods(n int32) (Conmon_MethodLatency_List, error) {
+	l, err := NewConmon_MethodLatency_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_MethodLatency_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_RequestLatenciesResponse_List is a list of Conmon_RequestLatenciesResponse.
+type Conmon_RequestLatenciesResponse_List = capnp.StructList[Conmon_RequestLatenciesResponse]
+
+// NewConmon_RequestLatenciesResponse creates a new list of Conmon_RequestLatenciesResponse.
+func NewConmon_RequestLatenciesResponse_List(s *capnp.Segment, sz int32) (Conmon_RequestLatenciesResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_RequestLatenciesResponse](l), err
+}
+
+// Conmon_RequestLatenciesResponse_Future is a wrapper for a Conmon_RequestLatenciesResponse promised by a client call.
+type Conmon_RequestLatenciesResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_RequestLatenciesResponse_Future) Struct() (Conmon_RequestLatenciesResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_RequestLatenciesResponse(s), err
+}
+
+type Conmon_KillContainerRequest capnp.Struct
+
+// Conmon_KillContainerRequest_TypeID is the unique identifier for the type Conmon_KillContainerRequest.
+const Conmon_KillContainerRequest_TypeID = 0xd285ab9e532f8e8f
+
+func NewConmon_KillContainerRequest(s *capnp.Segment) (Conmon_KillContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_KillContainerRequest(st), err
+}
+
+func NewRootConmon_KillContainerRequest(s *capnp.Segment) (Conmon_KillContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_KillContainerRequest(st), err
+}
+
+func ReadRootConmon_KillContainerRequest(msg *capnp.Message) (Conmon_KillContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_KillContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_KillContainerRequest) String() string {
+	str, _ := text.Marshal(0xd285ab9e532f8e8f, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_KillContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_KillContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_KillContainerRequest {
+	return Conmon_KillContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_KillContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_KillContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_KillContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_KillContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_KillContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_KillContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_KillContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_KillContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_KillContainerRequest) Signal() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_KillContainerRequest) SetSignal(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+// Conmon_KillContainerRequest_List is a list of Conmon_KillContainerRequest.
+type Conmon_KillContainerRequest_List = capnp.StructList[Conmon_KillContainerRequest]
+
+// NewConmon_KillContainerRequest creates a new list of Conmon_KillContainerRequest.
+func NewConmon_KillContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_KillContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_KillContainerRequest](l), err
+}
+
+// Conmon_KillContainerRequest_Future is a wrapper for a Conmon_KillContainerRequest promised by a client call.
+type Conmon_KillContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_KillContainerRequest_Future) Struct() (Conmon_KillContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_KillContainerRequest(s), err
+}
+
+type Conmon_KillContainerResponse capnp.Struct
+
+// Conmon_KillContainerResponse_TypeID is the unique identifier for the type Conmon_KillContainerResponse.
+const Conmon_KillContainerResponse_TypeID = 0xadb66abea677f8fc
+
+func NewConmon_KillContainerResponse(s *capnp.Segment) (Conmon_KillContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_KillContainerResponse(st), err
+}
+
+func NewRootConmon_KillContainerResponse(s *capnp.Segment) (Conmon_KillContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_KillContainerResponse(st), err
+}
+
+func ReadRootConmon_KillContainerResponse(msg *capnp.Message) (Conmon_KillContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_KillContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_KillContainerResponse) String() string {
+	str, _ := text.Marshal(0xadb66abea677f8fc, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_KillContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_KillContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_KillContainerResponse {
+	return Conmon_KillContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_KillContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_KillContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_KillContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_KillContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_KillContainerResponse_List is a list of Conmon_KillContainerResponse.
+type Conmon_KillContainerResponse_List = capnp.StructList[Conmon_KillContainerResponse]
+
+// NewConmon_KillContainerResponse creates a new list of Conmon_KillContainerResponse.
+func NewConmon_KillContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_KillContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_KillContainerResponse](l), err
+}
+
+// Conmon_KillContainerResponse_Future is a wrapper for a Conmon_KillContainerResponse promised by a client call.
+type Conmon_KillContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_KillContainerResponse_Future) Struct() (Conmon_KillContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_KillContainerResponse(s), err
+}
+
+type Conmon_StartContainerRequest capnp.Struct
+
+// Conmon_StartContainerRequest_TypeID is the unique identifier for the type Conmon_StartContainerRequest.
+const Conmon_StartContainerRequest_TypeID = 0xfef64f2c60ea9ff8
+
+func NewConmon_StartContainerRequest(s *capnp.Segment) (Conmon_StartContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_StartContainerRequest(st), err
+}
+
+func NewRootConmon_StartContainerRequest(s *capnp.Segment) (Conmon_StartContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_StartContainerRequest(st), err
+}
+
+func ReadRootConmon_StartContainerRequest(msg *capnp.Message) (Conmon_StartContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_StartContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_StartContainerRequest) String() string {
+	str, _ := text.Marshal(0xfef64f2c60ea9ff8, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_StartContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_StartContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_StartContainerRequest {
+	return Conmon_StartContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_StartContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_StartContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_StartContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_StartContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_StartContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_StartContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_StartContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_StartContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_StartContainerRequest_List is a list of Conmon_StartContainerRequest.
+type Conmon_StartContainerRequest_List = capnp.StructList[Conmon_StartContainerRequest]
+
+// NewConmon_StartContainerRequest creates a new list of Conmon_StartContainerRequest.
+func NewConmon_StartContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_StartContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_StartContainerRequest](l), err
+}
+
+// Conmon_StartContainerRequest_Future is a wrapper for a Conmon_StartContainerRequest promised by a client call.
+type Conmon_StartContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_StartContainerRequest_Future) Struct() (Conmon_StartContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_StartContainerRequest(s), err
+}
+
+type Conmon_StartContainerResponse capnp.Struct
+
+// Conmon_StartContainerResponse_TypeID is the unique identifier for the type Conmon_StartContainerResponse.
+const Conmon_StartContainerResponse_TypeID = 0x9cba43a78be72400
+
+func NewConmon_StartContainerResponse(s *capnp.Segment) (Conmon_StartContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_StartContainerResponse(st), err
+}
+
+func NewRootConmon_StartContainerResponse(s *capnp.Segment) (Conmon_StartContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_StartContainerResponse(st), err
+}
+
+func ReadRootConmon_StartContainerResponse(msg *capnp.Message) (Conmon_StartContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_StartContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_StartContainerResponse) String() string {
+	str, _ := text.Marshal(0x9cba43a78be72400, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_StartContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_StartContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_StartContainerResponse {
+	return Conmon_StartContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_StartContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_StartContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_StartContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_StartContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_StartContainerResponse_List is a list of Conmon_StartContainerResponse.
+type Conmon_StartContainerResponse_List = capnp.StructList[Conmon_StartContainerResponse]
+
+// NewConmon_StartContainerResponse creates a new list of Conmon_StartContainerResponse.
+func NewConmon_StartContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_StartContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_StartContainerResponse](l), err
+}
+
+// Conmon_StartContainerResponse_Future is a wrapper for a Conmon_StartContainerResponse promised by a client call.
+type Conmon_StartContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_StartContainerResponse_Future) Struct() (Conmon_StartContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_StartContainerResponse(s), err
+}
+
+type Conmon_ListContainersRequest capnp.Struct
+
+// Conmon_ListContainersRequest_TypeID is the unique identifier for the type Conmon_ListContainersRequest.
+const Conmon_ListContainersRequest_TypeID = 0xbc1bca51fe8ba645
+
+func NewConmon_ListContainersRequest(s *capnp.Segment) (Conmon_ListContainersRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ListContainersRequest(st), err
+}
+
+func NewRootConmon_ListContainersRequest(s *capnp.Segment) (Conmon_ListContainersRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ListContainersRequest(st), err
+}
+
+func ReadRootConmon_ListContainersRequest(msg *capnp.Message) (Conmon_ListContainersRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ListContainersRequest(root.Struct()), err
+}
+
+func (s Conmon_ListContainersRequest) String() string {
+	str, _ := text.Marshal(0xbc1bca51fe8ba645, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ListContainersRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ListContainersRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ListContainersRequest {
+	return Conmon_ListContainersRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ListContainersRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ListContainersRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ListContainersRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ListContainersRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_ListContainersRequest_List is a list of Conmon_ListContainersRequest.
+type Conmon_ListContainersRequest_List = capnp.StructList[Conmon_ListContainersRequest]
+
+// NewConmon_ListContainersRequest creates a new list of Conmon_ListContainersRequest.
+func NewConmon_ListContainersRequest_List(s *capnp.Segment, sz int32) (Conmon_ListContainersRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ListContainersRequest](l), err
+}
+
+// Conmon_ListContainersRequest_Future is a wrapper for a Conmon_ListContainersRequest promised by a client call.
+type Conmon_ListContainersRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ListContainersRequest_Future) Struct() (Conmon_ListContainersRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ListContainersRequest(s), err
+}
+
+type Conmon_Container capnp.Struct
+
+// Conmon_Container_TypeID is the unique identifier for the type Conmon_Container.
+const Conmon_Container_TypeID = 0xa3e6c413c9e0821d
+
+func NewConmon_Container(s *capnp.Segment) (Conmon_Container, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 3})
+	return Conmon_Container(st), err
+}
+
+func NewRootConmon_Container(s *capnp.Segment) (Conmon_Container, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 3})
+	return Conmon_Container(st), err
+}
+
+func ReadRootConmon_Container(msg *capnp.Message) (Conmon_Container, error) {
+	root, err := msg.Root()
+	return Conmon_Container(root.Struct()), err
+}
+
+func (s Conmon_Container) String() string {
+	str, _ := text.Marshal(0xa3e6c413c9e0821d, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_Container) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_Container) DecodeFromPtr(p capnp.Ptr) Conmon_Container {
+	return Conmon_Container(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_Container) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_Container) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_Container) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_Container) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_Container) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_Container) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_Container) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_Container) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_Container) Pid() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_Container) SetPid(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+func (s Conmon_Container) Created() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_Container) SetCreated(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_Container) Terminal() bool {
+	return capnp.Struct(s).Bit(32)
+}
+
+func (s Conmon_Container) SetTerminal(v bool) {
+	capnp.Struct(s).SetBit(32, v)
+}
+
+func (s Conmon_Container) Running() bool {
+	return capnp.Struct(s).Bit(33)
+}
+
+func (s Conmon_Container) SetRunning(v bool) {
+	capnp.Struct(s).SetBit(33, v)
+}
+
+func (s Conmon_Container) ExitPaths() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_Container) HasExitPaths() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_Container) SetExitPaths(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewExitPaths sets the exitPaths field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_Container) NewExitPaths(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_Container) OomExitPaths() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_Container) HasOomExitPaths() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_Container) SetOomExitPaths(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+}
+
+// NewOomExitPaths sets the oomExitPaths field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_Container) NewOomExitPaths(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
+	return l, err
+}
+
+// Conmon_Container_List is a list of Conmon_Container.
+type Conmon_Container_List = capnp.StructList[Conmon_Container]
+
+// NewConmon_Container creates a new list of Conmon_Container.
+func NewConmon_Container_List(s *capnp.Segment, sz int32) (Conmon_Container_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_Container](l), err
+}
+
+// Conmon_Container_Future is a wrapper for a Conmon_Container promised by a client call.
+type Conmon_Container_Future struct{ *capnp.Future }
+
+func (p Conmon_Container_Future) Struct() (Conmon_Container, error) {
+	s, err := p.Future.Struct()
+	return Conmon_Container(s), err
+}
+
+type Conmon_ListContainersResponse capnp.Struct
+
+// Conmon_ListContainersResponse_TypeID is the unique identifier for the type Conmon_ListContainersResponse.
+const Conmon_ListContainersResponse_TypeID = 0xfdae861fa8890aa3
+
+func NewConmon_ListContainersResponse(s *capnp.Segment) (Conmon_ListContainersResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListContainersResponse(st), err
+}
+
+func NewRootConmon_ListContainersResponse(s *capnp.Segment) (Conmon_ListContainersResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ListContainersResponse(st), err
+}
+
+func ReadRootConmon_ListContainersResponse(msg *capnp.Message) (Conmon_ListContainersResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ListContainersResponse(root.Struct()), err
+}
+
+func (s Conmon_ListContainersResponse) String() string {
+	str, _ := text.Marshal(0xfdae861fa8890aa3, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ListContainersResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ListContainersResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ListContainersResponse {
+	return Conmon_ListContainersResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ListContainersResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ListContainersResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ListContainersResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ListContainersResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ListContainersResponse) Containers() (Conmon_Container_List, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_Container_List(p.List()), err
+}
+
+func (s Conmon_ListContainersResponse) HasContainers() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ListContainersResponse) SetContainers(v Conmon_Container_List) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewContainers sets the containers field to a newly
+// allocated Conmon_Container_List, preferring placement in s's segment.
+func (s Conmon_ListContainersResponse) NewContainers(n int32) (Conmon_Container_List, error) {
+	l, err := NewConmon_Container_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_Container_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_ListContainersResponse_List is a list of Conmon_ListContainersResponse.
+type Conmon_ListContainersResponse_List = capnp.StructList[Conmon_ListContainersResponse]
+
+// NewConmon_ListContainersResponse creates a new list of Conmon_ListContainersResponse.
+func NewConmon_ListContainersResponse_List(s *capnp.Segment, sz int32) (Conmon_ListContainersResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ListContainersResponse](l), err
+}
+
+// Conmon_ListContainersResponse_Future is a wrapper for a Conmon_ListContainersResponse promised by a client call.
+type Conmon_ListContainersResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ListContainersResponse_Future) Struct() (Conmon_ListContainersResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ListContainersResponse(s), err
+}
+
+type Conmon_LinuxResources capnp.Struct
+
+// Conmon_LinuxResources_TypeID is the unique identifier for the type Conmon_LinuxResources.
+const Conmon_LinuxResources_TypeID = 0xcd8f48ddef242a41
+
+func NewConmon_LinuxResources(s *capnp.Segment) (Conmon_LinuxResources, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 48, PointerCount: 2})
+	return Conmon_LinuxResources(st), err
+}
+
+func NewRootConmon_LinuxResources(s *capnp.Segment) (Conmon_LinuxResources, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 48, PointerCount: 2})
+	return Conmon_LinuxResources(st), err
+}
+
+func ReadRootConmon_LinuxResources(msg *capnp.Message) (Conmon_LinuxResources, error) {
+	root, err := msg.Root()
+	return Conmon_LinuxResources(root.Struct()), err
+}
+
+func (s Conmon_LinuxResources) String() string {
+	str, _ := text.Marshal(0xcd8f48ddef242a41, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_LinuxResources) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_LinuxResources) DecodeFromPtr(p capnp.Ptr) Conmon_LinuxResources {
+	return Conmon_LinuxResources(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_LinuxResources) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_LinuxResources) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_LinuxResources) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_LinuxResources) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_LinuxResources) CpuShares() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_LinuxResources) SetCpuShares(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_LinuxResources) CpuQuota() int64 {
+	return int64(capnp.Struct(s).Uint64(8))
+}
+
+func (s Conmon_LinuxResources) SetCpuQuota(v int64) {
+	capnp.Struct(s).SetUint64(8, uint64(v))
+}
+
+func (s Conmon_LinuxResources) CpuPeriod() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_LinuxResources) SetCpuPeriod(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+func (s Conmon_LinuxResources) CpusetCpus() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_LinuxResources) HasCpusetCpus() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_LinuxResources) CpusetCpusBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_LinuxResources) SetCpusetCpus(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_LinuxResources) CpusetMems() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_LinuxResources) HasCpusetMems() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_LinuxResources) CpusetMemsBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_LinuxResources) SetCpusetMems(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_LinuxResources) MemoryLimit() int64 {
+	return int64(capnp.Struct(s).Uint64(24))
+}
+
+func (s Conmon_LinuxResources) SetMemoryLimit(v int64) {
+	capnp.Struct(s).SetUint64(24, uint64(v))
+}
+
+func (s Conmon_LinuxResources) MemorySwap() int64 {
+	return int64(capnp.Struct(s).Uint64(32))
+}
+
+func (s Conmon_LinuxResources) SetMemorySwap(v int64) {
+	capnp.Struct(s).SetUint64(32, uint64(v))
+}
+
+func (s Conmon_LinuxResources) PidsLimit() int64 {
+	return int64(capnp.Struct(s).Uint64(40))
+}
+
+func (s Conmon_LinuxResources) SetPidsLimit(v int64) {
+	capnp.Struct(s).SetUint64(40, uint64(v))
+}
+
+// Conmon_LinuxResources_List is a list of Conmon_LinuxResources.
+type Conmon_LinuxResources_List = capnp.StructList[Conmon_LinuxResources]
+
+// NewConmon_LinuxResources creates a new list of Conmon_LinuxResources.
+func NewConmon_LinuxResources_List(s *capnp.Segment, sz int32) (Conmon_LinuxResources_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 48, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_LinuxResources](l), err
+}
+
+// Conmon_LinuxResources_Future is a wrapper for a Conmon_LinuxResources promised by a client call.
+type Conmon_LinuxResources_Future struct{ *capnp.Future }
+
+func (p Conmon_LinuxResources_Future) Struct() (Conmon_LinuxResources, error) {
+	s, err := p.Future.Struct()
+	return Conmon_LinuxResources(s), err
+}
+
+type Conmon_UpdateContainerResourcesRequest capnp.Struct
+
+// Conmon_UpdateContainerResourcesRequest_TypeID is the unique identifier for the type Conmon_UpdateContainerResourcesRequest.
+const Conmon_UpdateContainerResourcesRequest_TypeID = 0xf3c4f20ca0d204ce
+
+func NewConmon_UpdateContainerResourcesRequest(s *capnp.Segment) (Conmon_UpdateContainerResourcesRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_UpdateContainerResourcesRequest(st), err
+}
+
+func NewRootConmon_UpdateContainerResourcesRequest(s *capnp.Segment) (Conmon_UpdateContainerResourcesRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_UpdateContainerResourcesRequest(st), err
+}
+
+func ReadRootConmon_UpdateContainerResourcesRequest(msg *capnp.Message) (Conmon_UpdateContainerResourcesRequest, error) {
+	root, err := msg.Root()
+	return Conmon_UpdateContainerResourcesRequest(root.Struct()), err
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) String() string {
+	str, _ := text.Marshal(0xf3c4f20ca0d204ce, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_UpdateContainerResourcesRequest) DecodeFromPtr(p capnp.Ptr) Conmon_UpdateContainerResourcesRequest {
+	return Conmon_UpdateContainerResourcesRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_UpdateContainerResourcesRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_UpdateContainerResourcesRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) Resources() (Conmon_LinuxResources, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_LinuxResources(p.Struct()), err
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) HasResources() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_UpdateContainerResourcesRequest) SetResources(v Conmon_LinuxResources) error {
+	return capnp.Struct(s).SetPtr(1, capnp.Struct(v).ToPtr())
+}
+
+// NewResources sets the resources field to a newly
+// allocated Conmon_LinuxResources struct, preferring placement in s's segment.
+func (s Conmon_UpdateContainerResourcesRequest) NewResources() (Conmon_LinuxResources, error) {
+	ss, err := NewConmon_LinuxResources(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_LinuxResources{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_UpdateContainerResourcesRequest_List is a list of Conmon_UpdateContainerResourcesRequest.
+type Conmon_UpdateContainerResourcesRequest_List = capnp.StructList[Conmon_UpdateContainerResourcesRequest]
+
+// NewConmon_UpdateContainerResourcesRequest creates a new list of Conmon_UpdateContainerResourcesRequest.
+func NewConmon_UpdateContainerResourcesRequest_List(s *capnp.Segment, sz int32) (Conmon_UpdateContainerResourcesRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_UpdateContainerResourcesRequest](l), err
+}
+
+// Conmon_UpdateContainerResourcesRequest_Future is a wrapper for a Conmon_UpdateContainerResourcesRequest promised by a client call.
+type Conmon_UpdateContainerResourcesRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_UpdateContainerResourcesRequest_Future) Struct() (Conmon_UpdateContainerResourcesRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_UpdateContainerResourcesRequest(s), err
+}
+
+func (p Conmon_UpdateContainerResourcesRequest_Future) Resources() Conmon_LinuxResources_Future {
+	return Conmon_LinuxResources_Future{Future: p.Future.Field(1, nil)}
+}
+
+type Conmon_UpdateContainerResourcesResponse capnp.Struct
+
+// Conmon_UpdateContainerResourcesResponse_TypeID is the unique identifier for the type Conmon_UpdateContainerResourcesResponse.
+const Conmon_UpdateContainerResourcesResponse_TypeID = 0x90dbf7ca3e53e1f6
+
+func NewConmon_UpdateContainerResourcesResponse(s *capnp.Segment) (Conmon_UpdateContainerResourcesResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_UpdateContainerResourcesResponse(st), err
+}
+
+func NewRootConmon_UpdateContainerResourcesResponse(s *capnp.Segment) (Conmon_UpdateContainerResourcesResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_UpdateContainerResourcesResponse(st), err
+}
+
+func ReadRootConmon_UpdateContainerResourcesResponse(msg *capnp.Message) (Conmon_UpdateContainerResourcesResponse, error) {
+	root, err := msg.Root()
+	return Conmon_UpdateContainerResourcesResponse(root.Struct()), err
+}
+
+func (s Conmon_UpdateContainerResourcesResponse) String() string {
+	str, _ := text.Marshal(0x90dbf7ca3e53e1f6, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_UpdateContainerResourcesResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_UpdateContainerResourcesResponse) DecodeFromPtr(p capnp.Ptr) Conmon_UpdateContainerResourcesResponse {
+	return Conmon_UpdateContainerResourcesResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_UpdateContainerResourcesResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_UpdateContainerResourcesResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_UpdateContainerResourcesResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_UpdateContainerResourcesResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_UpdateContainerResourcesResponse) Applied() (Conmon_LinuxResources, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_LinuxResources(p.Struct()), err
+}
+
+func (s Conmon_UpdateContainerResourcesResponse) HasApplied() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_UpdateContainerResourcesResponse) SetApplied(v Conmon_LinuxResources) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewApplied sets the applied field to a newly
+// allocated Conmon_LinuxResources struct, preferring placement in s's segment.
+func (s Conmon_UpdateContainerResourcesResponse) NewApplied() (Conmon_LinuxResources, error) {
+	ss, err := NewConmon_LinuxResources(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_LinuxResources{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_UpdateContainerResourcesResponse_List is a list of Conmon_UpdateContainerResourcesResponse.
+type Conmon_UpdateContainerResourcesResponse_List = capnp.StructList[Conmon_UpdateContainerResourcesResponse]
+
+// NewConmon_UpdateContainerResourcesResponse creates a new list of Conmon_UpdateContainerResourcesResponse.
+func NewConmon_UpdateContainerResourcesResponse_List(s *capnp.Segment, sz int32) (Conmon_UpdateContainerResourcesResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_UpdateContainerResourcesResponse](l), err
+}
+
+// Conmon_UpdateContainerResourcesResponse_Future is a wrapper for a Conmon_UpdateContainerResourcesResponse promised by a client call.
+type Conmon_UpdateContainerResourcesResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_UpdateContainerResourcesResponse_Future) Struct() (Conmon_UpdateContainerResourcesResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_UpdateContainerResourcesResponse(s), err
+}
+
+func (p Conmon_UpdateContainerResourcesResponse_Future) Applied() Conmon_LinuxResources_Future {
+	return Conmon_LinuxResources_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_PauseContainerRequest capnp.Struct
+
+// Conmon_PauseContainerRequest_TypeID is the unique identifier for the type Conmon_PauseContainerRequest.
+const Conmon_PauseContainerRequest_TypeID = 0xcefe45fd0d8dabff
+
+func NewConmon_PauseContainerRequest(s *capnp.Segment) (Conmon_PauseContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_PauseContainerRequest(st), err
+}
+
+func NewRootConmon_PauseContainerRequest(s *capnp.Segment) (Conmon_PauseContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_PauseContainerRequest(st), err
+}
+
+func ReadRootConmon_PauseContainerRequest(msg *capnp.Message) (Conmon_PauseContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_PauseContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_PauseContainerRequest) String() string {
+	str, _ := text.Marshal(0xcefe45fd0d8dabff, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_PauseContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_PauseContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_PauseContainerRequest {
+	return Conmon_PauseContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_PauseContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_PauseContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_PauseContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_PauseContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_PauseContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_PauseContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_PauseContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_PauseContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_PauseContainerRequest_List is a list of Conmon_PauseContainerRequest.
+type Conmon_PauseContainerRequest_List = capnp.StructList[Conmon_PauseContainerRequest]
+
+// NewConmon_PauseContainerRequest creates a new list of Conmon_PauseContainerRequest.
+func NewConmon_PauseContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_PauseContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_PauseContainerRequest](l), err
+}
+
+// Conmon_PauseContainerRequest_Future is a wrapper for a Conmon_PauseContainerRequest promised by a client call.
+type Conmon_PauseContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_PauseContainerRequest_Future) Struct() (Conmon_PauseContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_PauseContainerRequest(s), err
+}
+
+type Conmon_PauseContainerResponse capnp.Struct
+
+// Conmon_PauseContainerResponse_TypeID is the unique identifier for the type Conmon_PauseContainerResponse.
+const Conmon_PauseContainerResponse_TypeID = 0xab9e06d122b40479
+
+func NewConmon_PauseContainerResponse(s *capnp.Segment) (Conmon_PauseContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_PauseContainerResponse(st), err
+}
+
+func NewRootConmon_PauseContainerResponse(s *capnp.Segment) (Conmon_PauseContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_PauseContainerResponse(st), err
+}
+
+func ReadRootConmon_PauseContainerResponse(msg *capnp.Message) (Conmon_PauseContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_PauseContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_PauseContainerResponse) String() string {
+	str, _ := text.Marshal(0xab9e06d122b40479, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_PauseContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_PauseContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_PauseContainerResponse {
+	return Conmon_PauseContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_PauseContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_PauseContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_PauseContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_PauseContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_PauseContainerResponse_List is a list of Conmon_PauseContainerResponse.
+type Conmon_PauseContainerResponse_List = capnp.StructList[Conmon_PauseContainerResponse]
+
+// NewConmon_PauseContainerResponse creates a new list of Conmon_PauseContainerResponse.
+func NewConmon_PauseContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_PauseContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_PauseContainerResponse](l), err
+}
+
+// Conmon_PauseContainerResponse_Future is a wrapper for a Conmon_PauseContainerResponse promised by a client call.
+type Conmon_PauseContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_PauseContainerResponse_Future) Struct() (Conmon_PauseContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_PauseContainerResponse(s), err
+}
+
+type Conmon_UnpauseContainerRequest capnp.Struct
+
+// Conmon_UnpauseContainerRequest_TypeID is the unique identifier for the type Conmon_UnpauseContainerRequest.
+const Conmon_UnpauseContainerRequest_TypeID = 0x97094d00caad0b04
+
+func NewConmon_UnpauseContainerRequest(s *capnp.Segment) (Conmon_UnpauseContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_UnpauseContainerRequest(st), err
+}
+
+func NewRootConmon_UnpauseContainerRequest(s *capnp.Segment) (Conmon_UnpauseContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_UnpauseContainerRequest(st), err
+}
+
+func ReadRootConmon_UnpauseContainerRequest(msg *capnp.Message) (Conmon_UnpauseContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_UnpauseContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_UnpauseContainerRequest) String() string {
+	str, _ := text.Marshal(0x97094d00caad0b04, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_UnpauseContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_UnpauseContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_UnpauseContainerRequest {
+	return Conmon_UnpauseContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_UnpauseContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_UnpauseContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_UnpauseContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_UnpauseContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_UnpauseContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_UnpauseContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_UnpauseContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_UnpauseContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_UnpauseContainerRequest_List is a list of Conmon_UnpauseContainerRequest.
+type Conmon_UnpauseContainerRequest_List = capnp.StructList[Conmon_UnpauseContainerRequest]
+
+// NewConmon_UnpauseContainerRequest creates a new list of Conmon_UnpauseContainerRequest.
+func NewConmon_UnpauseContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_UnpauseContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_UnpauseContainerRequest](l), err
+}
+
+// Conmon_UnpauseContainerRequest_Future is a wrapper for a Conmon_UnpauseContainerRequest promised by a client call.
+type Conmon_UnpauseContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_UnpauseContainerRequest_Future) Struct() (Conmon_UnpauseContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_UnpauseContainerRequest(s), err
+}
+
+type Conmon_UnpauseContainerResponse capnp.Struct
+
+// Conmon_UnpauseContainerResponse_TypeID is the unique identifier for the type Conmon_UnpauseContainerResponse.
+const Conmon_UnpauseContainerResponse_TypeID = 0xf3fdff7dbc62813a
+
+func NewConmon_UnpauseContainerResponse(s *capnp.Segment) (Conmon_UnpauseContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_UnpauseContainerResponse(st), err
+}
+
+func NewRootConmon_UnpauseContainerResponse(s *capnp.Segment) (Conmon_UnpauseContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_UnpauseContainerResponse(st), err
+}
+
+func ReadRootConmon_UnpauseContainerResponse(msg *capnp.Message) (Conmon_UnpauseContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_UnpauseContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_UnpauseContainerResponse) String() string {
+	str, _ := text.Marshal(0xf3fdff7dbc62813a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_UnpauseContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_UnpauseContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_UnpauseContainerResponse {
+	return Conmon_UnpauseContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_UnpauseContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_UnpauseContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_UnpauseContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_UnpauseContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_UnpauseContainerResponse_List is a list of Conmon_UnpauseContainerResponse.
+type Conmon_UnpauseContainerResponse_List = capnp.StructList[Conmon_UnpauseContainerResponse]
+
+// NewConmon_UnpauseContainerResponse creates a new list of Conmon_UnpauseContainerResponse.
+func NewConmon_UnpauseContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_UnpauseContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_UnpauseContainerResponse](l), err
+}
+
+// Conmon_UnpauseContainerResponse_Future is a wrapper for a Conmon_UnpauseContainerResponse promised by a client call.
+type Conmon_UnpauseContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_UnpauseContainerResponse_Future) Struct() (Conmon_UnpauseContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_UnpauseContainerResponse(s), err
+}
+
+type Conmon_CheckpointContainerRequest capnp.Struct
+
+// Conmon_CheckpointContainerRequest_TypeID is the unique identifier for the type Conmon_CheckpointContainerRequest.
+const Conmon_CheckpointContainerRequest_TypeID = 0xcfae465adf42c669
+
+func NewConmon_CheckpointContainerRequest(s *capnp.Segment) (Conmon_CheckpointContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_CheckpointContainerRequest(st), err
+}
+
+func NewRootConmon_CheckpointContainerRequest(s *capnp.Segment) (Conmon_CheckpointContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_CheckpointContainerRequest(st), err
+}
+
+func ReadRootConmon_CheckpointContainerRequest(msg *capnp.Message) (Conmon_CheckpointContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_CheckpointContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_CheckpointContainerRequest) String() string {
+	str, _ := text.Marshal(0xcfae465adf42c669, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_CheckpointContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_CheckpointContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_CheckpointContainerRequest {
+	return Conmon_CheckpointContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_CheckpointContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_CheckpointContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_CheckpointContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_CheckpointContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_CheckpointContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_CheckpointContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_CheckpointContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CheckpointContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_CheckpointContainerRequest) ImagePath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_CheckpointContainerRequest) HasImagePath() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_CheckpointContainerRequest) ImagePathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CheckpointContainerRequest) SetImagePath(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_CheckpointContainerRequest) WorkPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_CheckpointContainerRequest) HasWorkPath() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_CheckpointContainerRequest) WorkPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CheckpointContainerRequest) SetWorkPath(v string) error {
+	return capnp.Struct(s).SetText(2, v)
+}
+
+func (s Conmon_CheckpointContainerRequest) LeaveRunning() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_CheckpointContainerRequest) SetLeaveRunning(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+func (s Conmon_CheckpointContainerRequest) TcpEstablished() bool {
+	return capnp.Struct(s).Bit(1)
+}
+
+func (s Conmon_CheckpointContainerRequest) SetTcpEstablished(v bool) {
+	capnp.Struct(s).SetBit(1, v)
+}
+
+// Conmon_CheckpointContainerRequest_List is a list of Conmon_CheckpointContainerRequest.
+type Conmon_CheckpointContainerRequest_List = capnp.StructList[Conmon_CheckpointContainerRequest]
+
+// NewConmon_CheckpointContainerRequest creates a new list of Conmon_CheckpointContainerRequest.
+func NewConmon_CheckpointContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_CheckpointContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_CheckpointContainerRequest](l), err
+}
+
+// Conmon_CheckpointContainerRequest_Future is a wrapper for a Conmon_CheckpointContainerRequest promised by a client call.
+type Conmon_CheckpointContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_CheckpointContainerRequest_Future) Struct() (Conmon_CheckpointContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_CheckpointContainerRequest(s), err
+}
+
+type Conmon_CheckpointContainerResponse capnp.Struct
+
+// Conmon_CheckpointContainerResponse_TypeID is the unique identifier for the type Conmon_CheckpointContainerResponse.
+const Conmon_CheckpointContainerResponse_TypeID = 0x82510d3464397f38
+
+func NewConmon_CheckpointContainerResponse(s *capnp.Segment) (Conmon_CheckpointContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_CheckpointContainerResponse(st), err
+}
+
+func NewRootConmon_CheckpointContainerResponse(s *capnp.Segment) (Conmon_CheckpointContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_CheckpointContainerResponse(st), err
+}
+
+func ReadRootConmon_CheckpointContainerResponse(msg *capnp.Message) (Conmon_CheckpointContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_CheckpointContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_CheckpointContainerResponse) String() string {
+	str, _ := text.Marshal(0x82510d3464397f38, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_CheckpointContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_CheckpointContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_CheckpointContainerResponse {
+	return Conmon_CheckpointContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_CheckpointContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_CheckpointContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_CheckpointContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_CheckpointContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_CheckpointContainerResponse) CriuLog() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_CheckpointContainerResponse) HasCriuLog() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_CheckpointContainerResponse) SetCriuLog(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewCriuLog sets the criuLog field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_CheckpointContainerResponse) NewCriuLog(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_CheckpointContainerResponse_List is a list of Conmon_CheckpointContainerResponse.
+type Conmon_CheckpointContainerResponse_List = capnp.StructList[Conmon_CheckpointContainerResponse]
+
+// NewConmon_CheckpointContainerResponse creates a new list of Conmon_CheckpointContainerResponse.
+func NewConmon_CheckpointContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_CheckpointContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_CheckpointContainerResponse](l), err
+}
+
+// Conmon_CheckpointContainerResponse_Future is a wrapper for a Conmon_CheckpointContainerResponse promised by a client call.
+type Conmon_CheckpointContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_CheckpointContainerResponse_Future) Struct() (Conmon_CheckpointContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_CheckpointContainerResponse(s), err
+}
+
+type Conmon_RestoreContainerRequest capnp.Struct
+
+// Conmon_RestoreContainerRequest_TypeID is the unique identifier for the type Conmon_RestoreContainerRequest.
+const Conmon_RestoreContainerRequest_TypeID = 0xeadf00d4ff560865
+
+func NewConmon_RestoreContainerRequest(s *capnp.Segment) (Conmon_RestoreContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_RestoreContainerRequest(st), err
+}
+
+func NewRootConmon_RestoreContainerRequest(s *capnp.Segment) (Conmon_RestoreContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_RestoreContainerRequest(st), err
+}
+
+func ReadRootConmon_RestoreContainerRequest(msg *capnp.Message) (Conmon_RestoreContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_RestoreContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_RestoreContainerRequest) String() string {
+	str, _ := text.Marshal(0xeadf00d4ff560865, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_RestoreContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_RestoreContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_RestoreContainerRequest {
+	return Conmon_RestoreContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_RestoreContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_RestoreContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_RestoreContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_RestoreContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_RestoreContainerRequest) Container() (Conmon_CreateContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_CreateContainerRequest(p.Struct()), err
+}
+
+func (s Conmon_RestoreContainerRequest) HasContainer() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_RestoreContainerRequest) SetContainer(v Conmon_CreateContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewContainer sets the container field to a newly
+// allocated Conmon_CreateContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_RestoreContainerRequest) NewContainer() (Conmon_CreateContainerRequest, error) {
+	ss, err := NewConmon_CreateContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_CreateContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+func (s Conmon_RestoreContainerRequest) ImagePath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_RestoreContainerRequest) HasImagePath() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_RestoreContainerRequest) ImagePathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_RestoreContainerRequest) SetImagePath(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_RestoreContainerRequest) WorkPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_RestoreContainerRequest) HasWorkPath() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_RestoreContainerRequest) WorkPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_RestoreContainerRequest) SetWorkPath(v string) error {
+	return capnp.Struct(s).SetText(2, v)
+}
+
+func (s Conmon_RestoreContainerRequest) TcpEstablished() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_RestoreContainerRequest) SetTcpEstablished(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+// Conmon_RestoreContainerRequest_List is a list of Conmon_RestoreContainerRequest.
+type Conmon_RestoreContainerRequest_List = capnp.StructList[Conmon_RestoreContainerRequest]
+
+// NewConmon_RestoreContainerRequest creates a new list of Conmon_RestoreContainerRequest.
+func NewConmon_RestoreContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_RestoreContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_RestoreContainerRequest](l), err
+}
+
+// Conmon_RestoreContainerRequest_Future is a wrapper for a Conmon_RestoreContainerRequest promised by a client call.
+type Conmon_RestoreContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_RestoreContainerRequest_Future) Struct() (Conmon_RestoreContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_RestoreContainerRequest(s), err
+}
+
+func (p Conmon_RestoreContainerRequest_Future) Container() Conmon_CreateContainerRequest_Future {
+	return Conmon_CreateContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_ExecContainerRequest capnp.Struct
+
+// Conmon_ExecContainerRequest_TypeID is the unique identifier for the type Conmon_ExecContainerRequest.
+const Conmon_ExecContainerRequest_TypeID = 0xc9971c07179123bc
+
+func NewConmon_ExecContainerRequest(s *capnp.Segment) (Conmon_ExecContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 4})
+	return Conmon_ExecContainerRequest(st), err
+}
+
+func NewRootConmon_ExecContainerRequest(s *capnp.Segment) (Conmon_ExecContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 4})
+	return Conmon_ExecContainerRequest(st), err
+}
+
+func ReadRootConmon_ExecContainerRequest(msg *capnp.Message) (Conmon_ExecContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ExecContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_ExecContainerRequest) String() string {
+	str, _ := text.Marshal(0xc9971c07179123bc, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ExecContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ExecContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ExecContainerRequest {
+	return Conmon_ExecContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ExecContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ExecContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ExecContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ExecContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ExecContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ExecContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ExecContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ExecContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ExecContainerRequest) Command() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_ExecContainerRequest) HasCommand() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ExecContainerRequest) SetCommand(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewCommand sets the command field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_ExecContainerRequest) NewCommand(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_ExecContainerRequest) Terminal() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_ExecContainerRequest) SetTerminal(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+func (s Conmon_ExecContainerRequest) TimeoutSec() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_ExecContainerRequest) SetTimeoutSec(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_ExecContainerRequest) ExitPaths() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_ExecContainerRequest) HasExitPaths() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_ExecContainerRequest) SetExitPaths(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+}
+
+// NewExitPaths sets the exitPaths field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_ExecContainerRequest) NewExitPaths(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
+	return l, err
+}
+
+func (s Conmon_ExecContainerRequest) AttachSocketPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return p.Text(), err
+}
+
+func (s Conmon_ExecContainerRequest) HasAttachSocketPath() bool {
+	return capnp.Struct(s).HasPtr(3)
+}
+
+func (s Conmon_ExecContainerRequest) AttachSocketPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ExecContainerRequest) SetAttachSocketPath(v string) error {
+	return capnp.Struct(s).SetText(3, v)
+}
+
+func (s Conmon_ExecContainerRequest) AttachProtocolVersion() uint32 {
+	return capnp.Struct(s).Uint32(4)
+}
+
+func (s Conmon_ExecContainerRequest) SetAttachProtocolVersion(v uint32) {
+	capnp.Struct(s).SetUint32(4, v)
+}
+
+// Conmon_ExecContainerRequest_List is a list of Conmon_ExecContainerRequest.
+type Conmon_ExecContainerRequest_List = capnp.StructList[Conmon_ExecContainerRequest]
+
+// NewConmon_ExecContainerRequest creates a new list of Conmon_ExecContainerRequest.
+func NewConmon_ExecContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_ExecContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 4}, sz)
+	return capnp.StructList[Conmon_ExecContainerRequest](l), err
+}
+
+// Conmon_ExecContainerRequest_Future is a wrapper for a Conmon_ExecContainerRequest promised by a client call.
+type Conmon_ExecContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ExecContainerRequest_Future) Struct() (Conmon_ExecContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ExecContainerRequest(s), err
+}
+
+type Conmon_ExecContainerResponse capnp.Struct
+
+// Conmon_ExecContainerResponse_TypeID is the unique identifier for the type Conmon_ExecContainerResponse.
+const Conmon_ExecContainerResponse_TypeID = 0xeacad74128d0e3e7
+
+func NewConmon_ExecContainerResponse(s *capnp.Segment) (Conmon_ExecContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_ExecContainerResponse(st), err
+}
+
+func NewRootConmon_ExecContainerResponse(s *capnp.Segment) (Conmon_ExecContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_ExecContainerResponse(st), err
+}
+
+func ReadRootConmon_ExecContainerResponse(msg *capnp.Message) (Conmon_ExecContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ExecContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_ExecContainerResponse) String() string {
+	str, _ := text.Marshal(0xeacad74128d0e3e7, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ExecContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ExecContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ExecContainerResponse {
+	return Conmon_ExecContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ExecContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ExecContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ExecContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ExecContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ExecContainerResponse) SessionId() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ExecContainerResponse) HasSessionId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ExecContainerResponse) SessionIdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ExecContainerResponse) SetSessionId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ExecContainerResponse) Pid() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_ExecContainerResponse) SetPid(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+func (s Conmon_ExecContainerResponse) AttachProtocolVersion() uint32 {
+	return capnp.Struct(s).Uint32(4)
+}
+
+func (s Conmon_ExecContainerResponse) SetAttachProtocolVersion(v uint32) {
+	capnp.Struct(s).SetUint32(4, v)
+}
+
+// Conmon_ExecContainerResponse_List is a list of Conmon_ExecContainerResponse.
+type Conmon_ExecContainerResponse_List = capnp.StructList[Conmon_ExecContainerResponse]
+
+// NewConmon_ExecContainerResponse creates a new list of Conmon_ExecContainerResponse.
+func NewConmon_ExecContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_ExecContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ExecContainerResponse](l), err
+}
+
+// Conmon_ExecContainerResponse_Future is a wrapper for a Conmon_ExecContainerResponse promised by a client call.
+type Conmon_ExecContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ExecContainerResponse_Future) Struct() (Conmon_ExecContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ExecContainerResponse(s), err
+}
+
+type Conmon_WaitContainerRequest capnp.Struct
+
+// Conmon_WaitContainerRequest_TypeID is the unique identifier for the type Conmon_WaitContainerRequest.
+const Conmon_WaitContainerRequest_TypeID = 0x8bf9d41f934c512d
+
+func NewConmon_WaitContainerRequest(s *capnp.Segment) (Conmon_WaitContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_WaitContainerRequest(st), err
+}
+
+func NewRootConmon_WaitContainerRequest(s *capnp.Segment) (Conmon_WaitContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_WaitContainerRequest(st), err
+}
+
+func ReadRootConmon_WaitContainerRequest(msg *capnp.Message) (Conmon_WaitContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_WaitContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_WaitContainerRequest) String() string {
+	str, _ := text.Marshal(0x8bf9d41f934c512d, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_WaitContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_WaitContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_WaitContainerRequest {
+	return Conmon_WaitContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_WaitContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_WaitContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_WaitContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_WaitContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_WaitContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_WaitContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_WaitContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_WaitContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_WaitContainerRequest_List is a list of Conmon_WaitContainerRequest.
+type Conmon_WaitContainerRequest_List = capnp.StructList[Conmon_WaitContainerRequest]
+
+// NewConmon_WaitContainerRequest creates a new list of Conmon_WaitContainerRequest.
+func NewConmon_WaitContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_WaitContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_WaitContainerRequest](l), err
+}
+
+// Conmon_WaitContainerRequest_Future is a wrapper for a Conmon_WaitContainerRequest promised by a client call.
+type Conmon_WaitContainerRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_WaitContainerRequest_Future) Struct() (Conmon_WaitContainerRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_WaitContainerRequest(s), err
+}
+
+type Conmon_WaitContainerResponse capnp.Struct
+
+// Conmon_WaitContainerResponse_TypeID is the unique identifier for the type Conmon_WaitContainerResponse.
+const Conmon_WaitContainerResponse_TypeID = 0xb6f01d18a2b0fd8e
+
+func NewConmon_WaitContainerResponse(s *capnp.Segment) (Conmon_WaitContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 0})
+	return Conmon_WaitContainerResponse(st), err
+}
+
+func NewRootConmon_WaitContainerResponse(s *capnp.Segment) (Conmon_WaitContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 0})
+	return Conmon_WaitContainerResponse(st), err
+}
+
+func ReadRootConmon_WaitContainerResponse(msg *capnp.Message) (Conmon_WaitContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_WaitContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_WaitContainerResponse) String() string {
+	str, _ := text.Marshal(0xb6f01d18a2b0fd8e, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_WaitContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_WaitContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_WaitContainerResponse {
+	return Conmon_WaitContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_WaitContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_WaitContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_WaitContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_WaitContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_WaitContainerResponse) ExitCode() int32 {
+	return int32(capnp.Struct(s).Uint32(0))
+}
+
+func (s Conmon_WaitContainerResponse) SetExitCode(v int32) {
+	capnp.Struct(s).SetUint32(0, uint32(v))
+}
+
+func (s Conmon_WaitContainerResponse) RawExitCode() int32 {
+	return int32(capnp.Struct(s).Uint32(4))
+}
+
+func (s Conmon_WaitContainerResponse) SetRawExitCode(v int32) {
+	capnp.Struct(s).SetUint32(4, uint32(v))
+}
+
+func (s Conmon_WaitContainerResponse) Signal() int32 {
+	return int32(capnp.Struct(s).Uint32(8))
+}
+
+func (s Conmon_WaitContainerResponse) SetSignal(v int32) {
+	capnp.Struct(s).SetUint32(8, uint32(v))
+}
+
+func (s Conmon_WaitContainerResponse) OomKilled() bool {
+	return capnp.Struct(s).Bit(96)
+}
+
+func (s Conmon_WaitContainerResponse) SetOomKilled(v bool) {
+	capnp.Struct(s).SetBit(96, v)
+}
+
+func (s Conmon_WaitContainerResponse) TimedOut() bool {
+	return capnp.Struct(s).Bit(97)
+}
+
+func (s Conmon_WaitContainerResponse) SetTimedOut(v bool) {
+	capnp.Struct(s).SetBit(97, v)
+}
+
+func (s Conmon_WaitContainerResponse) ExitedAt() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_WaitContainerResponse) SetExitedAt(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+// Conmon_WaitContainerResponse_List is a list of Conmon_WaitContainerResponse.
+type Conmon_WaitContainerResponse_List = capnp.StructList[Conmon_WaitContainerResponse]
+
+// NewConmon_WaitContainerResponse creates a new list of Conmon_WaitContainerResponse.
+func NewConmon_WaitContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_WaitContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 24, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_WaitContainerResponse](l), err
+}
+
+// Conmon_WaitContainerResponse_Future is a wrapper for a Conmon_WaitContainerResponse promised by a client call.
+type Conmon_WaitContainerResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_WaitContainerResponse_Future) Struct() (Conmon_WaitContainerResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_WaitContainerResponse(s), err
+}
+
+type Conmon_ShutdownRequest capnp.Struct
+
+// Conmon_ShutdownRequest_TypeID is the unique identifier for the type Conmon_ShutdownRequest.
+const Conmon_ShutdownRequest_TypeID = 0xbcc8964d9d37437b
+
+func NewConmon_ShutdownRequest(s *capnp.Segment) (Conmon_ShutdownRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 0})
+	return Conmon_ShutdownRequest(st), err
+}
+
+func NewRootConmon_ShutdownRequest(s *capnp.Segment) (Conmon_ShutdownRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 0})
+	return Conmon_ShutdownRequest(st), err
+}
+
+func ReadRootConmon_ShutdownRequest(msg *capnp.Message) (Conmon_ShutdownRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ShutdownRequest(root.Struct()), err
+}
+
+func (s Conmon_ShutdownRequest) String() string {
+	str, _ := text.Marshal(0xbcc8964d9d37437b, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ShutdownRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ShutdownRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ShutdownRequest {
+	return Conmon_ShutdownRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ShutdownRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ShutdownRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ShutdownRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ShutdownRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ShutdownRequest) WaitForContainers() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_ShutdownRequest) SetWaitForContainers(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+func (s Conmon_ShutdownRequest) TimeoutSec() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_ShutdownRequest) SetTimeoutSec(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+// Conmon_ShutdownRequest_List is a list of Conmon_ShutdownRequest.
+type Conmon_ShutdownRequest_List = capnp.StructList[Conmon_ShutdownRequest]
+
+// NewConmon_ShutdownRequest creates a new list of Conmon_ShutdownRequest.
+func NewConmon_ShutdownRequest_List(s *capnp.Segment, sz int32) (Conmon_ShutdownRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ShutdownRequest](l), err
+}
+
+// Conmon_ShutdownRequest_Future is a wrapper for a Conmon_ShutdownRequest promised by a client call.
+type Conmon_ShutdownRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ShutdownRequest_Future) Struct() (Conmon_ShutdownRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ShutdownRequest(s), err
+}
+
+type Conmon_ShutdownResponse capnp.Struct
+
+// Conmon_ShutdownResponse_TypeID is the unique identifier for the type Conmon_ShutdownResponse.
+const Conmon_ShutdownResponse_TypeID = 0x826958b3c0d0f6e3
+
+func NewConmon_ShutdownResponse(s *capnp.Segment) (Conmon_ShutdownResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ShutdownResponse(st), err
+}
+
+func NewRootConmon_ShutdownResponse(s *capnp.Segment) (Conmon_ShutdownResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ShutdownResponse(st), err
+}
+
+func ReadRootConmon_ShutdownResponse(msg *capnp.Message) (Conmon_ShutdownResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ShutdownResponse(root.Struct()), err
+}
+
+func (s Conmon_ShutdownResponse) String() string {
+	str, _ := text.Marshal(0x826958b3c0d0f6e3, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ShutdownResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ShutdownResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ShutdownResponse {
+	return Conmon_ShutdownResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ShutdownResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ShutdownResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ShutdownResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ShutdownResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_ShutdownResponse_List is a list of Conmon_ShutdownResponse.
+type Conmon_ShutdownResponse_List = capnp.StructList[Conmon_ShutdownResponse]
+
+// NewConmon_ShutdownResponse creates a new list of Conmon_ShutdownResponse.
+func NewConmon_ShutdownResponse_List(s *capnp.Segment, sz int32) (Conmon_ShutdownResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ShutdownResponse](l), err
+}
+
+// Conmon_ShutdownResponse_Future is a wrapper for a Conmon_ShutdownResponse promised by a client call.
+type Conmon_ShutdownResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ShutdownResponse_Future) Struct() (Conmon_ShutdownResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ShutdownResponse(s), err
+}
+
+type Conmon_ReloadConfigRequest capnp.Struct
+
+// Conmon_ReloadConfigRequest_TypeID is the unique identifier for the type Conmon_ReloadConfigRequest.
+const Conmon_ReloadConfigRequest_TypeID = 0xb6e12b86d68fe663
+
+func NewConmon_ReloadConfigRequest(s *capnp.Segment) (Conmon_ReloadConfigRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_ReloadConfigRequest(st), err
+}
+
+func NewRootConmon_ReloadConfigRequest(s *capnp.Segment) (Conmon_ReloadConfigRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_ReloadConfigRequest(st), err
+}
+
+func ReadRootConmon_ReloadConfigRequest(msg *capnp.Message) (Conmon_ReloadConfigRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ReloadConfigRequest(root.Struct()), err
+}
+
+func (s Conmon_ReloadConfigRequest) String() string {
+	str, _ := text.Marshal(0xb6e12b86d68fe663, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ReloadConfigRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ReloadConfigRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ReloadConfigRequest {
+	return Conmon_ReloadConfigRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ReloadConfigRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ReloadConfigRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ReloadConfigRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ReloadConfigRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ReloadConfigRequest) Runtime() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ReloadConfigRequest) HasRuntime() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ReloadConfigRequest) RuntimeBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReloadConfigRequest) SetRuntime(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ReloadConfigRequest) RuntimeRoot() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_ReloadConfigRequest) HasRuntimeRoot() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ReloadConfigRequest) RuntimeRootBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReloadConfigRequest) SetRuntimeRoot(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_ReloadConfigRequest) LogLevel() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_ReloadConfigRequest) HasLogLevel() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_ReloadConfigRequest) LogLevelBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReloadConfigRequest) SetLogLevel(v string) error {
+	return capnp.Struct(s).SetText(2, v)
+}
+
+// Conmon_ReloadConfigRequest_List is a list of Conmon_ReloadConfigRequest.
+type Conmon_ReloadConfigRequest_List = capnp.StructList[Conmon_ReloadConfigRequest]
+
+// NewConmon_ReloadConfigRequest creates a new list of Conmon_ReloadConfigRequest.
+func NewConmon_ReloadConfigRequest_List(s *capnp.Segment, sz int32) (Conmon_ReloadConfigRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_ReloadConfigRequest](l), err
+}
+
+// Conmon_ReloadConfigRequest_Future is a wrapper for a Conmon_ReloadConfigRequest promised by a client call.
+type Conmon_ReloadConfigRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ReloadConfigRequest_Future) Struct() (Conmon_ReloadConfigRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ReloadConfigRequest(s), err
+}
+
+type Conmon_ReloadConfigResponse capnp.Struct
+
+// Conmon_ReloadConfigResponse_TypeID is the unique identifier for the type Conmon_ReloadConfigResponse.
+const Conmon_ReloadConfigResponse_TypeID = 0xa439d311e69f29c8
+
+func NewConmon_ReloadConfigResponse(s *capnp.Segment) (Conmon_ReloadConfigResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_ReloadConfigResponse(st), err
+}
+
+func NewRootConmon_ReloadConfigResponse(s *capnp.Segment) (Conmon_ReloadConfigResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_ReloadConfigResponse(st), err
+}
+
+func ReadRootConmon_ReloadConfigResponse(msg *capnp.Message) (Conmon_ReloadConfigResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ReloadConfigResponse(root.Struct()), err
+}
+
+func (s Conmon_ReloadConfigResponse) String() string {
+	str, _ := text.Marshal(0xa439d311e69f29c8, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ReloadConfigResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ReloadConfigResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ReloadConfigResponse {
+	return Conmon_ReloadConfigResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ReloadConfigResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ReloadConfigResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ReloadConfigResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ReloadConfigResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ReloadConfigResponse) Runtime() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ReloadConfigResponse) HasRuntime() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ReloadConfigResponse) RuntimeBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReloadConfigResponse) SetRuntime(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ReloadConfigResponse) RuntimeRoot() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_ReloadConfigResponse) HasRuntimeRoot() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ReloadConfigResponse) RuntimeRootBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReloadConfigResponse) SetRuntimeRoot(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_ReloadConfigResponse) LogLevel() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_ReloadConfigResponse) HasLogLevel() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_ReloadConfigResponse) LogLevelBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReloadConfigResponse) SetLogLevel(v string) error {
+	return capnp.Struct(s).SetText(2, v)
+}
+
+// Conmon_ReloadConfigResponse_List is a list of Conmon_ReloadConfigResponse.
+type Conmon_ReloadConfigResponse_List = capnp.StructList[Conmon_ReloadConfigResponse]
+
+// NewConmon_ReloadConfigResponse creates a new list of Conmon_ReloadConfigResponse.
+func NewConmon_ReloadConfigResponse_List(s *capnp.Segment, sz int32) (Conmon_ReloadConfigResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_ReloadConfigResponse](l), err
+}
+
+// Conmon_ReloadConfigResponse_Future is a wrapper for a Conmon_ReloadConfigResponse promised by a client call.
+type Conmon_ReloadConfigResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ReloadConfigResponse_Future) Struct() (Conmon_ReloadConfigResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ReloadConfigResponse(s), err
+}
+
+type Conmon_ContainerStatusRequest capnp.Struct
+
+// Conmon_ContainerStatusRequest_TypeID is the unique identifier for the type Conmon_ContainerStatusRequest.
+const Conmon_ContainerStatusRequest_TypeID = 0xb4a5e5ca18fd98ef
+
+func NewConmon_ContainerStatusRequest(s *capnp.Segment) (Conmon_ContainerStatusRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ContainerStatusRequest(st), err
+}
+
+func NewRootConmon_ContainerStatusRequest(s *capnp.Segment) (Conmon_ContainerStatusRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_ContainerStatusRequest(st), err
+}
+
+func ReadRootConmon_ContainerStatusRequest(msg *capnp.Message) (Conmon_ContainerStatusRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerStatusRequest(root.Struct()), err
+}
+
+func (s Conmon_ContainerStatusRequest) String() string {
+	str, _ := text.Marshal(0xb4a5e5ca18fd98ef, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerStatusRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerStatusRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerStatusRequest {
+	return Conmon_ContainerStatusRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerStatusRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerStatusRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerStatusRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerStatusRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerStatusRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ContainerStatusRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ContainerStatusRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ContainerStatusRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_ContainerStatusRequest_List is a list of Conmon_ContainerStatusRequest.
+type Conmon_ContainerStatusRequest_List = capnp.StructList[Conmon_ContainerStatusRequest]
+
+// NewConmon_ContainerStatusRequest creates a new list of Conmon_ContainerStatusRequest.
+func NewConmon_ContainerStatusRequest_List(s *capnp.Segment, sz int32) (Conmon_ContainerStatusRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ContainerStatusRequest](l), err
+}
+
+// Conmon_ContainerStatusRequest_Future is a wrapper for a Conmon_ContainerStatusRequest promised by a client call.
+type Conmon_ContainerStatusRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerStatusRequest_Future) Struct() (Conmon_ContainerStatusRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerStatusRequest(s), err
+}
+
+type Conmon_ContainerStatusResponse capnp.Struct
+
+// Conmon_ContainerStatusResponse_TypeID is the unique identifier for the type Conmon_ContainerStatusResponse.
+const Conmon_ContainerStatusResponse_TypeID = 0x8b5fce9ce65a7de7
+
+func NewConmon_ContainerStatusResponse(s *capnp.Segment) (Conmon_ContainerStatusResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 1})
+	return Conmon_ContainerStatusResponse(st), err
+}
+
+func NewRootConmon_ContainerStatusResponse(s *capnp.Segment) (Conmon_ContainerStatusResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 1})
+	return Conmon_ContainerStatusResponse(st), err
+}
+
+func ReadRootConmon_ContainerStatusResponse(msg *capnp.Message) (Conmon_ContainerStatusResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ContainerStatusResponse(root.Struct()), err
+}
+
+func (s Conmon_ContainerStatusResponse) String() string {
+	str, _ := text.Marshal(0x8b5fce9ce65a7de7, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ContainerStatusResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ContainerStatusResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ContainerStatusResponse {
+	return Conmon_ContainerStatusResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ContainerStatusResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ContainerStatusResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ContainerStatusResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ContainerStatusResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ContainerStatusResponse) State() Conmon_ContainerStatusResponse_State {
+	return Conmon_ContainerStatusResponse_State(capnp.Struct(s).Uint16(0))
+}
+
+func (s Conmon_ContainerStatusResponse) SetState(v Conmon_ContainerStatusResponse_State) {
+	capnp.Struct(s).SetUint16(0, uint16(v))
+}
+
+func (s Conmon_ContainerStatusResponse) Pid() uint32 {
+	return capnp.Struct(s).Uint32(4)
+}
+
+func (s Conmon_ContainerStatusResponse) SetPid(v uint32) {
+	capnp.Struct(s).SetUint32(4, v)
+}
+
+func (s Conmon_ContainerStatusResponse) ExitCode() int32 {
+	return int32(capnp.Struct(s).Uint32(8))
+}
+
+func (s Conmon_ContainerStatusResponse) SetExitCode(v int32) {
+	capnp.Struct(s).SetUint32(8, uint32(v))
+}
+
+func (s Conmon_ContainerStatusResponse) StartedAt() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_ContainerStatusResponse) SetStartedAt(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+func (s Conmon_ContainerStatusResponse) ExitedAt() uint64 {
+	return capnp.Struct(s).Uint64(24)
+}
+
+func (s Conmon_ContainerStatusResponse) SetExitedAt(v uint64) {
+	capnp.Struct(s).SetUint64(24, v)
+}
+
+func (s Conmon_ContainerStatusResponse) AttachSocketPaths() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_ContainerStatusResponse) HasAttachSocketPaths() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ContainerStatusResponse) SetAttachSocketPaths(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewAttachSocketPaths sets the attachSocketPaths field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_ContainerStatusResponse) NewAttachSocketPaths(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_ContainerStatusResponse_List is a list of Conmon_ContainerStatusResponse.
+type Conmon_ContainerStatusResponse_List = capnp.StructList[Conmon_ContainerStatusResponse]
+
+// NewConmon_ContainerStatusResponse creates a new list of Conmon_ContainerStatusResponse.
+func NewConmon_ContainerStatusResponse_List(s *capnp.Segment, sz int32) (Conmon_ContainerStatusResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 32, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_ContainerStatusResponse](l), err
+}
+
+// Conmon_ContainerStatusResponse_Future is a wrapper for a Conmon_ContainerStatusResponse promised by a client call.
+type Conmon_ContainerStatusResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_ContainerStatusResponse_Future) Struct() (Conmon_ContainerStatusResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_ContainerStatusResponse(s), err
+}
+
+type Conmon_ContainerStatusResponse_State uint16
+
+// Conmon_ContainerStatusResponse_State_TypeID is the unique identifier for the type Conmon_ContainerStatusResponse_State.
+const Conmon_ContainerStatusResponse_State_TypeID = 0x8d1e6349ca6a41a4
+
+// Values of Conmon_ContainerStatusResponse_State.
+const (
+	Conmon_ContainerStatusResponse_State_unknown Conmon_ContainerStatusResponse_State = 0
+	Conmon_ContainerStatusResponse_State_running Conmon_ContainerStatusResponse_State = 1
+	Conmon_ContainerStatusResponse_State_exited  Conmon_ContainerStatusResponse_State = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_ContainerStatusResponse_State) String() string {
+	switch c {
+	case Conmon_ContainerStatusResponse_State_unknown:
+		return "unknown"
+	case Conmon_ContainerStatusResponse_State_running:
+		return "running"
+	case Conmon_ContainerStatusResponse_State_exited:
+		return "exited"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_ContainerStatusResponse_StateFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_ContainerStatusResponse_StateFromString(c string) Conmon_ContainerStatusResponse_State {
+	switch c {
+	case "unknown":
+		return Conmon_ContainerStatusResponse_State_unknown
+	case "running":
+		return Conmon_ContainerStatusResponse_State_running
+	case "exited":
+		return Conmon_ContainerStatusResponse_State_exited
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_ContainerStatusResponse_State_List = capnp.EnumList[Conmon_ContainerStatusResponse_State]
+
+func NewConmon_ContainerStatusResponse_State_List(s *capnp.Segment, sz int32) (Conmon_ContainerStatusResponse_State_List, error) {
+	return capnp.NewEnumList[Conmon_ContainerStatusResponse_State](s, sz)
+}
+
+type Conmon_EventListener capnp.Client
+
+// Conmon_EventListener_TypeID is the unique identifier for the type Conmon_EventListener.
+const Conmon_EventListener_TypeID = 0x8f080427d9f4b2e3
+
+func (c Conmon_EventListener) Event(ctx context.Context, params func(Conmon_EventListener_event_Params) error) (Conmon_EventListener_event_Results_Future, capnp.ReleaseFunc) {
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0x8f080427d9f4b2e3,
+			MethodID:      0,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon.EventListener",
+			MethodName:    "event",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_EventListener_event_Params(s)) }
+	}
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_EventListener_event_Results_Future{Future: ans.Future()}, release
+}
+
+func (c Conmon_EventListener) AddRef() Conmon_EventListener {
+	return Conmon_EventListener(capnp.Client(c).AddRef())
+}
+
+func (c Conmon_EventListener) Release() {
+	capnp.Client(c).Release()
+}
+
+func (c Conmon_EventListener) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Client(c).EncodeAsPtr(seg)
+}
+
+func (Conmon_EventListener) DecodeFromPtr(p capnp.Ptr) Conmon_EventListener {
+	return Conmon_EventListener(capnp.Client{}.DecodeFromPtr(p))
+}
+
+func (c Conmon_EventListener) IsValid() bool {
+	return capnp.Client(c).IsValid()
+}
+
+// A Conmon_EventListener_Server is a Conmon_EventListener with a local implementation.
+type Conmon_EventListener_Server interface {
+	Event(context.Context, Conmon_EventListener_event) error
+}
+
+// Conmon_EventListener_NewServer creates a new Server from an implementation of Conmon_EventListener_Server.
+func Conmon_EventListener_NewServer(s Conmon_EventListener_Server) *server.Server {
+	c, _ := s.(server.Shutdowner)
+	return server.New(Conmon_EventListener_Methods(nil, s), s, c)
+}
+
+// Conmon_EventListener_ServerToClient creates a new Client from an implementation of Conmon_EventListener_Server.
+// The caller is responsible for calling Release on the returned Client.
+func Conmon_EventListener_ServerToClient(s Conmon_EventListener_Server) Conmon_EventListener {
+	return Conmon_EventListener(capnp.NewClient(Conmon_EventListener_NewServer(s)))
+}
+
+// Conmon_EventListener_Methods appends Methods to a slice that invoke the methods on s.
+// This can be used to create a more complicated Server.
+func Conmon_EventListener_Methods(methods []server.Method, s Conmon_EventListener_Server) []server.Method {
+	if cap(methods) == 0 {
+		methods = make([]server.Method, 0, 1)
+	}
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0x8f080427d9f4b2e3,
+			MethodID:      0,
+			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon.EventListener",
+			MethodName:    "event",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.Event(ctx, Conmon_EventListener_event{call})
+		},
+	})
+
+	return methods
+}
+
+// Conmon_EventListener_event holds the state for a server call to Conmon_EventListener.event.
+// See server.Call for documentation.
+type Conmon_EventListener_event struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_EventListener_event) Args() Conmon_EventListener_event_Params {
+	return Conmon_EventListener_event_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_EventListener_event) AllocResults() (Conmon_EventListener_event_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_EventListener_event_Results(r), err
+}
+
+// Conmon_EventListener_List is a list of Conmon_EventListener.
+type Conmon_EventListener_List = capnp.CapList[Conmon_EventListener]
+
+// NewConmon_EventListener creates a new list of Conmon_EventListener.
+func NewConmon_EventListener_List(s *capnp.Segment, sz int32) (Conmon_EventListener_List, error) {
+	l, err := capnp.NewPointerList(s, sz)
+	return capnp.CapList[Conmon_EventListener](l), err
+}
+
+type Conmon_EventListener_event_Params capnp.Struct
+
+// Conmon_EventListener_event_Params_TypeID is the unique identifier for the type Conmon_EventListener_event_Params.
+const Conmon_EventListener_event_Params_TypeID = 0xb06fe4f9c1d2ad84
+
+func NewConmon_EventListener_event_Params(s *capnp.Segment) (Conmon_EventListener_event_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_EventListener_event_Params(st), err
+}
+
+func NewRootConmon_EventListener_event_Params(s *capnp.Segment) (Conmon_EventListener_event_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_EventListener_event_Params(st), err
+}
+
+func ReadRootConmon_EventListener_event_Params(msg *capnp.Message) (Conmon_EventListener_event_Params, error) {
+	root, err := msg.Root()
+	return Conmon_EventListener_event_Params(root.Struct()), err
+}
+
+func (s Conmon_EventListener_event_Params) String() string {
+	str, _ := text.Marshal(0xb06fe4f9c1d2ad84, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_EventListener_event_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_EventListener_event_Params) DecodeFromPtr(p capnp.Ptr) Conmon_EventListener_event_Params {
+	return Conmon_EventListener_event_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_EventListener_event_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_EventListener_event_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_EventListener_event_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_EventListener_event_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_EventListener_event_Params) Event() (Conmon_Event, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_Event(p.Struct()), err
+}
+
+func (s Conmon_EventListener_event_Params) HasEvent() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_EventListener_event_Params) SetEvent(v Conmon_Event) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewEvent sets the event field to a newly
+// allocated Conmon_Event struct, preferring placement in s's segment.
+func (s Conmon_EventListener_event_Params) NewEvent() (Conmon_Event, error) {
+	ss, err := NewConmon_Event(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_Event{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_EventListener_event_Params_List is a list of Conmon_EventListener_event_Params.
+type Conmon_EventListener_event_Params_List = capnp.StructList[Conmon_EventListener_event_Params]
+
+// NewConmon_EventListener_event_Params creates a new list of Conmon_EventListener_event_Params.
+func NewConmon_EventListener_event_Params_List(s *capnp.Segment, sz int32) (Conmon_EventListener_event_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_EventListener_event_Params](l), err
+}
+
+// Conmon_EventListener_event_Params_Future is a wrapper for a Conmon_EventListener_event_Params promised by a client call.
+type Conmon_EventListener_event_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_EventListener_event_Params_Future) Struct() (Conmon_EventListener_event_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_EventListener_event_Params(s), err
+}
+
+func (p Conmon_EventListener_event_Params_Future) Event() Conmon_Event_Future {
+	return Conmon_Event_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_EventListener_event_Results capnp.Struct
+
+// Conmon_EventListener_event_Results_TypeID is the unique identifier for the type Conmon_EventListener_event_Results.
+const Conmon_EventListener_event_Results_TypeID = 0xe4f37be9f7af4d81
+
+func NewConmon_EventListener_event_Results(s *capnp.Segment) (Conmon_EventListener_event_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_EventListener_event_Results(st), err
+}
+
+func NewRootConmon_EventListener_event_Results(s *capnp.Segment) (Conmon_EventListener_event_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_EventListener_event_Results(st), err
+}
+
+func ReadRootConmon_EventListener_event_Results(msg *capnp.Message) (Conmon_EventListener_event_Results, error) {
+	root, err := msg.Root()
+	return Conmon_EventListener_event_Results(root.Struct()), err
+}
+
+func (s Conmon_EventListener_event_Results) String() string {
+	str, _ := text.Marshal(0xe4f37be9f7af4d81, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_EventListener_event_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_EventListener_event_Results) DecodeFromPtr(p capnp.Ptr) Conmon_EventListener_event_Results {
+	return Conmon_EventListener_event_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_EventListener_event_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_EventListener_event_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_EventListener_event_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_EventListener_event_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_EventListener_event_Results_List is a list of Conmon_EventListener_event_Results.
+type Conmon_EventListener_event_Results_List = capnp.StructList[Conmon_EventListener_event_Results]
+
+// NewConmon_EventListener_event_Results creates a new list of Conmon_EventListener_event_Results.
+func NewConmon_EventListener_event_Results_List(s *capnp.Segment, sz int32) (Conmon_EventListener_event_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_EventListener_event_Results](l), err
+}
+
+// Conmon_EventListener_event_Results_Future is a wrapper for a Conmon_EventListener_event_Results promised by a client call.
+type Conmon_EventListener_event_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_EventListener_event_Results_Future) Struct() (Conmon_EventListener_event_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_EventListener_event_Results(s), err
+}
+
+type Conmon_EventSubscription capnp.Client
+
+// Conmon_EventSubscription_TypeID is the unique identifier for the type Conmon_EventSubscription.
+const Conmon_EventSubscription_TypeID = 0x84071f28833de648
+
+func (c Conmon_EventSubscription) AddRef() Conmon_EventSubscription {
+	return Conmon_EventSubscription(capnp.Client(c).AddRef())
+}
+
+func (c Conmon_EventSubscription) Release() {
+	capnp.Client(c).Release()
+}
+
+func (c Conmon_EventSubscription) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Client(c).EncodeAsPtr(seg)
+}
+
+func (Conmon_EventSubscription) DecodeFromPtr(p capnp.Ptr) Conmon_EventSubscription {
+	return Conmon_EventSubscription(capnp.Client{}.DecodeFromPtr(p))
+}
+
+func (c Conmon_EventSubscription) IsValid() bool {
+	return capnp.Client(c).IsValid()
+}
+
+// A Conmon_EventSubscription_Server is a Conmon_EventSubscription with a local implementation.
+type Conmon_EventSubscription_Server interface {
+}
+
+// Conmon_EventSubscription_NewServer creates a new Server from an implementation of Conmon_EventSubscription_Server.
+func Conmon_EventSubscription_NewServer(s Conmon_EventSubscription_Server) *server.Server {
+	c, _ := s.(server.Shutdowner)
+	return server.New(Conmon_EventSubscription_Methods(nil, s), s, c)
+}
+
+// Conmon_EventSubscription_ServerToClient creates a new Client from an implementation of Conmon_EventSubscription_Server.
+// The caller is responsible for calling Release on the returned Client.
+func Conmon_EventSubscription_ServerToClient(s Conmon_EventSubscription_Server) Conmon_EventSubscription {
+	return Conmon_EventSubscription(capnp.NewClient(Conmon_EventSubscription_NewServer(s)))
+}
+
+// Conmon_EventSubscription_Methods appends Methods to a slice that invoke the methods on s.
+// This can be used to create a more complicated Server.
+func Conmon_EventSubscription_Methods(methods []server.Method, s Conmon_EventSubscription_Server) []server.Method {
+	if cap(methods) == 0 {
+		methods = make([]server.Method, 0, 0)
+	}
+
+	return methods
+}
+
+// Conmon_EventSubscription_List is a list of Conmon_EventSubscription.
+type Conmon_EventSubscription_List = capnp.CapList[Conmon_EventSubscription]
+
+// NewConmon_EventSubscription creates a new list of Conmon_EventSubscription.
+func NewConmon_EventSubscription_List(s *capnp.Segment, sz int32) (Conmon_EventSubscription_List, error) {
+	l, err := capnp.NewPointerList(s, sz)
+	return capnp.CapList[Conmon_EventSubscription](l), err
+}
+
+type Conmon_StreamEventsRequest capnp.Struct
+
+// Conmon_StreamEventsRequest_TypeID is the unique identifier for the type Conmon_StreamEventsRequest.
+const Conmon_StreamEventsRequest_TypeID = 0x855d884a853afbc7
+
+func NewConmon_StreamEventsRequest(s *capnp.Segment) (Conmon_StreamEventsRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_StreamEventsRequest(st), err
+}
+
+func NewRootConmon_StreamEventsRequest(s *capnp.Segment) (Conmon_StreamEventsRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_StreamEventsRequest(st), err
+}
+
+func ReadRootConmon_StreamEventsRequest(msg *capnp.Message) (Conmon_StreamEventsRequest, error) {
+	root, err := msg.Root()
+	return Conmon_StreamEventsRequest(root.Struct()), err
+}
+
+func (s Conmon_StreamEventsRequest) String() string {
+	str, _ := text.Marshal(0x855d884a853afbc7, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_StreamEventsRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_StreamEventsRequest) DecodeFromPtr(p capnp.Ptr) Conmon_StreamEventsRequest {
+	return Conmon_StreamEventsRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_StreamEventsRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_StreamEventsRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_StreamEventsRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_StreamEventsRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_StreamEventsRequest) SinceSequence() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_StreamEventsRequest) SetSinceSequence(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_StreamEventsRequest) Listener() Conmon_EventListener {
+	p, _ := capnp.Struct(s).Ptr(0)
+	return Conmon_EventListener(p.Interface().Client())
+}
+
+func (s Conmon_StreamEventsRequest) HasListener() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_StreamEventsRequest) SetListener(v Conmon_EventListener) error {
+	if !v.IsValid() {
+		return capnp.Struct(s).SetPtr(0, capnp.Ptr{})
+	}
+	seg := s.Segment()
+	in := capnp.NewInterface(seg, seg.Message().AddCap(capnp.Client(v)))
+	return capnp.Struct(s).SetPtr(0, in.ToPtr())
+}
+
+// Conmon_StreamEventsRequest_List is a list of Conmon_StreamEventsRequest.
+type Conmon_StreamEventsRequest_List = capnp.StructList[Conmon_StreamEventsRequest]
+
+// NewConmon_StreamEventsRequest creates a new list of Conmon_StreamEventsRequest.
+func NewConmon_StreamEventsRequest_List(s *capnp.Segment, sz int32) (Conmon_StreamEventsRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_StreamEventsRequest](l), err
+}
+
+// Conmon_StreamEventsRequest_Future is a wrapper for a Conmon_StreamEventsRequest promised by a client call.
+type Conmon_StreamEventsRequest_Future struct{ *capnp.Future }
+
+func (p Conmon_StreamEventsRequest_Future) Struct() (Conmon_StreamEventsRequest, error) {
+	s, err := p.Future.Struct()
+	return Conmon_StreamEventsRequest(s), err
+}
+
+func (p Conmon_StreamEventsRequest_Future) Listener() Conmon_EventListener {
+	return Conmon_EventListener(p.Future.Field(0, nil).Client())
+}
+
+type Conmon_StreamEventsResponse capnp.Struct
+
+// Conmon_StreamEventsResponse_TypeID is the unique identifier for the type Conmon_StreamEventsResponse.
+const Conmon_StreamEventsResponse_TypeID = 0xb7a8e13705a17ca4
+
+func NewConmon_StreamEventsResponse(s *capnp.Segment) (Conmon_StreamEventsResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
+	return Conmon_StreamEventsResponse(st), err
+}
+
+func NewRootConmon_StreamEventsResponse(s *capnp.Segment) (Conmon_StreamEventsResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
+	return Conmon_StreamEventsResponse(st), err
+}
+
+func ReadRootConmon_StreamEventsResponse(msg *capnp.Message) (Conmon_StreamEventsResponse, error) {
+	root, err := msg.Root()
+	return Conmon_StreamEventsResponse(root.Struct()), err
+}
+
+func (s Conmon_StreamEventsResponse) String() string {
+	str, _ := text.Marshal(0xb7a8e13705a17ca4, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_StreamEventsResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_StreamEventsResponse) DecodeFromPtr(p capnp.Ptr) Conmon_StreamEventsResponse {
+	return Conmon_StreamEventsResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_StreamEventsResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_StreamEventsResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_StreamEventsResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_StreamEventsResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_StreamEventsResponse) Subscription() Conmon_EventSubscription {
+	p, _ := capnp.Struct(s).Ptr(0)
+	return Conmon_EventSubscription(p.Interface().Client())
+}
+
+func (s Conmon_StreamEventsResponse) HasSubscription() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_StreamEventsResponse) SetSubscription(v Conmon_EventSubscription) error {
+	if !v.IsValid() {
+		return capnp.Struct(s).SetPtr(0, capnp.Ptr{})
+	}
+	seg := s.Segment()
+	in := capnp.NewInterface(seg, seg.Message().AddCap(capnp.Client(v)))
+	return capnp.Struct(s).SetPtr(0, in.ToPtr())
+}
+
+func (s Conmon_StreamEventsResponse) LastSequence() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_StreamEventsResponse) SetLastSequence(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_StreamEventsResponse) Truncated() bool {
+	return capnp.Struct(s).Bit(64)
+}
+
+func (s Conmon_StreamEventsResponse) SetTruncated(v bool) {
+	capnp.Struct(s).SetBit(64, v)
+}
+
+// Conmon_StreamEventsResponse_List is a list of Conmon_StreamEventsResponse.
+type Conmon_StreamEventsResponse_List = capnp.StructList[Conmon_StreamEventsResponse]
+
+// NewConmon_StreamEventsResponse creates a new list of Conmon_StreamEventsResponse.
+func NewConmon_StreamEventsResponse_List(s *capnp.Segment, sz int32) (Conmon_StreamEventsResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_StreamEventsResponse](l), err
+}
+
+// Conmon_StreamEventsResponse_Future is a wrapper for a Conmon_StreamEventsResponse promised by a client call.
+type Conmon_StreamEventsResponse_Future struct{ *capnp.Future }
+
+func (p Conmon_StreamEventsResponse_Future) Struct() (Conmon_StreamEventsResponse, error) {
+	s, err := p.Future.Struct()
+	return Conmon_StreamEventsResponse(s), err
+}
+
+func (p Conmon_StreamEventsResponse_Future) Subscription() Conmon_EventSubscription {
+	return Conmon_EventSubscription(p.Future.Field(0, nil).Client())
+}
+
+type Conmon_version_Params capnp.Struct
+
+// Conmon_version_Params_TypeID is the unique identifier for the type Conmon_version_Params.
+const Conmon_version_Params_TypeID = 0xcc2f70676afee4e7
+
+func NewConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_version_Params(st), err
+}
+
+func NewRootConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_version_Params(st), err
+}
+
+func ReadRootConmon_version_Params(msg *capnp.Message) (Conmon_version_Params, error) {
+	root, err := msg.Root()
+	return Conmon_version_Params(root.Struct()), err
+}
+
+func (s Conmon_version_Params) String() string {
+	str, _ := text.Marshal(0xcc2f70676afee4e7, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_version_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_version_Params) DecodeFromPtr(p capnp.Ptr) Conmon_version_Params {
+	return Conmon_version_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_version_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_version_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_version_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_version_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_version_Params_List is a list of Conmon_version_Params.
+type Conmon_version_Params_List = capnp.StructList[Conmon_version_Params]
+
+// NewConmon_version_Params creates a new list of Conmon_version_Params.
+func NewConmon_version_Params_List(s *capnp.Segment, sz int32) (Conmon_version_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_version_Params](l), err
+}
+
+// Conmon_version_Params_Future is a wrapper for a Conmon_version_Params promised by a client call.
+type Conmon_version_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_version_Params_Future) Struct() (Conmon_version_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_version_Params(s), err
+}
+
+type Conmon_version_Results capnp.Struct
+
+// Conmon_version_Results_TypeID is the unique identifier for the type Conmon_version_Results.
+const Conmon_version_Results_TypeID = 0xe313695ea9477b30
+
+func NewConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_version_Results(st), err
+}
+
+func NewRootConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_version_Results(st), err
+}
+
+func ReadRootConmon_version_Results(msg *capnp.Message) (Conmon_version_Results, error) {
+	root, err := msg.Root()
+	return Conmon_version_Results(root.Struct()), err
+}
+
+func (s Conmon_version_Results) String() string {
+	str, _ := text.Marshal(0xe313695ea9477b30, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_version_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_version_Results) DecodeFromPtr(p capnp.Ptr) Conmon_version_Results {
+	return Conmon_version_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_version_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_version_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_version_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_version_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_version_Results) Response() (Conmon_VersionResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_VersionResponse(p.Struct()), err
+}
+
+func (s Conmon_version_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_version_Results) SetResponse(v Conmon_VersionResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_VersionResponse struct, preferring placement in s's segment.
+func (s Conmon_version_Results) NewResponse() (Conmon_VersionResponse, error) {
+	ss, err := NewConmon_VersionResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_VersionResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_version_Results_List is a list of Conmon_version_Results.
+type Conmon_version_Results_List = capnp.StructList[Conmon_version_Results]
+
+// NewConmon_version_Results creates a new list of Conmon_version_Results.
+func NewConmon_version_Results_List(s *capnp.Segment, sz int32) (Conmon_version_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_version_Results](l), err
+}
+
+// Conmon_version_Results_Future is a wrapper for a Conmon_version_Results promised by a client call.
+type Conmon_version_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_version_Results_Future) Struct() (Conmon_version_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_version_Results(s), err
+}
+
+func (p Conmon_version_Results_Future) Response() Conmon_VersionResponse_Future {
+	return Conmon_VersionResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_createContainer_Params capnp.Struct
+
+// Conmon_createContainer_Params_TypeID is the unique identifier for the type Conmon_createContainer_Params.
+const Conmon_createContainer_Params_TypeID = 0xf44732c48f949ab8
+
+func NewConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Params(st), err
+}
+
+func NewRootConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Params(st), err
+}
+
+func ReadRootConmon_createContainer_Params(msg *capnp.Message) (Conmon_createContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_createContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_createContainer_Params) String() string {
+	str, _ := text.Marshal(0xf44732c48f949ab8, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_createContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_createContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_createContainer_Params {
+	return Conmon_createContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_createContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_createContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_createContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_createContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_createContainer_Params) Request() (Conmon_CreateContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_CreateContainerRequest(p.Struct()), err
+}
+
+func (s Conmon_createContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_createContainer_Params) SetRequest(v Conmon_CreateContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_CreateContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_createContainer_Params) NewRequest() (Conmon_CreateContainerRequest, error) {
+	ss, err := NewConmon_CreateContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_CreateContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_createContainer_Params_List is a list of Conmon_createContainer_Params.
+type Conmon_createContainer_Params_List = capnp.StructList[Conmon_createContainer_Params]
+
+// NewConmon_createContainer_Params creates a new list of Conmon_createContainer_Params.
+func NewConmon_createContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_createContainer_Params](l), err
+}
+
+// Conmon_createContainer_Params_Future is a wrapper for a Conmon_createContainer_Params promised by a client call.
+type Conmon_createContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_createContainer_Params_Future) Struct() (Conmon_createContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_createContainer_Params(s), err
+}
+
+func (p Conmon_createContainer_Params_Future) Request() Conmon_CreateContainerRequest_Future {
+	return Conmon_CreateContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_createContainer_Results capnp.Struct
+
+// Conmon_createContainer_Results_TypeID is the unique identifier for the type Conmon_createContainer_Results.
+const Conmon_createContainer_Results_TypeID = 0xceba3c1a97be15f8
+
+func NewConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Results(st), err
+}
+
+func NewRootConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Results(st), err
+}
+
+func ReadRootConmon_createContainer_Results(msg *capnp.Message) (Conmon_createContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_createContainer_Results(root.Struct()), err
+}
+
+func (s Conmon_createContainer_Results) String() string {
+	str, _ := text.Marshal(0xceba3c1a97be15f8, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_createContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_createContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_createContainer_Results {
+	return Conmon_createContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_createContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_createContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_createContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_createContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_createContainer_Results) Response() (Conmon_CreateContainerResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_CreateContainerResponse(p.Struct()), err
+}
+
+func (s Conmon_createContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_createContainer_Results) SetResponse(v Conmon_CreateContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_CreateContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_createContainer_Results) NewResponse() (Conmon_CreateContainerResponse, error) {
+	ss, err := NewConmon_CreateContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_CreateContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_createContainer_Results_List is a list of Conmon_createContainer_Results.
+type Conmon_createContainer_Results_List = capnp.StructList[Conmon_createContainer_Results]
+
+// NewConmon_createContainer_Results creates a new list of Conmon_createContainer_Results.
+func NewConmon_createContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_createContainer_Results](l), err
+}
+
+// Conmon_createContainer_Results_Future is a wrapper for a Conmon_createContainer_Results promised by a client call.
+type Conmon_createContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_createContainer_Results_Future) Struct() (Conmon_createContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_createContainer_Results(s), err
+}
+
+func (p Conmon_createContainer_Results_Future) Response() Conmon_CreateContainerResponse_Future {
+	return Conmon_CreateContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_execSyncContainer_Params capnp.Struct
+
+// Conmon_execSyncContainer_Params_TypeID is the unique identifier for the type Conmon_execSyncContainer_Params.
+const Conmon_execSyncContainer_Params_TypeID = 0x83479da67279e173
+
+func NewConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Params(st), err
+}
+
+func NewRootConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Params(st), err
+}
+
+func ReadRootConmon_execSyncContainer_Params(msg *capnp.Message) (Conmon_execSyncContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_execSyncContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_execSyncContainer_Params) String() string {
+	str, _ := text.Marshal(0x83479da67279e173, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_execSyncContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_execSyncContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_execSyncContainer_Params {
+	return Conmon_execSyncContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_execSyncContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_execSyncContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_execSyncContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_execSyncContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_execSyncContainer_Params) Request() (Conmon_ExecSyncContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ExecSyncContainerRequest(p.Struct()), err
+}
+
+func (s Conmon_execSyncContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_execSyncContainer_Params) SetRequest(v Conmon_ExecSyncContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ExecSyncContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_execSyncContainer_Params) NewRequest() (Conmon_ExecSyncContainerRequest, error) {
+	ss, err := NewConmon_ExecSyncContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ExecSyncContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_execSyncContainer_Params_List is a list of Conmon_execSyncContainer_Params.
+type Conmon_execSyncContainer_Params_List = capnp.StructList[Conmon_execSyncContainer_Params]
+
+// NewConmon_execSyncContainer_Params creates a new list of Conmon_execSyncContainer_Params.
+func NewConmon_execSyncContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_execSyncContainer_Params](l), err
+}
+
+// Conmon_execSyncContainer_Params_Future is a wrapper for a Conmon_execSyncContainer_Params promised by a client call.
+type Conmon_execSyncContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_execSyncContainer_Params_Future) Struct() (Conmon_execSyncContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_execSyncContainer_Params(s), err
+}
+
+func (p Conmon_execSyncContainer_Params_Future) Request() Conmon_ExecSyncContainerRequest_Future {
+	return Conmon_ExecSyncContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_execSyncContainer_Results capnp.Struct
+
+// Conmon_execSyncContainer_Results_TypeID is the unique identifier for the type Conmon_execSyncContainer_Results.
+const Conmon_execSyncContainer_Results_TypeID = 0xf8e86a5c0baa01bc
+
+func NewConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Results(st), err
+}
+
+func NewRootConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Results(st), err
+}
+
+func ReadRootConmon_execSyncContainer_Results(msg *capnp.Message) (Conmon_execSyncContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_execSyncContainer_Results(root.Struct()), err
+}
+
+func (s Conmon_execSyncContainer_Results) String() string {
+	str, _ := text.Marshal(0xf8e86a5c0baa01bc, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_execSyncContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_execSyncContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_execSyncContainer_Results {
+	return Conmon_execSyncContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_execSyncContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_execSyncContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_execSyncContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_execSyncContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_execSyncContainer_Results) Response() (Conmon_ExecSyncContainerResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ExecSyncContainerResponse(p.Struct()), err
+}
+
+func (s Conmon_execSyncContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_execSyncContainer_Results) SetResponse(v Conmon_ExecSyncContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ExecSyncContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_execSyncContainer_Results) NewResponse() (Conmon_ExecSyncContainerResponse, error) {
+	ss, err := NewConmon_ExecSyncContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ExecSyncContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_execSyncContainer_Results_List is a list of Conmon_execSyncContainer_Results.
+type Conmon_execSyncContainer_Results_List = capnp.StructList[Conmon_execSyncContainer_Results]
+
+// NewConmon_execSyncContainer_Results creates a new list of Conmon_execSyncContainer_Results.
+func NewConmon_execSyncContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_execSyncContainer_Results](l), err
+}
+
+// Conmon_execSyncContainer_Results_Future is a wrapper for a Conmon_execSyncContainer_Results promised by a client call.
+type Conmon_execSyncContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_execSyncContainer_Results_Future) Struct() (Conmon_execSyncContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_execSyncContainer_Results(s), err
+}
+
+func (p Conmon_execSyncContainer_Results_Future) Response() Conmon_ExecSyncContainerResponse_Future {
+	return Conmon_ExecSyncContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_attachContainer_Params capnp.Struct
+
+// Conmon_attachContainer_Params_TypeID is the unique identifier for the type Conmon_attachContainer_Params.
+const Conmon_attachContainer_Params_TypeID = 0xaa2f3c8ad1c3af24
+
+func NewConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Params(st), err
+}
+
+func NewRootConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Params(st), err
+}
+
+func ReadRootConmon_attachContainer_Params(msg *capnp.Message) (Conmon_attachContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_attachContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_attachContainer_Params) String() string {
+	str, _ := text.Marshal(0xaa2f3c8ad1c3af24, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_attachContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_attachContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_attachContainer_Params {
+	return Conmon_attachContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_attachContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_attachContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_attachContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_attachContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_attachContainer_Params) Request() (Conmon_AttachRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_AttachRequest(p.Struct()), err
+}
+
+func (s Conmon_attachContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_attachContainer_Params) SetRequest(v Conmon_AttachRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_AttachRequest struct, preferring placement in s's segment.
+func (s Conmon_attachContainer_Params) NewRequest() (Conmon_AttachRequest, error) {
+	ss, err := NewConmon_AttachRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_AttachRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_attachContainer_Params_List is a list of Conmon_attachContainer_Params.
+type Conmon_attachContainer_Params_List = capnp.StructList[Conmon_attachContainer_Params]
+
+// NewConmon_attachContainer_Params creates a new list of Conmon_attachContainer_Params.
+func NewConmon_attachContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_attachContainer_Params](l), err
+}
+
+// Conmon_attachContainer_Params_Future is a wrapper for a Conmon_attachContainer_Params promised by a client call.
+type Conmon_attachContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_attachContainer_Params_Future) Struct() (Conmon_attachContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_attachContainer_Params(s), err
+}
+
+func (p Conmon_attachContainer_Params_Future) Request() Conmon_AttachRequest_Future {
+	return Conmon_AttachRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_attachContainer_Results capnp.Struct
+
+// Conmon_attachContainer_Results_TypeID is the unique identifier for the type Conmon_attachContainer_Results.
+const Conmon_attachContainer_Results_TypeID = 0xc5e65eec3dcf5b10
+
+func NewConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Results(st), err
+}
+
+func NewRootConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Results(st), err
+}
+
+func ReadRootConmon_attachContainer_Results(msg *capnp.Message) (Conmon_attachContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_attachContainer_Results(root.Struct()), err
+}
+
+func (s Conmon_attachContainer_Results) String() string {
+	str, _ := text.Marshal(0xc5e65eec3dcf5b10, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_attachContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_attachContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_attachContainer_Results {
+	return Conmon_attachContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_attachContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_attachContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_attachContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_attachContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_attachContainer_Results) Response() (Conmon_AttachResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_AttachResponse(p.Struct()), err
+}
+
+func (s Conmon_attachContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_attachContainer_Results) SetResponse(v Conmon_AttachResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_AttachResponse struct, preferring placement in s's segment.
+func (s Conmon_attachContainer_Results) NewResponse() (Conmon_AttachResponse, error) {
+	ss, err := NewConmon_AttachResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_AttachResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_attachContainer_Results_List is a list of Conmon_attachContainer_Results.
+type Conmon_attachContainer_Results_List = capnp.StructList[Conmon_attachContainer_Results]
+
+// NewConmon_attachContainer_Results creates a new list of Conmon_attachContainer_Results.
+func NewConmon_attachContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_attachContainer_Results](l), err
+}
+
+// Conmon_attachContainer_Results_Future is a wrapper for a Conmon_attachContainer_Results promised by a client call.
+type Conmon_attachContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_attachContainer_Results_Future) Struct() (Conmon_attachContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_attachContainer_Results(s), err
+}
+
+func (p Conmon_attachContainer_Results_Future) Response() Conmon_AttachResponse_Future {
+	return Conmon_AttachResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_reopenLogContainer_Params capnp.Struct
+
+// Conmon_reopenLogContainer_Params_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Params.
+const Conmon_reopenLogContainer_Params_TypeID = 0xe5ea916eb0c31336
+
+func NewConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Params(st), err
+}
+
+func NewRootConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Params(st), err
+}
+
+func ReadRootConmon_reopenLogContainer_Params(msg *capnp.Message) (Conmon_reopenLogContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_reopenLogContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_reopenLogContainer_Params) String() string {
+	str, _ := text.Marshal(0xe5ea916eb0c31336, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_reopenLogContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_reopenLogContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_reopenLogContainer_Params {
+	return Conmon_reopenLogContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_reopenLogContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_reopenLogContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_reopenLogContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_reopenLogContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_reopenLogContainer_Params) Request() (Conmon_ReopenLogRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ReopenLogRequest(p.Struct()), err
+}
+
+func (s Conmon_reopenLogContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_reopenLogContainer_Params) SetRequest(v Conmon_ReopenLogRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ReopenLogRequest struct, preferring placement in s's segment.
+func (s Conmon_reopenLogContainer_Params) NewRequest() (Conmon_ReopenLogRequest, error) {
+	ss, err := NewConmon_ReopenLogRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ReopenLogRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_reopenLogContainer_Params_List is a list of Conmon_reopenLogContainer_Params.
+type Conmon_reopenLogContainer_Params_List = capnp.StructList[Conmon_reopenLogContainer_Params]
+
+// NewConmon_reopenLogContainer_Params creates a new list of Conmon_reopenLogContainer_Params.
+func NewConmon_reopenLogContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_reopenLogContainer_Params](l), err
+}
+
+// Conmon_reopenLogContainer_Params_Future is a wrapper for a Conmon_reopenLogContainer_Params promised by a client call.
+type Conmon_reopenLogContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_reopenLogContainer_Params_Future) Struct() (Conmon_reopenLogContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_reopenLogContainer_Params(s), err
+}
+
+func (p Conmon_reopenLogContainer_Params_Future) Request() Conmon_ReopenLogRequest_Future {
+	return Conmon_ReopenLogRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_reopenLogContainer_Results capnp.Struct
+
+// Conmon_reopenLogContainer_Results_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Results.
+const Conmon_reopenLogContainer_Results_TypeID = 0xa0ef8355b64ee985
+
+func NewConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Results(st), err
+}
+
+func NewRootConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Results(st), err
+}
+
+func ReadRootConmon_reopenLogContainer_Results(msg *capnp.Message) (Conmon_reopenLogContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_reopenLogContainer_Results(root.Struct()), err
+}
+
+func (s Conmon_reopenLogContainer_Results) String() string {
+	str, _ := text.Marshal(0xa0ef8355b64ee985, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_reopenLogContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_reopenLogContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_reopenLogContainer_Results {
+	return Conmon_reopenLogContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_reopenLogContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_reopenLogContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_reopenLogContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_reopenLogContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_reopenLogContainer_Results) Response() (Conmon_ReopenLogResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ReopenLogResponse(p.Struct()), err
+}
+
+func (s Conmon_reopenLogContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_reopenLogContainer_Results) SetResponse(v Conmon_ReopenLogResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ReopenLogResponse struct, preferring placement in s's segment.
+func (s Conmon_reopenLogContainer_Results) NewResponse() (Conmon_ReopenLogResponse, error) {
+	ss, err := NewConmon_ReopenLogResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ReopenLogResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_reopenLogContainer_Results_List is a list of Conmon_reopenLogContainer_Results.
+type Conmon_reopenLogContainer_Results_List = capnp.StructList[Conmon_reopenLogContainer_Results]
+
+// NewConmon_reopenLogContainer_Results creates a new list of Conmon_reopenLogContainer_Results.
+func NewConmon_reopenLogContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_reopenLogContainer_Results](l), err
+}
+
+// Conmon_reopenLogContainer_Results_Future is a wrapper for a Conmon_reopenLogContainer_Results promised by a client call.
+type Conmon_reopenLogContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_reopenLogContainer_Results_Future) Struct() (Conmon_reopenLogContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_reopenLogContainer_Results(s), err
+}
+
+func (p Conmon_reopenLogContainer_Results_Future) Response() Conmon_ReopenLogResponse_Future {
+	return Conmon_ReopenLogResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_setWindowSizeContainer_Params capnp.Struct
+
+// Conmon_setWindowSizeContainer_Params_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Params.
+const Conmon_setWindowSizeContainer_Params_TypeID = 0xc76ccd4502bb61e7
+
+func NewConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Params(st), err
+}
+
+func NewRootConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Params(st), err
+}
+
+func ReadRootConmon_setWindowSizeContainer_Params(msg *capnp.Message) (Conmon_setWindowSizeContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_setWindowSizeContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_setWindowSizeContainer_Params) String() string {
+	str, _ := text.Marshal(0xc76ccd4502bb61e7, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_setWindowSizeContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_setWindowSizeContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_setWindowSizeContainer_Params {
+	return Conmon_setWindowSizeContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_setWindowSizeContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_setWindowSizeContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_setWindowSizeContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_setWindowSizeContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_setWindowSizeContainer_Params) Request() (Conmon_SetWindowSizeRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_SetWindowSizeRequest(p.Struct()), err
+}
+
+func (s Conmon_setWindowSizeContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_setWindowSizeContainer_Params) SetRequest(v Conmon_SetWindowSizeRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_SetWindowSizeRequest struct, preferring placement in s's segment.
+func (s Conmon_setWindowSizeContainer_Params) NewRequest() (Conmon_SetWindowSizeRequest, error) {
+	ss, err := NewConmon_SetWindowSizeRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_SetWindowSizeRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_setWindowSizeContainer_Params_List is a list of Conmon_setWindowSizeContainer_Params.
+type Conmon_setWindowSizeContainer_Params_List = capnp.StructList[Conmon_setWindowSizeContainer_Params]
+
+// NewConmon_setWindowSizeContainer_Params creates a new list of Conmon_setWindowSizeContainer_Params.
+func NewConmon_setWindowSizeContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_setWindowSizeContainer_Params](l), err
+}
+
+// Conmon_setWindowSizeContainer_Params_Future is a wrapper for a Conmon_setWindowSizeContainer_Params promised by a client call.
+type Conmon_setWindowSizeContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_setWindowSizeContainer_Params_Future) Struct() (Conmon_setWindowSizeContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_setWindowSizeContainer_Params(s), err
+}
+
+func (p Conmon_setWindowSizeContainer_Params_Future) Request() Conmon_SetWindowSizeRequest_Future {
+	return Conmon_SetWindowSizeRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_setWindowSizeContainer_Results capnp.Struct
+
+// Conmon_setWindowSizeContainer_Results_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Results.
+const Conmon_setWindowSizeContainer_Results_TypeID = 0xe00e522611477055
+
+func NewConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Results(st), err
+}
+
+func NewRootConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Results(st), err
+}
+
+func ReadRootConmon_setWindowSizeContainer_Results(msg *capnp.Message) (Conmon_setWindowSizeContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_setWindowSizeContainer_Results(root.Struct()), err
+}
+
+func (s Conmon_setWindowSizeContainer_Results) String() string {
+	str, _ := text.Marshal(0xe00e522611477055, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_setWindowSizeContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_setWindowSizeContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_setWindowSizeContainer_Results {
+	return Conmon_setWindowSizeContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_setWindowSizeContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_setWindowSizeContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_setWindowSizeContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_setWindowSizeContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_setWindowSizeContainer_Results) Response() (Conmon_SetWindowSizeResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_SetWindowSizeResponse(p.Struct()), err
+}
+
+func (s Conmon_setWindowSizeContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_setWindowSizeContainer_Results) SetResponse(v Conmon_SetWindowSizeResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_SetWindowSizeResponse struct, preferring placement in s's segment.
+func (s Conmon_setWindowSizeContainer_Results) NewResponse() (Conmon_SetWindowSizeResponse, error) {
+	ss, err := NewConmon_SetWindowSizeResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_SetWindowSizeResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_setWindowSizeContainer_Results_List is a list of Conmon_setWindowSizeContainer_Results.
+type Conmon_setWindowSizeContainer_Results_List = capnp.StructList[Conmon_setWindowSizeContainer_Results]
+
+// NewConmon_setWindowSizeContainer_Results creates a new list of Conmon_setWindowSizeContainer_Results.
+func NewConmon_setWindowSizeContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_setWindowSizeContainer_Results](l), err
+}
+
+// Conmon_setWindowSizeContainer_Results_Future is a wrapper for a Conmon_setWindowSizeContainer_Results promised by a client call.
+type Conmon_setWindowSizeContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_setWindowSizeContainer_Results_Future) Struct() (Conmon_setWindowSizeContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_setWindowSizeContainer_Results(s), err
+}
+
+func (p Conmon_setWindowSizeContainer_Results_Future) Response() Conmon_SetWindowSizeResponse_Future {
+	return Conmon_SetWindowSizeResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_negotiate_Params capnp.Struct
+
+// Conmon_negotiate_Params_TypeID is the unique identifier for the type Conmon_negotiate_Params.
+const Conmon_negotiate_Params_TypeID = 0x8b4c03a0662a38dc
+
+func NewConmon_negotiate_Params(s *capnp.Segment) (Conmon_negotiate_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_negotiate_Params(st), err
+}
+
+func NewRootConmon_negotiate_Params(s *capnp.Segment) (Conmon_negotiate_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_negotiate_Params(st), err
+}
+
+func ReadRootConmon_negotiate_Params(msg *capnp.Message) (Conmon_negotiate_Params, error) {
+	root, err := msg.Root()
+	return Conmon_negotiate_Params(root.Struct()), err
+}
+
+func (s Conmon_negotiate_Params) String() string {
+	str, _ := text.Marshal(0x8b4c03a0662a38dc, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_negotiate_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_negotiate_Params) DecodeFromPtr(p capnp.Ptr) Conmon_negotiate_Params {
+	return Conmon_negotiate_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_negotiate_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_negotiate_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_negotiate_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_negotiate_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_negotiate_Params) Request() (Conmon_NegotiateRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_NegotiateRequest(p.Struct()), err
+}
+
+func (s Conmon_negotiate_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_negotiate_Params) SetRequest(v Conmon_NegotiateRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_NegotiateRequest struct, preferring placement in s's segment.
+func (s Conmon_negotiate_Params) NewRequest() (Conmon_NegotiateRequest, error) {
+	ss, err := NewConmon_NegotiateRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_NegotiateRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_negotiate_Params_List is a list of Conmon_negotiate_Params.
+type Conmon_negotiate_Params_List = capnp.StructList[Conmon_negotiate_Params]
+
+// NewConmon_negotiate_Params creates a new list of Conmon_negotiate_Params.
+func NewConmon_negotiate_Params_List(s *capnp.Segment, sz int32) (Conmon_negotiate_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_negotiate_Params](l), err
+}
+
+// Conmon_negotiate_Params_Future is a wrapper for a Conmon_negotiate_Params promised by a client call.
+type Conmon_negotiate_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_negotiate_Params_Future) Struct() (Conmon_negotiate_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_negotiate_Params(s), err
+}
+
+func (p Conmon_negotiate_Params_Future) Request() Conmon_NegotiateRequest_Future {
+	return Conmon_NegotiateRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_negotiate_Results capnp.Struct
+
+// Conmon_negotiate_Results_TypeID is the unique identifier for the type Conmon_negotiate_Results.
+const Conmon_negotiate_Results_TypeID = 0x8aef91973dc8a4f5
+
+func NewConmon_negotiate_Results(s *capnp.Segment) (Conmon_negotiate_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_negotiate_Results(st), err
+}
+
+func NewRootConmon_negotiate_Results(s *capnp.Segment) (Conmon_negotiate_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_negotiate_Results(st), err
+}
+
+func ReadRootConmon_negotiate_Results(msg *capnp.Message) (Conmon_negotiate_Results, error) {
+	root, err := msg.Root()
+	return Conmon_negotiate_Results(root.Struct()), err
+}
+
+func (s Conmon_negotiate_Results) String() string {
+	str, _ := text.Marshal(0x8aef91973dc8a4f5, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_negotiate_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_negotiate_Results) DecodeFromPtr(p capnp.Ptr) Conmon_negotiate_Results {
+	return Conmon_negotiate_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_negotiate_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_negotiate_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_negotiate_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_negotiate_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_negotiate_Results) Response() (Conmon_NegotiateResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_NegotiateResponse(p.Struct()), err
+}
+
+func (s Conmon_negotiate_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_negotiate_Results) SetResponse(v Conmon_NegotiateResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_NegotiateResponse struct, preferring placement in s's segment.
+func (s Conmon_negotiate_Results) NewResponse() (Conmon_NegotiateResponse, error) {
+	ss, err := NewConmon_NegotiateResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_NegotiateResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_negotiate_Results_List is a list of Conmon_negotiate_Results.
+type Conmon_negotiate_Results_List = capnp.StructList[Conmon_negotiate_Results]
+
+// NewConmon_negotiate_Results creates a new list of Conmon_negotiate_Results.
+func NewConmon_negotiate_Results_List(s *capnp.Segment, sz int32) (Conmon_negotiate_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_negotiate_Results](l), err
+}
+
+// Conmon_negotiate_Results_Future is a wrapper for a Conmon_negotiate_Results promised by a client call.
+type Conmon_negotiate_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_negotiate_Results_Future) Struct() (Conmon_negotiate_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_negotiate_Results(s), err
+}
+
+func (p Conmon_negotiate_Results_Future) Response() Conmon_NegotiateResponse_Future {
+	return Conmon_NegotiateResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_attachStreamContainer_Params capnp.Struct
+
+// Conmon_attachStreamContainer_Params_TypeID is the unique identifier for the type Conmon_attachStreamContainer_Params.
+const Conmon_attachStreamContainer_Params_TypeID = 0xce733f0914c80b6b
+
+func NewConmon_attachStreamContainer_Params(s *capnp.Segment) (Conmon_attachStreamContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachStreamContainer_Params(st), err
+}
+
+func NewRootConmon_attachStreamContainer_Params(s *capnp.Segment) (Conmon_attachStreamContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachStreamContainer_Params(st), err
+}
+
+func ReadRootConmon_attachStreamContainer_Params(msg *capnp.Message) (Conmon_attachStreamContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_attachStreamContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_attachStreamContainer_Params) String() string {
+	str, _ := text.Marshal(0xce733f0914c80b6b, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_attachStreamContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_attachStreamContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_attachStreamContainer_Params {
+	return Conmon_attachStreamContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_attachStreamContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_attachStreamContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_attachStreamContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_attachStreamContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_attachStreamContainer_Params) Request() (Conmon_AttachStreamRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_AttachStreamRequest(p.Struct()), err
+}
+
+func (s Conmon_attachStreamContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_attachStreamContainer_Params) SetRequest(v Conmon_AttachStreamRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_AttachStreamRequest struct, preferring placement in s's segment.
+func (s Conmon_attachStreamContainer_Params) NewRequest() (Conmon_AttachStreamRequest, error) {
+	ss, err := NewConmon_AttachStreamRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_AttachStreamRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_attachStreamContainer_Params_List is a list of Conmon_attachStreamContainer_Params.
+type Conmon_attachStreamContainer_Params_List = capnp.StructList[Conmon_attachStreamContainer_Params]
+
+// NewConmon_attachStreamContainer_Params creates a new list of Conmon_attachStreamContainer_Params.
+func NewConmon_attachStreamContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_attachStreamContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_attachStreamContainer_Params](l), err
+}
+
+// Conmon_attachStreamContainer_Params_Future is a wrapper for a Conmon_attachStreamContainer_Params promised by a client call.
+type Conmon_attachStreamContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_attachStreamContainer_Params_Future) Struct() (Conmon_attachStreamContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_attachStreamContainer_Params(s), err
+}
+
+func (p Conmon_attachStreamContainer_Params_Future) Request() Conmon_AttachStreamRequest_Future {
+	return Conmon_AttachStreamRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_attachStreamContainer_Results capnp.Struct
+
+// Conmon_attachStreamContainer_Results_TypeID is the unique identifier for the type Conmon_attachStreamContainer_Results.
+const Conmon_attachStreamContainer_Results_TypeID = 0xf4e3e92ae0815f15
+
+func NewConmon_attachStreamContainer_Results(s *capnp.Segment) (Conmon_attachStreamContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachStreamContainer_Results(st), err
+}
+
+func NewRootConmon_attachStreamContainer_Results(s *capnp.Segment) (Conmon_attachStreamContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachStreamContainer_Results(st), err
+}
+
+func ReadRootConmon_attachStreamContainer_Results(msg *capnp.Message) (Conmon_attachStreamContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_attachStreamContainer_Results(root.Struct()), err
+}
+
+func (s Conmon_attachStreamContainer_Results) String() string {
+	str, _ := text.Marshal(0xf4e3e92ae0815f15, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_attachStreamContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_attachStreamContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_attachStreamContainer_Results {
+	return Conmon_attachStreamContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_attachStreamContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_attachStreamContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_attachStreamContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_attachStreamContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_attachStreamContainer_Results) Response() (Conmon_AttachStreamResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_AttachStreamResponse(p.Struct()), err
+}
+
+func (s Conmon_attachStreamContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_attachStreamContainer_Results) SetResponse(v Conmon_AttachStreamResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_AttachStreamResponse struct, preferring placement in s's segment.
+func (s Conmon_attachStreamContainer_Results) NewResponse() (Conmon_AttachStreamResponse, error) {
+	ss, err := NewConmon_AttachStreamResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_AttachStreamResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_attachStreamContainer_Results_List is a list of Conmon_attachStreamContainer_Results.
+type Conmon_attachStreamContainer_Results_List = capnp.StructList[Conmon_attachStreamContainer_Results]
+
+// NewConmon_attachStreamContainer_Results creates a new list of Conmon_attachStreamContainer_Results.
+func NewConmon_attachStreamContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_attachStreamContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_attachStreamContainer_Results](l), err
+}
+
+// Conmon_attachStreamContainer_Results_Future is a wrapper for a Conmon_attachStreamContainer_Results promised by a client call.
+type Conmon_attachStreamContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_attachStreamContainer_Results_Future) Struct() (Conmon_attachStreamContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_attachStreamContainer_Results(s), err
+}
+
+func (p Conmon_attachStreamContainer_Results_Future) Response() Conmon_AttachStreamResponse_Future {
+	return Conmon_AttachStreamResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_listContainerStatuses_Params capnp.Struct
+
+// Conmon_listContainerStatuses_Params_TypeID is the unique identifier for the type Conmon_listContainerStatuses_Params.
+const Conmon_listContainerStatuses_Params_TypeID = 0x90a3950a51412b8b
+
+func NewConmon_listContainerStatuses_Params(s *capnp.Segment) (Conmon_listContainerStatuses_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainerStatuses_Params(st), err
+}
+
+func NewRootConmon_listContainerStatuses_Params(s *capnp.Segment) (Conmon_listContainerStatuses_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainerStatuses_Params(st), err
+}
+
+func ReadRootConmon_listContainerStatuses_Params(msg *capnp.Message) (Conmon_listContainerStatuses_Params, error) {
+	root, err := msg.Root()
+	return Conmon_listContainerStatuses_Params(root.Struct()), err
+}
+
+func (s Conmon_listContainerStatuses_Params) String() string {
+	str, _ := text.Marshal(0x90a3950a51412b8b, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_listContainerStatuses_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_listContainerStatuses_Params) DecodeFromPtr(p capnp.Ptr) Conmon_listContainerStatuses_Params {
+	return Conmon_listContainerStatuses_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_listContainerStatuses_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_listContainerStatuses_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_listContainerStatuses_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_listContainerStatuses_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_listContainerStatuses_Params) Request() (Conmon_ListContainerStatusesRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ListContainerStatusesRequest(p.Struct()), err
+}
+
+func (s Conmon_listContainerStatuses_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_listContainerStatuses_Params) SetRequest(v Conmon_ListContainerStatusesRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ListContainerStatusesRequest struct, preferring placement in s's segment.
+func (s Conmon_listContainerStatuses_Params) NewRequest() (Conmon_ListContainerStatusesRequest, error) {
+	ss, err := NewConmon_ListContainerStatusesRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ListContainerStatusesRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_listContainerStatuses_Params_List is a list of Conmon_listContainerStatuses_Params.
+type Conmon_listContainerStatuses_Params_List = capnp.StructList[Conmon_listContainerStatuses_Params]
+
+// NewConmon_listContainerStatuses_Params creates a new list of Conmon_listContainerStatuses_Params.
+func NewConmon_listContainerStatuses_Params_List(s *capnp.Segment, sz int32) (Conmon_listContainerStatuses_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_listContainerStatuses_Params](l), err
+}
+
+// Conmon_listContainerStatuses_Params_Future is a wrapper for a Conmon_listContainerStatuses_Params promised by a client call.
+type Conmon_listContainerStatuses_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_listContainerStatuses_Params_Future) Struct() (Conmon_listContainerStatuses_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_listContainerStatuses_Params(s), err
+}
+
+func (p Conmon_listContainerStatuses_Params_Future) Request() Conmon_ListContainerStatusesRequest_Future {
+	return Conmon_ListContainerStatusesRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_listContainerStatuses_Results capnp.Struct
+
+// Conmon_listContainerStatuses_Results_TypeID is the unique identifier for the type Conmon_listContainerStatuses_Results.
+const Conmon_listContainerStatuses_Results_TypeID = 0xdebaeed2a782ac80
+
+func NewConmon_listContainerStatuses_Results(s *capnp.Segment) (Conmon_listContainerStatuses_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainerStatuses_Results(st), err
+}
+
+func NewRootConmon_listContainerStatuses_Results(s *capnp.Segment) (Conmon_listContainerStatuses_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainerStatuses_Results(st), err
+}
+
+func ReadRootConmon_listContainerStatuses_Results(msg *capnp.Message) (Conmon_listContainerStatuses_Results, error) {
+	root, err := msg.Root()
+	return Conmon_listContainerStatuses_Results(root.Struct()), err
+}
+
+func (s Conmon_listContainerStatuses_Results) String() string {
+	str, _ := text.Marshal(0xdebaeed2a782ac80, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_listContainerStatuses_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_listContainerStatuses_Results) DecodeFromPtr(p capnp.Ptr) Conmon_listContainerStatuses_Results {
+	return Conmon_listContainerStatuses_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_listContainerStatuses_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_listContainerStatuses_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_listContainerStatuses_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_listContainerStatuses_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_listContainerStatuses_Results) Response() (Conmon_ListContainerStatusesResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ListContainerStatusesResponse(p.Struct()), err
+}
+
+func (s Conmon_listContainerStatuses_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_listContainerStatuses_Results) SetResponse(v Conmon_ListContainerStatusesResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ListContainerStatusesResponse struct, preferring placement in s's segment.
+func (s Conmon_listContainerStatuses_Results) NewResponse() (Conmon_ListContainerStatusesResponse, error) {
+	ss, err := NewConmon_ListContainerStatusesResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ListContainerStatusesResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_listContainerStatuses_Results_List is a list of Conmon_listContainerStatuses_Results.
+type Conmon_listContainerStatuses_Results_List = capnp.StructList[Conmon_listContainerStatuses_Results]
+
+// NewConmon_listContainerStatuses_Results creates a new list of Conmon_listContainerStatuses_Results.
+func NewConmon_listContainerStatuses_Results_List(s *capnp.Segment, sz int32) (Conmon_listContainerStatuses_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_listContainerStatuses_Results](l), err
+}
+
+// Conmon_listContainerStatuses_Results_Future is a wrapper for a Conmon_listContainerStatuses_Results promised by a client call.
+type Conmon_listContainerStatuses_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_listContainerStatuses_Results_Future) Struct() (Conmon_listContainerStatuses_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_listContainerStatuses_Results(s), err
+}
+
+func (p Conmon_listContainerStatuses_Results_Future) Response() Conmon_ListContainerStatusesResponse_Future {
+	return Conmon_ListContainerStatusesResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_getEvents_Params capnp.Struct
+
+// Conmon_getEvents_Params_TypeID is the unique identifier for the type Conmon_getEvents_Params.
+const Conmon_getEvents_Params_TypeID = 0xa3cb406c522dcab1
+
+func NewConmon_getEvents_Params(s *capnp.Segment) (Conmon_getEvents_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_getEvents_Params(st), err
+}
+
+func NewRootConmon_getEvents_Params(s *capnp.Segment) (Conmon_getEvents_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_getEvents_Params(st), err
+}
+
+func ReadRootConmon_getEvents_Params(msg *capnp.Message) (Conmon_getEvents_Params, error) {
+	root, err := msg.Root()
+	return Conmon_getEvents_Params(root.Struct()), err
+}
+
+func (s Conmon_getEvents_Params) String() string {
+	str, _ := text.Marshal(0xa3cb406c522dcab1, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_getEvents_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_getEvents_Params) DecodeFromPtr(p capnp.Ptr) Conmon_getEvents_Params {
+	return Conmon_getEvents_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_getEvents_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_getEvents_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_getEvents_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_getEvents_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_getEvents_Params) Request() (Conmon_GetEventsRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_GetEventsRequest(p.Struct()), err
+}
+
+func (s Conmon_getEvents_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_getEvents_Params) SetRequest(v Conmon_GetEventsRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_GetEventsRequest struct, preferring placement in s's segment.
+func (s Conmon_getEvents_Params) NewRequest() (Conmon_GetEventsRequest, error) {
+	ss, err := NewConmon_GetEventsRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_GetEventsRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_getEvents_Params_List is a list of Conmon_getEvents_Params.
+type Conmon_getEvents_Params_List = capnp.StructList[Conmon_getEvents_Params]
+
+// NewConmon_getEvents_Params creates a new list of Conmon_getEvents_Params.
+func NewConmon_getEvents_Params_List(s *capnp.Segment, sz int32) (Conmon_getEvents_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_getEvents_Params](l), err
+}
+
+// Conmon_getEvents_Params_Future is a wrapper for a Conmon_getEvents_Params promised by a client call.
+type Conmon_getEvents_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_getEvents_Params_Future) Struct() (Conmon_getEvents_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_getEvents_Params(s), err
+}
+
+func (p Conmon_getEvents_Params_Future) Request() Conmon_GetEventsRequest_Future {
+	return Conmon_GetEventsRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_getEvents_Results capnp.Struct
+
+// Conmon_getEvents_Results_TypeID is the unique identifier for the type Conmon_getEvents_Results.
+const Conmon_getEvents_Results_TypeID = 0xedd2e5b018f17bbb
+
+func NewConmon_getEvents_Results(s *capnp.Segment) (Conmon_getEvents_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_getEvents_Results(st), err
+}
+
+func NewRootConmon_getEvents_Results(s *capnp.Segment) (Conmon_getEvents_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_getEvents_Results(st), err
+}
+
+func ReadRootConmon_getEvents_Results(msg *capnp.Message) (Conmon_getEvents_Results, error) {
+	root, err := msg.Root()
+	return Conmon_getEvents_Results(root.Struct()), err
+}
+
+func (s Conmon_getEvents_Results) String() string {
+	str, _ := text.Marshal(0xedd2e5b018f17bbb, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_getEvents_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_getEvents_Results) DecodeFromPtr(p capnp.Ptr) Conmon_getEvents_Results {
+	return Conmon_getEvents_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_getEvents_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_getEvents_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_getEvents_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_getEvents_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_getEvents_Results) Response() (Conmon_GetEventsResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_GetEventsResponse(p.Struct()), err
+}
+
+func (s Conmon_getEvents_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_getEvents_Results) SetResponse(v Conmon_GetEventsResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_GetEventsResponse struct, preferring placement in s's segment.
+func (s Conmon_getEvents_Results) NewResponse() (Conmon_GetEventsResponse, error) {
+	ss, err := NewConmon_GetEventsResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_GetEventsResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_getEvents_Results_List is a list of Conmon_getEvents_Results.
+type Conmon_getEvents_Results_List = capnp.StructList[Conmon_getEvents_Results]
+
+// NewConmon_getEvents_Results creates a new list of Conmon_getEvents_Results.
+func NewConmon_getEvents_Results_List(s *capnp.Segment, sz int32) (Conmon_getEvents_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_getEvents_Results](l), err
+}
+
+// Conmon_getEvents_Results_Future is a wrapper for a Conmon_getEvents_Results promised by a client call.
+type Conmon_getEvents_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_getEvents_Results_Future) Struct() (Conmon_getEvents_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_getEvents_Results(s), err
+}
+
+func (p Conmon_getEvents_Results_Future) Response() Conmon_GetEventsResponse_Future {
+	return Conmon_GetEventsResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_listExecSessions_Params capnp.Struct
+
+// Conmon_listExecSessions_Params_TypeID is the unique identifier for the type Conmon_listExecSessions_Params.
+const Conmon_listExecSessions_Params_TypeID = 0x9d82529754851252
+
+func NewConmon_listExecSessions_Params(s *capnp.Segment) (Conmon_listExecSessions_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listExecSessions_Params(st), err
+}
+
+func NewRootConmon_listExecSessions_Params(s *capnp.Segment) (Conmon_listExecSessions_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listExecSessions_Params(st), err
+}
+
+func ReadRootConmon_listExecSessions_Params(msg *capnp.Message) (Conmon_listExecSessions_Params, error) {
+	root, err := msg.Root()
+	return Conmon_listExecSessions_Params(root.Struct()), err
+}
+
+func (s Conmon_listExecSessions_Params) String() string {
+	str, _ := text.Marshal(0x9d82529754851252, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_listExecSessions_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_listExecSessions_Params) DecodeFromPtr(p capnp.Ptr) Conmon_listExecSessions_Params {
+	return Conmon_listExecSessions_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_listExecSessions_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_listExecSessions_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_listExecSessions_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_listExecSessions_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_listExecSessions_Params) Request() (Conmon_ListExecSessionsRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ListExecSessionsRequest(p.Struct()), err
+}
+
+func (s Conmon_listExecSessions_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_listExecSessions_Params) SetRequest(v Conmon_ListExecSessionsRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ListExecSessionsRequest struct, preferring placement in s's segment.
+func (s Conmon_listExecSessions_Params) NewRequest() (Conmon_ListExecSessionsRequest, error) {
+	ss, err := NewConmon_ListExecSessionsRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ListExecSessionsRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_listExecSessions_Params_List is a list of Conmon_listExecSessions_Params.
+type Conmon_listExecSessions_Params_List = capnp.StructList[Conmon_listExecSessions_Params]
+
+// NewConmon_listExecSessions_Params creates a new list of Conmon_listExecSessions_Params.
+func NewConmon_listExecSessions_Params_List(s *capnp.Segment, sz int32) (Conmon_listExecSessions_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_listExecSessions_Params](l), err
+}
+
+// Conmon_listExecSessions_Params_Future is a wrapper for a Conmon_listExecSessions_Params promised by a client call.
+type Conmon_listExecSessions_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_listExecSessions_Params_Future) Struct() (Conmon_listExecSessions_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_listExecSessions_Params(s), err
+}
+
+func (p Conmon_listExecSessions_Params_Future) Request() Conmon_ListExecSessionsRequest_Future {
+	return Conmon_ListExecSessionsRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_listExecSessions_Results capnp.Struct
+
+// Conmon_listExecSessions_Results_TypeID is the unique identifier for the type Conmon_listExecSessions_Results.
+const Conmon_listExecSessions_Results_TypeID = 0xae5e0ae5001ebdfe
+
+func NewConmon_listExecSessions_Results(s *capnp.Segment) (Conmon_listExecSessions_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listExecSessions_Results(st), err
+}
+
+func NewRootConmon_listExecSessions_Results(s *capnp.Segment) (Conmon_listExecSessions_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listExecSessions_Results(st), err
+}
+
+func ReadRootConmon_listExecSessions_Results(msg *capnp.Message) (Conmon_listExecSessions_Results, error) {
+	root, err := msg.Root()
+	return Conmon_listExecSessions_Results(root.Struct()), err
+}
+
+func (s Conmon_listExecSessions_Results) String() string {
+	str, _ := text.Marshal(0xae5e0ae5001ebdfe, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_listExecSessions_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_listExecSessions_Results) DecodeFromPtr(p capnp.Ptr) Conmon_listExecSessions_Results {
+	return Conmon_listExecSessions_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_listExecSessions_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_listExecSessions_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_listExecSessions_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_listExecSessions_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_listExecSessions_Results) Response() (Conmon_ListExecSessionsResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ListExecSessionsResponse(p.Struct()), err
+}
+
+func (s Conmon_listExecSessions_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_listExecSessions_Results) SetResponse(v Conmon_ListExecSessionsResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ListExecSessionsResponse struct, preferring placement in s's segment.
+func (s Conmon_listExecSessions_Results) NewResponse() (Conmon_ListExecSessionsResponse, error) {
+	ss, err := NewConmon_ListExecSessionsResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ListExecSessionsResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_listExecSessions_Results_List is a list of Conmon_listExecSessions_Results.
+type Conmon_listExecSessions_Results_List = capnp.StructList[Conmon_listExecSessions_Results]
+
+// NewConmon_listExecSessions_Results creates a new list of Conmon_listExecSessions_Results.
+func NewConmon_listExecSessions_Results_List(s *capnp.Segment, sz int32) (Conmon_listExecSessions_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_listExecSessions_Results](l), err
+}
+
+// Conmon_listExecSessions_Results_Future is a wrapper for a Conmon_listExecSessions_Results promised by a client call.
+type Conmon_listExecSessions_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_listExecSessions_Results_Future) Struct() (Conmon_listExecSessions_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_listExecSessions_Results(s), err
+}
+
+func (p Conmon_listExecSessions_Results_Future) Response() Conmon_ListExecSessionsResponse_Future {
+	return Conmon_ListExecSessionsResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_containerIOStats_Params capnp.Struct
+
+// Conmon_containerIOStats_Params_TypeID is the unique identifier for the type Conmon_containerIOStats_Params.
+const Conmon_containerIOStats_Params_TypeID = 0xa6d76ce69f13a816
+
+func NewConmon_containerIOStats_Params(s *capnp.Segment) (Conmon_containerIOStats_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerIOStats_Params(st), err
+}
+
+func NewRootConmon_containerIOStats_Params(s *capnp.Segment) (Conmon_containerIOStats_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerIOStats_Params(st), err
+}
+
+func ReadRootConmon_containerIOStats_Params(msg *capnp.Message) (Conmon_containerIOStats_Params, error) {
+	root, err := msg.Root()
+	return Conmon_containerIOStats_Params(root.Struct()), err
+}
+
+func (s Conmon_containerIOStats_Params) String() string {
+	str, _ := text.Marshal(0xa6d76ce69f13a816, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_containerIOStats_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_containerIOStats_Params) DecodeFromPtr(p capnp.Ptr) Conmon_containerIOStats_Params {
+	return Conmon_containerIOStats_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_containerIOStats_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_containerIOStats_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_containerIOStats_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_containerIOStats_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_containerIOStats_Params) Request() (Conmon_ContainerIOStatsRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ContainerIOStatsRequest(p.Struct()), err
+}
+
+func (s Conmon_containerIOStats_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_containerIOStats_Params) SetRequest(v Conmon_ContainerIOStatsRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ContainerIOStatsRequest struct, preferring placement in s's segment.
+func (s Conmon_containerIOStats_Params) NewRequest() (Conmon_ContainerIOStatsRequest, error) {
+	ss, err := NewConmon_ContainerIOStatsRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ContainerIOStatsRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_containerIOStats_Params_List is a list of Conmon_containerIOStats_Params.
+type Conmon_containerIOStats_Params_List = capnp.StructList[Conmon_containerIOStats_Params]
+
+// NewConmon_containerIOStats_Params creates a new list of Conmon_containerIOStats_Params.
+func NewConmon_containerIOStats_Params_List(s *capnp.Segment, sz int32) (Conmon_containerIOStats_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_containerIOStats_Params](l), err
+}
+
+// Conmon_containerIOStats_Params_Future is a wrapper for a Conmon_containerIOStats_Params promised by a client call.
+type Conmon_containerIOStats_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_containerIOStats_Params_Future) Struct() (Conmon_containerIOStats_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_containerIOStats_Params(s), err
+}
+
+func (p Conmon_containerIOStats_Params_Future) Request() Conmon_ContainerIOStatsRequest_Future {
+	return Conmon_ContainerIOStatsRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_containerIOStats_Results capnp.Struct
+
+// Conmon_containerIOStats_Results_TypeID is the unique identifier for the type Conmon_containerIOStats_Results.
+const Conmon_containerIOStats_Results_TypeID = 0xaaa69aebe451afba
+
+func NewConmon_containerIOStats_Results(s *capnp.Segment) (Conmon_containerIOStats_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerIOStats_Results(st), err
+}
+
+func NewRootConmon_containerIOStats_Results(s *capnp.Segment) (Conmon_containerIOStats_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerIOStats_Results(st), err
+}
+
+func ReadRootConmon_containerIOStats_Results(msg *capnp.Message) (Conmon_containerIOStats_Results, error) {
+	root, err := msg.Root()
+	return Conmon_containerIOStats_Results(root.Struct()), err
+}
+
+func (s Conmon_containerIOStats_Results) String() string {
+	str, _ := text.Marshal(0xaaa69aebe451afba, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_containerIOStats_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_containerIOStats_Results) DecodeFromPtr(p capnp.Ptr) Conmon_containerIOStats_Results {
+	return Conmon_containerIOStats_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_containerIOStats_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_containerIOStats_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_containerIOStats_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_containerIOStats_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_containerIOStats_Results) Response() (Conmon_ContainerIOStatsResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ContainerIOStatsResponse(p.Struct()), err
+}
+
+func (s Conmon_containerIOStats_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_containerIOStats_Results) SetResponse(v Conmon_ContainerIOStatsResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ContainerIOStatsResponse struct, preferring placement in s's segment.
+func (s Conmon_containerIOStats_Results) NewResponse() (Conmon_ContainerIOStatsResponse, error) {
+	ss, err := NewConmon_ContainerIOStatsResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ContainerIOStatsResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_containerIOStats_Results_List is a list of Conmon_containerIOStats_Results.
+type Conmon_containerIOStats_Results_List = capnp.StructList[Conmon_containerIOStats_Results]
+
+// NewConmon_containerIOStats_Results creates a new list of Conmon_containerIOStats_Results.
+func NewConmon_containerIOStats_Results_List(s *capnp.Segment, sz int32) (Conmon_containerIOStats_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_containerIOStats_Results](l), err
+}
+
+// Conmon_containerIOStats_Results_Future is a wrapper for a Conmon_containerIOStats_Results promised by a client call.
+type Conmon_containerIOStats_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_containerIOStats_Results_Future) Struct() (Conmon_containerIOStats_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_containerIOStats_Results(s), err
+}
+
+func (p Conmon_containerIOStats_Results_Future) Response() Conmon_ContainerIOStatsResponse_Future {
+	return Conmon_ContainerIOStatsResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_setLogLevel_Params capnp.Struct
+
+// Conmon_setLogLevel_Params_TypeID is the unique identifier for the type Conmon_setLogLevel_Params.
+const Conmon_setLogLevel_Params_TypeID = 0xe989fde14d6e82dd
+
+func NewConmon_setLogLevel_Params(s *capnp.Segment) (Conmon_setLogLevel_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setLogLevel_Params(st), err
+}
+
+func NewRootConmon_setLogLevel_Params(s *capnp.Segment) (Conmon_setLogLevel_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setLogLevel_Params(st), err
+}
+
+func ReadRootConmon_setLogLevel_Params(msg *capnp.Message) (Conmon_setLogLevel_Params, error) {
+	root, err := msg.Root()
+	return Conmon_setLogLevel_Params(root.Struct()), err
+}
+
+func (s Conmon_setLogLevel_Params) String() string {
+	str, _ := text.Marshal(0xe989fde14d6e82dd, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_setLogLevel_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_setLogLevel_Params) DecodeFromPtr(p capnp.Ptr) Conmon_setLogLevel_Params {
+	return Conmon_setLogLevel_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_setLogLevel_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_setLogLevel_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_setLogLevel_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_setLogLevel_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_setLogLevel_Params) Request() (Conmon_SetLogLevelRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_SetLogLevelRequest(p.Struct()), err
+}
+
+func (s Conmon_setLogLevel_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_setLogLevel_Params) SetRequest(v Conmon_SetLogLevelRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_SetLogLevelRequest struct, preferring placement in s's segment.
+func (s Conmon_setLogLevel_Params) NewRequest() (Conmon_SetLogLevelRequest, error) {
+	ss, err := NewConmon_SetLogLevelRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_SetLogLevelRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_setLogLevel_Params_List is a list of Conmon_setLogLevel_Params.
+type Conmon_setLogLevel_Params_List = capnp.StructList[Conmon_setLogLevel_Params]
+
+// NewConmon_setLogLevel_Params creates a new list of Conmon_setLogLevel_Params.
+func NewConmon_setLogLevel_Params_List(s *capnp.Segment, sz int32) (Conmon_setLogLevel_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_setLogLevel_Params](l), err
+}
+
+// Conmon_setLogLevel_Params_Future is a wrapper for a Conmon_setLogLevel_Params promised by a client call.
+type Conmon_setLogLevel_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_setLogLevel_Params_Future) Struct() (Conmon_setLogLevel_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_setLogLevel_Params(s), err
+}
+
+func (p Conmon_setLogLevel_Params_Future) Request() Conmon_SetLogLevelRequest_Future {
+	return Conmon_SetLogLevelRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_setLogLevel_Results capnp.Struct
+
+// Conmon_setLogLevel_Results_TypeID is the unique identifier for the type Conmon_setLogLevel_Results.
+const Conmon_setLogLevel_Results_TypeID = 0x9488d71c49c86c29
+
+func NewConmon_setLogLevel_Results(s *capnp.Segment) (Conmon_setLogLevel_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setLogLevel_Results(st), err
+}
+
+func NewRootConmon_setLogLevel_Results(s *capnp.Segment) (Conmon_setLogLevel_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setLogLevel_Results(st), err
+}
+
+func ReadRootConmon_setLogLevel_Results(msg *capnp.Message) (Conmon_setLogLevel_Results, error) {
+	root, err := msg.Root()
+	return Conmon_setLogLevel_Results(root.Struct()), err
+}
+
+func (s Conmon_setLogLevel_Results) String() string {
+	str, _ := text.Marshal(0x9488d71c49c86c29, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_setLogLevel_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_setLogLevel_Results) DecodeFromPtr(p capnp.Ptr) Conmon_setLogLevel_Results {
+	return Conmon_setLogLevel_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_setLogLevel_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_setLogLevel_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_setLogLevel_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_setLogLevel_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_setLogLevel_Results) Response() (Conmon_SetLogLevelResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_SetLogLevelResponse(p.Struct()), err
+}
+
+func (s Conmon_setLogLevel_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_setLogLevel_Results) SetResponse(v Conmon_SetLogLevelResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_SetLogLevelResponse struct, preferring placement in s's segment.
+func (s Conmon_setLogLevel_Results) NewResponse() (Conmon_SetLogLevelResponse, error) {
+	ss, err := NewConmon_SetLogLevelResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_SetLogLevelResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_setLogLevel_Results_List is a list of Conmon_setLogLevel_Results.
+type Conmon_setLogLevel_Results_List = capnp.StructList[Conmon_setLogLevel_Results]
+
+// NewConmon_setLogLevel_Results creates a new list of Conmon_setLogLevel_Results.
+func NewConmon_setLogLevel_Results_List(s *capnp.Segment, sz int32) (Conmon_setLogLevel_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_setLogLevel_Results](l), err
+}
+
+// Conmon_setLogLevel_Results_Future is a wrapper for a Conmon_setLogLevel_Results promised by a client call.
+type Conmon_setLogLevel_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_setLogLevel_Results_Future) Struct() (Conmon_setLogLevel_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_setLogLevel_Results(s), err
+}
+
+func (p Conmon_setLogLevel_Results_Future) Response() Conmon_SetLogLevelResponse_Future {
+	return Conmon_SetLogLevelResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_removeContainer_Params capnp.Struct
+
+// Conmon_removeContainer_Params_TypeID is the unique identifier for the type Conmon_removeContainer_Params.
+const Conmon_removeContainer_Params_TypeID = 0xad5e6e3b177fdffd
+
+func NewConmon_removeContainer_Params(s *capnp.Segment) (Conmon_removeContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_removeContainer_Params(st), err
+}
+
+func NewRootConmon_removeContainer_Params(s *capnp.Segment) (Conmon_removeContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_removeContainer_Params(st), err
+}
+
+func ReadRootConmon_removeContainer_Params(msg *capnp.Message) (Conmon_removeContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_removeContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_removeContainer_Params) String() string {
+	str, _ := text.Marshal(0xad5e6e3b177fdffd, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_removeContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_removeContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_removeContainer_Params {
+	return Conmon_removeContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_removeContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_removeContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_removeContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_removeContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_removeContainer_Params) Request() (Conmon_RemoveContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_RemoveContainerRequest(p.Struct()), err
+}
+
+func (s Conmon_removeContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_removeContainer_Params) SetRequest(v Conmon_RemoveContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_RemoveContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_removeContainer_Params) NewRequest() (Conmon_RemoveContainerRequest, error) {
+	ss, err := NewConmon_RemoveContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_RemoveContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_removeContainer_Params_List is a list of Conmon_removeContainer_Params.
+type Conmon_removeContainer_Params_List = capnp.StructList[Conmon_removeContainer_Params]
+
+// NewConmon_removeContainer_Params creates a new list of Conmon_removeContainer_Params.
+func NewConmon_removeContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_removeContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_removeContainer_Params](l), err
+}
+
+// Conmon_removeContainer_Params_Future is a wrapper for a Conmon_removeContainer_Params promised by a client call.
+type Conmon_removeContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_removeContainer_Params_Future) Struct() (Conmon_removeContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_removeContainer_Params(s), err
+}
+
+func (p Conmon_removeContainer_Params_Future) Request() Conmon_RemoveContainerRequest_Future {
+	return Conmon_RemoveContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_removeContainer_Results capnp.Struct
+
+// Conmon_removeContainer_Results_TypeID is the unique identifier for the type Conmon_removeContainer_Results.
+const Conmon_removeContainer_Results_TypeID = 0xc9701dd28ecc4dec
+
+func NewConmon_removeContainer_Results(s *capnp.Segment) (Conmon_removeContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_removeContainer_Results(st), err
+}
+
+func NewRootConmon_removeContainer_Results(s *capnp.Segment) (Conmon_removeContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_removeContainer_Results(st), err
+}
+
+func ReadRootConmon_removeContainer_Results(msg *capnp.Message) (Conmon_removeContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_removeContainer_Results(root.Struct()), err
+}
+
+func (s Conmon_removeContainer_Results) String() string {
+	str, _ := text.Marshal(0xc9701dd28ecc4dec, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_removeContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_removeContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_removeContainer_Results {
+	return Conmon_removeContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_removeContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_removeContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_removeContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_removeContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_removeContainer_Results) Response() (Conmon_RemoveContainerResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_RemoveContainerResponse(p.Struct()), err
+}
+
+func (s Conmon_removeContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_removeContainer_Results) SetResponse(v Conmon_RemoveContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_RemoveContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_removeContainer_Results) NewResponse() (Conmon_RemoveContainerResponse, error) {
+	ss, err := NewConmon_RemoveContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_RemoveContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_removeContainer_Results_List is a list of Conmon_removeContainer_Results.
+type Conmon_removeContainer_Results_List = capnp.StructList[Conmon_removeContainer_Results]
+
+// NewConmon_removeContainer_Results creates a new list of Conmon_removeContainer_Results.
+func NewConmon_removeContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_removeContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_removeContainer_Results](l), err
+}
+
+// Conmon_removeContainer_Results_Future is a wrapper for a Conmon_removeContainer_Results promised by a client call.
+type Conmon_removeContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_removeContainer_Results_Future) Struct() (Conmon_removeContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_removeContainer_Results(s), err
+}
+
+func (p Conmon_removeContainer_Results_Future) Response() Conmon_RemoveContainerResponse_Future {
+	return Conmon_RemoveContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_getContainerLogOffset_Params capnp.Struct
+
+// Conmon_getContainerLogOffset_Params_TypeID is the unique identifier for the type Conmon_getContainerLogOffset_Params.
+const Conmon_getContainerLogOffset_Params_TypeID = 0xe1d66f75234ae38a
+
+func NewConmon_getContainerLogOffset_Params(s *capnp.Segment) (Conmon_getContainerLogOffset_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_getContainerLogOffset_Params(st), err
+}
+
+func NewRootConmon_getContainerLogOffset_Params(s *capnp.Segment) (Conmon_getContainerLogOffset_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_getContainerLogOffset_Params(st), err
+}
+
+func ReadRootConmon_getContainerLogOffset_Params(msg *capnp.Message) (Conmon_getContainerLogOffset_Params, error) {
+	root, err := msg.Root()
+	return Conmon_getContainerLogOffset_Params(root.Struct()), err
+}
+
+func (s Conmon_getContainerLogOffset_Params) String() string {
+	str, _ := text.Marshal(0xe1d66f75234ae38a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_getContainerLogOffset_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_getContainerLogOffset_Params) DecodeFromPtr(p capnp.Ptr) Conmon_getContainerLogOffset_Params {
+	return Conmon_getContainerLogOffset_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_getContainerLogOffset_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_getContainerLogOffset_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_getContainerLogOffset_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_getContainerLogOffset_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_getContainerLogOffset_Params) Request() (Conmon_GetContainerLogOffsetRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_GetContainerLogOffsetRequest(p.Struct()), err
+}
+
+func (s Conmon_getContainerLogOffset_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_getContainerLogOffset_Params) SetRequest(v Conmon_GetContainerLogOffsetRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_GetContainerLogOffsetRequest struct, preferring placement in s's segment.
+func (s Conmon_getContainerLogOffset_Params) NewRequest() (Conmon_GetContainerLogOffsetRequest, error) {
+	ss, err := NewConmon_GetContainerLogOffsetRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_GetContainerLogOffsetRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_getContainerLogOffset_Params_List is a list of Conmon_getContainerLogOffset_Params.
+type Conmon_getContainerLogOffset_Params_List = capnp.StructList[Conmon_getContainerLogOffset_Params]
+
+// NewConmon_getContainerLogOffset_Params creates a new list of Conmon_getContainerLogOffset_Params.
+func NewConmon_getContainerLogOffset_Params_List(s *capnp.Segment, sz int32) (Conmon_getContainerLogOffset_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_getContainerLogOffset_Params](l), err
+}
+
+// Conmon_getContainerLogOffset_Params_Future is a wrapper for a Conmon_getContainerLogOffset_Params promised by a client call.
+type Conmon_getContainerLogOffset_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_getContainerLogOffset_Params_Future) Struct() (Conmon_getContainerLogOffset_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_getContainerLogOffset_Params(s), err
+}
+
+func (p Conmon_getContainerLogOffset_Params_Future) Request() Conmon_GetContainerLogOffsetRequest_Future {
+	return Conmon_GetContainerLogOffsetRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_getContainerLogOffset_Results capnp.Struct
+
+// Conmon_getContainerLogOffset_Results_TypeID is the unique identifier for the type Conmon_getContainerLogOffset_Results.
+const Conmon_getContainerLogOffset_Results_TypeID = 0xb34e262fa935335a
+
+func NewConmon_getContainerLogOffset_Results(s *capnp.Segment) (Conmon_getContainerLogOffset_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_getContainerLogOffset_Results(st), err
+}
+
+func NewRootConmon_getContainerLogOffset_Results(s *capnp.Segment) (Conmon_getContainerLogOffset_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_getContainerLogOffset_Results(st), err
+}
+
+func ReadRootConmon_getContainerLogOffset_Results(msg *capnp.Message) (Conmon_getContainerLogOffset_Results, error) {
+	root, err := msg.Root()
+	return Conmon_getContainerLogOffset_Results(root.Struct()), err
+}
+
+func (s Conmon_getContainerLogOffset_Results) String() string {
+	str, _ := text.Marshal(0xb34e262fa935335a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_getContainerLogOffset_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_getContainerLogOffset_Results) DecodeFromPtr(p capnp.Ptr) Conmon_getContainerLogOffset_Results {
+	return Conmon_getContainerLogOffset_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_getContainerLogOffset_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_getContainerLogOffset_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_getContainerLogOffset_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_getContainerLogOffset_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_getContainerLogOffset_Results) Response() (Conmon_GetContainerLogOffsetResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_GetContainerLogOffsetResponse(p.Struct()), err
+}
+
+func (s Conmon_getContainerLogOffset_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_getContainerLogOffset_Results) SetResponse(v Conmon_GetContainerLogOffsetResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_GetContainerLogOffsetResponse struct, preferring placement in s's segment.
+func (s Conmon_getContainerLogOffset_Results) NewResponse() (Conmon_GetContainerLogOffsetResponse, error) {
+	ss, err := NewConmon_GetContainerLogOffsetResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_GetContainerLogOffsetResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_getContainerLogOffset_Results_List is a list of Conmon_getContainerLogOffset_Results.
+type Conmon_getContainerLogOffset_Results_List = capnp.StructList[Conmon_getContainerLogOffset_Results]
+
+// NewConmon_getContainerLogOffset_Results creates a new list of Conmon_getContainerLogOffset_Results.
+func NewConmon_getContainerLogOffset_Results_List(s *capnp.Segment, sz int32) (Conmon_getContainerLogOffset_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_getContainerLogOffset_Results](l), err
+}
+
+// Conmon_getContainerLogOffset_Results_Future is a wrapper for a Conmon_getContainerLogOffset_Results promised by a client call.
+type Conmon_getContainerLogOffset_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_getContainerLogOffset_Results_Future) Struct() (Conmon_getContainerLogOffset_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_getContainerLogOffset_Results(s), err
+}
+
+func (p Conmon_getContainerLogOffset_Results_Future) Response() Conmon_GetContainerLogOffsetResponse_Future {
+	return Conmon_GetContainerLogOffsetResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_flushContainerLogs_Params capnp.Struct
+
+// Conmon_flushContainerLogs_Params_TypeID is the unique identifier for the type Conmon_flushContainerLogs_Params.
+const Conmon_flushContainerLogs_Params_TypeID = 0xd2cb6549091ed7df
+
+func NewConmon_flushContainerLogs_Params(s *capnp.Segment) (Conmon_flushContainerLogs_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_flushContainerLogs_Params(st), err
+}
+
+func NewRootConmon_flushContainerLogs_Params(s *capnp.Segment) (Conmon_flushContainerLogs_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_flushContainerLogs_Params(st), err
+}
+
+func ReadRootConmon_flushContainerLogs_Params(msg *capnp.Message) (Conmon_flushContainerLogs_Params, error) {
+	root, err := msg.Root()
+	return Conmon_flushContainerLogs_Params(root.Struct()), err
+}
+
+func (s Conmon_flushContainerLogs_Params) String() string {
+	str, _ := text.Marshal(0xd2cb6549091ed7df, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_flushContainerLogs_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_flushContainerLogs_Params) DecodeFromPtr(p capnp.Ptr) Conmon_flushContainerLogs_Params {
+	return Conmon_flushContainerLogs_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_flushContainerLogs_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_flushContainerLogs_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_flushContainerLogs_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_flushContainerLogs_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_flushContainerLogs_Params) Request() (Conmon_FlushContainerLogsRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_FlushContainerLogsRequest(p.Struct()), err
+}
+
+func (s Conmon_flushContainerLogs_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_flushContainerLogs_Params) SetRequest(v Conmon_FlushContainerLogsRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_FlushContainerLogsRequest struct, preferring placement in s's segment.
+func (s Conmon_flushContainerLogs_Params) NewRequest() (Conmon_FlushContainerLogsRequest, error) {
+	ss, err := NewConmon_FlushContainerLogsRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_FlushContainerLogsRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_flushContainerLogs_Params_List is a list of Conmon_flushContainerLogs_Params.
+type Conmon_flushContainerLogs_Params_List = capnp.StructList[Conmon_flushContainerLogs_Params]
+
+// NewConmon_flushContainerLogs_Params creates a new list of Conmon_flushContainerLogs_Params.
+func NewConmon_flushContainerLogs_Params_List(s *capnp.Segment, sz int32) (Conmon_flushContainerLogs_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_flushContainerLogs_Params](l), err
+}
+
+// Conmon_flushContainerLogs_Params_Future is a wrapper for a Conmon_flushContainerLogs_Params promised by a client call.
+type Conmon_flushContainerLogs_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_flushContainerLogs_Params_Future) Struct() (Conmon_flushContainerLogs_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_flushContainerLogs_Params(s), err
+}
+
+func (p Conmon_flushContainerLogs_Params_Future) Request() Conmon_FlushContainerLogsRequest_Future {
+	return Conmon_FlushContainerLogsRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_flushContainerLogs_Results capnp.Struct
+
+// Conmon_flushContainerLogs_Results_TypeID is the unique identifier for the type Conmon_flushContainerLogs_Results.
+const Conmon_flushContainerLogs_Results_TypeID = 0x97c2918f8d3765ca
+
+func NewConmon_flushContainerLogs_Results(s *capnp.Segment) (Conmon_flushContainerLogs_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_flushContainerLogs_Results(st), err
+}
+
+func NewRootConmon_flushContainerLogs_Results(s *capnp.Segment) (Conmon_flushContainerLogs_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_flushContainerLogs_Results(st), err
+}
+
+func ReadRootConmon_flushContainerLogs_Results(msg *capnp.Message) (Conmon_flushContainerLogs_Results, error) {
+	root, err := msg.Root()
+	return Conmon_flushContainerLogs_Results(root.Struct()), err
+}
+
+func (s Conmon_flushContainerLogs_Results) String() string {
+	str, _ := text.Marshal(0x97c2918f8d3765ca, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_flushContainerLogs_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_flushContainerLogs_Results) DecodeFromPtr(p capnp.Ptr) Conmon_flushContainerLogs_Results {
+	return Conmon_flushContainerLogs_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_flushContainerLogs_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_flushContainerLogs_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_flushContainerLogs_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_flushContainerLogs_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_flushContainerLogs_Results) Response() (Conmon_FlushContainerLogsResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_FlushContainerLogsResponse(p.Struct()), err
+}
+
+func (s Conmon_flushContainerLogs_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_flushContainerLogs_Results) SetResponse(v Conmon_FlushContainerLogsResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_FlushContainerLogsResponse struct, preferring placement in s's segment.
+func (s Conmon_flushContainerLogs_Results) NewResponse() (Conmon_FlushContainerLogsResponse, error) {
+	ss, err := NewConmon_FlushContainerLogsResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_FlushContainerLogsResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_flushContainerLogs_Results_List is a list of Conmon_flushContainerLogs_Results.
+type Conmon_flushContainerLogs_Results_List = capnp.StructList[Conmon_flushContainerLogs_Results]
+
+// NewConmon_flushContainerLogs_Results creates a new list of Conmon_flushContainerLogs_Results.
+func NewConmon_flushContainerLogs_Results_List(s *capnp.Segment, sz int32) (Conmon_flushContainerLogs_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_flushContainerLogs_Results](l), err
+}
+
+// Conmon_flushContainerLogs_Results_Future is a wrapper for a Conmon_flushContainerLogs_Results promised by a client call.
+type Conmon_flushContainerLogs_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_flushContainerLogs_Results_Future) Struct() (Conmon_flushContainerLogs_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_flushContainerLogs_Results(s), err
+}
+
+func (p Conmon_flushContainerLogs_Results_Future) Response() Conmon_FlushContainerLogsResponse_Future {
+	return Conmon_FlushContainerLogsResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_validateCreate_Params capnp.Struct
+
+// Conmon_validateCreate_Params_TypeID is the unique identifier for the type Conmon_validateCreate_Params.
+const Conmon_validateCreate_Params_TypeID = 0xa01442f335a6cc00
+
+func NewConmon_validateCreate_Params(s *capnp.Segment) (Conmon_validateCreate_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_validateCreate_Params(st), err
+}
+
+func NewRootConmon_validateCreate_Params(s *capnp.Segment) (Conmon_validateCreate_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_validateCreate_Params(st), err
+}
+
+func ReadRootConmon_validateCreate_Params(msg *capnp.Message) (Conmon_validateCreate_Params, error) {
+	root, err := msg.Root()
+	return Conmon_validateCreate_Params(root.Struct()), err
+}
+
+func (s Conmon_validateCreate_Params) String() string {
+	str, _ := text.Marshal(0xa01442f335a6cc00, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_validateCreate_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_validateCreate_Params) DecodeFromPtr(p capnp.Ptr) Conmon_validateCreate_Params {
+	return Conmon_validateCreate_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_validateCreate_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_validateCreate_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_validateCreate_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_validateCreate_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_validateCreate_Params) Request() (Conmon_CreateContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_CreateContainerRequest(p.Struct()), err
+}
+
+func (s Conmon_validateCreate_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_validateCreate_Params) SetRequest(v Conmon_CreateContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_CreateContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_validateCreate_Params) NewRequest() (Conmon_CreateContainerRequest, error) {
+	ss, err := NewConmon_CreateContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_CreateContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_validateCreate_Params_List is a list of Conmon_validateCreate_Params.
+type Conmon_validateCreate_Params_List = capnp.StructList[Conmon_validateCreate_Params]
+
+// NewConmon_validateCreate_Params creates a new list of Conmon_validateCreate_Params.
+func NewConmon_validateCreate_Params_List(s *capnp.Segment, sz int32) (Conmon_validateCreate_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_validateCreate_Params](l), err
+}
+
+// Conmon_validateCreate_Params_Future is a wrapper for a Conmon_validateCreate_Params promised by a client call.
+type Conmon_validateCreate_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_validateCreate_Params_Future) Struct() (Conmon_validateCreate_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_validateCreate_Params(s), err
+}
+
+func (p Conmon_validateCreate_Params_Future) Request() Conmon_CreateContainerRequest_Future {
+	return Conmon_CreateContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_validateCreate_Results capnp.Struct
+
+// Conmon_validateCreate_Results_TypeID is the unique identifier for the type Conmon_validateCreate_Results.
+const Conmon_validateCreate_Results_TypeID = 0xa85a62dd95c50d7f
+
+func NewConmon_validateCreate_Results(s *capnp.Segment) (Conmon_validateCreate_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_validateCreate_Results(st), err
+}
+
+func NewRootConmon_validateCreate_Results(s *capnp.Segment) (Conmon_validateCreate_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_validateCreate_Results(st), err
+}
+
+func ReadRootConmon_validateCreate_Results(msg *capnp.Message) (Conmon_validateCreate_Results, error) {
+	root, err := msg.Root()
+	return Conmon_validateCreate_Results(root.Struct()), err
+}
+
+func (s Conmon_validateCreate_Results) String() string {
+	str, _ := text.Marshal(0xa85a62dd95c50d7f, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_validateCreate_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_validateCreate_Results) DecodeFromPtr(p capnp.Ptr) Conmon_validateCreate_Results {
+	return Conmon_validateCreate_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_validateCreate_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_validateCreate_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_validateCreate_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_validateCreate_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_validateCreate_Results) Response() (Conmon_ValidateCreateResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ValidateCreateResponse(p.Struct()), err
+}
+
+func (s Conmon_validateCreate_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_validateCreate_Results) SetResponse(v Conmon_ValidateCreateResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ValidateCreateResponse struct, preferring placement in s's segment.
+func (s Conmon_validateCreate_Results) NewResponse() (Conmon_ValidateCreateResponse, error) {
+	ss, err := NewConmon_ValidateCreateResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ValidateCreateResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_validateCreate_Results_List is a list of Conmon_validateCreate_Results.
+type Conmon_validateCreate_Results_List = capnp.StructList[Conmon_validateCreate_Results]
+
+// NewConmon_validateCreate_Results creates a new list of Conmon_validateCreate_Results.
+func NewConmon_validateCreate_Results_List(s *capnp.Segment, sz int32) (Conmon_validateCreate_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_validateCreate_Results](l), err
+}
+
+// Conmon_validateCreate_Results_Future is a wrapper for a Conmon_validateCreate_Results promised by a client call.
+type Conmon_validateCreate_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_validateCreate_Results_Future) Struct() (Conmon_validateCreate_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_validateCreate_Results(s), err
+}
+
+func (p Conmon_validateCreate_Results_Future) Response() Conmon_ValidateCreateResponse_Future {
+	return Conmon_ValidateCreateResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_updateLogDrivers_Params capnp.Struct
+
+// Conmon_updateLogDrivers_Params_TypeID is the unique identifier for the type Conmon_updateLogDrivers_Params.
+const Conmon_updateLogDrivers_Params_TypeID = 0xdfca9ee49ebf0d98
+
+func NewConmon_updateLogDrivers_Params(s *capnp.Segment) (Conmon_updateLogDrivers_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_updateLogDrivers_Params(st), err
+}
+
+func NewRootConmon_updateLogDrivers_Params(s *capnp.Segment) (Conmon_updateLogDrivers_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_updateLogDrivers_Params(st), err
+}
+
+func ReadRootConmon_updateLogDrivers_Params(msg *capnp.Message) (Conmon_updateLogDrivers_Params, error) {
+	root, err := msg.Root()
+	return Conmon_updateLogDrivers_Params(root.Struct()), err
+}
+
+func (s Conmon_updateLogDrivers_Params) String() string {
+	str, _ := text.Marshal(0xdfca9ee49ebf0d98, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_updateLogDrivers_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_updateLogDrivers_Params) DecodeFromPtr(p capnp.Ptr) Conmon_updateLogDrivers_Params {
+	return Conmon_updateLogDrivers_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_updateLogDrivers_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_updateLogDrivers_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_updateLogDrivers_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_updateLogDrivers_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_updateLogDrivers_Params) Request() (Conmon_UpdateLogDriversRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_UpdateLogDriversRequest(p.Struct()), err
+}
+
+func (s Conmon_updateLogDrivers_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_updateLogDrivers_Params) SetRequest(v Conmon_UpdateLogDriversRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_UpdateLogDriversRequest struct, preferring placement in s's segment.
+func (s Conmon_updateLogDrivers_Params) NewRequest() (Conmon_UpdateLogDriversRequest, error) {
+	ss, err := NewConmon_UpdateLogDriversRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_UpdateLogDriversRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_updateLogDrivers_Params_List is a list of Conmon_updateLogDrivers_Params.
+type Conmon_updateLogDrivers_Params_List = capnp.StructList[Conmon_updateLogDrivers_Params]
+
+// NewConmon_updateLogDrivers_Params creates a new list of Conmon_updateLogDrivers_Params.
+func NewConmon_updateLogDrivers_Params_List(s *capnp.Segment, sz int32) (Conmon_updateLogDrivers_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_updateLogDrivers_Params](l), err
+}
+
+// Conmon_updateLogDrivers_Params_Future is a wrapper for a Conmon_updateLogDrivers_Params promised by a client call.
+type Conmon_updateLogDrivers_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_updateLogDrivers_Params_Future) Struct() (Conmon_updateLogDrivers_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_updateLogDrivers_Params(s), err
+}
+
+func (p Conmon_updateLogDrivers_Params_Future) Request() Conmon_UpdateLogDriversRequest_Future {
+	return Conmon_UpdateLogDriversRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_updateLogDrivers_Results capnp.Struct
+
+// Conmon_updateLogDrivers_Results_TypeID is the unique identifier for the type Conmon_updateLogDrivers_Results.
+const Conmon_updateLogDrivers_Results_TypeID = 0x968709e5ac646fae
+
+func NewConmon_updateLogDrivers_Results(s *capnp.Segment) (Conmon_updateLogDrivers_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_updateLogDrivers_Results(st), err
+}
+
+func NewRootConmon_updateLogDrivers_Results(s *capnp.Segment) (Conmon_updateLogDrivers_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_updateLogDrivers_Results(st), err
+}
+
+func ReadRootConmon_updateLogDrivers_Results(msg *capnp.Message) (Conmon_updateLogDrivers_Results, error) {
+	root, err := msg.Root()
+	return Conmon_updateLogDrivers_Results(root.Struct()), err
+}
+
+func (s Conmon_updateLogDrivers_Results) String() string {
+	str, _ := text.Marshal(0x968709e5ac646fae, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_updateLogDrivers_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_updateLogDrivers_Results) DecodeFromPtr(p capnp.Ptr) Conmon_updateLogDrivers_Results {
+	return Conmon_updateLogDrivers_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_updateLogDrivers_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_updateLogDrivers_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_updateLogDrivers_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_updateLogDrivers_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_updateLogDrivers_Results) Response() (Conmon_UpdateLogDriversResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_UpdateLogDriversResponse(p.Struct()), err
+}
+
+func (s Conmon_updateLogDrivers_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_updateLogDrivers_Results) SetResponse(v Conmon_UpdateLogDriversResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_UpdateLogDriversResponse struct, preferring placement in s's segment.
+func (s Conmon_updateLogDrivers_Results) NewResponse() (Conmon_UpdateLogDriversResponse, error) {
+	ss, err := NewConmon_UpdateLogDriversResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_UpdateLogDriversResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_updateLogDrivers_Results_List is a list of Conmon_updateLogDrivers_Results.
+type Conmon_updateLogDrivers_Results_List = capnp.StructList[Conmon_updateLogDrivers_Results]
+
+// NewConmon_updateLogDrivers_Results creates a new list of Conmon_updateLogDrivers_Results.
+func NewConmon_updateLogDrivers_Results_List(s *capnp.Segment, sz int32) (Conmon_updateLogDrivers_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_updateLogDrivers_Results](l), err
+}
+
+// Conmon_updateLogDrivers_Results_Future is a wrapper for a Conmon_updateLogDrivers_Results promised by a client call.
+type Conmon_updateLogDrivers_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_updateLogDrivers_Results_Future) Struct() (Conmon_updateLogDrivers_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_updateLogDrivers_Results(s), err
+}
+
+func (p Conmon_updateLogDrivers_Results_Future) Response() Conmon_UpdateLogDriversResponse_Future {
+	return Conmon_UpdateLogDriversResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_health_Params capnp.Struct
+
+// Conmon_health_Params_TypeID is the unique identifier for the type Conmon_health_Params.
+const Conmon_health_Params_TypeID = 0xf18bd11dcac0404b
+
+func NewConmon_health_Params(s *capnp.Segment) (Conmon_health_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_health_Params(st), err
+}
+
+func NewRootConmon_health_Params(s *capnp.Segment) (Conmon_health_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_health_Params(st), err
+}
+
+func ReadRootConmon_health_Params(msg *capnp.Message) (Conmon_health_Params, error) {
+	root, err := msg.Root()
+	return Conmon_health_Params(root.Struct()), err
+}
+
+func (s Conmon_health_Params) String() string {
+	str, _ := text.Marshal(0xf18bd11dcac0404b, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_health_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_health_Params) DecodeFromPtr(p capnp.Ptr) Conmon_health_Params {
+	return Conmon_health_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_health_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_health_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_health_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_health_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_health_Params) Request() (Conmon_HealthRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_HealthRequest(p.Struct()), err
+}
+
+func (s Conmon_health_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_health_Params) SetRequest(v Conmon_HealthRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_HealthRequest struct, preferring placement in s's segment.
+func (s Conmon_health_Params) NewRequest() (Conmon_HealthRequest, error) {
+	ss, err := NewConmon_HealthRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_HealthRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_health_Params_List is a list of Conmon_health_Params.
+type Conmon_health_Params_List = capnp.StructList[Conmon_health_Params]
+
+// NewConmon_health_Params creates a new list of Conmon_health_Params.
+func NewConmon_health_Params_List(s *capnp.Segment, sz int32) (Conmon_health_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_health_Params](l), err
+}
+
+// Conmon_health_Params_Future is a wrapper for a Conmon_health_Params promised by a client call.
+type Conmon_health_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_health_Params_Future) Struct() (Conmon_health_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_health_Params(s), err
+}
+
+func (p Conmon_health_Params_Future) Request() Conmon_HealthRequest_Future {
+	return Conmon_HealthRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_health_Results capnp.Struct
+
+// Conmon_health_Results_TypeID is the unique identifier for the type Conmon_health_Results.
+const Conmon_health_Results_TypeID = 0xa199c5435b00304a
+
+func NewConmon_health_Results(s *capnp.Segment) (Conmon_health_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_health_Results(st), err
+}
+
+func NewRootConmon_health_Results(s *capnp.Segment) (Conmon_health_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_health_Results(st), err
+}
+
+func ReadRootConmon_health_Results(msg *capnp.Message) (Conmon_health_Results, error) {
+	root, err := msg.Root()
+	return Conmon_health_Results(root.Struct()), err
+}
+
+func (s Conmon_health_Results) String() string {
+	str, _ := text.Marshal(0xa199c5435b00304a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_health_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_health_Results) DecodeFromPtr(p capnp.Ptr) Conmon_health_Results {
+	return Conmon_health_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_health_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_health_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_health_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_health_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_health_Results) Response() (Conmon_HealthResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_HealthResponse(p.Struct()), err
+}
+
+func (s Conmon_health_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_health_Results) SetResponse(v Conmon_HealthResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_HealthResponse struct, preferring placement in s's segment.
+func (s Conmon_health_Results) NewResponse() (Conmon_HealthResponse, error) {
+	ss, err := NewConmon_HealthResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_HealthResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_health_Results_List is a list of Conmon_health_Results.
+type Conmon_health_Results_List = capnp.StructList[Conmon_health_Results]
+
+// NewConmon_health_Results creates a new list of Conmon_health_Results.
+func NewConmon_health_Results_List(s *capnp.Segment, sz int32) (Conmon_health_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_health_Results](l), err
+}
+
+// Conmon_health_Results_Future is a wrapper for a Conmon_health_Results promised by a client call.
+type Conmon_health_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_health_Results_Future) Struct() (Conmon_health_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_health_Results(s), err
+}
+
+func (p Conmon_health_Results_Future) Response() Conmon_HealthResponse_Future {
+	return Conmon_HealthResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_setTerminalModeContainer_Params capnp.Struct
+
+// Conmon_setTerminalModeContainer_Params_TypeID is the unique identifier for the type Conmon_setTerminalModeContainer_Params.
+const Conmon_setTerminalModeContainer_Params_TypeID = 0x8ceb3503d8b127df
+
+func NewConmon_setTerminalModeContainer_Params(s *capnp.Segment) (Conmon_setTerminalModeContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setTerminalModeContainer_Params(st), err
+}
+
+func NewRootConmon_setTerminalModeContainer_Params(s *capnp.Segment) (Conmon_setTerminalModeContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setTerminalModeContainer_Params(st), err
+}
+
+func ReadRootConmon_setTerminalModeContainer_Params(msg *capnp.Message) (Conmon_setTerminalModeContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_setTerminalModeContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_setTerminalModeContainer_Params) String() string {
+	str, _ := text.Marshal(0x8ceb3503d8b127df, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_setTerminalModeContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_setTerminalModeContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_setTerminalModeContainer_Params {
+	return Conmon_setTerminalModeContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_setTerminalModeContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_setTerminalModeContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_setTerminalModeContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_setTerminalModeContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_setTerminalModeContainer_Params) Request() (Conmon_SetTerminalModeRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_SetTerminalModeRequest(p.Struct()), err
+}
+
+func (s Conmon_setTerminalModeContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_setTerminalModeContainer_Params) SetRequest(v Conmon_SetTerminalModeRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_SetTerminalModeRequest struct, preferring placement in s's segment.
+func (s Conmon_setTerminalModeContainer_Params) NewRequest() (Conmon_SetTerminalModeRequest, error) {
+	ss, err := NewConmon_SetTerminalModeRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_SetTerminalModeRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_setTerminalModeContainer_Params_List is a list of Conmon_setTerminalModeContainer_Params.
+type Conmon_setTerminalModeContainer_Params_List = capnp.StructList[Conmon_setTerminalModeContainer_Params]
+
+// NewConmon_setTerminalModeContainer_Params creates a new list of Conmon_setTerminalModeContainer_Params.
+func NewConmon_setTerminalModeContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_setTerminalModeContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_setTerminalModeContainer_Params](l), err
+}
+
+// Conmon_setTerminalModeContainer_Params_Future is a wrapper for a Conmon_setTerminalModeContainer_Params promised by a client call.
+type Conmon_setTerminalModeContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_setTerminalModeContainer_Params_Future) Struct() (Conmon_setTerminalModeContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_setTerminalModeContainer_Params(s), err
+}
+
+func (p Conmon_setTerminalModeContainer_Params_Future) Request() Conmon_SetTerminalModeRequest_Future {
+	return Conmon_SetTerminalModeRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_setTerminalModeContainer_Results capnp.Struct
+
+// Conmon_setTerminalModeContainer_Results_TypeID is the unique identifier for the type Conmon_setTerminalModeContainer_Results.
+const Conmon_setTerminalModeContainer_Results_TypeID = 0xfaf066b0dfd2c1d5
+
+func NewConmon_setTerminalModeContainer_Results(s *capnp.Segment) (Conmon_setTerminalModeContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setTerminalModeContainer_Results(st), err
+}
+
+func NewRootConmon_setTerminalModeContainer_Results(s *capnp.Segment) (Conmon_setTerminalModeContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setTerminalModeContainer_Results(st), err
+}
+
+func ReadRootConmon_setTerminalModeContainer_Results(msg *capnp.Message) (Conmon_setTerminalModeContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_setTerminalModeContainer_Results(root.Struct()), err
+}
+
+func (s Conmon_setTerminalModeContainer_Results) String() string {
+	str, _ := text.Marshal(0xfaf066b0dfd2c1d5, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_setTerminalModeContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_setTerminalModeContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_setTerminalModeContainer_Results {
+	return Conmon_setTerminalModeContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_setTerminalModeContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_setTerminalModeContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_setTerminalModeContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_setTerminalModeContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_setTerminalModeContainer_Results) Response() (Conmon_SetTerminalModeResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_SetTerminalModeResponse(p.Struct()), err
+}
+
+func (s Conmon_setTerminalModeContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_setTerminalModeContainer_Results) SetResponse(v Conmon_SetTerminalModeResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_SetTerminalModeResponse struct, preferring placement in s's segment.
+func (s Conmon_setTerminalModeContainer_Results) NewResponse() (Conmon_SetTerminalModeResponse, error) {
+	ss, err := NewConmon_SetTerminalModeResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_SetTerminalModeResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_setTerminalModeContainer_Results_List is a list of Conmon_setTerminalModeContainer_Results.
+type Conmon_setTerminalModeContainer_Results_List = capnp.StructList[Conmon_setTerminalModeContainer_Results]
+
+// NewConmon_setTerminalModeContainer_Results creates a new list of Conmon_setTerminalModeContainer_Results.
+func NewConmon_setTerminalModeContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_setTerminalModeContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_setTerminalModeContainer_Results](l), err
+}
+
+// Conmon_setTerminalModeContainer_Results_Future is a wrapper for a Conmon_setTerminalModeContainer_Results promised by a client call.
+type Conmon_setTerminalModeContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_setTerminalModeContainer_Results_Future) Struct() (Conmon_setTerminalModeContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_setTerminalModeContainer_Results(s), err
+}
+
+func (p Conmon_setTerminalModeContainer_Results_Future) Response() Conmon_SetTerminalModeResponse_Future {
+	return Conmon_SetTerminalModeResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_readExecOutput_Params capnp.Struct
+
+// Conmon_readExecOutput_Params_TypeID is the unique identifier for the type Conmon_readExecOutput_Params.
+const Conmon_readExecOutput_Params_TypeID = 0x88a7c20d48426128
+
+func NewConmon_readExecOutput_Params(s *capnp.Segment) (Conmon_readExecOutput_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_readExecOutput_Params(st), err
+}
+
+func NewRootConmon_readExecOutput_Params(s *capnp.Segment) (Conmon_readExecOutput_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_readExecOutput_Params(st), err
+}
+
+func ReadRootConmon_readExecOutput_Params(msg *capnp.Message) (Conmon_readExecOutput_Params, error) {
+	root, err := msg.Root()
+	return Conmon_readExecOutput_Params(root.Struct()), err
+}
+
+func (s Conmon_readExecOutput_Params) String() string {
+	str, _ := text.Marshal(0x88a7c20d48426128, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_readExecOutput_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_readExecOutput_Params) DecodeFromPtr(p capnp.Ptr) Conmon_readExecOutput_Params {
+	return Conmon_readExecOutput_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_readExecOutput_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_readExecOutput_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_readExecOutput_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_readExecOutput_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_readExecOutput_Params) Request() (Conmon_ReadExecOutputRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ReadExecOutputRequest(p.Struct()), err
+}
+
+func (s Conmon_readExecOutput_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_readExecOutput_Params) SetRequest(v Conmon_ReadExecOutputRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ReadExecOutputRequest struct, preferring placement in s's segment.
+func (s Conmon_readExecOutput_Params) NewRequest() (Conmon_ReadExecOutputRequest, error) {
+	ss, err := NewConmon_ReadExecOutputRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ReadExecOutputRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_readExecOutput_Params_List is a list of Conmon_readExecOutput_Params.
+type Conmon_readExecOutput_Params_List = capnp.StructList[Conmon_readExecOutput_Params]
+
+// NewConmon_readExecOutput_Params creates a new list of Conmon_readExecOutput_Params.
+func NewConmon_readExecOutput_Params_List(s *capnp.Segment, sz int32) (Conmon_readExecOutput_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_readExecOutput_Params](l), err
+}
+
+// Conmon_readExecOutput_Params_Future is a wrapper for a Conmon_readExecOutput_Params promised by a client call.
+type Conmon_readExecOutput_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_readExecOutput_Params_Future) Struct() (Conmon_readExecOutput_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_readExecOutput_Params(s), err
+}
+
+func (p Conmon_readExecOutput_Params_Future) Request() Conmon_ReadExecOutputRequest_Future {
+	return Conmon_ReadExecOutputRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_readExecOutput_Results capnp.Struct
+
+// Conmon_readExecOutput_Results_TypeID is the unique identifier for the type Conmon_readExecOutput_Results.
+const Conmon_readExecOutput_Results_TypeID = 0xbe34f78f6a935b18
+
+func NewConmon_readExecOutput_Results(s *capnp.Segment) (Conmon_readExecOutput_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_readExecOutput_Results(st), err
+}
+
+func NewRootConmon_readExecOutput_Results(s *capnp.Segment) (Conmon_readExecOutput_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_readExecOutput_Results(st), err
+}
+
+func ReadRootConmon_readExecOutput_Results(msg *capnp.Message) (Conmon_readExecOutput_Results, error) {
+	root, err := msg.Root()
+	return Conmon_readExecOutput_Results(root.Struct()), err
+}
+
+func (s Conmon_readExecOutput_Results) String() string {
+	str, _ := text.Marshal(0xbe34f78f6a935b18, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_readExecOutput_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_readExecOutput_Results) DecodeFromPtr(p capnp.Ptr) Conmon_readExecOutput_Results {
+	return Conmon_readExecOutput_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_readExecOutput_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_readExecOutput_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_readExecOutput_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_readExecOutput_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_readExecOutput_Results) Response() (Conmon_ReadExecOutputResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ReadExecOutputResponse(p.Struct()), err
+}
+
+func (s Conmon_readExecOutput_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_readExecOutput_Results) SetResponse(v Conmon_ReadExecOutputResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ReadExecOutputResponse struct, preferring placement in s's segment.
+func (s Conmon_readExecOutput_Results) NewResponse() (Conmon_ReadExecOutputResponse, error) {
+	ss, err := NewConmon_ReadExecOutputResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ReadExecOutputResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_readExecOutput_Results_List is a list of Conmon_readExecOutput_Results.
+type Conmon_readExecOutput_Results_List = capnp.StructList[Conmon_readExecOutput_Results]
+
+// NewConmon_readExecOutput_Results creates a new list of Conmon_readExecOutput_Results.
+func NewConmon_readExecOutput_Results_List(s *capnp.Segment, sz int32) (Conmon_readExecOutput_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_readExecOutput_Results](l), err
+}
+
+// Conmon_readExecOutput_Results_Future is a wrapper for a Conmon_readExecOutput_Results promised by a client call.
+type Conmon_readExecOutput_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_readExecOutput_Results_Future) Struct() (Conmon_readExecOutput_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_readExecOutput_Results(s), err
+}
+
+func (p Conmon_readExecOutput_Results_Future) Response() Conmon_ReadExecOutputResponse_Future {
+	return Conmon_ReadExecOutputResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_mirrorContainerIO_Params capnp.Struct
+
+// Conmon_mirrorContainerIO_Params_TypeID is the unique identifier for the type Conmon_mirrorContainerIO_Params.
+const Conmon_mirrorContainerIO_Params_TypeID = 0xfd592f0d89b7b928
+
+func NewConmon_mirrorContainerIO_Params(s *capnp.Segment) (Conmon_mirrorContainerIO_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_mirrorContainerIO_Params(st), err
+}
+
+func NewRootConmon_mirrorContainerIO_Params(s *capnp.Segment) (Conmon_mirrorContainerIO_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_mirrorContainerIO_Params(st), err
+}
+
+func ReadRootConmon_mirrorContainerIO_Params(msg *capnp.Message) (Conmon_mirrorContainerIO_Params, error) {
+	root, err := msg.Root()
+	return Conmon_mirrorContainerIO_Params(root.Struct()), err
+}
+
+func (s Conmon_mirrorContainerIO_Params) String() string {
+	str, _ := text.Marshal(0xfd592f0d89b7b928, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_mirrorContainerIO_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_mirrorContainerIO_Params) DecodeFromPtr(p capnp.Ptr) Conmon_mirrorContainerIO_Params {
+	return Conmon_mirrorContainerIO_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_mirrorContainerIO_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_mirrorContainerIO_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_mirrorContainerIO_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_mirrorContainerIO_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_mirrorContainerIO_Params) Request() (Conmon_MirrorContainerIORequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_MirrorContainerIORequest(p.Struct()), err
+}
+
+func (s Conmon_mirrorContainerIO_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_mirrorContainerIO_Params) SetRequest(v Conmon_MirrorContainerIORequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_MirrorContainerIORequest struct, preferring placement in s's segment.
+func (s Conmon_mirrorContainerIO_Params) NewRequest() (Conmon_MirrorContainerIORequest, error) {
+	ss, err := NewConmon_MirrorContainerIORequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_MirrorContainerIORequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_mirrorContainerIO_Params_List is a list of Conmon_mirrorContainerIO_Params.
+type Conmon_mirrorContainerIO_Params_List = capnp.StructList[Conmon_mirrorContainerIO_Params]
+
+// NewConmon_mirrorContainerIO_Params creates a new list of Conmon_mirrorContainerIO_Params.
+func NewConmon_mirrorContainerIO_Params_List(s *capnp.Segment, sz int32) (Conmon_mirrorContainerIO_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_mirrorContainerIO_Params](l), err
+}
+
+// Conmon_mirrorContainerIO_Params_Future is a wrapper for a Conmon_mirrorContainerIO_Params promised by a client call.
+type Conmon_mirrorContainerIO_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_mirrorContainerIO_Params_Future) Struct() (Conmon_mirrorContainerIO_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_mirrorContainerIO_Params(s), err
+}
+
+func (p Conmon_mirrorContainerIO_Params_Future) Request() Conmon_MirrorContainerIORequest_Future {
+	return Conmon_MirrorContainerIORequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_mirrorContainerIO_Results capnp.Struct
+
+// Conmon_mirrorContainerIO_Results_TypeID is the unique identifier for the type Conmon_mirrorContainerIO_Results.
+const Conmon_mirrorContainerIO_Results_TypeID = 0xa8757cef51f9fba2
+
+func NewConmon_mirrorContainerIO_Results(s *capnp.Segment) (Conmon_mirrorContainerIO_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_mirrorContainerIO_Results(st), err
+}
+
+func NewRootConmon_mirrorContainerIO_Results(s *capnp.Segment) (Conmon_mirrorContainerIO_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_mirrorContainerIO_Results(st), err
+}
+
+func ReadRootConmon_mirrorContainerIO_Results(msg *capnp.Message) (Conmon_mirrorContainerIO_Results, error) {
+	root, err := msg.Root()
+	return Conmon_mirrorContainerIO_Results(root.Struct()), err
+}
+
+func (s Conmon_mirrorContainerIO_Results) String() string {
+	str, _ := text.Marshal(0xa8757cef51f9fba2, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_mirrorContainerIO_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_mirrorContainerIO_Results) DecodeFromPtr(p capnp.Ptr) Conmon_mirrorContainerIO_Results {
+	return Conmon_mirrorContainerIO_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_mirrorContainerIO_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_mirrorContainerIO_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_mirrorContainerIO_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_mirrorContainerIO_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_mirrorContainerIO_Results) Response() (Conmon_MirrorContainerIOResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_MirrorContainerIOResponse(p.Struct()), err
+}
+
+func (s Conmon_mirrorContainerIO_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_mirrorContainerIO_Results) SetResponse(v Conmon_MirrorContainerIOResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_MirrorContainerIOResponse struct, preferring placement in s's segment.
+func (s Conmon_mirrorContainerIO_Results) NewResponse() (Conmon_MirrorContainerIOResponse, error) {
+	ss, err := NewConmon_MirrorContainerIOResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_MirrorContainerIOResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_mirrorContainerIO_Results_List is a list of Conmon_mirrorContainerIO_Results.
+type Conmon_mirrorContainerIO_Results_List = capnp.StructList[Conmon_mirrorContainerIO_Results]
+
+// NewConmon_mirrorContainerIO_Results creates a new list of Conmon_mirrorContainerIO_Results.
+func NewConmon_mirrorContainerIO_Results_List(s *capnp.Segment, sz int32) (Conmon_mirrorContainerIO_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_mirrorContainerIO_Results](l), err
+}
+
+// Conmon_mirrorContainerIO_Results_Future is a wrapper for a Conmon_mirrorContainerIO_Results promised by a client call.
+type Conmon_mirrorContainerIO_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_mirrorContainerIO_Results_Future) Struct() (Conmon_mirrorContainerIO_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_mirrorContainerIO_Results(s), err
+}
+
+func (p Conmon_mirrorContainerIO_Results_Future) Response() Conmon_MirrorContainerIOResponse_Future {
+	return Conmon_MirrorContainerIOResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_containerStats_Params capnp.Struct
+
+// Conmon_containerStats_Params_TypeID is the unique identifier for the type Conmon_containerStats_Params.
+const Conmon_containerStats_Params_TypeID = 0x9a5dadc3cb5eb5a1
+
+func NewConmon_containerStats_Params(s *capnp.Segment) (Conmon_containerStats_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerStats_Params(st), err
+}
+
+func NewRootConmon_containerStats_Params(s *capnp.Segment) (Conmon_containerStats_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerStats_Params(st), err
+}
+
+func ReadRootConmon_containerStats_Params(msg *capnp.Message) (Conmon_containerStats_Params, error) {
+	root, err := msg.Root()
+	return Conmon_containerStats_Params(root.Struct()), err
+}
+
+func (s Conmon_containerStats_Params) String() string {
+	str, _ := text.Marshal(0x9a5dadc3cb5eb5a1, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_containerStats_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_containerStats_Params) DecodeFromPtr(p capnp.Ptr) Conmon_containerStats_Params {
+	return Conmon_containerStats_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_containerStats_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_containerStats_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_containerStats_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_containerStats_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_containerStats_Params) Request() (Conmon_ContainerStatsRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ContainerStatsRequest(p.Struct()), err
+}
+
+func (s Conmon_containerStats_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_containerStats_Params) SetRequest(v Conmon_ContainerStatsRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ContainerStatsRequest struct, preferring placement in s's segment.
+func (s Conmon_containerStats_Params) NewRequest() (Conmon_ContainerStatsRequest, error) {
+	ss, err := NewConmon_ContainerStatsRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ContainerStatsRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_containerStats_Params_List is a list of Conmon_containerStats_Params.
+type Conmon_containerStats_Params_List = capnp.StructList[Conmon_containerStats_Params]
+
+// NewConmon_containerStats_Params creates a new list of Conmon_containerStats_Params.
+func NewConmon_containerStats_Params_List(s *capnp.Segment, sz int32) (Conmon_containerStats_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_containerStats_Params](l), err
+}
+
+// Conmon_containerStats_Params_Future is a wrapper for a Conmon_containerStats_Params promised by a client call.
+type Conmon_containerStats_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_containerStats_Params_Future) Struct() (Conmon_containerStats_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_containerStats_Params(s), err
+}
+
+func (p Conmon_containerStats_Params_Future) Request() Conmon_ContainerStatsRequest_Future {
+	return Conmon_ContainerStatsRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_containerStats_Results capnp.Struct
+
+// Conmon_containerStats_Results_TypeID is the unique identifier for the type Conmon_containerStats_Results.
+const Conmon_containerStats_Results_TypeID = 0xe3cc22436fc42c31
+
+func NewConmon_containerStats_Results(s *capnp.Segment) (Conmon_containerStats_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerStats_Results(st), err
+}
+
+func NewRootConmon_containerStats_Results(s *capnp.Segment) (Conmon_containerStats_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerStats_Results(st), err
+}
+
+func ReadRootConmon_containerStats_Results(msg *capnp.Message) (Conmon_containerStats_Results, error) {
+	root, err := msg.Root()
+	return Conmon_containerStats_Results(root.Struct()), err
+}
+
+func (s Conmon_containerStats_Results) String() string {
+	str, _ := text.Marshal(0xe3cc22436fc42c31, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_containerStats_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_containerStats_Results) DecodeFromPtr(p capnp.Ptr) Conmon_containerStats_Results {
+	return Conmon_containerStats_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_containerStats_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_containerStats_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_containerStats_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_containerStats_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_containerStats_Results) Response() (Conmon_ContainerStatsResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ContainerStatsResponse(p.Struct()), err
+}
+
+func (s Conmon_containerStats_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_containerStats_Results) SetResponse(v Conmon_ContainerStatsResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ContainerStatsResponse struct, preferring placement in s's segment.
+func (s Conmon_containerStats_Results) NewResponse() (Conmon_ContainerStatsResponse, error) {
+	ss, err := NewConmon_ContainerStatsResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ContainerStatsResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_containerStats_Results_List is a list of Conmon_containerStats_Results.
+type Conmon_containerStats_Results_List = capnp.StructList[Conmon_containerStats_Results]
+
+// NewConmon_containerStats_Results creates a new list of Conmon_containerStats_Results.
+func NewConmon_containerStats_Results_List(s *capnp.Segment, sz int32) (Conmon_containerStats_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_containerStats_Results](l), err
+}
+
+// Conmon_containerStats_Results_Future is a wrapper for a Conmon_containerStats_Results promised by a client call.
+type Conmon_containerStats_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_containerStats_Results_Future) Struct() (Conmon_containerStats_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_containerStats_Results(s), err
+}
+
+func (p Conmon_containerStats_Results_Future) Response() Conmon_ContainerStatsResponse_Future {
+	return Conmon_ContainerStatsResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_freezeContainer_Params capnp.Struct
+
+// Conmon_freezeContainer_Params_TypeID is the unique identifier for the type Conmon_freezeContainer_Params.
+const Conmon_freezeContainer_Params_TypeID = 0xe5adba5696f0c278
+
+func NewConmon_freezeContainer_Params(s *capnp.Segment) (Conmon_freezeContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_freezeContainer_Params(st), err
+}
+
+func NewRootConmon_freezeContainer_Params(s *capnp.Segment) (Conmon_freezeContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_freezeContainer_Params(st), err
+}
+
+func ReadRootConmon_freezeContainer_Params(msg *capnp.Message) (Conmon_freezeContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_freezeContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_freezeContainer_Params) String() string {
+	str, _ := text.Marshal(0xe5adba5696f0c278, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_freezeContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_freezeContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_freezeContainer_Params {
+	return Conmon_freezeContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_freezeContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_freezeContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_freezeContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_freezeContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_freezeContainer_Params) Request() (Conmon_FreezeContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_FreezeContainerRequest(p.Struct()), err
+}
+
+func (s Conmon_freezeContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_freezeContainer_Params) SetRequest(v Conmon_FreezeContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_FreezeContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_freezeContainer_Params) NewRequest() (Conmon_FreezeContainerRequest, error) {
+	ss, err := NewConmon_FreezeContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_FreezeContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_freezeContainer_Params_List is a list of Conmon_freezeContainer_Params.
+type Conmon_freezeContainer_Params_List = capnp.StructList[Conmon_freezeContainer_Params]
+
+// NewConmon_freezeContainer_Params creates a new list of Conmon_freezeContainer_Params.
+func NewConmon_freezeContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_freezeContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_freezeContainer_Params](l), err
+}
+
+// Conmon_freezeContainer_Params_Future is a wrapper for a Conmon_freezeContainer_Params promised by a client call.
+type Conmon_freezeContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_freezeContainer_Params_Future) Struct() (Conmon_freezeContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_freezeContainer_Params(s), err
+}
+
+func (p Conmon_freezeContainer_Params_Future) Request() Conmon_FreezeContainerRequest_Future {
+	return Conmon_FreezeContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_freezeContainer_Results capnp.Struct
+
+// Conmon_freezeContainer_Results_TypeID is the unique identifier for the type Conmon_freezeContainer_Results.
+const Conmon_freezeContainer_Results_TypeID = 0x86b1a5ed2ee3fe0a
+
+func NewConmon_freezeContainer_Results(s *capnp.Segment) (Conmon_freezeContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_freezeContainer_Results(st), err
+}
+
+func NewRootConmon_freezeContainer_Results(s *capnp.Segment) (Conmon_freezeContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_freezeContainer_Results(st), err
+}
+
+func ReadRootConmon_freezeContainer_Results(msg *capnp.Message) (Conmon_freezeContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_freezeContainer_Results(root.Struct()), err
+}
+
+func (s Conmon_freezeContainer_Results) String() string {
+	str, _ := text.Marshal(0x86b1a5ed2ee3fe0a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_freezeContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_freezeContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_freezeContainer_Results {
+	return Conmon_freezeContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_freezeContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_freezeContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_freezeContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_freezeContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_freezeContainer_Results) Response() (Conmon_FreezeContainerResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_FreezeContainerResponse(p.Struct()), err
+}
+
+func (s Conmon_freezeContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_freezeContainer_Results) SetResponse(v Conmon_FreezeContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_FreezeContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_freezeContainer_Results) NewResponse() (Conmon_FreezeContainerResponse, error) {
+	ss, err := NewConmon_FreezeContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_FreezeContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_freezeContainer_Results_List is a list of Conmon_freezeContainer_Results.
+type Conmon_freezeContainer_Results_List = capnp.StructList[Conmon_freezeContainer_Results]
+
+// NewConmon_freezeContainer_Results creates a new list of Conmon_freezeContainer_Results.
+func NewConmon_freezeContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_freezeContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_freezeContainer_Results](l), err
+}
+
+// Conmon_freezeContainer_Results_Future is a wrapper for a Conmon_freezeContainer_Results promised by a client call.
+type Conmon_freezeContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_freezeContainer_Results_Future) Struct() (Conmon_freezeContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_freezeContainer_Results(s), err
+}
+
+func (p Conmon_freezeContainer_Results_Future) Response() Conmon_FreezeContainerResponse_Future {
+	return Conmon_FreezeContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_thawContainer_Params capnp.Struct
+
+// Conmon_thawContainer_Params_TypeID is the unique identifier for the type Conmon_thawContainer_Params.
+const Conmon_thawContainer_Params_TypeID = 0xcfb7c5597c044cdb
+
+func NewConmon_thawContainer_Params(s *capnp.Segment) (Conmon_thawContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_thawContainer_Params(st), err
+}
+
+func NewRootConmon_thawContainer_Params(s *capnp.Segment) (Conmon_thawContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_thawContainer_Params(st), err
+}
+
+func ReadRootConmon_thawContainer_Params(msg *capnp.Message) (Conmon_thawContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_thawContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_thawContainer_Params) String() string {
+	str, _ := text.Marshal(0xcfb7c5597c044cdb, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_thawContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_thawContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_thawContainer_Params {
+	return Conmon_thawContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_thawContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_thawContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_thawContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_thawContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_thawContainer_Params) Request() (Conmon_ThawContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ThawContainerRequest(p.Struct()), err
+}
+
+func (s Conmon_thawContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_thawContainer_Params) SetRequest(v Conmon_ThawContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ThawContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_thawContainer_Params) NewRequest() (Conmon_ThawContainerRequest, error) {
+	ss, err := NewConmon_ThawContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ThawContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_thawContainer_Params_List is a list of Conmon_thawContainer_Params.
+type Conmon_thawContainer_Params_List = capnp.StructList[Conmon_thawContainer_Params]
+
+// NewConmon_thawContainer_Params creates a new list of Conmon_thawContainer_Params.
+func NewConmon_thawContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_thawContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_thawContainer_Params](l), err
+}
+
+// Conmon_thawContainer_Params_Future is a wrapper for a Conmon_thawContainer_Params promised by a client call.
+type Conmon_thawContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_thawContainer_Params_Future) Struct() (Conmon_thawContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_thawContainer_Params(s), err
+}
+
+func (p Conmon_thawContainer_Params_Future) Request() Conmon_ThawContainerRequest_Future {
+	return Conmon_ThawContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_thawContainer_Results capnp.Struct
+
+// Conmon_thawContainer_Results_TypeID is the unique identifier for the type Conmon_thawContainer_Results.
+const Conmon_thawContainer_Results_TypeID = 0xb2b0116d3f068d4f
+
+func NewConmon_thawContainer_Results(s *capnp.Segment) (Conmon_thawContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_thawContainer_Results(st), err
+}
+
+func NewRootConmon_thawContainer_Results(s *capnp.Segment) (Conmon_thawContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_thawContainer_Results(st), err
+}
+
+func ReadRootConmon_thawContainer_Results(msg *capnp.Message) (Conmon_thawContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_thawContainer_Results(root.Struct()), err
+}
+
+func (s Conmon_thawContainer_Results) String() string {
+	str, _ := text.Marshal(0xb2b0116d3f068d4f, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_thawContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_thawContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_thawContainer_Results {
+	return Conmon_thawContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_thawContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_thawContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_thawContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_thawContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_thawContainer_Results) Response() (Conmon_ThawContainerResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ThawContainerResponse(p.Struct()), err
+}
+
+func (s Conmon_thawContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_thawContainer_Results) SetResponse(v Conmon_ThawContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ThawContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_thawContainer_Results) NewResponse() (Conmon_ThawContainerResponse, error) {
+	ss, err := NewConmon_ThawContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ThawContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_thawContainer_Results_List is a list of Conmon_thawContainer_Results.
+type Conmon_thawContainer_Results_List = capnp.StructList[Conmon_thawContainer_Results]
+
+// NewConmon_thawContainer_Results creates a new list of Conmon_thawContainer_Results.
+func NewConmon_thawContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_thawContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_thawContainer_Results](l), err
+}
+
+// Conmon_thawContainer_Results_Future is a wrapper for a Conmon_thawContainer_Results promised by a client call.
+type Conmon_thawContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_thawContainer_Results_Future) Struct() (Conmon_thawContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_thawContainer_Results(s), err
+}
+
+func (p Conmon_thawContainer_Results_Future) Response() Conmon_ThawContainerResponse_Future {
+	return Conmon_ThawContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_containerProcesses_Params capnp.Struct
+
+// Conmon_containerProcesses_Params_TypeID is the unique identifier for the type Conmon_containerProcesses_Params.
+const Conmon_containerProcesses_Params_TypeID = 0xaf643dcb7f32e91b
+
+func NewConmon_containerProcesses_Params(s *capnp.Segment) (Conmon_containerProcesses_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerProcesses_Params(st), err
+}
+
+func NewRootConmon_containerProcesses_Params(s *capnp.Segment) (Conmon_containerProcesses_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerProcesses_Params(st), err
+}
+
+func ReadRootConmon_containerProcesses_Params(msg *capnp.Message) (Conmon_containerProcesses_Params, error) {
+	root, err := msg.Root()
+	return Conmon_containerProcesses_Params(root.Struct()), err
+}
+
+func (s Conmon_containerProcesses_Params) String() string {
+	str, _ := text.Marshal(0xaf643dcb7f32e91b, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_containerProcesses_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_containerProcesses_Params) DecodeFromPtr(p capnp.Ptr) Conmon_containerProcesses_Params {
+	return Conmon_containerProcesses_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_containerProcesses_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_containerProcesses_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_containerProcesses_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_containerProcesses_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_containerProcesses_Params) Request() (Conmon_ContainerProcessesRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ContainerProcessesRequest(p.Struct()), err
+}
+
+func (s Conmon_containerProcesses_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_containerProcesses_Params) SetRequest(v Conmon_ContainerProcessesRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_ContainerProcessesRequest struct, preferring placement in s's segment.
+func (s Conmon_containerProcesses_Params) NewRequest() (Conmon_ContainerProcessesRequest, error) {
+	ss, err := NewConmon_ContainerProcessesRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ContainerProcessesRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_containerProcesses_Params_List is a list of Conmon_containerProcesses_Params.
+type Conmon_containerProcesses_Params_List = capnp.StructList[Conmon_containerProcesses_Params]
+
+// NewConmon_containerProcesses_Params creates a new list of Conmon_containerProcesses_Params.
+func NewConmon_containerProcesses_Params_List(s *capnp.Segment, sz int32) (Conmon_containerProcesses_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_containerProcesses_Params](l), err
+}
+
+// Conmon_containerProcesses_Params_Future is a wrapper for a Conmon_containerProcesses_Params promised by a client call.
+type Conmon_containerProcesses_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_containerProcesses_Params_Future) Struct() (Conmon_containerProcesses_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_containerProcesses_Params(s), err
+}
+
+func (p Conmon_containerProcesses_Params_Future) Request() Conmon_ContainerProcessesRequest_Future {
+	return Conmon_ContainerProcessesRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_containerProcesses_Results capnp.Struct
+
+// Conmon_containerProcesses_Results_TypeID is the unique identifier for the type Conmon_containerProcesses_Results.
+const Conmon_containerProcesses_Results_TypeID = 0xefbec970d17dc985
+
+func NewConmon_containerProcesses_Results(s *capnp.Segment) (Conmon_containerProcesses_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerProcesses_Results(st), err
+}
+
+func NewRootConmon_containerProcesses_Results(s *capnp.Segment) (Conmon_containerProcesses_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_containerProcesses_Results(st), err
+}
+
+func ReadRootConmon_containerProcesses_Results(msg *capnp.Message) (Conmon_containerProcesses_Results, error) {
+	root, err := msg.Root()
+	return Conmon_containerProcesses_Results(root.Struct()), err
+}
+
+func (s Conmon_containerProcesses_Results) String() string {
+	str, _ := text.Marshal(0xefbec970d17dc985, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_containerProcesses_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_containerProcesses_Results) DecodeFromPtr(p capnp.Ptr) Conmon_containerProcesses_Results {
+	return Conmon_containerProcesses_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_containerProcesses_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_containerProcesses_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_containerProcesses_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_containerProcesses_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_containerProcesses_Results) Response() (Conmon_ContainerProcessesResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ContainerProcessesResponse(p.Struct()), err
+}
+
+func (s Conmon_containerProcesses_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_containerProcesses_Results) SetResponse(v Conmon_ContainerProcessesResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewResponse sets the response field to a newly
+// allocated Conmon_ContainerProcessesResponse struct, preferring placement in s's segment.
+func (s Conmon_containerProcesses_Results) NewResponse() (Conmon_ContainerProcessesResponse, error) {
+	ss, err := NewConmon_ContainerProcessesResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ContainerProcessesResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_containerProcesses_Results_List is a list of Conmon_containerProcesses_Results.
+type Conmon_containerProcesses_Results_List = capnp.StructList[Conmon_containerProcesses_Results]
+
+// NewConmon_containerProcesses_Results creates a new list of Conmon_containerProcesses_Results.
+func NewConmon_containerProcesses_Results_List(s *capnp.Segment, sz int32) (Conmon_containerProcesses_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_containerProcesses_Results](l), err
+}
+
+// Conmon_containerProcesses_Results_Future is a wrapper for a Conmon_containerProcesses_Results promised by a client call.
+type Conmon_containerProcesses_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_containerProcesses_Results_Future) Struct() (Conmon_containerProcesses_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_containerProcesses_Results(s), err
+}
+
+func (p Conmon_containerProcesses_Results_Future) Response() Conmon_ContainerProcessesResponse_Future {
+	return Conmon_ContainerProcessesResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_requestLatencies_Params capnp.Struct
+
+// Conmon_requestLatencies_Params_TypeID is the unique identifier for the type Conmon_requestLatencies_Params.
+const Conmon_requestLatencies_Params_TypeID = 0xc6e1e7b26fef688a
+
+func NewConmon_requestLatencies_Params(s *capnp.Segment) (Conmon_requestLatencies_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_requestLatencies_Params(st), err
+}
+
+func NewRootConmon_requestLatencies_Params(s *capnp.Segment) (Conmon_requestLatencies_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_requestLatencies_Params(st), err
+}
+
+func ReadRootConmon_requestLatencies_Params(msg *capnp.Message) (Conmon_requestLatencies_Params, error) {
+	root, err := msg.Root()
+	return Conmon_requestLatencies_Params(root.Struct()), err
+}
+
+func (s Conmon_requestLatencies_Params) String() string {
+	str, _ := text.Marshal(0xc6e1e7b26fef688a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_requestLatencies_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_requestLatencies_Params) DecodeFromPtr(p capnp.Ptr) Conmon_requestLatencies_Params {
+	return Conmon_requestLatencies_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_requestLatencies_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_requestLatencies_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_requestLatencies_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_requestLatencies_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_requestLatencies_Params) Request() (Conmon_RequestLatenciesRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_RequestLatenciesRequest(p.Struct()), err
+}
+
+func (s Conmon_requestLatencies_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_requestLatencies_Params) SetRequest(v Conmon_RequestLatenciesRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_RequestLatenciesRequest struct, preferring placement in s's segment.
+func (s Conmon_requestLatencies_Params) NewRequest() (Conmon_RequestLatenciesRequest, error) {
+	ss, err := NewConmon_RequestLatenciesRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_RequestLatenciesRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_requestLatencies_Params_List is a list of Conmon_requestLatencies_Params.
+type Conmon_requestLatencies_Params_List = capnp.StructList[Conmon_requestLatencies_Params]
+
+// NewConmon_requestLatencies_Params creates a new list of Conmon_requestLatencies_Params.
+func NewConmon_requestLatencies_Params_List(s *capnp.Segment, sz int32) (Conmon_requestLatencies_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_requestLatencies_Params](l), err
+}
+
+// Conmon_requestLatencies_Params_Future is a wrapper for a Conmon_requestLatencies_Params promised by a client call.
+type Conmon_requestLatencies_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_requestLatencies_Params_Future) Struct() (Conmon_requestLatencies_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_requestLatencies_Params(s), err
+}
+
+func (p Conmon_requestLatencies_Params_Future) Request() Conmon_RequestLatenciesRequest_Future {
+	return Conmon_RequestLatenciesRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_requestLatencies_Results capnp.Struct
+
+// Conmon_requestLatencies_Results_TypeID is the unique identifier for the type Conmon_requestLatencies_Results.
+const Conmon_requestLatencies_Results_TypeID = 0xe024baaf8cbb64fb
+
+func NewConmon_requestLatencies_Results(s *capnp.Segment) (Conmon_requestLatencies_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_requestLatencies_Results(st), err
+}
+
+func NewRootConmon_requestLatencies_Results(s *capnp.Segment) (Conmon_requestLatencies_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_requestLatencies_Results(st), err
+}
+
+func ReadRootConmon_requestLatencies_Results(msg *capnp.Message) (Conmon_requestLatencies_Results, error) {
+	root, err := msg.Root()
+	return Conmon_requestLatencies_Results(root.Struct()), err
+}
+
+func (s Conmon_requestLatencies_Results) String() string {
+	str, _ := text.Marshal(0xe024baaf8cbb64fb, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_requestLatencies_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_requestLatencies_Results) DecodeFromPtr(p capnp.Ptr) Conmon_requestLatencies_Results {
+	return Conmon_requestLatencies_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_requestLatencies_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_requestLatencies_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
 
-	methods = append(methods, server.Method{
-		Method: capnp.Method{
-			InterfaceID:   0xb737e899dd6633f1,
-			MethodID:      5,
-			InterfaceName: "conmon-rs/common/proto/conmon.capnp:Conmon",
-			MethodName:    "setWindowSizeContainer",
-		},
-		Impl: func(ctx context.Context, call *server.Call) error {
-			return s.SetWindowSizeContainer(ctx, Conmon_setWindowSizeContainer{call})
-		},
-	})
+func (s Conmon_requestLatencies_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
 
-	return methods
+func (s Conmon_requestLatencies_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_requestLatencies_Results) Response() (Conmon_RequestLatenciesResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_RequestLatenciesResponse(p.Struct()), err
 }
 
-// Conmon_version holds the state for a server call to Conmon.version.
-// See server.Call for documentation.
-type Conmon_version struct {
-	*server.Call
+func (s Conmon_requestLatencies_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
 }
 
-// Args returns the call's arguments.
-func (c Conmon_version) Args() Conmon_version_Params {
-	return Conmon_version_Params(c.Call.Args())
+func (s Conmon_requestLatencies_Results) SetResponse(v Conmon_RequestLatenciesResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// AllocResults allocates the results struct.
-func (c Conmon_version) AllocResults() (Conmon_version_Results, error) {
-	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_version_Results(r), err
+// NewResponse sets the response field to a newly
+// allocated Conmon_RequestLatenciesResponse struct, preferring placement in s's segment.
+func (s Conmon_requestLatencies_Results) NewResponse() (Conmon_RequestLatenciesResponse, error) {
+	ss, err := NewConmon_RequestLatenciesResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_RequestLatenciesResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_createContainer holds the state for a server call to Conmon.createContainer.
-// See server.Call for documentation.
-type Conmon_createContainer struct {
-	*server.Call
+// Conmon_requestLatencies_Results_List is a list of Conmon_requestLatencies_Results.
+type Conmon_requestLatencies_Results_List = capnp.StructList[Conmon_requestLatencies_Results]
+
+// NewConmon_requestLatencies_Results creates a new list of Conmon_requestLatencies_Results.
+func NewConmon_requestLatencies_Results_List(s *capnp.Segment, sz int32) (Conmon_requestLatencies_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_requestLatencies_Results](l), err
 }
 
-// Args returns the call's arguments.
-func (c Conmon_createContainer) Args() Conmon_createContainer_Params {
-	return Conmon_createContainer_Params(c.Call.Args())
+// Conmon_requestLatencies_Results_Future is a wrapper for a Conmon_requestLatencies_Results promised by a client call.
+type Conmon_requestLatencies_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_requestLatencies_Results_Future) Struct() (Conmon_requestLatencies_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_requestLatencies_Results(s), err
 }
 
-// AllocResults allocates the results struct.
-func (c Conmon_createContainer) AllocResults() (Conmon_createContainer_Results, error) {
-	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Results(r), err
+func (p Conmon_requestLatencies_Results_Future) Response() Conmon_RequestLatenciesResponse_Future {
+	return Conmon_RequestLatenciesResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-// Conmon_execSyncContainer holds the state for a server call to Conmon.execSyncContainer.
-// See server.Call for documentation.
-type Conmon_execSyncContainer struct {
-	*server.Call
+type Conmon_killContainer_Params capnp.Struct
+
+// Conmon_killContainer_Params_TypeID is the unique identifier for the type Conmon_killContainer_Params.
+const Conmon_killContainer_Params_TypeID = 0xca27841148b7050e
+
+func NewConmon_killContainer_Params(s *capnp.Segment) (Conmon_killContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_killContainer_Params(st), err
 }
 
-// Args returns the call's arguments.
-func (c Conmon_execSyncContainer) Args() Conmon_execSyncContainer_Params {
-	return Conmon_execSyncContainer_Params(c.Call.Args())
+func NewRootConmon_killContainer_Params(s *capnp.Segment) (Conmon_killContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_killContainer_Params(st), err
 }
 
-// AllocResults allocates the results struct.
-func (c Conmon_execSyncContainer) AllocResults() (Conmon_execSyncContainer_Results, error) {
-	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Results(r), err
+func ReadRootConmon_killContainer_Params(msg *capnp.Message) (Conmon_killContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_killContainer_Params(root.Struct()), err
 }
 
-// Conmon_attachContainer holds the state for a server call to Conmon.attachContainer.
-// See server.Call for documentation.
-type Conmon_attachContainer struct {
-	*server.Call
+func (s Conmon_killContainer_Params) String() string {
+	str, _ := text.Marshal(0xca27841148b7050e, capnp.Struct(s))
+	return str
 }
 
-// Args returns the call's arguments.
-func (c Conmon_attachContainer) Args() Conmon_attachContainer_Params {
-	return Conmon_attachContainer_Params(c.Call.Args())
+func (s Conmon_killContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-// AllocResults allocates the results struct.
-func (c Conmon_attachContainer) AllocResults() (Conmon_attachContainer_Results, error) {
-	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Results(r), err
+func (Conmon_killContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_killContainer_Params {
+	return Conmon_killContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-// Conmon_reopenLogContainer holds the state for a server call to Conmon.reopenLogContainer.
-// See server.Call for documentation.
-type Conmon_reopenLogContainer struct {
-	*server.Call
+func (s Conmon_killContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_killContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
 }
 
-// Args returns the call's arguments.
-func (c Conmon_reopenLogContainer) Args() Conmon_reopenLogContainer_Params {
-	return Conmon_reopenLogContainer_Params(c.Call.Args())
+func (s Conmon_killContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
 }
 
-// AllocResults allocates the results struct.
-func (c Conmon_reopenLogContainer) AllocResults() (Conmon_reopenLogContainer_Results, error) {
-	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Results(r), err
+func (s Conmon_killContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_killContainer_Params) Request() (Conmon_KillContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_KillContainerRequest(p.Struct()), err
 }
 
-// Conmon_setWindowSizeContainer holds the state for a server call to Conmon.setWindowSizeContainer.
-// See server.Call for documentation.
-type Conmon_setWindowSizeContainer struct {
-	*server.Call
+func (s Conmon_killContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
 }
 
-// Args returns the call's arguments.
-func (c Conmon_setWindowSizeContainer) Args() Conmon_setWindowSizeContainer_Params {
-	return Conmon_setWindowSizeContainer_Params(c.Call.Args())
+func (s Conmon_killContainer_Params) SetRequest(v Conmon_KillContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// AllocResults allocates the results struct.
-func (c Conmon_setWindowSizeContainer) AllocResults() (Conmon_setWindowSizeContainer_Results, error) {
-	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Results(r), err
+// NewRequest sets the request field to a newly
+// allocated Conmon_KillContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_killContainer_Params) NewRequest() (Conmon_KillContainerRequest, error) {
+	ss, err := NewConmon_KillContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_KillContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_List is a list of Conmon.
-type Conmon_List = capnp.CapList[Conmon]
+// Conmon_killContainer_Params_List is a list of Conmon_killContainer_Params.
+type Conmon_killContainer_Params_List = capnp.StructList[Conmon_killContainer_Params]
 
-// NewConmon creates a new list of Conmon.
-func NewConmon_List(s *capnp.Segment, sz int32) (Conmon_List, error) {
-	l, err := capnp.NewPointerList(s, sz)
-	return capnp.CapList[Conmon](l), err
+// NewConmon_killContainer_Params creates a new list of Conmon_killContainer_Params.
+func NewConmon_killContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_killContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_killContainer_Params](l), err
 }
 
-type Conmon_VersionResponse capnp.Struct
+// Conmon_killContainer_Params_Future is a wrapper for a Conmon_killContainer_Params promised by a client call.
+type Conmon_killContainer_Params_Future struct{ *capnp.Future }
 
-// Conmon_VersionResponse_TypeID is the unique identifier for the type Conmon_VersionResponse.
-const Conmon_VersionResponse_TypeID = 0xf34be5cbac1feed1
+func (p Conmon_killContainer_Params_Future) Struct() (Conmon_killContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_killContainer_Params(s), err
+}
 
-func NewConmon_VersionResponse(s *capnp.Segment) (Conmon_VersionResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
-	return Conmon_VersionResponse(st), err
+func (p Conmon_killContainer_Params_Future) Request() Conmon_KillContainerRequest_Future {
+	return Conmon_KillContainerRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-func NewRootConmon_VersionResponse(s *capnp.Segment) (Conmon_VersionResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
-	return Conmon_VersionResponse(st), err
+type Conmon_killContainer_Results capnp.Struct
+
+// Conmon_killContainer_Results_TypeID is the unique identifier for the type Conmon_killContainer_Results.
+const Conmon_killContainer_Results_TypeID = 0xf5024761975c861f
+
+func NewConmon_killContainer_Results(s *capnp.Segment) (Conmon_killContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_killContainer_Results(st), err
 }
 
-func ReadRootConmon_VersionResponse(msg *capnp.Message) (Conmon_VersionResponse, error) {
+func NewRootConmon_killContainer_Results(s *capnp.Segment) (Conmon_killContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_killContainer_Results(st), err
+}
+
+func ReadRootConmon_killContainer_Results(msg *capnp.Message) (Conmon_killContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_VersionResponse(root.Struct()), err
+	return Conmon_killContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_VersionResponse) String() string {
-	str, _ := text.Marshal(0xf34be5cbac1feed1, capnp.Struct(s))
+func (s Conmon_killContainer_Results) String() string {
+	str, _ := text.Marshal(0xf5024761975c861f, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_VersionResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_killContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_VersionResponse) DecodeFromPtr(p capnp.Ptr) Conmon_VersionResponse {
-	return Conmon_VersionResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_killContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_killContainer_Results {
+	return Conmon_killContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_VersionResponse) ToPtr() capnp.Ptr {
+func (s Conmon_killContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_VersionResponse) IsValid() bool {
+func (s Conmon_killContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_VersionResponse) Message() *capnp.Message {
+func (s Conmon_killContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_VersionResponse) Segment() *capnp.Segment {
+func (s Conmon_killContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_VersionResponse) Version() (string, error) {
+func (s Conmon_killContainer_Results) Response() (Conmon_KillContainerResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_KillContainerResponse(p.Struct()), err
 }
 
-func (s Conmon_VersionResponse) HasVersion() bool {
+func (s Conmon_killContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_VersionResponse) VersionBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
+func (s Conmon_killContainer_Results) SetResponse(v Conmon_KillContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_VersionResponse) SetVersion(v string) error {
-	return capnp.Struct(s).SetText(0, v)
+// NewResponse sets the response field to a newly
+// allocated Conmon_KillContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_killContainer_Results) NewResponse() (Conmon_KillContainerResponse, error) {
+	ss, err := NewConmon_KillContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_KillContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-func (s Conmon_VersionResponse) Tag() (string, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return p.Text(), err
-}
+// Conmon_killContainer_Results_List is a list of Conmon_killContainer_Results.
+type Conmon_killContainer_Results_List = capnp.StructList[Conmon_killContainer_Results]
 
-func (s Conmon_VersionResponse) HasTag() bool {
-	return capnp.Struct(s).HasPtr(1)
+// NewConmon_killContainer_Results creates a new list of Conmon_killContainer_Results.
+func NewConmon_killContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_killContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_killContainer_Results](l), err
 }
 
-func (s Conmon_VersionResponse) TagBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return p.TextBytes(), err
-}
+// Conmon_killContainer_Results_Future is a wrapper for a Conmon_killContainer_Results promised by a client call.
+type Conmon_killContainer_Results_Future struct{ *capnp.Future }
 
-func (s Conmon_VersionResponse) SetTag(v string) error {
-	return capnp.Struct(s).SetText(1, v)
+func (p Conmon_killContainer_Results_Future) Struct() (Conmon_killContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_killContainer_Results(s), err
 }
 
-func (s Conmon_VersionResponse) Commit() (string, error) {
-	p, err := capnp.Struct(s).Ptr(2)
-	return p.Text(), err
+func (p Conmon_killContainer_Results_Future) Response() Conmon_KillContainerResponse_Future {
+	return Conmon_KillContainerResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-func (s Conmon_VersionResponse) HasCommit() bool {
-	return capnp.Struct(s).HasPtr(2)
-}
+type Conmon_startContainer_Params capnp.Struct
 
-func (s Conmon_VersionResponse) CommitBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(2)
-	return p.TextBytes(), err
+// Conmon_startContainer_Params_TypeID is the unique identifier for the type Conmon_startContainer_Params.
+const Conmon_startContainer_Params_TypeID = 0xeb6d1af8db5d3520
+
+func NewConmon_startContainer_Params(s *capnp.Segment) (Conmon_startContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_startContainer_Params(st), err
 }
 
-func (s Conmon_VersionResponse) SetCommit(v string) error {
-	return capnp.Struct(s).SetText(2, v)
+func NewRootConmon_startContainer_Params(s *capnp.Segment) (Conmon_startContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_startContainer_Params(st), err
 }
 
-func (s Conmon_VersionResponse) BuildDate() (string, error) {
-	p, err := capnp.Struct(s).Ptr(3)
-	return p.Text(), err
+func ReadRootConmon_startContainer_Params(msg *capnp.Message) (Conmon_startContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_startContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_VersionResponse) HasBuildDate() bool {
-	return capnp.Struct(s).HasPtr(3)
+func (s Conmon_startContainer_Params) String() string {
+	str, _ := text.Marshal(0xeb6d1af8db5d3520, capnp.Struct(s))
+	return str
 }
 
-func (s Conmon_VersionResponse) BuildDateBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(3)
-	return p.TextBytes(), err
+func (s Conmon_startContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (s Conmon_VersionResponse) SetBuildDate(v string) error {
-	return capnp.Struct(s).SetText(3, v)
+func (Conmon_startContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_startContainer_Params {
+	return Conmon_startContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_VersionResponse) RustVersion() (string, error) {
-	p, err := capnp.Struct(s).Ptr(4)
-	return p.Text(), err
+func (s Conmon_startContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_startContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_VersionResponse) HasRustVersion() bool {
-	return capnp.Struct(s).HasPtr(4)
+func (s Conmon_startContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_VersionResponse) RustVersionBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(4)
-	return p.TextBytes(), err
+func (s Conmon_startContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_startContainer_Params) Request() (Conmon_StartContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_StartContainerRequest(p.Struct()), err
 }
 
-func (s Conmon_VersionResponse) SetRustVersion(v string) error {
-	return capnp.Struct(s).SetText(4, v)
+func (s Conmon_startContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_VersionResponse) ProcessId() uint32 {
-	return capnp.Struct(s).Uint32(0)
+func (s Conmon_startContainer_Params) SetRequest(v Conmon_StartContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_VersionResponse) SetProcessId(v uint32) {
-	capnp.Struct(s).SetUint32(0, v)
+// NewRequest sets the request field to a newly
+// allocated Conmon_StartContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_startContainer_Params) NewRequest() (Conmon_StartContainerRequest, error) {
+	ss, err := NewConmon_StartContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_StartContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_VersionResponse_List is a list of Conmon_VersionResponse.
-type Conmon_VersionResponse_List = capnp.StructList[Conmon_VersionResponse]
+// Conmon_startContainer_Params_List is a list of Conmon_startContainer_Params.
+type Conmon_startContainer_Params_List = capnp.StructList[Conmon_startContainer_Params]
 
-// NewConmon_VersionResponse creates a new list of Conmon_VersionResponse.
-func NewConmon_VersionResponse_List(s *capnp.Segment, sz int32) (Conmon_VersionResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5}, sz)
-	return capnp.StructList[Conmon_VersionResponse](l), err
+// NewConmon_startContainer_Params creates a new list of Conmon_startContainer_Params.
+func NewConmon_startContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_startContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_startContainer_Params](l), err
 }
 
-// Conmon_VersionResponse_Future is a wrapper for a Conmon_VersionResponse promised by a client call.
-type Conmon_VersionResponse_Future struct{ *capnp.Future }
+// Conmon_startContainer_Params_Future is a wrapper for a Conmon_startContainer_Params promised by a client call.
+type Conmon_startContainer_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_VersionResponse_Future) Struct() (Conmon_VersionResponse, error) {
+func (p Conmon_startContainer_Params_Future) Struct() (Conmon_startContainer_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_VersionResponse(s), err
+	return Conmon_startContainer_Params(s), err
 }
 
-type Conmon_CreateContainerRequest capnp.Struct
+func (p Conmon_startContainer_Params_Future) Request() Conmon_StartContainerRequest_Future {
+	return Conmon_StartContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_CreateContainerRequest_TypeID is the unique identifier for the type Conmon_CreateContainerRequest.
-const Conmon_CreateContainerRequest_TypeID = 0xba77e3fa3aa9b6ca
+type Conmon_startContainer_Results capnp.Struct
 
-func NewConmon_CreateContainerRequest(s *capnp.Segment) (Conmon_CreateContainerRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 6})
-	return Conmon_CreateContainerRequest(st), err
+// Conmon_startContainer_Results_TypeID is the unique identifier for the type Conmon_startContainer_Results.
+const Conmon_startContainer_Results_TypeID = 0x844530cf92fcc3c6
+
+func NewConmon_startContainer_Results(s *capnp.Segment) (Conmon_startContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_startContainer_Results(st), err
 }
 
-func NewRootConmon_CreateContainerRequest(s *capnp.Segment) (Conmon_CreateContainerRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 6})
-	return Conmon_CreateContainerRequest(st), err
+func NewRootConmon_startContainer_Results(s *capnp.Segment) (Conmon_startContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_startContainer_Results(st), err
 }
 
-func ReadRootConmon_CreateContainerRequest(msg *capnp.Message) (Conmon_CreateContainerRequest, error) {
+func ReadRootConmon_startContainer_Results(msg *capnp.Message) (Conmon_startContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_CreateContainerRequest(root.Struct()), err
+	return Conmon_startContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_CreateContainerRequest) String() string {
-	str, _ := text.Marshal(0xba77e3fa3aa9b6ca, capnp.Struct(s))
+func (s Conmon_startContainer_Results) String() string {
+	str, _ := text.Marshal(0x844530cf92fcc3c6, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_CreateContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_startContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_CreateContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_CreateContainerRequest {
-	return Conmon_CreateContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_startContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_startContainer_Results {
+	return Conmon_startContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_CreateContainerRequest) ToPtr() capnp.Ptr {
+func (s Conmon_startContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_CreateContainerRequest) IsValid() bool {
+func (s Conmon_startContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_CreateContainerRequest) Message() *capnp.Message {
+func (s Conmon_startContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_CreateContainerRequest) Segment() *capnp.Segment {
+func (s Conmon_startContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_CreateContainerRequest) Id() (string, error) {
+func (s Conmon_startContainer_Results) Response() (Conmon_StartContainerResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_StartContainerResponse(p.Struct()), err
 }
 
-func (s Conmon_CreateContainerRequest) HasId() bool {
+func (s Conmon_startContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_CreateContainerRequest) IdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
-}
-
-func (s Conmon_CreateContainerRequest) SetId(v string) error {
-	return capnp.Struct(s).SetText(0, v)
+func (s Conmon_startContainer_Results) SetResponse(v Conmon_StartContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_CreateContainerRequest) BundlePath() (string, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return p.Text(), err
+// NewResponse sets the response field to a newly
+// allocated Conmon_StartContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_startContainer_Results) NewResponse() (Conmon_StartContainerResponse, error) {
+	ss, err := NewConmon_StartContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_StartContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-func (s Conmon_CreateContainerRequest) HasBundlePath() bool {
-	return capnp.Struct(s).HasPtr(1)
-}
+// Conmon_startContainer_Results_List is a list of Conmon_startContainer_Results.
+type Conmon_startContainer_Results_List = capnp.StructList[Conmon_startContainer_Results]
 
-func (s Conmon_CreateContainerRequest) BundlePathBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return p.TextBytes(), err
+// NewConmon_startContainer_Results creates a new list of Conmon_startContainer_Results.
+func NewConmon_startContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_startContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_startContainer_Results](l), err
 }
 
-func (s Conmon_CreateContainerRequest) SetBundlePath(v string) error {
-	return capnp.Struct(s).SetText(1, v)
-}
+// Conmon_startContainer_Results_Future is a wrapper for a Conmon_startContainer_Results promised by a client call.
+type Conmon_startContainer_Results_Future struct{ *capnp.Future }
 
-func (s Conmon_CreateContainerRequest) Terminal() bool {
-	return capnp.Struct(s).Bit(0)
+func (p Conmon_startContainer_Results_Future) Struct() (Conmon_startContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_startContainer_Results(s), err
 }
 
-func (s Conmon_CreateContainerRequest) SetTerminal(v bool) {
-	capnp.Struct(s).SetBit(0, v)
+func (p Conmon_startContainer_Results_Future) Response() Conmon_StartContainerResponse_Future {
+	return Conmon_StartContainerResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-func (s Conmon_CreateContainerRequest) ExitPaths() (capnp.TextList, error) {
-	p, err := capnp.Struct(s).Ptr(2)
-	return capnp.TextList(p.List()), err
-}
+type Conmon_listContainers_Params capnp.Struct
 
-func (s Conmon_CreateContainerRequest) HasExitPaths() bool {
-	return capnp.Struct(s).HasPtr(2)
-}
+// Conmon_listContainers_Params_TypeID is the unique identifier for the type Conmon_listContainers_Params.
+const Conmon_listContainers_Params_TypeID = 0xa788b2549075c742
 
-func (s Conmon_CreateContainerRequest) SetExitPaths(v capnp.TextList) error {
-	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+func NewConmon_listContainers_Params(s *capnp.Segment) (Conmon_listContainers_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainers_Params(st), err
 }
 
-// NewExitPaths sets the exitPaths field to a newly
-// allocated capnp.TextList, preferring placement in s's segment.
-func (s Conmon_CreateContainerRequest) NewExitPaths(n int32) (capnp.TextList, error) {
-	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return capnp.TextList{}, err
-	}
-	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
-	return l, err
+func NewRootConmon_listContainers_Params(s *capnp.Segment) (Conmon_listContainers_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainers_Params(st), err
 }
 
-func (s Conmon_CreateContainerRequest) OomExitPaths() (capnp.TextList, error) {
-	p, err := capnp.Struct(s).Ptr(3)
-	return capnp.TextList(p.List()), err
+func ReadRootConmon_listContainers_Params(msg *capnp.Message) (Conmon_listContainers_Params, error) {
+	root, err := msg.Root()
+	return Conmon_listContainers_Params(root.Struct()), err
 }
 
-func (s Conmon_CreateContainerRequest) HasOomExitPaths() bool {
-	return capnp.Struct(s).HasPtr(3)
+func (s Conmon_listContainers_Params) String() string {
+	str, _ := text.Marshal(0xa788b2549075c742, capnp.Struct(s))
+	return str
 }
 
-func (s Conmon_CreateContainerRequest) SetOomExitPaths(v capnp.TextList) error {
-	return capnp.Struct(s).SetPtr(3, v.ToPtr())
+func (s Conmon_listContainers_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-// NewOomExitPaths sets the oomExitPaths field to a newly
-// allocated capnp.TextList, preferring placement in s's segment.
-func (s Conmon_CreateContainerRequest) NewOomExitPaths(n int32) (capnp.TextList, error) {
-	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return capnp.TextList{}, err
-	}
-	err = capnp.Struct(s).SetPtr(3, l.ToPtr())
-	return l, err
+func (Conmon_listContainers_Params) DecodeFromPtr(p capnp.Ptr) Conmon_listContainers_Params {
+	return Conmon_listContainers_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_CreateContainerRequest) LogDrivers() (Conmon_LogDriver_List, error) {
-	p, err := capnp.Struct(s).Ptr(4)
-	return Conmon_LogDriver_List(p.List()), err
+func (s Conmon_listContainers_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
 }
-
-func (s Conmon_CreateContainerRequest) HasLogDrivers() bool {
-	return capnp.Struct(s).HasPtr(4)
+func (s Conmon_listContainers_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_CreateContainerRequest) SetLogDrivers(v Conmon_LogDriver_List) error {
-	return capnp.Struct(s).SetPtr(4, v.ToPtr())
+func (s Conmon_listContainers_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
 }
 
-// NewLogDrivers sets the logDrivers field to a newly
-// allocated Conmon_LogDriver_List, preferring placement in s's segment.
-func (s Conmon_CreateContainerRequest) NewLogDrivers(n int32) (Conmon_LogDriver_List, error) {
-	l, err := NewConmon_LogDriver_List(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return Conmon_LogDriver_List{}, err
-	}
-	err = capnp.Struct(s).SetPtr(4, l.ToPtr())
-	return l, err
+func (s Conmon_listContainers_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
 }
-
-func (s Conmon_CreateContainerRequest) CleanupCmd() (capnp.TextList, error) {
-	p, err := capnp.Struct(s).Ptr(5)
-	return capnp.TextList(p.List()), err
+func (s Conmon_listContainers_Params) Request() (Conmon_ListContainersRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ListContainersRequest(p.Struct()), err
 }
 
-func (s Conmon_CreateContainerRequest) HasCleanupCmd() bool {
-	return capnp.Struct(s).HasPtr(5)
+func (s Conmon_listContainers_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_CreateContainerRequest) SetCleanupCmd(v capnp.TextList) error {
-	return capnp.Struct(s).SetPtr(5, v.ToPtr())
+func (s Conmon_listContainers_Params) SetRequest(v Conmon_ListContainersRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// NewCleanupCmd sets the cleanupCmd field to a newly
-// allocated capnp.TextList, preferring placement in s's segment.
-func (s Conmon_CreateContainerRequest) NewCleanupCmd(n int32) (capnp.TextList, error) {
-	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+// NewRequest sets the request field to a newly
+// allocated Conmon_ListContainersRequest struct, preferring placement in s's segment.
+func (s Conmon_listContainers_Params) NewRequest() (Conmon_ListContainersRequest, error) {
+	ss, err := NewConmon_ListContainersRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return capnp.TextList{}, err
+		return Conmon_ListContainersRequest{}, err
 	}
-	err = capnp.Struct(s).SetPtr(5, l.ToPtr())
-	return l, err
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_CreateContainerRequest_List is a list of Conmon_CreateContainerRequest.
-type Conmon_CreateContainerRequest_List = capnp.StructList[Conmon_CreateContainerRequest]
+// Conmon_listContainers_Params_List is a list of Conmon_listContainers_Params.
+type Conmon_listContainers_Params_List = capnp.StructList[Conmon_listContainers_Params]
 
-// NewConmon_CreateContainerRequest creates a new list of Conmon_CreateContainerRequest.
-func NewConmon_CreateContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_CreateContainerRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 6}, sz)
-	return capnp.StructList[Conmon_CreateContainerRequest](l), err
+// NewConmon_listContainers_Params creates a new list of Conmon_listContainers_Params.
+func NewConmon_listContainers_Params_List(s *capnp.Segment, sz int32) (Conmon_listContainers_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_listContainers_Params](l), err
 }
 
-// Conmon_CreateContainerRequest_Future is a wrapper for a Conmon_CreateContainerRequest promised by a client call.
-type Conmon_CreateContainerRequest_Future struct{ *capnp.Future }
+// Conmon_listContainers_Params_Future is a wrapper for a Conmon_listContainers_Params promised by a client call.
+type Conmon_listContainers_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_CreateContainerRequest_Future) Struct() (Conmon_CreateContainerRequest, error) {
+func (p Conmon_listContainers_Params_Future) Struct() (Conmon_listContainers_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_CreateContainerRequest(s), err
+	return Conmon_listContainers_Params(s), err
 }
 
-type Conmon_LogDriver capnp.Struct
+func (p Conmon_listContainers_Params_Future) Request() Conmon_ListContainersRequest_Future {
+	return Conmon_ListContainersRequest_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_LogDriver_TypeID is the unique identifier for the type Conmon_LogDriver.
-const Conmon_LogDriver_TypeID = 0xae78ee8eb6b3a134
+type Conmon_listContainers_Results capnp.Struct
 
-func NewConmon_LogDriver(s *capnp.Segment) (Conmon_LogDriver, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
-	return Conmon_LogDriver(st), err
+// Conmon_listContainers_Results_TypeID is the unique identifier for the type Conmon_listContainers_Results.
+const Conmon_listContainers_Results_TypeID = 0xb727729a699a1475
+
+func NewConmon_listContainers_Results(s *capnp.Segment) (Conmon_listContainers_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainers_Results(st), err
 }
 
-func NewRootConmon_LogDriver(s *capnp.Segment) (Conmon_LogDriver, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
-	return Conmon_LogDriver(st), err
+func NewRootConmon_listContainers_Results(s *capnp.Segment) (Conmon_listContainers_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_listContainers_Results(st), err
 }
 
-func ReadRootConmon_LogDriver(msg *capnp.Message) (Conmon_LogDriver, error) {
+func ReadRootConmon_listContainers_Results(msg *capnp.Message) (Conmon_listContainers_Results, error) {
 	root, err := msg.Root()
-	return Conmon_LogDriver(root.Struct()), err
+	return Conmon_listContainers_Results(root.Struct()), err
 }
 
-func (s Conmon_LogDriver) String() string {
-	str, _ := text.Marshal(0xae78ee8eb6b3a134, capnp.Struct(s))
+func (s Conmon_listContainers_Results) String() string {
+	str, _ := text.Marshal(0xb727729a699a1475, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_LogDriver) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_listContainers_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_LogDriver) DecodeFromPtr(p capnp.Ptr) Conmon_LogDriver {
-	return Conmon_LogDriver(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_listContainers_Results) DecodeFromPtr(p capnp.Ptr) Conmon_listContainers_Results {
+	return Conmon_listContainers_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_LogDriver) ToPtr() capnp.Ptr {
+func (s Conmon_listContainers_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_LogDriver) IsValid() bool {
+func (s Conmon_listContainers_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_LogDriver) Message() *capnp.Message {
+func (s Conmon_listContainers_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_LogDriver) Segment() *capnp.Segment {
+func (s Conmon_listContainers_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_LogDriver) Type() Conmon_LogDriver_Type {
-	return Conmon_LogDriver_Type(capnp.Struct(s).Uint16(0))
-}
-
-func (s Conmon_LogDriver) SetType(v Conmon_LogDriver_Type) {
-	capnp.Struct(s).SetUint16(0, uint16(v))
-}
-
-func (s Conmon_LogDriver) Path() (string, error) {
+func (s Conmon_listContainers_Results) Response() (Conmon_ListContainersResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_ListContainersResponse(p.Struct()), err
 }
 
-func (s Conmon_LogDriver) HasPath() bool {
+func (s Conmon_listContainers_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_LogDriver) PathBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
-}
-
-func (s Conmon_LogDriver) SetPath(v string) error {
-	return capnp.Struct(s).SetText(0, v)
-}
-
-func (s Conmon_LogDriver) MaxSize() uint64 {
-	return capnp.Struct(s).Uint64(8)
+func (s Conmon_listContainers_Results) SetResponse(v Conmon_ListContainersResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_LogDriver) SetMaxSize(v uint64) {
-	capnp.Struct(s).SetUint64(8, v)
+// NewResponse sets the response field to a newly
+// allocated Conmon_ListContainersResponse struct, preferring placement in s's segment.
+func (s Conmon_listContainers_Results) NewResponse() (Conmon_ListContainersResponse, error) {
+	ss, err := NewConmon_ListContainersResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ListContainersResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_LogDriver_List is a list of Conmon_LogDriver.
-type Conmon_LogDriver_List = capnp.StructList[Conmon_LogDriver]
+// Conmon_listContainers_Results_List is a list of Conmon_listContainers_Results.
+type Conmon_listContainers_Results_List = capnp.StructList[Conmon_listContainers_Results]
 
-// NewConmon_LogDriver creates a new list of Conmon_LogDriver.
-func NewConmon_LogDriver_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_LogDriver](l), err
+// NewConmon_listContainers_Results creates a new list of Conmon_listContainers_Results.
+func NewConmon_listContainers_Results_List(s *capnp.Segment, sz int32) (Conmon_listContainers_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_listContainers_Results](l), err
 }
 
-// Conmon_LogDriver_Future is a wrapper for a Conmon_LogDriver promised by a client call.
-type Conmon_LogDriver_Future struct{ *capnp.Future }
+// Conmon_listContainers_Results_Future is a wrapper for a Conmon_listContainers_Results promised by a client call.
+type Conmon_listContainers_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_LogDriver_Future) Struct() (Conmon_LogDriver, error) {
+func (p Conmon_listContainers_Results_Future) Struct() (Conmon_listContainers_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_LogDriver(s), err
-}
-
-type Conmon_LogDriver_Type uint16
-
-// Conmon_LogDriver_Type_TypeID is the unique identifier for the type Conmon_LogDriver_Type.
-const Conmon_LogDriver_Type_TypeID = 0xf026e3d750335bc1
-
-// Values of Conmon_LogDriver_Type.
-const (
-	Conmon_LogDriver_Type_containerRuntimeInterface Conmon_LogDriver_Type = 0
-)
-
-// String returns the enum's constant name.
-func (c Conmon_LogDriver_Type) String() string {
-	switch c {
-	case Conmon_LogDriver_Type_containerRuntimeInterface:
-		return "containerRuntimeInterface"
-
-	default:
-		return ""
-	}
-}
-
-// Conmon_LogDriver_TypeFromString returns the enum value with a name,
-// or the zero value if there's no such value.
-func Conmon_LogDriver_TypeFromString(c string) Conmon_LogDriver_Type {
-	switch c {
-	case "containerRuntimeInterface":
-		return Conmon_LogDriver_Type_containerRuntimeInterface
-
-	default:
-		return 0
-	}
+	return Conmon_listContainers_Results(s), err
 }
 
-type Conmon_LogDriver_Type_List = capnp.EnumList[Conmon_LogDriver_Type]
-
-func NewConmon_LogDriver_Type_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_Type_List, error) {
-	return capnp.NewEnumList[Conmon_LogDriver_Type](s, sz)
+func (p Conmon_listContainers_Results_Future) Response() Conmon_ListContainersResponse_Future {
+	return Conmon_ListContainersResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_CreateContainerResponse capnp.Struct
+type Conmon_updateContainerResources_Params capnp.Struct
 
-// Conmon_CreateContainerResponse_TypeID is the unique identifier for the type Conmon_CreateContainerResponse.
-const Conmon_CreateContainerResponse_TypeID = 0xde3a625e70772b9a
+// Conmon_updateContainerResources_Params_TypeID is the unique identifier for the type Conmon_updateContainerResources_Params.
+const Conmon_updateContainerResources_Params_TypeID = 0xef387164b6b1f60d
 
-func NewConmon_CreateContainerResponse(s *capnp.Segment) (Conmon_CreateContainerResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
-	return Conmon_CreateContainerResponse(st), err
+func NewConmon_updateContainerResources_Params(s *capnp.Segment) (Conmon_updateContainerResources_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_updateContainerResources_Params(st), err
 }
 
-func NewRootConmon_CreateContainerResponse(s *capnp.Segment) (Conmon_CreateContainerResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
-	return Conmon_CreateContainerResponse(st), err
+func NewRootConmon_updateContainerResources_Params(s *capnp.Segment) (Conmon_updateContainerResources_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_updateContainerResources_Params(st), err
 }
 
-func ReadRootConmon_CreateContainerResponse(msg *capnp.Message) (Conmon_CreateContainerResponse, error) {
+func ReadRootConmon_updateContainerResources_Params(msg *capnp.Message) (Conmon_updateContainerResources_Params, error) {
 	root, err := msg.Root()
-	return Conmon_CreateContainerResponse(root.Struct()), err
+	return Conmon_updateContainerResources_Params(root.Struct()), err
 }
 
-func (s Conmon_CreateContainerResponse) String() string {
-	str, _ := text.Marshal(0xde3a625e70772b9a, capnp.Struct(s))
+func (s Conmon_updateContainerResources_Params) String() string {
+	str, _ := text.Marshal(0xef387164b6b1f60d, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_CreateContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_updateContainerResources_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_CreateContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_CreateContainerResponse {
-	return Conmon_CreateContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_updateContainerResources_Params) DecodeFromPtr(p capnp.Ptr) Conmon_updateContainerResources_Params {
+	return Conmon_updateContainerResources_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_CreateContainerResponse) ToPtr() capnp.Ptr {
+func (s Conmon_updateContainerResources_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_CreateContainerResponse) IsValid() bool {
+func (s Conmon_updateContainerResources_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_CreateContainerResponse) Message() *capnp.Message {
+func (s Conmon_updateContainerResources_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_CreateContainerResponse) Segment() *capnp.Segment {
+func (s Conmon_updateContainerResources_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_CreateContainerResponse) ContainerPid() uint32 {
-	return capnp.Struct(s).Uint32(0)
+func (s Conmon_updateContainerResources_Params) Request() (Conmon_UpdateContainerResourcesRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_UpdateContainerResourcesRequest(p.Struct()), err
 }
 
-func (s Conmon_CreateContainerResponse) SetContainerPid(v uint32) {
-	capnp.Struct(s).SetUint32(0, v)
+func (s Conmon_updateContainerResources_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
 }
 
-// Conmon_CreateContainerResponse_List is a list of Conmon_CreateContainerResponse.
-type Conmon_CreateContainerResponse_List = capnp.StructList[Conmon_CreateContainerResponse]
+func (s Conmon_updateContainerResources_Params) SetRequest(v Conmon_UpdateContainerResourcesRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
 
-// NewConmon_CreateContainerResponse creates a new list of Conmon_CreateContainerResponse.
-func NewConmon_CreateContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_CreateContainerResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_CreateContainerResponse](l), err
+// NewRequest sets the request field to a newly
+// allocated Conmon_UpdateContainerResourcesRequest struct, preferring placement in s's segment.
+func (s Conmon_updateContainerResources_Params) NewRequest() (Conmon_UpdateContainerResourcesRequest, error) {
+	ss, err := NewConmon_UpdateContainerResourcesRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_UpdateContainerResourcesRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_CreateContainerResponse_Future is a wrapper for a Conmon_CreateContainerResponse promised by a client call.
-type Conmon_CreateContainerResponse_Future struct{ *capnp.Future }
+// Conmon_updateContainerResources_Params_List is a list of Conmon_updateContainerResources_Params.
+type Conmon_updateContainerResources_Params_List = capnp.StructList[Conmon_updateContainerResources_Params]
 
-func (p Conmon_CreateContainerResponse_Future) Struct() (Conmon_CreateContainerResponse, error) {
+// NewConmon_updateContainerResources_Params creates a new list of Conmon_updateContainerResources_Params.
+func NewConmon_updateContainerResources_Params_List(s *capnp.Segment, sz int32) (Conmon_updateContainerResources_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_updateContainerResources_Params](l), err
+}
+
+// Conmon_updateContainerResources_Params_Future is a wrapper for a Conmon_updateContainerResources_Params promised by a client call.
+type Conmon_updateContainerResources_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_updateContainerResources_Params_Future) Struct() (Conmon_updateContainerResources_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_CreateContainerResponse(s), err
+	return Conmon_updateContainerResources_Params(s), err
 }
 
-type Conmon_ExecSyncContainerRequest capnp.Struct
+func (p Conmon_updateContainerResources_Params_Future) Request() Conmon_UpdateContainerResourcesRequest_Future {
+	return Conmon_UpdateContainerResourcesRequest_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_ExecSyncContainerRequest_TypeID is the unique identifier for the type Conmon_ExecSyncContainerRequest.
-const Conmon_ExecSyncContainerRequest_TypeID = 0xf41122f890a371a6
+type Conmon_updateContainerResources_Results capnp.Struct
 
-func NewConmon_ExecSyncContainerRequest(s *capnp.Segment) (Conmon_ExecSyncContainerRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 2})
-	return Conmon_ExecSyncContainerRequest(st), err
+// Conmon_updateContainerResources_Results_TypeID is the unique identifier for the type Conmon_updateContainerResources_Results.
+const Conmon_updateContainerResources_Results_TypeID = 0xcb5061b78617cf88
+
+func NewConmon_updateContainerResources_Results(s *capnp.Segment) (Conmon_updateContainerResources_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_updateContainerResources_Results(st), err
 }
 
-func NewRootConmon_ExecSyncContainerRequest(s *capnp.Segment) (Conmon_ExecSyncContainerRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 2})
-	return Conmon_ExecSyncContainerRequest(st), err
+func NewRootConmon_updateContainerResources_Results(s *capnp.Segment) (Conmon_updateContainerResources_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_updateContainerResources_Results(st), err
 }
 
-func ReadRootConmon_ExecSyncContainerRequest(msg *capnp.Message) (Conmon_ExecSyncContainerRequest, error) {
+func ReadRootConmon_updateContainerResources_Results(msg *capnp.Message) (Conmon_updateContainerResources_Results, error) {
 	root, err := msg.Root()
-	return Conmon_ExecSyncContainerRequest(root.Struct()), err
+	return Conmon_updateContainerResources_Results(root.Struct()), err
 }
 
-func (s Conmon_ExecSyncContainerRequest) String() string {
-	str, _ := text.Marshal(0xf41122f890a371a6, capnp.Struct(s))
+func (s Conmon_updateContainerResources_Results) String() string {
+	str, _ := text.Marshal(0xcb5061b78617cf88, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_ExecSyncContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_updateContainerResources_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_ExecSyncContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ExecSyncContainerRequest {
-	return Conmon_ExecSyncContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_updateContainerResources_Results) DecodeFromPtr(p capnp.Ptr) Conmon_updateContainerResources_Results {
+	return Conmon_updateContainerResources_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_ExecSyncContainerRequest) ToPtr() capnp.Ptr {
+func (s Conmon_updateContainerResources_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_ExecSyncContainerRequest) IsValid() bool {
+func (s Conmon_updateContainerResources_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_ExecSyncContainerRequest) Message() *capnp.Message {
+func (s Conmon_updateContainerResources_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_ExecSyncContainerRequest) Segment() *capnp.Segment {
+func (s Conmon_updateContainerResources_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_ExecSyncContainerRequest) Id() (string, error) {
+func (s Conmon_updateContainerResources_Results) Response() (Conmon_UpdateContainerResourcesResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_UpdateContainerResourcesResponse(p.Struct()), err
 }
 
-func (s Conmon_ExecSyncContainerRequest) HasId() bool {
+func (s Conmon_updateContainerResources_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_ExecSyncContainerRequest) IdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
-}
-
-func (s Conmon_ExecSyncContainerRequest) SetId(v string) error {
-	return capnp.Struct(s).SetText(0, v)
-}
-
-func (s Conmon_ExecSyncContainerRequest) TimeoutSec() uint64 {
-	return capnp.Struct(s).Uint64(0)
-}
-
-func (s Conmon_ExecSyncContainerRequest) SetTimeoutSec(v uint64) {
-	capnp.Struct(s).SetUint64(0, v)
-}
-
-func (s Conmon_ExecSyncContainerRequest) Command() (capnp.TextList, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return capnp.TextList(p.List()), err
-}
-
-func (s Conmon_ExecSyncContainerRequest) HasCommand() bool {
-	return capnp.Struct(s).HasPtr(1)
-}
-
-func (s Conmon_ExecSyncContainerRequest) SetCommand(v capnp.TextList) error {
-	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+func (s Conmon_updateContainerResources_Results) SetResponse(v Conmon_UpdateContainerResourcesResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// NewCommand sets the command field to a newly
-// allocated capnp.TextList, preferring placement in s's segment.
-func (s Conmon_ExecSyncContainerRequest) NewCommand(n int32) (capnp.TextList, error) {
-	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+// NewResponse sets the response field to a newly
+// allocated Conmon_UpdateContainerResourcesResponse struct, preferring placement in s's segment.
+func (s Conmon_updateContainerResources_Results) NewResponse() (Conmon_UpdateContainerResourcesResponse, error) {
+	ss, err := NewConmon_UpdateContainerResourcesResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return capnp.TextList{}, err
+		return Conmon_UpdateContainerResourcesResponse{}, err
 	}
-	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
-	return l, err
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-func (s Conmon_ExecSyncContainerRequest) Terminal() bool {
-	return capnp.Struct(s).Bit(64)
-}
+// Conmon_updateContainerResources_Results_List is a list of Conmon_updateContainerResources_Results.
+type Conmon_updateContainerResources_Results_List = capnp.StructList[Conmon_updateContainerResources_Results]
 
-func (s Conmon_ExecSyncContainerRequest) SetTerminal(v bool) {
-	capnp.Struct(s).SetBit(64, v)
+// NewConmon_updateContainerResources_Results creates a new list of Conmon_updateContainerResources_Results.
+func NewConmon_updateContainerResources_Results_List(s *capnp.Segment, sz int32) (Conmon_updateContainerResources_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_updateContainerResources_Results](l), err
 }
 
-// Conmon_ExecSyncContainerRequest_List is a list of Conmon_ExecSyncContainerRequest.
-type Conmon_ExecSyncContainerRequest_List = capnp.StructList[Conmon_ExecSyncContainerRequest]
+// Conmon_updateContainerResources_Results_Future is a wrapper for a Conmon_updateContainerResources_Results promised by a client call.
+type Conmon_updateContainerResources_Results_Future struct{ *capnp.Future }
 
-// NewConmon_ExecSyncContainerRequest creates a new list of Conmon_ExecSyncContainerRequest.
-func NewConmon_ExecSyncContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_ExecSyncContainerRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 2}, sz)
-	return capnp.StructList[Conmon_ExecSyncContainerRequest](l), err
+func (p Conmon_updateContainerResources_Results_Future) Struct() (Conmon_updateContainerResources_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_updateContainerResources_Results(s), err
 }
 
-// Conmon_ExecSyncContainerRequest_Future is a wrapper for a Conmon_ExecSyncContainerRequest promised by a client call.
-type Conmon_ExecSyncContainerRequest_Future struct{ *capnp.Future }
-
-func (p Conmon_ExecSyncContainerRequest_Future) Struct() (Conmon_ExecSyncContainerRequest, error) {
-	s, err := p.Future.Struct()
-	return Conmon_ExecSyncContainerRequest(s), err
+func (p Conmon_updateContainerResources_Results_Future) Response() Conmon_UpdateContainerResourcesResponse_Future {
+	return Conmon_UpdateContainerResourcesResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_ExecSyncContainerResponse capnp.Struct
+type Conmon_pauseContainer_Params capnp.Struct
 
-// Conmon_ExecSyncContainerResponse_TypeID is the unique identifier for the type Conmon_ExecSyncContainerResponse.
-const Conmon_ExecSyncContainerResponse_TypeID = 0xd9d61d1d803c85fc
+// Conmon_pauseContainer_Params_TypeID is the unique identifier for the type Conmon_pauseContainer_Params.
+const Conmon_pauseContainer_Params_TypeID = 0x85e40ceb4cb0ce18
 
-func NewConmon_ExecSyncContainerResponse(s *capnp.Segment) (Conmon_ExecSyncContainerResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
-	return Conmon_ExecSyncContainerResponse(st), err
+func NewConmon_pauseContainer_Params(s *capnp.Segment) (Conmon_pauseContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_pauseContainer_Params(st), err
 }
 
-func NewRootConmon_ExecSyncContainerResponse(s *capnp.Segment) (Conmon_ExecSyncContainerResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
-	return Conmon_ExecSyncContainerResponse(st), err
+func NewRootConmon_pauseContainer_Params(s *capnp.Segment) (Conmon_pauseContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_pauseContainer_Params(st), err
 }
 
-func ReadRootConmon_ExecSyncContainerResponse(msg *capnp.Message) (Conmon_ExecSyncContainerResponse, error) {
+func ReadRootConmon_pauseContainer_Params(msg *capnp.Message) (Conmon_pauseContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_ExecSyncContainerResponse(root.Struct()), err
+	return Conmon_pauseContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_ExecSyncContainerResponse) String() string {
-	str, _ := text.Marshal(0xd9d61d1d803c85fc, capnp.Struct(s))
+func (s Conmon_pauseContainer_Params) String() string {
+	str, _ := text.Marshal(0x85e40ceb4cb0ce18, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_ExecSyncContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_pauseContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_ExecSyncContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ExecSyncContainerResponse {
-	return Conmon_ExecSyncContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_pauseContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_pauseContainer_Params {
+	return Conmon_pauseContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_ExecSyncContainerResponse) ToPtr() capnp.Ptr {
+func (s Conmon_pauseContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_ExecSyncContainerResponse) IsValid() bool {
+func (s Conmon_pauseContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_ExecSyncContainerResponse) Message() *capnp.Message {
+func (s Conmon_pauseContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_ExecSyncContainerResponse) Segment() *capnp.Segment {
+func (s Conmon_pauseContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_ExecSyncContainerResponse) ExitCode() int32 {
-	return int32(capnp.Struct(s).Uint32(0))
-}
-
-func (s Conmon_ExecSyncContainerResponse) SetExitCode(v int32) {
-	capnp.Struct(s).SetUint32(0, uint32(v))
-}
-
-func (s Conmon_ExecSyncContainerResponse) Stdout() ([]byte, error) {
+func (s Conmon_pauseContainer_Params) Request() (Conmon_PauseContainerRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return []byte(p.Data()), err
+	return Conmon_PauseContainerRequest(p.Struct()), err
 }
 
-func (s Conmon_ExecSyncContainerResponse) HasStdout() bool {
+func (s Conmon_pauseContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_ExecSyncContainerResponse) SetStdout(v []byte) error {
-	return capnp.Struct(s).SetData(0, v)
-}
-
-func (s Conmon_ExecSyncContainerResponse) Stderr() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return []byte(p.Data()), err
-}
-
-func (s Conmon_ExecSyncContainerResponse) HasStderr() bool {
-	return capnp.Struct(s).HasPtr(1)
+func (s Conmon_pauseContainer_Params) SetRequest(v Conmon_PauseContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_ExecSyncContainerResponse) SetStderr(v []byte) error {
-	return capnp.Struct(s).SetData(1, v)
+// NewRequest sets the request field to a newly
+// allocated Conmon_PauseContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_pauseContainer_Params) NewRequest() (Conmon_PauseContainerRequest, error) {
+	ss, err := NewConmon_PauseContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_PauseContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-func (s Conmon_ExecSyncContainerResponse) TimedOut() bool {
-	return capnp.Struct(s).Bit(32)
-}
+// Conmon_pauseContainer_Params_List is a list of Conmon_pauseContainer_Params.
+type Conmon_pauseContainer_Params_List = capnp.StructList[Conmon_pauseContainer_Params]
 
-func (s Conmon_ExecSyncContainerResponse) SetTimedOut(v bool) {
-	capnp.Struct(s).SetBit(32, v)
+// NewConmon_pauseContainer_Params creates a new list of Conmon_pauseContainer_Params.
+func NewConmon_pauseContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_pauseContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_pauseContainer_Params](l), err
 }
 
-// Conmon_ExecSyncContainerResponse_List is a list of Conmon_ExecSyncContainerResponse.
-type Conmon_ExecSyncContainerResponse_List = capnp.StructList[Conmon_ExecSyncContainerResponse]
+// Conmon_pauseContainer_Params_Future is a wrapper for a Conmon_pauseContainer_Params promised by a client call.
+type Conmon_pauseContainer_Params_Future struct{ *capnp.Future }
 
-// NewConmon_ExecSyncContainerResponse creates a new list of Conmon_ExecSyncContainerResponse.
-func NewConmon_ExecSyncContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_ExecSyncContainerResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2}, sz)
-	return capnp.StructList[Conmon_ExecSyncContainerResponse](l), err
+func (p Conmon_pauseContainer_Params_Future) Struct() (Conmon_pauseContainer_Params, error) {
+	s, err := p.Future.Struct()
+	return Conmon_pauseContainer_Params(s), err
 }
 
-// Conmon_ExecSyncContainerResponse_Future is a wrapper for a Conmon_ExecSyncContainerResponse promised by a client call.
-type Conmon_ExecSyncContainerResponse_Future struct{ *capnp.Future }
-
-func (p Conmon_ExecSyncContainerResponse_Future) Struct() (Conmon_ExecSyncContainerResponse, error) {
-	s, err := p.Future.Struct()
-	return Conmon_ExecSyncContainerResponse(s), err
+func (p Conmon_pauseContainer_Params_Future) Request() Conmon_PauseContainerRequest_Future {
+	return Conmon_PauseContainerRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_AttachRequest capnp.Struct
+type Conmon_pauseContainer_Results capnp.Struct
 
-// Conmon_AttachRequest_TypeID is the unique identifier for the type Conmon_AttachRequest.
-const Conmon_AttachRequest_TypeID = 0xdf703ca0befc3afc
+// Conmon_pauseContainer_Results_TypeID is the unique identifier for the type Conmon_pauseContainer_Results.
+const Conmon_pauseContainer_Results_TypeID = 0x8e227cb048ce3dce
 
-func NewConmon_AttachRequest(s *capnp.Segment) (Conmon_AttachRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
-	return Conmon_AttachRequest(st), err
+func NewConmon_pauseContainer_Results(s *capnp.Segment) (Conmon_pauseContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_pauseContainer_Results(st), err
 }
 
-func NewRootConmon_AttachRequest(s *capnp.Segment) (Conmon_AttachRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
-	return Conmon_AttachRequest(st), err
+func NewRootConmon_pauseContainer_Results(s *capnp.Segment) (Conmon_pauseContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_pauseContainer_Results(st), err
 }
 
-func ReadRootConmon_AttachRequest(msg *capnp.Message) (Conmon_AttachRequest, error) {
+func ReadRootConmon_pauseContainer_Results(msg *capnp.Message) (Conmon_pauseContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_AttachRequest(root.Struct()), err
+	return Conmon_pauseContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_AttachRequest) String() string {
-	str, _ := text.Marshal(0xdf703ca0befc3afc, capnp.Struct(s))
+func (s Conmon_pauseContainer_Results) String() string {
+	str, _ := text.Marshal(0x8e227cb048ce3dce, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_AttachRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_pauseContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_AttachRequest) DecodeFromPtr(p capnp.Ptr) Conmon_AttachRequest {
-	return Conmon_AttachRequest(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_pauseContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_pauseContainer_Results {
+	return Conmon_pauseContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_AttachRequest) ToPtr() capnp.Ptr {
+func (s Conmon_pauseContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_AttachRequest) IsValid() bool {
+func (s Conmon_pauseContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_AttachRequest) Message() *capnp.Message {
+func (s Conmon_pauseContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_AttachRequest) Segment() *capnp.Segment {
+func (s Conmon_pauseContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_AttachRequest) Id() (string, error) {
+func (s Conmon_pauseContainer_Results) Response() (Conmon_PauseContainerResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_PauseContainerResponse(p.Struct()), err
 }
 
-func (s Conmon_AttachRequest) HasId() bool {
+func (s Conmon_pauseContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_AttachRequest) IdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
+func (s Conmon_pauseContainer_Results) SetResponse(v Conmon_PauseContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_AttachRequest) SetId(v string) error {
-	return capnp.Struct(s).SetText(0, v)
+// NewResponse sets the response field to a newly
+// allocated Conmon_PauseContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_pauseContainer_Results) NewResponse() (Conmon_PauseContainerResponse, error) {
+	ss, err := NewConmon_PauseContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_PauseContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-func (s Conmon_AttachRequest) SocketPath() (string, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return p.Text(), err
+// Conmon_pauseContainer_Results_List is a list of Conmon_pauseContainer_Results.
+type Conmon_pauseContainer_Results_List = capnp.StructList[Conmon_pauseContainer_Results]
+
+// NewConmon_pauseContainer_Results creates a new list of Conmon_pauseContainer_Results.
+func NewConmon_pauseContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_pauseContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_pauseContainer_Results](l), err
 }
 
-func (s Conmon_AttachRequest) HasSocketPath() bool {
-	return capnp.Struct(s).HasPtr(1)
+// Conmon_pauseContainer_Results_Future is a wrapper for a Conmon_pauseContainer_Results promised by a client call.
+type Conmon_pauseContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_pauseContainer_Results_Future) Struct() (Conmon_pauseContainer_Results, error) {
+	s, err := p.Future.Struct()
+	return Conmon_pauseContainer_Results(s), err
+}
+
+func (p Conmon_pauseContainer_Results_Future) Response() Conmon_PauseContainerResponse_Future {
+	return Conmon_PauseContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_unpauseContainer_Params capnp.Struct
+
+// Conmon_unpauseContainer_Params_TypeID is the unique identifier for the type Conmon_unpauseContainer_Params.
+const Conmon_unpauseContainer_Params_TypeID = 0xce07fd7fe8b3d1c9
+
+func NewConmon_unpauseContainer_Params(s *capnp.Segment) (Conmon_unpauseContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_unpauseContainer_Params(st), err
+}
+
+func NewRootConmon_unpauseContainer_Params(s *capnp.Segment) (Conmon_unpauseContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_unpauseContainer_Params(st), err
+}
+
+func ReadRootConmon_unpauseContainer_Params(msg *capnp.Message) (Conmon_unpauseContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_unpauseContainer_Params(root.Struct()), err
+}
+
+func (s Conmon_unpauseContainer_Params) String() string {
+	str, _ := text.Marshal(0xce07fd7fe8b3d1c9, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_unpauseContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_unpauseContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_unpauseContainer_Params {
+	return Conmon_unpauseContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_AttachRequest) SocketPathBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return p.TextBytes(), err
+func (s Conmon_unpauseContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_unpauseContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_AttachRequest) SetSocketPath(v string) error {
-	return capnp.Struct(s).SetText(1, v)
+func (s Conmon_unpauseContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_AttachRequest) ExecSessionId() (string, error) {
-	p, err := capnp.Struct(s).Ptr(2)
-	return p.Text(), err
+func (s Conmon_unpauseContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_unpauseContainer_Params) Request() (Conmon_UnpauseContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_UnpauseContainerRequest(p.Struct()), err
 }
 
-func (s Conmon_AttachRequest) HasExecSessionId() bool {
-	return capnp.Struct(s).HasPtr(2)
+func (s Conmon_unpauseContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_AttachRequest) ExecSessionIdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(2)
-	return p.TextBytes(), err
+func (s Conmon_unpauseContainer_Params) SetRequest(v Conmon_UnpauseContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_AttachRequest) SetExecSessionId(v string) error {
-	return capnp.Struct(s).SetText(2, v)
+// NewRequest sets the request field to a newly
+// allocated Conmon_UnpauseContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_unpauseContainer_Params) NewRequest() (Conmon_UnpauseContainerRequest, error) {
+	ss, err := NewConmon_UnpauseContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_UnpauseContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_AttachRequest_List is a list of Conmon_AttachRequest.
-type Conmon_AttachRequest_List = capnp.StructList[Conmon_AttachRequest]
+// Conmon_unpauseContainer_Params_List is a list of Conmon_unpauseContainer_Params.
+type Conmon_unpauseContainer_Params_List = capnp.StructList[Conmon_unpauseContainer_Params]
 
-// NewConmon_AttachRequest creates a new list of Conmon_AttachRequest.
-func NewConmon_AttachRequest_List(s *capnp.Segment, sz int32) (Conmon_AttachRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3}, sz)
-	return capnp.StructList[Conmon_AttachRequest](l), err
+// NewConmon_unpauseContainer_Params creates a new list of Conmon_unpauseContainer_Params.
+func NewConmon_unpauseContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_unpauseContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_unpauseContainer_Params](l), err
 }
 
-// Conmon_AttachRequest_Future is a wrapper for a Conmon_AttachRequest promised by a client call.
-type Conmon_AttachRequest_Future struct{ *capnp.Future }
+// Conmon_unpauseContainer_Params_Future is a wrapper for a Conmon_unpauseContainer_Params promised by a client call.
+type Conmon_unpauseContainer_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_AttachRequest_Future) Struct() (Conmon_AttachRequest, error) {
+func (p Conmon_unpauseContainer_Params_Future) Struct() (Conmon_unpauseContainer_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_AttachRequest(s), err
+	return Conmon_unpauseContainer_Params(s), err
 }
 
-type Conmon_AttachResponse capnp.Struct
+func (p Conmon_unpauseContainer_Params_Future) Request() Conmon_UnpauseContainerRequest_Future {
+	return Conmon_UnpauseContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_AttachResponse_TypeID is the unique identifier for the type Conmon_AttachResponse.
-const Conmon_AttachResponse_TypeID = 0xace5517aafc86077
+type Conmon_unpauseContainer_Results capnp.Struct
 
-func NewConmon_AttachResponse(s *capnp.Segment) (Conmon_AttachResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_AttachResponse(st), err
+// Conmon_unpauseContainer_Results_TypeID is the unique identifier for the type Conmon_unpauseContainer_Results.
+const Conmon_unpauseContainer_Results_TypeID = 0xebf2395e50275e51
+
+func NewConmon_unpauseContainer_Results(s *capnp.Segment) (Conmon_unpauseContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_unpauseContainer_Results(st), err
 }
 
-func NewRootConmon_AttachResponse(s *capnp.Segment) (Conmon_AttachResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_AttachResponse(st), err
+func NewRootConmon_unpauseContainer_Results(s *capnp.Segment) (Conmon_unpauseContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_unpauseContainer_Results(st), err
 }
 
-func ReadRootConmon_AttachResponse(msg *capnp.Message) (Conmon_AttachResponse, error) {
+func ReadRootConmon_unpauseContainer_Results(msg *capnp.Message) (Conmon_unpauseContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_AttachResponse(root.Struct()), err
+	return Conmon_unpauseContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_AttachResponse) String() string {
-	str, _ := text.Marshal(0xace5517aafc86077, capnp.Struct(s))
+func (s Conmon_unpauseContainer_Results) String() string {
+	str, _ := text.Marshal(0xebf2395e50275e51, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_AttachResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_unpauseContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_AttachResponse) DecodeFromPtr(p capnp.Ptr) Conmon_AttachResponse {
-	return Conmon_AttachResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_unpauseContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_unpauseContainer_Results {
+	return Conmon_unpauseContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_AttachResponse) ToPtr() capnp.Ptr {
+func (s Conmon_unpauseContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_AttachResponse) IsValid() bool {
+func (s Conmon_unpauseContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_AttachResponse) Message() *capnp.Message {
+func (s Conmon_unpauseContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_AttachResponse) Segment() *capnp.Segment {
+func (s Conmon_unpauseContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
+func (s Conmon_unpauseContainer_Results) Response() (Conmon_UnpauseContainerResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_UnpauseContainerResponse(p.Struct()), err
+}
 
-// Conmon_AttachResponse_List is a list of Conmon_AttachResponse.
-type Conmon_AttachResponse_List = capnp.StructList[Conmon_AttachResponse]
+func (s Conmon_unpauseContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
 
-// NewConmon_AttachResponse creates a new list of Conmon_AttachResponse.
-func NewConmon_AttachResponse_List(s *capnp.Segment, sz int32) (Conmon_AttachResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_AttachResponse](l), err
+func (s Conmon_unpauseContainer_Results) SetResponse(v Conmon_UnpauseContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// Conmon_AttachResponse_Future is a wrapper for a Conmon_AttachResponse promised by a client call.
-type Conmon_AttachResponse_Future struct{ *capnp.Future }
+// NewResponse sets the response field to a newly
+// allocated Conmon_UnpauseContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_unpauseContainer_Results) NewResponse() (Conmon_UnpauseContainerResponse, error) {
+	ss, err := NewConmon_UnpauseContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_UnpauseContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
 
-func (p Conmon_AttachResponse_Future) Struct() (Conmon_AttachResponse, error) {
+// Conmon_unpauseContainer_Results_List is a list of Conmon_unpauseContainer_Results.
+type Conmon_unpauseContainer_Results_List = capnp.StructList[Conmon_unpauseContainer_Results]
+
+// NewConmon_unpauseContainer_Results creates a new list of Conmon_unpauseContainer_Results.
+func NewConmon_unpauseContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_unpauseContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_unpauseContainer_Results](l), err
+}
+
+// Conmon_unpauseContainer_Results_Future is a wrapper for a Conmon_unpauseContainer_Results promised by a client call.
+type Conmon_unpauseContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_unpauseContainer_Results_Future) Struct() (Conmon_unpauseContainer_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_AttachResponse(s), err
+	return Conmon_unpauseContainer_Results(s), err
 }
 
-type Conmon_ReopenLogRequest capnp.Struct
+func (p Conmon_unpauseContainer_Results_Future) Response() Conmon_UnpauseContainerResponse_Future {
+	return Conmon_UnpauseContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_ReopenLogRequest_TypeID is the unique identifier for the type Conmon_ReopenLogRequest.
-const Conmon_ReopenLogRequest_TypeID = 0xd0476e0f34d1411a
+type Conmon_checkpointContainer_Params capnp.Struct
 
-func NewConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
+// Conmon_checkpointContainer_Params_TypeID is the unique identifier for the type Conmon_checkpointContainer_Params.
+const Conmon_checkpointContainer_Params_TypeID = 0x9fb7fa9c3928a4d3
+
+func NewConmon_checkpointContainer_Params(s *capnp.Segment) (Conmon_checkpointContainer_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_ReopenLogRequest(st), err
+	return Conmon_checkpointContainer_Params(st), err
 }
 
-func NewRootConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
+func NewRootConmon_checkpointContainer_Params(s *capnp.Segment) (Conmon_checkpointContainer_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_ReopenLogRequest(st), err
+	return Conmon_checkpointContainer_Params(st), err
 }
 
-func ReadRootConmon_ReopenLogRequest(msg *capnp.Message) (Conmon_ReopenLogRequest, error) {
+func ReadRootConmon_checkpointContainer_Params(msg *capnp.Message) (Conmon_checkpointContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_ReopenLogRequest(root.Struct()), err
+	return Conmon_checkpointContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_ReopenLogRequest) String() string {
-	str, _ := text.Marshal(0xd0476e0f34d1411a, capnp.Struct(s))
+func (s Conmon_checkpointContainer_Params) String() string {
+	str, _ := text.Marshal(0x9fb7fa9c3928a4d3, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_ReopenLogRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_checkpointContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_ReopenLogRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ReopenLogRequest {
-	return Conmon_ReopenLogRequest(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_checkpointContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_checkpointContainer_Params {
+	return Conmon_checkpointContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_ReopenLogRequest) ToPtr() capnp.Ptr {
+func (s Conmon_checkpointContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_ReopenLogRequest) IsValid() bool {
+func (s Conmon_checkpointContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_ReopenLogRequest) Message() *capnp.Message {
+func (s Conmon_checkpointContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_ReopenLogRequest) Segment() *capnp.Segment {
+func (s Conmon_checkpointContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_ReopenLogRequest) Id() (string, error) {
+func (s Conmon_checkpointContainer_Params) Request() (Conmon_CheckpointContainerRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_CheckpointContainerRequest(p.Struct()), err
 }
 
-func (s Conmon_ReopenLogRequest) HasId() bool {
+func (s Conmon_checkpointContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_ReopenLogRequest) IdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
+func (s Conmon_checkpointContainer_Params) SetRequest(v Conmon_CheckpointContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_ReopenLogRequest) SetId(v string) error {
-	return capnp.Struct(s).SetText(0, v)
+// NewRequest sets the request field to a newly
+// allocated Conmon_CheckpointContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_checkpointContainer_Params) NewRequest() (Conmon_CheckpointContainerRequest, error) {
+	ss, err := NewConmon_CheckpointContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_CheckpointContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_ReopenLogRequest_List is a list of Conmon_ReopenLogRequest.
-type Conmon_ReopenLogRequest_List = capnp.StructList[Conmon_ReopenLogRequest]
+// Conmon_checkpointContainer_Params_List is a list of Conmon_checkpointContainer_Params.
+type Conmon_checkpointContainer_Params_List = capnp.StructList[Conmon_checkpointContainer_Params]
 
-// NewConmon_ReopenLogRequest creates a new list of Conmon_ReopenLogRequest.
-func NewConmon_ReopenLogRequest_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogRequest_List, error) {
+// NewConmon_checkpointContainer_Params creates a new list of Conmon_checkpointContainer_Params.
+func NewConmon_checkpointContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_checkpointContainer_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_ReopenLogRequest](l), err
+	return capnp.StructList[Conmon_checkpointContainer_Params](l), err
 }
 
-// Conmon_ReopenLogRequest_Future is a wrapper for a Conmon_ReopenLogRequest promised by a client call.
-type Conmon_ReopenLogRequest_Future struct{ *capnp.Future }
+// Conmon_checkpointContainer_Params_Future is a wrapper for a Conmon_checkpointContainer_Params promised by a client call.
+type Conmon_checkpointContainer_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_ReopenLogRequest_Future) Struct() (Conmon_ReopenLogRequest, error) {
+func (p Conmon_checkpointContainer_Params_Future) Struct() (Conmon_checkpointContainer_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_ReopenLogRequest(s), err
+	return Conmon_checkpointContainer_Params(s), err
 }
 
-type Conmon_ReopenLogResponse capnp.Struct
+func (p Conmon_checkpointContainer_Params_Future) Request() Conmon_CheckpointContainerRequest_Future {
+	return Conmon_CheckpointContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_ReopenLogResponse_TypeID is the unique identifier for the type Conmon_ReopenLogResponse.
-const Conmon_ReopenLogResponse_TypeID = 0xa20f49456be85b99
+type Conmon_checkpointContainer_Results capnp.Struct
 
-func NewConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_ReopenLogResponse(st), err
+// Conmon_checkpointContainer_Results_TypeID is the unique identifier for the type Conmon_checkpointContainer_Results.
+const Conmon_checkpointContainer_Results_TypeID = 0xfc257fe2fa86af93
+
+func NewConmon_checkpointContainer_Results(s *capnp.Segment) (Conmon_checkpointContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_checkpointContainer_Results(st), err
 }
 
-func NewRootConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_ReopenLogResponse(st), err
+func NewRootConmon_checkpointContainer_Results(s *capnp.Segment) (Conmon_checkpointContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_checkpointContainer_Results(st), err
 }
 
-func ReadRootConmon_ReopenLogResponse(msg *capnp.Message) (Conmon_ReopenLogResponse, error) {
+func ReadRootConmon_checkpointContainer_Results(msg *capnp.Message) (Conmon_checkpointContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_ReopenLogResponse(root.Struct()), err
+	return Conmon_checkpointContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_ReopenLogResponse) String() string {
-	str, _ := text.Marshal(0xa20f49456be85b99, capnp.Struct(s))
+func (s Conmon_checkpointContainer_Results) String() string {
+	str, _ := text.Marshal(0xfc257fe2fa86af93, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_ReopenLogResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_checkpointContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_ReopenLogResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ReopenLogResponse {
-	return Conmon_ReopenLogResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_checkpointContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_checkpointContainer_Results {
+	return Conmon_checkpointContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_ReopenLogResponse) ToPtr() capnp.Ptr {
+func (s Conmon_checkpointContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_ReopenLogResponse) IsValid() bool {
+func (s Conmon_checkpointContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_ReopenLogResponse) Message() *capnp.Message {
+func (s Conmon_checkpointContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_ReopenLogResponse) Segment() *capnp.Segment {
+func (s Conmon_checkpointContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
+func (s Conmon_checkpointContainer_Results) Response() (Conmon_CheckpointContainerResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_CheckpointContainerResponse(p.Struct()), err
+}
 
-// Conmon_ReopenLogResponse_List is a list of Conmon_ReopenLogResponse.
-type Conmon_ReopenLogResponse_List = capnp.StructList[Conmon_ReopenLogResponse]
+func (s Conmon_checkpointContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
 
-// NewConmon_ReopenLogResponse creates a new list of Conmon_ReopenLogResponse.
-func NewConmon_ReopenLogResponse_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_ReopenLogResponse](l), err
+func (s Conmon_checkpointContainer_Results) SetResponse(v Conmon_CheckpointContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// Conmon_ReopenLogResponse_Future is a wrapper for a Conmon_ReopenLogResponse promised by a client call.
-type Conmon_ReopenLogResponse_Future struct{ *capnp.Future }
+// NewResponse sets the response field to a newly
+// allocated Conmon_CheckpointContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_checkpointContainer_Results) NewResponse() (Conmon_CheckpointContainerResponse, error) {
+	ss, err := NewConmon_CheckpointContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_CheckpointContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
 
-func (p Conmon_ReopenLogResponse_Future) Struct() (Conmon_ReopenLogResponse, error) {
+// Conmon_checkpointContainer_Results_List is a list of Conmon_checkpointContainer_Results.
+type Conmon_checkpointContainer_Results_List = capnp.StructList[Conmon_checkpointContainer_Results]
+
+// NewConmon_checkpointContainer_Results creates a new list of Conmon_checkpointContainer_Results.
+func NewConmon_checkpointContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_checkpointContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_checkpointContainer_Results](l), err
+}
+
+// Conmon_checkpointContainer_Results_Future is a wrapper for a Conmon_checkpointContainer_Results promised by a client call.
+type Conmon_checkpointContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_checkpointContainer_Results_Future) Struct() (Conmon_checkpointContainer_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_ReopenLogResponse(s), err
+	return Conmon_checkpointContainer_Results(s), err
 }
 
-type Conmon_SetWindowSizeRequest capnp.Struct
+func (p Conmon_checkpointContainer_Results_Future) Response() Conmon_CheckpointContainerResponse_Future {
+	return Conmon_CheckpointContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_SetWindowSizeRequest_TypeID is the unique identifier for the type Conmon_SetWindowSizeRequest.
-const Conmon_SetWindowSizeRequest_TypeID = 0xb5418b8ea8ead17b
+type Conmon_restoreContainer_Params capnp.Struct
 
-func NewConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
-	return Conmon_SetWindowSizeRequest(st), err
+// Conmon_restoreContainer_Params_TypeID is the unique identifier for the type Conmon_restoreContainer_Params.
+const Conmon_restoreContainer_Params_TypeID = 0xb30aaab6c6870e33
+
+func NewConmon_restoreContainer_Params(s *capnp.Segment) (Conmon_restoreContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_restoreContainer_Params(st), err
 }
 
-func NewRootConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
-	return Conmon_SetWindowSizeRequest(st), err
+func NewRootConmon_restoreContainer_Params(s *capnp.Segment) (Conmon_restoreContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_restoreContainer_Params(st), err
 }
 
-func ReadRootConmon_SetWindowSizeRequest(msg *capnp.Message) (Conmon_SetWindowSizeRequest, error) {
+func ReadRootConmon_restoreContainer_Params(msg *capnp.Message) (Conmon_restoreContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_SetWindowSizeRequest(root.Struct()), err
+	return Conmon_restoreContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_SetWindowSizeRequest) String() string {
-	str, _ := text.Marshal(0xb5418b8ea8ead17b, capnp.Struct(s))
+func (s Conmon_restoreContainer_Params) String() string {
+	str, _ := text.Marshal(0xb30aaab6c6870e33, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_SetWindowSizeRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_restoreContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_SetWindowSizeRequest) DecodeFromPtr(p capnp.Ptr) Conmon_SetWindowSizeRequest {
-	return Conmon_SetWindowSizeRequest(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_restoreContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_restoreContainer_Params {
+	return Conmon_restoreContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_SetWindowSizeRequest) ToPtr() capnp.Ptr {
+func (s Conmon_restoreContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_SetWindowSizeRequest) IsValid() bool {
+func (s Conmon_restoreContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_SetWindowSizeRequest) Message() *capnp.Message {
+func (s Conmon_restoreContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_SetWindowSizeRequest) Segment() *capnp.Segment {
+func (s Conmon_restoreContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_SetWindowSizeRequest) Id() (string, error) {
+func (s Conmon_restoreContainer_Params) Request() (Conmon_RestoreContainerRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_RestoreContainerRequest(p.Struct()), err
 }
 
-func (s Conmon_SetWindowSizeRequest) HasId() bool {
+func (s Conmon_restoreContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_SetWindowSizeRequest) IdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
-}
-
-func (s Conmon_SetWindowSizeRequest) SetId(v string) error {
-	return capnp.Struct(s).SetText(0, v)
-}
-
-func (s Conmon_SetWindowSizeRequest) Width() uint16 {
-	return capnp.Struct(s).Uint16(0)
-}
-
-func (s Conmon_SetWindowSizeRequest) SetWidth(v uint16) {
-	capnp.Struct(s).SetUint16(0, v)
-}
-
-func (s Conmon_SetWindowSizeRequest) Height() uint16 {
-	return capnp.Struct(s).Uint16(2)
+func (s Conmon_restoreContainer_Params) SetRequest(v Conmon_RestoreContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_SetWindowSizeRequest) SetHeight(v uint16) {
-	capnp.Struct(s).SetUint16(2, v)
+// NewRequest sets the request field to a newly
+// allocated Conmon_RestoreContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_restoreContainer_Params) NewRequest() (Conmon_RestoreContainerRequest, error) {
+	ss, err := NewConmon_RestoreContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_RestoreContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_SetWindowSizeRequest_List is a list of Conmon_SetWindowSizeRequest.
-type Conmon_SetWindowSizeRequest_List = capnp.StructList[Conmon_SetWindowSizeRequest]
+// Conmon_restoreContainer_Params_List is a list of Conmon_restoreContainer_Params.
+type Conmon_restoreContainer_Params_List = capnp.StructList[Conmon_restoreContainer_Params]
 
-// NewConmon_SetWindowSizeRequest creates a new list of Conmon_SetWindowSizeRequest.
-func NewConmon_SetWindowSizeRequest_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_SetWindowSizeRequest](l), err
+// NewConmon_restoreContainer_Params creates a new list of Conmon_restoreContainer_Params.
+func NewConmon_restoreContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_restoreContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_restoreContainer_Params](l), err
 }
 
-// Conmon_SetWindowSizeRequest_Future is a wrapper for a Conmon_SetWindowSizeRequest promised by a client call.
-type Conmon_SetWindowSizeRequest_Future struct{ *capnp.Future }
+// Conmon_restoreContainer_Params_Future is a wrapper for a Conmon_restoreContainer_Params promised by a client call.
+type Conmon_restoreContainer_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_SetWindowSizeRequest_Future) Struct() (Conmon_SetWindowSizeRequest, error) {
+func (p Conmon_restoreContainer_Params_Future) Struct() (Conmon_restoreContainer_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_SetWindowSizeRequest(s), err
+	return Conmon_restoreContainer_Params(s), err
 }
 
-type Conmon_SetWindowSizeResponse capnp.Struct
-
-// Conmon_SetWindowSizeResponse_TypeID is the unique identifier for the type Conmon_SetWindowSizeResponse.
-const Conmon_SetWindowSizeResponse_TypeID = 0xf9b3cd8033aba1f8
+func (p Conmon_restoreContainer_Params_Future) Request() Conmon_RestoreContainerRequest_Future {
+	return Conmon_RestoreContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
 
-func NewConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_SetWindowSizeResponse(st), err
+type Conmon_restoreContainer_Results capnp.Struct
+
+// Conmon_restoreContainer_Results_TypeID is the unique identifier for the type Conmon_restoreContainer_Results.
+const Conmon_restoreContainer_Results_TypeID = 0xd0066da47b16db35
+
+func NewConmon_restoreContainer_Results(s *capnp.Segment) (Conmon_restoreContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_restoreContainer_Results(st), err
 }
 
-func NewRootConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_SetWindowSizeResponse(st), err
+func NewRootConmon_restoreContainer_Results(s *capnp.Segment) (Conmon_restoreContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_restoreContainer_Results(st), err
 }
 
-func ReadRootConmon_SetWindowSizeResponse(msg *capnp.Message) (Conmon_SetWindowSizeResponse, error) {
+func ReadRootConmon_restoreContainer_Results(msg *capnp.Message) (Conmon_restoreContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_SetWindowSizeResponse(root.Struct()), err
+	return Conmon_restoreContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_SetWindowSizeResponse) String() string {
-	str, _ := text.Marshal(0xf9b3cd8033aba1f8, capnp.Struct(s))
+func (s Conmon_restoreContainer_Results) String() string {
+	str, _ := text.Marshal(0xd0066da47b16db35, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_SetWindowSizeResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_restoreContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_SetWindowSizeResponse) DecodeFromPtr(p capnp.Ptr) Conmon_SetWindowSizeResponse {
-	return Conmon_SetWindowSizeResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_restoreContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_restoreContainer_Results {
+	return Conmon_restoreContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_SetWindowSizeResponse) ToPtr() capnp.Ptr {
+func (s Conmon_restoreContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_SetWindowSizeResponse) IsValid() bool {
+func (s Conmon_restoreContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_SetWindowSizeResponse) Message() *capnp.Message {
+func (s Conmon_restoreContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_SetWindowSizeResponse) Segment() *capnp.Segment {
+func (s Conmon_restoreContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
+func (s Conmon_restoreContainer_Results) Response() (Conmon_CreateContainerResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_CreateContainerResponse(p.Struct()), err
+}
 
-// Conmon_SetWindowSizeResponse_List is a list of Conmon_SetWindowSizeResponse.
-type Conmon_SetWindowSizeResponse_List = capnp.StructList[Conmon_SetWindowSizeResponse]
+func (s Conmon_restoreContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
 
-// NewConmon_SetWindowSizeResponse creates a new list of Conmon_SetWindowSizeResponse.
-func NewConmon_SetWindowSizeResponse_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_SetWindowSizeResponse](l), err
+func (s Conmon_restoreContainer_Results) SetResponse(v Conmon_CreateContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// Conmon_SetWindowSizeResponse_Future is a wrapper for a Conmon_SetWindowSizeResponse promised by a client call.
-type Conmon_SetWindowSizeResponse_Future struct{ *capnp.Future }
+// NewResponse sets the response field to a newly
+// allocated Conmon_CreateContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_restoreContainer_Results) NewResponse() (Conmon_CreateContainerResponse, error) {
+	ss, err := NewConmon_CreateContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_CreateContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
 
-func (p Conmon_SetWindowSizeResponse_Future) Struct() (Conmon_SetWindowSizeResponse, error) {
+// Conmon_restoreContainer_Results_List is a list of Conmon_restoreContainer_Results.
+type Conmon_restoreContainer_Results_List = capnp.StructList[Conmon_restoreContainer_Results]
+
+// NewConmon_restoreContainer_Results creates a new list of Conmon_restoreContainer_Results.
+func NewConmon_restoreContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_restoreContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_restoreContainer_Results](l), err
+}
+
+// Conmon_restoreContainer_Results_Future is a wrapper for a Conmon_restoreContainer_Results promised by a client call.
+type Conmon_restoreContainer_Results_Future struct{ *capnp.Future }
+
+func (p Conmon_restoreContainer_Results_Future) Struct() (Conmon_restoreContainer_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_SetWindowSizeResponse(s), err
+	return Conmon_restoreContainer_Results(s), err
 }
 
-type Conmon_version_Params capnp.Struct
+func (p Conmon_restoreContainer_Results_Future) Response() Conmon_CreateContainerResponse_Future {
+	return Conmon_CreateContainerResponse_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_version_Params_TypeID is the unique identifier for the type Conmon_version_Params.
-const Conmon_version_Params_TypeID = 0xcc2f70676afee4e7
+type Conmon_execContainer_Params capnp.Struct
 
-func NewConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_version_Params(st), err
+// Conmon_execContainer_Params_TypeID is the unique identifier for the type Conmon_execContainer_Params.
+const Conmon_execContainer_Params_TypeID = 0x9fe3605dfe914696
+
+func NewConmon_execContainer_Params(s *capnp.Segment) (Conmon_execContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execContainer_Params(st), err
 }
 
-func NewRootConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_version_Params(st), err
+func NewRootConmon_execContainer_Params(s *capnp.Segment) (Conmon_execContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execContainer_Params(st), err
 }
 
-func ReadRootConmon_version_Params(msg *capnp.Message) (Conmon_version_Params, error) {
+func ReadRootConmon_execContainer_Params(msg *capnp.Message) (Conmon_execContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_version_Params(root.Struct()), err
+	return Conmon_execContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_version_Params) String() string {
-	str, _ := text.Marshal(0xcc2f70676afee4e7, capnp.Struct(s))
+func (s Conmon_execContainer_Params) String() string {
+	str, _ := text.Marshal(0x9fe3605dfe914696, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_version_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_execContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_version_Params) DecodeFromPtr(p capnp.Ptr) Conmon_version_Params {
-	return Conmon_version_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_execContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_execContainer_Params {
+	return Conmon_execContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_version_Params) ToPtr() capnp.Ptr {
+func (s Conmon_execContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_version_Params) IsValid() bool {
+func (s Conmon_execContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_version_Params) Message() *capnp.Message {
+func (s Conmon_execContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_version_Params) Segment() *capnp.Segment {
+func (s Conmon_execContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
+func (s Conmon_execContainer_Params) Request() (Conmon_ExecContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ExecContainerRequest(p.Struct()), err
+}
 
-// Conmon_version_Params_List is a list of Conmon_version_Params.
-type Conmon_version_Params_List = capnp.StructList[Conmon_version_Params]
+func (s Conmon_execContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
 
-// NewConmon_version_Params creates a new list of Conmon_version_Params.
-func NewConmon_version_Params_List(s *capnp.Segment, sz int32) (Conmon_version_Params_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_version_Params](l), err
+func (s Conmon_execContainer_Params) SetRequest(v Conmon_ExecContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// Conmon_version_Params_Future is a wrapper for a Conmon_version_Params promised by a client call.
-type Conmon_version_Params_Future struct{ *capnp.Future }
+// NewRequest sets the request field to a newly
+// allocated Conmon_ExecContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_execContainer_Params) NewRequest() (Conmon_ExecContainerRequest, error) {
+	ss, err := NewConmon_ExecContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ExecContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
 
-func (p Conmon_version_Params_Future) Struct() (Conmon_version_Params, error) {
+// Conmon_execContainer_Params_List is a list of Conmon_execContainer_Params.
+type Conmon_execContainer_Params_List = capnp.StructList[Conmon_execContainer_Params]
+
+// NewConmon_execContainer_Params creates a new list of Conmon_execContainer_Params.
+func NewConmon_execContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_execContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_execContainer_Params](l), err
+}
+
+// Conmon_execContainer_Params_Future is a wrapper for a Conmon_execContainer_Params promised by a client call.
+type Conmon_execContainer_Params_Future struct{ *capnp.Future }
+
+func (p Conmon_execContainer_Params_Future) Struct() (Conmon_execContainer_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_version_Params(s), err
+	return Conmon_execContainer_Params(s), err
 }
 
-type Conmon_version_Results capnp.Struct
+func (p Conmon_execContainer_Params_Future) Request() Conmon_ExecContainerRequest_Future {
+	return Conmon_ExecContainerRequest_Future{Future: p.Future.Field(0, nil)}
+}
 
-// Conmon_version_Results_TypeID is the unique identifier for the type Conmon_version_Results.
-const Conmon_version_Results_TypeID = 0xe313695ea9477b30
+type Conmon_execContainer_Results capnp.Struct
 
-func NewConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+// Conmon_execContainer_Results_TypeID is the unique identifier for the type Conmon_execContainer_Results.
+const Conmon_execContainer_Results_TypeID = 0xa2c865b278522903
+
+func NewConmon_execContainer_Results(s *capnp.Segment) (Conmon_execContainer_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_version_Results(st), err
+	return Conmon_execContainer_Results(st), err
 }
 
-func NewRootConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+func NewRootConmon_execContainer_Results(s *capnp.Segment) (Conmon_execContainer_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_version_Results(st), err
+	return Conmon_execContainer_Results(st), err
 }
 
-func ReadRootConmon_version_Results(msg *capnp.Message) (Conmon_version_Results, error) {
+func ReadRootConmon_execContainer_Results(msg *capnp.Message) (Conmon_execContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_version_Results(root.Struct()), err
+	return Conmon_execContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_version_Results) String() string {
-	str, _ := text.Marshal(0xe313695ea9477b30, capnp.Struct(s))
+func (s Conmon_execContainer_Results) String() string {
+	str, _ := text.Marshal(0xa2c865b278522903, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_version_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_execContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_version_Results) DecodeFromPtr(p capnp.Ptr) Conmon_version_Results {
-	return Conmon_version_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_execContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_execContainer_Results {
+	return Conmon_execContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_version_Results) ToPtr() capnp.Ptr {
+func (s Conmon_execContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_version_Results) IsValid() bool {
+func (s Conmon_execContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_version_Results) Message() *capnp.Message {
+func (s Conmon_execContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_version_Results) Segment() *capnp.Segment {
+func (s Conmon_execContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_version_Results) Response() (Conmon_VersionResponse, error) {
+func (s Conmon_execContainer_Results) Response() (Conmon_ExecContainerResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_VersionResponse(p.Struct()), err
+	return Conmon_ExecContainerResponse(p.Struct()), err
 }
 
-func (s Conmon_version_Results) HasResponse() bool {
+func (s Conmon_execContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_version_Results) SetResponse(v Conmon_VersionResponse) error {
+func (s Conmon_execContainer_Results) SetResponse(v Conmon_ExecContainerResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_VersionResponse struct, preferring placement in s's segment.
-func (s Conmon_version_Results) NewResponse() (Conmon_VersionResponse, error) {
-	ss, err := NewConmon_VersionResponse(capnp.Struct(s).Segment())
+// allocated Conmon_ExecContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_execContainer_Results) NewResponse() (Conmon_ExecContainerResponse, error) {
+	ss, err := NewConmon_ExecContainerResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_VersionResponse{}, err
+		return Conmon_ExecContainerResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_version_Results_List is a list of Conmon_version_Results.
-type Conmon_version_Results_List = capnp.StructList[Conmon_version_Results]
+// Conmon_execContainer_Results_List is a list of Conmon_execContainer_Results.
+type Conmon_execContainer_Results_List = capnp.StructList[Conmon_execContainer_Results]
 
-// NewConmon_version_Results creates a new list of Conmon_version_Results.
-func NewConmon_version_Results_List(s *capnp.Segment, sz int32) (Conmon_version_Results_List, error) {
+// NewConmon_execContainer_Results creates a new list of Conmon_execContainer_Results.
+func NewConmon_execContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_execContainer_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_version_Results](l), err
+	return capnp.StructList[Conmon_execContainer_Results](l), err
 }
 
-// Conmon_version_Results_Future is a wrapper for a Conmon_version_Results promised by a client call.
-type Conmon_version_Results_Future struct{ *capnp.Future }
+// Conmon_execContainer_Results_Future is a wrapper for a Conmon_execContainer_Results promised by a client call.
+type Conmon_execContainer_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_version_Results_Future) Struct() (Conmon_version_Results, error) {
+func (p Conmon_execContainer_Results_Future) Struct() (Conmon_execContainer_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_version_Results(s), err
+	return Conmon_execContainer_Results(s), err
 }
 
-func (p Conmon_version_Results_Future) Response() Conmon_VersionResponse_Future {
-	return Conmon_VersionResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_execContainer_Results_Future) Response() Conmon_ExecContainerResponse_Future {
+	return Conmon_ExecContainerResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_createContainer_Params capnp.Struct
+type Conmon_waitContainer_Params capnp.Struct
 
-// Conmon_createContainer_Params_TypeID is the unique identifier for the type Conmon_createContainer_Params.
-const Conmon_createContainer_Params_TypeID = 0xf44732c48f949ab8
+// Conmon_waitContainer_Params_TypeID is the unique identifier for the type Conmon_waitContainer_Params.
+const Conmon_waitContainer_Params_TypeID = 0x9bef35d18b7c8e16
 
-func NewConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+func NewConmon_waitContainer_Params(s *capnp.Segment) (Conmon_waitContainer_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Params(st), err
+	return Conmon_waitContainer_Params(st), err
 }
 
-func NewRootConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+func NewRootConmon_waitContainer_Params(s *capnp.Segment) (Conmon_waitContainer_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Params(st), err
+	return Conmon_waitContainer_Params(st), err
 }
 
-func ReadRootConmon_createContainer_Params(msg *capnp.Message) (Conmon_createContainer_Params, error) {
+func ReadRootConmon_waitContainer_Params(msg *capnp.Message) (Conmon_waitContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_createContainer_Params(root.Struct()), err
+	return Conmon_waitContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_createContainer_Params) String() string {
-	str, _ := text.Marshal(0xf44732c48f949ab8, capnp.Struct(s))
+func (s Conmon_waitContainer_Params) String() string {
+	str, _ := text.Marshal(0x9bef35d18b7c8e16, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_createContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_waitContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_createContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_createContainer_Params {
-	return Conmon_createContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_waitContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_waitContainer_Params {
+	return Conmon_waitContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_createContainer_Params) ToPtr() capnp.Ptr {
+func (s Conmon_waitContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_createContainer_Params) IsValid() bool {
+func (s Conmon_waitContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_createContainer_Params) Message() *capnp.Message {
+func (s Conmon_waitContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_createContainer_Params) Segment() *capnp.Segment {
+func (s Conmon_waitContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_createContainer_Params) Request() (Conmon_CreateContainerRequest, error) {
+func (s Conmon_waitContainer_Params) Request() (Conmon_WaitContainerRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_CreateContainerRequest(p.Struct()), err
+	return Conmon_WaitContainerRequest(p.Struct()), err
 }
 
-func (s Conmon_createContainer_Params) HasRequest() bool {
+func (s Conmon_waitContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_createContainer_Params) SetRequest(v Conmon_CreateContainerRequest) error {
+func (s Conmon_waitContainer_Params) SetRequest(v Conmon_WaitContainerRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_CreateContainerRequest struct, preferring placement in s's segment.
-func (s Conmon_createContainer_Params) NewRequest() (Conmon_CreateContainerRequest, error) {
-	ss, err := NewConmon_CreateContainerRequest(capnp.Struct(s).Segment())
+// allocated Conmon_WaitContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_waitContainer_Params) NewRequest() (Conmon_WaitContainerRequest, error) {
+	ss, err := NewConmon_WaitContainerRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_CreateContainerRequest{}, err
+		return Conmon_WaitContainerRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_createContainer_Params_List is a list of Conmon_createContainer_Params.
-type Conmon_createContainer_Params_List = capnp.StructList[Conmon_createContainer_Params]
+// Conmon_waitContainer_Params_List is a list of Conmon_waitContainer_Params.
+type Conmon_waitContainer_Params_List = capnp.StructList[Conmon_waitContainer_Params]
 
-// NewConmon_createContainer_Params creates a new list of Conmon_createContainer_Params.
-func NewConmon_createContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Params_List, error) {
+// NewConmon_waitContainer_Params creates a new list of Conmon_waitContainer_Params.
+func NewConmon_waitContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_waitContainer_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_createContainer_Params](l), err
+	return capnp.StructList[Conmon_waitContainer_Params](l), err
 }
 
-// Conmon_createContainer_Params_Future is a wrapper for a Conmon_createContainer_Params promised by a client call.
-type Conmon_createContainer_Params_Future struct{ *capnp.Future }
+// Conmon_waitContainer_Params_Future is a wrapper for a Conmon_waitContainer_Params promised by a client call.
+type Conmon_waitContainer_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_createContainer_Params_Future) Struct() (Conmon_createContainer_Params, error) {
+func (p Conmon_waitContainer_Params_Future) Struct() (Conmon_waitContainer_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_createContainer_Params(s), err
+	return Conmon_waitContainer_Params(s), err
 }
 
-func (p Conmon_createContainer_Params_Future) Request() Conmon_CreateContainerRequest_Future {
-	return Conmon_CreateContainerRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_waitContainer_Params_Future) Request() Conmon_WaitContainerRequest_Future {
+	return Conmon_WaitContainerRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_createContainer_Results capnp.Struct
+type Conmon_waitContainer_Results capnp.Struct
 
-// Conmon_createContainer_Results_TypeID is the unique identifier for the type Conmon_createContainer_Results.
-const Conmon_createContainer_Results_TypeID = 0xceba3c1a97be15f8
+// Conmon_waitContainer_Results_TypeID is the unique identifier for the type Conmon_waitContainer_Results.
+const Conmon_waitContainer_Results_TypeID = 0xdb0d49abe2bb2ab6
 
-func NewConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+func NewConmon_waitContainer_Results(s *capnp.Segment) (Conmon_waitContainer_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Results(st), err
+	return Conmon_waitContainer_Results(st), err
 }
 
-func NewRootConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+func NewRootConmon_waitContainer_Results(s *capnp.Segment) (Conmon_waitContainer_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Results(st), err
+	return Conmon_waitContainer_Results(st), err
 }
 
-func ReadRootConmon_createContainer_Results(msg *capnp.Message) (Conmon_createContainer_Results, error) {
+func ReadRootConmon_waitContainer_Results(msg *capnp.Message) (Conmon_waitContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_createContainer_Results(root.Struct()), err
+	return Conmon_waitContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_createContainer_Results) String() string {
-	str, _ := text.Marshal(0xceba3c1a97be15f8, capnp.Struct(s))
+func (s Conmon_waitContainer_Results) String() string {
+	str, _ := text.Marshal(0xdb0d49abe2bb2ab6, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_createContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_waitContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_createContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_createContainer_Results {
-	return Conmon_createContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_waitContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_waitContainer_Results {
+	return Conmon_waitContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_createContainer_Results) ToPtr() capnp.Ptr {
+func (s Conmon_waitContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_createContainer_Results) IsValid() bool {
+func (s Conmon_waitContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_createContainer_Results) Message() *capnp.Message {
+func (s Conmon_waitContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_createContainer_Results) Segment() *capnp.Segment {
+func (s Conmon_waitContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_createContainer_Results) Response() (Conmon_CreateContainerResponse, error) {
+func (s Conmon_waitContainer_Results) Response() (Conmon_WaitContainerResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_CreateContainerResponse(p.Struct()), err
+	return Conmon_WaitContainerResponse(p.Struct()), err
 }
 
-func (s Conmon_createContainer_Results) HasResponse() bool {
+func (s Conmon_waitContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_createContainer_Results) SetResponse(v Conmon_CreateContainerResponse) error {
+func (s Conmon_waitContainer_Results) SetResponse(v Conmon_WaitContainerResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_CreateContainerResponse struct, preferring placement in s's segment.
-func (s Conmon_createContainer_Results) NewResponse() (Conmon_CreateContainerResponse, error) {
-	ss, err := NewConmon_CreateContainerResponse(capnp.Struct(s).Segment())
+// allocated Conmon_WaitContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_waitContainer_Results) NewResponse() (Conmon_WaitContainerResponse, error) {
+	ss, err := NewConmon_WaitContainerResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_CreateContainerResponse{}, err
+		return Conmon_WaitContainerResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_createContainer_Results_List is a list of Conmon_createContainer_Results.
-type Conmon_createContainer_Results_List = capnp.StructList[Conmon_createContainer_Results]
+// Conmon_waitContainer_Results_List is a list of Conmon_waitContainer_Results.
+type Conmon_waitContainer_Results_List = capnp.StructList[Conmon_waitContainer_Results]
 
-// NewConmon_createContainer_Results creates a new list of Conmon_createContainer_Results.
-func NewConmon_createContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Results_List, error) {
+// NewConmon_waitContainer_Results creates a new list of Conmon_waitContainer_Results.
+func NewConmon_waitContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_waitContainer_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_createContainer_Results](l), err
+	return capnp.StructList[Conmon_waitContainer_Results](l), err
 }
 
-// Conmon_createContainer_Results_Future is a wrapper for a Conmon_createContainer_Results promised by a client call.
-type Conmon_createContainer_Results_Future struct{ *capnp.Future }
+// Conmon_waitContainer_Results_Future is a wrapper for a Conmon_waitContainer_Results promised by a client call.
+type Conmon_waitContainer_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_createContainer_Results_Future) Struct() (Conmon_createContainer_Results, error) {
+func (p Conmon_waitContainer_Results_Future) Struct() (Conmon_waitContainer_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_createContainer_Results(s), err
+	return Conmon_waitContainer_Results(s), err
 }
 
-func (p Conmon_createContainer_Results_Future) Response() Conmon_CreateContainerResponse_Future {
-	return Conmon_CreateContainerResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_waitContainer_Results_Future) Response() Conmon_WaitContainerResponse_Future {
+	return Conmon_WaitContainerResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_execSyncContainer_Params capnp.Struct
+type Conmon_shutdown_Params capnp.Struct
 
-// Conmon_execSyncContainer_Params_TypeID is the unique identifier for the type Conmon_execSyncContainer_Params.
-const Conmon_execSyncContainer_Params_TypeID = 0x83479da67279e173
+// Conmon_shutdown_Params_TypeID is the unique identifier for the type Conmon_shutdown_Params.
+const Conmon_shutdown_Params_TypeID = 0x8709f75f61f7fec9
 
-func NewConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+func NewConmon_shutdown_Params(s *capnp.Segment) (Conmon_shutdown_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Params(st), err
+	return Conmon_shutdown_Params(st), err
 }
 
-func NewRootConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+func NewRootConmon_shutdown_Params(s *capnp.Segment) (Conmon_shutdown_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Params(st), err
+	return Conmon_shutdown_Params(st), err
 }
 
-func ReadRootConmon_execSyncContainer_Params(msg *capnp.Message) (Conmon_execSyncContainer_Params, error) {
+func ReadRootConmon_shutdown_Params(msg *capnp.Message) (Conmon_shutdown_Params, error) {
 	root, err := msg.Root()
-	return Conmon_execSyncContainer_Params(root.Struct()), err
+	return Conmon_shutdown_Params(root.Struct()), err
 }
 
-func (s Conmon_execSyncContainer_Params) String() string {
-	str, _ := text.Marshal(0x83479da67279e173, capnp.Struct(s))
+func (s Conmon_shutdown_Params) String() string {
+	str, _ := text.Marshal(0x8709f75f61f7fec9, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_execSyncContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_shutdown_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_execSyncContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_execSyncContainer_Params {
-	return Conmon_execSyncContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_shutdown_Params) DecodeFromPtr(p capnp.Ptr) Conmon_shutdown_Params {
+	return Conmon_shutdown_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_execSyncContainer_Params) ToPtr() capnp.Ptr {
+func (s Conmon_shutdown_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_execSyncContainer_Params) IsValid() bool {
+func (s Conmon_shutdown_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_execSyncContainer_Params) Message() *capnp.Message {
+func (s Conmon_shutdown_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_execSyncContainer_Params) Segment() *capnp.Segment {
+func (s Conmon_shutdown_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_execSyncContainer_Params) Request() (Conmon_ExecSyncContainerRequest, error) {
+func (s Conmon_shutdown_Params) Request() (Conmon_ShutdownRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_ExecSyncContainerRequest(p.Struct()), err
+	return Conmon_ShutdownRequest(p.Struct()), err
 }
 
-func (s Conmon_execSyncContainer_Params) HasRequest() bool {
+func (s Conmon_shutdown_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_execSyncContainer_Params) SetRequest(v Conmon_ExecSyncContainerRequest) error {
+func (s Conmon_shutdown_Params) SetRequest(v Conmon_ShutdownRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_ExecSyncContainerRequest struct, preferring placement in s's segment.
-func (s Conmon_execSyncContainer_Params) NewRequest() (Conmon_ExecSyncContainerRequest, error) {
-	ss, err := NewConmon_ExecSyncContainerRequest(capnp.Struct(s).Segment())
+// allocated Conmon_ShutdownRequest struct, preferring placement in s's segment.
+func (s Conmon_shutdown_Params) NewRequest() (Conmon_ShutdownRequest, error) {
+	ss, err := NewConmon_ShutdownRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_ExecSyncContainerRequest{}, err
+		return Conmon_ShutdownRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_execSyncContainer_Params_List is a list of Conmon_execSyncContainer_Params.
-type Conmon_execSyncContainer_Params_List = capnp.StructList[Conmon_execSyncContainer_Params]
+// Conmon_shutdown_Params_List is a list of Conmon_shutdown_Params.
+type Conmon_shutdown_Params_List = capnp.StructList[Conmon_shutdown_Params]
 
-// NewConmon_execSyncContainer_Params creates a new list of Conmon_execSyncContainer_Params.
-func NewConmon_execSyncContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Params_List, error) {
+// NewConmon_shutdown_Params creates a new list of Conmon_shutdown_Params.
+func NewConmon_shutdown_Params_List(s *capnp.Segment, sz int32) (Conmon_shutdown_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_execSyncContainer_Params](l), err
+	return capnp.StructList[Conmon_shutdown_Params](l), err
 }
 
-// Conmon_execSyncContainer_Params_Future is a wrapper for a Conmon_execSyncContainer_Params promised by a client call.
-type Conmon_execSyncContainer_Params_Future struct{ *capnp.Future }
+// Conmon_shutdown_Params_Future is a wrapper for a Conmon_shutdown_Params promised by a client call.
+type Conmon_shutdown_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_execSyncContainer_Params_Future) Struct() (Conmon_execSyncContainer_Params, error) {
+func (p Conmon_shutdown_Params_Future) Struct() (Conmon_shutdown_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_execSyncContainer_Params(s), err
+	return Conmon_shutdown_Params(s), err
 }
 
-func (p Conmon_execSyncContainer_Params_Future) Request() Conmon_ExecSyncContainerRequest_Future {
-	return Conmon_ExecSyncContainerRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_shutdown_Params_Future) Request() Conmon_ShutdownRequest_Future {
+	return Conmon_ShutdownRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_execSyncContainer_Results capnp.Struct
+type Conmon_shutdown_Results capnp.Struct
 
-// Conmon_execSyncContainer_Results_TypeID is the unique identifier for the type Conmon_execSyncContainer_Results.
-const Conmon_execSyncContainer_Results_TypeID = 0xf8e86a5c0baa01bc
+// Conmon_shutdown_Results_TypeID is the unique identifier for the type Conmon_shutdown_Results.
+const Conmon_shutdown_Results_TypeID = 0xe5a25a689d86a6a8
 
-func NewConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+func NewConmon_shutdown_Results(s *capnp.Segment) (Conmon_shutdown_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Results(st), err
+	return Conmon_shutdown_Results(st), err
 }
 
-func NewRootConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+func NewRootConmon_shutdown_Results(s *capnp.Segment) (Conmon_shutdown_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Results(st), err
+	return Conmon_shutdown_Results(st), err
 }
 
-func ReadRootConmon_execSyncContainer_Results(msg *capnp.Message) (Conmon_execSyncContainer_Results, error) {
+func ReadRootConmon_shutdown_Results(msg *capnp.Message) (Conmon_shutdown_Results, error) {
 	root, err := msg.Root()
-	return Conmon_execSyncContainer_Results(root.Struct()), err
+	return Conmon_shutdown_Results(root.Struct()), err
 }
 
-func (s Conmon_execSyncContainer_Results) String() string {
-	str, _ := text.Marshal(0xf8e86a5c0baa01bc, capnp.Struct(s))
+func (s Conmon_shutdown_Results) String() string {
+	str, _ := text.Marshal(0xe5a25a689d86a6a8, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_execSyncContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_shutdown_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_execSyncContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_execSyncContainer_Results {
-	return Conmon_execSyncContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_shutdown_Results) DecodeFromPtr(p capnp.Ptr) Conmon_shutdown_Results {
+	return Conmon_shutdown_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_execSyncContainer_Results) ToPtr() capnp.Ptr {
+func (s Conmon_shutdown_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_execSyncContainer_Results) IsValid() bool {
+func (s Conmon_shutdown_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_execSyncContainer_Results) Message() *capnp.Message {
+func (s Conmon_shutdown_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_execSyncContainer_Results) Segment() *capnp.Segment {
+func (s Conmon_shutdown_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_execSyncContainer_Results) Response() (Conmon_ExecSyncContainerResponse, error) {
+func (s Conmon_shutdown_Results) Response() (Conmon_ShutdownResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_ExecSyncContainerResponse(p.Struct()), err
+	return Conmon_ShutdownResponse(p.Struct()), err
 }
 
-func (s Conmon_execSyncContainer_Results) HasResponse() bool {
+func (s Conmon_shutdown_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_execSyncContainer_Results) SetResponse(v Conmon_ExecSyncContainerResponse) error {
+func (s Conmon_shutdown_Results) SetResponse(v Conmon_ShutdownResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_ExecSyncContainerResponse struct, preferring placement in s's segment.
-func (s Conmon_execSyncContainer_Results) NewResponse() (Conmon_ExecSyncContainerResponse, error) {
-	ss, err := NewConmon_ExecSyncContainerResponse(capnp.Struct(s).Segment())
+// allocated Conmon_ShutdownResponse struct, preferring placement in s's segment.
+func (s Conmon_shutdown_Results) NewResponse() (Conmon_ShutdownResponse, error) {
+	ss, err := NewConmon_ShutdownResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_ExecSyncContainerResponse{}, err
+		return Conmon_ShutdownResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_execSyncContainer_Results_List is a list of Conmon_execSyncContainer_Results.
-type Conmon_execSyncContainer_Results_List = capnp.StructList[Conmon_execSyncContainer_Results]
+// Conmon_shutdown_Results_List is a list of Conmon_shutdown_Results.
+type Conmon_shutdown_Results_List = capnp.StructList[Conmon_shutdown_Results]
 
-// NewConmon_execSyncContainer_Results creates a new list of Conmon_execSyncContainer_Results.
-func NewConmon_execSyncContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Results_List, error) {
+// NewConmon_shutdown_Results creates a new list of Conmon_shutdown_Results.
+func NewConmon_shutdown_Results_List(s *capnp.Segment, sz int32) (Conmon_shutdown_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_execSyncContainer_Results](l), err
+	return capnp.StructList[Conmon_shutdown_Results](l), err
 }
 
-// Conmon_execSyncContainer_Results_Future is a wrapper for a Conmon_execSyncContainer_Results promised by a client call.
-type Conmon_execSyncContainer_Results_Future struct{ *capnp.Future }
+// Conmon_shutdown_Results_Future is a wrapper for a Conmon_shutdown_Results promised by a client call.
+type Conmon_shutdown_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_execSyncContainer_Results_Future) Struct() (Conmon_execSyncContainer_Results, error) {
+func (p Conmon_shutdown_Results_Future) Struct() (Conmon_shutdown_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_execSyncContainer_Results(s), err
+	return Conmon_shutdown_Results(s), err
 }
 
-func (p Conmon_execSyncContainer_Results_Future) Response() Conmon_ExecSyncContainerResponse_Future {
-	return Conmon_ExecSyncContainerResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_shutdown_Results_Future) Response() Conmon_ShutdownResponse_Future {
+	return Conmon_ShutdownResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_attachContainer_Params capnp.Struct
+type Conmon_reloadConfig_Params capnp.Struct
 
-// Conmon_attachContainer_Params_TypeID is the unique identifier for the type Conmon_attachContainer_Params.
-const Conmon_attachContainer_Params_TypeID = 0xaa2f3c8ad1c3af24
+// Conmon_reloadConfig_Params_TypeID is the unique identifier for the type Conmon_reloadConfig_Params.
+const Conmon_reloadConfig_Params_TypeID = 0x9b41a13e97cb6558
 
-func NewConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+func NewConmon_reloadConfig_Params(s *capnp.Segment) (Conmon_reloadConfig_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Params(st), err
+	return Conmon_reloadConfig_Params(st), err
 }
 
-func NewRootConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+func NewRootConmon_reloadConfig_Params(s *capnp.Segment) (Conmon_reloadConfig_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Params(st), err
+	return Conmon_reloadConfig_Params(st), err
 }
 
-func ReadRootConmon_attachContainer_Params(msg *capnp.Message) (Conmon_attachContainer_Params, error) {
+func ReadRootConmon_reloadConfig_Params(msg *capnp.Message) (Conmon_reloadConfig_Params, error) {
 	root, err := msg.Root()
-	return Conmon_attachContainer_Params(root.Struct()), err
+	return Conmon_reloadConfig_Params(root.Struct()), err
 }
 
-func (s Conmon_attachContainer_Params) String() string {
-	str, _ := text.Marshal(0xaa2f3c8ad1c3af24, capnp.Struct(s))
+func (s Conmon_reloadConfig_Params) String() string {
+	str, _ := text.Marshal(0x9b41a13e97cb6558, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_attachContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_reloadConfig_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_attachContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_attachContainer_Params {
-	return Conmon_attachContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_reloadConfig_Params) DecodeFromPtr(p capnp.Ptr) Conmon_reloadConfig_Params {
+	return Conmon_reloadConfig_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_attachContainer_Params) ToPtr() capnp.Ptr {
+func (s Conmon_reloadConfig_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_attachContainer_Params) IsValid() bool {
+func (s Conmon_reloadConfig_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_attachContainer_Params) Message() *capnp.Message {
+func (s Conmon_reloadConfig_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_attachContainer_Params) Segment() *capnp.Segment {
+func (s Conmon_reloadConfig_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_attachContainer_Params) Request() (Conmon_AttachRequest, error) {
+func (s Conmon_reloadConfig_Params) Request() (Conmon_ReloadConfigRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_AttachRequest(p.Struct()), err
+	return Conmon_ReloadConfigRequest(p.Struct()), err
 }
 
-func (s Conmon_attachContainer_Params) HasRequest() bool {
+func (s Conmon_reloadConfig_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_attachContainer_Params) SetRequest(v Conmon_AttachRequest) error {
+func (s Conmon_reloadConfig_Params) SetRequest(v Conmon_ReloadConfigRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_AttachRequest struct, preferring placement in s's segment.
-func (s Conmon_attachContainer_Params) NewRequest() (Conmon_AttachRequest, error) {
-	ss, err := NewConmon_AttachRequest(capnp.Struct(s).Segment())
+// allocated Conmon_ReloadConfigRequest struct, preferring placement in s's segment.
+func (s Conmon_reloadConfig_Params) NewRequest() (Conmon_ReloadConfigRequest, error) {
+	ss, err := NewConmon_ReloadConfigRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_AttachRequest{}, err
+		return Conmon_ReloadConfigRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_attachContainer_Params_List is a list of Conmon_attachContainer_Params.
-type Conmon_attachContainer_Params_List = capnp.StructList[Conmon_attachContainer_Params]
+// Conmon_reloadConfig_Params_List is a list of Conmon_reloadConfig_Params.
+type Conmon_reloadConfig_Params_List = capnp.StructList[Conmon_reloadConfig_Params]
 
-// NewConmon_attachContainer_Params creates a new list of Conmon_attachContainer_Params.
-func NewConmon_attachContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Params_List, error) {
+// NewConmon_reloadConfig_Params creates a new list of Conmon_reloadConfig_Params.
+func NewConmon_reloadConfig_Params_List(s *capnp.Segment, sz int32) (Conmon_reloadConfig_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_attachContainer_Params](l), err
+	return capnp.StructList[Conmon_reloadConfig_Params](l), err
 }
 
-// Conmon_attachContainer_Params_Future is a wrapper for a Conmon_attachContainer_Params promised by a client call.
-type Conmon_attachContainer_Params_Future struct{ *capnp.Future }
+// Conmon_reloadConfig_Params_Future is a wrapper for a Conmon_reloadConfig_Params promised by a client call.
+type Conmon_reloadConfig_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_attachContainer_Params_Future) Struct() (Conmon_attachContainer_Params, error) {
+func (p Conmon_reloadConfig_Params_Future) Struct() (Conmon_reloadConfig_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_attachContainer_Params(s), err
+	return Conmon_reloadConfig_Params(s), err
 }
 
-func (p Conmon_attachContainer_Params_Future) Request() Conmon_AttachRequest_Future {
-	return Conmon_AttachRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_reloadConfig_Params_Future) Request() Conmon_ReloadConfigRequest_Future {
+	return Conmon_ReloadConfigRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_attachContainer_Results capnp.Struct
+type Conmon_reloadConfig_Results capnp.Struct
 
-// Conmon_attachContainer_Results_TypeID is the unique identifier for the type Conmon_attachContainer_Results.
-const Conmon_attachContainer_Results_TypeID = 0xc5e65eec3dcf5b10
+// Conmon_reloadConfig_Results_TypeID is the unique identifier for the type Conmon_reloadConfig_Results.
+const Conmon_reloadConfig_Results_TypeID = 0x96dcde632ac079f4
 
-func NewConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
+func NewConmon_reloadConfig_Results(s *capnp.Segment) (Conmon_reloadConfig_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Results(st), err
+	return Conmon_reloadConfig_Results(st), err
 }
 
-func NewRootConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
+func NewRootConmon_reloadConfig_Results(s *capnp.Segment) (Conmon_reloadConfig_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Results(st), err
+	return Conmon_reloadConfig_Results(st), err
 }
 
-func ReadRootConmon_attachContainer_Results(msg *capnp.Message) (Conmon_attachContainer_Results, error) {
+func ReadRootConmon_reloadConfig_Results(msg *capnp.Message) (Conmon_reloadConfig_Results, error) {
 	root, err := msg.Root()
-	return Conmon_attachContainer_Results(root.Struct()), err
+	return Conmon_reloadConfig_Results(root.Struct()), err
 }
 
-func (s Conmon_attachContainer_Results) String() string {
-	str, _ := text.Marshal(0xc5e65eec3dcf5b10, capnp.Struct(s))
+func (s Conmon_reloadConfig_Results) String() string {
+	str, _ := text.Marshal(0x96dcde632ac079f4, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_attachContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_reloadConfig_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_attachContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_attachContainer_Results {
-	return Conmon_attachContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_reloadConfig_Results) DecodeFromPtr(p capnp.Ptr) Conmon_reloadConfig_Results {
+	return Conmon_reloadConfig_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_attachContainer_Results) ToPtr() capnp.Ptr {
+func (s Conmon_reloadConfig_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_attachContainer_Results) IsValid() bool {
+func (s Conmon_reloadConfig_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_attachContainer_Results) Message() *capnp.Message {
+func (s Conmon_reloadConfig_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_attachContainer_Results) Segment() *capnp.Segment {
+func (s Conmon_reloadConfig_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_attachContainer_Results) Response() (Conmon_AttachResponse, error) {
+func (s Conmon_reloadConfig_Results) Response() (Conmon_ReloadConfigResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_AttachResponse(p.Struct()), err
+	return Conmon_ReloadConfigResponse(p.Struct()), err
 }
 
-func (s Conmon_attachContainer_Results) HasResponse() bool {
+func (s Conmon_reloadConfig_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_attachContainer_Results) SetResponse(v Conmon_AttachResponse) error {
+func (s Conmon_reloadConfig_Results) SetResponse(v Conmon_ReloadConfigResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_AttachResponse struct, preferring placement in s's segment.
-func (s Conmon_attachContainer_Results) NewResponse() (Conmon_AttachResponse, error) {
-	ss, err := NewConmon_AttachResponse(capnp.Struct(s).Segment())
+// allocated Conmon_ReloadConfigResponse struct, preferring placement in s's segment.
+func (s Conmon_reloadConfig_Results) NewResponse() (Conmon_ReloadConfigResponse, error) {
+	ss, err := NewConmon_ReloadConfigResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_AttachResponse{}, err
+		return Conmon_ReloadConfigResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_attachContainer_Results_List is a list of Conmon_attachContainer_Results.
-type Conmon_attachContainer_Results_List = capnp.StructList[Conmon_attachContainer_Results]
+// Conmon_reloadConfig_Results_List is a list of Conmon_reloadConfig_Results.
+type Conmon_reloadConfig_Results_List = capnp.StructList[Conmon_reloadConfig_Results]
 
-// NewConmon_attachContainer_Results creates a new list of Conmon_attachContainer_Results.
-func NewConmon_attachContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Results_List, error) {
+// NewConmon_reloadConfig_Results creates a new list of Conmon_reloadConfig_Results.
+func NewConmon_reloadConfig_Results_List(s *capnp.Segment, sz int32) (Conmon_reloadConfig_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_attachContainer_Results](l), err
+	return capnp.StructList[Conmon_reloadConfig_Results](l), err
 }
 
-// Conmon_attachContainer_Results_Future is a wrapper for a Conmon_attachContainer_Results promised by a client call.
-type Conmon_attachContainer_Results_Future struct{ *capnp.Future }
+// Conmon_reloadConfig_Results_Future is a wrapper for a Conmon_reloadConfig_Results promised by a client call.
+type Conmon_reloadConfig_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_attachContainer_Results_Future) Struct() (Conmon_attachContainer_Results, error) {
+func (p Conmon_reloadConfig_Results_Future) Struct() (Conmon_reloadConfig_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_attachContainer_Results(s), err
+	return Conmon_reloadConfig_Results(s), err
 }
 
-func (p Conmon_attachContainer_Results_Future) Response() Conmon_AttachResponse_Future {
-	return Conmon_AttachResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_reloadConfig_Results_Future) Response() Conmon_ReloadConfigResponse_Future {
+	return Conmon_ReloadConfigResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_reopenLogContainer_Params capnp.Struct
+type Conmon_containerStatus_Params capnp.Struct
 
-// Conmon_reopenLogContainer_Params_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Params.
-const Conmon_reopenLogContainer_Params_TypeID = 0xe5ea916eb0c31336
+// Conmon_containerStatus_Params_TypeID is the unique identifier for the type Conmon_containerStatus_Params.
+const Conmon_containerStatus_Params_TypeID = 0xeedd21a69204efcb
 
-func NewConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+func NewConmon_containerStatus_Params(s *capnp.Segment) (Conmon_containerStatus_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Params(st), err
+	return Conmon_containerStatus_Params(st), err
 }
 
-func NewRootConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+func NewRootConmon_containerStatus_Params(s *capnp.Segment) (Conmon_containerStatus_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Params(st), err
+	return Conmon_containerStatus_Params(st), err
 }
 
-func ReadRootConmon_reopenLogContainer_Params(msg *capnp.Message) (Conmon_reopenLogContainer_Params, error) {
+func ReadRootConmon_containerStatus_Params(msg *capnp.Message) (Conmon_containerStatus_Params, error) {
 	root, err := msg.Root()
-	return Conmon_reopenLogContainer_Params(root.Struct()), err
+	return Conmon_containerStatus_Params(root.Struct()), err
 }
 
-func (s Conmon_reopenLogContainer_Params) String() string {
-	str, _ := text.Marshal(0xe5ea916eb0c31336, capnp.Struct(s))
+func (s Conmon_containerStatus_Params) String() string {
+	str, _ := text.Marshal(0xeedd21a69204efcb, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_reopenLogContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_containerStatus_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_reopenLogContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_reopenLogContainer_Params {
-	return Conmon_reopenLogContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_containerStatus_Params) DecodeFromPtr(p capnp.Ptr) Conmon_containerStatus_Params {
+	return Conmon_containerStatus_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_reopenLogContainer_Params) ToPtr() capnp.Ptr {
+func (s Conmon_containerStatus_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_reopenLogContainer_Params) IsValid() bool {
+func (s Conmon_containerStatus_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_reopenLogContainer_Params) Message() *capnp.Message {
+func (s Conmon_containerStatus_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_reopenLogContainer_Params) Segment() *capnp.Segment {
+func (s Conmon_containerStatus_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_reopenLogContainer_Params) Request() (Conmon_ReopenLogRequest, error) {
+func (s Conmon_containerStatus_Params) Request() (Conmon_ContainerStatusRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_ReopenLogRequest(p.Struct()), err
+	return Conmon_ContainerStatusRequest(p.Struct()), err
 }
 
-func (s Conmon_reopenLogContainer_Params) HasRequest() bool {
+func (s Conmon_containerStatus_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_reopenLogContainer_Params) SetRequest(v Conmon_ReopenLogRequest) error {
+func (s Conmon_containerStatus_Params) SetRequest(v Conmon_ContainerStatusRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_ReopenLogRequest struct, preferring placement in s's segment.
-func (s Conmon_reopenLogContainer_Params) NewRequest() (Conmon_ReopenLogRequest, error) {
-	ss, err := NewConmon_ReopenLogRequest(capnp.Struct(s).Segment())
+// allocated Conmon_ContainerStatusRequest struct, preferring placement in s's segment.
+func (s Conmon_containerStatus_Params) NewRequest() (Conmon_ContainerStatusRequest, error) {
+	ss, err := NewConmon_ContainerStatusRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_ReopenLogRequest{}, err
+		return Conmon_ContainerStatusRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_reopenLogContainer_Params_List is a list of Conmon_reopenLogContainer_Params.
-type Conmon_reopenLogContainer_Params_List = capnp.StructList[Conmon_reopenLogContainer_Params]
+// Conmon_containerStatus_Params_List is a list of Conmon_containerStatus_Params.
+type Conmon_containerStatus_Params_List = capnp.StructList[Conmon_containerStatus_Params]
 
-// NewConmon_reopenLogContainer_Params creates a new list of Conmon_reopenLogContainer_Params.
-func NewConmon_reopenLogContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Params_List, error) {
+// NewConmon_containerStatus_Params creates a new list of Conmon_containerStatus_Params.
+func NewConmon_containerStatus_Params_List(s *capnp.Segment, sz int32) (Conmon_containerStatus_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_reopenLogContainer_Params](l), err
+	return capnp.StructList[Conmon_containerStatus_Params](l), err
 }
 
-// Conmon_reopenLogContainer_Params_Future is a wrapper for a Conmon_reopenLogContainer_Params promised by a client call.
-type Conmon_reopenLogContainer_Params_Future struct{ *capnp.Future }
+// Conmon_containerStatus_Params_Future is a wrapper for a Conmon_containerStatus_Params promised by a client call.
+type Conmon_containerStatus_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_reopenLogContainer_Params_Future) Struct() (Conmon_reopenLogContainer_Params, error) {
+func (p Conmon_containerStatus_Params_Future) Struct() (Conmon_containerStatus_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_reopenLogContainer_Params(s), err
+	return Conmon_containerStatus_Params(s), err
 }
 
-func (p Conmon_reopenLogContainer_Params_Future) Request() Conmon_ReopenLogRequest_Future {
-	return Conmon_ReopenLogRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_containerStatus_Params_Future) Request() Conmon_ContainerStatusRequest_Future {
+	return Conmon_ContainerStatusRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_reopenLogContainer_Results capnp.Struct
+type Conmon_containerStatus_Results capnp.Struct
 
-// Conmon_reopenLogContainer_Results_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Results.
-const Conmon_reopenLogContainer_Results_TypeID = 0xa0ef8355b64ee985
+// Conmon_containerStatus_Results_TypeID is the unique identifier for the type Conmon_containerStatus_Results.
+const Conmon_containerStatus_Results_TypeID = 0xf2725610fbee91f4
 
-func NewConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
+func NewConmon_containerStatus_Results(s *capnp.Segment) (Conmon_containerStatus_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Results(st), err
+	return Conmon_containerStatus_Results(st), err
 }
 
-func NewRootConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
+func NewRootConmon_containerStatus_Results(s *capnp.Segment) (Conmon_containerStatus_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Results(st), err
+	return Conmon_containerStatus_Results(st), err
 }
 
-func ReadRootConmon_reopenLogContainer_Results(msg *capnp.Message) (Conmon_reopenLogContainer_Results, error) {
+func ReadRootConmon_containerStatus_Results(msg *capnp.Message) (Conmon_containerStatus_Results, error) {
 	root, err := msg.Root()
-	return Conmon_reopenLogContainer_Results(root.Struct()), err
+	return Conmon_containerStatus_Results(root.Struct()), err
 }
 
-func (s Conmon_reopenLogContainer_Results) String() string {
-	str, _ := text.Marshal(0xa0ef8355b64ee985, capnp.Struct(s))
+func (s Conmon_containerStatus_Results) String() string {
+	str, _ := text.Marshal(0xf2725610fbee91f4, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_reopenLogContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_containerStatus_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_reopenLogContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_reopenLogContainer_Results {
-	return Conmon_reopenLogContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_containerStatus_Results) DecodeFromPtr(p capnp.Ptr) Conmon_containerStatus_Results {
+	return Conmon_containerStatus_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_reopenLogContainer_Results) ToPtr() capnp.Ptr {
+func (s Conmon_containerStatus_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_reopenLogContainer_Results) IsValid() bool {
+func (s Conmon_containerStatus_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_reopenLogContainer_Results) Message() *capnp.Message {
+func (s Conmon_containerStatus_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_reopenLogContainer_Results) Segment() *capnp.Segment {
+func (s Conmon_containerStatus_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_reopenLogContainer_Results) Response() (Conmon_ReopenLogResponse, error) {
+func (s Conmon_containerStatus_Results) Response() (Conmon_ContainerStatusResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_ReopenLogResponse(p.Struct()), err
+	return Conmon_ContainerStatusResponse(p.Struct()), err
 }
 
-func (s Conmon_reopenLogContainer_Results) HasResponse() bool {
+func (s Conmon_containerStatus_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_reopenLogContainer_Results) SetResponse(v Conmon_ReopenLogResponse) error {
+func (s Conmon_containerStatus_Results) SetResponse(v Conmon_ContainerStatusResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_ReopenLogResponse struct, preferring placement in s's segment.
-func (s Conmon_reopenLogContainer_Results) NewResponse() (Conmon_ReopenLogResponse, error) {
-	ss, err := NewConmon_ReopenLogResponse(capnp.Struct(s).Segment())
+// allocated Conmon_ContainerStatusResponse struct, preferring placement in s's segment.
+func (s Conmon_containerStatus_Results) NewResponse() (Conmon_ContainerStatusResponse, error) {
+	ss, err := NewConmon_ContainerStatusResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_ReopenLogResponse{}, err
+		return Conmon_ContainerStatusResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_reopenLogContainer_Results_List is a list of Conmon_reopenLogContainer_Results.
-type Conmon_reopenLogContainer_Results_List = capnp.StructList[Conmon_reopenLogContainer_Results]
+// Conmon_containerStatus_Results_List is a list of Conmon_containerStatus_Results.
+type Conmon_containerStatus_Results_List = capnp.StructList[Conmon_containerStatus_Results]
 
-// NewConmon_reopenLogContainer_Results creates a new list of Conmon_reopenLogContainer_Results.
-func NewConmon_reopenLogContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Results_List, error) {
+// NewConmon_containerStatus_Results creates a new list of Conmon_containerStatus_Results.
+func NewConmon_containerStatus_Results_List(s *capnp.Segment, sz int32) (Conmon_containerStatus_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_reopenLogContainer_Results](l), err
+	return capnp.StructList[Conmon_containerStatus_Results](l), err
 }
 
-// Conmon_reopenLogContainer_Results_Future is a wrapper for a Conmon_reopenLogContainer_Results promised by a client call.
-type Conmon_reopenLogContainer_Results_Future struct{ *capnp.Future }
+// Conmon_containerStatus_Results_Future is a wrapper for a Conmon_containerStatus_Results promised by a client call.
+type Conmon_containerStatus_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_reopenLogContainer_Results_Future) Struct() (Conmon_reopenLogContainer_Results, error) {
+func (p Conmon_containerStatus_Results_Future) Struct() (Conmon_containerStatus_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_reopenLogContainer_Results(s), err
+	return Conmon_containerStatus_Results(s), err
 }
 
-func (p Conmon_reopenLogContainer_Results_Future) Response() Conmon_ReopenLogResponse_Future {
-	return Conmon_ReopenLogResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_containerStatus_Results_Future) Response() Conmon_ContainerStatusResponse_Future {
+	return Conmon_ContainerStatusResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_setWindowSizeContainer_Params capnp.Struct
+type Conmon_streamEvents_Params capnp.Struct
 
-// Conmon_setWindowSizeContainer_Params_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Params.
-const Conmon_setWindowSizeContainer_Params_TypeID = 0xc76ccd4502bb61e7
+// Conmon_streamEvents_Params_TypeID is the unique identifier for the type Conmon_streamEvents_Params.
+const Conmon_streamEvents_Params_TypeID = 0xd74ba8d601b5841e
 
-func NewConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
+func NewConmon_streamEvents_Params(s *capnp.Segment) (Conmon_streamEvents_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Params(st), err
+	return Conmon_streamEvents_Params(st), err
 }
 
-func NewRootConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
+func NewRootConmon_streamEvents_Params(s *capnp.Segment) (Conmon_streamEvents_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Params(st), err
+	return Conmon_streamEvents_Params(st), err
 }
 
-func ReadRootConmon_setWindowSizeContainer_Params(msg *capnp.Message) (Conmon_setWindowSizeContainer_Params, error) {
+func ReadRootConmon_streamEvents_Params(msg *capnp.Message) (Conmon_streamEvents_Params, error) {
 	root, err := msg.Root()
-	return Conmon_setWindowSizeContainer_Params(root.Struct()), err
+	return Conmon_streamEvents_Params(root.Struct()), err
 }
 
-func (s Conmon_setWindowSizeContainer_Params) String() string {
-	str, _ := text.Marshal(0xc76ccd4502bb61e7, capnp.Struct(s))
+func (s Conmon_streamEvents_Params) String() string {
+	str, _ := text.Marshal(0xd74ba8d601b5841e, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_setWindowSizeContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_streamEvents_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_setWindowSizeContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_setWindowSizeContainer_Params {
-	return Conmon_setWindowSizeContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_streamEvents_Params) DecodeFromPtr(p capnp.Ptr) Conmon_streamEvents_Params {
+	return Conmon_streamEvents_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_setWindowSizeContainer_Params) ToPtr() capnp.Ptr {
+func (s Conmon_streamEvents_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_setWindowSizeContainer_Params) IsValid() bool {
+func (s Conmon_streamEvents_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_setWindowSizeContainer_Params) Message() *capnp.Message {
+func (s Conmon_streamEvents_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_setWindowSizeContainer_Params) Segment() *capnp.Segment {
+func (s Conmon_streamEvents_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_setWindowSizeContainer_Params) Request() (Conmon_SetWindowSizeRequest, error) {
+func (s Conmon_streamEvents_Params) Request() (Conmon_StreamEventsRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_SetWindowSizeRequest(p.Struct()), err
+	return Conmon_StreamEventsRequest(p.Struct()), err
 }
 
-func (s Conmon_setWindowSizeContainer_Params) HasRequest() bool {
+func (s Conmon_streamEvents_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_setWindowSizeContainer_Params) SetRequest(v Conmon_SetWindowSizeRequest) error {
+func (s Conmon_streamEvents_Params) SetRequest(v Conmon_StreamEventsRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_SetWindowSizeRequest struct, preferring placement in s's segment.
-func (s Conmon_setWindowSizeContainer_Params) NewRequest() (Conmon_SetWindowSizeRequest, error) {
-	ss, err := NewConmon_SetWindowSizeRequest(capnp.Struct(s).Segment())
+// allocated Conmon_StreamEventsRequest struct, preferring placement in s's segment.
+func (s Conmon_streamEvents_Params) NewRequest() (Conmon_StreamEventsRequest, error) {
+	ss, err := NewConmon_StreamEventsRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_SetWindowSizeRequest{}, err
+		return Conmon_StreamEventsRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_setWindowSizeContainer_Params_List is a list of Conmon_setWindowSizeContainer_Params.
-type Conmon_setWindowSizeContainer_Params_List = capnp.StructList[Conmon_setWindowSizeContainer_Params]
+// Conmon_streamEvents_Params_List is a list of Conmon_streamEvents_Params.
+type Conmon_streamEvents_Params_List = capnp.StructList[Conmon_streamEvents_Params]
 
-// NewConmon_setWindowSizeContainer_Params creates a new list of Conmon_setWindowSizeContainer_Params.
-func NewConmon_setWindowSizeContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Params_List, error) {
+// NewConmon_streamEvents_Params creates a new list of Conmon_streamEvents_Params.
+func NewConmon_streamEvents_Params_List(s *capnp.Segment, sz int32) (Conmon_streamEvents_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_setWindowSizeContainer_Params](l), err
+	return capnp.StructList[Conmon_streamEvents_Params](l), err
 }
 
-// Conmon_setWindowSizeContainer_Params_Future is a wrapper for a Conmon_setWindowSizeContainer_Params promised by a client call.
-type Conmon_setWindowSizeContainer_Params_Future struct{ *capnp.Future }
+// Conmon_streamEvents_Params_Future is a wrapper for a Conmon_streamEvents_Params promised by a client call.
+type Conmon_streamEvents_Params_Future struct{ *capnp.Future }
 
-func (p Conmon_setWindowSizeContainer_Params_Future) Struct() (Conmon_setWindowSizeContainer_Params, error) {
+func (p Conmon_streamEvents_Params_Future) Struct() (Conmon_streamEvents_Params, error) {
 	s, err := p.Future.Struct()
-	return Conmon_setWindowSizeContainer_Params(s), err
+	return Conmon_streamEvents_Params(s), err
 }
 
-func (p Conmon_setWindowSizeContainer_Params_Future) Request() Conmon_SetWindowSizeRequest_Future {
-	return Conmon_SetWindowSizeRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_streamEvents_Params_Future) Request() Conmon_StreamEventsRequest_Future {
+	return Conmon_StreamEventsRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_setWindowSizeContainer_Results capnp.Struct
+type Conmon_streamEvents_Results capnp.Struct
 
-// Conmon_setWindowSizeContainer_Results_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Results.
-const Conmon_setWindowSizeContainer_Results_TypeID = 0xe00e522611477055
+// Conmon_streamEvents_Results_TypeID is the unique identifier for the type Conmon_streamEvents_Results.
+const Conmon_streamEvents_Results_TypeID = 0xdf59f911433f86e4
 
-func NewConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+func NewConmon_streamEvents_Results(s *capnp.Segment) (Conmon_streamEvents_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Results(st), err
+	return Conmon_streamEvents_Results(st), err
 }
 
-func NewRootConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+func NewRootConmon_streamEvents_Results(s *capnp.Segment) (Conmon_streamEvents_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Results(st), err
+	return Conmon_streamEvents_Results(st), err
 }
 
-func ReadRootConmon_setWindowSizeContainer_Results(msg *capnp.Message) (Conmon_setWindowSizeContainer_Results, error) {
+func ReadRootConmon_streamEvents_Results(msg *capnp.Message) (Conmon_streamEvents_Results, error) {
 	root, err := msg.Root()
-	return Conmon_setWindowSizeContainer_Results(root.Struct()), err
+	return Conmon_streamEvents_Results(root.Struct()), err
 }
 
-func (s Conmon_setWindowSizeContainer_Results) String() string {
-	str, _ := text.Marshal(0xe00e522611477055, capnp.Struct(s))
+func (s Conmon_streamEvents_Results) String() string {
+	str, _ := text.Marshal(0xdf59f911433f86e4, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_setWindowSizeContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_streamEvents_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_setWindowSizeContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_setWindowSizeContainer_Results {
-	return Conmon_setWindowSizeContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_streamEvents_Results) DecodeFromPtr(p capnp.Ptr) Conmon_streamEvents_Results {
+	return Conmon_streamEvents_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_setWindowSizeContainer_Results) ToPtr() capnp.Ptr {
+func (s Conmon_streamEvents_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_setWindowSizeContainer_Results) IsValid() bool {
+func (s Conmon_streamEvents_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_setWindowSizeContainer_Results) Message() *capnp.Message {
+func (s Conmon_streamEvents_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_setWindowSizeContainer_Results) Segment() *capnp.Segment {
+func (s Conmon_streamEvents_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_setWindowSizeContainer_Results) Response() (Conmon_SetWindowSizeResponse, error) {
+func (s Conmon_streamEvents_Results) Response() (Conmon_StreamEventsResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_SetWindowSizeResponse(p.Struct()), err
+	return Conmon_StreamEventsResponse(p.Struct()), err
 }
 
-func (s Conmon_setWindowSizeContainer_Results) HasResponse() bool {
+func (s Conmon_streamEvents_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_setWindowSizeContainer_Results) SetResponse(v Conmon_SetWindowSizeResponse) error {
+func (s Conmon_streamEvents_Results) SetResponse(v Conmon_StreamEventsResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_SetWindowSizeResponse struct, preferring placement in s's segment.
-func (s Conmon_setWindowSizeContainer_Results) NewResponse() (Conmon_SetWindowSizeResponse, error) {
-	ss, err := NewConmon_SetWindowSizeResponse(capnp.Struct(s).Segment())
+// allocated Conmon_StreamEventsResponse struct, preferring placement in s's segment.
+func (s Conmon_streamEvents_Results) NewResponse() (Conmon_StreamEventsResponse, error) {
+	ss, err := NewConmon_StreamEventsResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_SetWindowSizeResponse{}, err
+		return Conmon_StreamEventsResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_setWindowSizeContainer_Results_List is a list of Conmon_setWindowSizeContainer_Results.
-type Conmon_setWindowSizeContainer_Results_List = capnp.StructList[Conmon_setWindowSizeContainer_Results]
+// Conmon_streamEvents_Results_List is a list of Conmon_streamEvents_Results.
+type Conmon_streamEvents_Results_List = capnp.StructList[Conmon_streamEvents_Results]
 
-// NewConmon_setWindowSizeContainer_Results creates a new list of Conmon_setWindowSizeContainer_Results.
-func NewConmon_setWindowSizeContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Results_List, error) {
+// NewConmon_streamEvents_Results creates a new list of Conmon_streamEvents_Results.
+func NewConmon_streamEvents_Results_List(s *capnp.Segment, sz int32) (Conmon_streamEvents_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_setWindowSizeContainer_Results](l), err
+	return capnp.StructList[Conmon_streamEvents_Results](l), err
 }
 
-// Conmon_setWindowSizeContainer_Results_Future is a wrapper for a Conmon_setWindowSizeContainer_Results promised by a client call.
-type Conmon_setWindowSizeContainer_Results_Future struct{ *capnp.Future }
+// Conmon_streamEvents_Results_Future is a wrapper for a Conmon_streamEvents_Results promised by a client call.
+type Conmon_streamEvents_Results_Future struct{ *capnp.Future }
 
-func (p Conmon_setWindowSizeContainer_Results_Future) Struct() (Conmon_setWindowSizeContainer_Results, error) {
+func (p Conmon_streamEvents_Results_Future) Struct() (Conmon_streamEvents_Results, error) {
 	s, err := p.Future.Struct()
-	return Conmon_setWindowSizeContainer_Results(s), err
-}
-
-func (p Conmon_setWindowSizeContainer_Results_Future) Response() Conmon_SetWindowSizeResponse_Future {
-	return Conmon_SetWindowSizeResponse_Future{Future: p.Future.Field(0, nil)}
-}
-
-const schema_ffaaf7385bc4adad = "x\xda\xacX\x7f\x8cTW\xf5?\xe7\xdey\xfb\xd8\xee" +
-	"\xce\x8f\xdb\xb7\xcd\x97n\xbed\x95@\x13@\xbb\x94i" +
-	"\xd5\x10\x9a]X\x08n\xa5:w\x96J\xf8\x99>f" +
-	"nw\x87\xee\xbc7\xfb\xde\x1b`\xa9\x04h%&h" +
-	"Z\xdb\xd4\xe8\x12I@KS\x10\x84ZA\xc4\xa6)" +
-	"\x16\xd2\x8aE]\xfe\xd0\xb4\x89Z\xa4X$Z5\xd6" +
-	"\xb8\x18\xf4\x99\xfb\xde\xbe\x1f3\xbb\xa6\xfb\xc3\xffv\xee" +
-	"\xfb\xdcs\xcf9\xf7|>\xe7\xdc]\xb8O\xe9L\xdc" +
-	"\x93\\s\x1b\x10\xbe]ip\xed+\x83\xd6\xf3\x07V" +
-	">\x01l\x01\x02(\xa8\x02d\x992\x9b\x00j\xf3\x94" +
-	"\x0e@w\xef\x1f>{\xfa\xa1'\xfe|0\x0e\xe8V" +
-	"\x16I\x80\xee\x01\x86\xd6_\x7ftEw\xfa[\x12\xe0" +
-	"\xfe5\xfb\xc8\xaf\x87\xae\x7f\xf2\x07\x90\x90\xb8\xbd\xca\x0d" +
-	"\xd4\x0e)*Pw\xce\x89\xd7\x86\xf7-i?\x1a7" +
-	"\xb3S\xb9]\x9a\xf9\x9agf\xdb\xc3o\x9c\xd8\xc1\xaf" +
-	"\x1d\x1b\xc7\xcc)\xe52j\xc3\x9e\x99{\x0f\xbdt\xfa" +
-	"\xc9\xf7\xb7\x7f\x17\xf8\x02$\x11\xcc\xb7w\\9\x8a\xda" +
-	"\x05\xe5\xff\x00\xb4K\xca\x09@\xf7\xb1\xe1\x1b/<\xf9" +
-	"\xe5\xa5\xa7$\x1a\xeb\xd1\xa2\x81\x10mO\x83\x0a\xa0\xed" +
-	"l\x90\xe8\xf0;\x9bC\xdd\xe3\xc7\xcf\xaf\xff\xd4?\x8e" +
-	"\xba\x00\x98\xbdC]\x87\xda<\xf5u\x80lr\xc6\x97" +
-	"P\x9b\xd5\xa8\x02\xb8\x17O\x1fY\xfc\xcf\xab\xdb\xce\xd6" +
-	"\x1b\x97\x16\xb3J\xe3\xedD\x9b+q\xd9\x8f6>\x85" +
-	"\x80nf\xfd\xcf\xef\xff\xe3\xa6\xdf_\x88'\x00\x9bZ" +
-	"e\x02\xeel\x92\x09xO\xff\x11Yq\xa9\xff\xf58" +
-	"\xe0\xfe\xa6\x07$`\xad\x0fx\xf7\xdf[z+\xedo" +
-	"\xfa\x00/3\x83M\x97\x11\x12\xee\xc8\x1d\xaf|\xbdu" +
-	"\xc9\xd9\x9f\xc5\xb7\x96}\xdb{\xbd\xad\xadK\x87\xefM" +
-	"\x1b+\x7fQ\x9b\\\x1fx\xa4\xe9w\xa8]h\x92y" +
-	"8\xe7\x81o\xed]\xb2{\xd6\xac_\xbeU\x1f\x18\x91" +
-	"\xe8+M\xf3\x89\x86\xcd\x12\xfd\xaf\xa6\xf7\x00\xdd\xfd\x0b" +
-	"\xb6U6m^\xfc\x9b:\xb4\xe7\xde[\xcd\xadD\xbb" +
-	"\xe9\x81\xff\xde\xec\x99^|\xeb\x95\x83K*\xbf\xad\xf3" +
-	"\x83J\xf0\x9d\xc9\x8b\xa8\xdd\x97\x94\xe0{\x92\xf2>\x1e" +
-	"\xaa\xacdw\xe5S\xef\xc4\xa3\x1aN\xe6eT\x7fJ" +
-	"Jk\x0b\x1f[ydSI\xbbZS\xbb\xa9\xb7\x11" +
-	"P\x9b\x9b\x92\x80Oh\xaf\x9d4\x9e\xbeq\xad\xa6v" +
-	"S\xf3\xbd\xda\xf5\x00\xe7\xd6gs\xbf\xbaz\xd7_\x80" +
-	"\xddG\xa2\xda\x02\xcc\xeeI]F\xed@J:3\x94" +
-	"j\x03t\x87\xdfo;\xf6\xd3k\x9f\xf9[}R\x14" +
-	"is(\xf56j\xa7$:\xfbbj\x8d\xbc\xed\xe7" +
-	"\x07\xbe\xfd\xd5\x91\xd9\xec\x83\xfa:\xf5r\xd8\x98\x99M" +
-	"\xb4y\x19i|nF\xe6\xf0\xcc\xfeg\x9f:\xbfh" +
-	"\xe5\x075\x810\x8f\x1c\xf3\x98\xf4\xf3e<\xda\xb4a" +
-	"\xcb\xf5\x91\x9a@\x98\x1f\x88\x07\x189\xf4\x9d\xec\xeeK" +
-	"/\xdd\x1c\x8f\x84\xec6\xa2\x1df*\xb4\xbb\x05\xd3(" +
-	"\x9b\xc6\xc7-\xd5n/\x98\xe5\xb2i\xb4W,\xd31" +
-	"\xdb\xfd\xf5\xbb\x0bz\xc5\xa8,\xee\xf2\x7f\x88\xed\xa2\xd0" +
-	"3h\x14\xbaL\xc3\xd1K\x86\xb0\xe6\xe4tK\xd5\xcb" +
-	"6O\xd0\x04@\x02\x01Xr\x19\x00\x9fA\x91\xb7\x10" +
-	"\xdce\x89\x81\xaa\xb0\x1d\xccD\xc1\x03b\x06pR\xc7" +
-	"Z\xc2\xac\x08c\x95\xd9\x1b\x9d\x9b\x17mv\xb5\xdf\xa9" +
-	"9\xf8\x01\x00\xdeL\x91\xcf$\xe8Z\xc2\xae\x98\x86-" +
-	"\x00\x003\x91\x1c\xd5\x1d\xde0\x81\xc3\xf3\xc1\xe1ya" +
-	"W\xd2\xd2f\x0e'\xe7\xbe\xee8z\xa1\xaf&gz" +
-	"\x19'\x90\xb3\x90\x19Sp{\xa9wh\xdeO\x03\xd6" +
-	"\xf8\xacL`\xfb*\xb3w\xb9\x95.m\x15\x16O`" +
-	"\x9c\x138?\xbdz\xb0\"xs\xe8\xfc\x8a\xf9\x00\xbc" +
-	"\x93\"_E\x10\xb1\x05\xe5Z\xb7\\[N\x91\xe7\x08" +
-	"2\x82-H\x00\xd8\x832\xcaOS\xe4\xab\x09\xa6\x9d" +
-	"\xc1\x8a\xc0td\x18\x10\xd3\x80\xe9\x8a\xee\xf4a3\x10" +
-	"l\x06\xdcU\xd6\xb7\xf7\x94v\x08l\x04\x82\x8d\x93\xac" +
-	"\x99\x1e\xe1\xac)\x19Es\x9b\xb4\x90\xf7S\x0a9\xc4" +
-	"\xb8\xe3\xad\xe38\xbeh<\xc7\x17G\x8e\xd3R1\xf0" +
-	"\xafm[\xa9\xe8\xf4\xa1\x0a\x04U\xc0\x8e>Q\xea\xed" +
-	"s\x82\x9f\xa1\xb3\x89\x0fs\x96\x9a\x06_\x8815a" +
-	"k\x1f\x8f\x1a\x09[{6\x12!\xb61\x1fi+\xdb" +
-	"\xf8\xe3\x88UL\xbf\x18i4+]\x8e\xa9\xea\x80\x15" +
-	"\xeb\xa3\x03;b\xba?\xb0/\xd6\xa8\xab\xcfD\xad\x91" +
-	"\x0d\x1e\x8d\xa9\xc7\xce\xef\xb9\x9f\x17\x96]2\x8d<\x0d" +
-	"h\xd5e\x09\xdd\x11aM\xe7;\xfc\x0c\xbb^\xdd\x94" +
-	"\xb6\x0a@\xcb\x0d0J\x00\x0a6\xaf\xa8\x97\x91\xe0~" +
-	"\xc0\x0d>\x91\xd8\xb7\xd1\x12v\x83\x92\x866\xff\xac\xf0" +
-	"w\x87o\xd7\x0d\xa8\x8a\xbd\x91\xc1\xf8Z`(\xa8\x0d" +
-	"\x0c\x8a#\xed\xd9\xab_\xb6\xdb<\xb3|&U\x00\xc2" +
-	"N\x8bA\x87a/.\x03\xc2\x0e\xab\x18i5\x06M" +
-	"\x97\x0d=\x0e\x84=\xad\"\x09\x87)\x0c\xf4\x9a\xed}" +
-	"\x06\x08\xdb\xa3b4\x00a0\x08\xb0\xaa\xdcWV1" +
-	"\x11\xf6)\x0c\x86-\xa6\xef\x07\xc26\xaa\xa8\x84c\x01" +
-	"\x06\xed\x90\xf1\xb3@\xd8\x83\xea\xae\xad\xfeEu\xa2[" +
-	"\x18\xcd>\x8e\xe6\x11:\xd1\x0d\xf4\x1b\x83\xec\xa2\xd5\x89" +
-	"n\xa0Oq\xa4\x15\xa6m\x14J\x85\x84\xda5)\xea" +
-	"2\x8d\x0e\x7fK'NV\x14\xeb\x0b\xc8\xbb0t$" +
-	"C\xff?d\xe8)\xc9\xd0\x93\x14\xf9\xcb\x04Y@\xd1" +
-	"\x1f\xae\x03\xe0g(\xf2\xf3\x04\x91\xf8\x0c='\xb5\xff" +
-	"U\x8a\xfcM\x82\x8c\x92\x16\xa4\x00\xec'y\x00\xfe\x06" +
-	"E\xfe.A\x96\xa0-\x98\x00`W\xb6\x00\xf0w(" +
-	"\xf2[\x04\x99\x92hA\x05\x80\xdd\x94&G(\xf6\xb4" +
-	" A\xd6\xa0\xb4`\x03\x80\xc6p\x1d@O\x06)\xf6" +
-	"|\x0ck\x98\xefn\xae\x1a\xc5~\x91\xd3\x81Fr\xe5" +
-	":\xc2*\x97\x0c\xbd_\xf6\x1c\x04\x82\xb2\xe7\x8b\xed%" +
-	"'\xa7;}\x806\xa6\x00s\x14=x\x0a\xd05\xcd" +
-	"\xf2\x0a\xf9\x15\xd2\xba\xd37\xe6k\x7f@$j\x85\xdf" +
-	"2\xf1q\xc4C\x15\xfa\x85nT+]@\xcb\xc5z" +
-	"\x0b\xd3iQyaW\xfb\xe9D\xdbk(/u}" +
-	"j\xc6\x04N\xb6\xe3:]\xd7#m\x80\x0fo\x92\xa1" +
-	"hM\xa1I\x8e\xb2%\xe8\xc8\x93JY\xa1\xb6\x80'" +
-	"\x99\xb2P\xc5\xa77\x91\x0cTUa{\xa4\x89\x9d\xda" +
-	"\x1a\xe5)^\xb3\x93\x09n\x1c\x85\x8e\x86\x1f\x9e\x09\xcf" +
-	"\xd2e\x84\x0fS\xe4\xfdQ\x0b-\xc9nY\xa4\xc8+" +
-	"\xb1\x16Z\x96\x8b}\x14\xb9#\x09\xfa\x11\x9f\xa0\x03r" +
-	"w\x85\"\xff\x02\xf1\x99\xd2e\x16\xbd\xfc$\x80`\x02" +
-	"\xb0\xc3v\x8af\xd5\xc1$\x10L\xfa?\x85e\x05?" +
-	"]\xa7T\x16\xc5\xcfU\x9d8\xdf\xa6%A\xf2\x82\xa8" +
-	"\x1fb,\x9d[b\x97X\x18\x05C\xda\xca\x95\x8a8" +
-	"\x03\x08\xce\x98\xe2`6\xda\xa0\xfe\xfbH\x12\x0a^\xf7" +
-	"\xbah\xfc`dT\xf1\xb8\x05\xc0s\x14\xf9\x86\xda[" +
-	"\xb6\xcd\xc2\xa3\xc2\xa9S&O\xf7\x85mC[\xc94" +
-	"\xba\xc7\x96\xc44\x88\xea\x95\xbd\x83\x13,\xfbp\xa8\x98" +
-	"\x06Y'G\xb4p\xaa\xfa\xdf\xbc;rz\xda\x9a\xd0" +
-	"{'\x9c\xb2\xa6\x10i0DYw\xaf\x1e\xac\xa0_" +
-	"\x8f\xde\xa5+\x97\x01\xc2\x1a$V\xbejH\x0et\x1b" +
-	"\x8e\xb0\x1e\xd1\x0b(&uJ0\xd3\xc5\xcb~f\x18" +
-	"\xd6\x90\x0c\xebY\x8a\xfc`\xac\x12\x0f\xcc\x06\xe0\xdf\xa0" +
-	"\xc8\x9f\x8bU\xe2!I\xedoR\xe4/HjS\x9f" +
-	"\xda\x87e\xef}\x8e\"?){o\xc2\xef\xbd\xc77" +
-	"\x03\xf0c\x14\xf9\x19\x82\xa8\xf8\xad\xf7\x94\x04~\x9f\"" +
-	"\x7f\x95`0\xbd\x04\xf5\xa9:zo\xf0w\x87\x8c\xa7" +
-	"\xe4\xc4:p\xa9\xbf\xb8\\w\x00E\xb8fUmG" +
-	"F\x05j\xcc\x88[\xb1\xcc\x82\xb0\xedn\xc0\xb1\x84\x9d" +
-	"\xa2\x16F\xb2\x1b\x93BI\xdd\x0d\x14y_$\x85b" +
-	"\xddxR\xb8lT\x0a\xbf(\xf3\xd5\xe9\xe7k\x8f\xac" +
-	"\xe0\xdd\x14\xf9Wj\xe9,\xef\xd7\xac:=@E!" +
-	"x\x07\xed\x92.\xeb\xc6\x98\x86?\xde\xfc1\x9d\x8e6" +
-	"\xe1wj\xf8X\x99\x02\xc7\xc6\xfeK!/\xec\xf4\xc4" +
-	"\x9f\xf6\xe1\xa3g\x0ag\xd7\xbd\x11\x03\xb39\xc4\xff\x04" +
-	"\x00\x00\xff\xff\xf42F&"
+	return Conmon_streamEvents_Results(s), err
+}
+
+func (p Conmon_streamEvents_Results_Future) Response() Conmon_StreamEventsResponse_Future {
+	return Conmon_StreamEventsResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+const schema_ffaaf7385bc4adad = "x\xda\xcd}\x7f\x9cMe\xfe\xf8y\xee\xbdc\x90i" +
+	"\xcc\x1eJ6;f\"F\x89\x99\xf1#\x13;\x8c\x86" +
+	"f2ef\x8c\"\xe4\xce\xdcc\xe6r\x7f\xb9?0" +
+	"\x980\x99\x8aV\xc5f\x8bR)Z\xaa)\x14-\x9b" +
+	"J\x9bB\xa9\xd8l\xa9l\x09\x956\x95JQ\x8d\xfb" +
+	"y\xbf\x9fs\x9f\xe7<\xe7\xcc\xb1\xdd{\xd8\xef\xeb\xfb" +
+	"\x17\xf7\xfd\xbc\xe7\xf9\xf9~\xde\xcf\xfb\xf7\xe9\xbd<k" +
+	"\xb0#;%u\xa8d+ocOj\x11\x1d\x9e\xd9" +
+	"\x18\x19\x9e\xbbd\xae\x94V`\x8b\xde\xd5\xe2\x81\xc3W" +
+	"\xcaIOI\x12\xc9\xf5f\x14\xdb\xe4\xc5\x19\xc9\x92$" +
+	"/\xca\xb8^\"\xd1\xefW\xbeY\xf3Z\xeb\xa7\xe7I" +
+	"i\x97\x91\xe8\xf1\xdcI\x07\x96}\xd1\xffy)\x89\x00" +
+	"F\xee\xda\x8cL\x9b\xbc\x93\"o\xcf\xc8\x07\xe4+\xe7" +
+	"\x0cp\xf5I)\xad7C>\x95\x91g\x93/\xcaD" +
+	"\xe4\xf6\x99\x88|\xe8\xc7w^~\xf6F\xb7\x01\xd9\x81" +
+	"\xb8\x85\x99\x9f\x12\xd9\x09\xb8\xf6h\xe8`m\xf0\xf1\x15" +
+	"\xc3oE,)\xd6\xd5\xa0\xccL\x9bD\xe4\x0a\xda\xcd" +
+	"\xc1[\x1exvg\xdb1\xb7\x9a\x8d\x19\xc9\xb4\xc1j" +
+	"\xe8\x98\x8b(\xf25\x9f\x0d\xba\xb5{z\xf2|@\xb6" +
+	"k\xc8\xb0\xee\xc6\xcc/\x89\xbc\x130\x89\xbc\x0d\xf1\xa3" +
+	"\xaf\xfd\xe3\xd7%o\xf7.\x9c/\x8e\xbb6\xf3w6" +
+	"\x8a\x80]\xed;1\xc9\xb1w\xf3\x02@\xb8\x8aDw" +
+	"E;\xeft?\xf7\xca\xc7R\x92\x0d\x11\x8f\xc0\x04\xe5" +
+	"\xa4Kp\\r\xc9t@\xde\xf2\xc9\xb8S\xc3\x1f<" +
+	"\xbf\xc1d\xad\xe3/\xc9\xb1\xc9u\x97\xe0Z_\xff%" +
+	"\xaf\xa1\xf8\x8e\xf1\x0dR\xe9e\xa4\xd9Z\xc6\\\xf23" +
+	"\x91#\xb4\xcf\xa9\xb4\xcfMK\\\xf6\xf9m\xf7Sl" +
+	"a1\xea\x0c\xf6]\xd2\xda&\x9f\xb8\xe4B\xf8o\xd3" +
+	"%\xe9\x04\xd0;\xbc\xb5n\xc4\x7f\xda\x1cn\x10\x17\x94" +
+	"\xd6\xb55.(\xab+.\xe8\xe1\x8d\x87\xbaO\xdd\xe0" +
+	"\xb9\x0d\xfbs\x18G/\xea\xba\x05N\xa4+\xf6\xe7\xee" +
+	"z7\xf6\xd7\xfa\xf4\xa1+\x8e\xad^\x7f\x9b\xd8_\xd3" +
+	"\xa5\x1d\xb1\xbf\xf6\xdd\xb0\xbf\x7f\xbf4a\xea{\xa3[" +
+	"\xdenv0\x03\xba\xc1\xf4\xc6t\xc3\xc5TP\xe4\x9d" +
+	"\xa7\x7fr\xde\xfcS\xab\xdb\xc5\xde\"\xdd>\x80\x81\xe4" +
+	"\x05\x14\xe1\xeb\x81\x97\xd6\xf6\xdf\xfe\xe5\xed8;[3" +
+	"B\xec\x06\xf4\xb2\x9dv\xb7\xad\xdb\xe7\x80-\x1fs\xa6" +
+	"?\xe4L\xbf\xc3l\xece\xdd\xe1p6wG\xe4\x8d" +
+	"\xdd\xb1\xeb\xee\xce\x82kR^\xf9\xeb\x1d\xe2\xd8G\xba" +
+	"\xd3\x9di\xa2\x08}n \xf3\xfe\xf2\xcf\xcd\x0b\xa5\xb4" +
+	"\xbe\xb6h\x9f\x95\xcfn\xba\xeb\xeb\x19O#\xd5t\xca" +
+	"\xeaa\x93\x07eaW\x03\xb2\xf0\xb6\x8c\xffp\xdf\x0d" +
+	"\xadZ~\xb0\x10g\xd9Z\x18\xd8\x8e}Vd\xfd\xce" +
+	"&G\x10;wj\xd6\x85v@?\xb1j\xc7\xa0\xfb" +
+	"\x16\x7f\xb3P\x1c\xf9\xc0\xe5_\xe2\xaa\x8f_\x8e#\x7f" +
+	"te\x8fI\x8f\xd8G\xdc)\"\xb4\xef\xf9)\"d" +
+	"\xf5D\x84\xcf\xeb\xc6~\xf6\xe0[7\xdfi~h=" +
+	";\xdadwO<\xb4\xa9=o\xc0C\xdbQ\xb2v" +
+	"\xe2\xcd\x87\x1a\xef4\xdb\x97\x83W\xc0\xd5O\xeaE\x89" +
+	"\xb6\x17\xf6}b\xe0\xba\xb2'\xbbm\xbc\xd3@\x8e\x94" +
+	"j\xfb\xf6z\x85\xc8%\x14\xb9\xa8\xd73\x80\xdc\xb3t" +
+	"\xc4\x9f\xd3\xdf=e\xda\xf3\xd1^p\x0d\x93z\xd3\x9e" +
+	"{c\xcf\x1fw[\xff\xbe\xbd\xef\x7f\xfe$.+\xa3" +
+	"\xf7X\xdc\xf1A\x14a\xd5\x90\xc9\xbb\x8a\xaa\xfe\xb0H" +
+	"J\x1bj\xd3\xd6\x08;>\xbe7Lr\x1e\xed\xaa\xae" +
+	"7\xee\xf8\xd3ww\xbba\x9b\xfd\x8f\x8bLn\xd6\xe2" +
+	"\xde\xb0\xfc\xf5\xbd\xf1f\xbd5\xe8\xadk\xd6\xcd\xce\xbc" +
+	"K\x1cpQoz\x9bW\xd3\x01/\xb8?\xb9\xdd\xb5" +
+	"-B\x80p\xb5-:\xc3\xfb\xd7\xeb^X\xb3q\x17" +
+	"\x0e\xb8\x13\x07<F\x07<J\x07<\xb4\xe1\x87\xfd\xdd" +
+	"\x1c-\xefn\xc6B\x9az\xef\"\xf2E\xd9\x94\xc1e" +
+	"\x0f\x97\x07\xe1\xff\xa2w^6\xa4\xb4\xf5\xd2\xc7\xee\x11" +
+	"\x07\xee\x9a]@W\x9a\x8d\x03\xffx\xb0\xfc\x8f\xbb~" +
+	"\xfa\xf0\x1e\xb3}\x1b\x9f]\x09\x8b\xa5=\xd6Q\xe4\x0b" +
+	"[l}\x7f\xe1M\xb6\xc5\x06dz\xe3Wd\xc3\x1d" +
+	"\xd8L\x917f#\x7f\xc8\xf2\xec(\xba\xf8\xbd;\xee" +
+	"\xd5]\xf8\x9c\x9f\x91v\xba\xe6`oO\xfb]O\x1d" +
+	"iu\xfb_D\x84\xa2\x1c\xcaZ\x9d\x14\xe1\x87\xda\x97" +
+	"{T\xfd\xfb#\x1dBC\x8e\x0d\x11\x96!\xc27\x8e" +
+	"\xf3\x1aw\x95\xb4\xba\xcfd\xea[s`\xef\x0f\xe4\xe0" +
+	"l\xf6\xd3\xbev)\xfd\x17\xdd\xbd\xf8\x95\xfb\xc4\xbeN" +
+	"\xe5\xe4`_i\xb9\x88\xb0r\xe3\x847\xfe\xd18~" +
+	"\xb9\x88\xd07\x97\xde\xc2\x12\x8ap\xa3\xf2\xc6}\x7f\\" +
+	"9\xe4\x01\x11\xc1\x9bK\xd73\x8f\"\\p\xd7\xec;" +
+	"\xf7\xf4\xfdF\x87\xb02\x97Nw#E\x08\xbd\xdb9" +
+	"\xa5e\xf8\x81\x07Mhe_.\xcc\xf7D.\xd0\xca" +
+	"\xe9.\x9f\xdf\xf9\xd7\xa1[\x1el\x8e\xb3'\x17\xee\xef" +
+	"q\xc4\x89\x96\xfd\xaea\xd4}e\xf5+\xc4\xa1v\xe7" +
+	"R\xe6w\x84\x0e5z\xd4a\xe5\xd43_>d\xca" +
+	"\xca\x93\xfa\xc0c\x93\xd1\x077\xa7S\x1f<\xaa\xe5\xfd" +
+	"\x7f_8Z\xee\xf8\xb0\x94\xd6\xdf\xa6\xf1a\xa0\xa9\xc5" +
+	"}\xe0P\x1b)\xe6\xda>\xfd\x01\xf3\x9f\xab\xba\x0fx" +
+	"\xf0\xe7\xe7\x1f\x16\x07^\xdf\x87n\xe3\xce>8\xf0_" +
+	"\x86->=~\xe2!\x1d\xc2\xd1>t\x13\x9a\x10\xe1" +
+	"\xf4\x9b\x8f\xf7\xfd\xbe\xa0\xdd#Bs\xa7\xbet\x97\xfb" +
+	"\xf6\xc5\xbfo8z\xdd\xa6\x8a[\xbfyD\xfc\xfb\x8a" +
+	"\xbet\x00/\"\xfcR\xdc\xfb\xa6\xa1\xdb\x97\xad\x14\x9a" +
+	"\x17\xf7\xdd\x8b\x87\xb0\x9a\xfe\xfd\xf1\xc9E76\xa5\xee" +
+	"Yi\xba\xf0\x9d}a\x07\x8f\xf6\xc5\xe5\x1c\xe9\x8b\x0b" +
+	"_v\xd3\x17S\x0a\x8bR\x1f59\x91!\xfd`\x93" +
+	"\xc6\xf7\xc3\xdd\xb6g\x95\xcd\xd8\xa0\xecxT\x9c\xd3\x80" +
+	"~t\xd2\xa5\xfd\xe8\xa4o\xfb\xe5\xb0\xe7\x80\xf2\x98\xd9" +
+	"\xbd\x88\xf4+\xb0\xc9K\xfb\xe1\x98\x8b\xfb\xe1\x98\xebw" +
+	"\xf5,\xf3\x0c~\xe31\xdd\xc3\xd5\x8f\xf2\xd4\xb4\xfe\xd8" +
+	"[\xa7\xfaOv\xca\xaf~\xf6\x98\xf1\xa9\xa1L<\xbb" +
+	"\xff\x13D.\xeaO\xa5\x94\xfe\xf4!\xdc\x91\xf5\xf0g" +
+	"i\xff\x1c\xb0\xca08\xc5>u%p\xbe\xf6\x03p" +
+	"\xf0\xb4\x01\xc8&\x0b\x96\xed\xae\xca\xb8q= \x17\x90" +
+	"\xe8\x901\xef|\xb2\xf2\xd8\xa0\x9fb3]?\xa0\xcc" +
+	"&\xef\xa3\xc8{\x06\xe0L/X#?\xfc\x99\xe7\xbd" +
+	"\xc7\xc5\x99\xf6\xcc\xa3TV\x98\x873-x=r\xcf" +
+	"\xa8\x0dw\xfcUDP\xf2\xe8\xc6\xd4Q\x849)\xdb" +
+	"\x97\x1e\xa8\x1c\xbbFDX\x91G\xf9\xdeF\x8a\xf0\xe8" +
+	"/\xa7J\xbf\x99\x1d\xd1!\xec\xcf\xeb\x81\x08\xc7)B" +
+	"\x97g\xfe\xb1g\xe1\xc0^O\xe8^\xa0\xabh\x0f=" +
+	"\xafB\x84-\xcf\x94\x1e\xfe\xcf\xf2\xc7u\x08%WQ" +
+	".\xa2P\x84Z\xc7s\x99{Z<\xf4\xa4\xc9\x19/" +
+	"\x80\x8e\xe4\xb5W\xe1\x19s!\xd4\x8cn\x1a\x10o\xf5" +
+	"U\xf0\x90\xc9\x8dW\xe1\xfb\xfe\xfc\xa7\x13_9\x1a\xd9" +
+	"\xfe\x94\x19\xd7l\x18\x08Wy\xf5@\xdc\xc6\x95\x03q" +
+	"\x02\xd3'\xeexff\xe9\x91\xa7\xcc\xde\xb1m\x03\xf7" +
+	"\x12\xf9\x00E\xdeO\x91/\xbe\xf6\xf6\xf3?\xfa\xf1?" +
+	"\xa6=7\x0d\x04\x11\xec\xa2A\x94\xc3\x0fB\xe4\xa6\x8f" +
+	"\xe7\\x\x95oB\xa3\x8eg\x0d\xa2\x9bSB\x11~" +
+	"=9\xfd\xf1\x17'oj4Y\xbbw\x10\x88@\x8b" +
+	"\xa03\xfbO\xa7\xb7\xfe\xe1H\xeb\x09O\x0b\x9d\xb8\x07" +
+	"\xd1\x0d\x9cG;\xe1\x12\x87\x81\x1e)\xd1\xac\x1e\x04\xf4" +
+	"\xb8uP7\xe4a\x83\xe8\x1b\xff\xfb\xa39s\xde\x18" +
+	"\xe4zF\x9cTV>=\xd2!\xf9\xd8\xdf\xfc\xc6\xbd" +
+	"\xdbN\x1d\xf6\xaf\x93\xd2\x06p\x04g>\xbd\xe2\xb5\x14" +
+	"a\xf1\xfem\x1b\xde8\x9a\xbd\xc1\xf4,\x96\xe5\xc3}" +
+	"\xda\x9cO\xdf\x99|\xa4\xd2\xeb\x17\xb5\xc8\xf7\xa6\xad\xdb" +
+	"\xa0{g\x06\xab\x82\xe5`\xec.\xf7\xfc\xdb_\xdb\xf4" +
+	"D\xebgu\x142\x98\x92\xb1B\x11\xc6\xe6\xf6]\xdb" +
+	"\xeb\xd2\xebt\x08\x0d\x83\x8b\x11a%E\x98\xf9\xf4\xe2" +
+	";\xeez\xf7\x1f\xcf\xe2\x84\x92\x0c\xfb\xb8}0<\xbb" +
+	"\x07\x07S\xd1i0\x95t\xbf\xb9\xbf\xa9\xc3\xae#\xab" +
+	"\x9f3;\xc2\x94\x02\xa0\xa4\x9e\x058\xfb\xac\x02\xecz" +
+	"\xd6\x9e/\xd7\xdcu\xe7\x90\x8d\xa6k-*\x80\xeb\xab" +
+	"Plg\x01^\xdf\xaa\xcf\xee\xfe\xd7m\x97\x1d\xdcd" +
+	"z\xd7\x0b\x80:\xda\x0f\xa5w}(\"\xdf\xd5\xb4\xee" +
+	"\xd1\x0e\x9d\xbe\xddd\x10\xd0\xe9\xac\xd7\x0f\x85\xd3\xdf3" +
+	"\x94\xbe\x1bC\xe9\xb1E\xda-w/\x0fv{^w" +
+	"\xd9\x0b)-\x15\x16R\xce\xca:H\xebb\x8f66" +
+	"\xbez\xd3\x95?=\x11\xc5G\xc2]8\x96\xe4\xd6\x15" +
+	"\xfe\x94\x04\x7fql\xc4\xf0\xd6\xf2\x90Q(z\xac\x9a" +
+	"\xbd2\xa9\xff\xc15\xcf\x9bJ\xccY\xa3`e\x85\x88" +
+	"\x07\xd88\xd9\xce5\xc3\xbc\xd7\xbe\xfd\xd8f\xd3}8" +
+	"2\x0a\x9e!R\x81\xd8M\xa3\xa8l\xf1@\xc3\xe8N" +
+	"\xb7do1\xdb\xe2\xa9\x15p\xff\x16S\xe4E\x15\xf4" +
+	"\xe9\xdf\xb46\xef\xe7C\xd3\xb7\x18\xbbn\x83\xd8\x8d\x15" +
+	"p \xbb+\xe8\xebPA\x85\xe2\xc2\xc7\xef<]\xba" +
+	"\xeb\xf7/\x98\xdc\x99\xda1\xb0k\xcb\xc6 \xbf\xe8\xfe" +
+	"h0\xbf\xe1\xa5\x8c\x17L\xe7\x1b\x19\x03\x9d.\x1dC" +
+	"y\xfe\x18\x9c\xef\xac\xa1\xfdW\x94\xfce\xc7\x0b\x86\xbd" +
+	"\xa0\x9d\x1e\x1f\xf3\x01\x91S\xc6\"r\xab\xb1\x88\xdc\xe1" +
+	"\xa6?O\xbe\xfb\xa7>/\x8a'1u,=\x89\x05" +
+	"cqA\xcb{^S\xd5t\xc1\xd3/\x9a\xad\xbeq" +
+	",\xa8s\xbbio;)\xf2\xd3\x0fe-8r\xd5" +
+	"\xe8\x97\x8cj\x9a\xcaQ\xc6\x02\xfb\xb9\xe8&\xcaQn" +
+	"B\xc6v\xd4\xd5+u\x8d\xed\x81\x97M\x97\x952\x0e" +
+	"d\xd1\xecq\x88\xdds\x1c\xcet\x7f\x97\xe1Y)O" +
+	"\xf6\x7f\xd5l\"\x0b\xc6}\x07o2E^9\x0e'" +
+	"\xd2\xf6\xa6\xb7\x07}5\xe1\xb3\xed\xe2\xb2\xb6\x8d\xa3\xd7" +
+	"p?EXX\xf3\x8d\x7f\xc3\xe7\x07_\xd3=\x8c*" +
+	"B\xfb\xf1T\xd9p\xfe\xddV\xb8\xdb\xf3\xba\x8e\xdd\x8d" +
+	"\xa7\xf7\xb4\x94\"\x9c\xbc\xee\xa6W\xce\xbf\xf9\xb6\x1d\xa6" +
+	"d1\x1e\xae\xc7\xa2\xf18\x9f\x05\x14\xf9\xab\x927\xef" +
+	"\xda\xdb)\xb0S\xec\xadq<\x1dn;Ex\xe1\x92" +
+	"\xc5\x17&_|\xdfN#\x01\xd3S;:\x1eu\x8a" +
+	"\x09\xf8_2\x81\xbe\xc3\xcb\xc6\xae\xed2\xb7[\xd3N" +
+	"\xb3\xeb\xb6\xfdfd\x127\xe3\xe0\x07nFr??" +
+	"\xe9\xf9k\xd2\xe6w\xdb\xa5c9\x13U\xd1v\"\x0e" +
+	"\xceu\x00\xc3AP\xa6\xbby\"\xa8\x90\xfb'\xe2{" +
+	"tp\"\x1e\xdb\x1do_x\xdb\xf3\xce\x91o\x88\xdd" +
+	"muV\xd2\xbdubw\x07\x8e\xf5s\xcd\x0c\xac\x7f" +
+	"\xc3\x84\xa8\x9b\x9cp_:U\"Q\xffn\xfb\xbd\xfd" +
+	":'\xb7}SJ\x1bb\xd3\xf4{\xb8\xe2\xa7\x9c@" +
+	"U\x17UR:\xa9D9\xf0\xf3\xc3\xa7'W\x07z" +
+	"\xbd\xa9\x0eH\xfb\xb9\xa8\x12\xc40G4\xfa\xd6g/" +
+	"=<<\xffM3Y(\xa5\x12\x86\xeaI\xbb\xc9\xaa" +
+	"D\x02\x1a\xd2\xa3\xcb7\x07\xae\xb9{7\xae\xb2\x85\x11" +
+	"{\x19t(o\xac\xa4\xec\xaa\xf2u\xdc\xe2\x9d{\x9e" +
+	"\xfdbNS\xf2[\xe22\x97\xba\xe8\x915\xba\xa8M" +
+	"\xa7az\xe0\x93OZ\xbde\xb2\xcc\xdd.\xb8\xbb\xc7" +
+	"\\\xb8\xcc)\xe7\xedh\xd7*?\xa4\xebf\xa7\x8b*" +
+	"E\x07i7'\xdb\xbfx_\xc7\x81[t\x08D\xa1" +
+	"\xe3\\\xa4 B\xf4\xc9E)M\x85\xa7\xdf2#\xb4" +
+	"A\x0a\x0c4^\xc1E\x8e\xa1\xc8\xee\xd7\x0a>\x1e;" +
+	"\xec\xe9\xb7\x8dGI\xb9v\xad\x02;\xbbL\xa1+Q" +
+	"\xe8\xf3\xf1\xe1\x08\xc7\xec1\xdb\x9f\x7f[\x1c|\xe3$" +
+	"J\x1a;'a\x7f}?\xbc`\xd6*o\x8bwt" +
+	"\"\xf6$\xfa`\x93jD\xe88dO\x9fT\xdf\xf0" +
+	"w\xccf\xd7\xb5\x1aX\xe9\x90j\x9c\xdd \x8a\xbc\xb7" +
+	"\xc3\xc6\x9d\x05\xc9\x99{\x8d\x1a<=\x82\xf1\xd5@\xb7" +
+	"\xb5\xd5\x94\xa7U\xd3g\xe2\xee\xbbz\x95?\xf4d\xc3" +
+	"^S\x06\xb1\xbf\x06.\xc5\x89\x1a\xec\xfcx\x0d\x9e\xef" +
+	"\xc7\xef\xfd\xa1U\x91\xf2\xc6^\xdd\xd3\xeb\xa6\xb2\x80\xe2" +
+	"\xc6\xd1\x17\xfd\xf0\xc1\x8a\x17\xd6\xdd\xfc\xae\xe9#\xd1\xe0" +
+	"\x06\x11|\xa5\x1b\xbb[\xe1\xc6[\xf3\x87\xf9\x1b\xc9\xbf" +
+	"\xd6\\\xfb\x9e\xae\xbb\xc9T\x05sN\xc6\xee^Z\xbe" +
+	"\xfe\xb5o\x16-{\xdfL\xd4\x9a7\x19V\xbeb2" +
+	"\xf6\xb6\x8c\"+\xbd\xf7n\xb2\x0d\xfd\xf7\xfbf\x94\xba" +
+	"u2\xacd?E\xde7\x19W\xf2k\xc3\xc0\xb9\x9d" +
+	":\xfdk\xbfq\x9b\xe8!\xf6\x9d\xd2\xc3&WL\xc1" +
+	"\xff\x96N\x89\xe26\x85\x7f,\xectY\xd6\xf9\x1fH" +
+	"i\xd9\xb6\xe8\xd2WG\xcf\x1dr\xe1\x0b_\xd2\xc7\xd2" +
+	"\xbb\x01\xe4*/\xd5\xa9\xbd\xaf\xa3\xa9\xe1\xdd^]\x7f" +
+	"\xfaa\xf6\x07&${\xc2\x0b\xd7\xbc\xbd\x0fIvS" +
+	"\x8f\xbf\x7f\xfadQ\xca\x87\xe2\xca\x8f{\xa9\x90\xd3\xca" +
+	"G\xdf\x84\xcb\xa6\x07&T\xe6\xfd\xdb\xf4\\z\xfa\xe0" +
+	"\xde\x95\xf8\xa8\xb5\xc4\x87\xab\x99\xfbT\xfd_\xf7~\xbd" +
+	"\xe5\xdf:\xeb\xa2\x8fr\xd2\xed\xb4\xbb\xc3\xb7\xe5\x0fM" +
+	";5\xe6c\x1d\x8d\xf9T5\x8e\",=\xed\xb9\xb9" +
+	"v\xed\xb8\x8fq\xbcd\xc3\xbc3\xfc0\xef!~z" +
+	"\x19\xfc\x947\xfe\x9a\xf7\xeb\x8b\x8f\x0c\x0c|lz\x05" +
+	"\x0e\x04\x80\xc8N\x05\xd0L\x944\x95\x12\x197g\x9a" +
+	"q^w\x10\xde\xcb\x86 \xa2/\x0e\xd2\xcd\xbe?\xe5" +
+	"\xa5\x87\x0e?\xb4K7\xd9\xcdaz]\xf7\x84q\xb2" +
+	"\x15\x81\xe1i\x97\x96\x9d\xff\x89n\xf7\xc2e\x88\x90\x12" +
+	"A\x84_\\\x7f\xff\xd33[\xba\xe8\x10\xb2#\xf4J" +
+	"\x15Q\x84\x85\x87\x8a/\x89\xf8\xffuP\xa7\xfcG(" +
+	"\xcbh\xa0\x08\xbdg\x0d_;\xc1-\x1f\x12\x11VG" +
+	"\xa8\x01q3E\xc8\xbe\xfcU\xff\xd0\xcc7u\x08\x07" +
+	"\"\xf4U?A\x11\xe6\x95<\xf3\xd3\xd1Y\xdf\x1fV" +
+	"\xc5b\x95\xa5N\xcb\xb3\x01K]\xf3\xf8m+j\xc6" +
+	">zD'\xe1N\xa3\x1ac\xd7i\xf4\xb1x\xe5\xdb" +
+	"\xbf\x8c\xde\xd2\xa8C(\x9aF\xfbvR\x84~\xf2?" +
+	"\xd6\xf9\x16\x7f\xa9C\x987\x8d\xde\xc3e\x14\xe1@\xbd" +
+	"\xaf\xe4`\xd3\x82\xa3\xba-\x9c\xf6\x1d\x0e\xb1\x9b\"p" +
+	"\x1a\xd6\x8b\xc0\xeam96m!\x91\x93\xa6\xe3\x91\xa4" +
+	"L\xa7G\xf2\xf9\xa1w\xba\x0fyo\xd7\x97\xa6\xe4\xd8" +
+	"\xb5\x168da-\x15\xfej\xe1^\x7f\xac\xb4\x1c\x1d" +
+	"}\xf7\xe3/\xcd\x88\xe3H-\x90n\xd2Lj\xbb\x9b" +
+	"\x89O]\xe7\xbe\xe3?<\xd9\xd1\xfb\x1f\x9d\xb5t\xa6" +
+	"j-\x9d\x893-\x9d\xd0m\xe4\x84\x01\xdf\xe9\x10:" +
+	"\xcd\xa2g\xd9w\x16\"\xd4\xbd\xbc\xb8\xfe\xa6\xcd\x9b\xbe" +
+	"2c\x8fcf\xc1M\xae\x9d\x85\xc3E(\xf2\xdfg" +
+	"\x1d\xef\xb0\xee\xc8\xdec\xba'g\x165\x91\xae\xa5\x08" +
+	"\x8f\xad:\xf9~\xf9\xd5\xb7\x7fm&\xda\xed\x9e\x05\x94" +
+	"}\x94\xf6vd\x16\xde\xbb7\xbeq,y<\xe3\xc0" +
+	"\xd7\xba\x83\x9a\xad\x1e\xd4l\xec-\xe5\xc7\xf5\x9b\\S" +
+	"\xaf\xfcFwP\xb3\xa9er\x05Eh\xd8Y\xb7'" +
+	"\xb0\xf3E\x1d\xc2\xd6\xd9Ty\xdaG\x11\xb6\xdd\x94;" +
+	"\xf2\xbdC\x97~\xdb\xccX|j6<\xa7\xed\xeb\xa8" +
+	"~P\x87o\xf8\x94\x05\xf7/{\xbbr\xc2\xb7&\x9c" +
+	"'\xa3\x0ev}H\x1dr\x9ek\x07\xbf\xbc\xab\xd3\x9e" +
+	";\x8f\xeb\xb6\xb3n\x17n@v\x1de\xa3\x0d\xaf\x1d" +
+	"l\xf1x\xbb\xef\x0c\x9c\x91\xf6SZ\xf7(\x91\xddt" +
+	"L\xa5\x0eO\xef\x87\xc5_\xff\xd2vt\xf0;\x9d)" +
+	"\xe5\x16zUKo\xc1\xde\xf6|\x9d\xfe\xd4\x1bG\xae" +
+	"\xfd\xdeH8I\xf4\x0d\xba\x05\xae\xfe\xe2[\xa8\xf5\xf4" +
+	"\x16\xca)\xder\xec}\xa4\xcdw\xaf~o\xc6\xc3\x8f" +
+	"\xcc\x19k\x93[\xcd\xc5\xc1\x93\xe6\xe2\xee_\xbbeb" +
+	"R\xd6s\xaf}o\xb2`e.\x9c{\xc3\\\\p" +
+	"\xde\xbc\xca\x17\xea\xa2MfX\xce\xb9\xc0\xd8\xe6Q\xac" +
+	"\xc7\xa7>v\xcf\xc9\xcc\xb4\x1f\x8cb\xb5\xeaSA\xbc" +
+	"\xba\xb9\xf4}\x9fK\x19\xe0\xdf\x96\xdf{\xf7\xab9\xc3" +
+	"\x7f\xd0)\xc5\xf5\xf4\xe0\x87\xd4\xe3\xba\xdb\xdf<\xef\x93" +
+	"\x1eG\x0f\xe9\x10\x9c\xf5\x94#\xd7Q\x84\xf4\xdb\xc6\xdd" +
+	"\xe7\x1cn;\xa1\xb3.\xd6S\xba\xdfL\x11\x96\xa7E" +
+	"\xda>\xbe\xe3\xc5\x1fM\xe6}\xa0\x1e9l=\xce\x9b" +
+	"\xdb|L\x88\x7f\x1fLH>Q\x8fbe\x13\xed\xf2" +
+	"\xf5\xcf\xa7\xdew\xf1!7 \x0f\xb0i<\x1ch)" +
+	"\xfbV\xc0,\xbd\x15\xb7\xb6\xe4\xd6jT\xd9O\xdd0" +
+	"x\xb0<\xff\xa4\xa9\x17\x0c\x91\x97R\xe4\xc5\xb7R\xc1" +
+	"\x9a<q\xde\xb8\xc9_\x9c\xd4\x19\x11o\xa5\xdch'" +
+	"E8\xb9\xf2\xc9\xdc\xb9\xbb\x9f=e\xb2\x94c\xb7\x02" +
+	"\xefH\x99\x8fK\xd9\xb7m\xef\xc7\xeb&}\xfb\xb3\xee" +
+	"\x8d\xba\x95\x0a\xbdI\xf3\xb1\x9b??s\xdb\xcf\x9f\xce" +
+	"\xe9\xfa\xabn\xd3\xe7\xe7\xd1M\xa7\x08?\xbd\xec\x19\xd3" +
+	"au\xee\xaff\xb3V\xe6#E\xcc\xc7Y\xcf\xa3\xc8" +
+	"\xdd7?\xbf \xa5\xd7\x98&\xdd\x01\xcc\xa7|e3" +
+	"Ex\xac\xf5\x825\xe9\xb7=\xddd\xd6\xdb\xfe\xf9\xb0" +
+	"\x07\xa7ho'(\xf2\xc9\x87\xbf\x9cx\xf9\xf5?\x9e" +
+	"6C\xcej\x805\x165 raC\xbe\xd43Z" +
+	"\xe5\xf7y\xfd\xbe\x9e\xc1\x96\xa1^U~/\xfc\xb7W" +
+	" \xe8\x0f\xfb{\xa9\xf0+\xaa\x9c\x01_ o\xa8\xfa" +
+	"\xa3\\\x09\x8fR\x82^\xb7\xcf\xe9)\xf1\xbb\x942e" +
+	"jD\x09\x85\xaf\x18\xe5\xaf\xae\xf6(\x924\x92\x90\xd2" +
+	"6\xc4&Ii\x9d\xca\xe0$I\xdaE\x05\xf0\x8f-" +
+	"-\xad\x18T\xf6\x88\xaf\xaa\xc6\xe9\xabV$\xe2\x9a\xa3" +
+	"\xf8\x9c\x95\x1e\xc5\x15u\xb9C\xf4?\x12\xb4\xb3y$" +
+	"\xc71\x8f\x11\xeeP\xb8p\x86RU\xae\x84Bn\xbf" +
+	"/T\xa6\x84\x02\xfed_H\xc1\x198\xec\x0ex\xe2" +
+	"`\x1b\xd3R`\xdc\xd26vR\xda\xddF\xa2\xa1\x18" +
+	".\x8cE\xce\x97\xc8H;!mE{4\x02\x13\x9a" +
+	"\xc4\xd0\x1a\xa5jJ\xc0\xef\xf6\x85\x01\x10v\xba}J" +
+	"\xb0L\xc9\x87\x894\x9f\x07lCiK\x98G\x17\x1b" +
+	"\x99S\x15tGF\xf8\xab\xd9\x1c\xdaH6\xdd\xc8-" +
+	"\xe29\x86\x9aH\xd8\xe5\x9f\xee\x13\x97\x9d\xd0\xd4\x15\xdc" +
+	"\xbbZ_\x15\x9fx\x97\x91\xce`\xb2\xd3\x1b2\x9bt" +
+	";\x98tP=i\xd81\xce\x9b`\xc7\xda&\xb8c" +
+	"\xa3j\x9c\xd3\x85\xbd\xa2]J\x86\xad\xea\xa8\x8djw" +
+	"\xbb\xe8\xf6\xb4Ip{\x0a\xa7)\xbepy\xa42\x04" +
+	"[\x1dH\x0d\xc3\xa1\xc3\x18\x89nQ(\xec\x0cj\x07" +
+	"\xdb\x05\xb6:\xe2\x09\x93\x90\x19uu\x00\xea\x0a*\xea" +
+	"\xc1#u\xb5en\x16\x0b[\xc4G,\x0f;\xc3\x91" +
+	"\xd0\x15\xd7OS\x82\xc1d\xb7\x8bRTK>vV" +
+	"&\x8c\xdd\x05\xc6\xeem#i\x84\xb4#\x08\xec\x99\x03" +
+	"\xc0\xee\x00\xecc#\xc9S\x94Z\xb6y\xe9\xd3\x9c\x9e" +
+	"\x88\xd2l+\xe3\x99\xce0O$T\xc3\xe7\x04d\x8b" +
+	"W-\x9d\x91xbD\x1b\x0e*N/=\x9bPY" +
+	"\xbez\xf8\x86E\x05\xb5\xf9\xb35e\xe3&\xf7\x06\xd8" +
+	"@\xbc\xc2n_\x95R\xaeL\x95\xd2#\x0a\xfc\x8f\xb4" +
+	"\x82\x15\xb5\x82\x15y\x80\x1d(0?\xdc\xfc4\xcd\x89" +
+	"\x89\x1c(\xc1\xf5\x96)N\x172\x96\xeb#\xe1@$" +
+	"\xccHT*u\xc0b5\x03\x06\xe9\x91:\xd2\x1dP" +
+	"J\xdb\xf1\xa9\xd7!\xd9\xce\x80i\xce\x17\xcec\x1e0" +
+	"\xc1\xd2\xb9\x00\xfc\x13\xac\xc7\xd6\x8e2\xc6\x05=\x006" +
+	"\x1f`\xf7\x00\xa2\x1d\x10\xed\x00\\\x94\x07\xc0;\x00x" +
+	"/\x00\x1d\x80\x09\xdd\xa6-F\xe0\x9f\x00x\xbf\xfe&" +
+	"\xc4\xf8X\x11\xf0Q\x06K\x0d\xc0lH\xaa6CX" +
+	"y\xaaD\xf2\xfd\x93&\x85\x940\xdb\xa6|\x8f\xe2\xab" +
+	"\x0e\xd7\xf0]Kd_\x02\xceHH\xd1q\x0b`\x16" +
+	"\xb8/\xbf\xc5.\xb81\xc3p\x17\x92\xe2\xb9\xc7\xc8\xa5" +
+	"\xf2\xd5\xe5\xd2\x13\xd0\\\x89p\x02\xd7\xba}\xae\xd2\x8b" +
+	"\xf9\xf8\x1b\xf1\x04\xd6\xc1\xf8/h\xc4\xb3\x197\xfb9" +
+	"\x80\xbd\x0c\xfbj#\xea\x09l\xc5\xab\xf37\x00\xbe\x8a" +
+	"'\xd0V=\x81m8\xfb\x17\x00\xb8C8\x81\xedH" +
+	"{\xaf\x02\xf0\x1d\x00&\x010\x09\x80\xbb\xf1P\xdf\x04" +
+	"\xe0\xfb\x00laoGZ\x00p\xdfX\x00\xbe\x0b\xc0" +
+	"Otg\x95:\x05\xe6\x08\xe7\xc2\xe7\xad\x9eKr\x00" +
+	"PZ\x02JK\x09\xb6*\xe2\xf3\xb9}\xd50_\x98" +
+	"7J\xbc3\xdc\xc0v\\\x94\x918\x00\xe6\xc0#G" +
+	"^\xa4\xb8\x86H\x84\x1fft\x92\xdb\xe7\x0e\xd5\xc0\xd3" +
+	"i\x1f\x12\xb6t\xa4\x93\x82\x8a2S1p8{8" +
+	">\x0e\xa7\xc9\xf7g\xcb\xe3B\xfc\x92\x9d\xfb\x87 \x14" +
+	"{')\xbd\xda\xe3y\xdd\xb8-\xdd\xb0\xac\x16\x89," +
+	"kd\xd0_\x95\x0cd\x8b\x0bj\xcbGt\"\xdd\x8d" +
+	"\x83\x11k\x04\x16\xa1 \x85N\x04\xa0Gc\x11n\x84" +
+	"\xb9\x00\x16\x10X\x84\x17\xc9\xce\x03\xc0\x196\x1d\xfd\xa4" +
+	"\x06\xc4\x1f8G\x8dQ \xd5\x8cr{%\xa2X\"" +
+	"\x90\xd8\xc1\x8cp\x02o\xadr+g\x14\xb2D\xe1\xc6" +
+	"\xab\x84k\xfc\xae\x90&`q\x0b\xa0\x05\x01+\xa8c" +
+	"\xc6\x090\x1d\xc1\xba\x9c0q\xc2\x1bwu\xd0\x0d\xaf" +
+	"\xee\x15\xb0u\xd0\x9d\xd3\x1b\x18\xe6O\x0dz\x9daA" +
+	"\xba\xadT\xa5\xdb\xb1T\xbam\x0f\xe7\x15\x0dN\xaa\xca" +
+	"\xcd\xcd\x1dp\x9d\x94\xec\xf4\xf9\xa3J\xc0_Us\x9d" +
+	"\xd3'\xd9\xfd\xa1T\x9f\xdf\xa7\x9c\xd5\xfd\xa0\xf7\x8e\xd0" +
+	"m\xbf\x92-]\xae%\xf5\x92T>\x83\xd8I\xf9|" +
+	"\xa2Q\x94<\x8f,\x01\xf8|\x84\xdfC8Q\xc9\x8b" +
+	"\x08\xcc\xb9\xfcO\x08\xbe\x9fht%/%3\x01~" +
+	"/\xc2\x1f!\x1a\xef\x93W\x10X\\\xf9\x83\x08_\x83" +
+	"\xf0$B\xd9\x9f\xbc\x9a\xc0+[\xbe\x0a\xe1\xeb\x10\xde" +
+	"\xc2F9\xa0\xdcH\xe1O!\xfco\x08O\x06\xceH" +
+	"\xdd\xa8d2\xc0\x9fC\xf8\xcb\x08o\xe9h\x07\x94*" +
+	"\xc9[\xe9\xb8/ |\x07\xc2[%\xb5\x03\x02\x95\xe4" +
+	"\xedd9\xc0w \xfc]\x84\xb7n\xd1\x8e\xb4\xc6\xa0" +
+	"\x01\xba\xdcw\x10\xfe\x11\xc2\xcfKnG\xceC\xc76" +
+	"]\xd7\xfb\x08?\x8c\xf06-\xdb\x01\xf1K\xf2A\x0a" +
+	"\xff\x04\xe1_!<\xa5U;\x92\x82qNt>_" +
+	" \xfc\x07\x80G\x03\xc0\xd6B\x91\xa0B\xca\xe1\xa0Q" +
+	"\x13\xe1\x8c\x98\xb70: \x01\xed\x1a\x05\"#\xb1Y" +
+	"JF\x84\xb6Z\xecR\x8c\xe0\xbc\x8a\xd7\x1f\xac\x1d\x19" +
+	"\x94\xf2c}4Gq\xfbi\x0f\x11\xc9n\xda\\U" +
+	"\x1d\xf4G\x02\xe5N)\x9dN\x8b\xcf\x0aF\xae\x089" +
+	"A\x8bJ\x07\xf2\xf2\x87\xc4\x19U\x84\x94\xe0uR\xaa" +
+	"\x11\\^\x0b\xc2\x91\x94\xef\xd5\xe3\xab3\x1c\x1a!\xc1" +
+	" \x08d\x05\xb5a\xbbbl\x1c\xa9\x10\xe7\x14hQ" +
+	"B\x12o\x01f\x13\x1a\x1a\x09\x06\xa5d\xf8+\x0eu" +
+	"\xfbQn*\xa8\x95\x92\xc3B/n\xff\x0dAwX" +
+	")\x90RkEp\",\xd5\xa7T\xfb\xc3n`@" +
+	"\xf8:\xa5\x82\x00\x1e\xe7\xeb\xc4#\x94,\xb0qm\xcc" +
+	"\xb8u\"\xee_>{\x81_\xbd\xed\xf6\x90B\x05\x1e" +
+	"-T\x90\xe4\xa4#\x82R\xda\x81Of\x19\x8a\xfb\xf7" +
+	"\xc2d\x1e\x11\x1e\x94\x15\xf8\xca\xdc\x0f\xc0U(\xf2\xc4" +
+	"^\x94\x95\xb8S\x8f\x00\xf0)\xbc\xf96\xf5EY\x8b" +
+	"/\xca\x1a\x00>\x87\xd7\xde\xae\x8a<\xeb\x8b\x05)*" +
+	"I\x95x6/\x89\xc9F \xf1\xa4\x87p\x12 \xd2" +
+	"\xf0\x995\x17i\xe2\x16a\x10\x0f@a\xc4c0g" +
+	"8\xec\xac\xaa)\xf7\x93\xaa)Jx\xa4\x13\xc4\xd5\xd0" +
+	"\x994\xe5\x96q\x1a\x0a\x0c;\xac0\x8e\xaa4\x139" +
+	"t\xe6\x82\x18\xb2\xce\\\xc0}\x07\x86\xd7,>\xfd\x07" +
+	";\x1c\xe6V<\xae\x90j)\xe1\x03\x17\xe2I\x0e\x86" +
+	"\x81G\x08'Y\x84\xda\xd05\x00\x1c%\x9cd)\x1e" +
+	"\x1a\xcc\xa5t\x1c?\x0b\xc6\x19\xe0\x06\xf8#\xc1*\xe0" +
+	"\x0c\x94Ap\xb8\x1f5HP %\xd8I\xceE\x12" +
+	" \xd0\x1b\x9c\xee\xf0Y*\xed\xf1\x9cTHoZ\xd2" +
+	"\xeb\x1aq\xc9nB\xa8\xbf\xee\x16\xb6\xb4z\x0bC\x0a" +
+	"=4\xbda\xab\x80>\xfd\xedU\xc3V\x0a\xe8hs" +
+	"\"\xbe)>\x102\x99$\x9f\xaf\x92\xf5Y\x8aZ\xf0" +
+	"\xdbN\xf5\xe3\xb3Q\xd3\x122Zh\xb1l\x16\xf6\xaf" +
+	"\xc4\x1d\x0c\xfa\x83|\xe4\xa2\xeb\x99i\x10\xf5d\xdd\xfe" +
+	"e\xaa\xfb\x97\xc7\xf7/\xd9\xe9\xf1\xe4\x87@N\x8f\x84" +
+	"\xf1\x1f \xd6\xc4\x8d=#\xb8\x01@%L`[<" +
+	"\x12\x8c0\xdfWZZ\x8edKKJNW\xf0O" +
+	"\x06\xeb,B\xf1\xac\xd1c\xc6K\x98T\xfa\xdb\xe4\xc9" +
+	"\xe38-loE\xc0\x05\xa4!\xdcB\xf5\xaa\xff\xa6" +
+	"H\x8e3p\x06\x02\x1e7H\x10m\xb5\x80\x08\x0b\xaf" +
+	"\"\x1b\x92\x0a-(\xf5\x18L7=\xcc\xecQ=4" +
+	"{Nj\xc8\xefE9\x87;t\xd49\xa4N\x8ax" +
+	"<\xcd\xc1\x89iyJ\x18\x84\xf6\x11p\xaa\x9e.e" +
+	"\xf9\x94\xe4\xe3\xa4x\x1e\xd2c\xe1\xdd\x8e\xd0#\xe1\xda" +
+	"B\x88^\xb6\xe4\xb8\x87\xe6\x9ey\x0bC\x07\x15\x8f\xdf" +
+	"\xe9\x82\x1f\x93\xdc\xd5\xb1;\xae'\xc03\x0f\xcb\xe3y" +
+	"-\x0c[\xe1\xd3\xb3\x17\x81I%\xf0\x16\xc4e\x9ah" +
+	"fu\x84e\xa6'p\xb0<%\xc9\xc22\xabt\xda" +
+	"W\x02Z'\xcf\x09\xb2@\xc3\xba#\xcdW\xc7\xfc\xed" +
+	"\x11y\xc4\xa6\x85eN\x17\x9f\xf5\xf8\xb9\x18O\x85\xb1" +
+	"0d3\xefQL\xd4M\xf4\x95+\xd7\x99\xe6E\xfd" +
+	"8\xa1n<\x06'R\xfc\x92\x06\x8f\x10\xb5p\xd2\xd7" +
+	"1\xf5\x02\xe7\x9d\xca|Eq\x1a\xc1G\xa0`ZU" +
+	"\xa3x\x9d\xa3A\xce\x0b\xe2\xb4\xb9\xd4=I\x81\x17)" +
+	"\xa8\x13X\xad\xf8\x96\x84\x1d\xb9\xe2\xdad\xb7\xcfE\xa7" +
+	"G\xdf\xee\xb4\x1e\xf4\xedn\x052hj\xa8\xd6W\x05" +
+	"22\x9a\xf4\xdd>\x89T'v\xc5\x9a{\xce\xba\x8c" +
+	"L\x8f\x93\xe8y\xc4\x9b\x05\x0aD\xbf\x97\x05\xa2\xe7\xc1" +
+	"\x9a\x16\x86\x9c\xe6\xf4\xb8\xe9\xe3\x0d[\xa5\xea\x94q\xb2" +
+	"\x13\x1eYl\xe9\x85\xf0\x07\x14\x1f0NQ\x16L\x84" +
+	"\x81\xf2\xdc\x15\x0b\x14^\xa38=\xe1\x9a\x04\x85O\x1e" +
+	"Ila\xb5F\xa7\x8d`/3\x97P\xb8\x80\x92)" +
+	"\x08(pHN\x92\x027&\x05\xd4Y\xc5?\xa9\x99" +
+	"\xb2\x14\x9f\x98\x14\xdbx\xf1v\x9f\x05\x89\xb2\xd7=\xce" +
+	"\xe7\x9dGFY\x901\xcf\xa0*k\xfa\x9e\x89\xf7q" +
+	"\xb0 \xed\x0dB\x7f\xd5\x95\x00\xbc\xdaF\x92\xdd.\xa3" +
+	"\xda\x9e?\x89j\xbe0I\x9ezi\x81\xb6\xaa\x95\xb0" +
+	"\xeaA\x8c\xdf8\xc3\xc36-x\xa0\xe8~\xa4R\x9b" +
+	">l\xc0o8\x9b2\xcd\x9cM\x05\xa2\xb3\xa9s\xcc" +
+	"\xd9\x84\x07\xf82\x00\xdfD\xcbK\x86jy\xd9Y " +
+	":\x9b\x88\xc1\xd9\xf4\x85fjM;2\x19\x80\x87\xed" +
+	"\xa4\x8c\xe8$,\x9dW\xa9\x8ar\x1b\x177\xab\x84c" +
+	"\xef.\x92I\x8c\xaeM=Ohq\x91\x9a\x9b\\\xfc" +
+	"~o!\xb6J\xa9\xd0~F\x83L|wU\x13t" +
+	"\xb8\x1d\xc6`\x0d)0\xb3\x86T\x9aYC\x8a5k" +
+	"\x08.'\xec\xf6jn\xee\xd8\xef2)\xd9\xef\x0fs" +
+	"\xa8'\xa6)\xe0FX1S\x8c\xd6\xb1sn'\x18" +
+	"\x19\xf4Wz\x14\xe25\xdc\x93\x1c3\xad\xa8@c:" +
+	"\xe9\xf4R\xb0\x89\xcc\xf1\xc2\xbb\x8b\xa6\x1b+2s\x95" +
+	"\xa6{k\x82k\\\xd2\x0c\xcf7\xb3\xc0zu:q" +
+	"\"\xc22O\x849\xfb'5\xb1\x87\x86\xc7\xa8Y\x18" +
+	"\xd8k\xb4r$h\x96\xe61\x82\x16\xc6V\x0d\xa3\x06" +
+	"\xe1\x85\xc4q\xbcBh\x9du]\x88\x11UbJ." +
+	"\x8f\xe3\xb60\xf4H\x83\xaeiQ\xc87\x8dX#a" +
+	"j]\xd7\x0a\x85\x90\xbc|5\x86M\xf4\xd7v\xd4\xfc" +
+	"\xb5f\xeeZ\xce\xe3\xdd\x99\xa2\xbf\xb6ss\x7f\xad\x18" +
+	"\x11\xa0T\xd5\xf8I\xaa6t\xcc|\x1etNo\x0e" +
+	"\x8d\x86\x14\x9f\xab\x00\x88\\\"S,\x99n\x87\x1a\x8e" +
+	"\xcf\x9a\xc6\x1e\xcf\xbb<\x84R\xa7N\xf4\x12\x06\xa8\x17" +
+	"(\x84\xf6Q\xe5\xf7\x90\xd1\x0a\xd5_$\xae\xc0$>" +
+	"\x9e\x1aR$\x06\x13\x9d[;DP\xf1\xfa\xa7)\x16" +
+	"\xee\x1cO\xe0\xb3@\xf8\xd7\xba=\x1e#\xdd\xab&\xd4" +
+	"\xb3\xd3n\x13\xbb\xba\xbc4\x8e\x05\xa9\x89\x1a\xc5R\xd1" +
+	"*\x06\x8a\xa3\x184\xde\xaa\x87Pn$\xa9>uT" +
+	"m@\x89j\xee\xd5a~t\xb3K\xa2\x8b\xab\x87\xe6" +
+	"\xe2\xe2\x1e\xae\x1e\xa2\x87+v\x07W\xe29<\x08\xc0" +
+	"5B\xcc\xc4\xea\x1c\xc1\xed\xe5 \xaa\x9c\xb5\xb6^p" +
+	"{%uV\xe5\xac\xf5\x935y.5\\K\x83\xa9" +
+	"\xf8\xb4\xd5\xcb\x98\x1a@g\x14\x7f\xa9\x9d3\xca\xdd3" +
+	"y\\Ez\xc0\xef*\xd2h,l\\\x12t\xc7\x17" +
+	"\x1e\xbb\xdb\x0cGJ\x0dT\x84\xab,]\xef*1\xe0" +
+	"\x04\x8e\x99Z\xa3S\xe3\xd3\xa2yt\xb2\x05\x0a\xd5\x99" +
+	"\xdc\xaf\xa0\xd6t3\xf5=G\x1bX5\xb9\xe3\x8b\xa0" +
+	"e+%\xac\xa0\x0cW\xc2\xa2M\xf0z\x1a\xdcv\x06" +
+	"\x05\xa5\xa3\x89\xb2'DG\x0a\x0c!\x9d\x06\x15Z\x0a" +
+	"\x90\x09\x8b\xe1\xac\x89\xaaj<\x9b\xd0\x92z\x1f\x0a\xfb" +
+	"\x83\x16|d,\x19\xc6\xc2\xeeW\x9b\xed~\xa2\x8b\xe6" +
+	"\x19\xc0V\xc2\xab\xa8\xb4\x07\xdc\x02t\x96\x90\xea\xe8\x11" +
+	"\xe2/\x97k\xa1\x96\\\xd2^\xb0E\x8b\xa0\xe4:\xc3" +
+	"\xd2\xa0\xe05\xb7\xdbUN\xb1b\x89\xc8)\x1c1N" +
+	"\x11\xd48\x05\xd3&\xcaI\xc09\xddW\xe2\xae\x0a\xda" +
+	"\xfd\xba\x90\x86\x90\xdf\xa3\\C\x9c>W\xa8\xc69E" +
+	"\xc9G\x0c\x01!\xf6\xd7%R\xba\x1e\x0e*\xdb$\xb7" +
+	"G\xb9\x818\xdda\xfa'Dk\x03M\xa5\xc8\x07`" +
+	"\xe3\xdf\x9cUp\x81*\xfd\x9c\xfb\x97\x12\xc4\xac\x1b\xdc" +
+	">\x97\x7f:rE\xf1N\x0aj]GM\xad\xe3Z" +
+	"\x1d\xde\xc9\xab\x016R\xe0\xe5%y\x9a\xaa'^\xd4" +
+	"\xe9n\x17p\xe0d\xf8\x95,\x91\xfc\x1a\xc5]]\x13" +
+	"f?\x134\xd2\x08:\xa8 <\xfc\x7f\xa0\x82Zp" +
+	"\xc8\x8b\xb1\x0c\xc2\xcbY,\xbc\x92l\x05++\x052" +
+	"g+X\x8b\xdb\xbd\x0a\x80\xeb\xf0BLT/D#" +
+	"\x8a\xafO\x01\xf0ox!\x9c\xea\x85\xd8X,X8" +
+	"X<\xec\xd6b-r\xd64\xf2\x03D[\xb4\x1d\x0c" +
+	"\x85Mp)\x0c\x9a\x1frW\x83H\xce\x91\xfc~/" +
+	"\xca=\x98\x1c\xc2\x9fB\xdc:\xd7\xf5\x91\xb0`\xb30" +
+	"\x0d\x199\x0b}51\xd5\x91\xe7\xe0\x18\xb8\x97\xe3\xb7" +
+	"\x06\xb6\xfb}\xe5\xb3\x81\xe8\xb5\x044\xf9\xb8\xad^\xb3" +
+	"\xf0\xc2\xaf-Z:\x9d|\xc2V\xa6U\x05\x81_A" +
+	"-\x01\x17~\xbd\xa2\xa5`\xc8\xa7l\xbb\xb4\xe4a\x99" +
+	"\xd8\xf7j\xda\x9e\xdc\xca\x1e\xd4\xaa\xc3\xc0\xaf\x99Z\xea" +
+	"6\xfcZ\xa8\x99x\xe5\x14\xfb\x12\xadT\x88\x9cf\x7f" +
+	"B\xcb\x91\x92\xdb\xdb7h\xe1M\xf2E\xf0w<\xb6" +
+	"J\xee\x04\x7f\xc7K\xca\xc8\x19\xf6G\xb5\xacg\xb9+" +
+	"\xf4\xc2=\xder\x96\xfdK\xcd\xec'g\xdb'k\x91" +
+	"4\xf0\xab^+o\x00\xbf\x82Z!7\xf8\xf5\x9df" +
+	"\xbf\x93\x07\xc0\xe8\\\x80\x90\x07\xd9s\xb4\x0coh[" +
+	"\xa2\xb9e\xa0\xed\x15-\xb5G\x1eb\xaf\xd4\xe4Y\xf8" +
+	"\xb5K3y\xc8E\x80\xc9\xd5T\xb9\x04\xdax\xdd\x09" +
+	"\xb9\xc2\xbe\\s\x11\xcbc`}\\\xae\x97\xc7\xdb\xb7" +
+	"hu\x0fd'\xf4\xc2\x0b\xcb\xc8\x0a\xac\x96\xbft\xb2" +
+	"\x17\xd6\xc0\xb3B\xe5\x08\x9c\x11\xf7N\xcau\xf6\x0f4" +
+	"\x83\x84\xdc\x00}\xf2\x0a\x07\xf2\x02\xe8\x93{\x89\xe5E" +
+	"03\x9e\x84'/\x85]\xe2\x16s\xf85S\x0b~" +
+	"\x81_[\xb4b`\xf22\xe8\x85\x87\xc3\xca+\xe04" +
+	"y\x11+y%`\xf2R\x10\xf2j\x18\x81\x1b(\xe4" +
+	"F\x98'wh\xca\x1b\xe1\xef\xb8\x8b^\xdel/\xd6" +
+	"\xe2\x15\xe1\xd7B\xad4\xa0\xbc\x15\xfa\xe4\xf9z\xf26" +
+	"\xf8\xc5#\xc5\xe5\xed0\x17^\xd1R\xde\x09\x14\xc2\x85" +
+	" y7\x8c\xc0eRy\x0f\x8c\xce\x8b\"\xca\xfba" +
+	"\x04^\x96D>\x00{\xc6\x0b\xe3\xc9G\xa0O\x9e%" +
+	"+\x1f\x85}\xe1\x11\xc7\xf4\x17/\x96\x08\xbfvi\x05" +
+	"\x06\xe4\xe30:/`$\x9f\x80\xd1y\xd2\x9c|\xca" +
+	"\xbe\x81e\xf0\xc8M\xb0\x02n\xab\x92\x89c\x83VA" +
+	"KNr\x94i<\x01~m\xd1\",\xe4V\x8e\x99" +
+	"Z\xfehn+\x87M\xa8\x86\x97\x9b\xe6h-\x94y" +
+	"\x90;A\xaf<\xf8F\xcepla\xb5\xe7\xe4\xae\x8e" +
+	"W\xb4\x94Q9\xcb\xb1K\xf3~\xc9\xd9\x8e\x0f\xb4\x9a" +
+	"\xa4\xf2\x00\xc7\xa7L\xb8\x93\x87\xc0_q\x97\x95\\\xe8" +
+	"xB\xf3\x0b\xc8E0\x16\xf7\xe1\xca%\xd0\xc6+\xf9" +
+	"\xc8\xa5\xd0\xc6\x03\xe0\xe5\x0aG\xbdV\xc6\x14~-\xd4" +
+	"\xdc\xcd\xf2\x18\xc7\xa3Z(\x81<\x1ez\xe1u\x89d" +
+	"'\xec\x03\xafj(+0\x17\x9e\x90#\xbb\x1dA\xad" +
+	"L)\xfcZ\xa2\x95\x05\x95\xbd\xd0'/\xeb#O\x85" +
+	">c\x16\x822;\xe3\xc2\xaa\x08\xa8\xbd}\xb1\xb7;" +
+	"\xca\xa20$\x12\x8c21QJ\xa7\x82b\x94\xfdM" +
+	"\x92\xf1\xc1,4\xe6\xc0\xf1D\x84(k\xb25\xb7?" +
+	"E\x99\xa5CJW\xc7\xe6\xbfc\x99\x7fQ\xe6\x02\"" +
+	"\xd5Z\x87\"\x8cu\xc4D&\xc2d\xa6T\xda\x9f\x11" +
+	"\x1c\xcb\xb6\x8a2\xbf1Q\xb4nE\x98~~\xe5a" +
+	"BM#\x80\x9a\xac\xcd\x92Cc\xbe\xa9(\xf3\xf9\xd8" +
+	"\x8cN\x9f\xd8\\b!\x8bR*\xban\xa2l7l" +
+	"1L\x89\x05\x05\xa9\x11\x87g\xec\x8d-ax\xccy" +
+	"C\xb4\x9c\x8ft\x0a\xd0\xb5\xb0\x85\xb0\x1cO\xbb\x96\xe4" +
+	"\xa9\xfe\x11\xb3j$\x03\x90c\xd9t\xa9\xa0\xf4\xc9\xe6" +
+	"\xf3M\xd2\xd9\xbf\xa0\x87\xe6v1\xfe7\xe5\xb1`\"" +
+	"\x02\x02\x1a3\x97\x19\x80\xb1\x90+8T\xd5:D\x8c" +
+	"\xe4\xc8\x1a\x9a\x91\xdc\x994Xu\xb7Y\xab\xcd\xd0\xcc" +
+	"\xb6\x8f\xe5\xe2\xd9\xc4d<U\xa07o\x8b\x85WD" +
+	"\x99\xcf\x820\xa7\x05#\xd5\x8aX\x0cS\x12\x0bbb" +
+	"\x1bTa\x08n\x126\xe8\x1a\xea\xdd\xd5\xe8\x9f\xfff" +
+	"\x9d2\x8b+a&W\xb6-\xac\xc1\xa1\x8b\xff\x80N" +
+	"\x8d\xfe\xdbt\xb6\x8d*\x9808\x1b\x81\x05\x1b\xda\x8c" +
+	"\xd1\x860=\xb36FQl\xef\x09K\xac\x88\x0d4" +
+	"2\x16\xe9/\xd1\x9b\xaa\x924\xd1\x80\xcd\xff\x8eMd" +
+	"X,\x85\xaa\x19\x05\xb0\x06\x87\x91\x02\x8c\x19\xb0\xea\xc9" +
+	"3(a\xc8\xb1\x13\xd7.\x1c\xb7\xf0\xf0\x13\xd7&\x15" +
+	"k\x13&\xaa\xc3gT\xc0bL\xed\xba S\xd84" +
+	"5\xea\xb4\xb6 =\x821\xd7\xd1\x12\x9a\xc23\xc2)" +
+	"\xa5S0\xff;\x9b.\x0fH%\x06f\xab$\xfa\xf5" +
+	"\x18\xc1l=,p\x87\xb0\xf5\xc7\x0e\xa0\x19\x9cm0" +
+	"\xe3(jG\xda\x89\xb1\x85\"\xc3o\x86\xa3\xfd\xb1/" +
+	"2\x03\x06\x97\xf2\xd5`\xc9(\x8b\xa2Ln\x1eF)" +
+	"\x92\xfd\x19\xc2,\xd5\x153\xb7\x84q\x09\xcd\xe0\xfc\x8e" +
+	"\xc5\xa2\xe6\x92\x8c\xcfL\xf3p:\x8dq\xc5\xe2cl" +
+	"\xbep\xb3h\xbb34jLI5\x0d9\x8c\xc3\x15" +
+	"\x8aq\x05\xfc\xa4\x18\xd4xRL\xc74\x1c\xac\x11\xcc" +
+	"\x0f6\x96hgg\xa3\xe92\xd4\xd5E1\xad\x9b\xa8" +
+	"\xae\xdf\xd8\xc3d\x80\xb2\x87I\x7f\xe5\"<a\x987" +
+	"\xd8\x0d\x91\xda\x123J\xa6S\xabd\x94\xe5\x80\x13\x9a" +
+	"\x04\x1ev\xc3\x00Q\x96\x81Lh\x0a2\x9b\x81\x01\x1a" +
+	"\x9b\x01h\xc4\x18D\xccJ\x91\x11V\x82GNK*" +
+	"\x90lrRR2\xd1\x0ao\x10V\xd3K>\xe5\xa8" +
+	"\x87\xd6\xe3\x8edb\xe3\xd5\xdd\x09+I!\x1fq," +
+	"\x81\xd6\x83\xd0j\xe7\xb5G\x09+]'\xef\xa3\x7f\xbb" +
+	"\x1bZ\x1d\xbc\xa6\x0ea%k\xe5m\x8e\xe5\xd0\xba\x15" +
+	"Z\x93x\xad:\xc2j\x0f\xc9\xeb\x1d[\xa0\xb5\x11Z" +
+	"[\xf0\xba\xda\x84U\xe0\x96W:\xca\xa0u\x19\xb4&" +
+	"\xf3\xfad\x84\x15\x05\x91\x1796@\xeb\x02hm\xc9" +
+	"K:\x13V\xc4I\xae\xa3\xad\xb5\xd0\xda\x8aW\x97%" +
+	"\xacp\x0d\x08m\xd8\xb3\x02\xad\xady\xdd`rz\xeb" +
+	"\x1f$\xac\xf9\x09b\xe2Bh\xad\x80\xd6\xf3x\xbdW" +
+	"\xc2j\xaa\x82(\x8a\xad\x85\xd0\xda\x86\x97\x08\"\xac\xb0" +
+	"3\x08\xb4\x95\xd0\x9a\x0d\xad)\xbc\x14)ae\xf5@" +
+	"0\xc6\xbd\xea\x04\xad\xe7\xf3\xf2I\x84\x95\xda\x94\xd3\xe8" +
+	"\x9cS\xa05\x95W\x09#\xacH3\x08\xf1\xb8\x93M" +
+	"\xf6d\xd2\x96\x15\x0c\xd6j\xc9\x82b0\x13Z\x8fB" +
+	"k\x1a\xaf\xfdDX5iP>p\xce\xfb\xa1\xf5w" +
+	"\xbcx\x0d)\xee-\xd1\xb2\xc1\xa0\xc4\xe4A\xeb6h" +
+	"\x95y\x19p\xc2*\x89\x80\x12\xb5\x0bZ7Bk;" +
+	"^\x96\x9d\xb0z\x8c\xf2Z:\xeeJhm\xcf\xab\x81" +
+	"\x10V\xc1\x16\x94:\xa4\x9c\xc5\xd0z\x01/&MX" +
+	"\xd5'P\x1c\xf1o\xeb\xa0\xf5B^\xae\x89\xb0\x1a\xf6" +
+	"\xf2T;\xee\x95\x1bZ;\xf0\xfao\x84\x156\x05E" +
+	"6\x88g\x04\xad\x17\xf1\xea\xaa\x84\x15\x02\x02\xf5\x18\xf7" +
+	"\xaa\x10Z;\xf2\x12\x8a\x84\x15\xb4B\x85\x1cZ\xfbB" +
+	"\xeb\xefy\xd1A\xc2j\xc9\x80\xd2\x8f=g@\xeb\xc5" +
+	"\xbc\xb0\x12a\x9f\x1e\x90\xdb\xd39\xa7@k'^\xea" +
+	"\x97\xb0:\xa12\xa1\xad\xa7l\xc9\xe4\x0f\xbcl\x11a" +
+	"\x85\x08\xe5c6\xdc\xc9c\xd0\x9a\xceK\xff\x13V\x06" +
+	"]>h\xc3\xbf\xdd\x0f\xad\x9dyQ?\xc2\x0a7\xc9" +
+	"\xbbm8\xe7\x9d\xd0\x9a\xc1\x0bN\x13V\xccE\xdej" +
+	"{\x14Z7Ck&/\xedJXM<\xb9\x91\xfe" +
+	"\xedZh\xbd\x84\xd7\xa2&\xac\x80\xb3\xbc\xc2\x86\xeb]" +
+	"\x0a\xad]x\xb9n\xc2j\xab\xc9\x0bh\xeb<h\xed" +
+	"\xca?\x08@X\xf1-9b+\x86V/\xb4^\xca" +
+	"k\x81\x13V\xa2\\v\xda&C\xeb\x18h\xed\xc6\xab" +
+	"<\x11V\xedH.\xb1\xe1\xf9\x16Bkw^\xc4\x8e" +
+	"\xb0\x1ak\xf2\x00\xfa\xb7\xd9\xb6\xe49\xd3Tuj0" +
+	"\x89V\xc5t\"&\xb7H\x83\xd1\xb2\xa6\xea<Dc" +
+	"\xbd\x83Y:\x96\x1e3\xc8\x95\x99\x18\xaa]A\xd4\x90" +
+	"Nq\x81\xa6|\xf5O\xa0\x89\xa5\xd4ID\xe1}\xc6" +
+	"t\x11\xec\"\x9d\xe1ytO90\xf8t\xaaA@" +
+	"\x0b\x8b\xfb\x92H(\x86G%\xc3r^\xd3e05" +
+	"\xc7\xc5&\x1e\x13\xeb)\x94e\x07H\xc9 \xbc\xd3\xd9" +
+	"\xeb\xa5vI\xed\x9d.\x850\xc9;\x9d\x8a\xde\xd0\xc2" +
+	"\"\xd1\x09\x93\xab\xed\xd58\x01\x16\x81\"\xe5\xab\"5" +
+	"\x80X>\x00a23\x8e\x9e\xaf\xc6B\xaa\xd3\xa0\xc2" +
+	"\xaf\xcd\x98\xdf$\xa9[\xaa\x8a\xbaR\xbe*\xeb\x02\x88" +
+	"\xc5\x9a\xb0y\x16\x91\xeb\x855J\xf9\xaa,\x8aS4" +
+	"\x08\xa1\xd8\x1f\xf3LI|k\xab\x8c\xe2\xa2\x9d\xeek" +
+	"\xcc34\x820\xe1\x8eNgJL~\xd3\xfe\x9cU" +
+	"C\x91\xb4Ce\x87%\xe5\xab\x82\x17\xdf\x03M\xf8\xe6" +
+	"\x02\x14\xed\x95\x89;B\x1f\x91\x98\x0cDt\xbb\xc1\xc2" +
+	"\x83\x09\x93p\x92Ul\xe6\xf6\xd2c\xb3\x80Im\xb2" +
+	",\xb4]\x98~L\x1c\x91b\xbb\xad\xca\x1cR*\x0a" +
+	"\x1d\xba\xcd\x89)\xb6t\xc5\xaaX\x00\xba\xaf\x0f\xf79" +
+	"\xf1 u\xa1\xdc\xc9\x19\x02\xdd&k\xde\x0ff\xa2/" +
+	"A\xd8\x08\x80\xdd\x88&\xfa\xc1\xaa\x89\xbe\x02\xad\xf1\xa3" +
+	"\x008\x11\xe3\xbfc\x85m$Z\xd9\x06^)nP" +
+	"\x89\xd5;\xf18C\xe1r8W\x9c\xb9\xe0\xd9\x0c\x07" +
+	"#\xbe*\xa4Y\xc1\xc4n1p]\x95\x9c\xfe\xdf\xc5" +
+	"\xad[+\x09\xf5\xbf\xcaN1Z\xa1\x04\xa7\x9a\x98\x9e" +
+	"\x0f#\x01\x8f\xb3\x93\xf2\xb9bz~\x1d\xcd\xab\x9f\x8d" +
+	"\xf0;\x84\xf4\xfc\x06R,f\xed\xb3$]y\x11)" +
+	"ci\xfb4\x0d?\x96\xa7+\xaf\xa6i\xec\xabxZ" +
+	"}\x92CM\xcf\xdfJ\xbb\xa7i\xf5\xef\xd3\xf4\xfc$" +
+	"5=\x7f\x1f\x85\xbf\x8b\xf0oiz~\x0b5=\xff" +
+	"\x18\xe9!\xa6\xc3\xa7\xb5$jz\xfeq\x9an\xff-" +
+	"\xc2\x7f\xa5\xe9\xf9\xc9jz\xfe)\xb2\x10\xe0\xbf\"\xbc" +
+	"\x83\x0d\xd3\xf3[\xaa\xe9\xf9\xedm8\xcfv6\x80w" +
+	"F\xf8y\xad\xd4\xf4\xfcN\xb6\x02\x80w@x\x17\x84" +
+	"\xb7i\xad\xa6\xe7g\xd8\xb0\x9f.\x08\xef\x8d\xf0\x94\xf3" +
+	"\xd4\xf4\xfc\x9e6,#p9\xc2\xaf\xb4\xe9\x8f\xa82" +
+	"\xe2sy\x94\x91N\xc9\xae\xc5a\x98\xc5\xb5Z\x8fc" +
+	"\xf503\xa2=(T\xb0\x10\xca\x1c\xaaD\xe9Q\x9c" +
+	"\xbeH`\xa8d\xf7\xba\x0c=\xa4\xfa\x9c\x82\xf7/\xa4" +
+	"\x04\xdd\xf0N\xcc$\x8a\xca\xd4\xb5\x19\x86\"U\xc8\x82" +
+	"\x0bI\xcc]\x16\xd2H\xdf\x11\x9bK(\xecr\xfbF" +
+	"\xe2\x93\xa9\x05\x9d\x84\x15e\xa4\x10\x84\x12u\xbb\x14o" +
+	"\xc0\x1fV\x08\xa8\xe9\xa3\xfcS\x14\x9f\xe0P\x0c\x84k" +
+	"\xcb\xfd\xa0\xcf\x83\x16\xaf\xfb\x9bD\xaf\x94\xe6,\x13\xeb" +
+	"\xb4$\x18\xf2\xab\x8b\xa2\x12\xef\xcboDl`U\x9d" +
+	"\xcb\x01v\xa5\x9e\x12\xd4'|\x84_\x82\xc7\xd8\x12+" +
+	"\xd3\xb4S\x8dG\x08SY\xa2\x0d\xcb]\xa7}q." +
+	"}\xd4\x90w\xfa\xc0\x0c\x83\xc7\x99?DBj5z" +
+	"-\xfd\x91p\xb9d\x07\xf9\xc4\x8a\x8b\xd2P\xf5$1" +
+	"\x17%w\xee\x9cM%\x02\xd1x\x94nV\xce\xaeL" +
+	"\xc8\x93\x0f\xc4\x90\x85\xdb\xd6V\xf3\xddX\xc8\x93g\x06" +
+	"E!\x94P\x08\xca,0\x09\xca,\x8e\xc5_\xce\x15" +
+	"\x82\x08\xea\x9e\x10\x02BX\xc9\x83\x05\x0b\xb5\x80\x909" +
+	"\xaahV+\xd4\xfb\xc0\x10n\xaf\xd9;\x04GR\x05" +
+	"\x92GLJ\xaa-q\xa7\xea\"2\xf0\xc1-S\x9c" +
+	"\x01\xa2\x04opNQ\"\x01\x13\xe7\xf4Y\x048\x89" +
+	"\x0e\xfe\xdf\xc8g\xc9\x13\xf2Y\xc4x5C%\xb0\xc4" +
+	".\x8b\x96^\xcb\xac]\x06r\x10\x03\xbd<\x88g\x89" +
+	"\xdb\x18\x03\x9c\x13\xabG\xc5=\xdd\x96\xc2\xa9\xf4\xd9\xf7" +
+	"\xf1\x87\xces?\xa4\x85\x80\xaa\x90\x182c\xa5\xb4\x1a" +
+	"w\xd9[\x08\xa6\xd2\x1d\xaay\xcd\xca\xa0.hW\x99" +
+	"\xe6\xf6\x83`\x9c>\xc2\xf2\xf9\x1a\x83i\x13;_\xee" +
+	"k\xb7\x12\xafh0}\xf2\xe0$\xf3\x84\x1e\xce\xf37" +
+	"\x17\xc4\xe2]\xde\xd1\x8as\xed.\x16\xca\xbf\xb1@S" +
+	"\xb1\xfc\x1b\xaf\x1ew\x00\xb9\xe4G\x00<\x89\xf2\x99]" +
+	"\x8d\x969\x81\x1c\xe8$H7\x0e*\x9d\x11U:#" +
+	"d\x03H=\x0e\x94\xaa\xda\xea\x93z\xe6\xe0\xe2\x9c>" +
+	"\xa3\xa0a*\xf7\x98\xbe<\xffE\x18jVjE\x10" +
+	"\x1f\xd4\xb6\x91A\xa2\x06k\x8fV\x0cB\xbb\x95\xd2\x01" +
+	"j\xdd\"\xa3\x0e4S\x88\x00\xe3J\xd0B-\xa8\x8a" +
+	"\xc7)\x8d\xc1\x9d\xbf\x11\x80. \x0eP\x1a\x83\x188" +
+	",\x19#\xedBX\xed\xc8;\xcaM\xbc\x8a\xda\"\xf0" +
+	"\xe2`(\xa4\x963\xb2\x18<4E\x8c\xcc\x8e?{" +
+	"\x94\x07\x1dX ]\xd3\xba\x1a\xa8|\xd1\x04\x06\xed\xc3" +
+	"~\xac\"\xa5y\xfa\x82Vnn\xac\x96\xaa\xc0(\xda" +
+	"\x8b\x0fI\x0d\xc0\xc2\x02EO\xc5H\xb1\x00\x00g\x1b" +
+	"\x8aO\xfaUb\x11\x85pV}\x92\xcf&\x16\xe1\xec" +
+	"\x8a\x04\x9d\xa8\xa2J\xc9\xe5@\x90F\xcai\x19w\x8d" +
+	"\x85\xe6\xfe\x98\x04c\xd9y\xe0\x85\xa5\xdcR\x83\xf0j" +
+	"1g\xdd\xb4\xb0h:-\x8f\"$X\xe7\xa9\x09\xd6" +
+	"\xf0\xcfYTD\x89\xd9\xf9X\xb6Bb5\x1e\x9a9" +
+	"~M%\xe4\x8efymLZ\xbfFO2qj" +
+	"U\x09,\x909\xf6TG--\xb3\xd8\x99\xcfm\x0f" +
+	"\xb2\xddw`\x1a\x1f\x09s\xdb\x8f\xc4\xf1>\x00\x0f\x0b" +
+	"\x0c\xe5 b~\x02\xc0\xaf\xe02\xc4\x02\x81\x8f\xe2\"" +
+	"\xbe\x00\xd8\x0fB\xca\xc0q\x04~\x0b\xc0_\x05N~" +
+	"\xaaR\xc7\xc9\x1d\x8c\x93\x03n\x192\xf26T\xcdN" +
+	"R\xd5\xecVT\x8do\x89\xf0vXu\x0e\xcb\xb2\xd5" +
+	"8\x83Th\x16J\xb5\x95F\xfca'\xd2l\x12\xc0" +
+	"\x92bu\xe6@\x8b\xf4\xa3\xcdF\xc0\x03\xd1ah@" +
+	"\xb2GB\xdaCL\x81%\xb0\xc9^\x0d\xa8\x96q\x1b" +
+	"\xe1\x96\x92\xbd\xee0\xefS\x85\x96O\x97\xec \xb12" +
+	" \xd6u\x1b\xe1\xf6\xba\xb1<\x17\x1f<\x91j(>" +
+	"\x93\x0a\xb1\xf1\x88P,\xd4\xe9\xec\xabI\x9b%\xca\xb4" +
+	"\x8c[\xeeT\xcdu\x16\xb8;\x0f\x80\xb4\x92^\xa7\xb7" +
+	"$%(\x12\xf1\xa0\xd0s\x90]g\xa9\x04\xab\xe5\"" +
+	"\xe9\x9a\x16\x91@\x05e~o\x17\x14k\xd5\x92\xd9\xbd" +
+	"]\x8cf\xd3{\x00\xf6\xa0po\x97\xcd\xd4b\xa0\xc5" +
+	"\xf9\xbb\xbd \x88\xe8m+\xd1\xe9\xfe\xe0\x14U\x06\xd2" +
+	"\x84 \x8f\xe2\x9c\xa6\x94E|R\xaa.O:\\\x15" +
+	"(\x0c\x85\x9d\x95R\xbe\x87V\xe0\xb5\x94\xbb\xa3\xcf\x1d" +
+	"\x89\x9b\xdax\xf8\xe4\xb9\xc8\x1aI\xec\x15=\x13\xb9%" +
+	"X\x16A\xb3\x1d\x9f\xdb\x0cD\x16\xb6B5\xf4f\xd1" +
+	"\xf0y&yd9fydeqWJ\xec!D" +
+	"\xc8\xb3|\xfd\x8d\x0515\xe2M\x1b\xc9Wk\xe1\xf2" +
+	"\xf4\x85*\x7fD(\x99\x19\x8ax\xa9|*\xbe\x00^" +
+	"\xe7\x0c#,5\xe4\xf1Og?\xe6T\xd2\x08\x1d\xe1" +
+	"\x15\xe5\x91\xaf\x16\xaa\xeb\x1a\xf2\x0c\xe3N\xa3\xca3M" +
+	"\xa3b\x01\xfdF1\xcfb\xb9\xa7\xb8\xf3\xd8xd\xb5" +
+	"\x05\xaadQ\x87!])\x1eA7\xc9\x8be\x81\x8c" +
+	"\xd3\x96?f\xb2\xa6\x85p\xff\x8c\xb3LK\x0b\xce\xa7" +
+	"\xa9n\xc2\x19\x89)o\xe7\xff\xef\xdc3!\xc1\xdf\x14" +
+	"\x7f\x05)\x1e~{v\xdbgz\xa9E\x03\xc2\x99*" +
+	"\xf7'B&\xba\x9c_\xd1\xa3&\x10\xecX\x8d8\xf9" +
+	"#\x92\x9d\xa3\xd9sM\x15\x98\xf40\x1a\xcf-=o" +
+	"&A\xc4\x02)u\xe1\x13;\x86|\xf5+\xd5\x18\xc0" +
+	"\xe6u\"O\x10+\x19\x03:\x85\xc0\x1f\xd4\xe2\x1e<" +
+	"\x99\xbc\x09\xff\xfaW\x10\x1e[\x8a%\x9a\x93H\xa6(" +
+	"k\xc6\x12r\x8c\xb2&\xaf\xd0\x9cF]@m\x11~" +
+	"\xb1X\xa1\xf9\"\x0a\xef\x80\xf0.\xd4\x05\x94\xa1\xba\x80" +
+	"2\xa8\x8b\xa93\xc2/'\xe6\xb9=1%\x85U\xcd" +
+	"\x89)+\xec\xa7Y\x02\x8f\xaeN\xac\xc9\x97\x0c\xa2j" +
+	"\x8f\xe5n\xc9\xaee\xd7F\xd5~\x0d@?\xd5\xa4\xca" +
+	"\xc3R*<j\xcd\xefLR\xbc\x89\xac\xe9W`\x1a" +
+	"2U\"T;\x83Z\xe1\xb3T\xadPY\x84\xf5*" +
+	"\xediC\x10\xe8H\x1b\x10D\xe1<\xad/\xfe\xd3\"" +
+	"-{9\xfc\x93\x9c\x96\x0d\xfb\xc7\x0a\xae\xc4\x8a\x7f&" +
+	"\xfb\xfd\xde9\xca4w\x15-\x04\xaa:\x8c\x86\xa1;" +
+	"\x9a~\xf4F\x09U)>\x97SJ\xf7\x85\xaf\xf7{" +
+	"\xb5\xd4B\xac5:BqN\xb1\x03\x12hMe\xa0" +
+	"\x0c\x84%{\x82\xa5DMCw\xad|#F_ " +
+	".\xc1DO\x9eT`\xc5\x0fa\xf4\xafj*\xb7x" +
+	"\xd5'\x9b\xf8\x9b\x0b\x84\x9b\xceC%R\x83#\x85\xca" +
+	"8a5\x81\x14\xe6\xc8S\xac,\x18o\xcdk\x81&" +
+	"\xb8I<\xcf\xc9\xc2&\xe9\xd8}b5 y\x92\xc5" +
+	"\xd9\xf8\x88\xc4\xc8yV\x80T0\xa7V\x0a\x99\x82\xec" +
+	"t\xb6V\x0a\xdf\xd8`\xc2\xfc\xf6J\xa1\xec\x11K\xc7" +
+	"\xdd\x1d\x14\x8c\xac,\x1dw_\x9e\xa0\xd8'\xc5jS" +
+	"\xef\x9f,(\xf6-Z\xa8\x05\x92\x0e\xce\xd4\x14\xfb\x18" +
+	"C1\xd6&W9\x8a\x11\xaa\xaa\x82Fhed\xd2" +
+	"$\x05X\x8c\x94^ \x162\x07n\xe7\xf4xt\xf9" +
+	"\xbaW\xbbCS\x8c\xe5\xce\x03p\xbd\x15\xd7\x08\xbf\x94" +
+	"_]`\xb9\x0e:K2\x11?G\xa3}?\x8d\x14" +
+	"G\x0b}U~\x17\xd05\x9c\xb0(\xf6v4\xab\x10" +
+	">V(\x95\xc0\x8eauPK\x02e:Uc\xbd" +
+	"\x98\x03\x1a\xb3jo\x9c)\xe6\x80\xc6l![\x97h" +
+	"\x05\xad~\xcbZ\xa8\x06\x9aa\xbcz:\xf2~\x0d\xf9" +
+	"\xbf\x14\x06\xf1\xb8\x81\xcc\x0b}R\xbe\xbaH\x92\xaa-" +
+	">fk\xd4\"xb;A\xce\x80\x95\xf8\xd7<\x90" +
+	"\xce\xed\x91\x10\xdd\xf3}'&9\xf6n^0\x9f\xee" +
+	"\xf9\xf5\xac\xb06\x15\x92\xf9K\xdfQ\xb3 \xb1-?" +
+	"\x9e\xa9\xbd\xfei\xb6\xce\xea\x96\x9f(\x10\x9e\x7f\xa6j" +
+	"\x9c*\x16\xadJ1EV&\xf8q\x05\xe1\xa5\xb7\xb3" +
+	"\x97\xbeR\xff\xd2g\xb0\x97\xbeX\xff\xd2\xdb\xd8K_" +
+	"\xc6^\xfa>\xf4\xa5\xb7\xab/}6\xfd\x16Co\x84" +
+	"\x0f\xfc/5\xc5\xe2\xf9R\x8d\xb1\x08y[-\xb53" +
+	"\xc6mL\xd3\x81\xe1M\xac\x0e:]\xf4Cp\xa6\x85" +
+	"\xcb\xb94\xcdO@\x1f\x8d1\x0c\xa3\xd0<\xf8\xa5\x05" +
+	"+R\\\xb3Z\xbeq;\x00y\xba\xe6\xb9s\x00&" +
+	"\xe6\xf0\xe7\x99\xba\xe7\xc2\xef\x99\x98A\x80\xa7V\x9e\xb3" +
+	"j\x12q\x9bAx\xae\xad\x05\xb5\x85\x99\xc7\x133\xb6" +
+	"\xf1\xcc\xed\xb3\xaf(\x9c\xd8\x01\xf3\xd4\xdasT\xa8\x85" +
+	"U\xc8\xb6\xf6!\xa5\xc4(\x84\xa7\x8fZ\x98\xbb\xf1\xf3" +
+	"Tq\xd7^\xe2\xd9\xc7\xe7\xa6nj\xdc\x96\x08\x9e]" +
+	"\x7f\x96%\xcc\xe3.\x84\xcb\x13\xd5\xad|Z\x8d\x06S" +
+	"\xab\x82\x84\xf69q\xd2\x83\x96d:\x83\xde\x9aF\x1c" +
+	"1\xc5\xb5\x87\xf6r1/\xe2\xa9\x8e\xa2\xde\x1a\x93 " +
+	"\x9a2u\xaf\x99\x83\xbdf\xc5\xe6\xaf\x99AoMb" +
+	"\xafY\xa5\xfe5k\xd9\xec5\xeb\"\x86.fP}" +
+	"V\xd3[C\xb8a\xbe*E\xf0\xfa\xb2\x12O|\xe5" +
+	"\xaa|p\x86\x87\xcf\xf4\xa1\xe3\xe5\x9b\x84\xaf\x02\x99?" +
+	"m\xe85V\\#\xdd\xa8\xe5\xb2\x1eU\xd8P\xbfd" +
+	"\x17\xbe\xd7u\x16!\x0db\x88\x90`\xbf*3\x89/" +
+	"\xce\x14\x8b\x98\xc4\x8c\x0e\xa5\x1b\xc4\xf8\xe2\xe6Z\xb9n" +
+	"7\xe2\x0d\x0d\x88\xcf+\xaa7I\x8b\xeeF\xc1\x95-" +
+	"\x98\xd7\xb8\x18\xeb.\x13\xdc\xd6L\x8c\x9dZ\xac\xb9\xad" +
+	"\x99\x18[;Ss,hJ!\x09\x9a\x94X\x8e\xd7" +
+	"1pN<\x00\x86\x0f\x8c\xc6\x1f\xf7\xc3\x0b\x15X\xf9" +
+	"j\x81\xcf\xe4\x13!\xf13t^\x8b\xc0\xc2\xd0f\x9f" +
+	"\x12U\xd3\x0a\xcf\xbd3@+\x13\x9cX\x81N^O" +
+	"\xc4\x02\x0fgi\xb4\xd4F\xdf,Zo\xa1YLi" +
+	"\x8e\xf0m\xd3H \xa0\x04\x0b\xfc\x11\xe2s\x19\x03W" +
+	"\x0c\xae\x03\xcb\xa2G$\x14\xff+\xca\xeb9X\x10\xee" +
+	"\xce\x18\xb6\x11\xb7X\xcd+g\x9c\x8d\xb4\xa5U\xc5K" +
+	"\xac\xf69/5b\x85\x08\xb4\xaf\x07\xd6\x06\x88\x18\xda" +
+	"\xb1W\xfd\xeeM\xa6\xfa\xa5\xebX\x9av\x99j\xfa+" +
+	"\xf2\x85\x95\xe0$g\x15Q\x92\x03\xfe\xc4\xcc}\xc3\xf4" +
+	"b\x92i|J\x02E\xdb\xe3\xe7B\xbc\x18\x8d\x05\xe9" +
+	"C\xf7\x11\x19\x81\xcf\xe7\x98\x85,\xe5\x88%W\xd9'" +
+	"2\xf3\xc4\x92\xab1F\xef\xcd\xd1^\x84t\xe7\xb4\xea" +
+	"\xec\xde\xe4<\xb82\xe7I\xf4W?\xfe+\x1f~\xe5" +
+	"\xf6\xd6\x1a\xc3\xfe\xb0\xd3sNnWb*\x05/\x92" +
+	"b\x81\xceXi\x14\xd16*\xd8}\x0a\xe2\xfe2\\" +
+	"\x9eX73\xb6\x91\xab\xcb\x84\x8a`\xcc\xfc\xd6X\xa9" +
+	"Y\x83\xd8\x97\xe16\x96i\xc6 \x96^\xc8e\x86\xb0" +
+	"\xb3\x9a;\x07q=\xee\xb0\x90\xca\xe1\xf6\xb8\xae\xa6\xf9" +
+	"\x80B\xad\xb4P\x18W%%\x0b\x9d\xb0\xe8\xf2\"Q" +
+	"x:7\x1fN\x8a?\xae\xa9\xecLY\x081S\x07" +
+	"\x09\xfd\x97o+Y\x0c\xf2\xb3\xf8\xf1\x00\xd3\x9a\x02V" +
+	"\x8c\xff\xa6\xc5q\x98\x8f\xef7\xea\xe0\x8f\x15J\xde3" +
+	"\x09\x93\x7f_\x99\x86\xcd\x0e\x8e\x85\xcd\x16\x9b\x85\xcd\xd6" +
+	"\xc7\xc2f\xbf\x10\x0c\x8cG\x16\x0aaY\xac\x0e\xfe\xf1" +
+	"zM\x0d\x11\x0f\xc6,\x0e6\x918Z,\xca\xe8*" +
+	"\xf7W\x11\x16\x16\x1bW\xc2\x8d\xd79\xa3\xc8\xe7q\xfb" +
+	"\xb4l\x1fK\x1c\xc5\x10\x11\x14\xf7{}\xa6\xaf\x85X" +
+	"\x8f\x80J\xd0\x99\xc1K\xbfY\xb8\x00\xfa\xc0\xda\x04\x07" +
+	"\xe6U\xbc,\xb0Q\x96kb1\xb3\xc9\xf4c\x00D" +
+	"\xfdtg\xc1\xb2\xddU\x197\xae_\x05Zu\xc1\x1c" +
+	"\xf5\xf3\x00\xde3}k\xd2$\xf5\xa4\xad\xd6\x81\x85\xd8" +
+	"\x0e\x9d\xbf\xe0\x0an\x12\xa7\xcf-\xbd\x8fY\xea\x17\x8c" +
+	"\xbb\xf6\xa0N\xceNy\xd4\xc9\xd9\x1e\x1e\xd0h\xc0\x19" +
+	"\x0a\x85k\x82~)9R]\x93\x1a\x09O\xba2\xdf" +
+	"\xe3\x0c\xbb}\xd9\xe9\xceP\x95\xdb}v\xc2\xc9\xff\xaa" +
+	"\xfc\xa8bdW\x09\xca\xfd\xbc\x86\xa2\xb5\xafZ\x89\xa5" +
+	"O\xad\x86!\x9e\xf1\xfb\x97\x89ih\xbc\xf2\x9f\x15\xc9" +
+	"\xd9\xe4\xb3L\x89}P\x8f\xd7\xa3;G\xe9gV\xf4" +
+	"Ck\x1fr\x88\xfbs2\xbc`\xa2\x95o\x8b\x1b\x12" +
+	"*\xcd\xab\xe5\x8f\x158\x03\xb7W\xe8\x82\xa7y\x09B" +
+	"\x0b\xac\xc1\xf8\xf1\xb4D\"?\xff\x0f\"t\x83-"
 
 func init() {
 	schemas.Register(schema_ffaaf7385bc4adad,
+		0x8092334775ad2247,
+		0x81ae0ac668cca1f3,
+		0x82510d3464397f38,
+		0x826958b3c0d0f6e3,
 		0x83479da67279e173,
+		0x835910c9b39b7ee1,
+		0x84071f28833de648,
+		0x844530cf92fcc3c6,
+		0x8489b9d20466f5d5,
+		0x850e9c47f95ce0ba,
+		0x855d884a853afbc7,
+		0x85d91084036492b6,
+		0x85e40ceb4cb0ce18,
+		0x866cb27128e3b59f,
+		0x86b1a5ed2ee3fe0a,
+		0x870856d7715ebfde,
+		0x8709f75f61f7fec9,
+		0x87eac53779263cee,
+		0x881f619e1f61ed13,
+		0x88a7c20d48426128,
+		0x8ab9d39681015734,
+		0x8ada080957d5db5d,
+		0x8aef91973dc8a4f5,
+		0x8b4c03a0662a38dc,
+		0x8b5fce9ce65a7de7,
+		0x8bade35f60a94dc8,
+		0x8bb527ab52b03cf5,
+		0x8bf9d41f934c512d,
+		0x8ceb3503d8b127df,
+		0x8d1e6349ca6a41a4,
+		0x8d3e03c157278fae,
+		0x8e227cb048ce3dce,
+		0x8e73064b14079816,
+		0x8f080427d9f4b2e3,
+		0x90a3950a51412b8b,
+		0x90dbf7ca3e53e1f6,
+		0x91025b8ad8bd0617,
+		0x9488d71c49c86c29,
+		0x968709e5ac646fae,
+		0x96dcde632ac079f4,
+		0x97094d00caad0b04,
+		0x97c2918f8d3765ca,
+		0x9a5dadc3cb5eb5a1,
+		0x9b41a13e97cb6558,
+		0x9bef35d18b7c8e16,
+		0x9c9b74080d20d473,
+		0x9cba43a78be72400,
+		0x9d82529754851252,
+		0x9eeaaff965e45456,
+		0x9f1a1356451b379a,
+		0x9fb7fa9c3928a4d3,
+		0x9fe3605dfe914696,
+		0xa01442f335a6cc00,
 		0xa0ef8355b64ee985,
+		0xa199c5435b00304a,
+		0xa1d10ffd58496af1,
 		0xa20f49456be85b99,
+		0xa2c865b278522903,
+		0xa365dd6ce4fb8685,
+		0xa3cb406c522dcab1,
+		0xa3e6c413c9e0821d,
+		0xa439d311e69f29c8,
+		0xa4b1582163cd9942,
+		0xa6d76ce69f13a816,
+		0xa788b2549075c742,
+		0xa85a62dd95c50d7f,
+		0xa8757cef51f9fba2,
 		0xaa2f3c8ad1c3af24,
+		0xaaa69aebe451afba,
+		0xab9e06d122b40479,
+		0xac051338e49b068e,
+		0xacc575e9c260e2b7,
 		0xace5517aafc86077,
+		0xacebf6dc0e874b1c,
+		0xad5e6e3b177fdffd,
+		0xadb66abea677f8fc,
+		0xae5e0ae5001ebdfe,
 		0xae78ee8eb6b3a134,
+		0xaf643dcb7f32e91b,
+		0xb06fe4f9c1d2ad84,
+		0xb231e9cbb2c1d991,
+		0xb2b0116d3f068d4f,
+		0xb30aaab6c6870e33,
+		0xb34e262fa935335a,
+		0xb3c3d48e8891ae7a,
+		0xb4a5e5ca18fd98ef,
 		0xb5418b8ea8ead17b,
+		0xb6e12b86d68fe663,
+		0xb6f01d18a2b0fd8e,
+		0xb727729a699a1475,
 		0xb737e899dd6633f1,
+		0xb7a8e13705a17ca4,
+		0xb9a3cf4b6d466820,
+		0xba317e1d56859b29,
 		0xba77e3fa3aa9b6ca,
+		0xbc1bca51fe8ba645,
+		0xbc21bf853f72a228,
+		0xbcc8964d9d37437b,
+		0xbe34f78f6a935b18,
+		0xbeae16fd63482d9a,
+		0xbf563be589299eae,
+		0xc09b02a80f2f64e9,
+		0xc437ab0d294724d9,
 		0xc5e65eec3dcf5b10,
+		0xc6e1e7b26fef688a,
 		0xc76ccd4502bb61e7,
+		0xc8865f0ec25b4ef8,
+		0xc9701dd28ecc4dec,
+		0xc9971c07179123bc,
+		0xc9fd278024a95a99,
+		0xca27841148b7050e,
+		0xcab5a8bc4ea76d78,
+		0xcb5061b78617cf88,
+		0xcbb1707a6436eddd,
+		0xcc1007203694c512,
 		0xcc2f70676afee4e7,
+		0xcc3f479fbfe6ceff,
+		0xcd8f48ddef242a41,
+		0xce07fd7fe8b3d1c9,
+		0xce09e0e0707785e3,
+		0xce733f0914c80b6b,
 		0xceba3c1a97be15f8,
+		0xcefe45fd0d8dabff,
+		0xcfae465adf42c669,
+		0xcfb7c5597c044cdb,
+		0xd0066da47b16db35,
 		0xd0476e0f34d1411a,
+		0xd2220742c9b518d2,
+		0xd285ab9e532f8e8f,
+		0xd2cb6549091ed7df,
+		0xd45fb0bc9ddaf48d,
+		0xd74ba8d601b5841e,
+		0xd8998defc6b19abf,
+		0xd8de4302b6d23065,
 		0xd9d61d1d803c85fc,
+		0xda0e292b1d45f674,
+		0xda7cf4f7252fd4a4,
+		0xdb0d49abe2bb2ab6,
 		0xde3a625e70772b9a,
+		0xdebaeed2a782ac80,
+		0xdf59f911433f86e4,
+		0xdf5ca9795f6cfe95,
 		0xdf703ca0befc3afc,
+		0xdfc2b469c920ffca,
+		0xdfca9ee49ebf0d98,
 		0xe00e522611477055,
+		0xe024baaf8cbb64fb,
+		0xe1d66f75234ae38a,
 		0xe313695ea9477b30,
+		0xe3cc22436fc42c31,
+		0xe4f37be9f7af4d81,
+		0xe5a25a689d86a6a8,
+		0xe5adba5696f0c278,
 		0xe5ea916eb0c31336,
+		0xe989fde14d6e82dd,
+		0xeabc17418056c495,
+		0xeacad74128d0e3e7,
+		0xeadf00d4ff560865,
+		0xeb6d1af8db5d3520,
+		0xebf2395e50275e51,
+		0xecb6b95b8291c07d,
+		0xedd2e5b018f17bbb,
+		0xee874453d8f8a4a3,
+		0xeedd21a69204efcb,
+		0xef387164b6b1f60d,
+		0xefbec970d17dc985,
 		0xf026e3d750335bc1,
+		0xf05e62cf9998896b,
+		0xf18bd11dcac0404b,
+		0xf214a606e1c68565,
+		0xf2725610fbee91f4,
 		0xf34be5cbac1feed1,
+		0xf3c4f20ca0d204ce,
+		0xf3c6b4290560ba4b,
+		0xf3fdff7dbc62813a,
 		0xf41122f890a371a6,
 		0xf44732c48f949ab8,
+		0xf4e3e92ae0815f15,
+		0xf5024761975c861f,
+		0xf6bec8a61075119a,
+		0xf73deda1e0d05941,
+		0xf769e31c9771e7c7,
+		0xf88413404057f991,
 		0xf8e86a5c0baa01bc,
-		0xf9b3cd8033aba1f8)
+		0xf9b3cd8033aba1f8,
+		0xfaf066b0dfd2c1d5,
+		0xfc257fe2fa86af93,
+		0xfc33a518596cc0f7,
+		0xfd592f0d89b7b928,
+		0xfdae861fa8890aa3,
+		0xfef64f2c60ea9ff8)
 }
@@ -382,3 +382,94 @@ func (c *ConmonClient) SetWindowSizeContainer(ctx context.Context, cfg *SetWindo
 
 	return nil
 }
+
+// AttachStreamContainerConfig is the configuration for calling the
+// AttachStreamContainer method.
+type AttachStreamContainerConfig struct {
+	// ID is the container identifier or name.
+	ID string
+}
+
+// AttachStreamContainerResult is the result of the AttachStreamContainer
+// method.
+type AttachStreamContainerResult struct {
+	// SocketPath is the path of the one-shot raw stream socket.
+	SocketPath string
+
+	// Token has to be sent right after connecting to the socket.
+	Token string
+}
+
+// AttachStreamContainer requests a one-shot raw stream socket which passes the
+// container IO through. Use DialAttachStream to connect to it.
+func (c *ConmonClient) AttachStreamContainer(
+	ctx context.Context, cfg *AttachStreamContainerConfig,
+) (*AttachStreamContainerResult, error) {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return nil, fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.AttachStreamContainer(ctx, func(p proto.Conmon_attachStreamContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return nil, fmt.Errorf("create result: %w", err)
+	}
+
+	resp, err := result.Response()
+	if err != nil {
+		return nil, fmt.Errorf("get response: %w", err)
+	}
+
+	socketPath, err := resp.SocketPath()
+	if err != nil {
+		return nil, fmt.Errorf("get socket path: %w", err)
+	}
+
+	token, err := resp.Token()
+	if err != nil {
+		return nil, fmt.Errorf("get token: %w", err)
+	}
+
+	return &AttachStreamContainerResult{
+		SocketPath: socketPath,
+		Token:      token,
+	}, nil
+}
+
+// DialAttachStream connects to the one-shot raw stream socket of an attach
+// stream and sends the token of the server response, which is required before
+// any data gets forwarded.
+func DialAttachStream(socketPath, token string) (*net.UnixConn, error) {
+	conn, err := DialLongSocket("unix", socketPath)
+	if err != nil {
+		return nil, fmt.Errorf("connect to attach stream socket: %w", err)
+	}
+
+	if _, err := conn.Write([]byte(token)); err != nil {
+		conn.Close()
+
+		return nil, fmt.Errorf("send attach stream token: %w", err)
+	}
+
+	return conn, nil
+}
@@ -39,6 +39,7 @@ var (
 type ConmonClient struct {
 	serverPID uint32
 	runDir    string
+	podID     string
 	logger    *logrus.Logger
 }
 
@@ -81,6 +82,11 @@ type ConmonServerConfig struct {
 
 	// CgroupManager can be use to select the cgroup manager.
 	CgroupManager CgroupManager
+
+	// PodID is sent on every connection to a server running the dispatch
+	// command, which serves every pod by a dedicated instance. Has to be empty
+	// for all other servers.
+	PodID string
 }
 
 // CgroupManager is the enum for all available cgroup managers.
@@ -194,6 +200,7 @@ func (c *ConmonServerConfig) toClient() (*ConmonClient, error) {
 
 	return &ConmonClient{
 		runDir: c.ServerRunDir,
+		podID:  c.PodID,
 		logger: c.ClientLogger,
 	}, nil
 }
@@ -353,6 +360,14 @@ func (c *ConmonClient) newRPCConn() (*rpc.Conn, error) {
 		return nil, fmt.Errorf("dial long socket: %w", err)
 	}
 
+	if c.podID != "" {
+		if _, err := socketConn.Write([]byte(c.podID + "\n")); err != nil {
+			socketConn.Close()
+
+			return nil, fmt.Errorf("send pod ID: %w", err)
+		}
+	}
+
 	return rpc.NewConn(rpc.NewStreamTransport(socketConn), nil), nil
 }
 
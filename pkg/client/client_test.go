@@ -4,12 +4,18 @@ import (
 	"context"
 	"fmt"
 	"io"
+	"net"
 	"os"
+	"os/exec"
 	"path/filepath"
+	"strings"
 	"sync"
+	"syscall"
 	"time"
 
+	"capnproto.org/go/capnp/v3/rpc"
 	"github.com/containers/common/pkg/resize"
+	"github.com/containers/conmon-rs/internal/proto"
 	"github.com/containers/conmon-rs/pkg/client"
 	"github.com/containers/storage/pkg/unshare"
 	. "github.com/onsi/ginkgo/v2"
@@ -381,6 +387,174 @@ var _ = Describe("ConmonClient", func() {
 			testAttach(sut, cfg, stdinWriter, stderrReader, "Test stderr", true)
 		})
 
+		It("should pass the IO through the attach stream socket", func() {
+			const (
+				terminal   = false
+				testString = "Test passthrough"
+			)
+			tr = newTestRunner()
+			tr.createRuntimeConfigWithProcessArgs(terminal, []string{"/busybox", "sh"}, nil)
+			sut = tr.configGivenEnv()
+			tr.createContainer(sut, terminal)
+			tr.startContainer(sut)
+
+			ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
+			defer cancel()
+			result, err := sut.AttachStreamContainer(ctx, &client.AttachStreamContainerConfig{
+				ID: tr.ctrID,
+			})
+			Expect(err).To(BeNil())
+			Expect(result.Token).NotTo(BeEmpty())
+
+			conn, err := client.DialAttachStream(result.SocketPath, result.Token)
+			Expect(err).To(BeNil())
+			defer conn.Close()
+
+			// The socket is one-shot
+			Eventually(func() bool {
+				_, err := os.Stat(result.SocketPath)
+
+				return os.IsNotExist(err)
+			}, time.Second*5).Should(BeTrue())
+
+			_, err = fmt.Fprintf(conn, "/busybox echo -n %s\n", testString)
+			Expect(err).To(BeNil())
+
+			Expect(conn.SetReadDeadline(time.Now().Add(5 * time.Second))).To(BeNil())
+			data := make([]byte, len(testString))
+			_, err = io.ReadFull(conn, data)
+			Expect(err).To(BeNil())
+			Expect(string(data)).To(Equal(testString))
+		})
+
 		// TODO: add terminal based attach tests
 	})
+
+	Describe("IdleAudit", func() {
+		It("should not wake up while no containers are active", func() {
+			const (
+				auditEnv        = "CONMON_AUDIT_IDLE_WAKEUPS"
+				sessionTTLEnv   = "CONMON_EXEC_SESSION_TTL"
+				containerTTLEnv = "CONMON_EXITED_CONTAINER_TTL"
+				idleWakeup      = "Wakeup while no containers are active"
+			)
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+
+			// Short retention periods make periodic cleanup timers fire within the
+			// observed time, whereas they must not be armed at all without state to clean.
+			env := map[string]string{
+				auditEnv:        "true",
+				sessionTTLEnv:   "1",
+				containerTTLEnv: "1",
+			}
+			for key, value := range env {
+				Expect(os.Setenv(key, value)).To(BeNil())
+			}
+			defer func() {
+				for key := range env {
+					os.Unsetenv(key)
+				}
+			}()
+
+			logPath := filepath.Join(tr.tmpDir, "server.log")
+			logFile, err := os.Create(logPath)
+			Expect(err).To(BeNil())
+			defer logFile.Close()
+
+			cfg := client.NewConmonServerConfig(runtimePath, tr.rr.runtimeRoot, tr.tmpDir)
+			cfg.ConmonServerPath = conmonPath
+			cfg.Stdout = logFile
+			sut, err = client.New(cfg)
+			Expect(err).To(BeNil())
+
+			// Connecting clients wake up the server, but nothing else should.
+			time.Sleep(time.Second)
+			wakeups := strings.Count(fileContents(logPath), idleWakeup)
+			time.Sleep(5 * time.Second)
+			Expect(strings.Count(fileContents(logPath), idleWakeup)).To(Equal(wakeups))
+		})
+	})
+
+	Describe("ServeStdio", func() {
+		It("should serve RPC over stdin and stdout", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+			sut = nil
+
+			fds, err := syscall.Socketpair(syscall.AF_UNIX, syscall.SOCK_STREAM|syscall.SOCK_CLOEXEC, 0)
+			Expect(err).To(BeNil())
+			serverEnd := os.NewFile(uintptr(fds[0]), "server")
+			clientEnd := os.NewFile(uintptr(fds[1]), "client")
+			defer clientEnd.Close()
+
+			cmd := exec.Command(conmonPath,
+				"--serve-stdio",
+				"--skip-fork",
+				"--log-driver", client.LogDriverStdout,
+				"--runtime", runtimePath,
+				"--runtime-dir", tr.tmpDir,
+				"--runtime-root", tr.rr.runtimeRoot,
+			)
+			cmd.Stdin = serverEnd
+			cmd.Stdout = serverEnd
+			cmd.Stderr = os.Stderr
+			Expect(cmd.Start()).To(BeNil())
+			Expect(serverEnd.Close()).To(BeNil())
+			defer func() {
+				Expect(cmd.Process.Signal(syscall.SIGTERM)).To(BeNil())
+				Expect(cmd.Wait()).To(BeNil())
+			}()
+
+			conn, err := net.FileConn(clientEnd)
+			Expect(err).To(BeNil())
+			rpcConn := rpc.NewConn(rpc.NewStreamTransport(conn), nil)
+			defer rpcConn.Close()
+
+			ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
+			defer cancel()
+			conmon := proto.Conmon(rpcConn.Bootstrap(ctx))
+			future, free := conmon.Version(ctx, nil)
+			defer free()
+
+			result, err := future.Struct()
+			Expect(err).To(BeNil())
+			response, err := result.Response()
+			Expect(err).To(BeNil())
+			Expect(response.ProcessId()).To(Equal(uint32(cmd.Process.Pid)))
+
+			_, err = os.Stat(filepath.Join(tr.tmpDir, "conmon.sock"))
+			Expect(os.IsNotExist(err)).To(BeTrue())
+		})
+	})
+
+	Describe("LogLevel", func() {
+		It("should cycle the log level on SIGUSR2", func() {
+			tr = newTestRunner()
+			tr.createRuntimeConfig(false)
+
+			logPath := filepath.Join(tr.tmpDir, "server.log")
+			logFile, err := os.Create(logPath)
+			Expect(err).To(BeNil())
+			defer logFile.Close()
+
+			cfg := client.NewConmonServerConfig(runtimePath, tr.rr.runtimeRoot, tr.tmpDir)
+			cfg.ConmonServerPath = conmonPath
+			cfg.LogLevel = client.LogLevelInfo
+			cfg.Stdout = logFile
+			sut, err = client.New(cfg)
+			Expect(err).To(BeNil())
+
+			Expect(syscall.Kill(int(sut.PID()), syscall.SIGUSR2)).To(BeNil())
+			Eventually(func() string {
+				return fileContents(logPath)
+			}, time.Second*5).Should(ContainSubstring("set log level to: debug"))
+
+			_, err = sut.Version(context.Background())
+			Expect(err).To(BeNil())
+			Eventually(func() string {
+				return fileContents(logPath)
+			}, time.Second*5).Should(ContainSubstring("Got a version request"))
+		})
+	})
 })
@@ -0,0 +1,356 @@
+package client
+
+import (
+	"context"
+	"fmt"
+	"syscall"
+	"time"
+
+	"github.com/containers/conmon-rs/internal/proto"
+)
+
+// ContainerConfig is the configuration for the lifecycle methods which only
+// require the container.
+type ContainerConfig struct {
+	// ID is the container identifier or name.
+	ID string
+}
+
+// StartContainer starts a container created by CreateContainer.
+func (c *ConmonClient) StartContainer(ctx context.Context, cfg *ContainerConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.StartContainer(ctx, func(p proto.Conmon_startContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("get response: %w", err)
+	}
+
+	return nil
+}
+
+// KillContainerConfig is the configuration for calling the KillContainer
+// method.
+type KillContainerConfig struct {
+	// ID is the container identifier or name.
+	ID string
+
+	// Signal is sent to the container process.
+	Signal syscall.Signal
+}
+
+// KillContainer sends a signal to the container process.
+func (c *ConmonClient) KillContainer(ctx context.Context, cfg *KillContainerConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.KillContainer(ctx, func(p proto.Conmon_killContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		req.SetSignal(uint32(cfg.Signal))
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("get response: %w", err)
+	}
+
+	return nil
+}
+
+// WaitContainerResult is the result of the WaitContainer method.
+type WaitContainerResult struct {
+	// ExitCode after applying the success exit codes.
+	ExitCode int32
+
+	// RawExitCode before applying the success exit codes.
+	RawExitCode int32
+
+	// Signal which terminated the container, 0 if it exited on its own.
+	Signal syscall.Signal
+
+	// OOMKilled is true if the container got killed because of memory
+	// exhaustion.
+	OOMKilled bool
+
+	// TimedOut is true if the container got killed because of its timeout.
+	TimedOut bool
+
+	// ExitedAt is the time the container exited.
+	ExitedAt time.Time
+}
+
+// WaitContainer blocks until the container exited, which returns immediately
+// for exited containers.
+func (c *ConmonClient) WaitContainer(ctx context.Context, cfg *ContainerConfig) (*WaitContainerResult, error) {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return nil, fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.WaitContainer(ctx, func(p proto.Conmon_waitContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return nil, fmt.Errorf("create result: %w", err)
+	}
+
+	resp, err := result.Response()
+	if err != nil {
+		return nil, fmt.Errorf("get response: %w", err)
+	}
+
+	return &WaitContainerResult{
+		ExitCode:    resp.ExitCode(),
+		RawExitCode: resp.RawExitCode(),
+		Signal:      syscall.Signal(resp.Signal()),
+		OOMKilled:   resp.OomKilled(),
+		TimedOut:    resp.TimedOut(),
+		ExitedAt:    time.Unix(0, int64(resp.ExitedAt())),
+	}, nil
+}
+
+// PauseContainer freezes all processes of the container.
+func (c *ConmonClient) PauseContainer(ctx context.Context, cfg *ContainerConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.PauseContainer(ctx, func(p proto.Conmon_pauseContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("get response: %w", err)
+	}
+
+	return nil
+}
+
+// UnpauseContainer thaws all processes of a paused container.
+func (c *ConmonClient) UnpauseContainer(ctx context.Context, cfg *ContainerConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.UnpauseContainer(ctx, func(p proto.Conmon_unpauseContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("get response: %w", err)
+	}
+
+	return nil
+}
+
+// RemoveContainerConfig is the configuration for calling the RemoveContainer
+// method.
+type RemoveContainerConfig struct {
+	// ID is the container identifier or name.
+	ID string
+
+	// RemoveLogs removes the CRI log files as well.
+	RemoveLogs bool
+}
+
+// RemoveContainer stops tracking an exited container.
+func (c *ConmonClient) RemoveContainer(ctx context.Context, cfg *RemoveContainerConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.RemoveContainer(ctx, func(p proto.Conmon_removeContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		req.SetRemoveLogs(cfg.RemoveLogs)
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("get response: %w", err)
+	}
+
+	return nil
+}
+
+// ShutdownServerConfig is the configuration for calling the ShutdownServer
+// method.
+type ShutdownServerConfig struct {
+	// WaitForContainers waits for the containers to exit instead of stopping
+	// them.
+	WaitForContainers bool
+
+	// Timeout is the maximum time to wait for the containers, 0 for no limit.
+	Timeout time.Duration
+}
+
+// ShutdownServer shuts the server down in order via RPC. In contrast to
+// Shutdown, it does not wait for the server process to be removed.
+func (c *ConmonClient) ShutdownServer(ctx context.Context, cfg *ShutdownServerConfig) error {
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.Shutdown(ctx, func(p proto.Conmon_shutdown_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		req.SetWaitForContainers(cfg.WaitForContainers)
+		req.SetTimeoutSec(uint64(cfg.Timeout.Seconds()))
+
+		if err := p.SetRequest(req); err != nil {
+			return fmt.Errorf("set request: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("get response: %w", err)
+	}
+
+	return nil
+}